@@ -3,13 +3,16 @@
 use crate::auth::{AuthManager, BearerAuthManager};
 use crate::config::AnthropicConfig;
 use crate::errors::{AnthropicError, AnthropicResult};
+use crate::services::messages::{MessagesService, MessagesServiceImpl};
 use crate::transport::{HttpTransport, ReqwestTransport};
 use std::sync::Arc;
 
 /// Trait defining the main Anthropic client interface
 pub trait AnthropicClient: Send + Sync {
-    // Service accessors will be added here as services are implemented
-    // fn messages(&self) -> Arc<dyn MessagesService>;
+    /// Messages API, honouring the configured model fallback policy
+    fn messages(&self) -> Arc<dyn MessagesService>;
+
+    // Remaining service accessors will be added here as services are implemented
     // fn models(&self) -> Arc<dyn ModelsService>;
     // fn batches(&self) -> Arc<dyn BatchesService>;
 }
@@ -19,6 +22,7 @@ pub struct AnthropicClientImpl {
     config: Arc<AnthropicConfig>,
     transport: Arc<dyn HttpTransport>,
     auth_manager: Arc<dyn AuthManager>,
+    messages: Arc<dyn MessagesService>,
 }
 
 impl AnthropicClientImpl {
@@ -42,10 +46,13 @@ impl AnthropicClientImpl {
             }
         })?;
 
+        let messages = build_messages_service(&config, &transport, &auth_manager)?;
+
         Ok(Self {
             config,
             transport,
             auth_manager,
+            messages,
         })
     }
 
@@ -56,10 +63,14 @@ impl AnthropicClientImpl {
         transport: Arc<dyn HttpTransport>,
         auth_manager: Arc<dyn AuthManager>,
     ) -> Self {
+        let messages = build_messages_service(&config, &transport, &auth_manager)
+            .expect("test configuration has a valid base URL");
+
         Self {
             config: Arc::new(config),
             transport,
             auth_manager,
+            messages,
         }
     }
 
@@ -80,7 +91,21 @@ impl AnthropicClientImpl {
 }
 
 impl AnthropicClient for AnthropicClientImpl {
-    // Service implementations will be added here
+    fn messages(&self) -> Arc<dyn MessagesService> {
+        self.messages.clone()
+    }
+}
+
+fn build_messages_service(
+    config: &AnthropicConfig,
+    transport: &Arc<dyn HttpTransport>,
+    auth_manager: &Arc<dyn AuthManager>,
+) -> AnthropicResult<Arc<dyn MessagesService>> {
+    let service = MessagesServiceImpl::from_config(transport.clone(), auth_manager.clone(), config)
+        .map_err(|e| AnthropicError::Configuration {
+            message: e.to_string(),
+        })?;
+    Ok(Arc::new(service))
 }
 
 /// Create a new Anthropic client from configuration
@@ -121,4 +146,16 @@ mod tests {
         let client = create_client(config);
         assert!(client.is_err());
     }
+
+    #[test]
+    fn test_create_client_invalid_base_url() {
+        let config = AnthropicConfig::builder()
+            .api_key(SecretString::new("sk-ant-test123456789012345".to_string()))
+            .base_url("not a url")
+            .build()
+            .unwrap();
+
+        let client = create_client(config);
+        assert!(matches!(client, Err(AnthropicError::Configuration { .. })));
+    }
 }
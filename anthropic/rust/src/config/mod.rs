@@ -39,6 +39,50 @@ impl BetaFeature {
     }
 }
 
+/// Ordered models to fall back to when the requested model is unavailable.
+///
+/// `MessagesService::create` moves to the next model when the current one is
+/// overloaded (529), or is still rate limited (429) after
+/// `rate_limit_retries` further attempts. Other errors are returned as-is.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelFallbackPolicy {
+    /// Model ids tried in order after the requested model
+    pub models: Vec<String>,
+    /// Extra attempts against a rate-limited model before falling back
+    pub rate_limit_retries: u32,
+    /// Delay between rate-limited attempts on the same model
+    pub rate_limit_backoff: Duration,
+}
+
+impl ModelFallbackPolicy {
+    /// Creates a policy falling back to the given models in order
+    pub fn new(models: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            models: models.into_iter().map(Into::into).collect(),
+            rate_limit_retries: 2,
+            rate_limit_backoff: Duration::from_secs(1),
+        }
+    }
+
+    /// Sets how often a rate-limited model is retried before falling back
+    pub fn with_rate_limit_retries(mut self, retries: u32, backoff: Duration) -> Self {
+        self.rate_limit_retries = retries;
+        self.rate_limit_backoff = backoff;
+        self
+    }
+
+    /// Returns the models to try for a request, starting with the requested one
+    pub fn chain(&self, requested: &str) -> Vec<String> {
+        let mut chain = vec![requested.to_string()];
+        for model in &self.models {
+            if !chain.contains(model) {
+                chain.push(model.clone());
+            }
+        }
+        chain
+    }
+}
+
 /// Configuration for the Anthropic API client.
 #[derive(Clone)]
 pub struct AnthropicConfig {
@@ -54,6 +98,8 @@ pub struct AnthropicConfig {
     pub max_retries: u32,
     /// Beta features to enable
     pub beta_features: Vec<BetaFeature>,
+    /// Models to fall back to on overload or persistent rate limiting
+    pub model_fallback: Option<ModelFallbackPolicy>,
 }
 
 impl AnthropicConfig {
@@ -92,6 +138,7 @@ impl AnthropicConfig {
             timeout: Duration::from_secs(timeout_secs),
             max_retries,
             beta_features: Vec::new(),
+            model_fallback: None,
         })
     }
 }
@@ -105,6 +152,7 @@ pub struct AnthropicConfigBuilder {
     timeout: Option<Duration>,
     max_retries: Option<u32>,
     beta_features: Vec<BetaFeature>,
+    model_fallback: Option<ModelFallbackPolicy>,
 }
 
 impl AnthropicConfigBuilder {
@@ -150,6 +198,12 @@ impl AnthropicConfigBuilder {
         self
     }
 
+    /// Sets the model fallback policy
+    pub fn model_fallback(mut self, policy: ModelFallbackPolicy) -> Self {
+        self.model_fallback = Some(policy);
+        self
+    }
+
     /// Builds the configuration
     pub fn build(self) -> AnthropicResult<AnthropicConfig> {
         let api_key = self.api_key.ok_or_else(|| AnthropicError::Configuration {
@@ -163,6 +217,7 @@ impl AnthropicConfigBuilder {
             timeout: self.timeout.unwrap_or(Duration::from_secs(DEFAULT_TIMEOUT_SECS)),
             max_retries: self.max_retries.unwrap_or(DEFAULT_MAX_RETRIES),
             beta_features: self.beta_features,
+            model_fallback: self.model_fallback,
         })
     }
 }
@@ -218,4 +273,25 @@ mod tests {
         assert_eq!(config.max_retries, 5);
         assert_eq!(config.beta_features.len(), 1);
     }
+
+    #[test]
+    fn test_model_fallback_chain() {
+        let policy = ModelFallbackPolicy::new(["claude-3-5-sonnet", "claude-3-5-haiku"]);
+        assert_eq!(
+            policy.chain("claude-3-opus"),
+            vec!["claude-3-opus", "claude-3-5-sonnet", "claude-3-5-haiku"]
+        );
+        // The requested model is not tried twice
+        assert_eq!(
+            policy.chain("claude-3-5-sonnet"),
+            vec!["claude-3-5-sonnet", "claude-3-5-haiku"]
+        );
+
+        let config = AnthropicConfig::builder()
+            .api_key(SecretString::new("sk-ant-test".to_string()))
+            .model_fallback(policy.clone())
+            .build()
+            .unwrap();
+        assert_eq!(config.model_fallback, Some(policy));
+    }
 }
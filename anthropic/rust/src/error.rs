@@ -45,6 +45,17 @@ pub enum AnthropicError {
     #[error("Stream error: {0}")]
     Stream(String),
 
+    /// Request input exceeds the caller's token budget
+    #[error("Token budget exceeded: {input_tokens} input tokens, {max_input_tokens} allowed")]
+    TokenBudgetExceeded {
+        input_tokens: u32,
+        max_input_tokens: u32,
+    },
+
+    /// Tool loop kept requesting tools past its iteration cap
+    #[error("Tool loop exceeded {max_iterations} iterations")]
+    ToolLoopLimitExceeded { max_iterations: u32 },
+
     /// Internal error
     #[error("Internal error: {0}")]
     Internal(String),
@@ -109,7 +120,7 @@ pub struct ApiErrorDetail {
 }
 
 /// Stream error types
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, PartialEq, Error)]
 pub enum StreamError {
     #[error("Stream parsing error: {0}")]
     Parse(String),
@@ -70,7 +70,7 @@ pub mod fixtures;
 // Re-exports for convenience
 pub use auth::{AuthManager, BearerAuthManager};
 pub use client::{create_client, create_client_from_env, AnthropicClient, AnthropicClientImpl};
-pub use config::{AnthropicConfig, AnthropicConfigBuilder, BetaFeature};
+pub use config::{AnthropicConfig, AnthropicConfigBuilder, BetaFeature, ModelFallbackPolicy};
 pub use errors::{AnthropicError, AnthropicResult};
 pub use observability::{
     DefaultTracer, InMemoryMetricsCollector, LogFormat, LogLevel, LoggingConfig, MetricsCollector,
@@ -87,6 +87,7 @@ pub use types::{Role, StopReason, Usage};
 pub use services::messages::{
     MessagesService, MessagesServiceImpl, Message, MessageParam, MessageContent,
     ContentBlock, CreateMessageRequest, CountTokensRequest, TokenCount, MessageStream,
+    StreamAccumulator, AccumulatorEvent,
 };
 pub use services::models::{ModelsService, ModelsServiceImpl, ModelInfo, ModelListResponse};
 
@@ -111,6 +112,7 @@ pub use services::admin::{
 pub use services::batches::{
     BatchesService, BatchesServiceImpl, MessageBatch, CreateBatchRequest, BatchRequest,
     BatchListParams, BatchListResponse, BatchResultsResponse, BatchStatus, BatchProcessingStatus,
+    BatchOutcome, BatchOutcomeStream,
 };
 
 #[cfg(feature = "beta")]
@@ -123,6 +125,7 @@ pub use services::beta::{
     extract_text_without_thinking, get_extended_thinking_beta_header,
     // PDF Support
     create_pdf_content, create_pdf_content_from_base64, create_cacheable_pdf_content,
+    create_pdf_content_with_pages, create_multi_pdf_message,
     validate_pdf_bytes, validate_pdf_base64, extract_pdf_blocks, get_pdf_support_beta_header,
     // Prompt Caching
     CacheableContent, CacheableSystemPromptBuilder, cacheable_system_prompt,
@@ -132,7 +135,8 @@ pub use services::beta::{
     // Computer Use
     create_computer_use_tools, ComputerToolResultBuilder, create_text_result,
     create_screenshot_result, create_error_result, get_computer_use_beta_header,
-    validate_screen_dimensions,
+    validate_screen_dimensions, ComputerUseAgent, ComputerUseOutcome,
+    DEFAULT_MAX_COMPUTER_USE_ITERATIONS,
 };
 
 /// The default Anthropic API base URL
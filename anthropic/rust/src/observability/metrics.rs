@@ -215,6 +215,12 @@ pub mod metric_names {
     /// Number of output tokens generated
     pub const TOKENS_OUTPUT: &str = "anthropic.tokens.output";
 
+    /// Number of input tokens written to the prompt cache
+    pub const TOKENS_CACHE_CREATION: &str = "anthropic.tokens.cache_creation";
+
+    /// Number of input tokens read from the prompt cache
+    pub const TOKENS_CACHE_READ: &str = "anthropic.tokens.cache_read";
+
     /// Number of times rate limits were hit
     pub const RATE_LIMIT_HITS: &str = "anthropic.rate_limit.hits";
 
@@ -223,6 +229,9 @@ pub mod metric_names {
 
     /// Number of retry attempts made
     pub const RETRY_ATTEMPTS: &str = "anthropic.retry.attempts";
+
+    /// Number of times a request moved on to a fallback model
+    pub const MODEL_FALLBACKS: &str = "anthropic.model_fallbacks";
 }
 
 #[cfg(test)]
@@ -1,10 +1,12 @@
 use crate::errors::AnthropicError;
 use crate::resilience::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig};
-use crate::resilience::rate_limiter::{RateLimiter, RateLimitConfig};
-use crate::resilience::retry::{RetryConfig, RetryExecutor};
+use crate::resilience::rate_limiter::{RateLimitHeaders, RateLimiter, RateLimitConfig};
+use crate::resilience::retry::{RetryConfig, RetryExecutor, RetryHook};
 use async_trait::async_trait;
+use http::{Response, StatusCode};
 use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 
 /// Trait for resilience orchestration
 #[async_trait]
@@ -39,12 +41,14 @@ pub struct DefaultResilienceOrchestrator {
     retry_executor: RetryExecutor,
     circuit_breaker: Arc<CircuitBreaker>,
     rate_limiter: Arc<RateLimiter>,
+    max_backoff: Duration,
 }
 
 impl DefaultResilienceOrchestrator {
     /// Create a new resilience orchestrator with the given configuration
     pub fn new(config: ResilienceConfig) -> Self {
         Self {
+            max_backoff: config.retry.max_backoff,
             retry_executor: RetryExecutor::new(config.retry),
             circuit_breaker: Arc::new(CircuitBreaker::new(config.circuit_breaker)),
             rate_limiter: Arc::new(RateLimiter::new(config.rate_limit)),
@@ -85,6 +89,50 @@ impl DefaultResilienceOrchestrator {
     pub fn rate_limiter(&self) -> &RateLimiter {
         &self.rate_limiter
     }
+
+    /// Get a shared handle to the rate limiter, e.g. for
+    /// [`ReqwestTransport::with_rate_limiter`](crate::transport::ReqwestTransport::with_rate_limiter)
+    pub fn shared_rate_limiter(&self) -> Arc<RateLimiter> {
+        Arc::clone(&self.rate_limiter)
+    }
+
+    /// Execute an HTTP operation, honoring Anthropic's rate limit headers
+    ///
+    /// Every response's `anthropic-ratelimit-*` headers are fed to the rate
+    /// limiter, so once a limit is exhausted later requests wait for its reset
+    /// instead of failing. A 429 response is retried after the server-provided
+    /// `retry-after` or reset time, capped at the configured maximum backoff.
+    pub async fn execute_http<F, Fut, B>(
+        &self,
+        operation: &str,
+        f: F,
+    ) -> Result<Response<B>, AnthropicError>
+    where
+        F: Fn() -> Fut + Send + Sync,
+        Fut: Future<Output = Result<Response<B>, AnthropicError>> + Send,
+        B: Send,
+    {
+        let rate_limiter = &self.rate_limiter;
+        self.execute(operation, || async {
+            let response = f().await?;
+            let headers = RateLimitHeaders::from_headers(response.headers());
+
+            if response.status() == StatusCode::TOO_MANY_REQUESTS {
+                return Err(headers.to_error("Rate limit exceeded"));
+            }
+
+            rate_limiter.update_from_headers(&headers);
+            Ok(response)
+        })
+        .await
+    }
+
+    /// Wait out an exhausted server-side rate limit before sending
+    async fn wait_for_rate_limit_reset(&self) {
+        if let Some(delay) = self.rate_limiter.blocked_for() {
+            tokio::time::sleep(delay.min(self.max_backoff)).await;
+        }
+    }
 }
 
 #[async_trait]
@@ -111,6 +159,7 @@ impl ResilienceOrchestrator for DefaultResilienceOrchestrator {
         let result = self
             .retry_executor
             .execute(operation, || async {
+                self.wait_for_rate_limit_reset().await;
                 let result = f().await;
                 match &result {
                     Ok(_) => circuit_breaker.record_success(),
@@ -130,6 +179,7 @@ pub struct ResilienceOrchestratorBuilder {
     retry_config: RetryConfig,
     circuit_breaker_config: CircuitBreakerConfig,
     rate_limit_config: RateLimitConfig,
+    retry_hook: Option<Arc<dyn RetryHook>>,
 }
 
 impl ResilienceOrchestratorBuilder {
@@ -139,6 +189,7 @@ impl ResilienceOrchestratorBuilder {
             retry_config: RetryConfig::default(),
             circuit_breaker_config: CircuitBreakerConfig::default(),
             rate_limit_config: RateLimitConfig::default(),
+            retry_hook: None,
         }
     }
 
@@ -160,13 +211,23 @@ impl ResilienceOrchestratorBuilder {
         self
     }
 
+    /// Set a hook invoked before each retry
+    pub fn retry_hook(mut self, hook: Arc<dyn RetryHook>) -> Self {
+        self.retry_hook = Some(hook);
+        self
+    }
+
     /// Build the resilience orchestrator
     pub fn build(self) -> DefaultResilienceOrchestrator {
-        DefaultResilienceOrchestrator::new(ResilienceConfig {
+        let mut orchestrator = DefaultResilienceOrchestrator::new(ResilienceConfig {
             retry: self.retry_config,
             circuit_breaker: self.circuit_breaker_config,
             rate_limit: self.rate_limit_config,
-        })
+        });
+        if let Some(hook) = self.retry_hook {
+            orchestrator.retry_executor = orchestrator.retry_executor.with_hook(hook);
+        }
+        orchestrator
     }
 }
 
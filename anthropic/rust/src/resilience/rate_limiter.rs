@@ -30,6 +30,8 @@ pub struct RateLimiter {
     concurrent_semaphore: Semaphore,
     request_bucket: Mutex<Option<TokenBucket>>,
     token_bucket: Mutex<Option<TokenBucket>>,
+    /// Set when the server reports an exhausted limit, until its reset time
+    blocked_until: Mutex<Option<Instant>>,
 }
 
 impl RateLimiter {
@@ -47,6 +49,7 @@ impl RateLimiter {
             concurrent_semaphore: Semaphore::new(config.max_concurrent_requests),
             request_bucket: Mutex::new(request_bucket),
             token_bucket: Mutex::new(token_bucket),
+            blocked_until: Mutex::new(None),
             config,
         }
     }
@@ -84,12 +87,32 @@ impl RateLimiter {
         }
     }
 
+    /// Time left until the server-reported rate limit resets, if exhausted
+    pub fn blocked_for(&self) -> Option<Duration> {
+        let mut blocked_until = self.blocked_until.lock().unwrap();
+        match *blocked_until {
+            Some(until) if until > Instant::now() => Some(until - Instant::now()),
+            Some(_) => {
+                *blocked_until = None;
+                None
+            }
+            None => None,
+        }
+    }
+
     /// Update rate limits based on API response headers
     pub fn update_from_headers(&self, headers: &RateLimitHeaders) {
         if !self.config.auto_adjust {
             return;
         }
 
+        // Hold off further requests until the exhausted limit resets
+        if headers.is_exhausted() {
+            if let Some(delay) = headers.retry_delay(Utc::now()) {
+                *self.blocked_until.lock().unwrap() = Some(Instant::now() + delay);
+            }
+        }
+
         // Log rate limit information for monitoring
         if let (Some(remaining), Some(limit)) = (headers.requests_remaining, headers.requests_limit)
         {
@@ -178,6 +201,7 @@ pub struct RateLimitHeaders {
     pub tokens_limit: Option<u32>,
     pub tokens_remaining: Option<u32>,
     pub tokens_reset: Option<DateTime<Utc>>,
+    pub retry_after: Option<Duration>,
     pub request_id: Option<String>,
 }
 
@@ -211,12 +235,50 @@ impl RateLimitHeaders {
                 .and_then(|v| v.to_str().ok())
                 .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
                 .map(|dt| dt.with_timezone(&Utc)),
+            retry_after: headers
+                .get("retry-after")
+                .and_then(|v| v.to_str().ok())
+                .and_then(|s| s.trim().parse::<f64>().ok())
+                .and_then(|secs| Duration::try_from_secs_f64(secs).ok()),
             request_id: headers
                 .get("request-id")
                 .and_then(|v| v.to_str().ok())
                 .map(String::from),
         }
     }
+
+    /// Returns true if the request or token limit has no capacity left
+    pub fn is_exhausted(&self) -> bool {
+        self.requests_remaining == Some(0) || self.tokens_remaining == Some(0)
+    }
+
+    /// How long the server asks us to wait before the next request
+    ///
+    /// `retry-after` wins when present; otherwise the latest reset time of
+    /// any exhausted limit is used. Returns `None` if neither applies.
+    pub fn retry_delay(&self, now: DateTime<Utc>) -> Option<Duration> {
+        if self.retry_after.is_some() {
+            return self.retry_after;
+        }
+
+        [
+            (self.requests_remaining, self.requests_reset),
+            (self.tokens_remaining, self.tokens_reset),
+        ]
+        .into_iter()
+        .filter(|(remaining, _)| *remaining == Some(0))
+        .filter_map(|(_, reset)| reset)
+        .max()
+        .map(|reset| (reset - now).to_std().unwrap_or(Duration::ZERO))
+    }
+
+    /// Build the error for a 429 response carrying these headers
+    pub fn to_error(&self, message: impl Into<String>) -> AnthropicError {
+        AnthropicError::RateLimit {
+            message: message.into(),
+            retry_after: self.retry_delay(Utc::now()),
+        }
+    }
 }
 
 #[cfg(test)]
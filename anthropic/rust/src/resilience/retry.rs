@@ -105,11 +105,18 @@ impl RetryExecutor {
     }
 
     /// Calculate the backoff delay for a given attempt
+    ///
+    /// A server-provided delay replaces exponential backoff, capped at
+    /// `max_backoff`.
     fn calculate_backoff(
         &self,
         attempt: u32,
         server_retry_after: Option<Duration>,
     ) -> Duration {
+        if let Some(server_delay) = server_retry_after {
+            return server_delay.min(self.config.max_backoff);
+        }
+
         let base_delay = self.config.initial_backoff.as_millis() as f64
             * self.config.backoff_multiplier.powi((attempt - 1) as i32);
 
@@ -118,13 +125,7 @@ impl RetryExecutor {
         let jitter = rand::random::<f64>() * jitter_range * 2.0 - jitter_range;
         let delay_ms = (base_delay + jitter).min(self.config.max_backoff.as_millis() as f64);
 
-        let calculated = Duration::from_millis(delay_ms.max(100.0) as u64);
-
-        // Use server's retry-after if longer
-        match server_retry_after {
-            Some(server_delay) if server_delay > calculated => server_delay,
-            _ => calculated,
-        }
+        Duration::from_millis(delay_ms.max(100.0) as u64)
     }
 }
 
@@ -514,3 +514,173 @@ async fn test_passthrough_orchestrator() {
     assert!(result.is_err());
     assert_eq!(attempt_count.load(Ordering::SeqCst), 1); // No retries
 }
+
+// ============================================================================
+// Rate limit headers on 429 responses
+// ============================================================================
+
+/// Records the delay chosen for each retry, then retries immediately
+#[derive(Default)]
+struct DelayRecorder {
+    delays: std::sync::Mutex<Vec<Duration>>,
+}
+
+#[async_trait::async_trait]
+impl RetryHook for DelayRecorder {
+    async fn on_retry(&self, context: RetryContext) -> RetryDecision {
+        self.delays.lock().unwrap().push(context.delay);
+        RetryDecision::Retry(Duration::ZERO)
+    }
+}
+
+fn http_response(status: u16, headers: &[(&str, String)]) -> http::Response<()> {
+    let mut builder = http::Response::builder().status(status);
+    for (name, value) in headers {
+        builder = builder.header(*name, value.as_str());
+    }
+    builder.body(()).unwrap()
+}
+
+fn reset_in(secs: i64) -> String {
+    (chrono::Utc::now() + chrono::Duration::seconds(secs)).to_rfc3339()
+}
+
+/// Send one 429 with the given headers followed by a 200, returning the
+/// delay the orchestrator chose before retrying
+async fn delay_after_429(headers: Vec<(&'static str, String)>) -> Duration {
+    let recorder = Arc::new(DelayRecorder::default());
+    let orchestrator = DefaultResilienceOrchestrator::builder()
+        .retry_config(RetryConfig {
+            max_retries: 1,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            backoff_multiplier: 2.0,
+            jitter: 0.0,
+        })
+        .retry_hook(recorder.clone())
+        .build();
+
+    let attempt = AtomicU32::new(0);
+    let result = orchestrator
+        .execute_http("rate_limited", || {
+            let first = attempt.fetch_add(1, Ordering::SeqCst) == 0;
+            let headers = headers.clone();
+            async move {
+                if first {
+                    Ok(http_response(429, &headers))
+                } else {
+                    Ok(http_response(200, &[]))
+                }
+            }
+        })
+        .await;
+
+    assert_eq!(result.unwrap().status(), 200);
+    let delays = recorder.delays.lock().unwrap();
+    assert_eq!(delays.len(), 1);
+    delays[0]
+}
+
+fn assert_close(actual: Duration, expected_secs: u64) {
+    let expected = Duration::from_secs(expected_secs);
+    assert!(
+        actual <= expected && actual + Duration::from_secs(2) >= expected,
+        "expected ~{:?}, got {:?}",
+        expected,
+        actual
+    );
+}
+
+#[tokio::test]
+async fn test_429_uses_retry_after_header() {
+    let delay = delay_after_429(vec![("retry-after", "7".to_string())]).await;
+    assert_eq!(delay, Duration::from_secs(7));
+}
+
+#[tokio::test]
+async fn test_429_retry_after_wins_over_reset() {
+    let delay = delay_after_429(vec![
+        ("retry-after", "3".to_string()),
+        ("anthropic-ratelimit-tokens-remaining", "0".to_string()),
+        ("anthropic-ratelimit-tokens-reset", reset_in(20)),
+    ])
+    .await;
+    assert_eq!(delay, Duration::from_secs(3));
+}
+
+#[tokio::test]
+async fn test_429_uses_tokens_reset_when_exhausted() {
+    let delay = delay_after_429(vec![
+        ("anthropic-ratelimit-tokens-remaining", "0".to_string()),
+        ("anthropic-ratelimit-tokens-reset", reset_in(20)),
+    ])
+    .await;
+    assert_close(delay, 20);
+}
+
+#[tokio::test]
+async fn test_429_uses_latest_reset_of_exhausted_limits() {
+    let delay = delay_after_429(vec![
+        ("anthropic-ratelimit-requests-remaining", "0".to_string()),
+        ("anthropic-ratelimit-requests-reset", reset_in(5)),
+        ("anthropic-ratelimit-tokens-remaining", "0".to_string()),
+        ("anthropic-ratelimit-tokens-reset", reset_in(15)),
+    ])
+    .await;
+    assert_close(delay, 15);
+}
+
+#[tokio::test]
+async fn test_429_ignores_reset_of_limits_with_capacity() {
+    let delay = delay_after_429(vec![
+        ("anthropic-ratelimit-tokens-remaining", "5000".to_string()),
+        ("anthropic-ratelimit-tokens-reset", reset_in(20)),
+    ])
+    .await;
+    assert_eq!(delay, Duration::from_millis(500));
+}
+
+#[tokio::test]
+async fn test_429_server_delay_capped_at_max_backoff() {
+    let delay = delay_after_429(vec![("retry-after", "120".to_string())]).await;
+    assert_eq!(delay, Duration::from_secs(30));
+}
+
+#[tokio::test]
+async fn test_429_without_headers_uses_exponential_backoff() {
+    let delay = delay_after_429(Vec::new()).await;
+    assert_eq!(delay, Duration::from_millis(500));
+}
+
+#[tokio::test]
+async fn test_exhausted_tokens_delay_next_request() {
+    let orchestrator = DefaultResilienceOrchestrator::new(ResilienceConfig::default());
+
+    // Succeeds, but reports no tokens left until shortly after
+    let reset = (chrono::Utc::now() + chrono::Duration::milliseconds(300)).to_rfc3339();
+    orchestrator
+        .execute_http("exhausting", || {
+            let reset = reset.clone();
+            async move {
+                Ok(http_response(
+                    200,
+                    &[
+                        ("anthropic-ratelimit-tokens-remaining", "0".to_string()),
+                        ("anthropic-ratelimit-tokens-reset", reset),
+                    ],
+                ))
+            }
+        })
+        .await
+        .unwrap();
+    assert!(orchestrator.rate_limiter().blocked_for().is_some());
+
+    let start = std::time::Instant::now();
+    orchestrator
+        .execute_http("after_exhaustion", || async { Ok(http_response(200, &[])) })
+        .await
+        .unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(200));
+    assert!(orchestrator.rate_limiter().blocked_for().is_none());
+}
@@ -750,3 +750,60 @@ mod error_handling_tests {
         ));
     }
 }
+
+mod rate_limit_tests {
+    use super::*;
+    use crate::auth::BearerAuthManager;
+    use crate::resilience::{DefaultResilienceOrchestrator, ResilienceConfig};
+    use crate::transport::ReqwestTransport;
+    use secrecy::SecretString;
+    use std::time::Duration;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_success_headers_reach_rate_limiter() {
+        let server = MockServer::start().await;
+        let reset = (chrono::Utc::now() + chrono::Duration::seconds(30)).to_rfc3339();
+        let org = Organization {
+            id: "org-123".to_string(),
+            name: "Test Org".to_string(),
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: "2024-01-01T00:00:00Z".to_string(),
+        };
+        Mock::given(method("GET"))
+            .and(path("/v1/organizations/me"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("anthropic-ratelimit-tokens-remaining", "0")
+                    .insert_header("anthropic-ratelimit-tokens-reset", reset.as_str())
+                    .set_body_json(&org),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let resilience = Arc::new(DefaultResilienceOrchestrator::new(ResilienceConfig::default()));
+        let transport = Arc::new(
+            ReqwestTransport::new(Duration::from_secs(5))
+                .unwrap()
+                .with_rate_limiter(resilience.shared_rate_limiter()),
+        );
+        let auth_manager = Arc::new(BearerAuthManager::new(
+            SecretString::new("sk-ant-test123456789012345".to_string()),
+            "2023-06-01".to_string(),
+            Vec::new(),
+        ));
+        let service = OrganizationsServiceImpl::new(
+            transport,
+            auth_manager,
+            resilience.clone(),
+            Url::parse(&server.uri()).unwrap(),
+        );
+
+        let result = service.get().await.unwrap();
+
+        assert_eq!(result, org);
+        assert!(resilience.rate_limiter().blocked_for().is_some());
+    }
+}
@@ -1,16 +1,24 @@
 //! Batches service implementation
 
 use super::types::{
-    BatchListParams, BatchListResponse, BatchResultsResponse, CreateBatchRequest, MessageBatch,
+    BatchListParams, BatchListResponse, BatchOutcome, BatchResultsResponse, CreateBatchRequest,
+    MessageBatch,
 };
 use crate::auth::AuthManager;
 use crate::error::{AnthropicError, ApiErrorResponse, ValidationError};
 use crate::transport::HttpTransport;
 use async_trait::async_trait;
+use futures::stream::{Stream, StreamExt};
 use http::{HeaderMap, Method};
+use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Duration;
 use url::Url;
 
+/// Stream of per-request batch outcomes keyed by `custom_id`
+pub type BatchOutcomeStream =
+    Pin<Box<dyn Stream<Item = Result<(String, BatchOutcome), AnthropicError>> + Send>>;
+
 /// Batches service trait for testability
 #[async_trait]
 pub trait BatchesService: Send + Sync {
@@ -40,6 +48,52 @@ pub trait BatchesService: Send + Sync {
         &self,
         batch_id: &str,
     ) -> Result<super::stream::BatchResultsStream, AnthropicError>;
+
+    /// Poll a batch until processing stops, returning the final batch
+    ///
+    /// A batch is complete once its status is `ended` or `canceled`; a
+    /// `canceling` batch keeps being polled until it settles. Returns
+    /// `AnthropicError::Timeout` if the batch is still processing after `timeout`.
+    async fn poll_until_complete(
+        &self,
+        batch_id: &str,
+        interval: Duration,
+        timeout: Duration,
+    ) -> Result<MessageBatch, AnthropicError> {
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        loop {
+            let batch = self.retrieve(batch_id).await?;
+            if batch.processing_status.is_terminal() {
+                return Ok(batch);
+            }
+
+            if tokio::time::Instant::now() + interval > deadline {
+                return Err(AnthropicError::Timeout);
+            }
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    /// Stream the outcome of every request in a finished batch
+    ///
+    /// Canceled batches yield the partial results that were processed before
+    /// cancellation, with the remaining requests reported as
+    /// [`BatchOutcome::Canceled`].
+    async fn stream_results(&self, batch_id: &str) -> Result<BatchOutcomeStream, AnthropicError> {
+        let batch = self.retrieve(batch_id).await?;
+        if !batch.processing_status.is_terminal() {
+            return Err(AnthropicError::Validation(ValidationError::Invalid {
+                field: "batch_id".to_string(),
+                reason: format!("Batch {} is still processing; results are not ready", batch_id),
+            }));
+        }
+
+        let results = self.results_stream(batch_id).await?;
+        Ok(Box::pin(
+            results.map(|result| result.and_then(|result| result.into_outcome())),
+        ))
+    }
 }
 
 /// Implementation of the Batches service
@@ -32,7 +32,7 @@ pub struct BatchResultsStream {
     /// The underlying byte stream
     inner: Pin<Box<dyn Stream<Item = Result<Bytes, AnthropicError>> + Send>>,
     /// Buffer for incomplete lines
-    buffer: String,
+    buffer: Vec<u8>,
     /// Whether we've reached the end of the stream
     finished: bool,
 }
@@ -42,7 +42,7 @@ impl BatchResultsStream {
     pub fn new(inner: Pin<Box<dyn Stream<Item = Result<Bytes, AnthropicError>> + Send>>) -> Self {
         Self {
             inner,
-            buffer: String::new(),
+            buffer: Vec::new(),
             finished: false,
         }
     }
@@ -55,42 +55,38 @@ impl BatchResultsStream {
                 .into_iter()
                 .map(|line| Ok(Bytes::from(format!("{}\n", line)))),
         );
-        Self {
-            inner: Box::pin(stream),
-            buffer: String::new(),
-            finished: false,
-        }
+        Self::new(Box::pin(stream))
     }
 
-    /// Try to parse a complete line from the buffer
+    /// Try to parse the next complete, non-empty line from the buffer
     fn try_parse_line(&mut self) -> Option<Result<BatchResult, AnthropicError>> {
-        if let Some(newline_pos) = self.buffer.find('\n') {
-            let line = self.buffer[..newline_pos].trim().to_string();
-            self.buffer = self.buffer[newline_pos + 1..].to_string();
-
-            if line.is_empty() {
-                return None;
-            }
+        while let Some(newline_pos) = self.buffer.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=newline_pos).collect();
 
-            match serde_json::from_str::<BatchResult>(&line) {
-                Ok(result) => Some(Ok(result)),
-                Err(e) => Some(Err(AnthropicError::Serialization(e.to_string()))),
+            if let Some(result) = Self::parse_line(&line) {
+                return Some(result);
             }
-        } else {
-            None
         }
+
+        None
     }
 
     /// Process any remaining data in the buffer after stream ends
     fn flush_buffer(&mut self) -> Option<Result<BatchResult, AnthropicError>> {
-        let remaining = self.buffer.trim().to_string();
-        self.buffer.clear();
+        let remaining = std::mem::take(&mut self.buffer);
+        Self::parse_line(&remaining)
+    }
+
+    /// Parse a single JSONL line, skipping blank lines
+    fn parse_line(line: &[u8]) -> Option<Result<BatchResult, AnthropicError>> {
+        let line = String::from_utf8_lossy(line);
+        let line = line.trim();
 
-        if remaining.is_empty() {
+        if line.is_empty() {
             return None;
         }
 
-        match serde_json::from_str::<BatchResult>(&remaining) {
+        match serde_json::from_str::<BatchResult>(line) {
             Ok(result) => Some(Ok(result)),
             Err(e) => Some(Err(AnthropicError::Serialization(e.to_string()))),
         }
@@ -115,36 +111,24 @@ impl Stream for BatchResultsStream {
     type Item = Result<BatchResult, AnthropicError>;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        // First, try to parse any complete lines in the buffer
-        if let Some(result) = self.try_parse_line() {
-            return Poll::Ready(Some(result));
-        }
-
-        // If we've finished reading from the inner stream, flush remaining buffer
-        if self.finished {
-            return Poll::Ready(self.flush_buffer());
-        }
+        loop {
+            // First, try to parse any complete lines in the buffer
+            if let Some(result) = self.try_parse_line() {
+                return Poll::Ready(Some(result));
+            }
 
-        // Try to read more data from the inner stream
-        match Pin::new(&mut self.inner).poll_next(cx) {
-            Poll::Ready(Some(Ok(bytes))) => {
-                // Add to buffer
-                if let Ok(text) = std::str::from_utf8(&bytes) {
-                    self.buffer.push_str(text);
-                }
-                // Try to parse a line now
-                Poll::Ready(self.try_parse_line().or_else(|| {
-                    // No complete line yet, need more data
-                    cx.waker().wake_by_ref();
-                    None
-                }))
+            // If we've finished reading from the inner stream, flush remaining buffer
+            if self.finished {
+                return Poll::Ready(self.flush_buffer());
             }
-            Poll::Ready(Some(Err(e))) => Poll::Ready(Some(Err(e))),
-            Poll::Ready(None) => {
-                self.finished = true;
-                Poll::Ready(self.flush_buffer())
+
+            // Read more data; lines may span several chunks
+            match Pin::new(&mut self.inner).poll_next(cx) {
+                Poll::Ready(Some(Ok(bytes))) => self.buffer.extend_from_slice(&bytes),
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(None) => self.finished = true,
+                Poll::Pending => return Poll::Pending,
             }
-            Poll::Pending => Poll::Pending,
         }
     }
 }
@@ -195,6 +179,24 @@ mod tests {
         assert_eq!(results.len(), 0);
     }
 
+    #[tokio::test]
+    async fn test_batch_results_stream_lines_split_across_chunks() {
+        let jsonl = "{\"custom_id\":\"req1\",\"type\":\"succeeded\"}\n\n{\"custom_id\":\"caf\u{e9}\",\"type\":\"expired\"}";
+        let chunks: Vec<Result<Bytes, AnthropicError>> = jsonl
+            .as_bytes()
+            .chunks(5)
+            .map(|chunk| Ok(Bytes::copy_from_slice(chunk)))
+            .collect();
+
+        let stream = BatchResultsStream::new(Box::pin(futures::stream::iter(chunks)));
+        let results = stream.collect().await.unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].custom_id, "req1");
+        assert_eq!(results[1].custom_id, "caf\u{e9}");
+        assert_eq!(results[1].result_type, "expired");
+    }
+
     #[tokio::test]
     async fn test_batch_results_stream_invalid_json() {
         let jsonl = "not valid json";
@@ -20,6 +20,7 @@ use url::Url;
 struct MockHttpTransport {
     responses: Arc<Mutex<Vec<Result<HttpResponse, AnthropicError>>>>,
     requests: Arc<Mutex<Vec<(Method, String, HeaderMap, Option<Vec<u8>>)>>>,
+    stream_body: Arc<Mutex<Option<String>>>,
 }
 
 impl MockHttpTransport {
@@ -27,6 +28,7 @@ impl MockHttpTransport {
         Self {
             responses: Arc::new(Mutex::new(Vec::new())),
             requests: Arc::new(Mutex::new(Vec::new())),
+            stream_body: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -35,6 +37,11 @@ impl MockHttpTransport {
         self
     }
 
+    fn with_stream_body(self, body: &str) -> Self {
+        *self.stream_body.lock().unwrap() = Some(body.to_string());
+        self
+    }
+
     fn get_requests(&self) -> Vec<(Method, String, HeaderMap, Option<Vec<u8>>)> {
         self.requests.lock().unwrap().clone()
     }
@@ -67,17 +74,33 @@ impl HttpTransport for MockHttpTransport {
 
     async fn execute_stream(
         &self,
-        _method: Method,
-        _url: String,
-        _headers: HeaderMap,
-        _body: Option<Vec<u8>>,
+        method: Method,
+        url: String,
+        headers: HeaderMap,
+        body: Option<Vec<u8>>,
     ) -> Result<
         Box<dyn futures::Stream<Item = Result<bytes::Bytes, AnthropicError>> + Send + Unpin>,
         AnthropicError,
     > {
-        Err(AnthropicError::Internal(
-            "Streaming not supported in this mock".to_string(),
-        ))
+        self.requests
+            .lock()
+            .unwrap()
+            .push((method, url, headers, body));
+
+        match self.stream_body.lock().unwrap().take() {
+            // Split into small chunks so lines straddle chunk boundaries
+            Some(body) => {
+                let chunks: Vec<Result<bytes::Bytes, AnthropicError>> = body
+                    .into_bytes()
+                    .chunks(16)
+                    .map(|chunk| Ok(bytes::Bytes::copy_from_slice(chunk)))
+                    .collect();
+                Ok(Box::new(futures::stream::iter(chunks)))
+            }
+            None => Err(AnthropicError::Internal(
+                "Streaming not supported in this mock".to_string(),
+            )),
+        }
     }
 }
 
@@ -585,6 +608,206 @@ async fn test_get_batch_results_not_ready() {
     }
 }
 
+// ============================================================================
+// Tests: Polling and Result Outcomes
+// ============================================================================
+
+fn create_batch_with_status(status: BatchStatus) -> Result<HttpResponse, AnthropicError> {
+    let mut batch = create_test_batch();
+    batch.processing_status = status;
+    create_success_response(&serde_json::to_string(&batch).unwrap())
+}
+
+#[tokio::test]
+async fn test_poll_until_complete_waits_for_ended() {
+    // Responses are popped from the end, so push them in reverse order
+    let transport = Arc::new(
+        MockHttpTransport::new()
+            .with_response(create_batch_with_status(BatchStatus::Ended))
+            .with_response(create_batch_with_status(BatchStatus::InProgress))
+            .with_response(create_batch_with_status(BatchStatus::InProgress)),
+    );
+
+    let service = create_test_service(transport.clone());
+
+    let batch = service
+        .poll_until_complete(
+            "batch_123",
+            std::time::Duration::from_millis(1),
+            std::time::Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(batch.processing_status, BatchStatus::Ended);
+    let requests = transport.get_requests();
+    assert_eq!(requests.len(), 3);
+    assert!(requests
+        .iter()
+        .all(|(method, url, _, _)| method == Method::GET
+            && url.ends_with("/v1/messages/batches/batch_123")));
+}
+
+#[tokio::test]
+async fn test_poll_until_complete_canceling_settles_to_canceled() {
+    let transport = Arc::new(
+        MockHttpTransport::new()
+            .with_response(create_batch_with_status(BatchStatus::Canceled))
+            .with_response(create_batch_with_status(BatchStatus::Canceling)),
+    );
+
+    let service = create_test_service(transport.clone());
+
+    let batch = service
+        .poll_until_complete(
+            "batch_123",
+            std::time::Duration::from_millis(1),
+            std::time::Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(batch.processing_status, BatchStatus::Canceled);
+    assert_eq!(transport.get_requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_poll_until_complete_timeout() {
+    let transport = Arc::new(
+        MockHttpTransport::new()
+            .with_response(create_batch_with_status(BatchStatus::InProgress))
+            .with_response(create_batch_with_status(BatchStatus::InProgress)),
+    );
+
+    let service = create_test_service(transport.clone());
+
+    let result = service
+        .poll_until_complete(
+            "batch_123",
+            std::time::Duration::from_millis(20),
+            std::time::Duration::from_millis(30),
+        )
+        .await;
+
+    assert!(matches!(result, Err(AnthropicError::Timeout)));
+    assert_eq!(transport.get_requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_stream_results_mixed_outcomes() {
+    use futures::StreamExt;
+
+    let jsonl = r#"{"custom_id":"req1","type":"succeeded","message":{"id":"msg_1","type":"message","role":"assistant","content":[{"type":"text","text":"Hello!"}],"model":"claude-3-5-sonnet-20241022","stop_reason":"end_turn","usage":{"input_tokens":10,"output_tokens":5}}}
+{"custom_id":"req2","type":"errored","error":{"type":"invalid_request_error","message":"Invalid request"}}
+
+{"custom_id":"req3","type":"expired"}
+"#;
+
+    let transport = Arc::new(
+        MockHttpTransport::new()
+            .with_response(create_batch_with_status(BatchStatus::Ended))
+            .with_stream_body(jsonl),
+    );
+
+    let service = create_test_service(transport.clone());
+
+    let outcomes: Vec<_> = service
+        .stream_results("batch_123")
+        .await
+        .unwrap()
+        .map(|outcome| outcome.unwrap())
+        .collect()
+        .await;
+
+    assert_eq!(outcomes.len(), 3);
+
+    let (custom_id, outcome) = &outcomes[0];
+    assert_eq!(custom_id, "req1");
+    match outcome {
+        BatchOutcome::Succeeded(message) => assert_eq!(message.id, "msg_1"),
+        other => panic!("Expected succeeded outcome, got {:?}", other),
+    }
+
+    assert_eq!(outcomes[1].0, "req2");
+    match &outcomes[1].1 {
+        BatchOutcome::Errored(error) => {
+            assert_eq!(error.error_type, "invalid_request_error");
+            assert_eq!(error.message, "Invalid request");
+        }
+        other => panic!("Expected errored outcome, got {:?}", other),
+    }
+
+    assert_eq!(outcomes[2], ("req3".to_string(), BatchOutcome::Expired));
+
+    let requests = transport.get_requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[1].1.ends_with("/v1/messages/batches/batch_123/results"));
+}
+
+#[tokio::test]
+async fn test_stream_results_canceled_batch_returns_partial_results() {
+    use futures::StreamExt;
+
+    let jsonl = r#"{"custom_id":"req1","type":"errored","error":{"type":"overloaded_error","message":"Overloaded"}}
+{"custom_id":"req2","type":"canceled"}
+{"custom_id":"req3","type":"canceled"}"#;
+
+    let transport = Arc::new(
+        MockHttpTransport::new()
+            .with_response(create_batch_with_status(BatchStatus::Canceled))
+            .with_stream_body(jsonl),
+    );
+
+    let service = create_test_service(transport);
+
+    let outcomes: Vec<_> = service
+        .stream_results("batch_123")
+        .await
+        .unwrap()
+        .map(|outcome| outcome.unwrap())
+        .collect()
+        .await;
+
+    assert_eq!(outcomes.len(), 3);
+    assert!(matches!(outcomes[0].1, BatchOutcome::Errored(_)));
+    assert_eq!(outcomes[1], ("req2".to_string(), BatchOutcome::Canceled));
+    assert_eq!(outcomes[2], ("req3".to_string(), BatchOutcome::Canceled));
+}
+
+#[tokio::test]
+async fn test_stream_results_in_progress_batch() {
+    let transport = Arc::new(
+        MockHttpTransport::new().with_response(create_batch_with_status(BatchStatus::InProgress)),
+    );
+
+    let service = create_test_service(transport.clone());
+
+    let result = service.stream_results("batch_123").await;
+
+    assert!(matches!(result, Err(AnthropicError::Validation(_))));
+    assert_eq!(transport.get_requests().len(), 1);
+}
+
+#[tokio::test]
+async fn test_stream_results_unknown_result_type() {
+    use futures::StreamExt;
+
+    let transport = Arc::new(
+        MockHttpTransport::new()
+            .with_response(create_batch_with_status(BatchStatus::Ended))
+            .with_stream_body(r#"{"custom_id":"req1","type":"mystery"}"#),
+    );
+
+    let service = create_test_service(transport);
+
+    let mut outcomes = service.stream_results("batch_123").await.unwrap();
+
+    assert!(matches!(
+        outcomes.next().await,
+        Some(Err(AnthropicError::Serialization(_)))
+    ));
+}
+
 // ============================================================================
 // Tests: Error Handling
 // ============================================================================
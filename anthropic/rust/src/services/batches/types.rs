@@ -1,5 +1,6 @@
 //! Type definitions for the Batches API
 
+use crate::error::AnthropicError;
 use crate::services::messages::CreateMessageRequest;
 use serde::{Deserialize, Serialize};
 
@@ -17,6 +18,13 @@ pub enum BatchStatus {
     Canceled,
 }
 
+impl BatchStatus {
+    /// Whether the batch has stopped processing and its results can be downloaded
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, BatchStatus::Ended | BatchStatus::Canceled)
+    }
+}
+
 /// Processing status with request counts
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BatchProcessingStatus {
@@ -199,7 +207,7 @@ pub struct BatchResult {
 }
 
 /// Error information for a failed batch request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BatchError {
     /// Error type
     #[serde(rename = "type")]
@@ -208,6 +216,50 @@ pub struct BatchError {
     pub message: String,
 }
 
+/// Outcome of a single request within a batch
+#[derive(Debug, Clone, PartialEq)]
+pub enum BatchOutcome {
+    /// The request completed and produced a message
+    Succeeded(Box<crate::services::messages::Message>),
+    /// The request failed
+    Errored(BatchError),
+    /// The batch expired before the request was processed
+    Expired,
+    /// The batch was canceled before the request was processed
+    Canceled,
+}
+
+impl BatchResult {
+    /// Convert this result into its custom_id and typed outcome
+    pub fn into_outcome(self) -> Result<(String, BatchOutcome), AnthropicError> {
+        let outcome = match self.result_type.as_str() {
+            "succeeded" => match self.message {
+                Some(message) => BatchOutcome::Succeeded(Box::new(message)),
+                None => {
+                    return Err(AnthropicError::Serialization(format!(
+                        "Succeeded batch result '{}' has no message",
+                        self.custom_id
+                    )))
+                }
+            },
+            "errored" => BatchOutcome::Errored(self.error.unwrap_or_else(|| BatchError {
+                error_type: "unknown".to_string(),
+                message: "No error details provided".to_string(),
+            })),
+            "expired" => BatchOutcome::Expired,
+            "canceled" => BatchOutcome::Canceled,
+            other => {
+                return Err(AnthropicError::Serialization(format!(
+                    "Unknown batch result type '{}' for '{}'",
+                    other, self.custom_id
+                )))
+            }
+        };
+
+        Ok((self.custom_id, outcome))
+    }
+}
+
 /// Response from downloading batch results
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BatchResultsResponse {
@@ -0,0 +1,322 @@
+//! Computer Use Agent
+//!
+//! This module drives the computer use tool loop: it sends a message,
+//! executes every `tool_use` block the model returns, feeds the results
+//! back, and repeats until the model stops asking for tools.
+
+use std::sync::Arc;
+
+use crate::config::BetaFeature;
+use crate::error::AnthropicError;
+use crate::services::messages::{
+    ContentBlock, CreateMessageRequest, ImageSource, Message, MessageParam, MessagesService,
+    StopReason, ToolResultContent,
+};
+
+use super::types::{ComputerToolResult, ComputerToolResultContent};
+
+/// Default cap on model turns for a single agent run
+pub const DEFAULT_MAX_COMPUTER_USE_ITERATIONS: u32 = 25;
+
+/// Result of a completed computer use run
+#[derive(Debug, Clone)]
+pub struct ComputerUseOutcome {
+    /// The last assistant message, which did not request any tools
+    pub final_message: Message,
+    /// Every message exchanged, starting with the request's messages
+    pub transcript: Vec<MessageParam>,
+    /// Number of messages created during the run
+    pub iterations: u32,
+}
+
+/// Agent that runs the computer use tool loop against the Messages API
+///
+/// The request passed to [`ComputerUseAgent::run`] carries the model, prompt
+/// and tool definitions; the agent enables the computer use beta on it.
+pub struct ComputerUseAgent {
+    messages: Arc<dyn MessagesService>,
+    max_iterations: u32,
+}
+
+impl ComputerUseAgent {
+    /// Create a new agent with the default iteration cap
+    pub fn new(messages: Arc<dyn MessagesService>) -> Self {
+        Self {
+            messages,
+            max_iterations: DEFAULT_MAX_COMPUTER_USE_ITERATIONS,
+        }
+    }
+
+    /// Set the maximum number of messages created in one run
+    pub fn with_max_iterations(mut self, max_iterations: u32) -> Self {
+        self.max_iterations = max_iterations.max(1);
+        self
+    }
+
+    /// Get the maximum number of messages created in one run
+    pub fn max_iterations(&self) -> u32 {
+        self.max_iterations
+    }
+
+    /// Run the tool loop until the model stops requesting tools
+    ///
+    /// `executor` is called with the id, name and input of every `tool_use`
+    /// block and returns the result to send back, e.g. one built with
+    /// [`create_screenshot_result`](super::create_screenshot_result) or
+    /// [`create_error_result`](super::create_error_result).
+    ///
+    /// # Errors
+    /// Returns `AnthropicError::ToolLoopLimitExceeded` if the model still
+    /// requests tools after `max_iterations` messages, or any error from the
+    /// Messages API.
+    pub async fn run<F>(
+        &self,
+        request: CreateMessageRequest,
+        mut executor: F,
+    ) -> Result<ComputerUseOutcome, AnthropicError>
+    where
+        F: FnMut(&str, &str, &serde_json::Value) -> ComputerToolResult,
+    {
+        let mut request = request.with_beta_features(&[BetaFeature::ComputerUse])?;
+
+        for iteration in 1..=self.max_iterations {
+            let message = self.messages.create(request.clone()).await?;
+            request
+                .messages
+                .push(MessageParam::assistant_blocks(message.content.clone()));
+
+            let wants_tools = message.stop_reason == Some(StopReason::ToolUse)
+                && message
+                    .content
+                    .iter()
+                    .any(|block| matches!(block, ContentBlock::ToolUse { .. }));
+
+            if !wants_tools {
+                return Ok(ComputerUseOutcome {
+                    final_message: message,
+                    transcript: request.messages,
+                    iterations: iteration,
+                });
+            }
+
+            let results = message
+                .content
+                .iter()
+                .filter_map(|block| match block {
+                    ContentBlock::ToolUse { id, name, input } => {
+                        Some(tool_result_block(executor(id, name, input)))
+                    }
+                    _ => None,
+                })
+                .collect();
+            request.messages.push(MessageParam::user_blocks(results));
+        }
+
+        Err(AnthropicError::ToolLoopLimitExceeded {
+            max_iterations: self.max_iterations,
+        })
+    }
+}
+
+/// Convert a computer tool result into a `tool_result` content block
+fn tool_result_block(result: ComputerToolResult) -> ContentBlock {
+    let content = result
+        .content
+        .into_iter()
+        .map(|content| match content {
+            ComputerToolResultContent::Text { text } => ContentBlock::Text {
+                text,
+                cache_control: None,
+            },
+            ComputerToolResultContent::Image { source } => ContentBlock::Image {
+                source: ImageSource::base64(source.media_type, source.data),
+                cache_control: None,
+            },
+        })
+        .collect();
+
+    ContentBlock::ToolResult {
+        tool_use_id: result.tool_use_id,
+        content: ToolResultContent::Blocks(content),
+        is_error: result.is_error,
+        cache_control: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::beta::{create_error_result, create_screenshot_result};
+    use crate::services::messages::{
+        CountTokensRequest, MessageContent, MessageStream, Role, TokenCount, Usage,
+    };
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    /// Messages service that replays canned responses and records requests
+    struct ScriptedMessages {
+        responses: Mutex<Vec<Message>>,
+        requests: Mutex<Vec<CreateMessageRequest>>,
+    }
+
+    impl ScriptedMessages {
+        fn new(mut responses: Vec<Message>) -> Self {
+            responses.reverse();
+            Self {
+                responses: Mutex::new(responses),
+                requests: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl MessagesService for ScriptedMessages {
+        async fn create(&self, request: CreateMessageRequest) -> Result<Message, AnthropicError> {
+            self.requests.lock().unwrap().push(request);
+            self.responses
+                .lock()
+                .unwrap()
+                .pop()
+                .ok_or_else(|| AnthropicError::Internal("no scripted response".to_string()))
+        }
+
+        async fn create_stream(
+            &self,
+            _request: CreateMessageRequest,
+        ) -> Result<MessageStream, AnthropicError> {
+            Err(AnthropicError::Internal("not scripted".into()))
+        }
+
+        async fn count_tokens(
+            &self,
+            _request: CountTokensRequest,
+        ) -> Result<TokenCount, AnthropicError> {
+            Err(AnthropicError::Internal("not scripted".into()))
+        }
+
+        async fn create_within_budget(
+            &self,
+            _request: CreateMessageRequest,
+            _max_input_tokens: u32,
+        ) -> Result<Message, AnthropicError> {
+            Err(AnthropicError::Internal("not scripted".into()))
+        }
+    }
+
+    fn assistant(content: Vec<ContentBlock>, stop_reason: StopReason) -> Message {
+        Message {
+            id: "msg_123".to_string(),
+            message_type: "message".to_string(),
+            role: Role::Assistant,
+            content,
+            model: "claude-3-5-sonnet-20241022".to_string(),
+            stop_reason: Some(stop_reason),
+            stop_sequence: None,
+            usage: Usage {
+                input_tokens: 10,
+                output_tokens: 5,
+                cache_creation_input_tokens: None,
+                cache_read_input_tokens: None,
+            },
+        }
+    }
+
+    fn screenshot_tool_use() -> Message {
+        assistant(
+            vec![ContentBlock::ToolUse {
+                id: "toolu_1".to_string(),
+                name: "computer".to_string(),
+                input: serde_json::json!({"action": "screenshot"}),
+            }],
+            StopReason::ToolUse,
+        )
+    }
+
+    fn request() -> CreateMessageRequest {
+        CreateMessageRequest::new(
+            "claude-3-5-sonnet-20241022",
+            1024,
+            vec![MessageParam::user("Take a screenshot")],
+        )
+    }
+
+    #[tokio::test]
+    async fn test_run_executes_screenshot_then_stops() {
+        let final_text = ContentBlock::Text {
+            text: "The desktop is empty.".to_string(),
+            cache_control: None,
+        };
+        let messages = Arc::new(ScriptedMessages::new(vec![
+            screenshot_tool_use(),
+            assistant(vec![final_text.clone()], StopReason::EndTurn),
+        ]));
+        let agent = ComputerUseAgent::new(messages.clone());
+
+        let mut calls = Vec::new();
+        let outcome = agent
+            .run(request(), |id, name, input| {
+                calls.push((id.to_string(), name.to_string(), input["action"].clone()));
+                create_screenshot_result(id, "iVBORw0KGgo=")
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            calls,
+            vec![(
+                "toolu_1".to_string(),
+                "computer".to_string(),
+                serde_json::json!("screenshot")
+            )]
+        );
+        assert_eq!(outcome.iterations, 2);
+        assert_eq!(outcome.final_message.content, vec![final_text]);
+        assert_eq!(outcome.transcript.len(), 4);
+
+        match &outcome.transcript[2].content {
+            MessageContent::Blocks(blocks) => match &blocks[0] {
+                ContentBlock::ToolResult {
+                    tool_use_id,
+                    content: ToolResultContent::Blocks(content),
+                    is_error,
+                    ..
+                } => {
+                    assert_eq!(tool_use_id, "toolu_1");
+                    assert_eq!(*is_error, None);
+                    assert!(matches!(
+                        &content[0],
+                        ContentBlock::Image { source, .. } if source.media_type == "image/png"
+                    ));
+                }
+                other => panic!("Expected tool result, got {:?}", other),
+            },
+            other => panic!("Expected blocks, got {:?}", other),
+        }
+
+        let requests = messages.requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].beta_features.contains(&BetaFeature::ComputerUse));
+        assert_eq!(requests[1].messages.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_run_stops_at_iteration_cap() {
+        let messages = Arc::new(ScriptedMessages::new(vec![
+            screenshot_tool_use(),
+            screenshot_tool_use(),
+            screenshot_tool_use(),
+        ]));
+        let agent = ComputerUseAgent::new(messages.clone()).with_max_iterations(2);
+
+        let err = agent
+            .run(request(), |id, _, _| create_error_result(id, "display unavailable"))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            AnthropicError::ToolLoopLimitExceeded { max_iterations: 2 }
+        ));
+        assert_eq!(messages.requests.lock().unwrap().len(), 2);
+    }
+}
@@ -35,7 +35,7 @@ pub fn extract_thinking_blocks(content: &[ContentBlock]) -> Vec<String> {
     content
         .iter()
         .filter_map(|block| {
-            if let ContentBlock::Thinking { thinking } = block {
+            if let ContentBlock::Thinking { thinking, .. } = block {
                 Some(thinking.clone())
             } else {
                 None
@@ -105,9 +105,11 @@ mod tests {
             },
             ContentBlock::Thinking {
                 thinking: "Let me think about this...".to_string(),
+                signature: None,
             },
             ContentBlock::Thinking {
                 thinking: "The answer is...".to_string(),
+                signature: None,
             },
         ];
 
@@ -126,6 +128,7 @@ mod tests {
             },
             ContentBlock::Thinking {
                 thinking: "Thinking...".to_string(),
+                signature: None,
             },
         ];
 
@@ -149,6 +152,7 @@ mod tests {
             },
             ContentBlock::Thinking {
                 thinking: "Thinking...".to_string(),
+                signature: None,
             },
             ContentBlock::Text {
                 text: "Second text".to_string(),
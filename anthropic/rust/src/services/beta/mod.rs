@@ -12,6 +12,7 @@ mod pdf_support;
 mod prompt_caching;
 mod token_counting;
 mod computer_use;
+mod computer_use_agent;
 
 #[cfg(test)]
 mod tests;
@@ -23,3 +24,4 @@ pub use pdf_support::*;
 pub use prompt_caching::*;
 pub use token_counting::*;
 pub use computer_use::*;
+pub use computer_use_agent::*;
@@ -3,8 +3,11 @@
 //! This module provides utilities for working with PDF documents,
 //! a beta feature that allows sending PDF files to Claude.
 
+use std::ops::RangeInclusive;
+
 use base64::{engine::general_purpose::STANDARD, Engine as _};
-use crate::services::messages::{ContentBlock, DocumentSource};
+use crate::errors::{AnthropicError, AnthropicResult, ValidationDetail};
+use crate::services::messages::{CacheControl, ContentBlock, DocumentSource, MessageParam};
 
 /// Create a PDF document content block from raw bytes
 ///
@@ -46,6 +49,104 @@ pub fn create_pdf_content_from_base64(base64_data: String) -> ContentBlock {
     }
 }
 
+/// Create a PDF document content block containing only the given pages
+///
+/// The API has no page selection, so the document is trimmed locally before
+/// encoding, which keeps unused pages out of the input token count.
+///
+/// # Arguments
+/// * `pdf_bytes` - The raw PDF file bytes
+/// * `pages` - The 1-based, inclusive range of pages to keep
+///
+/// # Errors
+/// Returns a validation error if the bytes are not a PDF, cannot be parsed,
+/// or the range is empty or extends past the last page.
+pub fn create_pdf_content_with_pages(
+    pdf_bytes: &[u8],
+    pages: RangeInclusive<u32>,
+) -> AnthropicResult<ContentBlock> {
+    if !validate_pdf_bytes(pdf_bytes) {
+        return Err(pdf_validation_error("document", "Data is not a PDF document"));
+    }
+
+    let (first, last) = (*pages.start(), *pages.end());
+    if first == 0 || first > last {
+        return Err(pdf_validation_error(
+            "pages",
+            format!("Invalid page range {}..={}; pages are numbered from 1", first, last),
+        ));
+    }
+
+    let mut document = lopdf::Document::load_mem(pdf_bytes).map_err(|e| {
+        pdf_validation_error("document", format!("Failed to parse PDF document: {}", e))
+    })?;
+
+    let page_count = document.get_pages().len() as u32;
+    if last > page_count {
+        return Err(pdf_validation_error(
+            "pages",
+            format!(
+                "Page range {}..={} exceeds the document's {} pages",
+                first, last, page_count
+            ),
+        ));
+    }
+
+    let excluded: Vec<u32> = (1..=page_count).filter(|page| !pages.contains(page)).collect();
+    document.delete_pages(&excluded);
+    document.prune_objects();
+
+    let mut trimmed = Vec::new();
+    document.save_to(&mut trimmed).map_err(|e| {
+        pdf_validation_error("document", format!("Failed to write PDF document: {}", e))
+    })?;
+
+    Ok(create_pdf_content(&trimmed))
+}
+
+/// Create a user message containing several PDF documents followed by a prompt
+///
+/// Documents keep the order they are given in and come before the prompt.
+/// With `cache` set, only the last document is marked for caching; the cache
+/// breakpoint covers every document before it, so earlier markers are removed
+/// to stay within the API's breakpoint limit.
+pub fn create_multi_pdf_message(
+    documents: Vec<ContentBlock>,
+    prompt: impl Into<String>,
+    cache: bool,
+) -> MessageParam {
+    let last_index = documents.len().saturating_sub(1);
+    let mut blocks: Vec<ContentBlock> = documents
+        .into_iter()
+        .enumerate()
+        .map(|(index, block)| match block {
+            ContentBlock::Document { source, .. } => ContentBlock::Document {
+                source,
+                cache_control: (cache && index == last_index).then(CacheControl::ephemeral),
+            },
+            other => other,
+        })
+        .collect();
+
+    blocks.push(ContentBlock::Text {
+        text: prompt.into(),
+        cache_control: None,
+    });
+
+    MessageParam::user_blocks(blocks)
+}
+
+fn pdf_validation_error(field: &str, message: impl Into<String>) -> AnthropicError {
+    let message = message.into();
+    AnthropicError::Validation {
+        details: vec![ValidationDetail {
+            field: field.to_string(),
+            message: message.clone(),
+        }],
+        message,
+    }
+}
+
 /// Create a cacheable PDF document content block from raw bytes
 ///
 /// This marks the PDF for prompt caching, which can improve performance
@@ -54,7 +155,7 @@ pub fn create_cacheable_pdf_content(pdf_bytes: &[u8]) -> ContentBlock {
     let base64_data = STANDARD.encode(pdf_bytes);
     ContentBlock::Document {
         source: DocumentSource::base64("application/pdf", base64_data),
-        cache_control: Some(crate::services::messages::CacheControl::ephemeral()),
+        cache_control: Some(CacheControl::ephemeral()),
     }
 }
 
@@ -202,6 +303,116 @@ mod tests {
         assert_eq!(pdfs.len(), 2);
     }
 
+    /// Build a minimal PDF with the given number of blank pages
+    fn sample_pdf(page_count: u32) -> Vec<u8> {
+        use lopdf::{dictionary, Document, Object, Stream};
+
+        let mut document = Document::with_version("1.5");
+        let pages_id = document.new_object_id();
+        let kids: Vec<Object> = (0..page_count)
+            .map(|_| {
+                let contents = document.add_object(Stream::new(dictionary! {}, Vec::new()));
+                document
+                    .add_object(dictionary! {
+                        "Type" => "Page",
+                        "Parent" => pages_id,
+                        "Contents" => contents,
+                    })
+                    .into()
+            })
+            .collect();
+        document.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => kids,
+                "Count" => page_count,
+                "MediaBox" => vec![0.into(), 0.into(), 595.into(), 842.into()],
+            }),
+        );
+        let catalog_id = document.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        document.trailer.set("Root", catalog_id);
+
+        let mut bytes = Vec::new();
+        document.save_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    fn page_count(block: &ContentBlock) -> usize {
+        match block {
+            ContentBlock::Document { source, .. } => {
+                let bytes = STANDARD.decode(&source.data).unwrap();
+                lopdf::Document::load_mem(&bytes).unwrap().get_pages().len()
+            }
+            _ => panic!("Expected Document content block"),
+        }
+    }
+
+    #[test]
+    fn test_create_pdf_content_with_pages() {
+        let content = create_pdf_content_with_pages(&sample_pdf(5), 2..=3).unwrap();
+        assert_eq!(page_count(&content), 2);
+
+        let content = create_pdf_content_with_pages(&sample_pdf(5), 5..=5).unwrap();
+        assert_eq!(page_count(&content), 1);
+    }
+
+    #[test]
+    fn test_create_pdf_content_with_pages_out_of_range() {
+        let result = create_pdf_content_with_pages(&sample_pdf(3), 2..=4);
+
+        match result {
+            Err(AnthropicError::Validation { message, details }) => {
+                assert_eq!(message, "Page range 2..=4 exceeds the document's 3 pages");
+                assert_eq!(details[0].field, "pages");
+            }
+            other => panic!("Expected validation error, got {:?}", other),
+        }
+
+        assert!(create_pdf_content_with_pages(&sample_pdf(3), 0..=1).is_err());
+        assert!(create_pdf_content_with_pages(INVALID_PDF, 1..=1).is_err());
+    }
+
+    #[test]
+    fn test_create_multi_pdf_message() {
+        let first = create_pdf_content_with_pages(&sample_pdf(4), 1..=2).unwrap();
+        // A marker on an earlier document is dropped in favor of the last one
+        let second = create_cacheable_pdf_content(&sample_pdf(1));
+        let third = create_pdf_content(&sample_pdf(3));
+
+        let message = create_multi_pdf_message(
+            vec![first, second, third],
+            "Compare these reports",
+            true,
+        );
+
+        let blocks = match message.content {
+            crate::services::messages::MessageContent::Blocks(blocks) => blocks,
+            _ => panic!("Expected content blocks"),
+        };
+        assert_eq!(blocks.len(), 4);
+
+        let pages: Vec<usize> = blocks[..3].iter().map(page_count).collect();
+        assert_eq!(pages, vec![2, 1, 3]);
+
+        let cached: Vec<bool> = blocks[..3]
+            .iter()
+            .map(|block| matches!(block, ContentBlock::Document { cache_control: Some(_), .. }))
+            .collect();
+        assert_eq!(cached, vec![false, false, true]);
+
+        match &blocks[3] {
+            ContentBlock::Text { text, cache_control } => {
+                assert_eq!(text, "Compare these reports");
+                assert!(cache_control.is_none());
+            }
+            _ => panic!("Expected the prompt last"),
+        }
+    }
+
     #[test]
     fn test_beta_header() {
         assert_eq!(get_pdf_support_beta_header(), "pdfs-2024-09-25");
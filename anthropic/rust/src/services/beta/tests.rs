@@ -207,6 +207,7 @@ mod integration_tests {
         let content = vec![
             ContentBlock::Thinking {
                 thinking: "First, I need to understand the problem...".to_string(),
+                signature: None,
             },
             ContentBlock::Text {
                 text: "The answer is 42.".to_string(),
@@ -214,6 +215,7 @@ mod integration_tests {
             },
             ContentBlock::Thinking {
                 thinking: "Let me verify this calculation...".to_string(),
+                signature: None,
             },
             ContentBlock::Text {
                 text: "Yes, that's correct.".to_string(),
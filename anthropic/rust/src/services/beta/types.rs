@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 
 // Re-export types from messages module that are also used in beta features
 pub use crate::services::messages::{
-    CacheControl, ContentBlock, ImageSource, DocumentSource, MessageParam,
+    CacheControl, CacheUsage, ContentBlock, ImageSource, DocumentSource, MessageParam,
     Role, SystemPrompt, ThinkingConfig, Tool, Usage,
 };
 
@@ -177,13 +177,6 @@ impl SystemPromptWithCache {
     }
 }
 
-/// Cache usage statistics
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-pub struct CacheUsage {
-    pub cache_creation_input_tokens: u32,
-    pub cache_read_input_tokens: u32,
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
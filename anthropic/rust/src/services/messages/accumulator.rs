@@ -0,0 +1,277 @@
+//! Reassembly of streamed message events into a complete `Message`
+//!
+//! The Messages API streams a response as a sequence of SSE events. Text,
+//! tool-use input JSON and extended thinking all arrive as deltas that must be
+//! stitched back together per content block. `StreamAccumulator` does that
+//! bookkeeping so callers can render deltas as they arrive and still obtain the
+//! same `Message` a non-streaming request would have returned.
+
+use super::stream::MessageStreamEvent;
+use super::types::{ContentBlock, ContentDelta, Message, PartialMessage, StopReason, Usage};
+use crate::error::AnthropicError;
+use futures::Stream;
+use pin_project_lite::pin_project;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Item yielded by an [`AccumulatingStream`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum AccumulatorEvent {
+    /// An event as received from the underlying stream
+    Event(MessageStreamEvent),
+    /// The fully assembled message, yielded in place of `message_stop`
+    Message(Message),
+}
+
+/// Content block under construction
+#[derive(Debug, Clone)]
+struct BlockState {
+    block: ContentBlock,
+    /// Raw `input_json_delta` fragments for tool_use blocks
+    partial_json: String,
+    finished: bool,
+}
+
+impl BlockState {
+    fn new(block: ContentBlock) -> Self {
+        Self {
+            block,
+            partial_json: String::new(),
+            finished: false,
+        }
+    }
+
+    fn apply_delta(&mut self, index: usize, delta: &ContentDelta) -> Result<(), AnthropicError> {
+        match (delta.delta_type.as_str(), &mut self.block) {
+            ("text_delta", ContentBlock::Text { text, .. }) => {
+                text.push_str(delta.text.as_deref().unwrap_or_default());
+            }
+            ("input_json_delta", ContentBlock::ToolUse { .. }) => {
+                self.partial_json
+                    .push_str(delta.partial_json.as_deref().unwrap_or_default());
+            }
+            ("thinking_delta", ContentBlock::Thinking { thinking, .. }) => {
+                thinking.push_str(delta.thinking.as_deref().unwrap_or_default());
+            }
+            ("signature_delta", ContentBlock::Thinking { signature, .. }) => {
+                signature
+                    .get_or_insert_with(String::new)
+                    .push_str(delta.signature.as_deref().unwrap_or_default());
+            }
+            (delta_type, _) => {
+                return Err(AnthropicError::Stream(format!(
+                    "Unexpected {} for content block {}",
+                    delta_type, index
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self, index: usize) -> Result<(), AnthropicError> {
+        if self.finished {
+            return Ok(());
+        }
+        self.finished = true;
+
+        if let ContentBlock::ToolUse { input, .. } = &mut self.block {
+            // A tool called without arguments streams no JSON at all
+            if !self.partial_json.is_empty() {
+                *input = serde_json::from_str(&self.partial_json).map_err(|e| {
+                    AnthropicError::Stream(format!(
+                        "Invalid tool input JSON for content block {}: {}",
+                        index, e
+                    ))
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reconstructs a complete `Message` from a sequence of stream events
+///
+/// Feed every event to [`push`](Self::push); the assembled message is returned
+/// once `message_stop` is seen. To consume a stream directly, wrap it with
+/// [`StreamAccumulator::wrap`] or [`MessageStream::accumulate`].
+///
+/// [`MessageStream::accumulate`]: super::MessageStream::accumulate
+#[derive(Debug, Clone, Default)]
+pub struct StreamAccumulator {
+    message: Option<PartialMessage>,
+    blocks: Vec<Option<BlockState>>,
+    stop_reason: Option<StopReason>,
+    stop_sequence: Option<String>,
+    usage: Option<Usage>,
+}
+
+impl StreamAccumulator {
+    /// Create an empty accumulator
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wrap an event stream so it yields deltas followed by the final message
+    pub fn wrap<S>(stream: S) -> AccumulatingStream<S>
+    where
+        S: Stream<Item = Result<MessageStreamEvent, AnthropicError>>,
+    {
+        AccumulatingStream {
+            inner: stream,
+            accumulator: Self::new(),
+            is_done: false,
+        }
+    }
+
+    /// Apply a single event
+    ///
+    /// Returns the assembled message when `event` is `message_stop`.
+    pub fn push(&mut self, event: &MessageStreamEvent) -> Result<Option<Message>, AnthropicError> {
+        match event {
+            MessageStreamEvent::MessageStart { message } => {
+                self.usage = Some(message.usage);
+                self.message = Some(message.clone());
+            }
+            MessageStreamEvent::ContentBlockStart {
+                index,
+                content_block,
+            } => {
+                if self.blocks.len() <= *index {
+                    self.blocks.resize(*index + 1, None);
+                }
+                self.blocks[*index] = Some(BlockState::new(content_block.clone()));
+            }
+            MessageStreamEvent::ContentBlockDelta { index, delta } => {
+                self.block_mut(*index)?.apply_delta(*index, delta)?;
+            }
+            MessageStreamEvent::ContentBlockStop { index } => {
+                self.block_mut(*index)?.finish(*index)?;
+            }
+            MessageStreamEvent::MessageDelta { delta, usage } => {
+                self.stop_reason = delta.stop_reason;
+                self.stop_sequence = delta.stop_sequence.clone();
+                self.merge_usage(usage);
+            }
+            MessageStreamEvent::MessageStop => return self.finish().map(Some),
+            MessageStreamEvent::Ping => {}
+            MessageStreamEvent::Error { error } => {
+                return Err(AnthropicError::Stream(error.to_string()));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Usage as reported so far
+    pub fn usage(&self) -> Option<Usage> {
+        self.usage
+    }
+
+    fn block_mut(&mut self, index: usize) -> Result<&mut BlockState, AnthropicError> {
+        self.blocks
+            .get_mut(index)
+            .and_then(Option::as_mut)
+            .ok_or_else(|| {
+                AnthropicError::Stream(format!("Event for unknown content block {}", index))
+            })
+    }
+
+    /// `message_delta` carries cumulative output tokens; input and cache
+    /// counts are only reported on `message_start` unless the delta repeats them.
+    fn merge_usage(&mut self, delta: &Usage) {
+        let usage = self.usage.get_or_insert(*delta);
+        if delta.input_tokens > 0 {
+            usage.input_tokens = delta.input_tokens;
+        }
+        usage.output_tokens = delta.output_tokens;
+        if delta.cache_creation_input_tokens.is_some() {
+            usage.cache_creation_input_tokens = delta.cache_creation_input_tokens;
+        }
+        if delta.cache_read_input_tokens.is_some() {
+            usage.cache_read_input_tokens = delta.cache_read_input_tokens;
+        }
+    }
+
+    fn finish(&mut self) -> Result<Message, AnthropicError> {
+        let partial = self
+            .message
+            .take()
+            .ok_or_else(|| AnthropicError::Stream("No message start event received".to_string()))?;
+
+        let mut content = Vec::with_capacity(self.blocks.len());
+        for (index, state) in self.blocks.drain(..).enumerate() {
+            if let Some(mut state) = state {
+                state.finish(index)?;
+                content.push(state.block);
+            }
+        }
+
+        Ok(Message {
+            id: partial.id,
+            message_type: partial.message_type,
+            role: partial.role,
+            content,
+            model: partial.model,
+            stop_reason: self.stop_reason.take(),
+            stop_sequence: self.stop_sequence.take(),
+            usage: self.usage.take().unwrap_or(partial.usage),
+        })
+    }
+}
+
+pin_project! {
+    /// Stream adapter produced by [`StreamAccumulator::wrap`]
+    ///
+    /// Every event is passed through as [`AccumulatorEvent::Event`] except
+    /// `message_stop`, which is replaced by [`AccumulatorEvent::Message`].
+    pub struct AccumulatingStream<S> {
+        #[pin]
+        inner: S,
+        accumulator: StreamAccumulator,
+        is_done: bool,
+    }
+}
+
+impl<S> AccumulatingStream<S> {
+    /// The accumulator state, e.g. to inspect usage mid-stream
+    pub fn accumulator(&self) -> &StreamAccumulator {
+        &self.accumulator
+    }
+}
+
+impl<S> Stream for AccumulatingStream<S>
+where
+    S: Stream<Item = Result<MessageStreamEvent, AnthropicError>>,
+{
+    type Item = Result<AccumulatorEvent, AnthropicError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        if *this.is_done {
+            return Poll::Ready(None);
+        }
+
+        match this.inner.poll_next(cx) {
+            Poll::Ready(Some(Ok(event))) => match this.accumulator.push(&event) {
+                Ok(Some(message)) => {
+                    *this.is_done = true;
+                    Poll::Ready(Some(Ok(AccumulatorEvent::Message(message))))
+                }
+                Ok(None) => Poll::Ready(Some(Ok(AccumulatorEvent::Event(event)))),
+                Err(e) => {
+                    *this.is_done = true;
+                    Poll::Ready(Some(Err(e)))
+                }
+            },
+            Poll::Ready(Some(Err(e))) => {
+                *this.is_done = true;
+                Poll::Ready(Some(Err(e)))
+            }
+            Poll::Ready(None) => {
+                *this.is_done = true;
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
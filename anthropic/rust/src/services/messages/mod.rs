@@ -4,6 +4,7 @@
 //! messages with Claude, counting tokens, and handling tool use.
 
 mod types;
+mod accumulator;
 mod service;
 mod stream;
 mod validation;
@@ -15,11 +16,12 @@ mod tests;
 pub use types::{
     Message, MessageParam, MessageContent, ContentBlock, ContentDelta,
     CreateMessageRequest, CountTokensRequest, TokenCount,
-    Role, StopReason, Usage, Tool, ToolChoice, ToolResultContent,
+    Role, StopReason, Usage, CacheUsage, Tool, ToolChoice, ToolResultContent,
     ImageSource, DocumentSource, CacheControl, Metadata,
-    ThinkingConfig, SystemPrompt, PartialMessage, MessageDelta,
+    ThinkingConfig, SystemPrompt, PartialMessage, MessageDelta, compose_beta_header,
 };
 
 pub use service::{MessagesService, MessagesServiceImpl};
 pub use stream::{MessageStream, MessageStreamEvent};
+pub use accumulator::{AccumulatingStream, AccumulatorEvent, StreamAccumulator};
 pub use validation::{validate_create_message_request, validate_count_tokens_request};
@@ -1,18 +1,26 @@
 //! Messages service implementation
 
 use super::types::{
-    CreateMessageRequest, CountTokensRequest, Message, TokenCount,
+    compose_beta_header, CreateMessageRequest, CountTokensRequest, Message, TokenCount,
 };
 use super::validation::{validate_create_message_request, validate_count_tokens_request};
 use super::stream::MessageStream;
 use crate::auth::AuthManager;
+use crate::config::{AnthropicConfig, BetaFeature, ModelFallbackPolicy};
 use crate::error::{AnthropicError, ApiErrorResponse};
+use crate::observability::{metric_names, MetricsCollector, NoopMetricsCollector};
 use crate::transport::HttpTransport;
 use async_trait::async_trait;
-use http::{HeaderMap, Method};
-use std::sync::Arc;
+use http::{HeaderMap, HeaderValue, Method};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use url::Url;
 
+const ANTHROPIC_BETA: &str = "anthropic-beta";
+
+/// Maximum number of token counts kept for budget checks
+const TOKEN_COUNT_CACHE_CAPACITY: usize = 256;
+
 /// Messages service trait for testability
 #[async_trait]
 pub trait MessagesService: Send + Sync {
@@ -30,6 +38,17 @@ pub trait MessagesService: Send + Sync {
         &self,
         request: CountTokensRequest,
     ) -> Result<TokenCount, AnthropicError>;
+
+    /// Create a message only if its input fits within `max_input_tokens`
+    ///
+    /// The messages, system prompt, and tools are counted first; if the count
+    /// exceeds the budget, `AnthropicError::TokenBudgetExceeded` is returned
+    /// without calling the messages endpoint.
+    async fn create_within_budget(
+        &self,
+        request: CreateMessageRequest,
+        max_input_tokens: u32,
+    ) -> Result<Message, AnthropicError>;
 }
 
 /// Implementation of the Messages service
@@ -37,6 +56,9 @@ pub struct MessagesServiceImpl {
     transport: Arc<dyn HttpTransport>,
     auth_manager: Arc<dyn AuthManager>,
     base_url: Url,
+    metrics: Arc<dyn MetricsCollector>,
+    token_counts: Mutex<HashMap<Vec<u8>, TokenCount>>,
+    model_fallback: Option<ModelFallbackPolicy>,
 }
 
 impl MessagesServiceImpl {
@@ -50,7 +72,82 @@ impl MessagesServiceImpl {
             transport,
             auth_manager,
             base_url,
+            metrics: Arc::new(NoopMetricsCollector),
+            token_counts: Mutex::new(HashMap::new()),
+            model_fallback: None,
+        }
+    }
+
+    /// Create a Messages service using the configured base URL and model fallback
+    pub fn from_config(
+        transport: Arc<dyn HttpTransport>,
+        auth_manager: Arc<dyn AuthManager>,
+        config: &AnthropicConfig,
+    ) -> Result<Self, AnthropicError> {
+        let base_url = Url::parse(&config.base_url)
+            .map_err(|e| AnthropicError::Configuration(format!("Invalid base URL: {}", e)))?;
+
+        let mut service = Self::new(transport, auth_manager, base_url);
+        service.model_fallback = config.model_fallback.clone();
+        Ok(service)
+    }
+
+    /// Fall back to other models in `create` when the requested one is overloaded
+    ///
+    /// The model that served the request is reported in `Message::model`.
+    pub fn with_model_fallback(mut self, policy: ModelFallbackPolicy) -> Self {
+        self.model_fallback = Some(policy);
+        self
+    }
+
+    /// Record token usage into the given metrics collector
+    pub fn with_metrics(mut self, metrics: Arc<dyn MetricsCollector>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Record token counts, including prompt cache reads and writes
+    fn record_usage(&self, message: &Message) {
+        let labels = [("model", message.model.as_str())];
+        let usage = &message.usage;
+
+        self.metrics
+            .increment_counter(metric_names::TOKENS_INPUT, usage.input_tokens as u64, &labels);
+        self.metrics
+            .increment_counter(metric_names::TOKENS_OUTPUT, usage.output_tokens as u64, &labels);
+
+        if let Some(cache) = usage.cache_usage() {
+            self.metrics.increment_counter(
+                metric_names::TOKENS_CACHE_CREATION,
+                cache.cache_creation_input_tokens as u64,
+                &labels,
+            );
+            self.metrics.increment_counter(
+                metric_names::TOKENS_CACHE_READ,
+                cache.cache_read_input_tokens as u64,
+                &labels,
+            );
+        }
+    }
+
+    /// Count tokens for a request, reusing an earlier count of the same input
+    async fn cached_token_count(
+        &self,
+        request: CountTokensRequest,
+    ) -> Result<TokenCount, AnthropicError> {
+        let key = serde_json::to_vec(&request)?;
+        if let Some(count) = self.token_counts.lock().unwrap().get(&key) {
+            return Ok(count.clone());
+        }
+
+        let count = self.count_tokens(request).await?;
+
+        let mut cache = self.token_counts.lock().unwrap();
+        if cache.len() >= TOKEN_COUNT_CACHE_CAPACITY {
+            cache.clear();
         }
+        cache.insert(key, count.clone());
+        Ok(count)
     }
 
     /// Build headers for a request
@@ -60,43 +157,45 @@ impl MessagesServiceImpl {
         headers
     }
 
-    /// Parse API error from response
-    fn parse_api_error(&self, status: u16, body: &[u8]) -> AnthropicError {
-        if let Ok(error_response) = serde_json::from_slice::<ApiErrorResponse>(body) {
-            AnthropicError::Api {
-                status,
-                message: error_response.error.message,
-                error_type: error_response.error.error_type,
-            }
-        } else {
-            AnthropicError::Api {
-                status,
-                message: String::from_utf8_lossy(body).to_string(),
-                error_type: "unknown".to_string(),
-            }
+    /// Build headers for a message request, merging its beta features with
+    /// the client-level ones into a single `anthropic-beta` header
+    fn build_message_headers(
+        &self,
+        request: &CreateMessageRequest,
+    ) -> Result<HeaderMap, AnthropicError> {
+        let mut headers = self.build_headers();
+        if request.beta_features.is_empty() {
+            return Ok(headers);
         }
-    }
-}
 
-#[async_trait]
-impl MessagesService for MessagesServiceImpl {
-    async fn create(&self, mut request: CreateMessageRequest) -> Result<Message, AnthropicError> {
-        // Validate request
-        validate_create_message_request(&request)?;
+        let configured = headers
+            .get(ANTHROPIC_BETA)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.split(',').map(str::to_string).collect::<Vec<_>>())
+            .unwrap_or_default();
+        let requested = request.beta_features.iter().map(BetaFeature::header_value);
 
-        // Ensure stream is disabled
-        request.stream = Some(false);
+        if let Some(beta) = compose_beta_header(configured.into_iter().chain(requested))? {
+            let value = HeaderValue::from_str(&beta).map_err(|e| {
+                AnthropicError::Configuration(format!("Invalid beta header: {}", e))
+            })?;
+            headers.insert(ANTHROPIC_BETA, value);
+        }
+        Ok(headers)
+    }
 
+    /// Send a non-streaming message request once
+    async fn send_create(&self, request: &CreateMessageRequest) -> Result<Message, AnthropicError> {
         // Build URL
         let url = self.base_url
             .join("/v1/messages")
             .map_err(|e| AnthropicError::Configuration(format!("Invalid URL: {}", e)))?;
 
         // Build headers
-        let headers = self.build_headers();
+        let headers = self.build_message_headers(request)?;
 
         // Serialize request body
-        let body = serde_json::to_vec(&request)?;
+        let body = serde_json::to_vec(request)?;
 
         // Execute request
         let response = self.transport
@@ -111,12 +210,87 @@ impl MessagesService for MessagesServiceImpl {
         // Handle response
         if response.status == 200 {
             let message = serde_json::from_slice::<Message>(&response.body)?;
+            self.record_usage(&message);
             Ok(message)
         } else {
             Err(self.parse_api_error(response.status, &response.body))
         }
     }
 
+    /// Send a message request, moving down the fallback chain on overload
+    ///
+    /// A rate-limited model is retried `rate_limit_retries` times before the
+    /// next model is tried. Only the model changes between attempts.
+    async fn send_with_fallback(
+        &self,
+        mut request: CreateMessageRequest,
+        policy: &ModelFallbackPolicy,
+    ) -> Result<Message, AnthropicError> {
+        let chain = policy.chain(&request.model);
+        let mut last_error = None;
+
+        for (index, model) in chain.into_iter().enumerate() {
+            if index > 0 {
+                self.metrics.increment_counter(
+                    metric_names::MODEL_FALLBACKS,
+                    1,
+                    &[("from", request.model.as_str()), ("to", model.as_str())],
+                );
+            }
+            request.model = model;
+
+            let mut rate_limit_retries = 0;
+            loop {
+                match self.send_create(&request).await {
+                    Err(e) if is_rate_limited(&e) && rate_limit_retries < policy.rate_limit_retries => {
+                        rate_limit_retries += 1;
+                        tokio::time::sleep(policy.rate_limit_backoff).await;
+                    }
+                    Err(e) if is_rate_limited(&e) || is_overloaded(&e) => {
+                        last_error = Some(e);
+                        break;
+                    }
+                    result => return result,
+                }
+            }
+        }
+
+        Err(last_error.expect("fallback chain always contains the requested model"))
+    }
+
+    /// Parse API error from response
+    fn parse_api_error(&self, status: u16, body: &[u8]) -> AnthropicError {
+        if let Ok(error_response) = serde_json::from_slice::<ApiErrorResponse>(body) {
+            AnthropicError::Api {
+                status,
+                message: error_response.error.message,
+                error_type: error_response.error.error_type,
+            }
+        } else {
+            AnthropicError::Api {
+                status,
+                message: String::from_utf8_lossy(body).to_string(),
+                error_type: "unknown".to_string(),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl MessagesService for MessagesServiceImpl {
+    async fn create(&self, mut request: CreateMessageRequest) -> Result<Message, AnthropicError> {
+        // Validate request
+        validate_create_message_request(&request)?;
+
+        // Ensure stream is disabled
+        request.stream = Some(false);
+
+        match &self.model_fallback {
+            Some(policy) => self.send_with_fallback(request, policy).await,
+            None => self.send_create(&request).await,
+        }
+    }
+
     async fn create_stream(
         &self,
         mut request: CreateMessageRequest,
@@ -133,7 +307,7 @@ impl MessagesService for MessagesServiceImpl {
             .map_err(|e| AnthropicError::Configuration(format!("Invalid URL: {}", e)))?;
 
         // Build headers
-        let headers = self.build_headers();
+        let headers = self.build_message_headers(&request)?;
 
         // Serialize request body
         let body = serde_json::to_vec(&request)?;
@@ -188,6 +362,41 @@ impl MessagesService for MessagesServiceImpl {
             Err(self.parse_api_error(response.status, &response.body))
         }
     }
+
+    async fn create_within_budget(
+        &self,
+        request: CreateMessageRequest,
+        max_input_tokens: u32,
+    ) -> Result<Message, AnthropicError> {
+        // Validate before spending a count call on a request that cannot be sent
+        validate_create_message_request(&request)?;
+
+        let count = self
+            .cached_token_count(CountTokensRequest::from(&request))
+            .await?;
+        if count.input_tokens > max_input_tokens {
+            return Err(AnthropicError::TokenBudgetExceeded {
+                input_tokens: count.input_tokens,
+                max_input_tokens,
+            });
+        }
+
+        self.create(request).await
+    }
+}
+
+/// Whether the API reported the model as overloaded (HTTP 529)
+fn is_overloaded(error: &AnthropicError) -> bool {
+    matches!(
+        error,
+        AnthropicError::Api { status, error_type, .. }
+            if *status == 529 || error_type == "overloaded_error"
+    )
+}
+
+/// Whether the API rejected the request with a rate limit (HTTP 429)
+fn is_rate_limited(error: &AnthropicError) -> bool {
+    matches!(error, AnthropicError::Api { status: 429, .. })
 }
 
 #[cfg(test)]
@@ -1,8 +1,7 @@
 //! Streaming support for the Messages API
 
-use super::types::{
-    ContentBlock, ContentDelta, Message, MessageDelta, PartialMessage, Role, StopReason, Usage,
-};
+use super::accumulator::{AccumulatingStream, StreamAccumulator};
+use super::types::{ContentBlock, ContentDelta, Message, MessageDelta, PartialMessage, Usage};
 use crate::error::{AnthropicError, StreamError};
 use bytes::Bytes;
 use futures::{Stream, StreamExt};
@@ -96,10 +95,6 @@ pin_project! {
         inner: Box<dyn Stream<Item = Result<Bytes, AnthropicError>> + Send + Unpin>,
         buffer: String,
         is_done: bool,
-        // State for accumulating the final message
-        current_message: Option<PartialMessage>,
-        content_blocks: Vec<ContentBlock>,
-        accumulated_text: Vec<String>,
     }
 }
 
@@ -112,93 +107,31 @@ impl MessageStream {
             inner,
             buffer: String::new(),
             is_done: false,
-            current_message: None,
-            content_blocks: Vec::new(),
-            accumulated_text: Vec::new(),
         }
     }
 
     /// Collect the stream into a complete message
     pub async fn collect(mut self) -> Result<Message, AnthropicError> {
-        let mut message: Option<PartialMessage> = None;
-        let mut content_blocks: Vec<ContentBlock> = Vec::new();
-        let mut accumulated_text: Vec<String> = Vec::new();
-        let mut stop_reason: Option<StopReason> = None;
-        let mut stop_sequence: Option<String> = None;
-        let mut final_usage: Option<Usage> = None;
+        let mut accumulator = StreamAccumulator::new();
 
         while let Some(event) = self.next().await {
-            match event? {
-                MessageStreamEvent::MessageStart { message: msg } => {
-                    message = Some(msg);
-                }
-                MessageStreamEvent::ContentBlockStart {
-                    index,
-                    content_block,
-                } => {
-                    while content_blocks.len() <= index {
-                        content_blocks.push(ContentBlock::Text {
-                            text: String::new(),
-                            cache_control: None,
-                        });
-                        accumulated_text.push(String::new());
-                    }
-                    content_blocks[index] = content_block;
-                }
-                MessageStreamEvent::ContentBlockDelta { index, delta } => {
-                    if let Some(text) = delta.text {
-                        while accumulated_text.len() <= index {
-                            accumulated_text.push(String::new());
-                        }
-                        accumulated_text[index].push_str(&text);
-                    }
-                }
-                MessageStreamEvent::MessageDelta { delta, usage } => {
-                    stop_reason = delta.stop_reason;
-                    stop_sequence = delta.stop_sequence;
-                    final_usage = Some(usage);
-                }
-                MessageStreamEvent::MessageStop => {
-                    break;
-                }
-                MessageStreamEvent::Error { error } => {
-                    return Err(AnthropicError::Stream(error.to_string()));
-                }
-                _ => {}
-            }
-        }
-
-        // Update content blocks with accumulated text
-        for (i, text) in accumulated_text.iter().enumerate() {
-            if i < content_blocks.len() {
-                if let ContentBlock::Text { cache_control, .. } = &content_blocks[i] {
-                    content_blocks[i] = ContentBlock::Text {
-                        text: text.clone(),
-                        cache_control: cache_control.clone(),
-                    };
-                }
+            if let Some(message) = accumulator.push(&event?)? {
+                return Ok(message);
             }
         }
 
-        let partial_message = message
-            .ok_or_else(|| AnthropicError::Stream("No message start event received".to_string()))?;
-
-        let usage = final_usage.unwrap_or(partial_message.usage);
+        Err(AnthropicError::Stream(
+            "Stream ended before message_stop".to_string(),
+        ))
+    }
 
-        Ok(Message {
-            id: partial_message.id,
-            message_type: partial_message.message_type,
-            role: partial_message.role,
-            content: content_blocks,
-            model: partial_message.model,
-            stop_reason,
-            stop_sequence,
-            usage,
-        })
+    /// Yield deltas as they arrive, followed by the assembled message
+    pub fn accumulate(self) -> AccumulatingStream<Self> {
+        StreamAccumulator::wrap(self)
     }
 
     /// Parse an SSE event from a line
-    fn parse_sse_line(&mut self, line: &str) -> Option<Result<MessageStreamEvent, AnthropicError>> {
+    fn parse_sse_line(line: &str) -> Option<Result<MessageStreamEvent, AnthropicError>> {
         if line.is_empty() {
             return None;
         }
@@ -215,7 +148,7 @@ impl MessageStream {
 
         // Parse the JSON event
         match serde_json::from_str::<SseEvent>(data) {
-            Ok(event) => self.parse_event(event),
+            Ok(event) => Self::parse_event(event),
             Err(e) => Some(Err(AnthropicError::Stream(format!(
                 "Failed to parse SSE event: {}",
                 e
@@ -224,7 +157,7 @@ impl MessageStream {
     }
 
     /// Parse a typed event
-    fn parse_event(&self, event: SseEvent) -> Option<Result<MessageStreamEvent, AnthropicError>> {
+    fn parse_event(event: SseEvent) -> Option<Result<MessageStreamEvent, AnthropicError>> {
         match event.event_type.as_str() {
             "message_start" => {
                 match serde_json::from_value::<MessageStartEvent>(event.data) {
@@ -305,40 +238,34 @@ impl Stream for MessageStream {
             return Poll::Ready(None);
         }
 
-        // Poll the inner stream for more data
-        match this.inner.as_mut().poll_next(cx) {
-            Poll::Ready(Some(Ok(bytes))) => {
-                // Add bytes to buffer
-                let text = String::from_utf8_lossy(&bytes);
-                this.buffer.push_str(&text);
-
-                // Process complete lines
-                if let Some(newline_pos) = this.buffer.find('\n') {
-                    let line = this.buffer[..newline_pos].to_string();
-                    *this.buffer = this.buffer[newline_pos + 1..].to_string();
+        loop {
+            // Drain complete lines already buffered before reading more data
+            while let Some(newline_pos) = this.buffer.find('\n') {
+                let line = this.buffer[..newline_pos].trim_end_matches('\r').to_string();
+                this.buffer.drain(..=newline_pos);
 
-                    if let Some(event) = this.parse_sse_line(&line) {
-                        return Poll::Ready(Some(event));
-                    }
-
-                    // If we didn't get an event, poll again
-                    cx.waker().wake_by_ref();
-                    Poll::Pending
-                } else {
-                    // Need more data
-                    cx.waker().wake_by_ref();
-                    Poll::Pending
+                if let Some(event) = Self::parse_sse_line(&line) {
+                    return Poll::Ready(Some(event));
                 }
             }
-            Poll::Ready(Some(Err(e))) => {
-                *this.is_done = true;
-                Poll::Ready(Some(Err(e)))
-            }
-            Poll::Ready(None) => {
-                *this.is_done = true;
-                Poll::Ready(None)
+
+            // Poll the inner stream for more data
+            match this.inner.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(bytes))) => {
+                    this.buffer.push_str(&String::from_utf8_lossy(&bytes));
+                }
+                Poll::Ready(Some(Err(e))) => {
+                    *this.is_done = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+                Poll::Ready(None) => {
+                    *this.is_done = true;
+                    // Flush a final event not terminated by a newline
+                    let line = std::mem::take(this.buffer);
+                    return Poll::Ready(Self::parse_sse_line(line.trim_end()));
+                }
+                Poll::Pending => return Poll::Pending,
             }
-            Poll::Pending => Poll::Pending,
         }
     }
 }
@@ -4,13 +4,16 @@
 
 use super::*;
 use crate::auth::AuthManager;
+use crate::config::{AnthropicConfig, BetaFeature, ModelFallbackPolicy};
 use crate::error::AnthropicError;
+use crate::observability::{metric_names, InMemoryMetricsCollector};
 use crate::transport::{HttpResponse, HttpTransport};
 use async_trait::async_trait;
 use bytes::Bytes;
 use futures::stream;
 use http::{HeaderMap, Method};
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use url::Url;
 
 // ============================================================================
@@ -470,6 +473,570 @@ async fn test_count_tokens_validation_error() {
     }
 }
 
+// ============================================================================
+// Tests: Token Budget
+// ============================================================================
+
+fn budget_request() -> CreateMessageRequest {
+    CreateMessageRequest::new(
+        "claude-3-5-sonnet-20241022",
+        1024,
+        vec![MessageParam::user("What's the weather in Paris?")],
+    )
+    .with_system("You are a weather assistant")
+    .with_tools(vec![Tool::new(
+        "get_weather",
+        "Get weather information",
+        serde_json::json!({"type": "object"}),
+    )])
+}
+
+fn budget_transport(input_tokens: u32) -> Arc<MockHttpTransport> {
+    let message_json = serde_json::to_string(&create_test_message()).unwrap();
+    let count_json = serde_json::to_string(&TokenCount { input_tokens }).unwrap();
+
+    // Responses are served last-in first-out: the count, then the message
+    Arc::new(
+        MockHttpTransport::new()
+            .with_response(create_success_response(&message_json))
+            .with_response(create_success_response(&count_json)),
+    )
+}
+
+#[tokio::test]
+async fn test_create_within_budget_under_limit() {
+    let transport = budget_transport(100);
+    let service = create_test_service(transport.clone());
+
+    let message = service
+        .create_within_budget(budget_request(), 100)
+        .await
+        .unwrap();
+    assert_eq!(message.id, "msg_123");
+
+    let requests = transport.get_requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].1.ends_with("/v1/messages/count_tokens"));
+    assert!(requests[1].1.ends_with("/v1/messages"));
+
+    let count_body: serde_json::Value =
+        serde_json::from_slice(requests[0].3.as_ref().unwrap()).unwrap();
+    assert_eq!(count_body["system"], "You are a weather assistant");
+    assert_eq!(count_body["tools"][0]["name"], "get_weather");
+    assert!(count_body.get("max_tokens").is_none());
+}
+
+#[tokio::test]
+async fn test_create_within_budget_over_limit() {
+    let transport = budget_transport(101);
+    let service = create_test_service(transport.clone());
+
+    let result = service.create_within_budget(budget_request(), 100).await;
+
+    match result.unwrap_err() {
+        AnthropicError::TokenBudgetExceeded {
+            input_tokens,
+            max_input_tokens,
+        } => {
+            assert_eq!(input_tokens, 101);
+            assert_eq!(max_input_tokens, 100);
+        }
+        e => panic!("Expected token budget error, got: {:?}", e),
+    }
+
+    // The messages endpoint is never called
+    let requests = transport.get_requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].1.ends_with("/v1/messages/count_tokens"));
+}
+
+#[tokio::test]
+async fn test_create_within_budget_reuses_count() {
+    let message_json = serde_json::to_string(&create_test_message()).unwrap();
+    let transport = budget_transport(100);
+    transport
+        .responses
+        .lock()
+        .unwrap()
+        .insert(0, create_success_response(&message_json));
+    let service = create_test_service(transport.clone());
+
+    service.create_within_budget(budget_request(), 100).await.unwrap();
+    service.create_within_budget(budget_request(), 100).await.unwrap();
+
+    let urls: Vec<String> = transport
+        .get_requests()
+        .into_iter()
+        .map(|(_, url, _, _)| url)
+        .collect();
+    assert_eq!(urls.len(), 3);
+    assert_eq!(
+        urls.iter().filter(|url| url.ends_with("/count_tokens")).count(),
+        1
+    );
+}
+
+// ============================================================================
+// Tests: Model Fallback
+// ============================================================================
+
+const PRIMARY_MODEL: &str = "claude-3-opus-20240229";
+const SECONDARY_MODEL: &str = "claude-3-5-haiku-20241022";
+
+fn fallback_service(transport: Arc<MockHttpTransport>) -> MessagesServiceImpl {
+    let config = AnthropicConfig::builder()
+        .api_key(secrecy::SecretString::new("sk-ant-test123456789012345".to_string()))
+        .model_fallback(
+            ModelFallbackPolicy::new([SECONDARY_MODEL]).with_rate_limit_retries(1, Duration::ZERO),
+        )
+        .build()
+        .unwrap();
+    MessagesServiceImpl::from_config(transport, Arc::new(MockAuthManager::new()), &config)
+        .unwrap()
+}
+
+fn fallback_request() -> CreateMessageRequest {
+    CreateMessageRequest::new(PRIMARY_MODEL, 1024, vec![MessageParam::user("Hello!")])
+        .with_system("You are a helpful assistant")
+}
+
+fn secondary_message_json() -> String {
+    let mut message = create_test_message();
+    message.model = SECONDARY_MODEL.to_string();
+    serde_json::to_string(&message).unwrap()
+}
+
+fn sent_body(request: &(Method, String, HeaderMap, Option<Vec<u8>>)) -> serde_json::Value {
+    serde_json::from_slice(request.3.as_ref().unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn test_overloaded_primary_falls_back_to_secondary() {
+    // Responses are served last-in first-out: the overload, then the success
+    let transport = Arc::new(
+        MockHttpTransport::new()
+            .with_response(create_success_response(&secondary_message_json()))
+            .with_response(create_error_response(529, "overloaded_error", "Overloaded")),
+    );
+    let metrics = Arc::new(InMemoryMetricsCollector::new());
+    let service = fallback_service(transport.clone()).with_metrics(metrics.clone());
+
+    let message = service.create(fallback_request()).await.unwrap();
+    assert_eq!(message.model, SECONDARY_MODEL);
+
+    let requests = transport.get_requests();
+    assert_eq!(requests.len(), 2);
+    let (primary, secondary) = (sent_body(&requests[0]), sent_body(&requests[1]));
+    assert_eq!(primary["model"], PRIMARY_MODEL);
+    assert_eq!(secondary["model"], SECONDARY_MODEL);
+    assert_eq!(secondary["messages"], primary["messages"]);
+    assert_eq!(secondary["system"], "You are a helpful assistant");
+
+    assert_eq!(metrics.get_counter(metric_names::MODEL_FALLBACKS), 1);
+}
+
+#[tokio::test]
+async fn test_persistent_rate_limit_falls_back_after_retries() {
+    let transport = Arc::new(
+        MockHttpTransport::new()
+            .with_response(create_success_response(&secondary_message_json()))
+            .with_response(create_error_response(429, "rate_limit_error", "Rate limited"))
+            .with_response(create_error_response(429, "rate_limit_error", "Rate limited")),
+    );
+    let service = fallback_service(transport.clone());
+
+    let message = service.create(fallback_request()).await.unwrap();
+    assert_eq!(message.model, SECONDARY_MODEL);
+
+    let models: Vec<serde_json::Value> = transport
+        .get_requests()
+        .iter()
+        .map(|request| sent_body(request)["model"].clone())
+        .collect();
+    assert_eq!(models, vec![PRIMARY_MODEL, PRIMARY_MODEL, SECONDARY_MODEL]);
+}
+
+#[tokio::test]
+async fn test_client_error_does_not_fall_back() {
+    let transport = Arc::new(
+        MockHttpTransport::new()
+            .with_response(create_success_response(&secondary_message_json()))
+            .with_response(create_error_response(400, "invalid_request_error", "Bad request")),
+    );
+    let service = fallback_service(transport.clone());
+
+    let result = service.create(fallback_request()).await;
+    assert!(matches!(result, Err(AnthropicError::Api { status: 400, .. })));
+    assert_eq!(transport.get_requests().len(), 1);
+}
+
+// ============================================================================
+// Tests: Prompt Cache Usage
+// ============================================================================
+
+#[tokio::test]
+async fn test_create_message_reports_cache_usage() {
+    let mut message = create_test_message();
+    message.usage.cache_creation_input_tokens = Some(200);
+    message.usage.cache_read_input_tokens = Some(1800);
+    let response_json = serde_json::to_string(&message).unwrap();
+
+    let transport = Arc::new(
+        MockHttpTransport::new().with_response(create_success_response(&response_json))
+    );
+    let metrics = Arc::new(InMemoryMetricsCollector::new());
+    let service = create_test_service(transport).with_metrics(metrics.clone());
+
+    let request = CreateMessageRequest::new(
+        "claude-3-5-sonnet-20241022",
+        1024,
+        vec![MessageParam::user("Hello!")],
+    );
+    let result = service.create(request).await.unwrap();
+
+    let cache = result.usage.cache_usage().unwrap();
+    assert_eq!(
+        cache,
+        CacheUsage {
+            cache_creation_input_tokens: 200,
+            cache_read_input_tokens: 1800,
+        }
+    );
+    assert_eq!(cache.hit_ratio(), 0.9);
+
+    let key = |name: &str| format!("{}:model=claude-3-5-sonnet-20241022", name);
+    assert_eq!(metrics.get_counter(&key(metric_names::TOKENS_CACHE_CREATION)), 200);
+    assert_eq!(metrics.get_counter(&key(metric_names::TOKENS_CACHE_READ)), 1800);
+    assert_eq!(metrics.get_counter(&key(metric_names::TOKENS_INPUT)), 10);
+    assert_eq!(metrics.get_counter(&key(metric_names::TOKENS_OUTPUT)), 5);
+}
+
+#[tokio::test]
+async fn test_create_message_without_cache_fields() {
+    let response_json = r#"{
+        "id": "msg_123",
+        "type": "message",
+        "role": "assistant",
+        "content": [{"type": "text", "text": "Hello!"}],
+        "model": "claude-3-5-sonnet-20241022",
+        "stop_reason": "end_turn",
+        "usage": {"input_tokens": 10, "output_tokens": 5}
+    }"#;
+
+    let transport = Arc::new(
+        MockHttpTransport::new().with_response(create_success_response(response_json))
+    );
+    let metrics = Arc::new(InMemoryMetricsCollector::new());
+    let service = create_test_service(transport).with_metrics(metrics.clone());
+
+    let request = CreateMessageRequest::new(
+        "claude-3-5-sonnet-20241022",
+        1024,
+        vec![MessageParam::user("Hello!")],
+    );
+    let result = service.create(request).await.unwrap();
+
+    assert_eq!(result.usage.cache_creation_input_tokens, None);
+    assert_eq!(result.usage.cache_read_input_tokens, None);
+    assert!(result.usage.cache_usage().is_none());
+
+    let key = |name: &str| format!("{}:model=claude-3-5-sonnet-20241022", name);
+    assert_eq!(metrics.get_counter(&key(metric_names::TOKENS_CACHE_READ)), 0);
+    assert_eq!(metrics.get_counter(&key(metric_names::TOKENS_INPUT)), 10);
+}
+
+// ============================================================================
+// Tests: Beta Headers
+// ============================================================================
+
+async fn sent_beta_header(request: CreateMessageRequest) -> Option<String> {
+    let response_json = serde_json::to_string(&create_test_message()).unwrap();
+    let transport = Arc::new(
+        MockHttpTransport::new().with_response(create_success_response(&response_json))
+    );
+    let service = create_test_service(transport.clone());
+
+    service.create(request).await.unwrap();
+
+    let requests = transport.get_requests();
+    requests[0]
+        .2
+        .get("anthropic-beta")
+        .map(|value| value.to_str().unwrap().to_string())
+}
+
+fn beta_request() -> CreateMessageRequest {
+    CreateMessageRequest::new(
+        "claude-3-5-sonnet-20241022",
+        1024,
+        vec![MessageParam::user("Hello")],
+    )
+}
+
+#[tokio::test]
+async fn test_beta_header_two_features() {
+    let request = beta_request()
+        .with_beta_features(&[BetaFeature::PromptCaching, BetaFeature::PdfSupport])
+        .unwrap();
+
+    assert_eq!(
+        sent_beta_header(request).await.as_deref(),
+        Some("pdfs-2024-09-25,prompt-caching-2024-07-31")
+    );
+}
+
+#[tokio::test]
+async fn test_beta_header_three_features_deduped() {
+    let request = beta_request()
+        .with_beta_features(&[
+            BetaFeature::TokenCounting,
+            BetaFeature::PdfSupport,
+            BetaFeature::PromptCaching,
+        ])
+        .unwrap()
+        .with_beta_features(&[BetaFeature::PdfSupport])
+        .unwrap();
+
+    assert_eq!(
+        sent_beta_header(request).await.as_deref(),
+        Some("pdfs-2024-09-25,prompt-caching-2024-07-31,token-counting-2024-11-01")
+    );
+}
+
+#[tokio::test]
+async fn test_beta_header_absent_without_features() {
+    assert_eq!(sent_beta_header(beta_request()).await, None);
+}
+
+#[test]
+fn test_beta_header_order_is_stable() {
+    let forward = compose_beta_header(vec![
+        "prompt-caching-2024-07-31".to_string(),
+        "pdfs-2024-09-25".to_string(),
+    ])
+    .unwrap();
+    let reverse = compose_beta_header(vec![
+        "pdfs-2024-09-25".to_string(),
+        "prompt-caching-2024-07-31".to_string(),
+    ])
+    .unwrap();
+
+    assert_eq!(forward, reverse);
+}
+
+#[test]
+fn test_conflicting_beta_versions_rejected() {
+    let result = beta_request().with_beta_features(&[
+        BetaFeature::PdfSupport,
+        BetaFeature::Custom("pdfs-2025-01-01".to_string()),
+    ]);
+
+    assert!(matches!(result, Err(AnthropicError::Configuration(_))));
+}
+
+// ============================================================================
+// Tests: Stream Accumulator
+// ============================================================================
+
+/// Recorded SSE transcript of a response with thinking, text and a tool call
+const RECORDED_TRANSCRIPT: &str = r#"event: message_start
+data: {"type":"message_start","message":{"id":"msg_01XFDUDYJgAACzvnptvVoYEL","type":"message","role":"assistant","content":[],"model":"claude-3-7-sonnet-20250219","stop_reason":null,"stop_sequence":null,"usage":{"input_tokens":472,"output_tokens":2,"cache_creation_input_tokens":0,"cache_read_input_tokens":1024}}}
+
+event: content_block_start
+data: {"type":"content_block_start","index":0,"content_block":{"type":"thinking","thinking":"","signature":""}}
+
+event: content_block_delta
+data: {"type":"content_block_delta","index":0,"delta":{"type":"thinking_delta","thinking":"The user wants the weather, "}}
+
+event: content_block_delta
+data: {"type":"content_block_delta","index":0,"delta":{"type":"thinking_delta","thinking":"so I should call the tool."}}
+
+event: content_block_delta
+data: {"type":"content_block_delta","index":0,"delta":{"type":"signature_delta","signature":"EqQBCgIYAhIM1gbcDa9GJwZA2b3hGgxBdjrkzLoky3dl1pkiMOYds"}}
+
+event: content_block_stop
+data: {"type":"content_block_stop","index":0}
+
+event: content_block_start
+data: {"type":"content_block_start","index":1,"content_block":{"type":"text","text":""}}
+
+event: ping
+data: {"type": "ping"}
+
+event: content_block_delta
+data: {"type":"content_block_delta","index":1,"delta":{"type":"text_delta","text":"Let me check "}}
+
+event: content_block_delta
+data: {"type":"content_block_delta","index":1,"delta":{"type":"text_delta","text":"the weather."}}
+
+event: content_block_stop
+data: {"type":"content_block_stop","index":1}
+
+event: content_block_start
+data: {"type":"content_block_start","index":2,"content_block":{"type":"tool_use","id":"toolu_01T1x1fJ34qAmk2tNTrN7Up6","name":"get_weather","input":{}}}
+
+event: content_block_delta
+data: {"type":"content_block_delta","index":2,"delta":{"type":"input_json_delta","partial_json":""}}
+
+event: content_block_delta
+data: {"type":"content_block_delta","index":2,"delta":{"type":"input_json_delta","partial_json":"{\"location\": \"San Fra"}}
+
+event: content_block_delta
+data: {"type":"content_block_delta","index":2,"delta":{"type":"input_json_delta","partial_json":"ncisco, CA\", \"unit\": \"fah"}}
+
+event: content_block_delta
+data: {"type":"content_block_delta","index":2,"delta":{"type":"input_json_delta","partial_json":"renheit\"}"}}
+
+event: content_block_stop
+data: {"type":"content_block_stop","index":2}
+
+event: message_delta
+data: {"type":"message_delta","delta":{"stop_reason":"tool_use","stop_sequence":null},"usage":{"output_tokens":89}}
+
+event: message_stop
+data: {"type":"message_stop"}
+
+"#;
+
+/// The same response as returned by a non-streaming request
+const RECORDED_MESSAGE: &str = r#"{
+    "id": "msg_01XFDUDYJgAACzvnptvVoYEL",
+    "type": "message",
+    "role": "assistant",
+    "content": [
+        {"type": "thinking", "thinking": "The user wants the weather, so I should call the tool.", "signature": "EqQBCgIYAhIM1gbcDa9GJwZA2b3hGgxBdjrkzLoky3dl1pkiMOYds"},
+        {"type": "text", "text": "Let me check the weather."},
+        {"type": "tool_use", "id": "toolu_01T1x1fJ34qAmk2tNTrN7Up6", "name": "get_weather", "input": {"location": "San Francisco, CA", "unit": "fahrenheit"}}
+    ],
+    "model": "claude-3-7-sonnet-20250219",
+    "stop_reason": "tool_use",
+    "stop_sequence": null,
+    "usage": {"input_tokens": 472, "output_tokens": 89, "cache_creation_input_tokens": 0, "cache_read_input_tokens": 1024}
+}"#;
+
+/// Replay a transcript split into arbitrary chunks, as the network would
+fn replay_transcript(transcript: &str, chunk_size: usize) -> MessageStream {
+    let chunks = transcript
+        .as_bytes()
+        .chunks(chunk_size)
+        .map(|chunk| Ok(Bytes::copy_from_slice(chunk)))
+        .collect::<Vec<_>>();
+
+    MessageStream::new(Box::new(stream::iter(chunks)))
+}
+
+#[tokio::test]
+async fn test_accumulator_matches_non_streamed_message() {
+    let expected: Message = serde_json::from_str(RECORDED_MESSAGE).unwrap();
+
+    for chunk_size in [7, 64, RECORDED_TRANSCRIPT.len()] {
+        let message = replay_transcript(RECORDED_TRANSCRIPT, chunk_size)
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(message, expected);
+        assert!(matches!(
+            &message.content[0],
+            ContentBlock::Thinking { signature: Some(signature), .. } if !signature.is_empty()
+        ));
+    }
+}
+
+#[tokio::test]
+async fn test_accumulator_yields_deltas_then_message() {
+    use futures::StreamExt;
+
+    let expected: Message = serde_json::from_str(RECORDED_MESSAGE).unwrap();
+    let events = replay_transcript(RECORDED_TRANSCRIPT, 32)
+        .accumulate()
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    let text: String = events
+        .iter()
+        .filter_map(|event| match event {
+            AccumulatorEvent::Event(MessageStreamEvent::ContentBlockDelta { index: 1, delta }) => {
+                delta.text.clone()
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(text, "Let me check the weather.");
+
+    assert!(matches!(
+        events.first(),
+        Some(AccumulatorEvent::Event(MessageStreamEvent::MessageStart { .. }))
+    ));
+    assert_eq!(events.last(), Some(&AccumulatorEvent::Message(expected)));
+}
+
+#[test]
+fn test_accumulator_final_usage_and_stop_reason() {
+    let mut accumulator = StreamAccumulator::new();
+    let events: Vec<MessageStreamEvent> = vec![
+        MessageStreamEvent::MessageStart {
+            message: PartialMessage {
+                id: "msg_123".to_string(),
+                message_type: "message".to_string(),
+                role: Role::Assistant,
+                model: "claude-3-5-sonnet-20241022".to_string(),
+                usage: Usage {
+                    input_tokens: 25,
+                    output_tokens: 1,
+                    cache_creation_input_tokens: Some(100),
+                    cache_read_input_tokens: None,
+                },
+            },
+        },
+        MessageStreamEvent::MessageDelta {
+            delta: MessageDelta {
+                stop_reason: Some(StopReason::MaxTokens),
+                stop_sequence: None,
+            },
+            usage: serde_json::from_str(r#"{"output_tokens": 1024}"#).unwrap(),
+        },
+    ];
+
+    for event in &events {
+        assert!(accumulator.push(event).unwrap().is_none());
+    }
+
+    let message = accumulator
+        .push(&MessageStreamEvent::MessageStop)
+        .unwrap()
+        .unwrap();
+    assert_eq!(message.stop_reason, Some(StopReason::MaxTokens));
+    assert!(message.content.is_empty());
+    assert_eq!(
+        message.usage,
+        Usage {
+            input_tokens: 25,
+            output_tokens: 1024,
+            cache_creation_input_tokens: Some(100),
+            cache_read_input_tokens: None,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_accumulator_rejects_truncated_tool_input() {
+    let transcript = RECORDED_TRANSCRIPT.replace(r#"renheit\"}"#, r#"renh"#);
+
+    let result = replay_transcript(&transcript, 64).collect().await;
+    assert!(matches!(result, Err(AnthropicError::Stream(_))));
+}
+
+#[tokio::test]
+async fn test_accumulator_requires_message_stop() {
+    let transcript = &RECORDED_TRANSCRIPT[..RECORDED_TRANSCRIPT.find("event: message_delta").unwrap()];
+
+    let result = replay_transcript(transcript, 64).collect().await;
+    assert!(matches!(result, Err(AnthropicError::Stream(_))));
+}
+
 // ============================================================================
 // Tests: Type Conversions and Builders
 // ============================================================================
@@ -1,6 +1,9 @@
 //! Type definitions for the Messages API
 
+use crate::config::BetaFeature;
+use crate::error::AnthropicError;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// A complete message response from the API
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -50,6 +53,8 @@ pub enum StopReason {
 /// Token usage information
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Usage {
+    /// Omitted from `message_delta` events, which only report output tokens
+    #[serde(default)]
     pub input_tokens: u32,
     pub output_tokens: u32,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -58,6 +63,41 @@ pub struct Usage {
     pub cache_read_input_tokens: Option<u32>,
 }
 
+impl Usage {
+    /// Prompt cache statistics, if the response reported any
+    ///
+    /// Models without prompt caching omit both fields, in which case this
+    /// returns `None`.
+    pub fn cache_usage(&self) -> Option<CacheUsage> {
+        if self.cache_creation_input_tokens.is_none() && self.cache_read_input_tokens.is_none() {
+            return None;
+        }
+        Some(CacheUsage {
+            cache_creation_input_tokens: self.cache_creation_input_tokens.unwrap_or(0),
+            cache_read_input_tokens: self.cache_read_input_tokens.unwrap_or(0),
+        })
+    }
+}
+
+/// Cache usage statistics
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CacheUsage {
+    pub cache_creation_input_tokens: u32,
+    pub cache_read_input_tokens: u32,
+}
+
+impl CacheUsage {
+    /// Fraction of cacheable prompt tokens served from the cache
+    pub fn hit_ratio(&self) -> f64 {
+        let total = self.cache_creation_input_tokens as u64 + self.cache_read_input_tokens as u64;
+        if total == 0 {
+            0.0
+        } else {
+            self.cache_read_input_tokens as f64 / total as f64
+        }
+    }
+}
+
 /// Content block in a message
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -92,6 +132,8 @@ pub enum ContentBlock {
     },
     Thinking {
         thinking: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        signature: Option<String>,
     },
 }
 
@@ -106,6 +148,8 @@ pub struct ContentDelta {
     pub partial_json: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thinking: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
 }
 
 /// Message delta during streaming
@@ -243,6 +287,9 @@ pub struct CreateMessageRequest {
     pub stream: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thinking: Option<ThinkingConfig>,
+    /// Beta features enabled for this request only (sent as `anthropic-beta`)
+    #[serde(skip)]
+    pub beta_features: Vec<BetaFeature>,
 }
 
 impl CreateMessageRequest {
@@ -266,6 +313,7 @@ impl CreateMessageRequest {
             metadata: None,
             stream: None,
             thinking: None,
+            beta_features: Vec::new(),
         }
     }
 
@@ -334,6 +382,74 @@ impl CreateMessageRequest {
         self.thinking = Some(thinking);
         self
     }
+
+    /// Enable beta features for this request
+    ///
+    /// The features are combined with any configured on the client into a
+    /// single `anthropic-beta` header. Fails if two features request different
+    /// versions of the same beta.
+    pub fn with_beta_features(mut self, features: &[BetaFeature]) -> Result<Self, AnthropicError> {
+        self.beta_features.extend_from_slice(features);
+        compose_beta_header(self.beta_features.iter().map(BetaFeature::header_value))?;
+        Ok(self)
+    }
+}
+
+/// Combine beta tokens into a single `anthropic-beta` header value
+///
+/// Tokens are deduplicated and sorted so the same set of features always
+/// produces the same header, regardless of the order they were enabled in.
+/// Returns `None` if there are no tokens.
+pub fn compose_beta_header<I>(tokens: I) -> Result<Option<String>, AnthropicError>
+where
+    I: IntoIterator<Item = String>,
+{
+    // Keyed by beta name so two versions of the same beta are detected
+    let mut by_name: BTreeMap<String, String> = BTreeMap::new();
+
+    for token in tokens {
+        let token = token.trim();
+        if token.is_empty() {
+            continue;
+        }
+
+        let name = beta_name(token);
+        match by_name.get(name) {
+            Some(existing) if existing != token => {
+                return Err(AnthropicError::Configuration(format!(
+                    "Conflicting beta features: {} and {}",
+                    existing, token
+                )));
+            }
+            Some(_) => {}
+            None => {
+                by_name.insert(name.to_string(), token.to_string());
+            }
+        }
+    }
+
+    if by_name.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(by_name.into_values().collect::<Vec<_>>().join(",")))
+}
+
+/// Strip the trailing `-YYYY-MM-DD` version from a beta token
+fn beta_name(token: &str) -> &str {
+    let Some(split) = token.len().checked_sub(11) else {
+        return token;
+    };
+    match (token.get(..split), token.get(split..)) {
+        (Some(name), Some(version))
+            if version
+                .char_indices()
+                .all(|(i, c)| if matches!(i, 0 | 5 | 8) { c == '-' } else { c.is_ascii_digit() }) =>
+        {
+            name
+        }
+        _ => token,
+    }
 }
 
 /// Message parameter for requests
@@ -470,6 +586,19 @@ impl CountTokensRequest {
     }
 }
 
+impl From<&CreateMessageRequest> for CountTokensRequest {
+    /// Build the count request covering the messages, system prompt, and tools
+    /// that a message request would send
+    fn from(request: &CreateMessageRequest) -> Self {
+        Self {
+            model: request.model.clone(),
+            messages: request.messages.clone(),
+            system: request.system.clone(),
+            tools: request.tools.clone(),
+        }
+    }
+}
+
 /// Token count response
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct TokenCount {
@@ -1,12 +1,14 @@
 //! HTTP transport implementations.
 
 use crate::errors::{AnthropicError, AnthropicResult};
+use crate::resilience::{RateLimitHeaders, RateLimiter};
 use async_trait::async_trait;
 use bytes::Bytes;
 use futures::stream::Stream;
 use http::{HeaderMap, Method, Request, Response, StatusCode};
 use reqwest::Client;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::time::Duration;
 use url::Url;
 
@@ -36,6 +38,7 @@ pub trait HttpTransport: Send + Sync {
 pub struct ReqwestTransport {
     client: Client,
     timeout: Duration,
+    rate_limiter: Option<Arc<RateLimiter>>,
 }
 
 impl ReqwestTransport {
@@ -48,7 +51,25 @@ impl ReqwestTransport {
                 message: format!("Failed to create HTTP client: {}", e),
             })?;
 
-        Ok(Self { client, timeout })
+        Ok(Self {
+            client,
+            timeout,
+            rate_limiter: None,
+        })
+    }
+
+    /// Feed the `anthropic-ratelimit-*` headers of every successful response
+    /// to the given rate limiter, usually the resilience orchestrator's
+    pub fn with_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Report a successful response's rate limit headers
+    fn record_rate_limits(&self, headers: &HeaderMap) {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.update_from_headers(&RateLimitHeaders::from_headers(headers));
+        }
     }
 
     /// Convert HTTP method to reqwest method
@@ -105,8 +126,9 @@ impl HttpTransport for ReqwestTransport {
 
         // Check for HTTP errors
         if !status.is_success() {
-            return Err(self.map_http_error(status, &body_bytes));
+            return Err(self.map_http_error(status, &response_headers, &body_bytes));
         }
+        self.record_rate_limits(&response_headers);
 
         // Convert to http::Response
         let mut http_response = Response::builder()
@@ -147,9 +169,11 @@ impl HttpTransport for ReqwestTransport {
 
         if !response.status().is_success() {
             let status = response.status();
+            let response_headers = response.headers().clone();
             let body = response.bytes().await?;
-            return Err(self.map_http_error(status, &body));
+            return Err(self.map_http_error(status, &response_headers, &body));
         }
+        self.record_rate_limits(response.headers());
 
         let stream = response.bytes_stream();
         let mapped_stream = Box::pin(futures::stream::unfold(stream, |mut stream| async move {
@@ -171,20 +195,20 @@ impl HttpTransport for ReqwestTransport {
 }
 
 impl ReqwestTransport {
-    fn map_http_error(&self, status: reqwest::StatusCode, body: &Bytes) -> AnthropicError {
+    fn map_http_error(
+        &self,
+        status: reqwest::StatusCode,
+        headers: &HeaderMap,
+        body: &Bytes,
+    ) -> AnthropicError {
         let body_str = String::from_utf8_lossy(body);
 
         match status.as_u16() {
             401 => AnthropicError::Authentication {
                 message: format!("Authentication failed: {}", body_str),
             },
-            429 => {
-                // Try to parse retry-after header
-                AnthropicError::RateLimit {
-                    message: format!("Rate limit exceeded: {}", body_str),
-                    retry_after: None, // Could parse from headers
-                }
-            }
+            429 => RateLimitHeaders::from_headers(headers)
+                .to_error(format!("Rate limit exceeded: {}", body_str)),
             404 => AnthropicError::NotFound {
                 message: body_str.to_string(),
                 resource_type: "resource".to_string(),
@@ -9,8 +9,9 @@ use crate::services::{FamilyRequest, UnifiedService};
 use crate::signing::{AwsSigner, BedrockSigner};
 use crate::streaming::EventStreamParser;
 use crate::types::{
-    detect_model_family, GetModelRequest, GetModelResponse, ListModelsRequest,
-    ListModelsResponse, ModelFamily, TitanEmbedRequest, TitanEmbedResponse,
+    detect_model_family, GetModelRequest, GetModelResponse, ListInferenceProfilesRequest,
+    ListInferenceProfilesResponse, ListModelsRequest,
+    GuardrailAction, GuardrailAssessment, GuardrailConfig, ListModelsResponse, ModelFamily, StopReason, TitanEmbedRequest, TitanEmbedResponse, TitanImageRequest,
     UnifiedInvokeRequest, UnifiedInvokeResponse, UnifiedStreamChunk, UsageInfo,
 };
 use async_stream::try_stream;
@@ -25,6 +26,51 @@ use std::time::Duration;
 use tracing::{debug, info, instrument, trace, warn};
 use url::Url;
 
+/// Response header carrying the input token count.
+pub const INPUT_TOKEN_COUNT_HEADER: &str = "x-amzn-bedrock-input-token-count";
+
+/// Response header carrying the output token count.
+pub const OUTPUT_TOKEN_COUNT_HEADER: &str = "x-amzn-bedrock-output-token-count";
+
+/// Token counts reported in Bedrock response headers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HeaderTokenCounts {
+    /// Input token count, if reported.
+    pub input_tokens: Option<u32>,
+    /// Output token count, if reported.
+    pub output_tokens: Option<u32>,
+}
+
+impl HeaderTokenCounts {
+    /// Read token counts from response headers.
+    pub fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+        let count = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse().ok())
+        };
+
+        Self {
+            input_tokens: count(INPUT_TOKEN_COUNT_HEADER),
+            output_tokens: count(OUTPUT_TOKEN_COUNT_HEADER),
+        }
+    }
+
+    /// Fill in counts the response body did not report.
+    pub fn fill(&self, usage: UsageInfo) -> UsageInfo {
+        let input_tokens = match usage.input_tokens {
+            0 => self.input_tokens.unwrap_or(0),
+            n => n,
+        };
+        let output_tokens = match usage.output_tokens {
+            0 => self.output_tokens.unwrap_or(0),
+            n => n,
+        };
+        UsageInfo::new(input_tokens, output_tokens)
+    }
+}
+
 /// Bedrock client trait defining the public API.
 #[async_trait]
 pub trait BedrockClient: Send + Sync {
@@ -40,11 +86,20 @@ pub trait BedrockClient: Send + Sync {
     /// Generate embeddings using Titan.
     async fn embed(&self, request: TitanEmbedRequest, model_id: &str) -> Result<TitanEmbedResponse, BedrockError>;
 
+    /// Generate images using Titan Image Generator, returning the decoded image bytes.
+    async fn generate_image(&self, request: TitanImageRequest, model_id: &str) -> Result<Vec<Bytes>, BedrockError>;
+
     /// List available foundation models.
     async fn list_models(&self, request: ListModelsRequest) -> Result<ListModelsResponse, BedrockError>;
 
     /// Get details for a specific model.
     async fn get_model(&self, model_id: &str) -> Result<GetModelResponse, BedrockError>;
+
+    /// List inference profiles available in the region.
+    async fn list_inference_profiles(
+        &self,
+        request: ListInferenceProfilesRequest,
+    ) -> Result<ListInferenceProfilesResponse, BedrockError>;
 }
 
 /// Bedrock client implementation.
@@ -79,36 +134,34 @@ impl BedrockClientImpl {
         })
     }
 
-    /// Build the invoke URL for a model.
+    /// Build the invoke URL for a model ID or inference profile ARN.
     fn build_invoke_url(&self, model_id: &str) -> String {
         format!(
             "{}/model/{}/invoke",
             self.config.runtime_endpoint(),
-            model_id
+            encode_model_id(model_id)
         )
     }
 
-    /// Build the invoke-with-response-stream URL for a model.
+    /// Build the invoke-with-response-stream URL for a model ID or inference profile ARN.
     fn build_stream_url(&self, model_id: &str) -> String {
         format!(
             "{}/model/{}/invoke-with-response-stream",
             self.config.runtime_endpoint(),
-            model_id
+            encode_model_id(model_id)
         )
     }
 
     /// Build the list foundation models URL.
     fn build_list_models_url(&self, params: &[(String, String)]) -> String {
-        let mut url = format!("{}/foundation-models", self.config.api_endpoint());
-        if !params.is_empty() {
-            let query: Vec<String> = params
-                .iter()
-                .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-                .collect();
-            url.push('?');
-            url.push_str(&query.join("&"));
-        }
-        url
+        let url = format!("{}/foundation-models", self.config.api_endpoint());
+        with_query(url, params)
+    }
+
+    /// Build the list inference profiles URL.
+    fn build_list_inference_profiles_url(&self, params: &[(String, String)]) -> String {
+        let url = format!("{}/inference-profiles", self.config.api_endpoint());
+        with_query(url, params)
     }
 
     /// Build the get foundation model URL.
@@ -125,17 +178,18 @@ impl BedrockClientImpl {
         format!(
             "{}/model/{}/invoke",
             self.config.runtime_endpoint(),
-            model_id
+            encode_model_id(model_id)
         )
     }
 
-    /// Execute a signed request.
+    /// Execute a signed request, applying the guardrail headers if given.
     async fn execute_request(
         &self,
         method: &str,
         url: &str,
         body: Option<&[u8]>,
         signer: &BedrockSigner,
+        guardrail: Option<&GuardrailConfig>,
     ) -> Result<Response, BedrockError> {
         let parsed_url = Url::parse(url).map_err(|e| {
             BedrockError::Configuration(crate::error::ConfigurationError::InvalidConfiguration {
@@ -147,6 +201,11 @@ impl BedrockClientImpl {
         let mut headers = HashMap::new();
         headers.insert("content-type".to_string(), "application/json".to_string());
         headers.insert("accept".to_string(), "application/json".to_string());
+        if let Some(guardrail) = guardrail {
+            for (name, value) in guardrail.headers() {
+                headers.insert(name.to_string(), value);
+            }
+        }
 
         let signed = signer.sign(method, &parsed_url, &headers, body).await?;
 
@@ -225,6 +284,7 @@ impl BedrockClientImpl {
         body: &[u8],
         model_id: &str,
         family: ModelFamily,
+        header_counts: HeaderTokenCounts,
     ) -> Result<UnifiedInvokeResponse, BedrockError> {
         let json: serde_json::Value = serde_json::from_slice(body).map_err(|e| {
             BedrockError::Stream(crate::error::StreamError::ParseError {
@@ -232,31 +292,33 @@ impl BedrockClientImpl {
             })
         })?;
 
-        match family {
+        let mut response = match family {
             ModelFamily::Titan => {
-                // Extract input token count from headers if available
+                // Titan only reports input tokens in headers
                 let titan_response = crate::services::titan::parse_response(&json)?;
-                Ok(crate::services::titan::translate_response(
+                crate::services::titan::translate_response(
                     titan_response,
                     model_id,
-                    0, // Token count from headers in real implementation
-                ))
+                    header_counts.input_tokens.unwrap_or(0),
+                )
             }
             ModelFamily::Claude => {
                 let claude_response = crate::services::claude::parse_response(&json)?;
-                Ok(crate::services::claude::translate_response(
-                    claude_response,
-                    model_id,
-                ))
+                crate::services::claude::translate_response(claude_response, model_id)
             }
             ModelFamily::Llama => {
                 let llama_response = crate::services::llama::parse_response(&json)?;
-                Ok(crate::services::llama::translate_response(
-                    llama_response,
-                    model_id,
-                ))
+                crate::services::llama::translate_response(llama_response, model_id)
             }
-        }
+            ModelFamily::TitanImage => {
+                return Err(crate::services::titan::image::text_generation_unsupported(model_id));
+            }
+        };
+
+        // Prefer body-reported usage, falling back to headers
+        response.usage = header_counts.fill(response.usage);
+        response.guardrail = GuardrailAssessment::from_body(&json);
+        Ok(response)
     }
 }
 
@@ -265,7 +327,8 @@ impl BedrockClient for BedrockClientImpl {
     #[instrument(skip(self, request), fields(model_id = %request.model_id))]
     async fn invoke(&self, request: UnifiedInvokeRequest) -> Result<UnifiedInvokeResponse, BedrockError> {
         let model_id = request.model_id.clone();
-        let family = detect_model_family(&model_id)?;
+        let family = request.family()?;
+        validate_guardrail(&request)?;
 
         // Translate request to family-specific format
         let family_request = UnifiedService::translate_request(&request)?;
@@ -280,12 +343,23 @@ impl BedrockClient for BedrockClientImpl {
 
         // Build URL and execute request
         let url = self.build_invoke_url(&model_id);
-        let response = self.execute_request("POST", &url, Some(&body), &self.runtime_signer).await?;
+        let guardrail = request.guardrail.as_ref();
+        let response = self
+            .execute_request("POST", &url, Some(&body), &self.runtime_signer, guardrail)
+            .await?;
 
         if !response.status().is_success() {
             return Err(self.parse_error_response(response, Some(&model_id)).await);
         }
 
+        // Token counts must be read before the body is consumed
+        let header_counts = HeaderTokenCounts::from_headers(response.headers());
+        let request_id = response
+            .headers()
+            .get("x-amzn-requestid")
+            .and_then(|v| v.to_str().ok())
+            .map(String::from);
+
         // Parse response
         let response_body = response.bytes().await.map_err(|e| {
             BedrockError::Network(NetworkError::ConnectionFailed {
@@ -293,7 +367,22 @@ impl BedrockClient for BedrockClientImpl {
             })
         })?;
 
-        self.parse_invoke_response(&response_body, &model_id, family)
+        let response = self.parse_invoke_response(&response_body, &model_id, family, header_counts)?;
+
+        if let Some(guardrail) = guardrail {
+            if response.guardrail_intervened() {
+                warn!(guardrail_id = %guardrail.identifier, "Guardrail intervened");
+                if guardrail.error_on_intervention {
+                    return Err(BedrockError::Request(crate::error::RequestError::GuardrailIntervened {
+                        guardrail_id: guardrail.identifier.clone(),
+                        message: response.content,
+                        request_id,
+                    }));
+                }
+            }
+        }
+
+        Ok(response)
     }
 
     fn invoke_stream(
@@ -303,7 +392,9 @@ impl BedrockClient for BedrockClientImpl {
         let model_id = request.model_id.clone();
 
         Box::pin(try_stream! {
-            let family = detect_model_family(&model_id)?;
+            let family = request.family()?;
+            let mut stream_state = StreamState::new(family, &model_id)?;
+            validate_guardrail(&request)?;
 
             // Translate request to family-specific format
             let family_request = UnifiedService::translate_request(&request)?;
@@ -327,6 +418,11 @@ impl BedrockClient for BedrockClientImpl {
             let mut headers = HashMap::new();
             headers.insert("content-type".to_string(), "application/json".to_string());
             headers.insert("accept".to_string(), "application/vnd.amazon.eventstream".to_string());
+            if let Some(guardrail) = &request.guardrail {
+                for (name, value) in guardrail.headers() {
+                    headers.insert(name.to_string(), value);
+                }
+            }
 
             let signed = self.runtime_signer.sign("POST", &parsed_url, &headers, Some(&body)).await?;
 
@@ -344,6 +440,12 @@ impl BedrockClient for BedrockClientImpl {
                 })
             })?;
 
+            let request_id = response
+                .headers()
+                .get("x-amzn-requestid")
+                .and_then(|v| v.to_str().ok())
+                .map(String::from);
+
             if !response.status().is_success() {
                 let error = self.parse_error_response(response, Some(&model_id)).await;
                 Err(error)?;
@@ -352,11 +454,8 @@ impl BedrockClient for BedrockClientImpl {
             // Parse event stream
             let mut parser = EventStreamParser::new();
             let mut stream = response.bytes_stream();
-            let mut stream_state = match family {
-                ModelFamily::Claude => StreamState::Claude(crate::services::claude::ClaudeStreamState::new()),
-                ModelFamily::Llama => StreamState::Llama(crate::services::llama::LlamaStreamState::new()),
-                ModelFamily::Titan => StreamState::Titan,
-            };
+            let mut content = String::new();
+            let mut assessment = None;
 
             use futures::StreamExt;
             while let Some(chunk_result) = stream.next().await {
@@ -386,7 +485,21 @@ impl BedrockClient for BedrockClientImpl {
                             // Parse the chunk payload
                             if let Ok(payload_str) = msg.payload_str() {
                                 if let Ok(json) = serde_json::from_str::<serde_json::Value>(payload_str) {
-                                    if let Some(unified_chunk) = process_stream_chunk(&mut stream_state, &json, family)? {
+                                    // The guardrail result rides on the closing chunk's payload
+                                    if let Some(result) = GuardrailAssessment::from_body(&json) {
+                                        assessment = Some(result);
+                                    }
+                                    if let Some(mut unified_chunk) = process_stream_chunk(&mut stream_state, &json, family)? {
+                                        content.push_str(&unified_chunk.delta);
+                                        if let (true, Some(guardrail)) = (unified_chunk.is_final, &request.guardrail) {
+                                            apply_stream_guardrail(
+                                                &mut unified_chunk,
+                                                guardrail,
+                                                assessment.take(),
+                                                &content,
+                                                request_id.clone(),
+                                            )?;
+                                        }
                                         yield unified_chunk;
                                     }
                                 }
@@ -418,7 +531,7 @@ impl BedrockClient for BedrockClientImpl {
         );
 
         let url = self.build_embed_url(model_id);
-        let response = self.execute_request("POST", &url, Some(&body), &self.runtime_signer).await?;
+        let response = self.execute_request("POST", &url, Some(&body), &self.runtime_signer, None).await?;
 
         if !response.status().is_success() {
             return Err(self.parse_error_response(response, Some(model_id)).await);
@@ -439,6 +552,53 @@ impl BedrockClient for BedrockClientImpl {
         crate::services::titan::parse_embed_response(&json)
     }
 
+    #[instrument(skip(self, request), fields(model_id = %model_id))]
+    async fn generate_image(&self, request: TitanImageRequest, model_id: &str) -> Result<Vec<Bytes>, BedrockError> {
+        if detect_model_family(model_id)? != ModelFamily::TitanImage {
+            return Err(BedrockError::Request(crate::error::RequestError::InvalidParameter {
+                parameter: "model_id".to_string(),
+                message: format!("{} is not an image generation model", model_id),
+            }));
+        }
+
+        crate::services::titan::image::validate_image_request(&request)?;
+
+        let body = serde_json::to_vec(&request).map_err(|e| {
+            BedrockError::Request(crate::error::RequestError::Validation {
+                message: format!("Failed to serialize image request: {}", e),
+                request_id: None,
+            })
+        })?;
+
+        debug!(
+            model_id = %model_id,
+            number_of_images = request.image_generation_config.number_of_images.unwrap_or(1),
+            "Generating images"
+        );
+
+        let url = self.build_invoke_url(model_id);
+        let response = self.execute_request("POST", &url, Some(&body), &self.runtime_signer, None).await?;
+
+        if !response.status().is_success() {
+            return Err(self.parse_error_response(response, Some(model_id)).await);
+        }
+
+        let response_body = response.bytes().await.map_err(|e| {
+            BedrockError::Network(NetworkError::ConnectionFailed {
+                message: format!("Failed to read response: {}", e),
+            })
+        })?;
+
+        let json: serde_json::Value = serde_json::from_slice(&response_body).map_err(|e| {
+            BedrockError::Stream(crate::error::StreamError::ParseError {
+                message: format!("Failed to parse image response: {}", e),
+            })
+        })?;
+
+        let image_response = crate::services::titan::image::parse_image_response(&json)?;
+        crate::services::titan::image::decode_images(image_response)
+    }
+
     #[instrument(skip(self))]
     async fn list_models(&self, request: ListModelsRequest) -> Result<ListModelsResponse, BedrockError> {
         let params = crate::services::models::build_list_query_params(&request);
@@ -446,7 +606,7 @@ impl BedrockClient for BedrockClientImpl {
 
         debug!("Listing foundation models");
 
-        let response = self.execute_request("GET", &url, None, &self.api_signer).await?;
+        let response = self.execute_request("GET", &url, None, &self.api_signer, None).await?;
 
         if !response.status().is_success() {
             return Err(self.parse_error_response(response, None).await);
@@ -471,7 +631,7 @@ impl BedrockClient for BedrockClientImpl {
 
         debug!(model_id = %model_id, "Getting model details");
 
-        let response = self.execute_request("GET", &url, None, &self.api_signer).await?;
+        let response = self.execute_request("GET", &url, None, &self.api_signer, None).await?;
 
         if !response.status().is_success() {
             return Err(self.parse_error_response(response, Some(model_id)).await);
@@ -489,6 +649,101 @@ impl BedrockClient for BedrockClientImpl {
             })
         })
     }
+
+    #[instrument(skip(self))]
+    async fn list_inference_profiles(
+        &self,
+        request: ListInferenceProfilesRequest,
+    ) -> Result<ListInferenceProfilesResponse, BedrockError> {
+        let params = crate::services::models::build_inference_profiles_query_params(&request);
+        let url = self.build_list_inference_profiles_url(&params);
+
+        debug!("Listing inference profiles");
+
+        let response = self.execute_request("GET", &url, None, &self.api_signer, None).await?;
+
+        if !response.status().is_success() {
+            return Err(self.parse_error_response(response, None).await);
+        }
+
+        let response_body = response.bytes().await.map_err(|e| {
+            BedrockError::Network(NetworkError::ConnectionFailed {
+                message: format!("Failed to read response: {}", e),
+            })
+        })?;
+
+        serde_json::from_slice(&response_body).map_err(|e| {
+            BedrockError::Stream(crate::error::StreamError::ParseError {
+                message: format!("Failed to parse list inference profiles response: {}", e),
+            })
+        })
+    }
+}
+
+/// Reject malformed guardrail settings before anything is sent.
+fn validate_guardrail(request: &UnifiedInvokeRequest) -> Result<(), BedrockError> {
+    if let Some(guardrail) = &request.guardrail {
+        guardrail.validate().map_err(|message| {
+            BedrockError::Request(crate::error::RequestError::InvalidParameter {
+                parameter: "guardrail".to_string(),
+                message,
+            })
+        })?;
+    }
+    Ok(())
+}
+
+/// Attach the guardrail result to the final stream chunk.
+///
+/// An intervention is reported as [`StopReason::GuardrailIntervened`], or as an
+/// error when the caller asked for one; `content` is the streamed output so far.
+fn apply_stream_guardrail(
+    chunk: &mut UnifiedStreamChunk,
+    guardrail: &GuardrailConfig,
+    assessment: Option<GuardrailAssessment>,
+    content: &str,
+    request_id: Option<String>,
+) -> Result<(), BedrockError> {
+    let intervened = chunk.stop_reason == Some(StopReason::GuardrailIntervened)
+        || assessment
+            .as_ref()
+            .map(|a| a.action == GuardrailAction::Intervened)
+            .unwrap_or(false);
+    chunk.guardrail = assessment;
+
+    if intervened {
+        warn!(guardrail_id = %guardrail.identifier, "Guardrail intervened");
+        chunk.stop_reason = Some(StopReason::GuardrailIntervened);
+        if guardrail.error_on_intervention {
+            return Err(BedrockError::Request(crate::error::RequestError::GuardrailIntervened {
+                guardrail_id: guardrail.identifier.clone(),
+                message: content.to_string(),
+                request_id,
+            }));
+        }
+    }
+    Ok(())
+}
+
+/// Percent-encode a model ID or ARN for use as a single path segment.
+///
+/// Colons are valid in a path segment and are kept, so plain model IDs such
+/// as `anthropic.claude-v2:1` are sent unchanged; the `/` in ARNs is encoded.
+fn encode_model_id(model_id: &str) -> String {
+    urlencoding::encode(model_id).replace("%3A", ":")
+}
+
+/// Append URL-encoded query parameters to a URL.
+fn with_query(mut url: String, params: &[(String, String)]) -> String {
+    if !params.is_empty() {
+        let query: Vec<String> = params
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
+            .collect();
+        url.push('?');
+        url.push_str(&query.join("&"));
+    }
+    url
 }
 
 /// Stream state for different model families.
@@ -498,6 +753,21 @@ enum StreamState {
     Llama(crate::services::llama::LlamaStreamState),
 }
 
+impl StreamState {
+    /// Initial state for a family, or an error for families that cannot stream text.
+    fn new(family: ModelFamily, model_id: &str) -> Result<Self, BedrockError> {
+        if family == ModelFamily::TitanImage {
+            return Err(crate::services::titan::image::text_generation_unsupported(model_id));
+        }
+
+        Ok(match family {
+            ModelFamily::Claude => StreamState::Claude(crate::services::claude::ClaudeStreamState::new()),
+            ModelFamily::Llama => StreamState::Llama(crate::services::llama::LlamaStreamState::new()),
+            _ => StreamState::Titan,
+        })
+    }
+}
+
 /// Process a streaming chunk based on model family.
 fn process_stream_chunk(
     state: &mut StreamState,
@@ -631,6 +901,437 @@ mod tests {
         assert!(url.contains("/invoke-with-response-stream"));
     }
 
+    #[tokio::test]
+    async fn test_inference_profile_arn_is_encoded() {
+        let config = BedrockConfig::builder()
+            .region("us-east-1")
+            .build()
+            .unwrap();
+        let provider = Arc::new(StaticCredentialsProvider::new(
+            AwsCredentials::new("AKID", "SECRET"),
+        ));
+        let client = BedrockClientImpl::new(config, provider).unwrap();
+        let arn = "arn:aws:bedrock:us-east-1:123456789012:application-inference-profile/a1b2c3d4e5f6";
+
+        let url = client.build_invoke_url(arn);
+        assert_eq!(
+            url,
+            "https://bedrock-runtime.us-east-1.amazonaws.com/model/arn:aws:bedrock:us-east-1:123456789012:application-inference-profile%2Fa1b2c3d4e5f6/invoke"
+        );
+        assert!(client
+            .build_stream_url(arn)
+            .ends_with("application-inference-profile%2Fa1b2c3d4e5f6/invoke-with-response-stream"));
+
+        // The profile ARN stays a single path segment through URL parsing and signing
+        let parsed = Url::parse(&url).unwrap();
+        assert_eq!(parsed.path_segments().unwrap().count(), 3);
+
+        let timestamp = chrono::TimeZone::with_ymd_and_hms(&chrono::Utc, 2024, 1, 15, 12, 0, 0).unwrap();
+        let signed = client
+            .runtime_signer
+            .sign_at("POST", &parsed, &HashMap::new(), Some(b"{}"), timestamp)
+            .await
+            .unwrap();
+        assert_eq!(signed.url, parsed);
+        assert_eq!(signed.headers["x-amz-date"], "20240115T120000Z");
+        // Signed over the canonical path /model/arn%3Aaws%3A...%3Aapplication-inference-profile%252F.../invoke
+        assert_eq!(
+            signed.headers["authorization"],
+            "AWS4-HMAC-SHA256 Credential=AKID/20240115/us-east-1/bedrock-runtime/aws4_request, \
+             SignedHeaders=host;x-amz-content-sha256;x-amz-date, \
+             Signature=67b31928b90159a4382a047f393477be157185d5bba3a917a936d75a8755d148"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_inference_profiles() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/inference-profiles"))
+            .and(query_param("typeEquals", "APPLICATION"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "inferenceProfileSummaries": [{
+                    "inferenceProfileName": "chat-team-a",
+                    "inferenceProfileArn": "arn:aws:bedrock:us-east-1:123456789012:application-inference-profile/a1b2c3d4e5f6",
+                    "inferenceProfileId": "a1b2c3d4e5f6",
+                    "models": [{
+                        "modelArn": "arn:aws:bedrock:us-east-1::foundation-model/anthropic.claude-3-haiku-20240307-v1:0"
+                    }],
+                    "status": "ACTIVE",
+                    "type": "APPLICATION"
+                }]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server.uri());
+        let response = client
+            .list_inference_profiles(ListInferenceProfilesRequest {
+                type_equals: Some(crate::types::InferenceProfileType::Application),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let profile = &response.inference_profile_summaries[0];
+        assert_eq!(profile.inference_profile_id, "a1b2c3d4e5f6");
+        assert_eq!(profile.model_family(), Some(ModelFamily::Claude));
+        assert!(response.next_token.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_fips_endpoint_is_signed() {
+        let config = BedrockConfig::builder()
+            .region("us-east-1")
+            .use_fips(true)
+            .build()
+            .unwrap();
+        let provider = Arc::new(StaticCredentialsProvider::new(
+            AwsCredentials::new("AKID", "SECRET"),
+        ));
+        let client = BedrockClientImpl::new(config, provider).unwrap();
+
+        let url = client.build_invoke_url("amazon.titan-text-express-v1");
+        assert!(url.starts_with("https://bedrock-runtime-fips.us-east-1.amazonaws.com/model/"));
+
+        let parsed = Url::parse(&url).unwrap();
+        let signed = client
+            .runtime_signer
+            .sign("POST", &parsed, &HashMap::new(), Some(b"{}"))
+            .await
+            .unwrap();
+        assert_eq!(
+            signed.headers.get("host").map(String::as_str),
+            Some("bedrock-runtime-fips.us-east-1.amazonaws.com")
+        );
+        assert!(signed.headers["authorization"].contains("/us-east-1/bedrock-runtime/aws4_request"));
+    }
+
+    fn mock_client(endpoint: &str) -> BedrockClientImpl {
+        let config = BedrockConfig::builder()
+            .region("us-east-1")
+            .endpoint_url(endpoint)
+            .build()
+            .unwrap();
+        let provider = Arc::new(StaticCredentialsProvider::new(
+            AwsCredentials::new("AKID", "SECRET"),
+        ));
+        BedrockClientImpl::new(config, provider).unwrap()
+    }
+
+    #[test]
+    fn test_header_token_counts_fill() {
+        let counts = HeaderTokenCounts {
+            input_tokens: Some(12),
+            output_tokens: Some(34),
+        };
+
+        let filled = counts.fill(UsageInfo::new(0, 0));
+        assert_eq!(filled.input_tokens, 12);
+        assert_eq!(filled.output_tokens, 34);
+        assert_eq!(filled.total_tokens, 46);
+
+        let body_reported = counts.fill(UsageInfo::new(100, 50));
+        assert_eq!(body_reported.input_tokens, 100);
+        assert_eq!(body_reported.output_tokens, 50);
+    }
+
+    #[tokio::test]
+    async fn test_invoke_reads_token_count_headers() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/model/amazon.titan-text-express-v1/invoke"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(INPUT_TOKEN_COUNT_HEADER, "17")
+                    .insert_header(OUTPUT_TOKEN_COUNT_HEADER, "5")
+                    .set_body_json(serde_json::json!({
+                        "results": [{
+                            "outputText": "Hello!",
+                            "completionReason": "FINISH"
+                        }]
+                    })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server.uri());
+        let response = client
+            .invoke(UnifiedInvokeRequest::new(
+                "amazon.titan-text-express-v1",
+                vec![crate::types::Message::user("Hi")],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.content, "Hello!");
+        assert_eq!(response.usage.input_tokens, 17);
+        assert_eq!(response.usage.output_tokens, 5);
+        assert_eq!(response.usage.total_tokens, 22);
+    }
+
+    #[tokio::test]
+    async fn test_invoke_prefers_body_usage() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/model/meta.llama3-8b-instruct-v1:0/invoke"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(INPUT_TOKEN_COUNT_HEADER, "99")
+                    .insert_header(OUTPUT_TOKEN_COUNT_HEADER, "7")
+                    .set_body_json(serde_json::json!({
+                        "generation": "Hi there",
+                        "prompt_token_count": 11,
+                        "stop_reason": "stop"
+                    })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server.uri());
+        let response = client
+            .invoke(UnifiedInvokeRequest::new(
+                "meta.llama3-8b-instruct-v1:0",
+                vec![crate::types::Message::user("Hi")],
+            ))
+            .await
+            .unwrap();
+
+        // Prompt tokens come from the body; generation tokens fall back to the header
+        assert_eq!(response.usage.input_tokens, 11);
+        assert_eq!(response.usage.output_tokens, 7);
+    }
+
+    #[tokio::test]
+    async fn test_invoke_signs_guardrail_headers() {
+        use crate::types::{GuardrailAction, GuardrailTrace};
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/model/meta.llama3-8b-instruct-v1:0/invoke"))
+            .and(header("x-amzn-bedrock-guardrailidentifier", "gr-abc123"))
+            .and(header("x-amzn-bedrock-guardrailversion", "3"))
+            .and(header("x-amzn-bedrock-trace", "ENABLED"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "generation": "Hello there",
+                "prompt_token_count": 8,
+                "generation_token_count": 3,
+                "stop_reason": "stop",
+                "amazon-bedrock-guardrailAction": "NONE"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server.uri());
+        let response = client
+            .invoke(
+                UnifiedInvokeRequest::new(
+                    "meta.llama3-8b-instruct-v1:0",
+                    vec![crate::types::Message::user("Hi")],
+                )
+                .with_guardrail("gr-abc123", "3", GuardrailTrace::Enabled),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.guardrail.unwrap().action, GuardrailAction::Passed);
+
+        // wiremock splits the comma-separated authorization value, so check it whole
+        let requests = server.received_requests().await.unwrap();
+        let authorization = requests[0].headers.get(&"authorization".into()).unwrap().to_string();
+        assert!(authorization.contains(
+            "x-amzn-bedrock-guardrailidentifier;x-amzn-bedrock-guardrailversion;x-amzn-bedrock-trace"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_invoke_surfaces_guardrail_intervention() {
+        use crate::types::GuardrailTrace;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/model/meta.llama3-8b-instruct-v1:0/invoke"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-amzn-requestid", "req-42")
+                    .set_body_json(serde_json::json!({
+                        "generation": "Sorry, I can't help with that.",
+                        "prompt_token_count": 8,
+                        "generation_token_count": 7,
+                        "stop_reason": "stop",
+                        "amazon-bedrock-guardrailAction": "INTERVENED",
+                        "amazon-bedrock-trace": {
+                            "guardrail": { "input": { "gr-abc123": { "topicPolicy": {} } } }
+                        }
+                    })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server.uri());
+        let request = UnifiedInvokeRequest::new(
+            "meta.llama3-8b-instruct-v1:0",
+            vec![crate::types::Message::user("Something off-topic")],
+        )
+        .with_guardrail("gr-abc123", "DRAFT", GuardrailTrace::Enabled);
+
+        // Flagged on the response by default
+        let response = client.invoke(request.clone()).await.unwrap();
+        assert!(response.guardrail_intervened());
+        assert_eq!(response.content, "Sorry, I can't help with that.");
+        assert!(response.guardrail.unwrap().trace.unwrap()["input"]["gr-abc123"].is_object());
+
+        // Or a distinct error when requested
+        let result = client
+            .invoke(request.with_guardrail_error_on_intervention())
+            .await;
+        match result {
+            Err(BedrockError::Request(crate::error::RequestError::GuardrailIntervened {
+                guardrail_id,
+                message,
+                request_id,
+            })) => {
+                assert_eq!(guardrail_id, "gr-abc123");
+                assert_eq!(message, "Sorry, I can't help with that.");
+                assert_eq!(request_id.as_deref(), Some("req-42"));
+            }
+            other => panic!("Expected GuardrailIntervened, got {:?}", other),
+        }
+    }
+
+    /// Encode a JSON payload as an event stream message without headers.
+    fn event_frame(payload: serde_json::Value) -> Vec<u8> {
+        let payload = serde_json::to_vec(&payload).unwrap();
+        let total_len = (12 + payload.len() + 4) as u32;
+        let mut frame = Vec::new();
+        frame.extend_from_slice(&total_len.to_be_bytes());
+        frame.extend_from_slice(&0u32.to_be_bytes());
+        frame.extend_from_slice(&crc32c::crc32c(&frame).to_be_bytes());
+        frame.extend_from_slice(&payload);
+        frame.extend_from_slice(&crc32c::crc32c(&frame).to_be_bytes());
+        frame
+    }
+
+    #[tokio::test]
+    async fn test_invoke_stream_surfaces_guardrail_intervention() {
+        use crate::types::{GuardrailAction, GuardrailTrace};
+        use futures::StreamExt;
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let body = [
+            event_frame(serde_json::json!({
+                "generation": "Sorry, I can't help with that.",
+                "prompt_token_count": 8,
+                "generation_token_count": 7,
+                "stop_reason": null
+            })),
+            event_frame(serde_json::json!({
+                "generation": "",
+                "generation_token_count": 7,
+                "stop_reason": "stop",
+                "amazon-bedrock-guardrailAction": "INTERVENED",
+                "amazon-bedrock-trace": {
+                    "guardrail": { "input": { "gr-abc123": { "topicPolicy": {} } } }
+                }
+            })),
+        ]
+        .concat();
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/model/meta.llama3-8b-instruct-v1:0/invoke-with-response-stream"))
+            .and(header("x-amzn-bedrock-guardrailidentifier", "gr-abc123"))
+            .and(header("x-amzn-bedrock-guardrailversion", "DRAFT"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-amzn-requestid", "req-43")
+                    .set_body_raw(body, "application/vnd.amazon.eventstream"),
+            )
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server.uri());
+        let request = UnifiedInvokeRequest::new(
+            "meta.llama3-8b-instruct-v1:0",
+            vec![crate::types::Message::user("Something off-topic")],
+        )
+        .with_guardrail("gr-abc123", "DRAFT", GuardrailTrace::Enabled);
+
+        // Flagged on the final chunk by default
+        let chunks: Vec<UnifiedStreamChunk> = client
+            .invoke_stream(request.clone())
+            .map(|chunk| chunk.unwrap())
+            .collect()
+            .await;
+        let last = chunks.last().unwrap();
+        assert!(last.is_final);
+        assert_eq!(last.stop_reason, Some(StopReason::GuardrailIntervened));
+        let assessment = last.guardrail.as_ref().unwrap();
+        assert_eq!(assessment.action, GuardrailAction::Intervened);
+        assert!(assessment.trace.as_ref().unwrap()["input"]["gr-abc123"].is_object());
+
+        // Or a distinct error when requested
+        let results: Vec<_> = client
+            .invoke_stream(request.with_guardrail_error_on_intervention())
+            .collect()
+            .await;
+        match results.last().unwrap() {
+            Err(BedrockError::Request(crate::error::RequestError::GuardrailIntervened {
+                guardrail_id,
+                message,
+                request_id,
+            })) => {
+                assert_eq!(guardrail_id, "gr-abc123");
+                assert_eq!(message, "Sorry, I can't help with that.");
+                assert_eq!(request_id.as_deref(), Some("req-43"));
+            }
+            other => panic!("Expected GuardrailIntervened, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invoke_stream_rejects_invalid_guardrail() {
+        use crate::types::GuardrailTrace;
+        use futures::StreamExt;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(wiremock::matchers::any())
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server.uri());
+        let request = UnifiedInvokeRequest::new(
+            "meta.llama3-8b-instruct-v1:0",
+            vec![crate::types::Message::user("Hi")],
+        )
+        .with_guardrail("gr-abc123", "latest", GuardrailTrace::Disabled);
+
+        let first = client.invoke_stream(request).next().await.unwrap();
+        assert!(matches!(
+            first,
+            Err(BedrockError::Request(crate::error::RequestError::InvalidParameter { ref parameter, .. }))
+                if parameter == "guardrail"
+        ));
+    }
+
     #[test]
     fn test_builder() {
         let config = BedrockConfig::builder()
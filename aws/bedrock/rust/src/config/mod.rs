@@ -2,16 +2,36 @@
 
 use crate::credentials::{AwsCredentials, ChainCredentialsProvider, CredentialsProvider, StaticCredentialsProvider};
 use crate::error::{BedrockError, ConfigurationError};
+use std::fmt;
 use std::sync::Arc;
 use std::time::Duration;
 
+/// Bedrock service endpoint being resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndpointKind {
+    /// Bedrock Runtime (model invocation).
+    Runtime,
+    /// Bedrock control plane (model discovery).
+    Api,
+}
+
+/// Custom endpoint resolver, called with the endpoint kind and region.
+///
+/// Useful for VPC interface endpoints (PrivateLink), whose DNS names do not
+/// follow the public host pattern.
+pub type EndpointResolver = Arc<dyn Fn(EndpointKind, &str) -> String + Send + Sync>;
+
 /// Configuration for the Bedrock client.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct BedrockConfig {
     /// AWS region.
     pub region: String,
     /// Custom endpoint URL (for testing or custom deployments).
     pub endpoint_url: Option<String>,
+    /// Use FIPS 140-2 endpoints.
+    pub use_fips: bool,
+    /// Custom endpoint resolver, taking precedence over all other endpoint settings.
+    pub endpoint_resolver: Option<EndpointResolver>,
     /// Request timeout.
     pub timeout: Duration,
     /// Maximum retries.
@@ -32,20 +52,48 @@ impl BedrockConfig {
 
     /// Get the Bedrock Runtime endpoint URL.
     pub fn runtime_endpoint(&self) -> String {
-        if let Some(custom) = &self.endpoint_url {
-            custom.clone()
-        } else {
-            format!("https://bedrock-runtime.{}.amazonaws.com", self.region)
-        }
+        self.resolve_endpoint(EndpointKind::Runtime)
     }
 
     /// Get the Bedrock API endpoint URL (for model discovery).
     pub fn api_endpoint(&self) -> String {
+        self.resolve_endpoint(EndpointKind::Api)
+    }
+
+    /// Resolve an endpoint URL.
+    ///
+    /// A custom resolver wins, then an explicit endpoint URL, then the
+    /// public (or FIPS) host pattern for the region.
+    fn resolve_endpoint(&self, kind: EndpointKind) -> String {
+        if let Some(resolver) = &self.endpoint_resolver {
+            return resolver(kind, &self.region);
+        }
         if let Some(custom) = &self.endpoint_url {
-            custom.clone()
-        } else {
-            format!("https://bedrock.{}.amazonaws.com", self.region)
+            return custom.clone();
         }
+
+        let service = match kind {
+            EndpointKind::Runtime => "bedrock-runtime",
+            EndpointKind::Api => "bedrock",
+        };
+        let suffix = if self.use_fips { "-fips" } else { "" };
+        format!("https://{}{}.{}.amazonaws.com", service, suffix, self.region)
+    }
+}
+
+impl fmt::Debug for BedrockConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BedrockConfig")
+            .field("region", &self.region)
+            .field("endpoint_url", &self.endpoint_url)
+            .field("use_fips", &self.use_fips)
+            .field("endpoint_resolver", &self.endpoint_resolver.as_ref().map(|_| "<resolver>"))
+            .field("timeout", &self.timeout)
+            .field("max_retries", &self.max_retries)
+            .field("retry_delay", &self.retry_delay)
+            .field("stream_chunk_timeout", &self.stream_chunk_timeout)
+            .field("max_stream_duration", &self.max_stream_duration)
+            .finish()
     }
 }
 
@@ -54,6 +102,8 @@ impl Default for BedrockConfig {
         Self {
             region: "us-east-1".to_string(),
             endpoint_url: None,
+            use_fips: false,
+            endpoint_resolver: None,
             timeout: Duration::from_secs(60),
             max_retries: 3,
             retry_delay: Duration::from_millis(100),
@@ -64,10 +114,12 @@ impl Default for BedrockConfig {
 }
 
 /// Builder for BedrockConfig.
-#[derive(Debug, Default)]
+#[derive(Default)]
 pub struct BedrockConfigBuilder {
     region: Option<String>,
     endpoint_url: Option<String>,
+    use_fips: bool,
+    endpoint_resolver: Option<EndpointResolver>,
     timeout: Option<Duration>,
     max_retries: Option<u32>,
     retry_delay: Option<Duration>,
@@ -93,6 +145,21 @@ impl BedrockConfigBuilder {
         self
     }
 
+    /// Use FIPS 140-2 endpoints (`bedrock-runtime-fips.{region}.amazonaws.com`).
+    pub fn use_fips(mut self, use_fips: bool) -> Self {
+        self.use_fips = use_fips;
+        self
+    }
+
+    /// Set a custom resolver for the runtime and API endpoints.
+    pub fn endpoint_resolver<F>(mut self, resolver: F) -> Self
+    where
+        F: Fn(EndpointKind, &str) -> String + Send + Sync + 'static,
+    {
+        self.endpoint_resolver = Some(Arc::new(resolver));
+        self
+    }
+
     /// Set the request timeout.
     pub fn timeout(mut self, timeout: Duration) -> Self {
         self.timeout = Some(timeout);
@@ -155,9 +222,20 @@ impl BedrockConfigBuilder {
             ));
         }
 
+        if self.use_fips && !has_fips_endpoint(&region) {
+            return Err(BedrockError::Configuration(
+                ConfigurationError::InvalidConfiguration {
+                    field: "use_fips".to_string(),
+                    message: format!("region '{}' has no Bedrock FIPS endpoint", region),
+                },
+            ));
+        }
+
         Ok(BedrockConfig {
             region,
             endpoint_url: self.endpoint_url,
+            use_fips: self.use_fips,
+            endpoint_resolver: self.endpoint_resolver,
             timeout: self.timeout.unwrap_or(Duration::from_secs(60)),
             max_retries: self.max_retries.unwrap_or(3),
             retry_delay: self.retry_delay.unwrap_or(Duration::from_millis(100)),
@@ -204,6 +282,20 @@ pub fn is_bedrock_region(region: &str) -> bool {
     BEDROCK_REGIONS.contains(&region)
 }
 
+/// Regions with Bedrock FIPS endpoints.
+pub const BEDROCK_FIPS_REGIONS: &[&str] = &[
+    "us-east-1",
+    "us-west-2",
+    "ca-central-1",
+    "us-gov-east-1",
+    "us-gov-west-1",
+];
+
+/// Check if a region has Bedrock FIPS endpoints.
+pub fn has_fips_endpoint(region: &str) -> bool {
+    BEDROCK_FIPS_REGIONS.contains(&region)
+}
+
 /// Stream configuration.
 #[derive(Debug, Clone)]
 pub struct StreamConfig {
@@ -300,6 +392,63 @@ mod tests {
         assert_eq!(config.runtime_endpoint(), "http://localhost:4566");
     }
 
+    #[test]
+    fn test_fips_endpoints() {
+        let config = BedrockConfig::builder()
+            .region("us-west-2")
+            .use_fips(true)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            config.runtime_endpoint(),
+            "https://bedrock-runtime-fips.us-west-2.amazonaws.com"
+        );
+        assert_eq!(
+            config.api_endpoint(),
+            "https://bedrock-fips.us-west-2.amazonaws.com"
+        );
+    }
+
+    #[test]
+    fn test_fips_unsupported_region() {
+        let result = BedrockConfig::builder()
+            .region("eu-west-1")
+            .use_fips(true)
+            .build();
+
+        assert!(matches!(
+            result,
+            Err(BedrockError::Configuration(ConfigurationError::InvalidConfiguration { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_endpoint_resolver_overrides_endpoints() {
+        let config = BedrockConfig::builder()
+            .region("us-east-1")
+            .endpoint_url("http://localhost:4566")
+            .endpoint_resolver(|kind, region| match kind {
+                EndpointKind::Runtime => {
+                    format!("https://vpce-0abc-runtime.bedrock-runtime.{}.vpce.amazonaws.com", region)
+                }
+                EndpointKind::Api => {
+                    format!("https://vpce-0abc-api.bedrock.{}.vpce.amazonaws.com", region)
+                }
+            })
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            config.runtime_endpoint(),
+            "https://vpce-0abc-runtime.bedrock-runtime.us-east-1.vpce.amazonaws.com"
+        );
+        assert_eq!(
+            config.api_endpoint(),
+            "https://vpce-0abc-api.bedrock.us-east-1.vpce.amazonaws.com"
+        );
+    }
+
     #[test]
     fn test_is_valid_region() {
         assert!(is_valid_region("us-east-1"));
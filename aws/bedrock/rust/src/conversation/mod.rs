@@ -0,0 +1,310 @@
+//! Multi-turn conversation state.
+//!
+//! [`Conversation`] keeps the running message history for a model so callers
+//! do not have to append assistant replies to the request themselves. The
+//! history always starts with a user turn and alternates between user and
+//! assistant, as Claude requires; consecutive messages from the same role are
+//! collapsed into one.
+
+use crate::client::BedrockClient;
+use crate::error::{BedrockError, RequestError};
+use crate::types::{Message, UnifiedInvokeRequest, UnifiedInvokeResponse, UsageInfo};
+
+/// Separator used when collapsing consecutive same-role messages.
+const COLLAPSE_SEPARATOR: &str = "\n\n";
+
+/// Running conversation with a single model.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use aws_bedrock::{BedrockClientBuilder, Conversation};
+///
+/// # async fn example() -> Result<(), aws_bedrock::BedrockError> {
+/// let client = BedrockClientBuilder::new().from_env().build()?;
+///
+/// let mut conversation = Conversation::new("anthropic.claude-3-sonnet-20240229-v1:0")
+///     .with_system("You are a concise assistant.");
+///
+/// let first = conversation.send(&client, "What is Rust?").await?;
+/// let second = conversation.send(&client, "Who maintains it?").await?;
+///
+/// assert_eq!(conversation.messages().len(), 4);
+/// println!("{} / {}", first.content, second.content);
+/// println!("Total tokens: {}", conversation.usage().total_tokens);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Conversation {
+    model_id: String,
+    system: Option<String>,
+    max_tokens: Option<u32>,
+    messages: Vec<Message>,
+    usage: UsageInfo,
+}
+
+impl Conversation {
+    /// Create an empty conversation with a model.
+    pub fn new(model_id: impl Into<String>) -> Self {
+        Self {
+            model_id: model_id.into(),
+            system: None,
+            max_tokens: None,
+            messages: Vec::new(),
+            usage: UsageInfo::default(),
+        }
+    }
+
+    /// Set the system prompt sent with every turn.
+    pub fn with_system(mut self, system: impl Into<String>) -> Self {
+        self.system = Some(system.into());
+        self
+    }
+
+    /// Set the maximum tokens to generate per turn.
+    pub fn with_max_tokens(mut self, max_tokens: u32) -> Self {
+        self.max_tokens = Some(max_tokens);
+        self
+    }
+
+    /// Get the model ID.
+    pub fn model_id(&self) -> &str {
+        &self.model_id
+    }
+
+    /// Get the system prompt.
+    pub fn system(&self) -> Option<&str> {
+        self.system.as_deref()
+    }
+
+    /// Get the message history.
+    pub fn messages(&self) -> &[Message] {
+        &self.messages
+    }
+
+    /// Get the usage accumulated across all turns.
+    pub fn usage(&self) -> &UsageInfo {
+        &self.usage
+    }
+
+    /// Append a message to the history.
+    ///
+    /// A message with the same role as the last one is merged into it. The
+    /// first message must come from the user, and only `user` and `assistant`
+    /// roles are accepted.
+    pub fn push(&mut self, message: Message) -> Result<(), BedrockError> {
+        if message.role != "user" && message.role != "assistant" {
+            return Err(invalid_messages(format!(
+                "unsupported role '{}', expected 'user' or 'assistant'",
+                message.role
+            )));
+        }
+
+        match self.messages.last_mut() {
+            Some(last) if last.role == message.role => {
+                if !message.content.is_empty() {
+                    if !last.content.is_empty() {
+                        last.content.push_str(COLLAPSE_SEPARATOR);
+                    }
+                    last.content.push_str(&message.content);
+                }
+            }
+            Some(_) => self.messages.push(message),
+            None if message.role == "user" => self.messages.push(message),
+            None => {
+                return Err(invalid_messages(
+                    "conversation must start with a user message",
+                ))
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Build the invoke request for the current history.
+    pub fn to_request(&self) -> UnifiedInvokeRequest {
+        let mut request = UnifiedInvokeRequest::new(self.model_id.clone(), self.messages.clone());
+        if let Some(system) = &self.system {
+            request = request.with_system(system.clone());
+        }
+        if let Some(max_tokens) = self.max_tokens {
+            request = request.with_max_tokens(max_tokens);
+        }
+        request
+    }
+
+    /// Send a user turn and record the assistant reply.
+    ///
+    /// The user text is appended, the model is invoked with the full history,
+    /// and the reply is appended as an assistant turn. If the invocation fails
+    /// the history is left as it was before the call.
+    pub async fn send<C>(
+        &mut self,
+        client: &C,
+        user_text: impl Into<String>,
+    ) -> Result<UnifiedInvokeResponse, BedrockError>
+    where
+        C: BedrockClient + ?Sized,
+    {
+        let previous = self.messages.clone();
+        self.push(Message::user(user_text))?;
+
+        let response = match client.invoke(self.to_request()).await {
+            Ok(response) => response,
+            Err(error) => {
+                self.messages = previous;
+                return Err(error);
+            }
+        };
+
+        self.push(Message::assistant(response.content.clone()))?;
+        self.usage.add(&response.usage);
+
+        Ok(response)
+    }
+
+    /// Clear the history and accumulated usage, keeping the model and system prompt.
+    pub fn reset(&mut self) {
+        self.messages.clear();
+        self.usage = UsageInfo::default();
+    }
+}
+
+fn invalid_messages(message: impl Into<String>) -> BedrockError {
+    BedrockError::Request(RequestError::InvalidParameter {
+        parameter: "messages".to_string(),
+        message: message.into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::RateLimitError;
+    use crate::mocks::MockBedrockClient;
+    use crate::types::StopReason;
+
+    const CLAUDE: &str = "anthropic.claude-3-sonnet-20240229-v1:0";
+
+    fn reply(content: &str, input_tokens: u32, output_tokens: u32) -> UnifiedInvokeResponse {
+        UnifiedInvokeResponse {
+            content: content.to_string(),
+            stop_reason: StopReason::EndTurn,
+            usage: UsageInfo::new(input_tokens, output_tokens),
+            model_id: CLAUDE.to_string(),
+            guardrail: None,
+        }
+    }
+
+    fn roles(messages: &[Message]) -> Vec<&str> {
+        messages.iter().map(|m| m.role.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_three_turn_conversation() {
+        let client = MockBedrockClient::new();
+        client
+            .on_invoke(CLAUDE, reply("Rust is a systems language.", 12, 6))
+            .on_invoke(CLAUDE, reply("The Rust Foundation.", 25, 4))
+            .on_invoke(CLAUDE, reply("2015.", 36, 2));
+
+        let mut conversation = Conversation::new(CLAUDE)
+            .with_system("Be brief.")
+            .with_max_tokens(200);
+
+        let first = conversation.send(&client, "What is Rust?").await.unwrap();
+        let second = conversation.send(&client, "Who maintains it?").await.unwrap();
+        let third = conversation.send(&client, "When was 1.0 released?").await.unwrap();
+
+        assert_eq!(first.content, "Rust is a systems language.");
+        assert_eq!(second.content, "The Rust Foundation.");
+        assert_eq!(third.content, "2015.");
+
+        assert_eq!(
+            roles(conversation.messages()),
+            vec!["user", "assistant", "user", "assistant", "user", "assistant"]
+        );
+        assert_eq!(conversation.messages()[5].content, "2015.");
+
+        let requests = client.invoke_requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].messages.len(), 1);
+        assert_eq!(requests[1].messages.len(), 3);
+        assert_eq!(requests[2].messages.len(), 5);
+        assert_eq!(requests[2].messages[3].content, "The Rust Foundation.");
+        assert!(requests
+            .iter()
+            .all(|r| r.system.as_deref() == Some("Be brief.") && r.max_tokens == Some(200)));
+
+        let usage = conversation.usage();
+        assert_eq!(usage.input_tokens, 73);
+        assert_eq!(usage.output_tokens, 12);
+        assert_eq!(usage.total_tokens, 85);
+    }
+
+    #[test]
+    fn test_push_collapses_consecutive_roles() {
+        let mut conversation = Conversation::new(CLAUDE);
+        conversation.push(Message::user("Here is a file.")).unwrap();
+        conversation.push(Message::user("Summarize it.")).unwrap();
+        conversation.push(Message::assistant("It is short.")).unwrap();
+        conversation.push(Message::assistant("")).unwrap();
+
+        assert_eq!(roles(conversation.messages()), vec!["user", "assistant"]);
+        assert_eq!(
+            conversation.messages()[0].content,
+            "Here is a file.\n\nSummarize it."
+        );
+        assert_eq!(conversation.messages()[1].content, "It is short.");
+    }
+
+    #[test]
+    fn test_push_enforces_ordering() {
+        let mut conversation = Conversation::new(CLAUDE);
+
+        let err = conversation.push(Message::assistant("Hello")).unwrap_err();
+        assert!(matches!(
+            err,
+            BedrockError::Request(RequestError::InvalidParameter { .. })
+        ));
+
+        let err = conversation
+            .push(Message {
+                role: "system".to_string(),
+                content: "Be brief.".to_string(),
+            })
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            BedrockError::Request(RequestError::InvalidParameter { .. })
+        ));
+        assert!(conversation.messages().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_failed_send_keeps_history() {
+        let client = MockBedrockClient::new();
+        client
+            .on_invoke(CLAUDE, reply("Hi!", 3, 2))
+            .on_invoke_error(
+                CLAUDE,
+                BedrockError::RateLimit(RateLimitError::TooManyRequests {
+                    retry_after: None,
+                    request_id: None,
+                }),
+            )
+            .on_invoke(CLAUDE, reply("Still here.", 9, 3));
+
+        let mut conversation = Conversation::new(CLAUDE);
+        conversation.send(&client, "Hello").await.unwrap();
+
+        assert!(conversation.send(&client, "Are you there?").await.is_err());
+        assert_eq!(roles(conversation.messages()), vec!["user", "assistant"]);
+        assert_eq!(conversation.usage().total_tokens, 5);
+
+        conversation.send(&client, "Are you there?").await.unwrap();
+        assert_eq!(conversation.messages().len(), 4);
+        assert_eq!(conversation.usage().total_tokens, 17);
+    }
+}
@@ -17,6 +17,12 @@ pub fn map_bedrock_error(
 
     match (status, error_type) {
         // 400 Bad Request
+        (400, "ValidationException") if is_content_filter_message(message) => {
+            BedrockError::Request(RequestError::ContentFiltered {
+                message: message_str.unwrap_or_default(),
+                request_id,
+            })
+        }
         (400, "ValidationException") => BedrockError::Request(RequestError::Validation {
             message: message_str.unwrap_or_else(|| "Validation error".to_string()),
             request_id,
@@ -117,6 +123,11 @@ pub fn map_bedrock_error(
     }
 }
 
+/// Check whether a validation message reports blocked content.
+fn is_content_filter_message(message: Option<&str>) -> bool {
+    message.is_some_and(|m| m.to_lowercase().contains("content filter"))
+}
+
 /// Parse the x-amzn-errortype header to extract the error code.
 pub fn parse_error_type(header_value: &str) -> &str {
     // Header format: "ErrorType:additional_info" or just "ErrorType"
@@ -155,6 +166,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_map_content_filtered() {
+        let error = map_bedrock_error(
+            400,
+            Some("ValidationException"),
+            Some("This request has been blocked by our content filters."),
+            Some("req-789".to_string()),
+            Some("amazon.titan-image-generator-v1"),
+            None,
+        );
+
+        match error {
+            BedrockError::Request(RequestError::ContentFiltered { message, request_id }) => {
+                assert!(message.contains("content filters"));
+                assert_eq!(request_id, Some("req-789".to_string()));
+            }
+            _ => panic!("Expected ContentFiltered"),
+        }
+    }
+
     #[test]
     fn test_map_model_not_found() {
         let error = map_bedrock_error(
@@ -320,6 +320,17 @@ pub enum RequestError {
         request_id: Option<String>,
     },
 
+    /// A guardrail intervened and the caller asked for an error.
+    #[error("Guardrail '{guardrail_id}' intervened: {message}")]
+    GuardrailIntervened {
+        /// The guardrail identifier.
+        guardrail_id: String,
+        /// The guardrail's replacement output.
+        message: String,
+        /// AWS request ID.
+        request_id: Option<String>,
+    },
+
     /// Context length exceeded.
     #[error("Context length exceeded: input tokens ({input_tokens}) exceed model limit ({max_tokens})")]
     ContextLengthExceeded {
@@ -6,8 +6,9 @@
 //! # Features
 //!
 //! - **Unified API**: Single interface for all model families
-//! - **Model Family Support**: Titan (text + embeddings), Claude, LLaMA
+//! - **Model Family Support**: Titan (text + embeddings + images), Claude, LLaMA
 //! - **Streaming**: AWS Event Stream parsing for real-time responses
+//! - **Conversations**: Multi-turn history with role alternation and usage tracking
 //! - **AWS Signature V4**: Complete signing implementation
 //! - **Resilience**: Retry, circuit breaker, rate limiting
 //! - **Observability**: Tracing, structured logging
@@ -134,6 +135,7 @@
 
 pub mod client;
 pub mod config;
+pub mod conversation;
 pub mod credentials;
 pub mod error;
 pub mod mocks;
@@ -146,10 +148,16 @@ pub mod types;
 // Re-export main types at crate root
 
 // Client
-pub use client::{BedrockClient, BedrockClientBuilder, BedrockClientImpl};
+pub use client::{BedrockClient, BedrockClientBuilder, BedrockClientImpl, HeaderTokenCounts};
 
 // Configuration
-pub use config::{BedrockConfig, BedrockConfigBuilder, RetryConfig, StreamConfig, BEDROCK_REGIONS};
+pub use config::{
+    BedrockConfig, BedrockConfigBuilder, EndpointKind, EndpointResolver, RetryConfig, StreamConfig,
+    BEDROCK_FIPS_REGIONS, BEDROCK_REGIONS,
+};
+
+// Conversation
+pub use conversation::Conversation;
 
 // Credentials
 pub use credentials::{
@@ -184,6 +192,9 @@ pub use types::{
     detect_llama_version,
     detect_model_family,
     get_model_limits,
+    known_model_limits,
+    GuardrailAction,
+    GuardrailTrace,
     LlamaVersion,
     Message,
     ModelCapabilities,
@@ -195,24 +206,36 @@ pub use types::{
     ClaudeMessage,
     ClaudeRequest,
     GetModelRequest,
+    GuardrailConfig,
     LlamaRequest,
+    ListInferenceProfilesRequest,
     ListModelsRequest,
     TitanEmbedRequest,
+    TitanImageConfig,
+    TitanImageRequest,
     TitanTextConfig,
+    TitanTextToImageParams,
     TitanTextRequest,
     UnifiedInvokeRequest,
     // Response types
+    GuardrailAssessment,
     ClaudeContentBlock,
     ClaudeResponse,
     ClaudeStreamEvent,
     ClaudeUsage,
     GetModelResponse,
+    InferenceProfileModel,
+    InferenceProfileStatus,
+    InferenceProfileSummary,
+    InferenceProfileType,
     LlamaResponse,
     LlamaStreamChunk,
+    ListInferenceProfilesResponse,
     ListModelsResponse,
     ModelDetails,
     ModelSummary,
     TitanEmbedResponse,
+    TitanImageResponse,
     TitanStreamChunk,
     TitanTextResponse,
     TitanTextResult,
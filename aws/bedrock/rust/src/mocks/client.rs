@@ -0,0 +1,549 @@
+//! Mock `BedrockClient` for downstream integration tests.
+//!
+//! Responses, stream chunk sequences, and errors are registered per model ID and
+//! returned in registration order. The last registered response for a model is
+//! reused once the queue is drained; errors are returned once. Every call is
+//! recorded so tests can assert on the requests that were made.
+
+use crate::client::BedrockClient;
+use crate::error::{BedrockError, ModelError};
+use crate::types::{
+    GetModelResponse, ListInferenceProfilesRequest, ListInferenceProfilesResponse,
+    ListModelsRequest, ListModelsResponse, StopReason, TitanEmbedRequest,
+    TitanEmbedResponse, TitanImageRequest, UnifiedInvokeRequest, UnifiedInvokeResponse,
+    UnifiedStreamChunk, UsageInfo,
+};
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::Stream;
+use parking_lot::Mutex;
+use std::collections::{HashMap, VecDeque};
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// A request recorded by [`MockBedrockClient`].
+#[derive(Debug, Clone)]
+pub enum MockBedrockRequest {
+    /// Call to `invoke`.
+    Invoke(UnifiedInvokeRequest),
+    /// Call to `invoke_stream`.
+    InvokeStream(UnifiedInvokeRequest),
+    /// Call to `embed`.
+    Embed {
+        /// The embedding model ID.
+        model_id: String,
+        /// The embedding request.
+        request: TitanEmbedRequest,
+    },
+    /// Call to `generate_image`.
+    GenerateImage {
+        /// The image model ID.
+        model_id: String,
+        /// The image generation request.
+        request: TitanImageRequest,
+    },
+    /// Call to `list_models`.
+    ListModels(ListModelsRequest),
+    /// Call to `get_model`.
+    GetModel(String),
+    /// Call to `list_inference_profiles`.
+    ListInferenceProfiles(ListInferenceProfilesRequest),
+}
+
+impl MockBedrockRequest {
+    /// Get the model ID the request targeted, if any.
+    pub fn model_id(&self) -> Option<&str> {
+        match self {
+            MockBedrockRequest::Invoke(request) | MockBedrockRequest::InvokeStream(request) => {
+                Some(&request.model_id)
+            }
+            MockBedrockRequest::Embed { model_id, .. }
+            | MockBedrockRequest::GenerateImage { model_id, .. } => Some(model_id),
+            MockBedrockRequest::GetModel(model_id) => Some(model_id),
+            MockBedrockRequest::ListModels(_)
+            | MockBedrockRequest::ListInferenceProfiles(_) => None,
+        }
+    }
+}
+
+/// A registered mock outcome.
+enum Outcome<T> {
+    Response(T),
+    Error(BedrockError),
+}
+
+/// A registered stream: chunks yielded in order, optionally followed by an error.
+///
+/// The error is shared between clones so a reused stream only fails once.
+#[derive(Clone)]
+struct MockStream {
+    chunks: Vec<UnifiedStreamChunk>,
+    error: Arc<Mutex<Option<BedrockError>>>,
+}
+
+/// Queues of outcomes keyed by model ID.
+struct OutcomeQueues<T> {
+    queues: HashMap<String, VecDeque<Outcome<T>>>,
+}
+
+impl<T> Default for OutcomeQueues<T> {
+    fn default() -> Self {
+        Self {
+            queues: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Clone> OutcomeQueues<T> {
+    fn push(&mut self, key: &str, outcome: Outcome<T>) {
+        self.queues.entry(key.to_string()).or_default().push_back(outcome);
+    }
+
+    fn next(&mut self, key: &str) -> Option<Outcome<T>> {
+        let queue = self.queues.get_mut(key)?;
+        match queue.front() {
+            Some(Outcome::Response(response)) if queue.len() == 1 => {
+                Some(Outcome::Response(response.clone()))
+            }
+            _ => queue.pop_front(),
+        }
+    }
+}
+
+/// Mock Bedrock client implementing [`BedrockClient`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use aws_bedrock::mocks::MockBedrockClient;
+/// use aws_bedrock::{BedrockClient, Message, UnifiedInvokeRequest};
+///
+/// # async fn example() -> Result<(), aws_bedrock::BedrockError> {
+/// let client = MockBedrockClient::new();
+/// client.on_invoke_text("amazon.titan-text-express-v1", "Hello!");
+///
+/// let response = client
+///     .invoke(UnifiedInvokeRequest::new(
+///         "amazon.titan-text-express-v1",
+///         vec![Message::user("Hi")],
+///     ))
+///     .await?;
+///
+/// assert_eq!(response.content, "Hello!");
+/// assert_eq!(client.request_count(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct MockBedrockClient {
+    invoke: Mutex<OutcomeQueues<UnifiedInvokeResponse>>,
+    stream: Mutex<OutcomeQueues<MockStream>>,
+    embed: Mutex<OutcomeQueues<TitanEmbedResponse>>,
+    image: Mutex<OutcomeQueues<Vec<Bytes>>>,
+    get_model: Mutex<OutcomeQueues<GetModelResponse>>,
+    list_models: Mutex<Option<ListModelsResponse>>,
+    list_inference_profiles: Mutex<Option<ListInferenceProfilesResponse>>,
+    requests: Mutex<Vec<MockBedrockRequest>>,
+}
+
+impl MockBedrockClient {
+    /// Create a new mock client with no registered responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a response for `invoke` on a model.
+    pub fn on_invoke(&self, model_id: &str, response: UnifiedInvokeResponse) -> &Self {
+        self.invoke.lock().push(model_id, Outcome::Response(response));
+        self
+    }
+
+    /// Register a plain text response for `invoke` on a model.
+    pub fn on_invoke_text(&self, model_id: &str, content: impl Into<String>) -> &Self {
+        self.on_invoke(
+            model_id,
+            UnifiedInvokeResponse {
+                content: content.into(),
+                stop_reason: StopReason::EndTurn,
+                usage: UsageInfo::new(10, 5),
+                model_id: model_id.to_string(),
+                guardrail: None,
+            },
+        )
+    }
+
+    /// Register an error for the next `invoke` on a model.
+    pub fn on_invoke_error(&self, model_id: &str, error: BedrockError) -> &Self {
+        self.invoke.lock().push(model_id, Outcome::Error(error));
+        self
+    }
+
+    /// Register a chunk sequence for `invoke_stream` on a model.
+    pub fn on_invoke_stream(&self, model_id: &str, chunks: Vec<UnifiedStreamChunk>) -> &Self {
+        let stream = MockStream {
+            chunks,
+            error: Arc::new(Mutex::new(None)),
+        };
+        self.stream.lock().push(model_id, Outcome::Response(stream));
+        self
+    }
+
+    /// Register a chunk sequence that fails with `error` after the chunks are yielded.
+    pub fn on_invoke_stream_interrupted(
+        &self,
+        model_id: &str,
+        chunks: Vec<UnifiedStreamChunk>,
+        error: BedrockError,
+    ) -> &Self {
+        let stream = MockStream {
+            chunks,
+            error: Arc::new(Mutex::new(Some(error))),
+        };
+        self.stream.lock().push(model_id, Outcome::Response(stream));
+        self
+    }
+
+    /// Register an error returned when `invoke_stream` starts on a model.
+    pub fn on_invoke_stream_error(&self, model_id: &str, error: BedrockError) -> &Self {
+        self.stream.lock().push(model_id, Outcome::Error(error));
+        self
+    }
+
+    /// Register a response for `embed` on a model.
+    pub fn on_embed(&self, model_id: &str, response: TitanEmbedResponse) -> &Self {
+        self.embed.lock().push(model_id, Outcome::Response(response));
+        self
+    }
+
+    /// Register an error for the next `embed` on a model.
+    pub fn on_embed_error(&self, model_id: &str, error: BedrockError) -> &Self {
+        self.embed.lock().push(model_id, Outcome::Error(error));
+        self
+    }
+
+    /// Register decoded images for `generate_image` on a model.
+    pub fn on_generate_image(&self, model_id: &str, images: Vec<Bytes>) -> &Self {
+        self.image.lock().push(model_id, Outcome::Response(images));
+        self
+    }
+
+    /// Register an error for the next `generate_image` on a model.
+    pub fn on_generate_image_error(&self, model_id: &str, error: BedrockError) -> &Self {
+        self.image.lock().push(model_id, Outcome::Error(error));
+        self
+    }
+
+    /// Register the response for `list_models`.
+    pub fn on_list_models(&self, response: ListModelsResponse) -> &Self {
+        *self.list_models.lock() = Some(response);
+        self
+    }
+
+    /// Register the response for `list_inference_profiles`.
+    pub fn on_list_inference_profiles(&self, response: ListInferenceProfilesResponse) -> &Self {
+        *self.list_inference_profiles.lock() = Some(response);
+        self
+    }
+
+    /// Register a response for `get_model`.
+    pub fn on_get_model(&self, model_id: &str, response: GetModelResponse) -> &Self {
+        self.get_model.lock().push(model_id, Outcome::Response(response));
+        self
+    }
+
+    /// Register an error for the next `get_model` on a model.
+    pub fn on_get_model_error(&self, model_id: &str, error: BedrockError) -> &Self {
+        self.get_model.lock().push(model_id, Outcome::Error(error));
+        self
+    }
+
+    /// Get all recorded requests in call order.
+    pub fn requests(&self) -> Vec<MockBedrockRequest> {
+        self.requests.lock().clone()
+    }
+
+    /// Get recorded requests that targeted a model.
+    pub fn requests_for_model(&self, model_id: &str) -> Vec<MockBedrockRequest> {
+        self.requests
+            .lock()
+            .iter()
+            .filter(|r| r.model_id() == Some(model_id))
+            .cloned()
+            .collect()
+    }
+
+    /// Get the recorded `invoke` and `invoke_stream` requests.
+    pub fn invoke_requests(&self) -> Vec<UnifiedInvokeRequest> {
+        self.requests
+            .lock()
+            .iter()
+            .filter_map(|r| match r {
+                MockBedrockRequest::Invoke(request) | MockBedrockRequest::InvokeStream(request) => {
+                    Some(request.clone())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Get the number of recorded requests.
+    pub fn request_count(&self) -> usize {
+        self.requests.lock().len()
+    }
+
+    /// Clear recorded requests and registered responses.
+    pub fn reset(&self) {
+        self.requests.lock().clear();
+        *self.invoke.lock() = OutcomeQueues::default();
+        *self.stream.lock() = OutcomeQueues::default();
+        *self.embed.lock() = OutcomeQueues::default();
+        *self.image.lock() = OutcomeQueues::default();
+        *self.get_model.lock() = OutcomeQueues::default();
+        *self.list_models.lock() = None;
+        *self.list_inference_profiles.lock() = None;
+    }
+
+    fn record(&self, request: MockBedrockRequest) {
+        self.requests.lock().push(request);
+    }
+
+    fn resolve<T>(outcome: Option<Outcome<T>>, model_id: &str) -> Result<T, BedrockError> {
+        match outcome {
+            Some(Outcome::Response(response)) => Ok(response),
+            Some(Outcome::Error(error)) => Err(error),
+            None => Err(unregistered(model_id)),
+        }
+    }
+}
+
+/// Error returned when no mock outcome is registered for a model.
+fn unregistered(model_id: &str) -> BedrockError {
+    BedrockError::Model(ModelError::NotFound {
+        model_id: model_id.to_string(),
+        request_id: None,
+    })
+}
+
+#[async_trait]
+impl BedrockClient for MockBedrockClient {
+    async fn invoke(&self, request: UnifiedInvokeRequest) -> Result<UnifiedInvokeResponse, BedrockError> {
+        let model_id = request.model_id.clone();
+        self.record(MockBedrockRequest::Invoke(request));
+
+        let outcome = self.invoke.lock().next(&model_id);
+        Self::resolve(outcome, &model_id)
+    }
+
+    fn invoke_stream(
+        &self,
+        request: UnifiedInvokeRequest,
+    ) -> Pin<Box<dyn Stream<Item = Result<UnifiedStreamChunk, BedrockError>> + Send + '_>> {
+        let model_id = request.model_id.clone();
+        self.record(MockBedrockRequest::InvokeStream(request));
+
+        let outcome = self.stream.lock().next(&model_id);
+        match Self::resolve(outcome, &model_id) {
+            Ok(stream) => {
+                let trailing_error = stream.error.lock().take();
+                let items = stream
+                    .chunks
+                    .into_iter()
+                    .map(Ok)
+                    .chain(trailing_error.map(Err));
+                Box::pin(futures::stream::iter(items.collect::<Vec<_>>()))
+            }
+            Err(error) => Box::pin(futures::stream::once(async move { Err(error) })),
+        }
+    }
+
+    async fn embed(&self, request: TitanEmbedRequest, model_id: &str) -> Result<TitanEmbedResponse, BedrockError> {
+        self.record(MockBedrockRequest::Embed {
+            model_id: model_id.to_string(),
+            request,
+        });
+
+        let outcome = self.embed.lock().next(model_id);
+        Self::resolve(outcome, model_id)
+    }
+
+    async fn generate_image(&self, request: TitanImageRequest, model_id: &str) -> Result<Vec<Bytes>, BedrockError> {
+        self.record(MockBedrockRequest::GenerateImage {
+            model_id: model_id.to_string(),
+            request,
+        });
+
+        let outcome = self.image.lock().next(model_id);
+        Self::resolve(outcome, model_id)
+    }
+
+    async fn list_models(&self, request: ListModelsRequest) -> Result<ListModelsResponse, BedrockError> {
+        self.record(MockBedrockRequest::ListModels(request));
+
+        Ok(self
+            .list_models
+            .lock()
+            .clone()
+            .unwrap_or(ListModelsResponse {
+                model_summaries: Vec::new(),
+            }))
+    }
+
+    async fn get_model(&self, model_id: &str) -> Result<GetModelResponse, BedrockError> {
+        self.record(MockBedrockRequest::GetModel(model_id.to_string()));
+
+        let outcome = self.get_model.lock().next(model_id);
+        Self::resolve(outcome, model_id)
+    }
+
+    async fn list_inference_profiles(
+        &self,
+        request: ListInferenceProfilesRequest,
+    ) -> Result<ListInferenceProfilesResponse, BedrockError> {
+        self.record(MockBedrockRequest::ListInferenceProfiles(request));
+
+        Ok(self
+            .list_inference_profiles
+            .lock()
+            .clone()
+            .unwrap_or(ListInferenceProfilesResponse {
+                inference_profile_summaries: Vec::new(),
+                next_token: None,
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::RateLimitError;
+    use crate::types::Message;
+    use futures::StreamExt;
+
+    const TITAN: &str = "amazon.titan-text-express-v1";
+    const CLAUDE: &str = "anthropic.claude-3-sonnet-20240229-v1:0";
+
+    fn request(model_id: &str, prompt: &str) -> UnifiedInvokeRequest {
+        UnifiedInvokeRequest::new(model_id, vec![Message::user(prompt)])
+    }
+
+    fn throttled() -> BedrockError {
+        BedrockError::RateLimit(RateLimitError::TooManyRequests {
+            retry_after: None,
+            request_id: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_invoke_returns_registered_response() {
+        let client = MockBedrockClient::new();
+        client.on_invoke_text(TITAN, "first").on_invoke_text(TITAN, "second");
+
+        let first = client.invoke(request(TITAN, "a")).await.unwrap();
+        let second = client.invoke(request(TITAN, "b")).await.unwrap();
+        let third = client.invoke(request(TITAN, "c")).await.unwrap();
+
+        assert_eq!(first.content, "first");
+        assert_eq!(second.content, "second");
+        // The last response is reused once the queue is drained.
+        assert_eq!(third.content, "second");
+
+        let err = client.invoke(request(CLAUDE, "d")).await.unwrap_err();
+        assert!(matches!(err, BedrockError::Model(ModelError::NotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_invoke_stream_yields_chunks_in_order() {
+        let client = MockBedrockClient::new();
+        client.on_invoke_stream(
+            CLAUDE,
+            vec![
+                UnifiedStreamChunk::content("Hello"),
+                UnifiedStreamChunk::content(", world"),
+                UnifiedStreamChunk::final_chunk(StopReason::EndTurn, UsageInfo::new(3, 2)),
+            ],
+        );
+
+        let chunks: Vec<_> = client
+            .invoke_stream(request(CLAUDE, "hi"))
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        let deltas: Vec<&str> = chunks.iter().map(|c| c.delta.as_str()).collect();
+        assert_eq!(deltas, vec!["Hello", ", world", ""]);
+        assert!(chunks[2].is_final);
+    }
+
+    #[tokio::test]
+    async fn test_error_injection() {
+        let client = MockBedrockClient::new();
+        client
+            .on_invoke_error(TITAN, throttled())
+            .on_invoke_text(TITAN, "recovered");
+
+        let err = client.invoke(request(TITAN, "a")).await.unwrap_err();
+        assert!(err.is_retryable());
+        assert_eq!(client.invoke(request(TITAN, "b")).await.unwrap().content, "recovered");
+
+        client.on_invoke_stream_error(CLAUDE, throttled());
+        let results: Vec<_> = client.invoke_stream(request(CLAUDE, "c")).collect().await;
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_err());
+
+        client.on_invoke_stream_interrupted(
+            CLAUDE,
+            vec![UnifiedStreamChunk::content("partial")],
+            throttled(),
+        );
+        let results: Vec<_> = client.invoke_stream(request(CLAUDE, "d")).collect().await;
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap().delta, "partial");
+        assert!(results[1].is_err());
+
+        // The interrupted stream is reused without repeating its error.
+        let results: Vec<_> = client.invoke_stream(request(CLAUDE, "e")).collect().await;
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_request_recording() {
+        let client = MockBedrockClient::new();
+        client
+            .on_invoke_text(TITAN, "ok")
+            .on_invoke_stream(CLAUDE, vec![UnifiedStreamChunk::content("hi")])
+            .on_embed(
+                "amazon.titan-embed-text-v2:0",
+                TitanEmbedResponse {
+                    embedding: vec![0.1; 4],
+                    input_text_token_count: 2,
+                },
+            );
+
+        client.invoke(request(TITAN, "one")).await.unwrap();
+        let _ = client.invoke_stream(request(CLAUDE, "two")).collect::<Vec<_>>().await;
+        client
+            .embed(TitanEmbedRequest::new("three"), "amazon.titan-embed-text-v2:0")
+            .await
+            .unwrap();
+        client.list_models(ListModelsRequest::default()).await.unwrap();
+
+        assert_eq!(client.request_count(), 4);
+        assert!(matches!(client.requests()[3], MockBedrockRequest::ListModels(_)));
+
+        let invokes = client.invoke_requests();
+        assert_eq!(invokes.len(), 2);
+        assert_eq!(invokes[0].messages[0].content, "one");
+        assert_eq!(invokes[1].model_id, CLAUDE);
+
+        let claude_requests = client.requests_for_model(CLAUDE);
+        assert_eq!(claude_requests.len(), 1);
+        assert!(matches!(claude_requests[0], MockBedrockRequest::InvokeStream(_)));
+
+        client.reset();
+        assert_eq!(client.request_count(), 0);
+        assert!(client.invoke(request(TITAN, "four")).await.is_err());
+    }
+}
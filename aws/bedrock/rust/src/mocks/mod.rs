@@ -1,7 +1,14 @@
 //! Mock implementations for testing.
 //!
 //! This module provides mock implementations of the Bedrock client and services
-//! for unit testing without actual AWS calls.
+//! for unit testing without actual AWS calls. `MockBedrockClient` is available
+//! in non-test builds with the `mocks` feature.
+
+#[cfg(any(test, feature = "mocks"))]
+mod client;
+
+#[cfg(any(test, feature = "mocks"))]
+pub use client::{MockBedrockClient, MockBedrockRequest};
 
 use crate::credentials::{AwsCredentials, CredentialsProvider};
 use crate::error::BedrockError;
@@ -101,6 +101,7 @@ pub fn translate_response(
         stop_reason,
         usage: UsageInfo::new(response.usage.input_tokens, response.usage.output_tokens),
         model_id: model_id.to_string(),
+        guardrail: None,
     }
 }
 
@@ -162,6 +163,7 @@ impl ClaudeStreamState {
                     stop_reason: None,
                     usage: None,
                     index: Some(index),
+                    guardrail: None,
                 })
             }
             ClaudeStreamEvent::ContentBlockStop { index: _ } => {
@@ -180,6 +182,7 @@ impl ClaudeStreamState {
                     stop_reason: self.stop_reason,
                     usage: Some(UsageInfo::new(self.input_tokens, self.output_tokens)),
                     index: None,
+                    guardrail: None,
                 })
             }
         }
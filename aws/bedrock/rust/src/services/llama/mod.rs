@@ -184,6 +184,7 @@ pub fn translate_response(response: LlamaResponse, model_id: &str) -> UnifiedInv
         stop_reason,
         usage: UsageInfo::new(response.prompt_token_count, response.generation_token_count),
         model_id: model_id.to_string(),
+        guardrail: None,
     }
 }
 
@@ -239,6 +240,7 @@ impl LlamaStreamState {
                 None
             },
             index: None,
+            guardrail: None,
         }
     }
 }
@@ -1,7 +1,7 @@
 //! Service implementations for AWS Bedrock model families.
 //!
 //! This module provides model-specific services for:
-//! - Amazon Titan (text generation and embeddings)
+//! - Amazon Titan (text generation, embeddings and image generation)
 //! - Anthropic Claude (text generation via Bedrock)
 //! - Meta LLaMA (text generation)
 //! - Model discovery and management
@@ -18,7 +18,7 @@ pub use titan::{TitanService, TitanStreamIterator};
 
 use crate::error::BedrockError;
 use crate::types::{
-    detect_model_family, ModelFamily, UnifiedInvokeRequest, UnifiedInvokeResponse,
+    ModelFamily, UnifiedInvokeRequest, UnifiedInvokeResponse,
     UnifiedStreamChunk,
 };
 
@@ -30,7 +30,7 @@ impl UnifiedService {
     pub fn translate_request(
         request: &UnifiedInvokeRequest,
     ) -> Result<FamilyRequest, BedrockError> {
-        let family = detect_model_family(&request.model_id)?;
+        let family = request.family()?;
 
         match family {
             ModelFamily::Titan => {
@@ -45,12 +45,15 @@ impl UnifiedService {
                 let llama_request = llama::translate_request(request)?;
                 Ok(FamilyRequest::Llama(llama_request))
             }
+            ModelFamily::TitanImage => {
+                Err(titan::image::text_generation_unsupported(&request.model_id))
+            }
         }
     }
 
     /// Get the model family for a request.
     pub fn get_family(request: &UnifiedInvokeRequest) -> Result<ModelFamily, BedrockError> {
-        detect_model_family(&request.model_id).map_err(Into::into)
+        request.family().map_err(Into::into)
     }
 }
 
@@ -4,7 +4,8 @@
 
 use crate::error::BedrockError;
 use crate::types::{
-    GetModelRequest, GetModelResponse, ListModelsRequest, ListModelsResponse, ModelSummary,
+    GetModelRequest, GetModelResponse, InferenceProfileType, ListInferenceProfilesRequest,
+    ListInferenceProfilesResponse, ListModelsRequest, ListModelsResponse, ModelSummary,
 };
 use async_trait::async_trait;
 
@@ -17,6 +18,12 @@ pub trait ModelsService: Send + Sync {
     /// Get details for a specific foundation model.
     async fn get(&self, request: GetModelRequest) -> Result<GetModelResponse, BedrockError>;
 
+    /// List inference profiles, both system-defined and application profiles.
+    async fn list_inference_profiles(
+        &self,
+        request: ListInferenceProfilesRequest,
+    ) -> Result<ListInferenceProfilesResponse, BedrockError>;
+
     /// List models by provider.
     async fn list_by_provider(&self, provider: &str) -> Result<Vec<ModelSummary>, BedrockError> {
         let response = self
@@ -74,6 +81,31 @@ pub fn build_list_query_params(request: &ListModelsRequest) -> Vec<(String, Stri
     params
 }
 
+/// Build query parameters for list inference profiles request.
+pub fn build_inference_profiles_query_params(
+    request: &ListInferenceProfilesRequest,
+) -> Vec<(String, String)> {
+    let mut params = Vec::new();
+
+    if let Some(max_results) = request.max_results {
+        params.push(("maxResults".to_string(), max_results.to_string()));
+    }
+
+    if let Some(ref token) = request.next_token {
+        params.push(("nextToken".to_string(), token.clone()));
+    }
+
+    if let Some(profile_type) = request.type_equals {
+        let value = match profile_type {
+            InferenceProfileType::SystemDefined => "SYSTEM_DEFINED",
+            InferenceProfileType::Application => "APPLICATION",
+        };
+        params.push(("typeEquals".to_string(), value.to_string()));
+    }
+
+    params
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,4 +131,22 @@ mod tests {
         assert!(params.contains(&("byProvider".to_string(), "amazon".to_string())));
         assert!(params.contains(&("byOutputModality".to_string(), "TEXT".to_string())));
     }
+
+    #[test]
+    fn test_build_inference_profiles_query_params() {
+        let request = ListInferenceProfilesRequest {
+            max_results: Some(50),
+            next_token: None,
+            type_equals: Some(InferenceProfileType::Application),
+        };
+        let params = build_inference_profiles_query_params(&request);
+
+        assert_eq!(
+            params,
+            vec![
+                ("maxResults".to_string(), "50".to_string()),
+                ("typeEquals".to_string(), "APPLICATION".to_string()),
+            ]
+        );
+    }
 }
@@ -0,0 +1,267 @@
+//! Amazon Titan image generation.
+//!
+//! Validates text-to-image requests and decodes the base64 images returned by
+//! `amazon.titan-image-generator-v1`.
+
+use crate::error::{BedrockError, RequestError, StreamError};
+use crate::types::{TitanImageRequest, TitanImageResponse};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine as _;
+use bytes::Bytes;
+use serde_json::Value;
+
+/// Supported (width, height) pairs for Titan image generation.
+pub const TITAN_IMAGE_SIZES: &[(u32, u32)] = &[
+    (1024, 1024),
+    (768, 768),
+    (512, 512),
+    (768, 1152),
+    (384, 576),
+    (1152, 768),
+    (576, 384),
+    (768, 1280),
+    (384, 640),
+    (1280, 768),
+    (640, 384),
+    (896, 1152),
+    (448, 576),
+    (1152, 896),
+    (576, 448),
+    (768, 1408),
+    (384, 704),
+    (1408, 768),
+    (704, 384),
+    (640, 1408),
+    (320, 704),
+    (1408, 640),
+    (704, 320),
+    (1152, 640),
+    (1173, 640),
+];
+
+/// Maximum prompt length in characters.
+pub const MAX_PROMPT_LENGTH: usize = 512;
+
+/// Maximum number of images per request.
+pub const MAX_NUMBER_OF_IMAGES: u32 = 5;
+
+/// Valid cfg scale range.
+pub const CFG_SCALE_RANGE: std::ops::RangeInclusive<f32> = 1.1..=10.0;
+
+/// Maximum seed value.
+pub const MAX_SEED: u32 = 2_147_483_646;
+
+/// Validate an image generation request.
+pub fn validate_image_request(request: &TitanImageRequest) -> Result<(), BedrockError> {
+    let params = &request.text_to_image_params;
+    validate_prompt("text", &params.text)?;
+    if let Some(negative_text) = &params.negative_text {
+        validate_prompt("negative_text", negative_text)?;
+    }
+
+    let config = &request.image_generation_config;
+
+    if let Some(count) = config.number_of_images {
+        if !(1..=MAX_NUMBER_OF_IMAGES).contains(&count) {
+            return Err(invalid_parameter(
+                "number_of_images",
+                format!("number_of_images must be between 1 and {}", MAX_NUMBER_OF_IMAGES),
+            ));
+        }
+    }
+
+    match (config.width, config.height) {
+        (None, None) => {}
+        (Some(width), Some(height)) => {
+            if !TITAN_IMAGE_SIZES.contains(&(width, height)) {
+                return Err(invalid_parameter(
+                    "size",
+                    format!("{}x{} is not a supported image size", width, height),
+                ));
+            }
+        }
+        _ => {
+            return Err(invalid_parameter(
+                "size",
+                "width and height must be set together".to_string(),
+            ));
+        }
+    }
+
+    if let Some(cfg_scale) = config.cfg_scale {
+        if !CFG_SCALE_RANGE.contains(&cfg_scale) {
+            return Err(invalid_parameter(
+                "cfg_scale",
+                format!(
+                    "cfg_scale must be between {} and {}",
+                    CFG_SCALE_RANGE.start(),
+                    CFG_SCALE_RANGE.end()
+                ),
+            ));
+        }
+    }
+
+    if let Some(seed) = config.seed {
+        if seed > MAX_SEED {
+            return Err(invalid_parameter(
+                "seed",
+                format!("seed must not exceed {}", MAX_SEED),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_prompt(parameter: &str, text: &str) -> Result<(), BedrockError> {
+    if text.is_empty() {
+        return Err(invalid_parameter(parameter, format!("{} cannot be empty", parameter)));
+    }
+    if text.chars().count() > MAX_PROMPT_LENGTH {
+        return Err(invalid_parameter(
+            parameter,
+            format!("{} exceeds {} characters", parameter, MAX_PROMPT_LENGTH),
+        ));
+    }
+    Ok(())
+}
+
+fn invalid_parameter(parameter: &str, message: String) -> BedrockError {
+    BedrockError::Request(RequestError::InvalidParameter {
+        parameter: parameter.to_string(),
+        message,
+    })
+}
+
+/// Parse Titan image response from JSON.
+pub fn parse_image_response(json: &Value) -> Result<TitanImageResponse, BedrockError> {
+    serde_json::from_value(json.clone()).map_err(|e| {
+        BedrockError::Stream(StreamError::ParseError {
+            message: format!("Failed to parse Titan image response: {}", e),
+        })
+    })
+}
+
+/// Decode the base64 images in a Titan image response.
+///
+/// Content-filtered responses carry an `error` message (or no images) and are
+/// returned as [`RequestError::ContentFiltered`].
+pub fn decode_images(response: TitanImageResponse) -> Result<Vec<Bytes>, BedrockError> {
+    if let Some(message) = response.error {
+        return Err(BedrockError::Request(RequestError::ContentFiltered {
+            message,
+            request_id: None,
+        }));
+    }
+
+    if response.images.is_empty() {
+        return Err(BedrockError::Request(RequestError::ContentFiltered {
+            message: "No images returned; the request was blocked by content filters".to_string(),
+            request_id: None,
+        }));
+    }
+
+    response
+        .images
+        .iter()
+        .map(|image| {
+            STANDARD.decode(image).map(Bytes::from).map_err(|e| {
+                BedrockError::Stream(StreamError::ParseError {
+                    message: format!("Failed to decode Titan image: {}", e),
+                })
+            })
+        })
+        .collect()
+}
+
+/// Error returned when a text operation targets an image generation model.
+pub fn text_generation_unsupported(model_id: &str) -> BedrockError {
+    BedrockError::Request(RequestError::InvalidParameter {
+        parameter: "model_id".to_string(),
+        message: format!(
+            "{} is an image generation model; use generate_image instead",
+            model_id
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    // 1x1 transparent PNG.
+    const PNG_BASE64: &str =
+        "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAQAAAC1HAwCAAAAC0lEQVR42mNkYAAAAAYAAjCB0C8AAAAASUVORK5CYII=";
+
+    #[test]
+    fn test_decode_images() {
+        let json = json!({ "images": [PNG_BASE64, PNG_BASE64], "error": null });
+        let response = parse_image_response(&json).unwrap();
+
+        let images = decode_images(response).unwrap();
+        assert_eq!(images.len(), 2);
+        assert_eq!(&images[0][..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(images[0], images[1]);
+    }
+
+    #[test]
+    fn test_decode_images_content_filtered() {
+        let json = json!({
+            "images": [],
+            "error": "This request has been blocked by our content filters."
+        });
+        let response = parse_image_response(&json).unwrap();
+
+        match decode_images(response) {
+            Err(BedrockError::Request(RequestError::ContentFiltered { message, .. })) => {
+                assert!(message.contains("content filters"));
+            }
+            other => panic!("Expected ContentFiltered, got {:?}", other),
+        }
+
+        let empty = parse_image_response(&json!({ "images": [] })).unwrap();
+        assert!(matches!(
+            decode_images(empty),
+            Err(BedrockError::Request(RequestError::ContentFiltered { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_decode_images_invalid_base64() {
+        let response = parse_image_response(&json!({ "images": ["not base64!"] })).unwrap();
+        assert!(matches!(
+            decode_images(response),
+            Err(BedrockError::Stream(StreamError::ParseError { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_validate_image_request() {
+        let valid = TitanImageRequest::new("A lighthouse at dusk")
+            .with_number_of_images(5)
+            .with_size(1152, 640)
+            .with_cfg_scale(1.1)
+            .with_seed(MAX_SEED);
+        assert!(validate_image_request(&valid).is_ok());
+
+        let invalid = [
+            TitanImageRequest::new(""),
+            TitanImageRequest::new("a".repeat(MAX_PROMPT_LENGTH + 1)),
+            TitanImageRequest::new("cat").with_negative_prompt(""),
+            TitanImageRequest::new("cat").with_number_of_images(0),
+            TitanImageRequest::new("cat").with_number_of_images(6),
+            TitanImageRequest::new("cat").with_size(1000, 1000),
+            TitanImageRequest::new("cat").with_cfg_scale(1.0),
+            TitanImageRequest::new("cat").with_cfg_scale(10.5),
+            TitanImageRequest::new("cat").with_seed(MAX_SEED + 1),
+        ];
+        for request in invalid {
+            assert!(
+                validate_image_request(&request).is_err(),
+                "expected {:?} to be rejected",
+                request
+            );
+        }
+    }
+}
@@ -1,6 +1,9 @@
 //! Amazon Titan model family service.
 //!
 //! This module provides text generation and embedding capabilities for Titan models.
+//! Image generation lives in [`image`].
+
+pub mod image;
 
 use crate::error::{BedrockError, RequestError};
 use crate::streaming::EventStreamParser;
@@ -149,6 +152,7 @@ pub fn translate_response(
         stop_reason,
         usage: UsageInfo::new(input_tokens, output_tokens),
         model_id: model_id.to_string(),
+        guardrail: None,
     }
 }
 
@@ -167,6 +171,7 @@ pub fn translate_stream_chunk(chunk: TitanStreamChunk) -> UnifiedStreamChunk {
             None
         },
         index: Some(chunk.index),
+        guardrail: None,
     }
 }
 
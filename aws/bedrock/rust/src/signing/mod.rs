@@ -135,9 +135,22 @@ impl AwsSigner for BedrockSigner {
         url: &Url,
         headers: &HashMap<String, String>,
         body: Option<&[u8]>,
+    ) -> Result<SignedRequest, BedrockError> {
+        self.sign_at(method, url, headers, body, Utc::now()).await
+    }
+}
+
+impl BedrockSigner {
+    /// Sign a request as of `timestamp`.
+    pub(crate) async fn sign_at(
+        &self,
+        method: &str,
+        url: &Url,
+        headers: &HashMap<String, String>,
+        body: Option<&[u8]>,
+        timestamp: DateTime<Utc>,
     ) -> Result<SignedRequest, BedrockError> {
         let credentials = self.get_credentials().await?;
-        let timestamp = Utc::now();
 
         // Calculate payload hash
         let payload_hash = self.calculate_payload_hash(body);
@@ -432,6 +445,33 @@ mod tests {
         assert_eq!(uri_encode("a/b", false), "a/b");
     }
 
+    #[test]
+    fn test_canonical_request_encodes_path() {
+        // The already-encoded path is encoded again: `:` becomes %3A and %2F becomes %252F
+        let headers = vec![
+            ("host".to_string(), "bedrock-runtime.us-east-1.amazonaws.com".to_string()),
+            ("x-amz-date".to_string(), "20240115T120000Z".to_string()),
+        ];
+        let canonical = build_canonical_request(
+            "POST",
+            "/model/arn:aws:bedrock:us-east-1:123456789012:application-inference-profile%2Fabc/invoke",
+            "",
+            &headers,
+            UNSIGNED_PAYLOAD,
+        );
+        assert_eq!(
+            canonical,
+            "POST\n\
+             /model/arn%3Aaws%3Abedrock%3Aus-east-1%3A123456789012%3Aapplication-inference-profile%252Fabc/invoke\n\
+             \n\
+             host:bedrock-runtime.us-east-1.amazonaws.com\n\
+             x-amz-date:20240115T120000Z\n\
+             \n\
+             host;x-amz-date\n\
+             UNSIGNED-PAYLOAD"
+        );
+    }
+
     #[test]
     fn test_build_signed_headers() {
         let headers = vec![
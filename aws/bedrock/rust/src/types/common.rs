@@ -8,6 +8,9 @@ use serde::{Deserialize, Serialize};
 pub enum ModelFamily {
     /// Amazon Titan models (text and embeddings).
     Titan,
+    /// Amazon Titan image generator models.
+    #[serde(rename = "titan-image")]
+    TitanImage,
     /// Anthropic Claude models.
     Claude,
     /// Meta LLaMA models.
@@ -18,6 +21,7 @@ impl std::fmt::Display for ModelFamily {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             ModelFamily::Titan => write!(f, "titan"),
+            ModelFamily::TitanImage => write!(f, "titan-image"),
             ModelFamily::Claude => write!(f, "claude"),
             ModelFamily::Llama => write!(f, "llama"),
         }
@@ -39,8 +43,18 @@ pub enum LlamaVersion {
 
 /// Detect model family from model ID.
 ///
-/// Handles both base model IDs and ARN formats.
+/// Handles base model IDs, cross-region profile IDs such as
+/// `us.anthropic.claude-3-haiku-20240307-v1:0`, and ARN formats.
+/// Application inference profile ARNs end in an opaque ID, so their family
+/// cannot be detected; set it on the request with
+/// [`UnifiedInvokeRequest::with_model_family`](crate::types::UnifiedInvokeRequest::with_model_family).
 pub fn detect_model_family(model_id: &str) -> Result<ModelFamily, crate::error::ModelError> {
+    if model_id.starts_with("arn:") && model_id.contains(":application-inference-profile/") {
+        return Err(crate::error::ModelError::UnknownFamily {
+            model_id: model_id.to_string(),
+        });
+    }
+
     // Handle ARN format
     let effective_id = if model_id.starts_with("arn:") {
         // For ARN format, we need to check the model-id portion
@@ -55,7 +69,9 @@ pub fn detect_model_family(model_id: &str) -> Result<ModelFamily, crate::error::
 
     let lower = effective_id.to_lowercase();
 
-    if lower.starts_with("amazon.titan") || lower.contains("titan") {
+    if lower.starts_with("amazon.titan-image") || lower.contains("titan-image") {
+        Ok(ModelFamily::TitanImage)
+    } else if lower.starts_with("amazon.titan") || lower.contains("titan") {
         Ok(ModelFamily::Titan)
     } else if lower.starts_with("anthropic.claude") || lower.contains("claude") {
         Ok(ModelFamily::Claude)
@@ -117,6 +133,41 @@ impl Message {
     }
 }
 
+/// Guardrail trace level sent with an invoke request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum GuardrailTrace {
+    /// No trace is returned.
+    #[default]
+    Disabled,
+    /// The guardrail assessment is returned.
+    Enabled,
+    /// The full guardrail assessment, including non-blocking results, is returned.
+    EnabledFull,
+}
+
+impl GuardrailTrace {
+    /// Returns the header value for this trace level.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GuardrailTrace::Disabled => "DISABLED",
+            GuardrailTrace::Enabled => "ENABLED",
+            GuardrailTrace::EnabledFull => "ENABLED_FULL",
+        }
+    }
+}
+
+/// Action a guardrail took on a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GuardrailAction {
+    /// The guardrail blocked or masked content.
+    #[serde(rename = "INTERVENED")]
+    Intervened,
+    /// The guardrail let the content through unchanged.
+    #[serde(rename = "NONE")]
+    Passed,
+}
+
 /// Unified stop reason enumeration.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -131,6 +182,8 @@ pub enum StopReason {
     ContentFilter,
     /// Tool use requested.
     ToolUse,
+    /// A guardrail intervened and replaced the output.
+    GuardrailIntervened,
 }
 
 impl StopReason {
@@ -152,6 +205,7 @@ impl StopReason {
             "max_tokens" => StopReason::MaxTokens,
             "stop_sequence" => StopReason::StopSequence,
             "tool_use" => StopReason::ToolUse,
+            "guardrail_intervened" => StopReason::GuardrailIntervened,
             _ => StopReason::EndTurn,
         }
     }
@@ -186,6 +240,13 @@ impl UsageInfo {
             total_tokens: input_tokens + output_tokens,
         }
     }
+
+    /// Add another usage report to this one.
+    pub fn add(&mut self, other: &UsageInfo) {
+        self.input_tokens = self.input_tokens.saturating_add(other.input_tokens);
+        self.output_tokens = self.output_tokens.saturating_add(other.output_tokens);
+        self.total_tokens = self.total_tokens.saturating_add(other.total_tokens);
+    }
 }
 
 /// Model capability information.
@@ -238,78 +299,84 @@ pub struct ModelLimits {
 }
 
 /// Get model limits based on model ID.
+///
+/// Falls back to conservative defaults for unrecognized models.
 pub fn get_model_limits(model_id: &str) -> ModelLimits {
+    known_model_limits(model_id).unwrap_or(ModelLimits {
+        max_output_tokens: 4096,
+        default_max_tokens: 512,
+        max_context_tokens: 8192,
+        max_stop_sequences: 4,
+    })
+}
+
+/// Get model limits for a recognized model ID, or `None` if the model is unknown.
+pub fn known_model_limits(model_id: &str) -> Option<ModelLimits> {
     let lower = model_id.to_lowercase();
 
     // Titan limits
     if lower.contains("titan-text-express") {
-        return ModelLimits {
+        return Some(ModelLimits {
             max_output_tokens: 8192,
             default_max_tokens: 512,
             max_context_tokens: 8192,
             max_stop_sequences: 4,
-        };
+        });
     }
     if lower.contains("titan-text-lite") {
-        return ModelLimits {
+        return Some(ModelLimits {
             max_output_tokens: 4096,
             default_max_tokens: 512,
             max_context_tokens: 4096,
             max_stop_sequences: 4,
-        };
+        });
     }
 
     // Claude limits
     if lower.contains("claude-3") {
-        return ModelLimits {
+        return Some(ModelLimits {
             max_output_tokens: 4096,
             default_max_tokens: 1024,
             max_context_tokens: 200_000,
             max_stop_sequences: 8192,
-        };
+        });
     }
     if lower.contains("claude-2") || lower.contains("claude-instant") {
-        return ModelLimits {
+        return Some(ModelLimits {
             max_output_tokens: 4096,
             default_max_tokens: 1024,
             max_context_tokens: 100_000,
             max_stop_sequences: 8192,
-        };
+        });
     }
 
     // LLaMA limits
     if lower.contains("llama3-1") || lower.contains("llama3.1") {
-        return ModelLimits {
+        return Some(ModelLimits {
             max_output_tokens: 2048,
             default_max_tokens: 512,
             max_context_tokens: 128_000,
             max_stop_sequences: 0, // LLaMA doesn't support stop sequences
-        };
+        });
     }
     if lower.contains("llama3") {
-        return ModelLimits {
+        return Some(ModelLimits {
             max_output_tokens: 2048,
             default_max_tokens: 512,
             max_context_tokens: 8192,
             max_stop_sequences: 0,
-        };
+        });
     }
     if lower.contains("llama2") {
-        return ModelLimits {
+        return Some(ModelLimits {
             max_output_tokens: 2048,
             default_max_tokens: 512,
             max_context_tokens: 4096,
             max_stop_sequences: 0,
-        };
+        });
     }
 
-    // Default limits
-    ModelLimits {
-        max_output_tokens: 4096,
-        default_max_tokens: 512,
-        max_context_tokens: 8192,
-        max_stop_sequences: 4,
-    }
+    None
 }
 
 #[cfg(test)]
@@ -328,6 +395,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_detect_model_family_titan_image() {
+        assert_eq!(
+            detect_model_family("amazon.titan-image-generator-v1").unwrap(),
+            ModelFamily::TitanImage
+        );
+        assert_eq!(
+            detect_model_family("arn:aws:bedrock:us-east-1::foundation-model/amazon.titan-image-generator-v1").unwrap(),
+            ModelFamily::TitanImage
+        );
+        assert_eq!(ModelFamily::TitanImage.to_string(), "titan-image");
+    }
+
     #[test]
     fn test_detect_model_family_claude() {
         assert_eq!(
@@ -360,6 +440,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_detect_model_family_inference_profile_arn() {
+        assert_eq!(
+            detect_model_family("arn:aws:bedrock:us-east-1:123456789012:inference-profile/us.anthropic.claude-3-haiku-20240307-v1:0").unwrap(),
+            ModelFamily::Claude
+        );
+        assert!(detect_model_family(
+            "arn:aws:bedrock:us-east-1:123456789012:application-inference-profile/a1b2c3d4e5f6"
+        )
+        .is_err());
+    }
+
     #[test]
     fn test_detect_model_family_unknown() {
         assert!(detect_model_family("unknown.model-v1").is_err());
@@ -1,6 +1,8 @@
 //! Request types for AWS Bedrock operations.
 
-use super::common::Message;
+use super::common::{detect_model_family, known_model_limits, GuardrailTrace, Message, ModelFamily};
+use super::responses::InferenceProfileType;
+use crate::error::ModelError;
 use serde::{Deserialize, Serialize};
 
 /// Unified invoke request that works across all model families.
@@ -28,6 +30,12 @@ pub struct UnifiedInvokeRequest {
     /// Stop sequences to end generation.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stop_sequences: Option<Vec<String>>,
+    /// Guardrail applied to the invocation, sent as request headers.
+    #[serde(skip)]
+    pub guardrail: Option<GuardrailConfig>,
+    /// Model family, when it cannot be detected from `model_id`.
+    #[serde(skip)]
+    pub model_family: Option<ModelFamily>,
 }
 
 impl UnifiedInvokeRequest {
@@ -42,6 +50,8 @@ impl UnifiedInvokeRequest {
             top_p: None,
             top_k: None,
             stop_sequences: None,
+            guardrail: None,
+            model_family: None,
         }
     }
 
@@ -80,6 +90,157 @@ impl UnifiedInvokeRequest {
         self.stop_sequences = Some(stop_sequences);
         self
     }
+
+    /// Apply a guardrail to the invocation.
+    ///
+    /// Interventions are reported on the response; see
+    /// [`with_guardrail_error_on_intervention`](Self::with_guardrail_error_on_intervention)
+    /// to fail the call instead.
+    pub fn with_guardrail(
+        mut self,
+        identifier: impl Into<String>,
+        version: impl Into<String>,
+        trace: GuardrailTrace,
+    ) -> Self {
+        self.guardrail = Some(GuardrailConfig::new(identifier, version).with_trace(trace));
+        self
+    }
+
+    /// Set the model family explicitly.
+    ///
+    /// Needed for application inference profile ARNs, whose IDs do not name
+    /// the underlying model. See [`InferenceProfileSummary::model_family`](crate::types::InferenceProfileSummary::model_family).
+    pub fn with_model_family(mut self, family: ModelFamily) -> Self {
+        self.model_family = Some(family);
+        self
+    }
+
+    /// Size `max_tokens` to the context budget left after the prompt.
+    ///
+    /// Uses [`estimate_prompt_tokens`](Self::estimate_prompt_tokens); see
+    /// [`auto_max_tokens_with`](Self::auto_max_tokens_with) to supply another estimate.
+    pub fn auto_max_tokens(self) -> Self {
+        self.auto_max_tokens_with(Self::estimate_prompt_tokens)
+    }
+
+    /// Size `max_tokens` using a custom prompt token estimate.
+    ///
+    /// An explicit `max_tokens` is kept. Otherwise it is set to the model's
+    /// context window minus the estimated prompt size, clamped to the model's
+    /// output limit. It stays unset for models without known limits or when
+    /// the prompt already fills the context window.
+    pub fn auto_max_tokens_with<F>(mut self, estimate: F) -> Self
+    where
+        F: FnOnce(&Self) -> u32,
+    {
+        if self.max_tokens.is_some() {
+            return self;
+        }
+        let limits = match known_model_limits(&self.model_id) {
+            Some(limits) => limits,
+            None => return self,
+        };
+
+        let remaining = limits.max_context_tokens.saturating_sub(estimate(&self));
+        if remaining > 0 {
+            self.max_tokens = Some(remaining.min(limits.max_output_tokens));
+        }
+        self
+    }
+
+    /// Estimate the prompt size in tokens as one token per four characters
+    /// of the system prompt and all messages.
+    pub fn estimate_prompt_tokens(&self) -> u32 {
+        let chars: usize = self
+            .messages
+            .iter()
+            .map(|m| m.content.chars().count())
+            .chain(self.system.iter().map(|s| s.chars().count()))
+            .sum();
+        u32::try_from(chars.div_ceil(4)).unwrap_or(u32::MAX)
+    }
+
+    /// Get the model family, preferring an explicitly set one.
+    pub fn family(&self) -> Result<ModelFamily, ModelError> {
+        match self.model_family {
+            Some(family) => Ok(family),
+            None => detect_model_family(&self.model_id),
+        }
+    }
+
+    /// Return an error instead of the guardrail's replacement output when it intervenes.
+    ///
+    /// Has no effect unless a guardrail is set.
+    pub fn with_guardrail_error_on_intervention(mut self) -> Self {
+        if let Some(guardrail) = self.guardrail.as_mut() {
+            guardrail.error_on_intervention = true;
+        }
+        self
+    }
+}
+
+/// Header carrying the guardrail identifier.
+pub const GUARDRAIL_IDENTIFIER_HEADER: &str = "x-amzn-bedrock-guardrailidentifier";
+
+/// Header carrying the guardrail version.
+pub const GUARDRAIL_VERSION_HEADER: &str = "x-amzn-bedrock-guardrailversion";
+
+/// Header carrying the guardrail trace level.
+pub const GUARDRAIL_TRACE_HEADER: &str = "x-amzn-bedrock-trace";
+
+/// Guardrail settings for an invocation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuardrailConfig {
+    /// Guardrail ID or ARN.
+    pub identifier: String,
+    /// Guardrail version number, or `DRAFT`.
+    pub version: String,
+    /// Trace level.
+    pub trace: GuardrailTrace,
+    /// Fail the invocation when the guardrail intervenes.
+    pub error_on_intervention: bool,
+}
+
+impl GuardrailConfig {
+    /// Create a guardrail config with tracing disabled.
+    pub fn new(identifier: impl Into<String>, version: impl Into<String>) -> Self {
+        Self {
+            identifier: identifier.into(),
+            version: version.into(),
+            trace: GuardrailTrace::default(),
+            error_on_intervention: false,
+        }
+    }
+
+    /// Set the trace level.
+    pub fn with_trace(mut self, trace: GuardrailTrace) -> Self {
+        self.trace = trace;
+        self
+    }
+
+    /// Request headers that apply this guardrail.
+    pub fn headers(&self) -> [(&'static str, String); 3] {
+        [
+            (GUARDRAIL_IDENTIFIER_HEADER, self.identifier.clone()),
+            (GUARDRAIL_VERSION_HEADER, self.version.clone()),
+            (GUARDRAIL_TRACE_HEADER, self.trace.as_str().to_string()),
+        ]
+    }
+
+    /// Check the identifier is set and the version is `DRAFT` or a positive number.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.identifier.trim().is_empty() {
+            return Err("guardrail identifier must not be empty".to_string());
+        }
+        let numbered = self.version.parse::<u32>().map(|v| v > 0).unwrap_or(false);
+        if self.version != "DRAFT" && !numbered {
+            return Err(format!(
+                "guardrail version must be DRAFT or a positive number, got '{}'",
+                self.version
+            ));
+        }
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -151,6 +312,95 @@ impl TitanEmbedRequest {
     }
 }
 
+/// Titan image generation request body.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TitanImageRequest {
+    /// Task type ("TEXT_IMAGE").
+    pub task_type: String,
+    /// Text-to-image parameters.
+    pub text_to_image_params: TitanTextToImageParams,
+    /// Image generation configuration.
+    pub image_generation_config: TitanImageConfig,
+}
+
+/// Titan text-to-image parameters.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TitanTextToImageParams {
+    /// The text prompt describing the image.
+    pub text: String,
+    /// Text describing what should not appear in the image.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub negative_text: Option<String>,
+}
+
+/// Titan image generation configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TitanImageConfig {
+    /// Number of images to generate (1-5).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_of_images: Option<u32>,
+    /// Image height in pixels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    /// Image width in pixels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+    /// Prompt adherence (1.1-10.0).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cfg_scale: Option<f32>,
+    /// Seed for reproducible generation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u32>,
+}
+
+impl TitanImageRequest {
+    /// Create a new text-to-image request.
+    pub fn new(prompt: impl Into<String>) -> Self {
+        Self {
+            task_type: "TEXT_IMAGE".to_string(),
+            text_to_image_params: TitanTextToImageParams {
+                text: prompt.into(),
+                negative_text: None,
+            },
+            image_generation_config: TitanImageConfig::default(),
+        }
+    }
+
+    /// Set the negative prompt.
+    pub fn with_negative_prompt(mut self, negative_prompt: impl Into<String>) -> Self {
+        self.text_to_image_params.negative_text = Some(negative_prompt.into());
+        self
+    }
+
+    /// Set the number of images to generate.
+    pub fn with_number_of_images(mut self, number_of_images: u32) -> Self {
+        self.image_generation_config.number_of_images = Some(number_of_images);
+        self
+    }
+
+    /// Set the image size in pixels.
+    pub fn with_size(mut self, width: u32, height: u32) -> Self {
+        self.image_generation_config.width = Some(width);
+        self.image_generation_config.height = Some(height);
+        self
+    }
+
+    /// Set the cfg scale.
+    pub fn with_cfg_scale(mut self, cfg_scale: f32) -> Self {
+        self.image_generation_config.cfg_scale = Some(cfg_scale);
+        self
+    }
+
+    /// Set the seed.
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.image_generation_config.seed = Some(seed);
+        self
+    }
+}
+
 // ============================================================================
 // Claude-specific request types
 // ============================================================================
@@ -240,6 +490,20 @@ pub struct ListModelsRequest {
     pub by_inference_type: Option<String>,
 }
 
+/// Request for listing inference profiles.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListInferenceProfilesRequest {
+    /// Maximum number of profiles to return.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<u32>,
+    /// Pagination token from a previous response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// Filter by profile type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_equals: Option<InferenceProfileType>,
+}
+
 /// Request for getting a specific foundation model.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetModelRequest {
@@ -268,6 +532,74 @@ mod tests {
         assert_eq!(request.temperature, Some(0.7));
     }
 
+    #[test]
+    fn test_auto_max_tokens_for_claude() {
+        let model = "anthropic.claude-3-sonnet-20240229-v1:0";
+
+        // 195,900 + 100 estimated prompt tokens leave 4,000 of the 200,000 context
+        let request = UnifiedInvokeRequest::new(model, vec![Message::user("a".repeat(783_600))])
+            .with_system("b".repeat(400));
+        assert_eq!(request.estimate_prompt_tokens(), 196_000);
+        assert_eq!(request.auto_max_tokens().max_tokens, Some(4_000));
+
+        // Short prompts are clamped to the output limit
+        let request = UnifiedInvokeRequest::new(model, vec![Message::user("Hello")]).auto_max_tokens();
+        assert_eq!(request.max_tokens, Some(4096));
+
+        // Explicit values are kept
+        let request = UnifiedInvokeRequest::new(model, vec![Message::user("Hello")])
+            .with_max_tokens(256)
+            .auto_max_tokens();
+        assert_eq!(request.max_tokens, Some(256));
+    }
+
+    #[test]
+    fn test_auto_max_tokens_with_custom_estimate() {
+        let request = UnifiedInvokeRequest::new(
+            "anthropic.claude-3-haiku-20240307-v1:0",
+            vec![Message::user("Hello")],
+        )
+        .auto_max_tokens_with(|_| 198_500);
+        assert_eq!(request.max_tokens, Some(1_500));
+    }
+
+    #[test]
+    fn test_auto_max_tokens_unknown_model() {
+        let request = UnifiedInvokeRequest::new(
+            "arn:aws:bedrock:us-east-1:123456789012:application-inference-profile/abc123",
+            vec![Message::user("Hello")],
+        )
+        .auto_max_tokens();
+        assert_eq!(request.max_tokens, None);
+    }
+
+    #[test]
+    fn test_guardrail_config() {
+        let request = UnifiedInvokeRequest::new("anthropic.claude-3-haiku-20240307-v1:0", vec![])
+            .with_guardrail("gr-abc123", "2", GuardrailTrace::Enabled)
+            .with_guardrail_error_on_intervention();
+
+        let guardrail = request.guardrail.as_ref().unwrap();
+        assert!(guardrail.error_on_intervention);
+        assert!(guardrail.validate().is_ok());
+        assert_eq!(
+            guardrail.headers(),
+            [
+                (GUARDRAIL_IDENTIFIER_HEADER, "gr-abc123".to_string()),
+                (GUARDRAIL_VERSION_HEADER, "2".to_string()),
+                (GUARDRAIL_TRACE_HEADER, "ENABLED".to_string()),
+            ]
+        );
+
+        // Guardrail settings travel as headers, never in the body
+        let json = serde_json::to_value(&request).unwrap();
+        assert!(json.get("guardrail").is_none());
+
+        assert!(GuardrailConfig::new("gr-abc123", "DRAFT").validate().is_ok());
+        assert!(GuardrailConfig::new("gr-abc123", "0").validate().is_err());
+        assert!(GuardrailConfig::new("", "1").validate().is_err());
+    }
+
     #[test]
     fn test_titan_embed_request() {
         let request = TitanEmbedRequest::new("Hello, world!")
@@ -279,6 +611,39 @@ mod tests {
         assert_eq!(request.normalize, Some(true));
     }
 
+    #[test]
+    fn test_titan_image_request_serialization() {
+        let request = TitanImageRequest::new("A lighthouse at dusk")
+            .with_negative_prompt("people")
+            .with_number_of_images(2)
+            .with_size(1024, 1024)
+            .with_cfg_scale(8.0)
+            .with_seed(42);
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "taskType": "TEXT_IMAGE",
+                "textToImageParams": {
+                    "text": "A lighthouse at dusk",
+                    "negativeText": "people"
+                },
+                "imageGenerationConfig": {
+                    "numberOfImages": 2,
+                    "height": 1024,
+                    "width": 1024,
+                    "cfgScale": 8.0,
+                    "seed": 42
+                }
+            })
+        );
+
+        let minimal = serde_json::to_value(TitanImageRequest::new("A cat")).unwrap();
+        assert!(minimal["textToImageParams"].get("negativeText").is_none());
+        assert_eq!(minimal["imageGenerationConfig"], serde_json::json!({}));
+    }
+
     #[test]
     fn test_claude_message_from_message() {
         let msg = Message::user("Hello");
@@ -1,6 +1,6 @@
 //! Response types for AWS Bedrock operations.
 
-use super::common::{StopReason, UsageInfo};
+use super::common::{detect_model_family, GuardrailAction, ModelFamily, StopReason, UsageInfo};
 use serde::{Deserialize, Serialize};
 
 /// Unified invoke response that works across all model families.
@@ -14,6 +14,48 @@ pub struct UnifiedInvokeResponse {
     pub usage: UsageInfo,
     /// Model ID that was invoked.
     pub model_id: String,
+    /// Guardrail assessment, when a guardrail was applied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guardrail: Option<GuardrailAssessment>,
+}
+
+impl UnifiedInvokeResponse {
+    /// Returns true if a guardrail intervened, in which case `content` holds
+    /// the guardrail's replacement message rather than model output.
+    pub fn guardrail_intervened(&self) -> bool {
+        self.guardrail
+            .as_ref()
+            .map(|g| g.action == GuardrailAction::Intervened)
+            .unwrap_or(false)
+    }
+}
+
+/// Body field carrying the guardrail action.
+pub const GUARDRAIL_ACTION_FIELD: &str = "amazon-bedrock-guardrailAction";
+
+/// Body field carrying the guardrail trace.
+pub const GUARDRAIL_TRACE_FIELD: &str = "amazon-bedrock-trace";
+
+/// Guardrail result reported in an invoke response body.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuardrailAssessment {
+    /// Action the guardrail took.
+    pub action: GuardrailAction,
+    /// Raw guardrail trace, when tracing was enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace: Option<serde_json::Value>,
+}
+
+impl GuardrailAssessment {
+    /// Read the guardrail result from a response body, if present.
+    pub fn from_body(json: &serde_json::Value) -> Option<Self> {
+        let action = serde_json::from_value(json.get(GUARDRAIL_ACTION_FIELD)?.clone()).ok()?;
+        let trace = json
+            .get(GUARDRAIL_TRACE_FIELD)
+            .and_then(|t| t.get("guardrail"))
+            .cloned();
+        Some(Self { action, trace })
+    }
 }
 
 /// Unified streaming chunk.
@@ -32,6 +74,9 @@ pub struct UnifiedStreamChunk {
     /// Content block index (for multi-block responses).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub index: Option<usize>,
+    /// Guardrail assessment (only on final chunk, when a guardrail was applied).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guardrail: Option<GuardrailAssessment>,
 }
 
 impl UnifiedStreamChunk {
@@ -43,6 +88,7 @@ impl UnifiedStreamChunk {
             stop_reason: None,
             usage: None,
             index: None,
+            guardrail: None,
         }
     }
 
@@ -54,6 +100,7 @@ impl UnifiedStreamChunk {
             stop_reason: Some(stop_reason),
             usage: Some(usage),
             index: None,
+            guardrail: None,
         }
     }
 }
@@ -94,6 +141,18 @@ pub struct TitanEmbedResponse {
     pub input_text_token_count: u32,
 }
 
+/// Titan image generation response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TitanImageResponse {
+    /// Base64-encoded images.
+    #[serde(default)]
+    pub images: Vec<String>,
+    /// Error message (set when content is filtered).
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
 /// Titan streaming chunk.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -134,6 +193,7 @@ pub struct ClaudeResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stop_sequence: Option<String>,
     /// Token usage.
+    #[serde(default)]
     pub usage: ClaudeUsage,
 }
 
@@ -150,11 +210,13 @@ pub enum ClaudeContentBlock {
 }
 
 /// Claude usage information.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ClaudeUsage {
     /// Input tokens.
+    #[serde(default)]
     pub input_tokens: u32,
     /// Output tokens.
+    #[serde(default)]
     pub output_tokens: u32,
 }
 
@@ -254,8 +316,10 @@ pub struct LlamaResponse {
     /// Generated text.
     pub generation: String,
     /// Number of prompt tokens.
+    #[serde(default)]
     pub prompt_token_count: u32,
     /// Number of generation tokens.
+    #[serde(default)]
     pub generation_token_count: u32,
     /// Stop reason.
     pub stop_reason: String,
@@ -354,6 +418,106 @@ pub struct ModelLifecycle {
     pub status: String,
 }
 
+/// List inference profiles response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListInferenceProfilesResponse {
+    /// Inference profile summaries.
+    #[serde(default)]
+    pub inference_profile_summaries: Vec<InferenceProfileSummary>,
+    /// Token for the next page, if any.
+    pub next_token: Option<String>,
+}
+
+/// Inference profile summary.
+///
+/// Application profiles are invoked by ARN and carry the tags used for cost
+/// allocation; system-defined profiles route a model across regions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InferenceProfileSummary {
+    /// Profile ARN, usable as the model ID when invoking.
+    pub inference_profile_arn: String,
+    /// Profile ID.
+    pub inference_profile_id: String,
+    /// Profile name.
+    pub inference_profile_name: String,
+    /// Description.
+    pub description: Option<String>,
+    /// Profile status.
+    pub status: InferenceProfileStatus,
+    /// Profile type.
+    #[serde(rename = "type")]
+    pub profile_type: InferenceProfileType,
+    /// Models the profile routes to, one per region.
+    #[serde(default)]
+    pub models: Vec<InferenceProfileModel>,
+    /// Creation time.
+    pub created_at: Option<String>,
+    /// Last update time.
+    pub updated_at: Option<String>,
+}
+
+impl InferenceProfileSummary {
+    /// Get the foundation model IDs the profile routes to, without duplicates.
+    pub fn model_ids(&self) -> Vec<&str> {
+        let mut ids: Vec<&str> = Vec::new();
+        for model in &self.models {
+            let id = model.model_id();
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+        ids
+    }
+
+    /// Get the model family of the underlying model, if it can be detected.
+    pub fn model_family(&self) -> Option<ModelFamily> {
+        self.models
+            .iter()
+            .find_map(|model| detect_model_family(model.model_id()).ok())
+    }
+}
+
+/// Model an inference profile routes to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InferenceProfileModel {
+    /// Foundation model ARN.
+    pub model_arn: String,
+}
+
+impl InferenceProfileModel {
+    /// Get the model ID from the ARN.
+    pub fn model_id(&self) -> &str {
+        self.model_arn
+            .rsplit_once('/')
+            .map(|(_, id)| id)
+            .unwrap_or(&self.model_arn)
+    }
+}
+
+/// Inference profile status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum InferenceProfileStatus {
+    /// Profile can be invoked.
+    Active,
+    /// Status not known to this client.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Inference profile type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum InferenceProfileType {
+    /// Cross-region profile defined by AWS.
+    SystemDefined,
+    /// Profile created in the account.
+    Application,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -399,4 +563,54 @@ mod tests {
         assert_eq!(response.results[0].output_text, "Hello, world!");
         assert_eq!(response.results[0].completion_reason, Some("FINISH".to_string()));
     }
+
+    #[test]
+    fn test_list_inference_profiles_deserialization() {
+        let json = r#"{
+            "inferenceProfileSummaries": [
+                {
+                    "inferenceProfileName": "chat-team-a",
+                    "description": "Team A chat traffic",
+                    "createdAt": "2024-11-01T12:00:00Z",
+                    "updatedAt": "2024-11-01T12:00:00Z",
+                    "inferenceProfileArn": "arn:aws:bedrock:us-east-1:123456789012:application-inference-profile/a1b2c3d4e5f6",
+                    "models": [
+                        {"modelArn": "arn:aws:bedrock:us-east-1::foundation-model/anthropic.claude-3-haiku-20240307-v1:0"},
+                        {"modelArn": "arn:aws:bedrock:us-west-2::foundation-model/anthropic.claude-3-haiku-20240307-v1:0"}
+                    ],
+                    "inferenceProfileId": "a1b2c3d4e5f6",
+                    "status": "ACTIVE",
+                    "type": "APPLICATION"
+                },
+                {
+                    "inferenceProfileName": "US Meta Llama 3.1 8B Instruct",
+                    "inferenceProfileArn": "arn:aws:bedrock:us-east-1:123456789012:inference-profile/us.meta.llama3-1-8b-instruct-v1:0",
+                    "models": [
+                        {"modelArn": "arn:aws:bedrock:us-east-1::foundation-model/meta.llama3-1-8b-instruct-v1:0"}
+                    ],
+                    "inferenceProfileId": "us.meta.llama3-1-8b-instruct-v1:0",
+                    "status": "PENDING",
+                    "type": "SYSTEM_DEFINED"
+                }
+            ],
+            "nextToken": "page-2"
+        }"#;
+
+        let response: ListInferenceProfilesResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.next_token.as_deref(), Some("page-2"));
+
+        let application = &response.inference_profile_summaries[0];
+        assert_eq!(application.profile_type, InferenceProfileType::Application);
+        assert_eq!(application.status, InferenceProfileStatus::Active);
+        assert_eq!(
+            application.model_ids(),
+            vec!["anthropic.claude-3-haiku-20240307-v1:0"]
+        );
+        assert_eq!(application.model_family(), Some(ModelFamily::Claude));
+
+        let system = &response.inference_profile_summaries[1];
+        assert_eq!(system.profile_type, InferenceProfileType::SystemDefined);
+        assert_eq!(system.status, InferenceProfileStatus::Unknown);
+        assert_eq!(system.model_family(), Some(ModelFamily::Llama));
+    }
 }
@@ -25,6 +25,9 @@ pub struct S3Config {
     ///
     /// Path-style: `https://s3.region.amazonaws.com/bucket/key`
     /// Virtual-hosted: `https://bucket.s3.region.amazonaws.com/key`
+    ///
+    /// Defaults to `true` when a custom endpoint is set. Buckets whose names
+    /// are not valid DNS labels (e.g. contain dots) always use path-style.
     pub path_style: bool,
 
     /// Use dual-stack (IPv4/IPv6) endpoints.
@@ -144,10 +147,26 @@ impl S3Config {
         S3ConfigBuilder::default()
     }
 
+    /// Check whether requests for a bucket use path-style addressing.
+    ///
+    /// Falls back to path-style for bucket names that cannot be used as a
+    /// virtual host, since dotted names break TLS wildcard certificates.
+    pub fn uses_path_style(&self, bucket: &str) -> bool {
+        self.path_style || !is_virtual_hostable_bucket(bucket)
+    }
+
     /// Resolve the endpoint URL for a given bucket.
     pub fn resolve_endpoint(&self, bucket: Option<&str>) -> Url {
+        let virtual_host_bucket = bucket.filter(|b| !self.uses_path_style(b));
+
         if let Some(endpoint) = &self.endpoint {
-            return endpoint.clone();
+            let mut url = endpoint.clone();
+            if let (Some(bucket), Some(host)) = (virtual_host_bucket, endpoint.host_str()) {
+                let host = format!("{}.{}", bucket, host);
+                url.set_host(Some(&host))
+                    .expect("Failed to construct endpoint URL");
+            }
+            return url;
         }
 
         let host = if self.use_fips && self.use_dual_stack {
@@ -160,10 +179,9 @@ impl S3Config {
             format!("s3.{}.amazonaws.com", self.region)
         };
 
-        let url_str = if self.path_style || bucket.is_none() {
-            format!("https://{}", host)
-        } else {
-            format!("https://{}.{}", bucket.unwrap(), host)
+        let url_str = match virtual_host_bucket {
+            Some(bucket) => format!("https://{}.{}", bucket, host),
+            None => format!("https://{}", host),
         };
 
         Url::parse(&url_str).expect("Failed to construct endpoint URL")
@@ -171,7 +189,7 @@ impl S3Config {
 
     /// Build the path for an S3 request.
     pub fn build_path(&self, bucket: &str, key: Option<&str>) -> String {
-        if self.path_style || self.endpoint.is_some() {
+        if self.uses_path_style(bucket) {
             match key {
                 Some(k) => format!("/{}/{}", bucket, k),
                 None => format!("/{}", bucket),
@@ -185,6 +203,20 @@ impl S3Config {
     }
 }
 
+/// Check whether a bucket name can be used as a virtual-hosted subdomain.
+///
+/// The name must be a single DNS label: 3-63 lowercase letters, digits and
+/// hyphens, starting and ending with a letter or digit.
+pub fn is_virtual_hostable_bucket(bucket: &str) -> bool {
+    let bytes = bucket.as_bytes();
+    (3..=63).contains(&bytes.len())
+        && bytes
+            .iter()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || *b == b'-')
+        && bytes[0] != b'-'
+        && bytes[bytes.len() - 1] != b'-'
+}
+
 /// Builder for S3 configuration.
 #[derive(Default)]
 pub struct S3ConfigBuilder {
@@ -251,11 +283,19 @@ impl S3ConfigBuilder {
     }
 
     /// Enable path-style addressing.
+    ///
+    /// When unset, path-style is used for custom endpoints and virtual-hosted
+    /// style for AWS.
     pub fn path_style(mut self, enabled: bool) -> Self {
         self.path_style = Some(enabled);
         self
     }
 
+    /// Force path-style addressing (alias for [`path_style`](Self::path_style)).
+    pub fn force_path_style(self, enabled: bool) -> Self {
+        self.path_style(enabled)
+    }
+
     /// Enable dual-stack endpoints.
     pub fn dual_stack(mut self, enabled: bool) -> Self {
         self.use_dual_stack = Some(enabled);
@@ -469,8 +509,10 @@ impl S3ConfigBuilder {
             credentials_provider: self
                 .credentials_provider
                 .unwrap_or(defaults.credentials_provider),
+            path_style: self
+                .path_style
+                .unwrap_or(self.endpoint.is_some() || defaults.path_style),
             endpoint: self.endpoint,
-            path_style: self.path_style.unwrap_or(defaults.path_style),
             use_dual_stack: self.use_dual_stack.unwrap_or(defaults.use_dual_stack),
             use_fips: self.use_fips.unwrap_or(defaults.use_fips),
             connect_timeout: self.connect_timeout.unwrap_or(defaults.connect_timeout),
@@ -565,6 +607,61 @@ mod tests {
         assert_eq!(endpoint.as_str(), "http://localhost:9000/");
     }
 
+    #[test]
+    fn test_custom_endpoint_defaults_to_path_style() {
+        let config = S3Config::builder()
+            .endpoint("http://localhost:9000")
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(config.path_style);
+        assert_eq!(
+            config.build_path("my-bucket", Some("key.txt")),
+            "/my-bucket/key.txt"
+        );
+    }
+
+    #[test]
+    fn test_custom_endpoint_virtual_hosted() {
+        let config = S3Config::builder()
+            .endpoint("https://storage.example.com")
+            .unwrap()
+            .force_path_style(false)
+            .build()
+            .unwrap();
+        let endpoint = config.resolve_endpoint(Some("my-bucket"));
+        assert_eq!(endpoint.as_str(), "https://my-bucket.storage.example.com/");
+        assert_eq!(config.build_path("my-bucket", Some("key.txt")), "/key.txt");
+        assert_eq!(
+            config.resolve_endpoint(None).as_str(),
+            "https://storage.example.com/"
+        );
+    }
+
+    #[test]
+    fn test_dotted_bucket_falls_back_to_path_style() {
+        let config = S3Config::default();
+        assert!(!config.path_style);
+        assert!(config.uses_path_style("my.dotted.bucket"));
+
+        let endpoint = config.resolve_endpoint(Some("my.dotted.bucket"));
+        assert_eq!(endpoint.as_str(), "https://s3.us-east-1.amazonaws.com/");
+        assert_eq!(
+            config.build_path("my.dotted.bucket", Some("key.txt")),
+            "/my.dotted.bucket/key.txt"
+        );
+    }
+
+    #[test]
+    fn test_is_virtual_hostable_bucket() {
+        assert!(is_virtual_hostable_bucket("my-bucket"));
+        assert!(is_virtual_hostable_bucket("bucket123"));
+        assert!(!is_virtual_hostable_bucket("my.bucket"));
+        assert!(!is_virtual_hostable_bucket("My_Bucket"));
+        assert!(!is_virtual_hostable_bucket("-bucket"));
+        assert!(!is_virtual_hostable_bucket("ab"));
+    }
+
     #[test]
     fn test_build_path_virtual_hosted() {
         let config = S3Config::default();
@@ -65,10 +65,11 @@ pub use services::{
     BucketsService, MultipartService, ObjectsService, PresignService, TaggingService,
 };
 pub use signing::{AwsSigner, AwsSignerV4};
-pub use transport::{HttpRequest, HttpResponse, HttpTransport};
+pub use transport::{ByteStream, HttpRequest, HttpResponse, HttpStreamingResponse, HttpTransport};
 pub use transfer::{
-    calculate_md5, calculate_sha256, ChunkedReader, ProgressCallback, TransferConfig,
-    TransferManager, TransferProgress,
+    calculate_checksum, calculate_md5, calculate_sha256, download_object_parallel,
+    upload_from_reader, ChunkedReader, ProgressCallback, TransferConfig, TransferManager,
+    TransferProgress,
 };
 pub use types::{
     // Request types
@@ -93,6 +94,7 @@ pub use types::{
     PutBucketTaggingRequest,
     PutObjectRequest,
     PutObjectTaggingRequest,
+    UploadPartCopyRequest,
     UploadPartRequest,
     // Response types
     CompleteMultipartUploadOutput,
@@ -112,10 +114,12 @@ pub use types::{
     ListObjectsV2Output,
     ListPartsOutput,
     MultipartUpload,
+    PresignedPost,
     PresignedUrl,
     PutBucketTaggingOutput,
     PutObjectOutput,
     PutObjectTaggingOutput,
+    UploadPartCopyOutput,
     UploadPartOutput,
     // Common types
     Bucket,
@@ -126,6 +130,7 @@ pub use types::{
     ObjectIdentifier,
     Owner,
     Part,
+    PostPolicyCondition,
     S3Object,
     ServerSideEncryption,
     StorageClass,
@@ -2,7 +2,7 @@
 
 use crate::error::S3Error;
 use crate::signing::AwsSigner;
-use crate::types::PresignedUrl;
+use crate::types::{PostPolicyCondition, PresignedPost, PresignedUrl};
 use async_trait::async_trait;
 use bytes::Bytes;
 use std::collections::HashMap;
@@ -190,6 +190,42 @@ impl AwsSigner for MockSigner {
             signed_headers: additional_headers.cloned().unwrap_or_default(),
         })
     }
+    async fn presign_post(
+        &self,
+        url: &Url,
+        bucket: &str,
+        conditions: &[PostPolicyCondition],
+        expires_in: Duration,
+    ) -> Result<PresignedPost, S3Error> {
+        self.presign_count.fetch_add(1, Ordering::Relaxed);
+
+        // Check for error
+        if let Some(error) = self.error.lock().unwrap().take() {
+            return Err(error);
+        }
+
+        // Build a mock policy with the requested conditions
+        let mut policy_conditions: Vec<serde_json::Value> =
+            conditions.iter().map(|c| c.to_json()).collect();
+        policy_conditions.push(serde_json::json!({ "bucket": bucket }));
+        let policy = serde_json::json!({ "conditions": policy_conditions });
+
+        let mut fields = HashMap::new();
+        fields.insert("policy".to_string(), base64::encode(policy.to_string()));
+        fields.insert("x-amz-algorithm".to_string(), "AWS4-HMAC-SHA256".to_string());
+        fields.insert(
+            "x-amz-credential".to_string(),
+            "MOCK/20240115/us-east-1/s3/aws4_request".to_string(),
+        );
+        fields.insert("x-amz-date".to_string(), "20240115T100000Z".to_string());
+        fields.insert("x-amz-signature".to_string(), "mock-signature".to_string());
+
+        Ok(PresignedPost {
+            url: url.to_string(),
+            fields,
+            expires_at: chrono::Utc::now() + chrono::Duration::from_std(expires_in).unwrap_or_default(),
+        })
+    }
 }
 
 impl std::fmt::Debug for MockSigner {
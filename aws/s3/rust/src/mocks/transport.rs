@@ -1,7 +1,7 @@
 //! Mock HTTP transport for testing.
 
 use crate::error::S3Error;
-use crate::transport::{HttpRequest, HttpResponse, HttpTransport};
+use crate::transport::{HttpRequest, HttpResponse, HttpStreamingResponse, HttpTransport};
 use async_trait::async_trait;
 use bytes::Bytes;
 use std::collections::HashMap;
@@ -16,43 +16,54 @@ pub struct MockResponse {
     pub headers: HashMap<String, String>,
     /// Response body.
     pub body: Bytes,
+    /// Chunk size used when streaming the body (whole body if `None`).
+    pub chunk_size: Option<usize>,
+    /// Error raised after the body has been streamed.
+    pub stream_error: Option<String>,
 }
 
 impl MockResponse {
-    /// Create a successful response with empty body.
-    pub fn ok() -> Self {
+    /// Create a response with the given status and body.
+    fn new(status: u16, body: Bytes) -> Self {
         Self {
-            status: 200,
+            status,
             headers: HashMap::new(),
-            body: Bytes::new(),
+            body,
+            chunk_size: None,
+            stream_error: None,
         }
     }
 
+    /// Create a successful response with empty body.
+    pub fn ok() -> Self {
+        Self::new(200, Bytes::new())
+    }
+
     /// Create a successful response with body.
     pub fn ok_with_body(body: impl Into<Bytes>) -> Self {
-        Self {
-            status: 200,
-            headers: HashMap::new(),
-            body: body.into(),
-        }
+        Self::new(200, body.into())
     }
 
     /// Create a 204 No Content response.
     pub fn no_content() -> Self {
-        Self {
-            status: 204,
-            headers: HashMap::new(),
-            body: Bytes::new(),
-        }
+        Self::new(204, Bytes::new())
     }
 
     /// Create an error response.
     pub fn error(status: u16, body: impl Into<Bytes>) -> Self {
-        Self {
-            status,
-            headers: HashMap::new(),
-            body: body.into(),
-        }
+        Self::new(status, body.into())
+    }
+
+    /// Stream the body in chunks of `chunk_size` bytes.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = Some(chunk_size.max(1));
+        self
+    }
+
+    /// Fail the body stream with a network error after the body is sent.
+    pub fn with_stream_error(mut self, message: impl Into<String>) -> Self {
+        self.stream_error = Some(message.into());
+        self
     }
 
     /// Add a header to the response.
@@ -177,6 +188,18 @@ impl MockTransport {
     pub fn clear_responses(&self) {
         self.responses.lock().unwrap().clear();
     }
+
+    /// Record a request and take the next response.
+    fn next_response(&self, request: HttpRequest) -> Option<MockResponse> {
+        self.requests.lock().unwrap().push(request);
+
+        let mut responses = self.responses.lock().unwrap();
+        if responses.is_empty() {
+            self.default_response.clone()
+        } else {
+            Some(responses.remove(0))
+        }
+    }
 }
 
 impl Default for MockTransport {
@@ -188,20 +211,7 @@ impl Default for MockTransport {
 #[async_trait]
 impl HttpTransport for MockTransport {
     async fn send(&self, request: HttpRequest) -> Result<HttpResponse, S3Error> {
-        // Record the request
-        self.requests.lock().unwrap().push(request.clone());
-
-        // Get the next response from the queue
-        let response = {
-            let mut responses = self.responses.lock().unwrap();
-            if responses.is_empty() {
-                self.default_response.clone()
-            } else {
-                Some(responses.remove(0))
-            }
-        };
-
-        match response {
+        match self.next_response(request) {
             Some(mock) => Ok(HttpResponse {
                 status: mock.status,
                 headers: mock.headers,
@@ -212,6 +222,35 @@ impl HttpTransport for MockTransport {
             })),
         }
     }
+
+    async fn send_streaming_response(
+        &self,
+        request: HttpRequest,
+    ) -> Result<HttpStreamingResponse, S3Error> {
+        let Some(mock) = self.next_response(request) else {
+            return Err(S3Error::Network(crate::error::NetworkError::ConnectionFailed {
+                message: "No mock response available".to_string(),
+            }));
+        };
+
+        let chunk_size = mock.chunk_size.unwrap_or(mock.body.len()).max(1);
+        let mut items: Vec<Result<Bytes, S3Error>> = mock
+            .body
+            .chunks(chunk_size)
+            .map(|chunk| Ok(Bytes::copy_from_slice(chunk)))
+            .collect();
+        if let Some(message) = mock.stream_error {
+            items.push(Err(S3Error::Network(
+                crate::error::NetworkError::ConnectionFailed { message },
+            )));
+        }
+
+        Ok(HttpStreamingResponse {
+            status: mock.status,
+            headers: mock.headers,
+            body: Box::pin(futures::stream::iter(items)),
+        })
+    }
 }
 
 impl std::fmt::Debug for MockTransport {
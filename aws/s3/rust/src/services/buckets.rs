@@ -217,6 +217,103 @@ impl BucketsService {
         Ok("us-east-1".to_string())
     }
 
+    /// Enable or suspend versioning on a bucket.
+    ///
+    /// Versioning cannot be turned off once enabled, only suspended.
+    pub async fn put_bucket_versioning(&self, bucket: &str, enabled: bool) -> Result<(), S3Error> {
+        let status = if enabled {
+            VersioningStatus::Enabled
+        } else {
+            VersioningStatus::Suspended
+        };
+        let body = Bytes::from(xml::build_put_versioning_xml(status));
+
+        self.put_configuration(bucket, "versioning", body).await
+    }
+
+    /// Get the versioning state of a bucket.
+    pub async fn get_bucket_versioning(
+        &self,
+        bucket: &str,
+    ) -> Result<GetBucketVersioningOutput, S3Error> {
+        let url = self.build_url(Some(bucket), Some("versioning"))?;
+        let headers = HashMap::new();
+
+        let signed = self.signer.sign("GET", &url, &headers, None).await?;
+
+        let http_request = HttpRequest::new("GET", signed.url.as_str())
+            .with_headers(signed.headers);
+
+        let response = self.transport.send(http_request).await?;
+
+        if !response.is_success() {
+            return Err(self.parse_error(&response.body, bucket).await);
+        }
+
+        let body_str = String::from_utf8_lossy(&response.body);
+        let mut output = xml::parse_get_bucket_versioning(&body_str)?;
+        output.request_id = response.request_id().map(String::from);
+
+        Ok(output)
+    }
+
+    /// Replace the lifecycle configuration of a bucket.
+    pub async fn put_bucket_lifecycle(
+        &self,
+        bucket: &str,
+        rules: &[LifecycleRule],
+    ) -> Result<(), S3Error> {
+        if rules.is_empty() {
+            return Err(S3Error::Request(crate::error::RequestError::Validation {
+                message: "Lifecycle configuration requires at least one rule".to_string(),
+            }));
+        }
+
+        let body = Bytes::from(xml::build_put_lifecycle_xml(rules));
+
+        self.put_configuration(bucket, "lifecycle", body).await
+    }
+
+    /// Get the lifecycle rules of a bucket.
+    ///
+    /// A bucket without a lifecycle configuration yields an empty rule list.
+    pub async fn get_bucket_lifecycle(
+        &self,
+        bucket: &str,
+    ) -> Result<GetBucketLifecycleOutput, S3Error> {
+        let url = self.build_url(Some(bucket), Some("lifecycle"))?;
+        let headers = HashMap::new();
+
+        let signed = self.signer.sign("GET", &url, &headers, None).await?;
+
+        let http_request = HttpRequest::new("GET", signed.url.as_str())
+            .with_headers(signed.headers);
+
+        let response = self.transport.send(http_request).await?;
+
+        let body_str = String::from_utf8_lossy(&response.body);
+
+        if response.status == 404 {
+            if let Ok(error) = xml::parse_error_response(&body_str) {
+                if error.code == "NoSuchLifecycleConfiguration" {
+                    return Ok(GetBucketLifecycleOutput {
+                        rules: Vec::new(),
+                        request_id: response.request_id().map(String::from),
+                    });
+                }
+            }
+        }
+
+        if !response.is_success() {
+            return Err(self.parse_error(&response.body, bucket).await);
+        }
+
+        let mut output = xml::parse_get_bucket_lifecycle(&body_str)?;
+        output.request_id = response.request_id().map(String::from);
+
+        Ok(output)
+    }
+
     /// Check if a bucket exists.
     pub async fn exists(&self, bucket: &str) -> Result<bool, S3Error> {
         match self.head(HeadBucketRequest::new(bucket)).await {
@@ -226,6 +323,41 @@ impl BucketsService {
         }
     }
 
+    /// PUT an XML configuration document to a bucket subresource.
+    async fn put_configuration(
+        &self,
+        bucket: &str,
+        subresource: &str,
+        body: Bytes,
+    ) -> Result<(), S3Error> {
+        let url = self.build_url(Some(bucket), Some(subresource))?;
+
+        // Lifecycle configuration requires Content-MD5
+        let content_md5 = base64::encode(md5::compute(&body).0);
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/xml".to_string());
+        headers.insert("content-md5".to_string(), content_md5);
+        headers.insert("content-length".to_string(), body.len().to_string());
+
+        let signed = self
+            .signer
+            .sign("PUT", &url, &headers, Some(&body))
+            .await?;
+
+        let http_request = HttpRequest::new("PUT", signed.url.as_str())
+            .with_headers(signed.headers)
+            .with_body(body);
+
+        let response = self.transport.send(http_request).await?;
+
+        if !response.is_success() {
+            return Err(self.parse_error(&response.body, bucket).await);
+        }
+
+        Ok(())
+    }
+
     fn build_url(&self, bucket: Option<&str>, query: Option<&str>) -> Result<Url, S3Error> {
         let endpoint = self.config.resolve_endpoint(bucket);
 
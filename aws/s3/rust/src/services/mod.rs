@@ -2,7 +2,7 @@
 //!
 //! This module provides service implementations for different S3 operations:
 //! - Objects: Put, Get, Delete, Copy, List operations
-//! - Buckets: Create, Delete, List operations
+//! - Buckets: Create, Delete, List, versioning and lifecycle operations
 //! - Multipart: Multipart upload operations
 //! - Presign: Generate presigned URLs
 //! - Tagging: Object tagging operations
@@ -45,6 +45,17 @@ impl MultipartService {
             headers.insert("content-type".to_string(), content_type.clone());
         }
 
+        for (name, value) in [
+            ("content-encoding", &request.content_encoding),
+            ("content-disposition", &request.content_disposition),
+            ("cache-control", &request.cache_control),
+            ("content-language", &request.content_language),
+        ] {
+            if let Some(value) = value {
+                headers.insert(name.to_string(), value.clone());
+            }
+        }
+
         if let Some(storage_class) = &request.storage_class {
             headers.insert(
                 "x-amz-storage-class".to_string(),
@@ -144,6 +155,58 @@ impl MultipartService {
         })
     }
 
+    /// Upload a part by copying a byte range of an existing object.
+    pub async fn upload_part_copy(
+        &self,
+        request: UploadPartCopyRequest,
+    ) -> Result<UploadPartCopyOutput, S3Error> {
+        let query = format!("partNumber={}&uploadId={}", request.part_number, request.upload_id);
+        let url = self.build_url(&request.bucket, Some(&request.key), Some(&query))?;
+
+        let mut copy_source = format!("/{}/{}", request.source_bucket, request.source_key);
+        if let Some(version_id) = &request.source_version_id {
+            copy_source = format!("{}?versionId={}", copy_source, version_id);
+        }
+
+        let mut headers = HashMap::new();
+        headers.insert("x-amz-copy-source".to_string(), copy_source);
+
+        if let Some((start, end)) = request.copy_source_range {
+            headers.insert(
+                "x-amz-copy-source-range".to_string(),
+                format!("bytes={}-{}", start, end),
+            );
+        }
+
+        if let Some(e_tag) = &request.copy_source_if_match {
+            headers.insert("x-amz-copy-source-if-match".to_string(), e_tag.clone());
+        }
+
+        let signed = self.signer.sign("PUT", &url, &headers, None).await?;
+
+        let http_request = HttpRequest::new("PUT", signed.url.as_str())
+            .with_headers(signed.headers);
+
+        let response = self.transport.send(http_request).await?;
+
+        if !response.is_success() {
+            return Err(self.parse_error(&response.body).await);
+        }
+
+        // S3 can report a failed copy in the body of a 200 response
+        let body_str = String::from_utf8_lossy(&response.body);
+        let mut output = xml::parse_copy_part_result(&body_str)?;
+        if output.e_tag.is_empty() {
+            return Err(self.parse_error(&response.body).await);
+        }
+        output.copy_source_version_id = response
+            .get_header("x-amz-copy-source-version-id")
+            .map(String::from);
+        output.request_id = response.request_id().map(String::from);
+
+        Ok(output)
+    }
+
     /// Complete a multipart upload.
     pub async fn complete(
         &self,
@@ -1,12 +1,14 @@
 //! Objects service for S3 object operations.
 
 use crate::config::S3Config;
-use crate::error::{ObjectError, S3Error};
+use crate::error::{ObjectError, S3Error, TransferError};
 use crate::signing::{sha256_hex, AwsSigner};
-use crate::transport::{HttpRequest, HttpTransport};
+use crate::transfer::calculate_checksum;
+use crate::transport::{HttpRequest, HttpResponse, HttpTransport};
 use crate::types::*;
 use crate::xml;
 use bytes::Bytes;
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
 use std::collections::HashMap;
 use std::sync::Arc;
 use url::Url;
@@ -59,22 +61,20 @@ impl ObjectsService {
         }
 
         if let Some(encryption) = &request.server_side_encryption {
-            headers.insert(
-                "x-amz-server-side-encryption".to_string(),
-                encryption.as_header_value().to_string(),
-            );
-            if let ServerSideEncryption::AwsKms { key_id: Some(key) } = encryption {
-                headers.insert(
-                    "x-amz-server-side-encryption-aws-kms-key-id".to_string(),
-                    key.clone(),
-                );
-            }
+            insert_encryption_headers(&mut headers, encryption)?;
         }
 
         if let Some(cache_control) = &request.cache_control {
             headers.insert("cache-control".to_string(), cache_control.clone());
         }
 
+        if let Some(if_match) = &request.if_match {
+            headers.insert("if-match".to_string(), if_match.clone());
+        }
+        if let Some(if_none_match) = &request.if_none_match {
+            headers.insert("if-none-match".to_string(), if_none_match.clone());
+        }
+
         // Add user metadata
         for (key, value) in &request.metadata {
             headers.insert(format!("x-amz-meta-{}", key), value.clone());
@@ -101,6 +101,10 @@ impl ObjectsService {
 
         let response = self.transport.send(http_request).await?;
 
+        if response.status == 412 {
+            return Err(put_precondition_failed(&request, &response));
+        }
+
         if !response.is_success() {
             return Err(self.parse_error(&response.body, response.request_id()).await);
         }
@@ -122,7 +126,78 @@ impl ObjectsService {
     }
 
     /// Get an object from a bucket.
+    ///
+    /// The whole body is buffered in memory; use [`get_streaming`](Self::get_streaming)
+    /// for large objects.
     pub async fn get(&self, request: GetObjectRequest) -> Result<GetObjectOutput, S3Error> {
+        let http_request = self.build_get_request(&request).await?;
+        let response = self.transport.send(http_request).await?;
+
+        if response.status == 304 {
+            return Err(not_modified(&request, &response));
+        }
+
+        if !response.is_success() {
+            return Err(self.parse_error(&response.body, response.request_id()).await);
+        }
+
+        if request.checksum_mode {
+            verify_body_checksum(&response)?;
+        }
+
+        let body = response.body.clone();
+        Ok(get_object_output(&response, body))
+    }
+
+    /// Get an object from a bucket, streaming the body.
+    ///
+    /// Returns as soon as the response headers arrive. The returned output has
+    /// an empty `body`; the object data is read lazily from the stream. A
+    /// failure while reading the body is reported as
+    /// [`TransferError::StreamInterrupted`] with the number of bytes received.
+    /// Checksums are not verified; use [`get`](Self::get) with `checksum_mode`
+    /// for that.
+    pub async fn get_streaming(
+        &self,
+        request: GetObjectRequest,
+    ) -> Result<
+        (
+            GetObjectOutput,
+            impl Stream<Item = Result<Bytes, S3Error>> + Send + 'static,
+        ),
+        S3Error,
+    > {
+        let http_request = self.build_get_request(&request).await?;
+        let response = self.transport.send_streaming_response(http_request).await?;
+
+        if !response.is_success() {
+            let response = response.into_buffered().await?;
+            if response.status == 304 {
+                return Err(not_modified(&request, &response));
+            }
+            return Err(self.parse_error(&response.body, response.request_id()).await);
+        }
+
+        let (head, body) = response.into_parts();
+        let output = get_object_output(&head, Bytes::new());
+
+        let mut bytes_transferred = 0u64;
+        let body = body.map(move |chunk| match chunk {
+            Ok(bytes) => {
+                bytes_transferred += bytes.len() as u64;
+                Ok(bytes)
+            }
+            Err(e) => Err(S3Error::Transfer(TransferError::StreamInterrupted {
+                bytes_transferred,
+                message: e.to_string(),
+            })),
+        });
+
+        Ok((output, body))
+    }
+
+    /// Build and sign a GetObject request.
+    async fn build_get_request(&self, request: &GetObjectRequest) -> Result<HttpRequest, S3Error> {
         let mut query_params = Vec::new();
         if let Some(version_id) = &request.version_id {
             query_params.push(format!("versionId={}", version_id));
@@ -156,75 +231,20 @@ impl ObjectsService {
         if let Some(if_unmodified_since) = &request.if_unmodified_since {
             headers.insert("if-unmodified-since".to_string(), if_unmodified_since.clone());
         }
-
-        let signed = self.signer.sign("GET", &url, &headers, None).await?;
-
-        let http_request = HttpRequest::new("GET", signed.url.as_str())
-            .with_headers(signed.headers);
-
-        let response = self.transport.send(http_request).await?;
-
-        if response.status == 304 {
-            return Err(S3Error::Object(ObjectError::NotModified {
-                bucket: request.bucket.clone(),
-                key: request.key.clone(),
-                request_id: response.request_id().map(String::from),
-            }));
+        insert_sse_customer_headers(
+            &mut headers,
+            SSE_CUSTOMER_HEADER_PREFIX,
+            request.sse_customer_algorithm.as_deref(),
+            request.sse_customer_key.as_deref(),
+            request.sse_customer_key_md5.as_deref(),
+        )?;
+        if request.checksum_mode {
+            headers.insert("x-amz-checksum-mode".to_string(), "ENABLED".to_string());
         }
 
-        if !response.is_success() {
-            return Err(self.parse_error(&response.body, response.request_id()).await);
-        }
-
-        // Extract metadata from x-amz-meta-* headers
-        let metadata: HashMap<String, String> = response
-            .headers
-            .iter()
-            .filter(|(k, _)| k.to_lowercase().starts_with("x-amz-meta-"))
-            .map(|(k, v)| {
-                let key = k
-                    .to_lowercase()
-                    .strip_prefix("x-amz-meta-")
-                    .unwrap_or(k)
-                    .to_string();
-                (key, v.clone())
-            })
-            .collect();
+        let signed = self.signer.sign("GET", &url, &headers, None).await?;
 
-        Ok(GetObjectOutput {
-            body: response.body,
-            e_tag: response.etag().map(String::from),
-            content_length: response.content_length(),
-            content_type: response.content_type().map(String::from),
-            content_encoding: response.get_header("content-encoding").map(String::from),
-            content_disposition: response.get_header("content-disposition").map(String::from),
-            cache_control: response.get_header("cache-control").map(String::from),
-            content_language: response.get_header("content-language").map(String::from),
-            last_modified: response.get_header("last-modified").map(String::from),
-            version_id: response.get_header("x-amz-version-id").map(String::from),
-            storage_class: response
-                .get_header("x-amz-storage-class")
-                .and_then(|v| v.parse().ok()),
-            server_side_encryption: response
-                .get_header("x-amz-server-side-encryption")
-                .map(String::from),
-            sse_kms_key_id: response
-                .get_header("x-amz-server-side-encryption-aws-kms-key-id")
-                .map(String::from),
-            metadata,
-            tag_count: response
-                .get_header("x-amz-tagging-count")
-                .and_then(|v| v.parse().ok()),
-            delete_marker: response
-                .get_header("x-amz-delete-marker")
-                .map(|v| v == "true"),
-            parts_count: response
-                .get_header("x-amz-mp-parts-count")
-                .and_then(|v| v.parse().ok()),
-            content_range: response.get_header("content-range").map(String::from),
-            accept_ranges: response.get_header("accept-ranges").map(String::from),
-            request_id: response.request_id().map(String::from),
-        })
+        Ok(HttpRequest::new("GET", signed.url.as_str()).with_headers(signed.headers))
     }
 
     /// Delete an object.
@@ -318,7 +338,14 @@ impl ObjectsService {
         };
 
         let url = self.build_url(&request.bucket, Some(&request.key), query.as_deref())?;
-        let headers = HashMap::new();
+        let mut headers = HashMap::new();
+        insert_sse_customer_headers(
+            &mut headers,
+            SSE_CUSTOMER_HEADER_PREFIX,
+            request.sse_customer_algorithm.as_deref(),
+            request.sse_customer_key.as_deref(),
+            request.sse_customer_key_md5.as_deref(),
+        )?;
 
         let signed = self.signer.sign("HEAD", &url, &headers, None).await?;
 
@@ -422,6 +449,17 @@ impl ObjectsService {
             headers.insert("x-amz-acl".to_string(), acl.as_str().to_string());
         }
 
+        if let Some(encryption) = &request.server_side_encryption {
+            insert_encryption_headers(&mut headers, encryption)?;
+        }
+        insert_sse_customer_headers(
+            &mut headers,
+            COPY_SOURCE_SSE_CUSTOMER_HEADER_PREFIX,
+            request.copy_source_sse_customer_algorithm.as_deref(),
+            request.copy_source_sse_customer_key.as_deref(),
+            request.copy_source_sse_customer_key_md5.as_deref(),
+        )?;
+
         let signed = self.signer.sign("PUT", &url, &headers, None).await?;
 
         let http_request = HttpRequest::new("PUT", signed.url.as_str())
@@ -494,6 +532,44 @@ impl ObjectsService {
         Ok(output)
     }
 
+    /// List objects (v2) page by page, following continuation tokens.
+    ///
+    /// Each item is one `ListObjectsV2Output`. A `max_keys` set on the request
+    /// is used as the page size. The stream ends after the first error.
+    pub fn list_objects_pages(
+        &self,
+        request: ListObjectsV2Request,
+    ) -> impl Stream<Item = Result<ListObjectsV2Output, S3Error>> + '_ {
+        stream::unfold(Some(request), move |state| async move {
+            let request = state?;
+            match self.list(request.clone()).await {
+                Ok(page) => {
+                    let next = match (&page.next_continuation_token, page.is_truncated) {
+                        (Some(token), true) => Some(ListObjectsV2Request {
+                            continuation_token: Some(token.clone()),
+                            ..request
+                        }),
+                        _ => None,
+                    };
+                    Some((Ok(page), next))
+                }
+                Err(e) => Some((Err(e), None)),
+            }
+        })
+    }
+
+    /// List objects (v2) one at a time across all pages.
+    ///
+    /// An empty bucket yields an empty stream.
+    pub fn list_objects_paginated(
+        &self,
+        request: ListObjectsV2Request,
+    ) -> impl Stream<Item = Result<S3Object, S3Error>> + '_ {
+        self.list_objects_pages(request)
+            .map_ok(|page| stream::iter(page.contents.into_iter().map(Ok)))
+            .try_flatten()
+    }
+
     fn build_url(
         &self,
         bucket: &str,
@@ -536,6 +612,229 @@ impl ObjectsService {
     }
 }
 
+/// Build a `GetObjectOutput` from response headers and a body.
+fn get_object_output(response: &HttpResponse, body: Bytes) -> GetObjectOutput {
+    // Extract metadata from x-amz-meta-* headers
+    let metadata: HashMap<String, String> = response
+        .headers
+        .iter()
+        .filter(|(k, _)| k.to_lowercase().starts_with("x-amz-meta-"))
+        .map(|(k, v)| {
+            let key = k
+                .to_lowercase()
+                .strip_prefix("x-amz-meta-")
+                .unwrap_or(k)
+                .to_string();
+            (key, v.clone())
+        })
+        .collect();
+
+    GetObjectOutput {
+        body,
+        e_tag: response.etag().map(String::from),
+        content_length: response.content_length(),
+        content_type: response.content_type().map(String::from),
+        content_encoding: response.get_header("content-encoding").map(String::from),
+        content_disposition: response.get_header("content-disposition").map(String::from),
+        cache_control: response.get_header("cache-control").map(String::from),
+        content_language: response.get_header("content-language").map(String::from),
+        last_modified: response.get_header("last-modified").map(String::from),
+        version_id: response.get_header("x-amz-version-id").map(String::from),
+        storage_class: response
+            .get_header("x-amz-storage-class")
+            .and_then(|v| v.parse().ok()),
+        server_side_encryption: response
+            .get_header("x-amz-server-side-encryption")
+            .map(String::from),
+        sse_kms_key_id: response
+            .get_header("x-amz-server-side-encryption-aws-kms-key-id")
+            .map(String::from),
+        metadata,
+        tag_count: response
+            .get_header("x-amz-tagging-count")
+            .and_then(|v| v.parse().ok()),
+        delete_marker: response
+            .get_header("x-amz-delete-marker")
+            .map(|v| v == "true"),
+        parts_count: response
+            .get_header("x-amz-mp-parts-count")
+            .and_then(|v| v.parse().ok()),
+        content_range: response.get_header("content-range").map(String::from),
+        accept_ranges: response.get_header("accept-ranges").map(String::from),
+        request_id: response.request_id().map(String::from),
+    }
+}
+
+fn not_modified(request: &GetObjectRequest, response: &HttpResponse) -> S3Error {
+    S3Error::Object(ObjectError::NotModified {
+        bucket: request.bucket.clone(),
+        key: request.key.clone(),
+        request_id: response.request_id().map(String::from),
+    })
+}
+
+/// Map a 412 on a conditional put, naming the write conditions that were sent.
+fn put_precondition_failed(request: &PutObjectRequest, response: &HttpResponse) -> S3Error {
+    let condition = [
+        ("If-Match", &request.if_match),
+        ("If-None-Match", &request.if_none_match),
+    ]
+    .into_iter()
+    .filter_map(|(name, value)| value.as_ref().map(|v| format!("{}: {}", name, v)))
+    .collect::<Vec<_>>()
+    .join(", ");
+    let condition = if condition.is_empty() {
+        "Precondition failed".to_string()
+    } else {
+        condition
+    };
+
+    S3Error::Object(ObjectError::PreconditionFailed {
+        bucket: request.bucket.clone(),
+        key: request.key.clone(),
+        condition,
+        request_id: response.request_id().map(String::from),
+    })
+}
+
+/// Verify a GET response body against the checksum S3 returned for it.
+///
+/// An `x-amz-checksum-*` header is preferred. Composite checksums of multipart
+/// objects (`<checksum>-<parts>`) cannot be checked against the whole body and
+/// are skipped. Without a usable checksum header the ETag is compared when it
+/// is a plain MD5, which is not the case for multipart uploads, SSE-KMS or
+/// SSE-C objects, or ranged reads.
+fn verify_body_checksum(response: &HttpResponse) -> Result<(), S3Error> {
+    let algorithms = [
+        ChecksumAlgorithm::Crc32,
+        ChecksumAlgorithm::Crc32c,
+        ChecksumAlgorithm::Sha1,
+        ChecksumAlgorithm::Sha256,
+    ];
+    for algorithm in algorithms {
+        let header = format!("x-amz-checksum-{}", algorithm.as_str().to_lowercase());
+        let Some(expected) = response.get_header(&header) else {
+            continue;
+        };
+        if expected.contains('-') {
+            continue;
+        }
+        let actual = calculate_checksum(algorithm, &response.body);
+        if actual != expected {
+            return Err(S3Error::Transfer(TransferError::ChecksumMismatch {
+                expected: expected.to_string(),
+                actual,
+            }));
+        }
+        return Ok(());
+    }
+
+    let plain_md5_etag = response.status != 206
+        && !matches!(response.get_header("x-amz-server-side-encryption"), Some("aws:kms" | "aws:kms:dsse"))
+        && response
+            .get_header("x-amz-server-side-encryption-customer-algorithm")
+            .is_none();
+    let e_tag = response.etag().map(|e| e.trim_matches('"'));
+    if let Some(expected) = e_tag {
+        if plain_md5_etag && expected.len() == 32 && expected.chars().all(|c| c.is_ascii_hexdigit()) {
+            let actual = hex::encode(md5::compute(&response.body).0);
+            if !actual.eq_ignore_ascii_case(expected) {
+                return Err(S3Error::Transfer(TransferError::ChecksumMismatch {
+                    expected: expected.to_string(),
+                    actual,
+                }));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Header prefix for SSE-C keys of the object being read or written.
+const SSE_CUSTOMER_HEADER_PREFIX: &str = "x-amz-server-side-encryption-customer";
+
+/// Header prefix for SSE-C keys of a copy source.
+const COPY_SOURCE_SSE_CUSTOMER_HEADER_PREFIX: &str =
+    "x-amz-copy-source-server-side-encryption-customer";
+
+/// Add the headers for a server-side encryption setting.
+fn insert_encryption_headers(
+    headers: &mut HashMap<String, String>,
+    encryption: &ServerSideEncryption,
+) -> Result<(), S3Error> {
+    match encryption {
+        ServerSideEncryption::CustomerProvided {
+            algorithm,
+            key,
+            key_md5,
+        } => insert_sse_customer_headers(
+            headers,
+            SSE_CUSTOMER_HEADER_PREFIX,
+            Some(algorithm),
+            Some(key),
+            Some(key_md5),
+        ),
+        _ => {
+            headers.insert(
+                "x-amz-server-side-encryption".to_string(),
+                encryption.as_header_value().to_string(),
+            );
+            if let ServerSideEncryption::AwsKms { key_id: Some(key) } = encryption {
+                headers.insert(
+                    "x-amz-server-side-encryption-aws-kms-key-id".to_string(),
+                    key.clone(),
+                );
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Add the algorithm, key and key MD5 headers for an SSE-C key.
+///
+/// The key must decode to 32 bytes. A missing MD5 is computed from the key and
+/// a supplied one must match it.
+fn insert_sse_customer_headers(
+    headers: &mut HashMap<String, String>,
+    prefix: &str,
+    algorithm: Option<&str>,
+    key: Option<&str>,
+    key_md5: Option<&str>,
+) -> Result<(), S3Error> {
+    let Some(key) = key else {
+        return Ok(());
+    };
+
+    let invalid = |message: String| {
+        S3Error::Request(crate::error::RequestError::Validation { message })
+    };
+
+    let key_bytes = base64::decode(key)
+        .map_err(|e| invalid(format!("SSE-C key is not valid base64: {}", e)))?;
+    if key_bytes.len() != SSE_CUSTOMER_KEY_LENGTH {
+        return Err(invalid(format!(
+            "SSE-C key must be {} bytes, got {}",
+            SSE_CUSTOMER_KEY_LENGTH,
+            key_bytes.len()
+        )));
+    }
+
+    let expected_md5 = base64::encode(md5::compute(&key_bytes).0);
+    if let Some(key_md5) = key_md5 {
+        if key_md5 != expected_md5 {
+            return Err(invalid("SSE-C key MD5 does not match the key".to_string()));
+        }
+    }
+
+    headers.insert(
+        format!("{}-algorithm", prefix),
+        algorithm.unwrap_or(SSE_CUSTOMER_ALGORITHM).to_string(),
+    );
+    headers.insert(format!("{}-key", prefix), key.to_string());
+    headers.insert(format!("{}-key-md5", prefix), expected_md5);
+    Ok(())
+}
+
 impl std::fmt::Debug for ObjectsService {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ObjectsService")
@@ -115,6 +115,46 @@ impl PresignService {
         self.signer.presign("HEAD", &url, expires_in, None).await
     }
 
+    /// Generate a presigned POST form for browser uploads.
+    ///
+    /// Uploaded keys must start with `key_prefix`; the form's `key` field is
+    /// `key_prefix` followed by `${filename}`, which S3 replaces with the name
+    /// of the uploaded file. `conditions` can further restrict the upload, e.g.
+    /// with a content-length range or a content-type prefix.
+    pub async fn presign_post(
+        &self,
+        bucket: &str,
+        key_prefix: &str,
+        conditions: Vec<PostPolicyCondition>,
+        expires_in: Duration,
+    ) -> Result<PresignedPost, S3Error> {
+        let endpoint = self.config.resolve_endpoint(Some(bucket));
+        let path = self.config.build_path(bucket, None);
+        let url = Url::parse(&format!(
+            "{}{}",
+            endpoint.as_str().trim_end_matches('/'),
+            path
+        ))
+        .map_err(|e| {
+            S3Error::Request(crate::error::RequestError::Validation {
+                message: format!("Invalid URL: {}", e),
+            })
+        })?;
+
+        let mut conditions = conditions;
+        conditions.push(PostPolicyCondition::starts_with("key", key_prefix));
+
+        let mut presigned = self
+            .signer
+            .presign_post(&url, bucket, &conditions, expires_in)
+            .await?;
+        presigned
+            .fields
+            .insert("key".to_string(), format!("{}${{filename}}", key_prefix));
+
+        Ok(presigned)
+    }
+
     fn build_url(
         &self,
         bucket: &str,
@@ -42,6 +42,35 @@ pub trait AwsSigner: Send + Sync {
         expires_in: std::time::Duration,
         headers: Option<&HashMap<String, String>>,
     ) -> Result<crate::types::PresignedUrl, S3Error>;
+
+    /// Create a presigned POST form for browser uploads to `url`.
+    ///
+    /// The signer adds the bucket and its own credential fields to
+    /// `conditions` and returns the signed form fields, including `policy`
+    /// and `x-amz-signature`.
+    async fn presign_post(
+        &self,
+        url: &Url,
+        bucket: &str,
+        conditions: &[crate::types::PostPolicyCondition],
+        expires_in: std::time::Duration,
+    ) -> Result<crate::types::PresignedPost, S3Error>;
+}
+
+/// Maximum lifetime of a presigned URL or POST policy (7 days).
+const MAX_PRESIGN_EXPIRATION_SECS: u64 = 7 * 24 * 60 * 60;
+
+fn check_presign_expiration(expires_in: std::time::Duration) -> Result<u64, S3Error> {
+    let expires_seconds = expires_in.as_secs();
+    if expires_seconds > MAX_PRESIGN_EXPIRATION_SECS {
+        return Err(S3Error::Signing(SigningError::InvalidTimestamp {
+            message: format!(
+                "Presigned URL expiration exceeds maximum of {} seconds",
+                MAX_PRESIGN_EXPIRATION_SECS
+            ),
+        }));
+    }
+    Ok(expires_seconds)
 }
 
 /// AWS Signature V4 signer implementation.
@@ -206,17 +235,7 @@ impl AwsSigner for AwsSignerV4 {
         expires_in: std::time::Duration,
         additional_headers: Option<&HashMap<String, String>>,
     ) -> Result<crate::types::PresignedUrl, S3Error> {
-        const MAX_EXPIRATION: u64 = 7 * 24 * 60 * 60; // 7 days in seconds
-
-        let expires_seconds = expires_in.as_secs();
-        if expires_seconds > MAX_EXPIRATION {
-            return Err(S3Error::Signing(SigningError::InvalidTimestamp {
-                message: format!(
-                    "Presigned URL expiration exceeds maximum of {} seconds",
-                    MAX_EXPIRATION
-                ),
-            }));
-        }
+        let expires_seconds = check_presign_expiration(expires_in)?;
 
         let credentials = self.get_credentials().await?;
         let timestamp = Utc::now();
@@ -357,6 +376,66 @@ impl AwsSigner for AwsSignerV4 {
             signed_headers: signed_headers_map,
         })
     }
+    async fn presign_post(
+        &self,
+        url: &Url,
+        bucket: &str,
+        conditions: &[crate::types::PostPolicyCondition],
+        expires_in: std::time::Duration,
+    ) -> Result<crate::types::PresignedPost, S3Error> {
+        let expires_seconds = check_presign_expiration(expires_in)?;
+
+        let credentials = self.get_credentials().await?;
+        let timestamp = Utc::now();
+        let date_stamp = format_date_stamp(&timestamp);
+        let amz_date = format_datetime(&timestamp);
+        let expires_at = timestamp + Duration::seconds(expires_seconds as i64);
+
+        let credential_scope = build_credential_scope(&date_stamp, &self.region, &self.service);
+        let credential_string = build_credential_string(credentials.access_key_id(), &credential_scope);
+
+        let mut fields = HashMap::new();
+        fields.insert("x-amz-algorithm".to_string(), AWS_ALGORITHM.to_string());
+        fields.insert("x-amz-credential".to_string(), credential_string);
+        fields.insert("x-amz-date".to_string(), amz_date);
+        if let Some(token) = credentials.session_token() {
+            fields.insert("x-amz-security-token".to_string(), token.to_string());
+        }
+
+        // Every signed field must also be a policy condition
+        let mut policy_conditions: Vec<serde_json::Value> =
+            conditions.iter().map(|c| c.to_json()).collect();
+        policy_conditions.push(serde_json::json!({ "bucket": bucket }));
+        let mut signed_fields: Vec<_> = fields.iter().collect();
+        signed_fields.sort();
+        for (name, value) in signed_fields {
+            policy_conditions.push(serde_json::json!({ name.as_str(): value }));
+        }
+
+        let policy = serde_json::json!({
+            "expiration": expires_at.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
+            "conditions": policy_conditions,
+        });
+        let encoded_policy = base64::encode(policy.to_string());
+
+        // The string to sign for a POST policy is the encoded policy itself
+        let signing_key = derive_signing_key(
+            credentials.secret_access_key(),
+            &date_stamp,
+            &self.region,
+            &self.service,
+        );
+        let signature = hex::encode(hmac_sha256(&signing_key, encoded_policy.as_bytes()));
+
+        fields.insert("policy".to_string(), encoded_policy);
+        fields.insert("x-amz-signature".to_string(), signature);
+
+        Ok(crate::types::PresignedPost {
+            url: url.to_string(),
+            fields,
+            expires_at,
+        })
+    }
 }
 
 impl std::fmt::Debug for AwsSignerV4 {
@@ -469,4 +548,48 @@ mod tests {
         let signed = result.unwrap();
         assert!(signed.headers.contains_key("x-amz-security-token"));
     }
+
+    fn object_url(config: &crate::config::S3Config, bucket: &str, key: &str) -> Url {
+        let endpoint = config.resolve_endpoint(Some(bucket));
+        let path = config.build_path(bucket, Some(key));
+        Url::parse(&format!("{}{}", endpoint.as_str().trim_end_matches('/'), path)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_signed_host_matches_addressing_style() {
+        use crate::config::S3Config;
+
+        let signer = create_test_signer();
+        let headers = HashMap::new();
+
+        let virtual_hosted = S3Config::default();
+        let url = object_url(&virtual_hosted, "my-bucket", "test.txt");
+        assert_eq!(url.as_str(), "https://my-bucket.s3.us-east-1.amazonaws.com/test.txt");
+        let signed = signer.sign("GET", &url, &headers, None).await.unwrap();
+        assert_eq!(signed.headers["host"], "my-bucket.s3.us-east-1.amazonaws.com");
+
+        let path_style = S3Config::builder().force_path_style(true).build().unwrap();
+        let url = object_url(&path_style, "my-bucket", "test.txt");
+        assert_eq!(url.as_str(), "https://s3.us-east-1.amazonaws.com/my-bucket/test.txt");
+        let signed = signer.sign("GET", &url, &headers, None).await.unwrap();
+        assert_eq!(signed.headers["host"], "s3.us-east-1.amazonaws.com");
+
+        let minio = S3Config::builder()
+            .endpoint("http://localhost:9000")
+            .unwrap()
+            .build()
+            .unwrap();
+        let url = object_url(&minio, "my-bucket", "test.txt");
+        assert_eq!(url.as_str(), "http://localhost:9000/my-bucket/test.txt");
+        let signed = signer.sign("GET", &url, &headers, None).await.unwrap();
+        assert_eq!(signed.headers["host"], "localhost:9000");
+
+        let dotted = object_url(&virtual_hosted, "my.dotted.bucket", "test.txt");
+        assert_eq!(
+            dotted.as_str(),
+            "https://s3.us-east-1.amazonaws.com/my.dotted.bucket/test.txt"
+        );
+        let signed = signer.sign("GET", &dotted, &headers, None).await.unwrap();
+        assert_eq!(signed.headers["host"], "s3.us-east-1.amazonaws.com");
+    }
 }
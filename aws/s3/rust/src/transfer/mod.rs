@@ -3,12 +3,33 @@
 //! This module provides high-level utilities for efficient data transfer
 //! including streaming, chunked uploads, and progress tracking.
 
-use crate::error::S3Error;
+use crate::error::{RequestError, ResponseError, S3Error, TransferError};
+use crate::resilience::{RetryConfig, RetryPolicy};
+use crate::services::{MultipartService, ObjectsService};
+use crate::types::{
+    ChecksumAlgorithm, CompletedPart, CopyObjectOutput, CopyObjectRequest,
+    CreateMultipartUploadRequest, GetObjectRequest, HeadObjectOutput, HeadObjectRequest,
+    UploadPartCopyRequest, UploadPartRequest,
+};
 use bytes::Bytes;
-use std::io::Read;
+use futures::stream::{self, FuturesUnordered, StreamExt, TryStreamExt};
+use std::io::{Read, SeekFrom};
+use std::path::Path;
 use std::pin::Pin;
 use std::task::{Context, Poll};
-use tokio::io::AsyncRead;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+
+/// Minimum size of every multipart upload part except the last (5 MiB).
+pub const MIN_PART_SIZE: usize = 5 * 1024 * 1024;
+
+/// Maximum number of parts in a multipart upload.
+pub const MAX_PARTS: u32 = 10_000;
+
+/// Largest object S3 accepts in a single CopyObject request (5 GiB).
+pub const MAX_SINGLE_COPY_SIZE: u64 = 5 * 1024 * 1024 * 1024;
+
+/// Number of UploadPartCopy requests kept in flight by a multipart copy.
+const COPY_CONCURRENCY: usize = 4;
 
 /// Progress callback for transfer operations.
 pub type ProgressCallback = Box<dyn Fn(TransferProgress) + Send + Sync>;
@@ -142,6 +163,464 @@ pub fn calculate_sha256(data: &[u8]) -> String {
     hex::encode(hasher.finalize())
 }
 
+/// Calculate a checksum in the base64 form used by `x-amz-checksum-*` headers.
+pub fn calculate_checksum(algorithm: ChecksumAlgorithm, data: &[u8]) -> String {
+    use sha1::Sha1;
+    use sha2::{Digest, Sha256};
+    match algorithm {
+        ChecksumAlgorithm::Crc32 => base64::encode(crc32fast::hash(data).to_be_bytes()),
+        ChecksumAlgorithm::Crc32c => base64::encode(crc32c::crc32c(data).to_be_bytes()),
+        ChecksumAlgorithm::Sha1 => base64::encode(Sha1::digest(data)),
+        ChecksumAlgorithm::Sha256 => base64::encode(Sha256::digest(data)),
+    }
+}
+
+/// Download an object to `dest` using concurrent ranged GETs.
+///
+/// The object size is taken from a HEAD request and split into `part_size`
+/// byte ranges, up to `concurrency` of which are fetched at once and written
+/// at their offsets. Each range is retried on its own when it fails or comes
+/// back short. Objects no larger than one part are fetched with a single GET.
+///
+/// Returns the number of bytes written, or `TransferError::IncompleteBody`
+/// if it does not match the HEAD content length.
+pub async fn download_object_parallel(
+    objects: &ObjectsService,
+    bucket: &str,
+    key: &str,
+    dest: &Path,
+    part_size: u64,
+    concurrency: usize,
+) -> Result<u64, S3Error> {
+    if part_size == 0 {
+        return Err(S3Error::Request(RequestError::Validation {
+            message: "Part size must be greater than zero".to_string(),
+        }));
+    }
+
+    let head = objects.head(HeadObjectRequest::new(bucket, key)).await?;
+    let expected = head.content_length.ok_or_else(|| {
+        S3Error::Response(ResponseError::InvalidResponse {
+            message: "HEAD response is missing content-length".to_string(),
+        })
+    })?;
+    let e_tag = head.e_tag;
+
+    let retry = RetryPolicy::new(RetryConfig::default());
+    let write_error = |received: u64, e: std::io::Error| {
+        S3Error::Transfer(TransferError::StreamInterrupted {
+            bytes_transferred: received,
+            message: format!("Failed to write {}: {}", dest.display(), e),
+        })
+    };
+
+    let mut file = tokio::fs::File::create(dest)
+        .await
+        .map_err(|e| write_error(0, e))?;
+
+    let received = if expected <= part_size {
+        let output = retry
+            .execute(|| {
+                let mut request = GetObjectRequest::new(bucket, key);
+                request.if_match = e_tag.clone();
+                objects.get(request)
+            })
+            .await?;
+        file.write_all(&output.body)
+            .await
+            .map_err(|e| write_error(0, e))?;
+        output.body.len() as u64
+    } else {
+        file.set_len(expected)
+            .await
+            .map_err(|e| write_error(0, e))?;
+
+        let ranges = (0..expected)
+            .step_by(part_size as usize)
+            .map(|start| (start, (start + part_size).min(expected) - 1));
+
+        let mut parts = stream::iter(ranges)
+            .map(|(start, end)| {
+                let retry = &retry;
+                let e_tag = &e_tag;
+                async move {
+                    let body = retry
+                        .execute(|| download_range(objects, bucket, key, e_tag, start, end))
+                        .await?;
+                    Ok::<_, S3Error>((start, body))
+                }
+            })
+            .buffer_unordered(concurrency.max(1));
+
+        let mut received = 0;
+        while let Some(part) = parts.next().await {
+            let (start, body) = part?;
+            file.seek(SeekFrom::Start(start))
+                .await
+                .map_err(|e| write_error(received, e))?;
+            file.write_all(&body)
+                .await
+                .map_err(|e| write_error(received, e))?;
+            received += body.len() as u64;
+        }
+        received
+    };
+
+    file.flush().await.map_err(|e| write_error(received, e))?;
+
+    if received != expected {
+        return Err(S3Error::Transfer(TransferError::IncompleteBody {
+            expected,
+            received,
+        }));
+    }
+
+    Ok(received)
+}
+
+/// Fetch the inclusive byte range `start..=end` of an object.
+///
+/// A short body is reported as a retryable interrupted stream.
+async fn download_range(
+    objects: &ObjectsService,
+    bucket: &str,
+    key: &str,
+    e_tag: &Option<String>,
+    start: u64,
+    end: u64,
+) -> Result<Bytes, S3Error> {
+    let mut request = GetObjectRequest::new(bucket, key).with_range(start, end);
+    request.if_match = e_tag.clone();
+
+    let body = objects.get(request).await?.body;
+    let expected = end - start + 1;
+    if body.len() as u64 != expected {
+        return Err(S3Error::Transfer(TransferError::StreamInterrupted {
+            bytes_transferred: body.len() as u64,
+            message: format!(
+                "Range {}-{} returned {} of {} bytes",
+                start,
+                end,
+                body.len(),
+                expected
+            ),
+        }));
+    }
+
+    Ok(body)
+}
+
+/// Upload everything read from `reader` as a multipart upload.
+///
+/// The source is read in `part_size` chunks and up to `concurrency` parts are
+/// uploaded at once, so at most `concurrency` parts are held in memory. A
+/// source shorter than `part_size` is uploaded as a single part. If reading or
+/// any part fails the upload is aborted so no incomplete upload is left behind.
+///
+/// `part_size` must be at least [`MIN_PART_SIZE`]. Returns the ETag of the
+/// completed object.
+pub async fn upload_from_reader<R>(
+    multipart: &MultipartService,
+    bucket: &str,
+    key: &str,
+    mut reader: R,
+    part_size: usize,
+    concurrency: usize,
+) -> Result<String, S3Error>
+where
+    R: AsyncRead + Unpin,
+{
+    if part_size < MIN_PART_SIZE {
+        return Err(S3Error::Request(RequestError::EntityTooSmall {
+            size: part_size as u64,
+            min_size: MIN_PART_SIZE as u64,
+        }));
+    }
+
+    let upload = multipart
+        .create(CreateMultipartUploadRequest::new(bucket, key))
+        .await?;
+    let upload_id = upload.upload_id;
+
+    let result = match upload_parts(
+        multipart,
+        bucket,
+        key,
+        &upload_id,
+        &mut reader,
+        part_size,
+        concurrency.max(1),
+    )
+    .await
+    {
+        Ok(parts) => multipart.complete(bucket, key, &upload_id, &parts).await,
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(output) => output.e_tag.ok_or_else(|| {
+            S3Error::Response(ResponseError::InvalidResponse {
+                message: "CompleteMultipartUpload response is missing the ETag".to_string(),
+            })
+        }),
+        Err(e) => {
+            // Abort on failure
+            let _ = multipart.abort(bucket, key, &upload_id).await;
+            Err(e)
+        }
+    }
+}
+
+/// Read `reader` part by part and upload the parts, keeping at most
+/// `concurrency` uploads in flight. Returns the parts in order.
+async fn upload_parts<R>(
+    multipart: &MultipartService,
+    bucket: &str,
+    key: &str,
+    upload_id: &str,
+    reader: &mut R,
+    part_size: usize,
+    concurrency: usize,
+) -> Result<Vec<CompletedPart>, S3Error>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut in_flight = FuturesUnordered::new();
+    let mut parts = Vec::new();
+    let mut part_number = 0;
+    let mut bytes_read = 0;
+    let mut eof = false;
+
+    loop {
+        while !eof && in_flight.len() < concurrency {
+            let body = read_part(reader, part_size).await.map_err(|e| {
+                S3Error::Transfer(TransferError::StreamInterrupted {
+                    bytes_transferred: bytes_read,
+                    message: format!("Failed to read upload source: {}", e),
+                })
+            })?;
+            eof = body.len() < part_size;
+            // A source that ends on a part boundary leaves a final empty read
+            if body.is_empty() && part_number > 0 {
+                break;
+            }
+
+            part_number += 1;
+            if part_number > MAX_PARTS {
+                return Err(S3Error::Request(RequestError::Validation {
+                    message: format!(
+                        "Upload source needs more than {} parts of {} bytes",
+                        MAX_PARTS, part_size
+                    ),
+                }));
+            }
+            bytes_read += body.len() as u64;
+
+            let request = UploadPartRequest::new(bucket, key, upload_id, part_number, body);
+            in_flight.push(async move {
+                let part_number = request.part_number;
+                let output = multipart.upload_part(request).await?;
+                Ok::<_, S3Error>(CompletedPart {
+                    part_number,
+                    e_tag: output.e_tag,
+                })
+            });
+        }
+
+        match in_flight.next().await {
+            Some(part) => parts.push(part?),
+            None => break,
+        }
+    }
+
+    parts.sort_by_key(|p| p.part_number);
+    Ok(parts)
+}
+
+/// Read up to `part_size` bytes, stopping early only at the end of `reader`.
+async fn read_part<R>(reader: &mut R, part_size: usize) -> std::io::Result<Bytes>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut buffer = vec![0u8; part_size];
+    let mut total_read = 0;
+
+    while total_read < part_size {
+        match reader.read(&mut buffer[total_read..]).await {
+            Ok(0) => break,
+            Ok(n) => total_read += n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+
+    buffer.truncate(total_read);
+    Ok(Bytes::from(buffer))
+}
+
+/// Copy an object, using a multipart copy when it is too large for CopyObject.
+///
+/// The source is HEADed first. Objects up to [`MAX_SINGLE_COPY_SIZE`] are
+/// copied with a single CopyObject request. Larger objects are copied with
+/// UploadPartCopy in `part_size` byte ranges into a multipart upload whose
+/// content headers and user metadata are taken from the source, unless the
+/// request's metadata directive is `REPLACE`. Every range is pinned to the
+/// source ETag, and the upload is aborted if any part fails so no incomplete
+/// upload is left behind.
+///
+/// `part_size` must be at least [`MIN_PART_SIZE`] and at most
+/// [`MAX_SINGLE_COPY_SIZE`].
+pub async fn copy_object_multipart(
+    objects: &ObjectsService,
+    multipart: &MultipartService,
+    request: CopyObjectRequest,
+    part_size: u64,
+) -> Result<CopyObjectOutput, S3Error> {
+    let mut head_request = HeadObjectRequest::new(&request.source_bucket, &request.source_key);
+    head_request.version_id = request.source_version_id.clone();
+    head_request.sse_customer_algorithm = request.copy_source_sse_customer_algorithm.clone();
+    head_request.sse_customer_key = request.copy_source_sse_customer_key.clone();
+    head_request.sse_customer_key_md5 = request.copy_source_sse_customer_key_md5.clone();
+
+    let head = objects.head(head_request).await?;
+    let size = head.content_length.ok_or_else(|| {
+        S3Error::Response(ResponseError::InvalidResponse {
+            message: "HEAD response is missing content-length".to_string(),
+        })
+    })?;
+
+    if size <= MAX_SINGLE_COPY_SIZE {
+        return objects.copy(request).await;
+    }
+
+    if part_size < MIN_PART_SIZE as u64 {
+        return Err(S3Error::Request(RequestError::EntityTooSmall {
+            size: part_size,
+            min_size: MIN_PART_SIZE as u64,
+        }));
+    }
+    if part_size > MAX_SINGLE_COPY_SIZE {
+        return Err(S3Error::Request(RequestError::Validation {
+            message: format!(
+                "Part size {} exceeds the maximum copy part size of {} bytes",
+                part_size, MAX_SINGLE_COPY_SIZE
+            ),
+        }));
+    }
+    if size.div_ceil(part_size) > MAX_PARTS as u64 {
+        return Err(S3Error::Request(RequestError::Validation {
+            message: format!(
+                "Copy source of {} bytes needs more than {} parts of {} bytes",
+                size, MAX_PARTS, part_size
+            ),
+        }));
+    }
+
+    let upload = multipart
+        .create(copy_upload_request(&request, &head))
+        .await?;
+    let upload_id = upload.upload_id;
+
+    let result = match copy_parts(multipart, &request, &head, &upload_id, size, part_size).await {
+        Ok(parts) => {
+            multipart
+                .complete(&request.dest_bucket, &request.dest_key, &upload_id, &parts)
+                .await
+        }
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(output) => Ok(CopyObjectOutput {
+            e_tag: output.e_tag,
+            last_modified: None,
+            version_id: output.version_id,
+            copy_source_version_id: head.version_id,
+            server_side_encryption: output.server_side_encryption,
+            sse_kms_key_id: output.sse_kms_key_id,
+            request_id: output.request_id,
+        }),
+        Err(e) => {
+            // Abort on failure
+            let _ = multipart
+                .abort(&request.dest_bucket, &request.dest_key, &upload_id)
+                .await;
+            Err(e)
+        }
+    }
+}
+
+/// Build the CreateMultipartUpload request for a multipart copy.
+///
+/// Content headers and metadata come from the source unless the copy
+/// request replaces them, mirroring CopyObject's metadata directive.
+fn copy_upload_request(
+    request: &CopyObjectRequest,
+    head: &HeadObjectOutput,
+) -> CreateMultipartUploadRequest {
+    let mut upload = CreateMultipartUploadRequest::new(&request.dest_bucket, &request.dest_key);
+
+    if request.metadata_directive.as_deref() == Some("REPLACE") {
+        upload.content_type = request.content_type.clone();
+        upload.metadata = request.metadata.clone();
+    } else {
+        upload.content_type = head.content_type.clone();
+        upload.content_encoding = head.content_encoding.clone();
+        upload.content_disposition = head.content_disposition.clone();
+        upload.cache_control = head.cache_control.clone();
+        upload.content_language = head.content_language.clone();
+        upload.metadata = head.metadata.clone();
+    }
+
+    upload.storage_class = request.storage_class;
+    upload.server_side_encryption = request.server_side_encryption.clone();
+    upload.acl = request.acl;
+    upload.tagging = request.tagging.clone();
+    upload
+}
+
+/// Copy `size` bytes of the source in `part_size` ranges, keeping at most
+/// [`COPY_CONCURRENCY`] copies in flight. Returns the parts in order.
+async fn copy_parts(
+    multipart: &MultipartService,
+    request: &CopyObjectRequest,
+    head: &HeadObjectOutput,
+    upload_id: &str,
+    size: u64,
+    part_size: u64,
+) -> Result<Vec<CompletedPart>, S3Error> {
+    let ranges = (0..size)
+        .step_by(part_size as usize)
+        .map(|start| (start, (start + part_size).min(size) - 1))
+        .zip(1u32..);
+
+    stream::iter(ranges)
+        .map(|((start, end), part_number)| {
+            let mut part = UploadPartCopyRequest::new(
+                &request.dest_bucket,
+                &request.dest_key,
+                upload_id,
+                part_number,
+                &request.source_bucket,
+                &request.source_key,
+            )
+            .with_range(start, end);
+            part.source_version_id = request.source_version_id.clone();
+            part.copy_source_if_match = head.e_tag.clone();
+
+            async move {
+                let output = multipart.upload_part_copy(part).await?;
+                Ok::<_, S3Error>(CompletedPart {
+                    part_number,
+                    e_tag: output.e_tag,
+                })
+            }
+        })
+        .buffered(COPY_CONCURRENCY)
+        .try_collect()
+        .await
+}
+
 /// Transfer manager for high-level file operations.
 ///
 /// Provides convenient methods for uploading and downloading files
@@ -330,6 +809,20 @@ mod tests {
         assert!(!md5.is_empty());
     }
 
+    #[test]
+    fn test_calculate_checksum() {
+        assert_eq!(calculate_checksum(ChecksumAlgorithm::Crc32, b"hello"), "NhCmhg==");
+        assert_eq!(calculate_checksum(ChecksumAlgorithm::Crc32c, b"hello"), "mnG7TA==");
+        assert_eq!(
+            calculate_checksum(ChecksumAlgorithm::Sha1, b"hello"),
+            "qvTGHdzF6KLavt4PO0gs2a6pQ00="
+        );
+        assert_eq!(
+            calculate_checksum(ChecksumAlgorithm::Sha256, b"hello"),
+            "LPJNul+wow4m6DsqxbninhsWHlwfp0JecwQzYpOLmCQ="
+        );
+    }
+
     #[test]
     fn test_calculate_sha256() {
         let sha256 = calculate_sha256(b"hello");
@@ -6,9 +6,14 @@
 use crate::error::{NetworkError, S3Error};
 use async_trait::async_trait;
 use bytes::Bytes;
+use futures::{Stream, StreamExt, TryStreamExt};
 use std::collections::HashMap;
+use std::pin::Pin;
 use std::time::Duration;
 
+/// Lazily read response body.
+pub type ByteStream = Pin<Box<dyn Stream<Item = Result<Bytes, S3Error>> + Send>>;
+
 /// HTTP request to be sent.
 #[derive(Debug, Clone)]
 pub struct HttpRequest {
@@ -110,12 +115,78 @@ impl HttpResponse {
     }
 }
 
+/// HTTP response whose body is streamed rather than buffered.
+pub struct HttpStreamingResponse {
+    /// HTTP status code.
+    pub status: u16,
+    /// Response headers.
+    pub headers: HashMap<String, String>,
+    /// Response body stream.
+    pub body: ByteStream,
+}
+
+impl HttpStreamingResponse {
+    /// Check if the response indicates success (2xx status).
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Split into a response carrying only status and headers, and the body stream.
+    pub fn into_parts(self) -> (HttpResponse, ByteStream) {
+        let head = HttpResponse {
+            status: self.status,
+            headers: self.headers,
+            body: Bytes::new(),
+        };
+        (head, self.body)
+    }
+
+    /// Read the whole body into memory.
+    pub async fn into_buffered(self) -> Result<HttpResponse, S3Error> {
+        let (mut head, body) = self.into_parts();
+        let chunks: Vec<Bytes> = body.try_collect().await?;
+        head.body = Bytes::from(chunks.concat());
+        Ok(head)
+    }
+}
+
+impl From<HttpResponse> for HttpStreamingResponse {
+    fn from(response: HttpResponse) -> Self {
+        let body = response.body;
+        let stream = futures::stream::iter((!body.is_empty()).then_some(Ok(body)));
+        Self {
+            status: response.status,
+            headers: response.headers,
+            body: Box::pin(stream),
+        }
+    }
+}
+
+impl std::fmt::Debug for HttpStreamingResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HttpStreamingResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .finish_non_exhaustive()
+    }
+}
+
 /// HTTP transport trait for making requests.
 #[async_trait]
 pub trait HttpTransport: Send + Sync {
     /// Send an HTTP request and return the response.
     async fn send(&self, request: HttpRequest) -> Result<HttpResponse, S3Error>;
 
+    /// Send an HTTP request and return the response with a lazily read body.
+    ///
+    /// The default implementation buffers the body via [`send`](Self::send).
+    async fn send_streaming_response(
+        &self,
+        request: HttpRequest,
+    ) -> Result<HttpStreamingResponse, S3Error> {
+        self.send(request).await.map(HttpStreamingResponse::from)
+    }
+
     /// Send a request with streaming body.
     async fn send_streaming(
         &self,
@@ -125,8 +196,13 @@ pub trait HttpTransport: Send + Sync {
 }
 
 /// Default HTTP transport using reqwest.
+///
+/// Buffered requests must complete within the read timeout. Streaming
+/// downloads only need their headers and each body chunk to arrive within it,
+/// so large objects are not cut off while data keeps flowing.
 pub struct ReqwestTransport {
     client: reqwest::Client,
+    read_timeout: Duration,
 }
 
 impl ReqwestTransport {
@@ -156,7 +232,10 @@ impl HttpTransport for ReqwestTransport {
             })
         })?;
 
-        let mut req_builder = self.client.request(method, &request.url);
+        let mut req_builder = self
+            .client
+            .request(method, &request.url)
+            .timeout(self.read_timeout);
 
         // Add headers
         for (name, value) in &request.headers {
@@ -171,7 +250,7 @@ impl HttpTransport for ReqwestTransport {
         let response = req_builder.send().await.map_err(|e| {
             if e.is_timeout() {
                 S3Error::Network(NetworkError::Timeout {
-                    duration: Duration::from_secs(30),
+                    duration: self.read_timeout,
                 })
             } else if e.is_connect() {
                 S3Error::Network(NetworkError::ConnectionFailed {
@@ -204,6 +283,63 @@ impl HttpTransport for ReqwestTransport {
         })
     }
 
+    async fn send_streaming_response(
+        &self,
+        request: HttpRequest,
+    ) -> Result<HttpStreamingResponse, S3Error> {
+        let method = request.method.parse::<reqwest::Method>().map_err(|e| {
+            S3Error::Network(NetworkError::ConnectionFailed {
+                message: format!("Invalid HTTP method: {}", e),
+            })
+        })?;
+
+        let mut req_builder = self.client.request(method, &request.url);
+
+        // Add headers
+        for (name, value) in &request.headers {
+            req_builder = req_builder.header(name, value);
+        }
+
+        // Add body
+        if let Some(body) = request.body {
+            req_builder = req_builder.body(body);
+        }
+
+        let response = tokio::time::timeout(self.read_timeout, req_builder.send())
+            .await
+            .map_err(|_| {
+                S3Error::Network(NetworkError::Timeout {
+                    duration: self.read_timeout,
+                })
+            })?
+            .map_err(|e| {
+                S3Error::Network(NetworkError::ConnectionFailed {
+                    message: e.to_string(),
+                })
+            })?;
+
+        let status = response.status().as_u16();
+        let headers: HashMap<String, String> = response
+            .headers()
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_str().unwrap_or("").to_string()))
+            .collect();
+
+        let body = response.bytes_stream().map(|chunk| {
+            chunk.map_err(|e| {
+                S3Error::Network(NetworkError::ConnectionFailed {
+                    message: format!("Failed to read response body: {}", e),
+                })
+            })
+        });
+
+        Ok(HttpStreamingResponse {
+            status,
+            headers,
+            body: with_idle_timeout(body, self.read_timeout),
+        })
+    }
+
     async fn send_streaming(
         &self,
         request: HttpRequest,
@@ -215,7 +351,10 @@ impl HttpTransport for ReqwestTransport {
             })
         })?;
 
-        let mut req_builder = self.client.request(method, &request.url);
+        let mut req_builder = self
+            .client
+            .request(method, &request.url)
+            .timeout(self.read_timeout);
 
         // Add headers
         for (name, value) in &request.headers {
@@ -253,6 +392,28 @@ impl HttpTransport for ReqwestTransport {
     }
 }
 
+/// Fail a body stream when no chunk arrives within `idle_timeout`.
+fn with_idle_timeout<S>(body: S, idle_timeout: Duration) -> ByteStream
+where
+    S: Stream<Item = Result<Bytes, S3Error>> + Send + 'static,
+{
+    let body = Box::pin(body);
+    Box::pin(futures::stream::unfold(Some(body), move |body| async move {
+        let mut body = body?;
+        match tokio::time::timeout(idle_timeout, body.next()).await {
+            Ok(Some(chunk)) => Some((chunk, Some(body))),
+            Ok(None) => None,
+            // End the stream after reporting the stall
+            Err(_) => Some((
+                Err(S3Error::Network(NetworkError::Timeout {
+                    duration: idle_timeout,
+                })),
+                None,
+            )),
+        }
+    }))
+}
+
 impl std::fmt::Debug for ReqwestTransport {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ReqwestTransport").finish_non_exhaustive()
@@ -289,6 +450,9 @@ impl ReqwestTransportBuilder {
     }
 
     /// Set the read timeout.
+    ///
+    /// Bounds buffered requests as a whole, and the wait for headers and for
+    /// each body chunk of streaming responses.
     pub fn read_timeout(mut self, timeout: Duration) -> Self {
         self.read_timeout = timeout;
         self
@@ -322,7 +486,6 @@ impl ReqwestTransportBuilder {
     pub fn build(self) -> Result<ReqwestTransport, S3Error> {
         let client = reqwest::Client::builder()
             .connect_timeout(self.connect_timeout)
-            .timeout(self.read_timeout)
             .pool_max_idle_per_host(self.pool_max_idle_per_host)
             .pool_idle_timeout(self.pool_idle_timeout)
             .danger_accept_invalid_certs(!self.verify_ssl)
@@ -334,7 +497,10 @@ impl ReqwestTransportBuilder {
                 })
             })?;
 
-        Ok(ReqwestTransport { client })
+        Ok(ReqwestTransport {
+            client,
+            read_timeout: self.read_timeout,
+        })
     }
 }
 
@@ -423,4 +589,68 @@ mod tests {
 
         assert!(transport.is_ok());
     }
+
+    /// Serve one chunked response whose body arrives in `chunks` pieces,
+    /// `interval` apart.
+    async fn spawn_trickling_server(chunks: usize, interval: Duration) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 1024];
+            let _ = socket.read(&mut buf).await.unwrap();
+            socket
+                .write_all(b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n")
+                .await
+                .unwrap();
+            for _ in 0..chunks {
+                tokio::time::sleep(interval).await;
+                socket.write_all(b"4\r\ndata\r\n").await.unwrap();
+            }
+            socket.write_all(b"0\r\n\r\n").await.unwrap();
+        });
+        format!("http://{}/bucket/key", addr)
+    }
+
+    #[tokio::test]
+    async fn test_streaming_slow_but_progressing_body_is_not_aborted() {
+        // Total transfer (~400ms) exceeds the read timeout, but no single
+        // chunk takes longer than it.
+        let url = spawn_trickling_server(8, Duration::from_millis(50)).await;
+        let transport = ReqwestTransport::builder()
+            .read_timeout(Duration::from_millis(200))
+            .build()
+            .unwrap();
+
+        let response = transport
+            .send_streaming_response(HttpRequest::new("GET", url))
+            .await
+            .unwrap();
+        assert_eq!(response.status, 200);
+
+        let body: Vec<Bytes> = response.body.try_collect().await.unwrap();
+        assert_eq!(body.concat(), b"data".repeat(8));
+    }
+
+    #[tokio::test]
+    async fn test_streaming_stalled_body_times_out() {
+        let url = spawn_trickling_server(1, Duration::from_millis(300)).await;
+        let transport = ReqwestTransport::builder()
+            .read_timeout(Duration::from_millis(100))
+            .build()
+            .unwrap();
+
+        let response = transport
+            .send_streaming_response(HttpRequest::new("GET", url))
+            .await
+            .unwrap();
+
+        let result: Result<Vec<Bytes>, S3Error> = response.body.try_collect().await;
+        assert!(matches!(
+            result,
+            Err(S3Error::Network(NetworkError::Timeout { .. }))
+        ));
+    }
 }
@@ -1,7 +1,14 @@
 //! Common enums and data types for S3.
 
+use crate::error::RequestError;
 use serde::{Deserialize, Serialize};
 
+/// Length in bytes of an SSE-C customer key (AES-256).
+pub const SSE_CUSTOMER_KEY_LENGTH: usize = 32;
+
+/// Algorithm sent with SSE-C customer keys.
+pub const SSE_CUSTOMER_ALGORITHM: &str = "AES256";
+
 /// S3 storage class.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -106,6 +113,30 @@ impl ServerSideEncryption {
         }
     }
 
+    /// Create SSE-C encryption with a customer-provided 256-bit key.
+    pub fn customer_provided(key: [u8; SSE_CUSTOMER_KEY_LENGTH]) -> Self {
+        let (key, key_md5) = encode_sse_customer_key(&key);
+        ServerSideEncryption::CustomerProvided {
+            algorithm: SSE_CUSTOMER_ALGORITHM.to_string(),
+            key,
+            key_md5,
+        }
+    }
+
+    /// Create SSE-C encryption from a key slice, checking that it is 32 bytes.
+    pub fn try_customer_provided(key: &[u8]) -> Result<Self, RequestError> {
+        let key: [u8; SSE_CUSTOMER_KEY_LENGTH] = key.try_into().map_err(|_| {
+            RequestError::Validation {
+                message: format!(
+                    "SSE-C key must be {} bytes, got {}",
+                    SSE_CUSTOMER_KEY_LENGTH,
+                    key.len()
+                ),
+            }
+        })?;
+        Ok(Self::customer_provided(key))
+    }
+
     /// Returns the S3 API string representation.
     pub fn as_header_value(&self) -> &'static str {
         match self {
@@ -116,6 +147,12 @@ impl ServerSideEncryption {
     }
 }
 
+/// Returns the base64-encoded key and base64-encoded MD5 of the key, as sent
+/// in the SSE-C `-key` and `-key-MD5` headers.
+pub(crate) fn encode_sse_customer_key(key: &[u8]) -> (String, String) {
+    (base64::encode(key), base64::encode(md5::compute(key).0))
+}
+
 /// Canned ACL (Access Control List) settings.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum CannedAcl {
@@ -173,6 +210,70 @@ impl ChecksumAlgorithm {
     }
 }
 
+/// Condition in a browser POST upload policy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PostPolicyCondition {
+    /// Form field must equal a value, e.g. `{"acl": "private"}`.
+    Equals {
+        /// Form field name.
+        field: String,
+        /// Required value.
+        value: String,
+    },
+    /// Form field must start with a prefix, e.g. `["starts-with", "$key", "uploads/"]`.
+    StartsWith {
+        /// Form field name.
+        field: String,
+        /// Required prefix (empty allows any value).
+        prefix: String,
+    },
+    /// Upload size must be within `min..=max` bytes.
+    ContentLengthRange {
+        /// Minimum size in bytes.
+        min: u64,
+        /// Maximum size in bytes.
+        max: u64,
+    },
+}
+
+impl PostPolicyCondition {
+    /// Require a form field to equal a value.
+    pub fn equals(field: impl Into<String>, value: impl Into<String>) -> Self {
+        PostPolicyCondition::Equals {
+            field: field.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Require a form field to start with a prefix.
+    pub fn starts_with(field: impl Into<String>, prefix: impl Into<String>) -> Self {
+        PostPolicyCondition::StartsWith {
+            field: field.into(),
+            prefix: prefix.into(),
+        }
+    }
+
+    /// Limit the upload size in bytes.
+    pub fn content_length_range(min: u64, max: u64) -> Self {
+        PostPolicyCondition::ContentLengthRange { min, max }
+    }
+
+    /// Returns the condition as it appears in the policy document.
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            PostPolicyCondition::Equals { field, value } => {
+                serde_json::json!({ field.as_str(): value })
+            }
+            PostPolicyCondition::StartsWith { field, prefix } => {
+                serde_json::json!(["starts-with", format!("${}", field), prefix])
+            }
+            PostPolicyCondition::ContentLengthRange { min, max } => {
+                serde_json::json!(["content-length-range", min, max])
+            }
+        }
+    }
+}
+
 /// Object tag.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Tag {
@@ -192,6 +293,127 @@ impl Tag {
     }
 }
 
+/// Bucket versioning state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum VersioningStatus {
+    /// Versioning is enabled.
+    Enabled,
+    /// Versioning was enabled and is now suspended.
+    Suspended,
+}
+
+impl VersioningStatus {
+    /// Returns the S3 API string representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VersioningStatus::Enabled => "Enabled",
+            VersioningStatus::Suspended => "Suspended",
+        }
+    }
+}
+
+impl std::str::FromStr for VersioningStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Enabled" => Ok(VersioningStatus::Enabled),
+            "Suspended" => Ok(VersioningStatus::Suspended),
+            _ => Err(format!("Unknown versioning status: {}", s)),
+        }
+    }
+}
+
+/// Bucket lifecycle rule.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LifecycleRule {
+    /// Rule ID (max 255 characters).
+    pub id: Option<String>,
+    /// Whether the rule is applied.
+    pub enabled: bool,
+    /// Objects the rule applies to.
+    pub filter: LifecycleFilter,
+    /// Days after creation at which objects expire.
+    pub expiration_days: Option<u32>,
+    /// Storage class transitions.
+    pub transitions: Vec<LifecycleTransition>,
+    /// Days after initiation at which incomplete multipart uploads are aborted.
+    pub abort_incomplete_multipart_upload_days: Option<u32>,
+}
+
+impl LifecycleRule {
+    /// Create an enabled rule that applies to all objects.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: Some(id.into()),
+            enabled: true,
+            filter: LifecycleFilter::default(),
+            expiration_days: None,
+            transitions: Vec::new(),
+            abort_incomplete_multipart_upload_days: None,
+        }
+    }
+
+    /// Restrict the rule to keys with the given prefix.
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.filter.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Restrict the rule to objects with the given tag.
+    pub fn with_tag(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.filter.tags.push(Tag::new(key, value));
+        self
+    }
+
+    /// Expire objects the given number of days after creation.
+    pub fn expire_after_days(mut self, days: u32) -> Self {
+        self.expiration_days = Some(days);
+        self
+    }
+
+    /// Transition objects to a storage class the given number of days after creation.
+    pub fn transition_after_days(mut self, days: u32, storage_class: StorageClass) -> Self {
+        self.transitions.push(LifecycleTransition {
+            days,
+            storage_class,
+        });
+        self
+    }
+
+    /// Abort incomplete multipart uploads the given number of days after initiation.
+    pub fn abort_incomplete_multipart_after_days(mut self, days: u32) -> Self {
+        self.abort_incomplete_multipart_upload_days = Some(days);
+        self
+    }
+
+    /// Disable the rule without removing it.
+    pub fn disabled(mut self) -> Self {
+        self.enabled = false;
+        self
+    }
+}
+
+/// Lifecycle rule filter.
+///
+/// An empty filter matches every object; a prefix and tags must all match.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LifecycleFilter {
+    /// Key prefix.
+    pub prefix: Option<String>,
+    /// Tags the object must have.
+    pub tags: Vec<Tag>,
+}
+
+/// Lifecycle storage class transition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LifecycleTransition {
+    /// Days after creation.
+    pub days: u32,
+    /// Target storage class.
+    pub storage_class: StorageClass,
+}
+
 /// S3 bucket information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Bucket {
@@ -390,6 +612,47 @@ mod tests {
         assert_eq!(kms.as_header_value(), "aws:kms");
     }
 
+    #[test]
+    fn test_customer_provided_encryption() {
+        let key: [u8; 32] = std::array::from_fn(|i| i as u8);
+        let sse = ServerSideEncryption::customer_provided(key);
+
+        assert_eq!(sse.as_header_value(), "AES256");
+        assert_eq!(
+            sse,
+            ServerSideEncryption::CustomerProvided {
+                algorithm: "AES256".to_string(),
+                key: "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=".to_string(),
+                key_md5: "tP/LI3N87DFaSk0aoqYgzg==".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_customer_provided_key_length() {
+        assert!(ServerSideEncryption::try_customer_provided(&[0u8; 32]).is_ok());
+        assert!(matches!(
+            ServerSideEncryption::try_customer_provided(&[0u8; 16]),
+            Err(RequestError::Validation { .. })
+        ));
+    }
+
+    #[test]
+    fn test_post_policy_condition_json() {
+        assert_eq!(
+            PostPolicyCondition::equals("acl", "private").to_json().to_string(),
+            r#"{"acl":"private"}"#
+        );
+        assert_eq!(
+            PostPolicyCondition::starts_with("key", "uploads/").to_json().to_string(),
+            r#"["starts-with","$key","uploads/"]"#
+        );
+        assert_eq!(
+            PostPolicyCondition::content_length_range(1, 1024).to_json().to_string(),
+            r#"["content-length-range",1,1024]"#
+        );
+    }
+
     #[test]
     fn test_tag_creation() {
         let tag = Tag::new("Environment", "Production");
@@ -397,6 +660,21 @@ mod tests {
         assert_eq!(tag.value, "Production");
     }
 
+    #[test]
+    fn test_lifecycle_rule_builder() {
+        let rule = LifecycleRule::new("archive-logs")
+            .with_prefix("logs/")
+            .transition_after_days(30, StorageClass::StandardIa)
+            .expire_after_days(365);
+
+        assert!(rule.enabled);
+        assert_eq!(rule.filter.prefix, Some("logs/".to_string()));
+        assert!(rule.filter.tags.is_empty());
+        assert_eq!(rule.transitions[0].storage_class, StorageClass::StandardIa);
+        assert_eq!(rule.expiration_days, Some(365));
+        assert!(!rule.disabled().enabled);
+    }
+
     #[test]
     fn test_object_identifier() {
         let id = ObjectIdentifier::new("my-key");
@@ -45,6 +45,10 @@ pub struct PutObjectRequest {
     pub object_lock_legal_hold: Option<bool>,
     /// Expected bucket owner.
     pub expected_bucket_owner: Option<String>,
+    /// Write only if the current object's ETag matches.
+    pub if_match: Option<String>,
+    /// Write only if no object matches; `*` means the key must not exist.
+    pub if_none_match: Option<String>,
 }
 
 impl PutObjectRequest {
@@ -70,6 +74,8 @@ impl PutObjectRequest {
             object_lock_retain_until_date: None,
             object_lock_legal_hold: None,
             expected_bucket_owner: None,
+            if_match: None,
+            if_none_match: None,
         }
     }
 
@@ -97,6 +103,14 @@ impl PutObjectRequest {
         self
     }
 
+    /// Encrypt with a customer-provided key (SSE-C).
+    ///
+    /// The same key must be supplied to read the object back.
+    pub fn with_sse_customer_key(mut self, key: [u8; SSE_CUSTOMER_KEY_LENGTH]) -> Self {
+        self.server_side_encryption = Some(ServerSideEncryption::customer_provided(key));
+        self
+    }
+
     /// Set the canned ACL.
     pub fn with_acl(mut self, acl: CannedAcl) -> Self {
         self.acl = Some(acl);
@@ -120,6 +134,20 @@ impl PutObjectRequest {
         self.cache_control = Some(cache_control.into());
         self
     }
+
+    /// Only overwrite the object if its current ETag matches.
+    pub fn with_if_match(mut self, etag: impl Into<String>) -> Self {
+        self.if_match = Some(etag.into());
+        self
+    }
+
+    /// Set If-None-Match condition.
+    ///
+    /// Use `"*"` to create the object only if the key does not already exist.
+    pub fn with_if_none_match(mut self, etag: impl Into<String>) -> Self {
+        self.if_none_match = Some(etag.into());
+        self
+    }
 }
 
 /// Request to get an object.
@@ -161,6 +189,8 @@ pub struct GetObjectRequest {
     pub sse_customer_key: Option<String>,
     /// SSE-C key MD5.
     pub sse_customer_key_md5: Option<String>,
+    /// Request the object checksum and verify the body against it.
+    pub checksum_mode: bool,
     /// Expected bucket owner.
     pub expected_bucket_owner: Option<String>,
 }
@@ -198,6 +228,21 @@ impl GetObjectRequest {
         self.if_none_match = Some(etag.into());
         self
     }
+
+    /// Set the customer-provided key (SSE-C) the object was encrypted with.
+    pub fn with_sse_customer_key(mut self, key: [u8; SSE_CUSTOMER_KEY_LENGTH]) -> Self {
+        let (key, key_md5) = encode_sse_customer_key(&key);
+        self.sse_customer_algorithm = Some(SSE_CUSTOMER_ALGORITHM.to_string());
+        self.sse_customer_key = Some(key);
+        self.sse_customer_key_md5 = Some(key_md5);
+        self
+    }
+
+    /// Verify the downloaded body against the object's checksum or ETag.
+    pub fn with_checksum_validation(mut self) -> Self {
+        self.checksum_mode = true;
+        self
+    }
 }
 
 /// Request to delete an object.
@@ -312,6 +357,15 @@ impl HeadObjectRequest {
             ..Default::default()
         }
     }
+
+    /// Set the customer-provided key (SSE-C) the object was encrypted with.
+    pub fn with_sse_customer_key(mut self, key: [u8; SSE_CUSTOMER_KEY_LENGTH]) -> Self {
+        let (key, key_md5) = encode_sse_customer_key(&key);
+        self.sse_customer_algorithm = Some(SSE_CUSTOMER_ALGORITHM.to_string());
+        self.sse_customer_key = Some(key);
+        self.sse_customer_key_md5 = Some(key_md5);
+        self
+    }
 }
 
 /// Request to copy an object.
@@ -347,6 +401,12 @@ pub struct CopyObjectRequest {
     pub copy_source_if_match: Option<String>,
     /// If-None-Match condition.
     pub copy_source_if_none_match: Option<String>,
+    /// SSE-C algorithm of the source object.
+    pub copy_source_sse_customer_algorithm: Option<String>,
+    /// SSE-C key of the source object.
+    pub copy_source_sse_customer_key: Option<String>,
+    /// SSE-C key MD5 of the source object.
+    pub copy_source_sse_customer_key_md5: Option<String>,
     /// Expected bucket owner.
     pub expected_bucket_owner: Option<String>,
 }
@@ -375,6 +435,9 @@ impl CopyObjectRequest {
             tagging: None,
             copy_source_if_match: None,
             copy_source_if_none_match: None,
+            copy_source_sse_customer_algorithm: None,
+            copy_source_sse_customer_key: None,
+            copy_source_sse_customer_key_md5: None,
             expected_bucket_owner: None,
         }
     }
@@ -388,6 +451,24 @@ impl CopyObjectRequest {
         let bucket = bucket.into();
         Self::new(bucket.clone(), source_key, bucket, dest_key)
     }
+
+    /// Encrypt the copy with a customer-provided key (SSE-C).
+    pub fn with_sse_customer_key(mut self, key: [u8; SSE_CUSTOMER_KEY_LENGTH]) -> Self {
+        self.server_side_encryption = Some(ServerSideEncryption::customer_provided(key));
+        self
+    }
+
+    /// Set the customer-provided key (SSE-C) the source object was encrypted with.
+    pub fn with_copy_source_sse_customer_key(
+        mut self,
+        key: [u8; SSE_CUSTOMER_KEY_LENGTH],
+    ) -> Self {
+        let (key, key_md5) = encode_sse_customer_key(&key);
+        self.copy_source_sse_customer_algorithm = Some(SSE_CUSTOMER_ALGORITHM.to_string());
+        self.copy_source_sse_customer_key = Some(key);
+        self.copy_source_sse_customer_key_md5 = Some(key_md5);
+        self
+    }
 }
 
 /// Request to list objects (v2).
@@ -618,6 +699,59 @@ impl UploadPartRequest {
     }
 }
 
+/// Request to upload a part by copying a byte range of an existing object.
+#[derive(Debug, Clone)]
+pub struct UploadPartCopyRequest {
+    /// Bucket name.
+    pub bucket: String,
+    /// Object key.
+    pub key: String,
+    /// Upload ID.
+    pub upload_id: String,
+    /// Part number (1-10000).
+    pub part_number: u32,
+    /// Source bucket.
+    pub source_bucket: String,
+    /// Source key.
+    pub source_key: String,
+    /// Source version ID.
+    pub source_version_id: Option<String>,
+    /// Inclusive byte range of the source to copy (whole object if unset).
+    pub copy_source_range: Option<(u64, u64)>,
+    /// If-Match condition on the source.
+    pub copy_source_if_match: Option<String>,
+}
+
+impl UploadPartCopyRequest {
+    /// Create a new upload part copy request.
+    pub fn new(
+        bucket: impl Into<String>,
+        key: impl Into<String>,
+        upload_id: impl Into<String>,
+        part_number: u32,
+        source_bucket: impl Into<String>,
+        source_key: impl Into<String>,
+    ) -> Self {
+        Self {
+            bucket: bucket.into(),
+            key: key.into(),
+            upload_id: upload_id.into(),
+            part_number,
+            source_bucket: source_bucket.into(),
+            source_key: source_key.into(),
+            source_version_id: None,
+            copy_source_range: None,
+            copy_source_if_match: None,
+        }
+    }
+
+    /// Copy only the inclusive byte range `start..=end` of the source.
+    pub fn with_range(mut self, start: u64, end: u64) -> Self {
+        self.copy_source_range = Some((start, end));
+        self
+    }
+}
+
 /// Request to list parts.
 #[derive(Debug, Clone)]
 pub struct ListPartsRequest {
@@ -237,6 +237,19 @@ pub struct UploadPartOutput {
     pub request_id: Option<String>,
 }
 
+/// Response from upload part copy operation.
+#[derive(Debug, Clone)]
+pub struct UploadPartCopyOutput {
+    /// Part ETag.
+    pub e_tag: String,
+    /// Last modified date of the part.
+    pub last_modified: Option<String>,
+    /// Source version ID.
+    pub copy_source_version_id: Option<String>,
+    /// AWS request ID.
+    pub request_id: Option<String>,
+}
+
 /// Response from complete multipart upload operation.
 #[derive(Debug, Clone)]
 pub struct CompleteMultipartUploadOutput {
@@ -317,6 +330,17 @@ impl PresignedUrl {
     }
 }
 
+/// Presigned POST form for browser uploads.
+#[derive(Debug, Clone)]
+pub struct PresignedPost {
+    /// URL the form is posted to.
+    pub url: String,
+    /// Form fields to include before the file field.
+    pub fields: std::collections::HashMap<String, String>,
+    /// Expiration timestamp of the policy.
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
+
 /// Response from get object tagging operation.
 #[derive(Debug, Clone)]
 pub struct GetObjectTaggingOutput {
@@ -350,6 +374,26 @@ pub struct GetBucketTaggingOutput {
     pub request_id: Option<String>,
 }
 
+/// Response from get bucket versioning operation.
+#[derive(Debug, Clone)]
+pub struct GetBucketVersioningOutput {
+    /// Versioning state; `None` if versioning was never enabled.
+    pub status: Option<VersioningStatus>,
+    /// Whether MFA delete is enabled.
+    pub mfa_delete: Option<bool>,
+    /// AWS request ID.
+    pub request_id: Option<String>,
+}
+
+/// Response from get bucket lifecycle operation.
+#[derive(Debug, Clone)]
+pub struct GetBucketLifecycleOutput {
+    /// Lifecycle rules; empty if none are configured.
+    pub rules: Vec<LifecycleRule>,
+    /// AWS request ID.
+    pub request_id: Option<String>,
+}
+
 /// Response from put bucket tagging operation.
 #[derive(Debug, Clone)]
 pub struct PutBucketTaggingOutput {
@@ -379,6 +379,48 @@ pub fn parse_complete_multipart_upload(xml: &str) -> Result<CompleteMultipartUpl
     Ok(output)
 }
 
+/// Parse UploadPartCopy response.
+pub fn parse_copy_part_result(xml: &str) -> Result<UploadPartCopyOutput, S3Error> {
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+
+    let mut output = UploadPartCopyOutput {
+        e_tag: String::new(),
+        last_modified: None,
+        copy_source_version_id: None,
+        request_id: None,
+    };
+    let mut current_element = String::new();
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => {
+                current_element = String::from_utf8_lossy(e.name().as_ref()).to_string();
+            }
+            Ok(Event::Text(e)) => {
+                let text = e.unescape().unwrap_or_default().to_string();
+                match current_element.as_str() {
+                    "ETag" => output.e_tag = text,
+                    "LastModified" => output.last_modified = Some(text),
+                    _ => {}
+                }
+            }
+            Ok(Event::End(_)) => {
+                current_element.clear();
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                return Err(S3Error::Response(ResponseError::XmlParseError {
+                    message: e.to_string(),
+                }));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(output)
+}
+
 /// Parse ListParts response.
 pub fn parse_list_parts(xml: &str) -> Result<ListPartsOutput, S3Error> {
     let mut reader = Reader::from_str(xml);
@@ -859,6 +901,237 @@ pub fn parse_list_multipart_uploads(xml: &str) -> Result<ListMultipartUploadsOut
     Ok(output)
 }
 
+/// Build PutBucketVersioning XML request body.
+pub fn build_put_versioning_xml(status: VersioningStatus) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<VersioningConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/"><Status>{}</Status></VersioningConfiguration>"#,
+        status.as_str()
+    )
+}
+
+/// Parse GetBucketVersioning response.
+pub fn parse_get_bucket_versioning(xml: &str) -> Result<GetBucketVersioningOutput, S3Error> {
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+
+    let mut output = GetBucketVersioningOutput {
+        status: None,
+        mfa_delete: None,
+        request_id: None,
+    };
+    let mut current_element = String::new();
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => {
+                current_element = String::from_utf8_lossy(e.name().as_ref()).to_string();
+            }
+            Ok(Event::Text(e)) => {
+                let text = e.unescape().unwrap_or_default().to_string();
+                match current_element.as_str() {
+                    "Status" => output.status = text.parse().ok(),
+                    "MfaDelete" => output.mfa_delete = Some(text == "Enabled"),
+                    _ => {}
+                }
+            }
+            Ok(Event::End(_)) => {
+                current_element.clear();
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                return Err(S3Error::Response(ResponseError::XmlParseError {
+                    message: e.to_string(),
+                }));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(output)
+}
+
+/// Build PutBucketLifecycleConfiguration XML request body.
+pub fn build_put_lifecycle_xml(rules: &[LifecycleRule]) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<LifecycleConfiguration xmlns=\"http://s3.amazonaws.com/doc/2006-03-01/\">");
+
+    for rule in rules {
+        xml.push_str("<Rule>");
+        if let Some(id) = &rule.id {
+            xml.push_str(&format!("<ID>{}</ID>", escape_xml(id)));
+        }
+
+        // A single condition goes directly in the filter; several need <And>
+        let filter = &rule.filter;
+        let conditions = filter.tags.len() + usize::from(filter.prefix.is_some());
+        xml.push_str("<Filter>");
+        if conditions > 1 {
+            xml.push_str("<And>");
+        }
+        if let Some(prefix) = &filter.prefix {
+            xml.push_str(&format!("<Prefix>{}</Prefix>", escape_xml(prefix)));
+        }
+        for tag in &filter.tags {
+            xml.push_str("<Tag>");
+            xml.push_str(&format!("<Key>{}</Key>", escape_xml(&tag.key)));
+            xml.push_str(&format!("<Value>{}</Value>", escape_xml(&tag.value)));
+            xml.push_str("</Tag>");
+        }
+        if conditions > 1 {
+            xml.push_str("</And>");
+        }
+        xml.push_str("</Filter>");
+
+        let status = if rule.enabled { "Enabled" } else { "Disabled" };
+        xml.push_str(&format!("<Status>{}</Status>", status));
+
+        for transition in &rule.transitions {
+            xml.push_str("<Transition>");
+            xml.push_str(&format!("<Days>{}</Days>", transition.days));
+            xml.push_str(&format!(
+                "<StorageClass>{}</StorageClass>",
+                transition.storage_class.as_str()
+            ));
+            xml.push_str("</Transition>");
+        }
+        if let Some(days) = rule.expiration_days {
+            xml.push_str(&format!("<Expiration><Days>{}</Days></Expiration>", days));
+        }
+        if let Some(days) = rule.abort_incomplete_multipart_upload_days {
+            xml.push_str(&format!(
+                "<AbortIncompleteMultipartUpload><DaysAfterInitiation>{}</DaysAfterInitiation></AbortIncompleteMultipartUpload>",
+                days
+            ));
+        }
+        xml.push_str("</Rule>");
+    }
+
+    xml.push_str("</LifecycleConfiguration>");
+    xml
+}
+
+/// Parse GetBucketLifecycleConfiguration response.
+pub fn parse_get_bucket_lifecycle(xml: &str) -> Result<GetBucketLifecycleOutput, S3Error> {
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+
+    let mut rules = Vec::new();
+    let mut current_rule: Option<LifecycleRule> = None;
+    let mut current_transition: Option<LifecycleTransition> = None;
+    let mut current_tag: Option<Tag> = None;
+    let mut in_expiration = false;
+    let mut current_element = String::new();
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                current_element = name.clone();
+
+                match name.as_str() {
+                    "Rule" => {
+                        current_rule = Some(LifecycleRule {
+                            id: None,
+                            enabled: false,
+                            filter: LifecycleFilter::default(),
+                            expiration_days: None,
+                            transitions: Vec::new(),
+                            abort_incomplete_multipart_upload_days: None,
+                        });
+                    }
+                    "Transition" => {
+                        current_transition = Some(LifecycleTransition {
+                            days: 0,
+                            storage_class: StorageClass::default(),
+                        });
+                    }
+                    "Tag" => {
+                        current_tag = Some(Tag::new("", ""));
+                    }
+                    "Expiration" => {
+                        in_expiration = true;
+                    }
+                    _ => {}
+                }
+            }
+            Ok(Event::Text(e)) => {
+                let text = e.unescape().unwrap_or_default().to_string();
+
+                if let Some(tag) = current_tag.as_mut() {
+                    match current_element.as_str() {
+                        "Key" => tag.key = text,
+                        "Value" => tag.value = text,
+                        _ => {}
+                    }
+                } else if let Some(transition) = current_transition.as_mut() {
+                    match current_element.as_str() {
+                        "Days" => transition.days = text.parse().unwrap_or(0),
+                        "StorageClass" => {
+                            if let Ok(class) = text.parse() {
+                                transition.storage_class = class;
+                            }
+                        }
+                        _ => {}
+                    }
+                } else if let Some(rule) = current_rule.as_mut() {
+                    match current_element.as_str() {
+                        "ID" => rule.id = Some(text),
+                        // Also covers the legacy rule-level <Prefix>
+                        "Prefix" => rule.filter.prefix = Some(text),
+                        "Status" => rule.enabled = text == "Enabled",
+                        "Days" if in_expiration => rule.expiration_days = text.parse().ok(),
+                        "DaysAfterInitiation" => {
+                            rule.abort_incomplete_multipart_upload_days = text.parse().ok()
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Ok(Event::End(e)) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                match name.as_str() {
+                    "Rule" => {
+                        if let Some(rule) = current_rule.take() {
+                            rules.push(rule);
+                        }
+                    }
+                    "Transition" => {
+                        if let (Some(transition), Some(rule)) =
+                            (current_transition.take(), current_rule.as_mut())
+                        {
+                            rule.transitions.push(transition);
+                        }
+                    }
+                    "Tag" => {
+                        if let (Some(tag), Some(rule)) = (current_tag.take(), current_rule.as_mut())
+                        {
+                            rule.filter.tags.push(tag);
+                        }
+                    }
+                    "Expiration" => {
+                        in_expiration = false;
+                    }
+                    _ => {}
+                }
+                current_element.clear();
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                return Err(S3Error::Response(ResponseError::XmlParseError {
+                    message: e.to_string(),
+                }));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(GetBucketLifecycleOutput {
+        rules,
+        request_id: None,
+    })
+}
+
 /// Escape special characters for XML.
 fn escape_xml(s: &str) -> String {
     s.replace('&', "&amp;")
@@ -929,6 +1202,19 @@ mod tests {
         assert_eq!(result.upload_id, "upload-123");
     }
 
+    #[test]
+    fn test_parse_copy_part_result() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <CopyPartResult>
+            <LastModified>2024-01-01T00:00:00.000Z</LastModified>
+            <ETag>"part-etag"</ETag>
+        </CopyPartResult>"#;
+
+        let result = parse_copy_part_result(xml).unwrap();
+        assert_eq!(result.e_tag, "\"part-etag\"");
+        assert_eq!(result.last_modified.as_deref(), Some("2024-01-01T00:00:00.000Z"));
+    }
+
     #[test]
     fn test_build_delete_objects_xml() {
         let objects = vec![
@@ -961,6 +1247,43 @@ mod tests {
         assert!(xml.contains("<PartNumber>2</PartNumber>"));
     }
 
+    #[test]
+    fn test_lifecycle_xml_roundtrip() {
+        let rules = vec![
+            LifecycleRule::new("archive-logs")
+                .with_prefix("logs/")
+                .transition_after_days(30, StorageClass::StandardIa)
+                .transition_after_days(90, StorageClass::Glacier)
+                .expire_after_days(365),
+            LifecycleRule::new("temp & scratch")
+                .with_prefix("tmp/")
+                .with_tag("retention", "short")
+                .expire_after_days(7)
+                .disabled(),
+            LifecycleRule::new("cleanup-uploads").abort_incomplete_multipart_after_days(3),
+        ];
+
+        let xml = build_put_lifecycle_xml(&rules);
+        assert!(xml.contains("<ID>temp &amp; scratch</ID>"));
+        assert!(xml.contains("<Filter><And><Prefix>tmp/</Prefix><Tag>"));
+        assert!(xml.contains("<Filter></Filter>"));
+
+        let parsed = parse_get_bucket_lifecycle(&xml).unwrap();
+        assert_eq!(parsed.rules, rules);
+    }
+
+    #[test]
+    fn test_versioning_xml_roundtrip() {
+        let xml = build_put_versioning_xml(VersioningStatus::Suspended);
+        let parsed = parse_get_bucket_versioning(&xml).unwrap();
+        assert_eq!(parsed.status, Some(VersioningStatus::Suspended));
+
+        // Buckets that never had versioning enabled return an empty configuration
+        let never = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <VersioningConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/"/>"#;
+        assert_eq!(parse_get_bucket_versioning(never).unwrap().status, None);
+    }
+
     #[test]
     fn test_escape_xml() {
         assert_eq!(escape_xml("a&b"), "a&amp;b");
@@ -196,3 +196,71 @@ async fn test_bucket_exists_access_denied() {
     // Access denied means the bucket exists but we can't access it
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_put_bucket_versioning() {
+    let transport = Arc::new(MockTransport::with_responses(vec![MockResponse::ok()]));
+    let service = create_test_service_with_transport(transport.clone());
+
+    let result = service.put_bucket_versioning("test-bucket", true).await;
+    assert!(result.is_ok());
+
+    let recorded = transport.last_request().unwrap();
+    assert_eq!(recorded.method, "PUT");
+    assert!(recorded.url.ends_with("?versioning"));
+    let body = String::from_utf8_lossy(recorded.body.as_ref().unwrap()).to_string();
+    assert!(body.contains("<Status>Enabled</Status>"));
+}
+
+#[tokio::test]
+async fn test_bucket_lifecycle_roundtrip() {
+    let rules = vec![
+        LifecycleRule::new("archive-logs")
+            .with_prefix("logs/")
+            .transition_after_days(30, StorageClass::GlacierInstantRetrieval)
+            .expire_after_days(365),
+        LifecycleRule::new("cleanup-uploads").abort_incomplete_multipart_after_days(7),
+    ];
+
+    let transport = Arc::new(MockTransport::with_responses(vec![MockResponse::ok()]));
+    let service = create_test_service_with_transport(transport.clone());
+
+    service.put_bucket_lifecycle("test-bucket", &rules).await.unwrap();
+
+    let recorded = transport.last_request().unwrap();
+    assert!(recorded.url.ends_with("?lifecycle"));
+    let body = recorded.body.unwrap();
+
+    // Serve the uploaded configuration back
+    transport.queue_response(MockResponse::ok_with_body(body));
+    let output = service.get_bucket_lifecycle("test-bucket").await.unwrap();
+    assert_eq!(output.rules, rules);
+}
+
+#[tokio::test]
+async fn test_get_bucket_lifecycle_not_configured() {
+    let error_xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Error>
+    <Code>NoSuchLifecycleConfiguration</Code>
+    <Message>The lifecycle configuration does not exist</Message>
+    <BucketName>test-bucket</BucketName>
+</Error>"#;
+
+    let transport = Arc::new(MockTransport::with_responses(vec![
+        MockResponse::error(404, error_xml),
+    ]));
+    let service = create_test_service_with_transport(transport.clone());
+
+    let output = service.get_bucket_lifecycle("test-bucket").await.unwrap();
+    assert!(output.rules.is_empty());
+}
+
+#[tokio::test]
+async fn test_put_bucket_lifecycle_requires_rules() {
+    let transport = Arc::new(MockTransport::new());
+    let service = create_test_service_with_transport(transport.clone());
+
+    let result = service.put_bucket_lifecycle("test-bucket", &[]).await;
+    assert!(result.is_err());
+    assert_eq!(transport.request_count(), 0);
+}
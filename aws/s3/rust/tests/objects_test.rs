@@ -95,6 +95,81 @@ async fn test_get_object_not_found() {
     }
 }
 
+#[tokio::test]
+async fn test_get_streaming_concatenates_chunks() {
+    use futures::TryStreamExt;
+
+    let payload = "0123456789abcdefghijklmnopqrstuvwxyz".repeat(100);
+    let transport = Arc::new(MockTransport::with_responses(vec![
+        MockResponse::ok_with_body(payload.clone())
+            .with_chunk_size(64)
+            .with_headers(TestFixtures::get_object_headers()),
+    ]));
+    let service = create_test_service_with_transport(transport.clone());
+
+    let request = GetObjectRequest::new("test-bucket", "test-key.txt");
+    let (output, stream) = service.get_streaming(request).await.unwrap();
+
+    assert_eq!(output.content_type, Some("text/plain".to_string()));
+    assert!(output.body.is_empty());
+
+    let chunks: Vec<Bytes> = stream.try_collect().await.unwrap();
+    assert!(chunks.len() > 1);
+    assert_eq!(chunks.concat(), payload.as_bytes());
+}
+
+#[tokio::test]
+async fn test_get_streaming_reports_bytes_transferred_on_error() {
+    use futures::StreamExt;
+
+    let transport = Arc::new(MockTransport::with_responses(vec![
+        MockResponse::ok_with_body("hello world")
+            .with_chunk_size(4)
+            .with_stream_error("connection reset by peer"),
+    ]));
+    let service = create_test_service_with_transport(transport.clone());
+
+    let request = GetObjectRequest::new("test-bucket", "test-key.txt");
+    let (_, mut stream) = service.get_streaming(request).await.unwrap();
+
+    let mut received = Vec::new();
+    let error = loop {
+        match stream.next().await {
+            Some(Ok(chunk)) => received.extend_from_slice(&chunk),
+            Some(Err(e)) => break e,
+            None => panic!("Expected stream error"),
+        }
+    };
+
+    assert_eq!(received, b"hello world");
+    match error {
+        aws_s3::S3Error::Transfer(aws_s3::TransferError::StreamInterrupted {
+            bytes_transferred,
+            message,
+        }) => {
+            assert_eq!(bytes_transferred, 11);
+            assert!(message.contains("connection reset by peer"));
+        }
+        other => panic!("Expected TransferError::StreamInterrupted, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_get_streaming_not_found() {
+    let error_xml = TestFixtures::error_xml("NoSuchKey", "The specified key does not exist.");
+    let transport = Arc::new(MockTransport::with_responses(vec![
+        MockResponse::error(404, error_xml),
+    ]));
+    let service = create_test_service_with_transport(transport.clone());
+
+    let request = GetObjectRequest::new("test-bucket", "nonexistent.txt");
+    match service.get_streaming(request).await {
+        Err(aws_s3::S3Error::Object(aws_s3::ObjectError::NotFound { .. })) => {}
+        Err(other) => panic!("Expected ObjectError::NotFound, got {:?}", other),
+        Ok(_) => panic!("Expected ObjectError::NotFound"),
+    }
+}
+
 #[tokio::test]
 async fn test_delete_object_success() {
     let transport = Arc::new(MockTransport::with_responses(vec![
@@ -217,3 +292,346 @@ async fn test_delete_objects_batch() {
     let output = result.unwrap();
     assert_eq!(output.deleted.len(), 2);
 }
+
+fn sse_customer_key() -> [u8; 32] {
+    std::array::from_fn(|i| i as u8)
+}
+
+const SSE_CUSTOMER_KEY_B64: &str = "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=";
+const SSE_CUSTOMER_KEY_MD5: &str = "tP/LI3N87DFaSk0aoqYgzg==";
+
+#[tokio::test]
+async fn test_put_object_with_sse_customer_key() {
+    let transport = Arc::new(MockTransport::with_responses(vec![
+        MockResponse::ok().with_header("etag", "\"abc123\""),
+    ]));
+    let service = create_test_service_with_transport(transport.clone());
+
+    let request = PutObjectRequest::new("test-bucket", "secret.txt")
+        .with_body(Bytes::from("content"))
+        .with_sse_customer_key(sse_customer_key());
+    service.put(request).await.unwrap();
+
+    let recorded = transport.last_request().unwrap();
+    let headers = &recorded.headers;
+    assert_eq!(
+        headers.get("x-amz-server-side-encryption-customer-algorithm").map(String::as_str),
+        Some("AES256")
+    );
+    assert_eq!(
+        headers.get("x-amz-server-side-encryption-customer-key").map(String::as_str),
+        Some(SSE_CUSTOMER_KEY_B64)
+    );
+    assert_eq!(
+        headers.get("x-amz-server-side-encryption-customer-key-md5").map(String::as_str),
+        Some(SSE_CUSTOMER_KEY_MD5)
+    );
+    assert!(!headers.contains_key("x-amz-server-side-encryption"));
+}
+
+#[tokio::test]
+async fn test_get_object_with_sse_customer_key() {
+    let transport = Arc::new(MockTransport::with_responses(vec![
+        MockResponse::ok_with_body("content"),
+    ]));
+    let service = create_test_service_with_transport(transport.clone());
+
+    let request = GetObjectRequest::new("test-bucket", "secret.txt")
+        .with_sse_customer_key(sse_customer_key());
+    service.get(request).await.unwrap();
+
+    let recorded = transport.last_request().unwrap();
+    let headers = &recorded.headers;
+    assert_eq!(
+        headers.get("x-amz-server-side-encryption-customer-algorithm").map(String::as_str),
+        Some("AES256")
+    );
+    assert_eq!(
+        headers.get("x-amz-server-side-encryption-customer-key").map(String::as_str),
+        Some(SSE_CUSTOMER_KEY_B64)
+    );
+    assert_eq!(
+        headers.get("x-amz-server-side-encryption-customer-key-md5").map(String::as_str),
+        Some(SSE_CUSTOMER_KEY_MD5)
+    );
+}
+
+#[tokio::test]
+async fn test_copy_object_with_copy_source_sse_customer_key() {
+    let copy_response = r#"<?xml version="1.0" encoding="UTF-8"?>
+<CopyObjectResult>
+    <ETag>"abc123"</ETag>
+</CopyObjectResult>"#;
+    let transport = Arc::new(MockTransport::with_responses(vec![
+        MockResponse::ok_with_body(copy_response),
+    ]));
+    let service = create_test_service_with_transport(transport.clone());
+
+    let request = CopyObjectRequest::same_bucket("test-bucket", "secret.txt", "copy.txt")
+        .with_copy_source_sse_customer_key(sse_customer_key())
+        .with_sse_customer_key([7u8; 32]);
+    service.copy(request).await.unwrap();
+
+    let recorded = transport.last_request().unwrap();
+    let headers = &recorded.headers;
+    assert_eq!(
+        headers
+            .get("x-amz-copy-source-server-side-encryption-customer-key")
+            .map(String::as_str),
+        Some(SSE_CUSTOMER_KEY_B64)
+    );
+    assert_eq!(
+        headers
+            .get("x-amz-copy-source-server-side-encryption-customer-key-md5")
+            .map(String::as_str),
+        Some(SSE_CUSTOMER_KEY_MD5)
+    );
+    assert!(headers.contains_key("x-amz-server-side-encryption-customer-key"));
+}
+
+#[tokio::test]
+async fn test_get_object_rejects_short_sse_customer_key() {
+    let transport = Arc::new(MockTransport::new());
+    let service = create_test_service_with_transport(transport.clone());
+
+    let mut request = GetObjectRequest::new("test-bucket", "secret.txt");
+    request.sse_customer_key = Some("AAECAwQFBgcICQoLDA0ODw==".to_string());
+
+    let result = service.get(request).await;
+    assert!(matches!(
+        result,
+        Err(aws_s3::S3Error::Request(aws_s3::RequestError::Validation { .. }))
+    ));
+    assert_eq!(transport.request_count(), 0);
+}
+
+fn list_page_xml(keys: &[&str], next_token: Option<&str>) -> String {
+    let contents: String = keys
+        .iter()
+        .map(|key| format!("<Contents><Key>{}</Key><Size>1</Size></Contents>", key))
+        .collect();
+    let token = next_token
+        .map(|t| format!("<NextContinuationToken>{}</NextContinuationToken>", t))
+        .unwrap_or_default();
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+    <Name>test-bucket</Name>
+    <KeyCount>{}</KeyCount>
+    <MaxKeys>2</MaxKeys>
+    <IsTruncated>{}</IsTruncated>
+    {}
+    {}
+</ListBucketResult>"#,
+        keys.len(),
+        next_token.is_some(),
+        token,
+        contents
+    )
+}
+
+#[tokio::test]
+async fn test_list_objects_paginated_follows_continuation_tokens() {
+    use futures::TryStreamExt;
+
+    let transport = Arc::new(MockTransport::with_responses(vec![
+        MockResponse::ok_with_body(list_page_xml(&["a.txt", "b.txt"], Some("token-1"))),
+        MockResponse::ok_with_body(list_page_xml(&["c.txt", "d.txt"], Some("token-2"))),
+        MockResponse::ok_with_body(list_page_xml(&["e.txt"], None)),
+    ]));
+    let service = create_test_service_with_transport(transport.clone());
+
+    let request = ListObjectsV2Request::new("test-bucket").with_max_keys(2);
+    let keys: Vec<String> = service
+        .list_objects_paginated(request)
+        .map_ok(|object| object.key)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(keys, vec!["a.txt", "b.txt", "c.txt", "d.txt", "e.txt"]);
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 3);
+    assert!(requests.iter().all(|r| r.url.contains("max-keys=2")));
+    assert!(!requests[0].url.contains("continuation-token"));
+    assert!(requests[1].url.contains("continuation-token=token-1"));
+    assert!(requests[2].url.contains("continuation-token=token-2"));
+}
+
+#[tokio::test]
+async fn test_list_objects_pages_yields_whole_pages() {
+    use futures::TryStreamExt;
+
+    let transport = Arc::new(MockTransport::with_responses(vec![
+        MockResponse::ok_with_body(list_page_xml(&["a.txt", "b.txt"], Some("token-1"))),
+        MockResponse::ok_with_body(list_page_xml(&["c.txt"], None)),
+    ]));
+    let service = create_test_service_with_transport(transport.clone());
+
+    let pages: Vec<ListObjectsV2Output> = service
+        .list_objects_pages(ListObjectsV2Request::new("test-bucket"))
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(pages.len(), 2);
+    assert_eq!(pages[0].contents.len(), 2);
+    assert!(pages[0].is_truncated);
+    assert_eq!(pages[1].contents.len(), 1);
+    assert!(!pages[1].is_truncated);
+}
+
+#[tokio::test]
+async fn test_list_objects_paginated_empty_bucket() {
+    use futures::TryStreamExt;
+
+    let transport = Arc::new(MockTransport::with_responses(vec![
+        MockResponse::ok_with_body(list_page_xml(&[], None)),
+    ]));
+    let service = create_test_service_with_transport(transport.clone());
+
+    let objects: Vec<S3Object> = service
+        .list_objects_paginated(ListObjectsV2Request::new("test-bucket"))
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert!(objects.is_empty());
+    assert_eq!(transport.request_count(), 1);
+}
+
+#[tokio::test]
+async fn test_list_objects_paginated_stops_on_error() {
+    use futures::StreamExt;
+
+    let error_xml = TestFixtures::error_xml("NoSuchBucket", "The specified bucket does not exist.");
+    let transport = Arc::new(MockTransport::with_responses(vec![
+        MockResponse::ok_with_body(list_page_xml(&["a.txt"], Some("token-1"))),
+        MockResponse::error(404, error_xml),
+    ]));
+    let service = create_test_service_with_transport(transport.clone());
+
+    let results: Vec<_> = service
+        .list_objects_paginated(ListObjectsV2Request::new("test-bucket"))
+        .collect()
+        .await;
+
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert_eq!(transport.request_count(), 2);
+}
+
+#[tokio::test]
+async fn test_get_object_checksum_validation_matches() {
+    let transport = Arc::new(MockTransport::with_responses(vec![
+        MockResponse::ok_with_body("hello")
+            .with_header("etag", "\"3f2a9c1b0e2d4c5b6a7980a1b2c3d4e5-2\"")
+            .with_header("x-amz-checksum-crc32", "NhCmhg=="),
+    ]));
+    let service = create_test_service_with_transport(transport.clone());
+
+    let request = GetObjectRequest::new("test-bucket", "hello.txt").with_checksum_validation();
+    let output = service.get(request).await.unwrap();
+
+    assert_eq!(output.body, Bytes::from("hello"));
+    let recorded = transport.last_request().unwrap();
+    assert_eq!(
+        recorded.headers.get("x-amz-checksum-mode").map(String::as_str),
+        Some("ENABLED")
+    );
+}
+
+#[tokio::test]
+async fn test_get_object_checksum_validation_detects_corruption() {
+    let transport = Arc::new(MockTransport::with_responses(vec![
+        MockResponse::ok_with_body("hellp").with_header(
+            "x-amz-checksum-sha256",
+            "LPJNul+wow4m6DsqxbninhsWHlwfp0JecwQzYpOLmCQ=",
+        ),
+    ]));
+    let service = create_test_service_with_transport(transport);
+
+    let request = GetObjectRequest::new("test-bucket", "hello.txt").with_checksum_validation();
+    let result = service.get(request).await;
+
+    match result {
+        Err(aws_s3::S3Error::Transfer(aws_s3::TransferError::ChecksumMismatch {
+            expected,
+            ..
+        })) => assert_eq!(expected, "LPJNul+wow4m6DsqxbninhsWHlwfp0JecwQzYpOLmCQ="),
+        other => panic!("expected checksum mismatch, got {:?}", other.map(|o| o.body)),
+    }
+}
+
+#[tokio::test]
+async fn test_get_object_checksum_validation_uses_plain_md5_etag() {
+    let transport = Arc::new(MockTransport::with_responses(vec![
+        MockResponse::ok_with_body("hellp")
+            .with_header("etag", "\"5d41402abc4b2a76b9719d911017c592\""),
+    ]));
+    let service = create_test_service_with_transport(transport);
+
+    let request = GetObjectRequest::new("test-bucket", "hello.txt").with_checksum_validation();
+    let result = service.get(request).await;
+
+    assert!(matches!(
+        result,
+        Err(aws_s3::S3Error::Transfer(aws_s3::TransferError::ChecksumMismatch { .. }))
+    ));
+}
+
+#[tokio::test]
+async fn test_put_object_sends_conditional_headers() {
+    let transport = Arc::new(MockTransport::with_responses(vec![
+        MockResponse::ok().with_header("etag", "\"def456\""),
+    ]));
+    let service = create_test_service_with_transport(transport.clone());
+
+    let request = PutObjectRequest::new("test-bucket", "test-key.txt")
+        .with_body(Bytes::from("content"))
+        .with_if_match("\"abc123\"");
+    service.put(request).await.unwrap();
+
+    let recorded = transport.last_request().unwrap();
+    assert_eq!(
+        recorded.headers.get("if-match").map(String::as_str),
+        Some("\"abc123\"")
+    );
+    assert!(!recorded.headers.contains_key("if-none-match"));
+}
+
+#[tokio::test]
+async fn test_put_object_if_none_match_on_existing_object() {
+    let error_xml = TestFixtures::error_xml(
+        "PreconditionFailed",
+        "At least one of the pre-conditions you specified did not hold",
+    );
+    let transport = Arc::new(MockTransport::with_responses(vec![
+        MockResponse::error(412, error_xml),
+    ]));
+    let service = create_test_service_with_transport(transport.clone());
+
+    let request = PutObjectRequest::new("test-bucket", "test-key.txt")
+        .with_body(Bytes::from("content"))
+        .with_if_none_match("*");
+    let result = service.put(request).await;
+
+    let recorded = transport.last_request().unwrap();
+    assert_eq!(recorded.headers.get("if-none-match").map(String::as_str), Some("*"));
+
+    match result.unwrap_err() {
+        aws_s3::S3Error::Object(aws_s3::ObjectError::PreconditionFailed {
+            bucket,
+            key,
+            condition,
+            ..
+        }) => {
+            assert_eq!(bucket, "test-bucket");
+            assert_eq!(key, "test-key.txt");
+            assert_eq!(condition, "If-None-Match: *");
+        }
+        other => panic!("Expected ObjectError::PreconditionFailed, got {:?}", other),
+    }
+}
@@ -0,0 +1,554 @@
+//! Integration tests for transfer utilities.
+
+use async_trait::async_trait;
+use aws_s3::config::S3Config;
+use aws_s3::mocks::{MockSigner, TestFixtures};
+use aws_s3::services::{MultipartService, ObjectsService};
+use aws_s3::transfer::{
+    copy_object_multipart, download_object_parallel, upload_from_reader, MIN_PART_SIZE,
+};
+use aws_s3::{
+    CopyObjectRequest, HttpRequest, HttpResponse, HttpTransport, RequestError, S3Error,
+    TransferError,
+};
+use bytes::Bytes;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Transport that serves an in-memory object, honouring `Range` headers.
+///
+/// Earlier ranges are answered more slowly so that they complete out of
+/// order, and ranges listed in `fail_once` fail on their first request.
+struct RangeTransport {
+    payload: Bytes,
+    reported_length: u64,
+    fail_once: Mutex<Vec<u64>>,
+    ranges: Mutex<Vec<String>>,
+}
+
+impl RangeTransport {
+    fn new(payload: &'static [u8]) -> Self {
+        Self {
+            payload: Bytes::from_static(payload),
+            reported_length: payload.len() as u64,
+            fail_once: Mutex::new(Vec::new()),
+            ranges: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn response(status: u16, body: Bytes) -> HttpResponse {
+        let mut headers = HashMap::new();
+        headers.insert("content-length".to_string(), body.len().to_string());
+        HttpResponse {
+            status,
+            headers,
+            body,
+        }
+    }
+}
+
+#[async_trait]
+impl HttpTransport for RangeTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, S3Error> {
+        if request.method == "HEAD" {
+            let mut response = Self::response(200, Bytes::new());
+            response.headers.insert(
+                "content-length".to_string(),
+                self.reported_length.to_string(),
+            );
+            response
+                .headers
+                .insert("etag".to_string(), "\"v1\"".to_string());
+            return Ok(response);
+        }
+
+        let Some(range) = request.headers.get("range").cloned() else {
+            return Ok(Self::response(200, self.payload.clone()));
+        };
+        self.ranges.lock().unwrap().push(range.clone());
+
+        let (start, end) = range
+            .trim_start_matches("bytes=")
+            .split_once('-')
+            .map(|(s, e)| (s.parse::<u64>().unwrap(), e.parse::<u64>().unwrap()))
+            .unwrap();
+
+        let should_fail = {
+            let mut fail_once = self.fail_once.lock().unwrap();
+            let position = fail_once.iter().position(|&s| s == start);
+            position.map(|i| fail_once.remove(i)).is_some()
+        };
+        if should_fail {
+            return Ok(Self::response(
+                503,
+                Bytes::from(TestFixtures::error_xml(
+                    "SlowDown",
+                    "Please reduce your request rate.",
+                )),
+            ));
+        }
+
+        let delay = (self.reported_length - start) / 2;
+        tokio::time::sleep(Duration::from_millis(delay)).await;
+
+        let end = end.min(self.payload.len() as u64 - 1);
+        Ok(Self::response(
+            206,
+            self.payload.slice(start as usize..=end as usize),
+        ))
+    }
+
+    async fn send_streaming(
+        &self,
+        request: HttpRequest,
+        _body_stream: Box<dyn futures::Stream<Item = Result<Bytes, std::io::Error>> + Send + Unpin>,
+    ) -> Result<HttpResponse, S3Error> {
+        self.send(request).await
+    }
+}
+
+fn create_service(transport: Arc<RangeTransport>) -> ObjectsService {
+    let config = Arc::new(S3Config::default());
+    let signer = Arc::new(MockSigner::new());
+    ObjectsService::new(config, transport, signer)
+}
+
+const PAYLOAD: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+#[tokio::test]
+async fn test_download_object_parallel_assembles_ranges() {
+    let transport = Arc::new(RangeTransport::new(PAYLOAD));
+    let service = create_service(transport.clone());
+    let dir = tempfile::tempdir().unwrap();
+    let dest = dir.path().join("object.bin");
+
+    let written = download_object_parallel(&service, "test-bucket", "big.bin", &dest, 12, 3)
+        .await
+        .unwrap();
+
+    assert_eq!(written, PAYLOAD.len() as u64);
+    assert_eq!(std::fs::read(&dest).unwrap(), PAYLOAD);
+
+    let mut ranges = transport.ranges.lock().unwrap().clone();
+    ranges.sort();
+    assert_eq!(ranges, vec!["bytes=0-11", "bytes=12-23", "bytes=24-35"]);
+}
+
+#[tokio::test]
+async fn test_download_object_parallel_retries_failed_range() {
+    let transport = Arc::new(RangeTransport::new(PAYLOAD));
+    transport.fail_once.lock().unwrap().push(12);
+    let service = create_service(transport.clone());
+    let dir = tempfile::tempdir().unwrap();
+    let dest = dir.path().join("object.bin");
+
+    download_object_parallel(&service, "test-bucket", "big.bin", &dest, 12, 2)
+        .await
+        .unwrap();
+
+    assert_eq!(std::fs::read(&dest).unwrap(), PAYLOAD);
+    let ranges = transport.ranges.lock().unwrap();
+    assert_eq!(ranges.iter().filter(|r| *r == "bytes=12-23").count(), 2);
+}
+
+#[tokio::test]
+async fn test_download_object_parallel_small_object_single_get() {
+    let transport = Arc::new(RangeTransport::new(b"tiny"));
+    let service = create_service(transport.clone());
+    let dir = tempfile::tempdir().unwrap();
+    let dest = dir.path().join("tiny.bin");
+
+    let written = download_object_parallel(&service, "test-bucket", "tiny.bin", &dest, 12, 4)
+        .await
+        .unwrap();
+
+    assert_eq!(written, 4);
+    assert_eq!(std::fs::read(&dest).unwrap(), b"tiny");
+    assert!(transport.ranges.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_download_object_parallel_incomplete_body() {
+    let mut transport = RangeTransport::new(b"tiny");
+    transport.reported_length = 10;
+    let service = create_service(Arc::new(transport));
+    let dir = tempfile::tempdir().unwrap();
+    let dest = dir.path().join("tiny.bin");
+
+    let result = download_object_parallel(&service, "test-bucket", "tiny.bin", &dest, 12, 4).await;
+
+    assert!(matches!(
+        result,
+        Err(S3Error::Transfer(TransferError::IncompleteBody {
+            expected: 10,
+            received: 4
+        }))
+    ));
+}
+
+/// Transport that plays the multipart upload API, failing the part numbers
+/// listed in `fail_parts`.
+#[derive(Default)]
+struct MultipartTransport {
+    fail_parts: Vec<u32>,
+    requests: Mutex<Vec<(String, String, usize)>>,
+}
+
+impl MultipartTransport {
+    fn requests(&self) -> Vec<(String, String, usize)> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    fn uploaded_parts(&self) -> Vec<(u32, usize)> {
+        let mut parts: Vec<_> = self
+            .requests()
+            .into_iter()
+            .filter(|(method, _, _)| method == "PUT")
+            .map(|(_, url, len)| (query_param(&url, "partNumber").parse().unwrap(), len))
+            .collect();
+        parts.sort();
+        parts
+    }
+}
+
+fn query_param(url: &str, name: &str) -> String {
+    url::Url::parse(url)
+        .unwrap()
+        .query_pairs()
+        .find(|(k, _)| k == name)
+        .map(|(_, v)| v.into_owned())
+        .unwrap_or_default()
+}
+
+#[async_trait]
+impl HttpTransport for MultipartTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, S3Error> {
+        let body_len = request.body.as_ref().map_or(0, |b| b.len());
+        self.requests
+            .lock()
+            .unwrap()
+            .push((request.method.clone(), request.url.clone(), body_len));
+
+        let response = match request.method.as_str() {
+            "POST" if request.url.contains("uploads") => RangeTransport::response(
+                200,
+                Bytes::from_static(TestFixtures::create_multipart_xml().as_bytes()),
+            ),
+            "POST" => RangeTransport::response(
+                200,
+                Bytes::from_static(TestFixtures::complete_multipart_xml().as_bytes()),
+            ),
+            "PUT" => {
+                let part_number: u32 = query_param(&request.url, "partNumber").parse().unwrap();
+                if self.fail_parts.contains(&part_number) {
+                    RangeTransport::response(
+                        400,
+                        Bytes::from(TestFixtures::error_xml(
+                            "InvalidPart",
+                            "One or more of the specified parts could not be found.",
+                        )),
+                    )
+                } else {
+                    let mut response = RangeTransport::response(200, Bytes::new());
+                    response
+                        .headers
+                        .insert("etag".to_string(), format!("\"etag-{}\"", part_number));
+                    response
+                }
+            }
+            "DELETE" => RangeTransport::response(204, Bytes::new()),
+            method => panic!("unexpected {} request", method),
+        };
+        Ok(response)
+    }
+
+    async fn send_streaming(
+        &self,
+        request: HttpRequest,
+        _body_stream: Box<dyn futures::Stream<Item = Result<Bytes, std::io::Error>> + Send + Unpin>,
+    ) -> Result<HttpResponse, S3Error> {
+        self.send(request).await
+    }
+}
+
+fn create_multipart_service(transport: Arc<MultipartTransport>) -> MultipartService {
+    let config = Arc::new(S3Config::default());
+    let signer = Arc::new(MockSigner::new());
+    MultipartService::new(config, transport, signer)
+}
+
+#[tokio::test]
+async fn test_upload_from_reader_splits_into_parts() {
+    let transport = Arc::new(MultipartTransport::default());
+    let service = create_multipart_service(transport.clone());
+    let source = std::io::Cursor::new(vec![7u8; 12 * 1024 * 1024]);
+
+    let e_tag = upload_from_reader(&service, "test-bucket", "big.bin", source, MIN_PART_SIZE, 3)
+        .await
+        .unwrap();
+
+    assert!(e_tag.contains("combined-etag"));
+    assert_eq!(
+        transport.uploaded_parts(),
+        vec![(1, MIN_PART_SIZE), (2, MIN_PART_SIZE), (3, 2 * 1024 * 1024)]
+    );
+    assert!(transport
+        .requests()
+        .iter()
+        .all(|(method, _, _)| method != "DELETE"));
+}
+
+#[tokio::test]
+async fn test_upload_from_reader_aborts_on_part_failure() {
+    let transport = Arc::new(MultipartTransport {
+        fail_parts: vec![2],
+        ..Default::default()
+    });
+    let service = create_multipart_service(transport.clone());
+    let source = std::io::Cursor::new(vec![7u8; 12 * 1024 * 1024]);
+
+    let result =
+        upload_from_reader(&service, "test-bucket", "big.bin", source, MIN_PART_SIZE, 3).await;
+
+    assert!(result.is_err());
+    let requests = transport.requests();
+    let (method, url, _) = requests.last().unwrap();
+    assert_eq!(method, "DELETE");
+    assert_eq!(query_param(url, "uploadId"), "upload-id-12345");
+    // The upload was never completed
+    assert_eq!(
+        requests
+            .iter()
+            .filter(|(method, _, _)| method == "POST")
+            .count(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn test_upload_from_reader_short_source_single_part() {
+    let transport = Arc::new(MultipartTransport::default());
+    let service = create_multipart_service(transport.clone());
+
+    upload_from_reader(
+        &service,
+        "test-bucket",
+        "small.bin",
+        &b"hello"[..],
+        MIN_PART_SIZE,
+        4,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(transport.uploaded_parts(), vec![(1, 5)]);
+}
+
+#[tokio::test]
+async fn test_upload_from_reader_rejects_small_part_size() {
+    let transport = Arc::new(MultipartTransport::default());
+    let service = create_multipart_service(transport.clone());
+
+    let result =
+        upload_from_reader(&service, "test-bucket", "small.bin", &b"hello"[..], 1024, 4).await;
+
+    assert!(matches!(
+        result,
+        Err(S3Error::Request(RequestError::EntityTooSmall { .. }))
+    ));
+    assert!(transport.requests().is_empty());
+}
+
+const GIB: u64 = 1024 * 1024 * 1024;
+
+/// Transport that plays the CopyObject and multipart copy APIs for a source
+/// object of `source_length` bytes, failing the part numbers in `fail_parts`.
+#[derive(Default)]
+struct CopyTransport {
+    source_length: u64,
+    fail_parts: Vec<u32>,
+    requests: Mutex<Vec<HttpRequest>>,
+}
+
+impl CopyTransport {
+    fn new(source_length: u64) -> Self {
+        Self {
+            source_length,
+            ..Default::default()
+        }
+    }
+
+    fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    fn part_ranges(&self) -> Vec<(u32, String)> {
+        let mut ranges: Vec<_> = self
+            .requests()
+            .into_iter()
+            .filter(|r| r.method == "PUT")
+            .map(|r| {
+                (
+                    query_param(&r.url, "partNumber").parse().unwrap(),
+                    r.headers["x-amz-copy-source-range"].clone(),
+                )
+            })
+            .collect();
+        ranges.sort();
+        ranges
+    }
+}
+
+#[async_trait]
+impl HttpTransport for CopyTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, S3Error> {
+        self.requests.lock().unwrap().push(request.clone());
+
+        let response = match request.method.as_str() {
+            "HEAD" => {
+                let mut response = RangeTransport::response(200, Bytes::new());
+                let headers = &mut response.headers;
+                headers.insert("content-length".to_string(), self.source_length.to_string());
+                headers.insert("content-type".to_string(), "video/mp4".to_string());
+                headers.insert("cache-control".to_string(), "max-age=60".to_string());
+                headers.insert("x-amz-meta-owner".to_string(), "media-team".to_string());
+                headers.insert("etag".to_string(), "\"source-etag\"".to_string());
+                response
+            }
+            "POST" if request.url.contains("uploads") => RangeTransport::response(
+                200,
+                Bytes::from_static(TestFixtures::create_multipart_xml().as_bytes()),
+            ),
+            "POST" => RangeTransport::response(
+                200,
+                Bytes::from_static(TestFixtures::complete_multipart_xml().as_bytes()),
+            ),
+            "PUT" if request.url.contains("partNumber") => {
+                let part_number: u32 = query_param(&request.url, "partNumber").parse().unwrap();
+                if self.fail_parts.contains(&part_number) {
+                    RangeTransport::response(
+                        400,
+                        Bytes::from(TestFixtures::error_xml(
+                            "InvalidRange",
+                            "The requested range is not satisfiable.",
+                        )),
+                    )
+                } else {
+                    RangeTransport::response(
+                        200,
+                        Bytes::from(format!(
+                            "<CopyPartResult><ETag>\"etag-{}\"</ETag></CopyPartResult>",
+                            part_number
+                        )),
+                    )
+                }
+            }
+            "PUT" => {
+                let mut response = RangeTransport::response(200, Bytes::new());
+                response
+                    .headers
+                    .insert("etag".to_string(), "\"copied-etag\"".to_string());
+                response
+            }
+            "DELETE" => RangeTransport::response(204, Bytes::new()),
+            method => panic!("unexpected {} request", method),
+        };
+        Ok(response)
+    }
+
+    async fn send_streaming(
+        &self,
+        request: HttpRequest,
+        _body_stream: Box<dyn futures::Stream<Item = Result<Bytes, std::io::Error>> + Send + Unpin>,
+    ) -> Result<HttpResponse, S3Error> {
+        self.send(request).await
+    }
+}
+
+fn create_copy_services(transport: Arc<CopyTransport>) -> (ObjectsService, MultipartService) {
+    let config = Arc::new(S3Config::default());
+    let signer = Arc::new(MockSigner::new());
+    (
+        ObjectsService::new(config.clone(), transport.clone(), signer.clone()),
+        MultipartService::new(config, transport, signer),
+    )
+}
+
+fn copy_request() -> CopyObjectRequest {
+    CopyObjectRequest::new("source-bucket", "video.mp4", "dest-bucket", "video-copy.mp4")
+}
+
+#[tokio::test]
+async fn test_copy_object_multipart_large_source_uses_part_copies() {
+    let transport = Arc::new(CopyTransport::new(6 * GIB));
+    let (objects, multipart) = create_copy_services(transport.clone());
+
+    let output = copy_object_multipart(&objects, &multipart, copy_request(), 2 * GIB)
+        .await
+        .unwrap();
+
+    assert!(output.e_tag.unwrap().contains("combined-etag"));
+    assert_eq!(
+        transport.part_ranges(),
+        vec![
+            (1, "bytes=0-2147483647".to_string()),
+            (2, "bytes=2147483648-4294967295".to_string()),
+            (3, "bytes=4294967296-6442450943".to_string()),
+        ]
+    );
+
+    let requests = transport.requests();
+    let parts: Vec<_> = requests.iter().filter(|r| r.method == "PUT").collect();
+    assert!(parts.iter().all(|r| {
+        r.headers["x-amz-copy-source"] == "/source-bucket/video.mp4"
+            && r.headers["x-amz-copy-source-if-match"] == "\"source-etag\""
+    }));
+
+    let create = requests
+        .iter()
+        .find(|r| r.method == "POST" && r.url.contains("uploads"))
+        .unwrap();
+    assert!(create.url.contains("video-copy.mp4"));
+    assert_eq!(create.headers["content-type"], "video/mp4");
+    assert_eq!(create.headers["cache-control"], "max-age=60");
+    assert_eq!(create.headers["x-amz-meta-owner"], "media-team");
+    assert!(requests.iter().all(|r| r.method != "DELETE"));
+}
+
+#[tokio::test]
+async fn test_copy_object_multipart_small_source_single_copy() {
+    let transport = Arc::new(CopyTransport::new(GIB));
+    let (objects, multipart) = create_copy_services(transport.clone());
+
+    let output = copy_object_multipart(&objects, &multipart, copy_request(), 2 * GIB)
+        .await
+        .unwrap();
+
+    assert_eq!(output.e_tag.as_deref(), Some("\"copied-etag\""));
+    let methods: Vec<_> = transport
+        .requests()
+        .into_iter()
+        .map(|r| r.method)
+        .collect();
+    assert_eq!(methods, vec!["HEAD", "PUT"]);
+}
+
+#[tokio::test]
+async fn test_copy_object_multipart_aborts_on_part_failure() {
+    let transport = Arc::new(CopyTransport {
+        source_length: 6 * GIB,
+        fail_parts: vec![2],
+        ..Default::default()
+    });
+    let (objects, multipart) = create_copy_services(transport.clone());
+
+    let result = copy_object_multipart(&objects, &multipart, copy_request(), 2 * GIB).await;
+
+    assert!(result.is_err());
+    let requests = transport.requests();
+    let abort = requests.last().unwrap();
+    assert_eq!(abort.method, "DELETE");
+    assert_eq!(query_param(&abort.url, "uploadId"), "upload-id-12345");
+    assert_eq!(requests.iter().filter(|r| r.method == "POST").count(), 1);
+}
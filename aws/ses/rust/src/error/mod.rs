@@ -162,6 +162,17 @@ pub enum SesError {
         identity: String,
     },
 
+    /// DKIM verification failed errors.
+    ///
+    /// These errors occur when SES reports a permanent DKIM verification
+    /// failure for an identity, typically because the CNAME records were
+    /// never published or point at the wrong tokens.
+    #[error("DKIM verification failed: {identity}")]
+    DkimVerificationFailed {
+        /// The email address or domain whose DKIM verification failed.
+        identity: String,
+    },
+
     /// Configuration set not found errors.
     ///
     /// These errors occur when referencing a configuration set that
@@ -172,6 +183,28 @@ pub enum SesError {
         name: String,
     },
 
+    /// Configuration set already exists errors.
+    ///
+    /// These errors occur when creating a configuration set whose name is
+    /// already in use in the AWS account.
+    #[error("Configuration set already exists: {name}")]
+    ConfigurationSetAlreadyExists {
+        /// The name of the configuration set.
+        name: String,
+    },
+
+    /// Event destination already exists errors.
+    ///
+    /// These errors occur when adding an event destination whose name is
+    /// already in use in the configuration set.
+    #[error("Event destination already exists: {name} (configuration set {configuration_set_name})")]
+    EventDestinationAlreadyExists {
+        /// The name of the configuration set.
+        configuration_set_name: String,
+        /// The name of the event destination.
+        name: String,
+    },
+
     /// Template not found errors.
     ///
     /// These errors occur when referencing an email template that
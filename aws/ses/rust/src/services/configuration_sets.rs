@@ -3,6 +3,7 @@
 //! This module provides methods for managing configuration sets, which allow
 //! you to customize email sending behavior and publish events.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 use serde::{Deserialize, Serialize};
 
@@ -10,7 +11,8 @@ use crate::error::{SesError, SesResult};
 use crate::http::{HttpClient, SesRequest, HttpMethod};
 use crate::types::{
     TrackingOptions, DeliveryOptions, ReputationOptions, SendingOptions,
-    SuppressionOptions, Tag,
+    SuppressionOptions, Tag, CreateConfigurationSetRequest, EventDestination, EventType,
+    CloudWatchDestination, KinesisFirehoseDestination, PinpointDestination, SnsDestination,
 };
 use super::SesService;
 
@@ -18,6 +20,7 @@ use super::SesService;
 ///
 /// This service provides methods for:
 /// - Creating and deleting configuration sets
+/// - Attaching event destinations to configuration sets
 /// - Getting configuration set details
 /// - Listing all configuration sets
 /// - Updating delivery, reputation, sending, and tracking options
@@ -70,6 +73,104 @@ impl ConfigurationSetService {
             })
     }
 
+    /// Create a configuration set and optionally attach an event destination.
+    ///
+    /// The set name and event destination are validated before anything is
+    /// sent, so an invalid destination never leaves a half-configured set
+    /// behind. If the set is created but attaching the destination fails, the
+    /// set is kept and the destination error is returned; use
+    /// [`create_event_destination`](Self::create_event_destination) to retry.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The configuration set to create
+    /// * `event_destination` - Optional event destination to attach
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SesError::ConfigurationSetAlreadyExists`] if the name is
+    /// already in use, or [`SesError::Validation`] if the name or event
+    /// destination is invalid.
+    pub async fn create(
+        &self,
+        request: CreateConfigurationSetRequest,
+        event_destination: Option<EventDestination>,
+    ) -> SesResult<CreateConfigurationSetResponse> {
+        validate_name("configuration_set_name", &request.configuration_set_name)?;
+        if let Some(destination) = &event_destination {
+            validate_event_destination(destination)?;
+        }
+
+        let body = serde_json::to_vec(&request)
+            .map_err(|e| SesError::Serialization {
+                message: format!("Failed to serialize CreateConfigurationSet request: {}", e),
+            })?;
+
+        let ses_request = SesRequest::new(HttpMethod::Post, "/v2/email/configuration-sets")
+            .with_body(body);
+
+        let response = self.http_client.send_request(ses_request).await
+            .map_err(|e| map_already_exists(e, || SesError::ConfigurationSetAlreadyExists {
+                name: request.configuration_set_name.clone(),
+            }))?;
+
+        let created = serde_json::from_slice(&response.body)
+            .map_err(|e| SesError::Serialization {
+                message: format!("Failed to deserialize CreateConfigurationSet response: {}", e),
+            })?;
+
+        if let Some(destination) = event_destination {
+            self.create_event_destination(&request.configuration_set_name, destination)
+                .await?;
+        }
+
+        Ok(created)
+    }
+
+    /// Attach an event destination to a configuration set.
+    ///
+    /// Exactly one destination kind (CloudWatch, Kinesis Firehose, Pinpoint
+    /// or SNS) must be set, and at least one event type must be matched
+    /// without duplicates.
+    ///
+    /// # Arguments
+    ///
+    /// * `configuration_set_name` - Name of the configuration set
+    /// * `event_destination` - The event destination to attach
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SesError::EventDestinationAlreadyExists`] if the set already
+    /// has a destination with the same name, or [`SesError::Validation`] if
+    /// the destination is invalid.
+    pub async fn create_event_destination(
+        &self,
+        configuration_set_name: &str,
+        event_destination: EventDestination,
+    ) -> SesResult<CreateConfigurationSetEventDestinationResponse> {
+        validate_name("configuration_set_name", configuration_set_name)?;
+        validate_event_destination(&event_destination)?;
+
+        let body = serde_json::to_vec(&CreateEventDestinationBody::from(&event_destination))
+            .map_err(|e| SesError::Serialization {
+                message: format!("Failed to serialize CreateConfigurationSetEventDestination request: {}", e),
+            })?;
+
+        let path = format!("/v2/email/configuration-sets/{}/event-destinations", configuration_set_name);
+        let ses_request = SesRequest::new(HttpMethod::Post, &path).with_body(body);
+
+        let response = self.http_client.send_request(ses_request).await
+            .map_err(|e| map_already_exists(e, || SesError::EventDestinationAlreadyExists {
+                configuration_set_name: configuration_set_name.to_string(),
+                name: event_destination.name.clone(),
+            }))?;
+
+        serde_json::from_slice(&response.body)
+            .map_err(|e| SesError::Serialization {
+                message: format!("Failed to deserialize CreateConfigurationSetEventDestination response: {}", e),
+            })
+    }
+
     /// Delete a configuration set.
     ///
     /// # Arguments
@@ -260,24 +361,151 @@ impl SesService for ConfigurationSetService {
     }
 }
 
+/// Maximum length of configuration set and event destination names.
+const MAX_NAME_LENGTH: usize = 64;
+
+/// Validate a configuration set or event destination name.
+///
+/// Names may contain only ASCII letters, numbers, underscores and dashes.
+fn validate_name(field: &str, name: &str) -> SesResult<()> {
+    let message = if name.is_empty() {
+        format!("{} cannot be empty", field)
+    } else if name.len() > MAX_NAME_LENGTH {
+        format!("{} exceeds {} characters", field, MAX_NAME_LENGTH)
+    } else if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        format!("{} may contain only letters, numbers, underscores and dashes", field)
+    } else {
+        return Ok(());
+    };
+
+    Err(SesError::Validation {
+        message,
+        field: Some(field.to_string()),
+    })
+}
+
+/// Validate an event destination before it is sent.
+fn validate_event_destination(destination: &EventDestination) -> SesResult<()> {
+    validate_name("event_destination_name", &destination.name)?;
+
+    if destination.matching_event_types.is_empty() {
+        return Err(SesError::Validation {
+            message: "At least one matching event type is required".to_string(),
+            field: Some("matching_event_types".to_string()),
+        });
+    }
+
+    let mut seen = HashSet::new();
+    for event_type in &destination.matching_event_types {
+        if !seen.insert(event_type) {
+            return Err(SesError::Validation {
+                message: format!("Duplicate matching event type: {:?}", event_type),
+                field: Some("matching_event_types".to_string()),
+            });
+        }
+    }
+
+    let kinds = [
+        destination.cloud_watch_destination.is_some(),
+        destination.kinesis_firehose_destination.is_some(),
+        destination.pinpoint_destination.is_some(),
+        destination.sns_destination.is_some(),
+    ]
+    .iter()
+    .filter(|set| **set)
+    .count();
+
+    if kinds != 1 {
+        return Err(SesError::Validation {
+            message: format!(
+                "Exactly one destination (CloudWatch, Kinesis Firehose, Pinpoint or SNS) must be set, found {}",
+                kinds
+            ),
+            field: Some("event_destination".to_string()),
+        });
+    }
+
+    if let Some(cloud_watch) = &destination.cloud_watch_destination {
+        if cloud_watch.dimension_configurations.is_empty() {
+            return Err(SesError::Validation {
+                message: "CloudWatch destination requires at least one dimension".to_string(),
+                field: Some("cloud_watch_destination".to_string()),
+            });
+        }
+    }
+
+    if let Some(kinesis) = &destination.kinesis_firehose_destination {
+        validate_arn("kinesis_firehose_destination.iam_role_arn", &kinesis.iam_role_arn)?;
+        validate_arn("kinesis_firehose_destination.delivery_stream_arn", &kinesis.delivery_stream_arn)?;
+    }
+
+    if let Some(sns) = &destination.sns_destination {
+        validate_arn("sns_destination.topic_arn", &sns.topic_arn)?;
+    }
+
+    Ok(())
+}
+
+fn validate_arn(field: &str, arn: &str) -> SesResult<()> {
+    if arn.starts_with("arn:") {
+        Ok(())
+    } else {
+        Err(SesError::Validation {
+            message: format!("{} must be an ARN", field),
+            field: Some(field.to_string()),
+        })
+    }
+}
+
+/// Replace an `AlreadyExistsException` API error with a typed collision error.
+fn map_already_exists(error: SesError, collision: impl FnOnce() -> SesError) -> SesError {
+    match error {
+        SesError::AwsApi { ref code, .. } if code == "AlreadyExistsException" => collision(),
+        other => other,
+    }
+}
+
 // Request types
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Body of a CreateConfigurationSetEventDestination request.
+#[derive(Debug, Serialize)]
 #[serde(rename_all = "PascalCase")]
-struct CreateConfigurationSetRequest {
-    configuration_set_name: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    tracking_options: Option<TrackingOptions>,
+struct CreateEventDestinationBody<'a> {
+    event_destination_name: &'a str,
+    event_destination: EventDestinationDefinition<'a>,
+}
+
+/// An event destination as nested in the request body, without its name.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct EventDestinationDefinition<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
-    delivery_options: Option<DeliveryOptions>,
+    enabled: Option<bool>,
+    matching_event_types: &'a [EventType],
     #[serde(skip_serializing_if = "Option::is_none")]
-    reputation_options: Option<ReputationOptions>,
+    cloud_watch_destination: Option<&'a CloudWatchDestination>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    sending_options: Option<SendingOptions>,
+    kinesis_firehose_destination: Option<&'a KinesisFirehoseDestination>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    tags: Option<Vec<Tag>>,
+    pinpoint_destination: Option<&'a PinpointDestination>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    suppression_options: Option<SuppressionOptions>,
+    sns_destination: Option<&'a SnsDestination>,
+}
+
+impl<'a> From<&'a EventDestination> for CreateEventDestinationBody<'a> {
+    fn from(destination: &'a EventDestination) -> Self {
+        Self {
+            event_destination_name: &destination.name,
+            event_destination: EventDestinationDefinition {
+                enabled: destination.enabled,
+                matching_event_types: &destination.matching_event_types,
+                cloud_watch_destination: destination.cloud_watch_destination.as_ref(),
+                kinesis_firehose_destination: destination.kinesis_firehose_destination.as_ref(),
+                pinpoint_destination: destination.pinpoint_destination.as_ref(),
+                sns_destination: destination.sns_destination.as_ref(),
+            },
+        }
+    }
 }
 
 // Response types
@@ -286,6 +514,10 @@ struct CreateConfigurationSetRequest {
 #[serde(rename_all = "PascalCase")]
 pub struct CreateConfigurationSetResponse {}
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateConfigurationSetEventDestinationResponse {}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct DeleteConfigurationSetResponse {}
@@ -333,3 +565,136 @@ pub struct PutConfigurationSetSendingOptionsResponse {}
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct PutConfigurationSetTrackingOptionsResponse {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const TOPIC_ARN: &str = "arn:aws:sns:us-east-1:123456789012:ses-events";
+
+    fn sns_destination() -> EventDestination {
+        EventDestination::new("bounces-and-complaints", vec![EventType::Bounce, EventType::Complaint])
+            .with_sns(SnsDestination::new(TOPIC_ARN))
+    }
+
+    #[test]
+    fn test_create_event_destination_body_for_sns() {
+        let destination = sns_destination();
+        assert!(validate_event_destination(&destination).is_ok());
+
+        let body = serde_json::to_value(CreateEventDestinationBody::from(&destination)).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "EventDestinationName": "bounces-and-complaints",
+                "EventDestination": {
+                    "Enabled": true,
+                    "MatchingEventTypes": ["BOUNCE", "COMPLAINT"],
+                    "SnsDestination": { "TopicArn": TOPIC_ARN }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_create_configuration_set_request_body() {
+        let request = CreateConfigurationSetRequest::new("transactional");
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(body, json!({ "ConfigurationSetName": "transactional" }));
+    }
+
+    #[test]
+    fn test_event_destination_requires_exactly_one_kind() {
+        let none = EventDestination::new("events", vec![EventType::Send]);
+        let both = sns_destination().with_kinesis_firehose(KinesisFirehoseDestination::new(
+            "arn:aws:iam::123456789012:role/ses-firehose",
+            "arn:aws:firehose:us-east-1:123456789012:deliverystream/ses",
+        ));
+
+        for destination in [none, both] {
+            match validate_event_destination(&destination) {
+                Err(SesError::Validation { field, .. }) => {
+                    assert_eq!(field.as_deref(), Some("event_destination"));
+                }
+                other => panic!("Expected validation error, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_event_destination_event_types() {
+        let mut empty = sns_destination();
+        empty.matching_event_types.clear();
+
+        let mut duplicate = sns_destination();
+        duplicate.matching_event_types.push(EventType::Bounce);
+
+        for destination in [empty, duplicate] {
+            match validate_event_destination(&destination) {
+                Err(SesError::Validation { field, .. }) => {
+                    assert_eq!(field.as_deref(), Some("matching_event_types"));
+                }
+                other => panic!("Expected validation error, got {:?}", other),
+            }
+        }
+
+        let all = EventDestination::new(
+            "all-events",
+            vec![
+                EventType::Send,
+                EventType::Bounce,
+                EventType::Complaint,
+                EventType::Delivery,
+                EventType::Open,
+                EventType::Click,
+            ],
+        )
+        .with_sns(SnsDestination::new(TOPIC_ARN));
+        assert!(validate_event_destination(&all).is_ok());
+    }
+
+    #[test]
+    fn test_event_destination_targets() {
+        let bad_topic = EventDestination::new("events", vec![EventType::Send])
+            .with_sns(SnsDestination::new("ses-events"));
+        assert!(validate_event_destination(&bad_topic).is_err());
+
+        let no_dimensions = EventDestination::new("events", vec![EventType::Send])
+            .with_cloudwatch(CloudWatchDestination::new(vec![]));
+        assert!(validate_event_destination(&no_dimensions).is_err());
+    }
+
+    #[test]
+    fn test_validate_name() {
+        assert!(validate_name("configuration_set_name", "my_config-set-1").is_ok());
+        assert!(validate_name("configuration_set_name", "").is_err());
+        assert!(validate_name("configuration_set_name", "has space").is_err());
+        assert!(validate_name("configuration_set_name", &"a".repeat(MAX_NAME_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_map_already_exists() {
+        let conflict = SesError::AwsApi {
+            code: "AlreadyExistsException".to_string(),
+            message: "Configuration set <transactional> already exists.".to_string(),
+            request_id: None,
+            retryable: false,
+        };
+        let mapped = map_already_exists(conflict, || SesError::ConfigurationSetAlreadyExists {
+            name: "transactional".to_string(),
+        });
+        assert!(matches!(
+            mapped,
+            SesError::ConfigurationSetAlreadyExists { ref name } if name == "transactional"
+        ));
+
+        let other = SesError::ConfigurationSetNotFound {
+            name: "transactional".to_string(),
+        };
+        assert!(matches!(
+            map_already_exists(other, || unreachable!()),
+            SesError::ConfigurationSetNotFound { .. }
+        ));
+    }
+}
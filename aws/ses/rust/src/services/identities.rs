@@ -4,17 +4,22 @@
 //! for sending emails through SES.
 
 use std::sync::Arc;
+use std::time::Duration;
 use serde::{Deserialize, Serialize};
+use tokio::time::{sleep, Instant};
 
 use crate::error::{SesError, SesResult};
 use crate::http::{HttpClient, SesRequest, HttpMethod};
-use crate::types::{DkimAttributes, MailFromAttributes, IdentityInfo, Tag, IdentityType};
+use crate::types::{
+    DkimAttributes, DkimDnsRecord, DkimStatus, MailFromAttributes, IdentityInfo, Tag, IdentityType,
+};
 use super::SesService;
 
 /// Service for email identity operations.
 ///
 /// This service provides methods for:
 /// - Creating and deleting email identities
+/// - Waiting for DKIM verification to complete
 /// - Getting identity information
 /// - Listing all identities
 /// - Configuring DKIM settings
@@ -72,6 +77,77 @@ impl IdentityService {
             })
     }
 
+    /// Create an email identity and return the DKIM records to publish.
+    ///
+    /// SES issues Easy DKIM tokens for the new identity. Each token must be
+    /// published as a CNAME record on the identity's domain (the part after
+    /// `@` for email address identities) before DKIM verification can
+    /// succeed; the records are returned ready to add to DNS.
+    ///
+    /// # Arguments
+    ///
+    /// * `email_identity` - The email address or domain to verify
+    /// * `tags` - Optional tags to associate with the identity
+    pub async fn create(
+        &self,
+        email_identity: &str,
+        tags: Option<Vec<Tag>>,
+    ) -> SesResult<CreatedIdentity> {
+        let response = self.create_email_identity(email_identity, tags, None).await?;
+        Ok(CreatedIdentity::from_response(email_identity, response))
+    }
+
+    /// Poll an identity until its DKIM verification completes.
+    ///
+    /// `Pending`, `NotStarted` and `TemporaryFailure` keep polling every
+    /// `interval`; SES retries temporary DNS lookup failures on its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `email_identity` - The email address or domain to wait for
+    /// * `interval` - Delay between `get_email_identity` calls
+    /// * `timeout` - Maximum time to wait for a final status
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SesError::DkimVerificationFailed`] if SES reports `Failed`,
+    /// or [`SesError::Timeout`] if no final status is reached in time.
+    pub async fn wait_for_dkim_verification(
+        &self,
+        email_identity: &str,
+        interval: Duration,
+        timeout: Duration,
+    ) -> SesResult<DkimAttributes> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let identity = self.get_email_identity(email_identity).await?;
+            if let Some(attributes) = identity.dkim_attributes {
+                match attributes.status {
+                    Some(DkimStatus::Success) => return Ok(attributes),
+                    Some(DkimStatus::Failed) => {
+                        return Err(SesError::DkimVerificationFailed {
+                            identity: email_identity.to_string(),
+                        });
+                    }
+                    _ => {}
+                }
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(SesError::Timeout {
+                    message: format!(
+                        "DKIM verification for {} did not complete within {:?}",
+                        email_identity, timeout
+                    ),
+                    retryable: false,
+                });
+            }
+            sleep(interval.min(remaining)).await;
+        }
+    }
+
     /// Delete an email identity.
     ///
     /// # Arguments
@@ -301,6 +377,42 @@ pub struct CreateEmailIdentityResponse {
     pub dkim_attributes: Option<DkimAttributes>,
 }
 
+/// A newly created identity and the DNS records needed to verify it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatedIdentity {
+    /// Identity type reported by SES.
+    pub identity_type: Option<String>,
+    /// Whether the identity can already send email.
+    pub verified_for_sending_status: Option<bool>,
+    /// Easy DKIM tokens issued for the identity.
+    pub dkim_tokens: Vec<String>,
+    /// CNAME records to publish, one per DKIM token.
+    pub dns_records: Vec<DkimDnsRecord>,
+}
+
+impl CreatedIdentity {
+    fn from_response(email_identity: &str, response: CreateEmailIdentityResponse) -> Self {
+        let domain = email_identity
+            .rsplit_once('@')
+            .map_or(email_identity, |(_, domain)| domain);
+        let dkim_tokens = response
+            .dkim_attributes
+            .and_then(|attributes| attributes.tokens)
+            .unwrap_or_default();
+        let dns_records = dkim_tokens
+            .iter()
+            .map(|token| DkimDnsRecord::cname(domain, token))
+            .collect();
+
+        Self {
+            identity_type: response.identity_type,
+            verified_for_sending_status: response.verified_for_sending_status,
+            dkim_tokens,
+            dns_records,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct DeleteEmailIdentityResponse {}
@@ -346,3 +458,152 @@ pub struct PutEmailIdentityMailFromAttributesResponse {}
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct PutEmailIdentityFeedbackAttributesResponse {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::SesResponse;
+    use async_trait::async_trait;
+    use http::StatusCode;
+    use serde_json::{json, Value};
+    use std::collections::{HashMap, VecDeque};
+    use std::sync::Mutex;
+
+    /// Returns scripted bodies in order, repeating the last one.
+    struct ScriptedClient {
+        bodies: Mutex<VecDeque<Value>>,
+        calls: Mutex<usize>,
+    }
+
+    impl ScriptedClient {
+        fn new(bodies: Vec<Value>) -> Arc<Self> {
+            Arc::new(Self {
+                bodies: Mutex::new(bodies.into()),
+                calls: Mutex::new(0),
+            })
+        }
+
+        fn calls(&self) -> usize {
+            *self.calls.lock().unwrap()
+        }
+    }
+
+    #[async_trait]
+    impl HttpClient for ScriptedClient {
+        async fn send_request(&self, _request: SesRequest) -> SesResult<SesResponse> {
+            *self.calls.lock().unwrap() += 1;
+            let mut bodies = self.bodies.lock().unwrap();
+            let body = if bodies.len() > 1 {
+                bodies.pop_front().unwrap()
+            } else {
+                bodies.front().cloned().unwrap()
+            };
+            Ok(SesResponse::new(StatusCode::OK, HashMap::new(), body.to_string().into_bytes()))
+        }
+
+        fn endpoint(&self) -> &str {
+            "https://email.us-east-1.amazonaws.com"
+        }
+
+        fn region(&self) -> &str {
+            "us-east-1"
+        }
+    }
+
+    fn dkim_status(status: &str) -> Value {
+        json!({ "IdentityType": "DOMAIN", "DkimAttributes": { "Status": status } })
+    }
+
+    fn service(client: &Arc<ScriptedClient>) -> IdentityService {
+        IdentityService {
+            http_client: client.clone(),
+        }
+    }
+
+    #[test]
+    fn test_created_identity_dns_records_from_tokens() {
+        let response: CreateEmailIdentityResponse = serde_json::from_value(json!({
+            "IdentityType": "DOMAIN",
+            "VerifiedForSendingStatus": false,
+            "DkimAttributes": { "Status": "PENDING", "Tokens": ["abc123", "def456"] }
+        }))
+        .unwrap();
+
+        let created = CreatedIdentity::from_response("example.com", response);
+
+        assert_eq!(created.dkim_tokens, vec!["abc123", "def456"]);
+        assert_eq!(
+            created.dns_records[0],
+            DkimDnsRecord {
+                name: "abc123._domainkey.example.com".to_string(),
+                record_type: "CNAME".to_string(),
+                value: "abc123.dkim.amazonses.com".to_string(),
+            }
+        );
+        assert_eq!(created.dns_records[1].name, "def456._domainkey.example.com");
+    }
+
+    #[test]
+    fn test_created_identity_uses_domain_of_email_address() {
+        let response: CreateEmailIdentityResponse = serde_json::from_value(json!({
+            "IdentityType": "EMAIL_ADDRESS",
+            "DkimAttributes": { "Tokens": ["abc123"] }
+        }))
+        .unwrap();
+
+        let created = CreatedIdentity::from_response("sender@mail.example.com", response);
+        assert_eq!(created.dns_records[0].name, "abc123._domainkey.mail.example.com");
+
+        let without_dkim: CreateEmailIdentityResponse =
+            serde_json::from_value(json!({ "IdentityType": "EMAIL_ADDRESS" })).unwrap();
+        let created = CreatedIdentity::from_response("sender@example.com", without_dkim);
+        assert!(created.dkim_tokens.is_empty());
+        assert!(created.dns_records.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_dkim_polls_through_temporary_failure() {
+        let client = ScriptedClient::new(vec![
+            dkim_status("PENDING"),
+            dkim_status("TEMPORARY_FAILURE"),
+            dkim_status("SUCCESS"),
+        ]);
+
+        let attributes = service(&client)
+            .wait_for_dkim_verification("example.com", Duration::from_millis(1), Duration::from_secs(5))
+            .await
+            .unwrap();
+
+        assert_eq!(attributes.status, Some(DkimStatus::Success));
+        assert_eq!(client.calls(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_dkim_errors_on_failed() {
+        let client = ScriptedClient::new(vec![dkim_status("PENDING"), dkim_status("FAILED")]);
+
+        let err = service(&client)
+            .wait_for_dkim_verification("example.com", Duration::from_millis(1), Duration::from_secs(5))
+            .await
+            .unwrap_err();
+
+        match err {
+            SesError::DkimVerificationFailed { identity } => assert_eq!(identity, "example.com"),
+            other => panic!("Expected DKIM verification failure, got {:?}", other),
+        }
+        assert_eq!(client.calls(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_dkim_times_out_while_pending() {
+        let client = ScriptedClient::new(vec![dkim_status("PENDING")]);
+
+        let err = service(&client)
+            .wait_for_dkim_verification("example.com", Duration::from_millis(5), Duration::from_millis(20))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, SesError::Timeout { retryable: false, .. }));
+        assert!(client.calls() >= 2);
+    }
+}
@@ -69,6 +69,29 @@ pub enum DkimStatus {
     NotStarted,
 }
 
+/// DNS record that must be published for Easy DKIM verification.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DkimDnsRecord {
+    /// Record name, e.g. `token._domainkey.example.com`.
+    pub name: String,
+    /// Record type; always `CNAME` for Easy DKIM.
+    pub record_type: String,
+    /// Record value, e.g. `token.dkim.amazonses.com`.
+    pub value: String,
+}
+
+impl DkimDnsRecord {
+    /// Build the CNAME record for a DKIM token issued for `domain`.
+    pub fn cname(domain: &str, token: &str) -> Self {
+        Self {
+            name: format!("{}._domainkey.{}", token, domain),
+            record_type: "CNAME".to_string(),
+            value: format!("{}.dkim.amazonses.com", token),
+        }
+    }
+}
+
 /// Origin of DKIM signing attributes.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
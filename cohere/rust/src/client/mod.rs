@@ -1,9 +1,9 @@
 //! Client interface and implementation for the Cohere API.
 
 use crate::auth::{AuthManager, BearerAuthManager};
-use crate::config::CohereConfig;
+use crate::config::{ChatApiVersion, CohereConfig};
 use crate::errors::{CohereError, CohereResult};
-use crate::services::chat::{ChatService, ChatServiceImpl};
+use crate::services::chat::{ChatService, ChatServiceImpl, ChatV2Service, ChatV2ServiceImpl};
 use crate::services::classify::{ClassifyService, ClassifyServiceImpl};
 use crate::services::embed::{EmbedService, EmbedServiceImpl};
 use crate::services::generate::{GenerateService, GenerateServiceImpl};
@@ -11,7 +11,8 @@ use crate::services::models::{ModelsService, ModelsServiceImpl};
 use crate::services::rerank::{RerankService, RerankServiceImpl};
 use crate::services::summarize::{SummarizeService, SummarizeServiceImpl};
 use crate::services::tokenize::{TokenizeService, TokenizeServiceImpl};
-use crate::transport::{HttpTransport, ReqwestTransport};
+use crate::observability::UsageLedger;
+use crate::transport::{HttpTransport, MeteredTransport, ReqwestTransport};
 use std::sync::Arc;
 use url::Url;
 
@@ -29,6 +30,11 @@ pub trait CohereClient: Send + Sync {
     /// Get the chat service
     fn chat(&self) -> Arc<dyn ChatService>;
 
+    /// Get the v2 chat service
+    ///
+    /// Fails unless the client was configured with [`ChatApiVersion::V2`].
+    fn chat_v2(&self) -> CohereResult<Arc<dyn ChatV2Service>>;
+
     /// Get the generate service
     fn generate(&self) -> Arc<dyn GenerateService>;
 
@@ -69,9 +75,11 @@ pub struct CohereClientImpl {
     transport: Arc<dyn HttpTransport>,
     auth_manager: Arc<dyn AuthManager>,
     base_url: Url,
+    usage_ledger: Arc<UsageLedger>,
 
     // Lazily initialized services
     chat_service: once_cell::sync::OnceCell<Arc<dyn ChatService>>,
+    chat_v2_service: once_cell::sync::OnceCell<Arc<dyn ChatV2Service>>,
     generate_service: once_cell::sync::OnceCell<Arc<dyn GenerateService>>,
     embed_service: once_cell::sync::OnceCell<Arc<dyn EmbedService>>,
     rerank_service: once_cell::sync::OnceCell<Arc<dyn RerankService>>,
@@ -96,8 +104,11 @@ impl CohereClientImpl {
         let base_url = Url::parse(&config.base_url)?;
         let config = Arc::new(config);
 
-        let transport =
-            Arc::new(ReqwestTransport::new(config.timeout)?) as Arc<dyn HttpTransport>;
+        let usage_ledger = Arc::new(UsageLedger::new());
+        let transport = Arc::new(MeteredTransport::new(
+            Arc::new(ReqwestTransport::new(config.timeout)?),
+            usage_ledger.clone(),
+        )) as Arc<dyn HttpTransport>;
 
         let auth_manager = Arc::new(BearerAuthManager::with_options(
             config.api_key.clone(),
@@ -117,7 +128,9 @@ impl CohereClientImpl {
             transport,
             auth_manager,
             base_url,
+            usage_ledger,
             chat_service: once_cell::sync::OnceCell::new(),
+            chat_v2_service: once_cell::sync::OnceCell::new(),
             generate_service: once_cell::sync::OnceCell::new(),
             embed_service: once_cell::sync::OnceCell::new(),
             rerank_service: once_cell::sync::OnceCell::new(),
@@ -142,13 +155,17 @@ impl CohereClientImpl {
         auth_manager: Arc<dyn AuthManager>,
     ) -> CohereResult<Self> {
         let base_url = Url::parse(&config.base_url)?;
+        let usage_ledger = Arc::new(UsageLedger::new());
+        let transport = Arc::new(MeteredTransport::new(transport, usage_ledger.clone()));
 
         Ok(Self {
             config: Arc::new(config),
             transport,
             auth_manager,
             base_url,
+            usage_ledger,
             chat_service: once_cell::sync::OnceCell::new(),
+            chat_v2_service: once_cell::sync::OnceCell::new(),
             generate_service: once_cell::sync::OnceCell::new(),
             embed_service: once_cell::sync::OnceCell::new(),
             rerank_service: once_cell::sync::OnceCell::new(),
@@ -184,6 +201,11 @@ impl CohereClientImpl {
     pub fn base_url(&self) -> &Url {
         &self.base_url
     }
+
+    /// Get the ledger of billed units for calls made through this client
+    pub fn usage_ledger(&self) -> Arc<UsageLedger> {
+        self.usage_ledger.clone()
+    }
 }
 
 impl CohereClient for CohereClientImpl {
@@ -199,6 +221,25 @@ impl CohereClient for CohereClientImpl {
             .clone()
     }
 
+    fn chat_v2(&self) -> CohereResult<Arc<dyn ChatV2Service>> {
+        if self.config.chat_api_version != ChatApiVersion::V2 {
+            return Err(CohereError::Configuration {
+                message: "The v2 Chat API requires chat_api_version to be set to V2".to_string(),
+            });
+        }
+
+        Ok(self
+            .chat_v2_service
+            .get_or_init(|| {
+                Arc::new(ChatV2ServiceImpl::new(
+                    self.transport.clone(),
+                    self.auth_manager.clone(),
+                    self.base_url.clone(),
+                )) as Arc<dyn ChatV2Service>
+            })
+            .clone())
+    }
+
     fn generate(&self) -> Arc<dyn GenerateService> {
         self.generate_service
             .get_or_init(|| {
@@ -378,4 +419,76 @@ mod tests {
         // They should be the same Arc pointer
         assert!(Arc::ptr_eq(&chat1, &chat2));
     }
+
+    #[tokio::test]
+    async fn test_usage_ledger_aggregates_calls() {
+        use crate::mocks::{MockAuthManager, MockHttpTransport, MockResponse};
+        use crate::services::chat::ChatRequest;
+        use crate::services::rerank::RerankRequest;
+
+        let transport = Arc::new(MockHttpTransport::new());
+        transport.add_response(MockResponse::json(&serde_json::json!({
+            "text": "Hi",
+            "meta": {"billed_units": {"input_tokens": 10, "output_tokens": 20}}
+        })));
+        transport.add_response(MockResponse::json(&serde_json::json!({
+            "results": [],
+            "meta": {"billed_units": {"search_units": 1.0}}
+        })));
+        // No meta at all
+        transport.add_response(MockResponse::json(&serde_json::json!({"text": "Bye"})));
+
+        let config = CohereConfig::builder()
+            .api_key(SecretString::new("test-api-key-12345678".to_string()))
+            .build()
+            .unwrap();
+        let client = CohereClientImpl::with_dependencies(
+            config,
+            transport,
+            Arc::new(MockAuthManager::new()),
+        )
+        .unwrap();
+
+        client.chat().chat(ChatRequest::new("Hello")).await.unwrap();
+        client
+            .rerank()
+            .rerank(RerankRequest::new("query", vec!["doc".into()]))
+            .await
+            .unwrap();
+        client.chat().chat(ChatRequest::new("Goodbye")).await.unwrap();
+
+        let snapshot = client.usage_ledger().snapshot();
+        assert_eq!(snapshot.total.calls, 3);
+        assert_eq!(snapshot.total.input_tokens, 10);
+        assert_eq!(snapshot.total.output_tokens, 20);
+        assert_eq!(snapshot.total.search_units, 1.0);
+
+        let chat = &snapshot.by_endpoint["/v1/chat"];
+        assert_eq!(chat.calls, 2);
+        assert_eq!(chat.input_tokens, 10);
+        assert_eq!(snapshot.by_endpoint["/v1/rerank"].calls, 1);
+    }
+
+    #[test]
+    fn test_chat_v2_gated_by_config() {
+        let config = CohereConfig::builder()
+            .api_key(SecretString::new("test-api-key-12345678".to_string()))
+            .build()
+            .unwrap();
+        let client = create_client(config).unwrap();
+        assert!(matches!(
+            client.chat_v2(),
+            Err(CohereError::Configuration { .. })
+        ));
+
+        let config = CohereConfig::builder()
+            .api_key(SecretString::new("test-api-key-12345678".to_string()))
+            .chat_api_version(ChatApiVersion::V2)
+            .build()
+            .unwrap();
+        let client = create_client(config).unwrap();
+        let chat1 = client.chat_v2().unwrap();
+        let chat2 = client.chat_v2().unwrap();
+        assert!(Arc::ptr_eq(&chat1, &chat2));
+    }
 }
@@ -5,6 +5,30 @@ use crate::{DEFAULT_API_VERSION, DEFAULT_BASE_URL, DEFAULT_MAX_RETRIES, DEFAULT_
 use secrecy::SecretString;
 use std::time::Duration;
 
+/// Version of the Chat API to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChatApiVersion {
+    /// `/v1/chat` with `message` and `chat_history`
+    #[default]
+    V1,
+    /// `/v2/chat` with a `messages` array
+    V2,
+}
+
+impl std::str::FromStr for ChatApiVersion {
+    type Err = CohereError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "v1" | "1" => Ok(Self::V1),
+            "v2" | "2" => Ok(Self::V2),
+            other => Err(CohereError::Configuration {
+                message: format!("Unknown chat API version: {}", other),
+            }),
+        }
+    }
+}
+
 /// Configuration for the Cohere API client.
 #[derive(Clone)]
 pub struct CohereConfig {
@@ -14,6 +38,8 @@ pub struct CohereConfig {
     pub base_url: String,
     /// API version to use (for version headers)
     pub api_version: String,
+    /// Chat API version; the v2 chat service is only available with `V2`
+    pub chat_api_version: ChatApiVersion,
     /// Request timeout
     pub timeout: Duration,
     /// Maximum number of retry attempts
@@ -30,6 +56,7 @@ impl std::fmt::Debug for CohereConfig {
             .field("api_key", &"[REDACTED]")
             .field("base_url", &self.base_url)
             .field("api_version", &self.api_version)
+            .field("chat_api_version", &self.chat_api_version)
             .field("timeout", &self.timeout)
             .field("max_retries", &self.max_retries)
             .field("client_name", &self.client_name)
@@ -50,6 +77,7 @@ impl CohereConfig {
     /// - `COHERE_API_KEY` (required): API key for authentication
     /// - `COHERE_BASE_URL` (optional): Base URL (defaults to https://api.cohere.ai)
     /// - `COHERE_API_VERSION` (optional): API version header
+    /// - `COHERE_CHAT_API_VERSION` (optional): Chat API version (`v1` or `v2`)
     /// - `COHERE_TIMEOUT` (optional): Timeout in seconds
     /// - `COHERE_MAX_RETRIES` (optional): Maximum retry attempts
     pub fn from_env() -> CohereResult<Self> {
@@ -63,6 +91,11 @@ impl CohereConfig {
         let api_version = std::env::var("COHERE_API_VERSION")
             .unwrap_or_else(|_| DEFAULT_API_VERSION.to_string());
 
+        let chat_api_version = match std::env::var("COHERE_CHAT_API_VERSION") {
+            Ok(version) => version.parse()?,
+            Err(_) => ChatApiVersion::default(),
+        };
+
         let timeout_secs = std::env::var("COHERE_TIMEOUT")
             .ok()
             .and_then(|s| s.parse().ok())
@@ -79,6 +112,7 @@ impl CohereConfig {
             api_key: SecretString::new(api_key),
             base_url,
             api_version,
+            chat_api_version,
             timeout: Duration::from_secs(timeout_secs),
             max_retries,
             client_name,
@@ -127,6 +161,7 @@ pub struct CohereConfigBuilder {
     api_key: Option<SecretString>,
     base_url: Option<String>,
     api_version: Option<String>,
+    chat_api_version: Option<ChatApiVersion>,
     timeout: Option<Duration>,
     max_retries: Option<u32>,
     client_name: Option<String>,
@@ -152,6 +187,12 @@ impl CohereConfigBuilder {
         self
     }
 
+    /// Sets the Chat API version
+    pub fn chat_api_version(mut self, version: ChatApiVersion) -> Self {
+        self.chat_api_version = Some(version);
+        self
+    }
+
     /// Sets the request timeout
     pub fn timeout(mut self, timeout: Duration) -> Self {
         self.timeout = Some(timeout);
@@ -194,6 +235,7 @@ impl CohereConfigBuilder {
             api_version: self
                 .api_version
                 .unwrap_or_else(|| DEFAULT_API_VERSION.to_string()),
+            chat_api_version: self.chat_api_version.unwrap_or_default(),
             timeout: self
                 .timeout
                 .unwrap_or(Duration::from_secs(DEFAULT_TIMEOUT_SECS)),
@@ -222,6 +264,7 @@ mod tests {
 
         assert_eq!(config.base_url, DEFAULT_BASE_URL);
         assert_eq!(config.api_version, DEFAULT_API_VERSION);
+        assert_eq!(config.chat_api_version, ChatApiVersion::V1);
         assert_eq!(config.timeout, Duration::from_secs(DEFAULT_TIMEOUT_SECS));
         assert_eq!(config.max_retries, DEFAULT_MAX_RETRIES);
     }
@@ -245,6 +288,13 @@ mod tests {
         assert_eq!(config.client_name, Some("my-app".to_string()));
     }
 
+    #[test]
+    fn test_chat_api_version_parse() {
+        assert_eq!("v2".parse::<ChatApiVersion>().unwrap(), ChatApiVersion::V2);
+        assert_eq!("V1".parse::<ChatApiVersion>().unwrap(), ChatApiVersion::V1);
+        assert!("v3".parse::<ChatApiVersion>().is_err());
+    }
+
     #[test]
     fn test_config_builder_missing_api_key() {
         let result = CohereConfig::builder().build();
@@ -277,6 +327,7 @@ mod tests {
             api_key: SecretString::new("test".to_string()),
             base_url: "not-a-valid-url".to_string(),
             api_version: DEFAULT_API_VERSION.to_string(),
+            chat_api_version: ChatApiVersion::V1,
             timeout: Duration::from_secs(30),
             max_retries: 3,
             client_name: None,
@@ -77,6 +77,22 @@ pub enum CohereError {
         message: String,
     },
 
+    /// Operation did not complete before its deadline
+    #[error("Timeout: {message}")]
+    Timeout {
+        /// Error message describing what timed out
+        message: String,
+    },
+
+    /// Asynchronous job finished without succeeding
+    #[error("Job {job_id} failed: {reason}")]
+    JobFailed {
+        /// ID of the failed job
+        job_id: String,
+        /// Failure reason reported by the API
+        reason: String,
+    },
+
     /// API error (structured error from Cohere API)
     #[error("API error ({status}): {message}")]
     Api {
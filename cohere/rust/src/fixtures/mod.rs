@@ -28,6 +28,46 @@ pub fn chat_response() -> ChatResponse {
     }
 }
 
+/// Create a sample connector-grounded chat response body
+///
+/// The second citation overlaps the first and also references a document
+/// the connector did not return.
+pub fn connector_chat_response_json() -> serde_json::Value {
+    serde_json::json!({
+        "text": "Rust 1.0 was released in May 2015 by the Rust team.",
+        "generation_id": "gen-rag-123",
+        "finish_reason": "COMPLETE",
+        "citations": [
+            {
+                "start": 0,
+                "end": 33,
+                "text": "Rust 1.0 was released in May 2015",
+                "document_ids": ["web-search_0"]
+            },
+            {
+                "start": 25,
+                "end": 51,
+                "text": "May 2015 by the Rust team",
+                "document_ids": ["web-search_1", "web-search_9"]
+            }
+        ],
+        "documents": [
+            {
+                "id": "web-search_0",
+                "snippet": "Rust 1.0, the first stable release, shipped on May 15, 2015.",
+                "title": "Announcing Rust 1.0",
+                "url": "https://blog.rust-lang.org/2015/05/15/Rust-1.0.html"
+            },
+            {
+                "id": "web-search_1",
+                "snippet": "The Rust core team announced the release in May 2015.",
+                "title": "Rust (programming language)",
+                "url": "https://en.wikipedia.org/wiki/Rust_(programming_language)"
+            }
+        ]
+    })
+}
+
 /// Create a sample generate response
 pub fn generate_response() -> GenerateResponse {
     GenerateResponse {
@@ -175,6 +215,78 @@ data: {"event_type": "stream-end", "finish_reason": "COMPLETE"}
     ]
 }
 
+/// Create sample SSE data for a v2 chat turn that calls two tools
+pub fn sse_chat_v2_stream_data() -> Vec<String> {
+    vec![
+        r#"event: message-start
+data: {"type": "message-start", "id": "resp-2", "delta": {"message": {"role": "assistant"}}}
+
+"#.to_string(),
+        r#"event: tool-plan-delta
+data: {"type": "tool-plan-delta", "delta": {"message": {"tool_plan": "I will look up the weather"}}}
+
+"#.to_string(),
+        r#"event: tool-plan-delta
+data: {"type": "tool-plan-delta", "delta": {"message": {"tool_plan": " in both cities."}}}
+
+"#.to_string(),
+        r#"event: tool-call-start
+data: {"type": "tool-call-start", "index": 0, "delta": {"message": {"tool_calls": {"id": "call_a", "type": "function", "function": {"name": "get_weather", "arguments": ""}}}}}
+
+"#.to_string(),
+        r#"event: tool-call-delta
+data: {"type": "tool-call-delta", "index": 0, "delta": {"message": {"tool_calls": {"function": {"arguments": "{\"location\":"}}}}}
+
+"#.to_string(),
+        r#"event: tool-call-delta
+data: {"type": "tool-call-delta", "index": 0, "delta": {"message": {"tool_calls": {"function": {"arguments": " \"Toronto\"}"}}}}}
+
+"#.to_string(),
+        r#"event: tool-call-end
+data: {"type": "tool-call-end", "index": 0}
+
+"#.to_string(),
+        r#"event: tool-call-start
+data: {"type": "tool-call-start", "index": 1, "delta": {"message": {"tool_calls": {"id": "call_b", "type": "function", "function": {"name": "get_weather", "arguments": "{\"location\": \"Paris\"}"}}}}}
+
+"#.to_string(),
+        r#"event: tool-call-end
+data: {"type": "tool-call-end", "index": 1}
+
+"#.to_string(),
+        r#"event: message-end
+data: {"type": "message-end", "delta": {"finish_reason": "TOOL_CALL", "usage": {"billed_units": {"input_tokens": 30, "output_tokens": 25}}}}
+
+"#.to_string(),
+    ]
+}
+
+/// Create sample SSE data for summarize streaming tests
+pub fn sse_summarize_stream_data() -> Vec<String> {
+    vec![
+        r#"event: stream-start
+data: {"event_type": "stream-start", "id": "sum-stream-123"}
+
+"#.to_string(),
+        r#"event: text-generation
+data: {"event_type": "text-generation", "text": "- Rust is a systems language"}
+
+"#.to_string(),
+        r#"event: text-generation
+data: {"event_type": "text-generation", "text": "\n- It guarantees memory safety"}
+
+"#.to_string(),
+        r#"event: text-generation
+data: {"event_type": "text-generation", "text": "\n- It has no garbage collector"}
+
+"#.to_string(),
+        r#"event: stream-end
+data: {"event_type": "stream-end", "finish_reason": "COMPLETE", "response": {"id": "sum-stream-123", "meta": {"billed_units": {"input_tokens": 320, "output_tokens": 24}}}}
+
+"#.to_string(),
+    ]
+}
+
 /// Create sample SSE data for generate streaming tests
 pub fn sse_generate_stream_data() -> Vec<String> {
     vec![
@@ -71,11 +71,11 @@ pub mod fixtures;
 // Re-exports for convenience
 pub use auth::{AuthManager, BearerAuthManager};
 pub use client::{create_client, create_client_from_env, CohereClient, CohereClientImpl};
-pub use config::{CohereConfig, CohereConfigBuilder};
+pub use config::{ChatApiVersion, CohereConfig, CohereConfigBuilder};
 pub use errors::{CohereError, CohereResult};
 pub use observability::{
     DefaultTracer, InMemoryMetricsCollector, LogFormat, LogLevel, LoggingConfig, MetricsCollector,
-    NoopMetricsCollector, NoopTracer, RequestSpan, SpanStatus, Tracer,
+    NoopMetricsCollector, NoopTracer, RequestSpan, SpanStatus, Tracer, UsageLedger, UsageSnapshot,
 };
 pub use resilience::{
     CircuitBreaker, CircuitBreakerConfig, CircuitState, DefaultResilienceOrchestrator,
@@ -88,9 +88,10 @@ pub use types::{
 
 // Service re-exports
 pub use services::chat::{
-    ChatMessage, ChatRequest, ChatResponse, ChatService, ChatServiceImpl, ChatStream,
-    ChatStreamEvent, Citation, Connector as ChatConnector, Document, SearchQuery, SearchResult,
-    Tool, ToolCall, ToolResult,
+    AnnotatedCitation, ChatMessage, ChatRequest, ChatResponse, ChatService, ChatServiceImpl,
+    ChatStream, ChatStreamEvent, ChatV2Request, ChatV2Response, ChatV2Service, ChatV2ServiceImpl,
+    ChatV2Stream, ChatV2StreamEvent, Citation, Connector as ChatConnector, Document,
+    GroundedChatResponse, SearchQuery, SearchResult, Tool, ToolCall, ToolResult,
 };
 pub use services::generate::{
     GenerateRequest, GenerateResponse, GenerateService, GenerateServiceImpl, GenerateStream,
@@ -109,7 +110,8 @@ pub use services::classify::{
 };
 pub use services::summarize::{
     SummarizeExtractiveness, SummarizeFormat, SummarizeLength, SummarizeRequest,
-    SummarizeResponse, SummarizeService, SummarizeServiceImpl,
+    SummarizeResponse, SummarizeService, SummarizeServiceImpl, SummarizeStream,
+    SummarizeStreamEvent,
 };
 pub use services::tokenize::{
     DetokenizeRequest, DetokenizeResponse, TokenizeRequest, TokenizeResponse, TokenizeService,
@@ -51,6 +51,17 @@ impl MockResponse {
         }
     }
 
+    /// Create a successful SSE response from raw event blocks
+    pub fn sse(events: &[String]) -> Self {
+        let mut headers = HeaderMap::new();
+        headers.insert("content-type", "text/event-stream".parse().unwrap());
+        Self {
+            status: 200,
+            body: events.concat().into_bytes(),
+            headers,
+        }
+    }
+
     /// Create an error response
     pub fn error(status: u16, message: &str) -> Self {
         let body = serde_json::json!({
@@ -149,10 +160,14 @@ impl HttpTransport for MockHttpTransport {
         body: Option<Bytes>,
     ) -> CohereResult<std::pin::Pin<Box<dyn futures::Stream<Item = CohereResult<Bytes>> + Send>>>
     {
-        // For streaming, we just return an empty stream in the mock
-        // A more sophisticated mock could return test SSE data
-        let _ = self.send(method, url, headers, body).await?;
-        Ok(Box::pin(futures::stream::empty()))
+        // The configured body is replayed as the stream, one line per chunk
+        let response = self.send(method, url, headers, body).await?;
+        let chunks: Vec<CohereResult<Bytes>> = response
+            .body
+            .split_inclusive(|&b| b == b'\n')
+            .map(|line| Ok(Bytes::copy_from_slice(line)))
+            .collect();
+        Ok(Box::pin(futures::stream::iter(chunks)))
     }
 }
 
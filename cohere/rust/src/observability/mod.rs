@@ -3,9 +3,11 @@
 mod logging;
 mod metrics;
 mod tracing_impl;
+mod usage;
 
 pub use logging::{LogFormat, LogLevel, LoggingConfig, StructuredLogger};
 pub use metrics::{
     Counter, Gauge, Histogram, InMemoryMetricsCollector, MetricsCollector, NoopMetricsCollector,
 };
 pub use tracing_impl::{DefaultTracer, NoopTracer, RequestSpan, SpanStatus, Tracer};
+pub use usage::{EndpointUsage, UsageLedger, UsageSnapshot};
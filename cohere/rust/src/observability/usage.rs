@@ -0,0 +1,142 @@
+//! Per-endpoint accounting of billed units.
+
+use crate::types::BilledUnits;
+use parking_lot::Mutex;
+use std::collections::{BTreeMap, HashMap};
+
+/// Billed units accumulated for one endpoint (or across all of them)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EndpointUsage {
+    /// Number of calls recorded
+    pub calls: u64,
+    /// Input tokens billed
+    pub input_tokens: u64,
+    /// Output tokens billed
+    pub output_tokens: u64,
+    /// Search units billed
+    pub search_units: f64,
+    /// Classification units billed
+    pub classifications: u64,
+}
+
+impl EndpointUsage {
+    fn add_call(&mut self, units: Option<&BilledUnits>) {
+        self.calls += 1;
+        if let Some(units) = units {
+            self.input_tokens += units.input_tokens;
+            self.output_tokens += units.output_tokens;
+            self.search_units += units.search_units.unwrap_or_default();
+            self.classifications += units.classifications.unwrap_or_default();
+        }
+    }
+
+    fn merge(&mut self, other: &EndpointUsage) {
+        self.calls += other.calls;
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.search_units += other.search_units;
+        self.classifications += other.classifications;
+    }
+}
+
+/// Point-in-time copy of a [`UsageLedger`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UsageSnapshot {
+    /// Usage keyed by endpoint path (e.g. `/v1/chat`)
+    pub by_endpoint: BTreeMap<String, EndpointUsage>,
+    /// Usage summed over all endpoints
+    pub total: EndpointUsage,
+}
+
+/// Thread-safe ledger of billed units per endpoint
+///
+/// Calls whose response carries no `BilledUnits` are still counted, with
+/// zero units.
+#[derive(Debug, Default)]
+pub struct UsageLedger {
+    entries: Mutex<HashMap<String, EndpointUsage>>,
+}
+
+impl UsageLedger {
+    /// Create an empty ledger
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one call to `endpoint`
+    pub fn record(&self, endpoint: &str, units: Option<&BilledUnits>) {
+        self.entries
+            .lock()
+            .entry(endpoint.to_string())
+            .or_default()
+            .add_call(units);
+    }
+
+    /// Totals recorded so far
+    pub fn snapshot(&self) -> UsageSnapshot {
+        let entries = self.entries.lock();
+        let mut snapshot = UsageSnapshot::default();
+        for (endpoint, usage) in entries.iter() {
+            snapshot.total.merge(usage);
+            snapshot.by_endpoint.insert(endpoint.clone(), usage.clone());
+        }
+        snapshot
+    }
+
+    /// Clear all recorded usage
+    pub fn reset(&self) {
+        self.entries.lock().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_ledger_counts_missing_units_as_zero() {
+        let ledger = UsageLedger::new();
+        ledger.record("/v1/chat", None);
+
+        let snapshot = ledger.snapshot();
+        assert_eq!(snapshot.total.calls, 1);
+        assert_eq!(snapshot.total.input_tokens, 0);
+        assert_eq!(snapshot.by_endpoint["/v1/chat"].calls, 1);
+    }
+
+    #[test]
+    fn test_ledger_concurrent_records() {
+        let ledger = Arc::new(UsageLedger::new());
+        let units = BilledUnits {
+            input_tokens: 2,
+            output_tokens: 3,
+            search_units: Some(1.0),
+            classifications: None,
+        };
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let ledger = ledger.clone();
+                let units = units.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..100 {
+                        ledger.record("/v1/rerank", Some(&units));
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let total = ledger.snapshot().total;
+        assert_eq!(total.calls, 800);
+        assert_eq!(total.input_tokens, 1600);
+        assert_eq!(total.output_tokens, 2400);
+        assert_eq!(total.search_units, 800.0);
+
+        ledger.reset();
+        assert_eq!(ledger.snapshot(), UsageSnapshot::default());
+    }
+}
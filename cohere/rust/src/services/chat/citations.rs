@@ -0,0 +1,88 @@
+//! Citation mapping for grounded chat responses.
+
+use super::types::{ChatResponse, Citation, Document};
+
+/// A citation together with the documents it references
+#[derive(Debug, Clone)]
+pub struct AnnotatedCitation {
+    /// The citation as returned by the API
+    pub citation: Citation,
+    /// Referenced documents found in the response
+    pub documents: Vec<Document>,
+    /// Referenced document IDs with no matching document in the response
+    pub unresolved_document_ids: Vec<String>,
+}
+
+impl AnnotatedCitation {
+    /// Whether this citation's span covers the given character offset
+    pub fn covers(&self, offset: u32) -> bool {
+        self.citation.start <= offset && offset < self.citation.end
+    }
+}
+
+/// A chat response with its citations mapped to source documents
+#[derive(Debug, Clone)]
+pub struct GroundedChatResponse {
+    /// The underlying chat response
+    pub response: ChatResponse,
+    /// Citations ordered by span start, then span end
+    pub citations: Vec<AnnotatedCitation>,
+}
+
+impl GroundedChatResponse {
+    /// Map each citation in a response to the documents it references
+    ///
+    /// Overlapping citations are kept as separate entries. Document IDs that
+    /// do not appear in the response's documents, such as connector results
+    /// the API did not return in full, are listed as unresolved rather than
+    /// dropped.
+    pub fn from_response(response: ChatResponse) -> Self {
+        let documents = response.documents.as_deref().unwrap_or_default();
+
+        let mut citations: Vec<AnnotatedCitation> = response
+            .citations
+            .iter()
+            .flatten()
+            .map(|citation| {
+                let mut resolved = Vec::new();
+                let mut unresolved = Vec::new();
+                for id in &citation.document_ids {
+                    match documents.iter().find(|doc| doc.id.as_deref() == Some(id.as_str())) {
+                        Some(doc) => resolved.push(doc.clone()),
+                        None => unresolved.push(id.clone()),
+                    }
+                }
+                AnnotatedCitation {
+                    citation: citation.clone(),
+                    documents: resolved,
+                    unresolved_document_ids: unresolved,
+                }
+            })
+            .collect();
+        citations.sort_by_key(|c| (c.citation.start, c.citation.end));
+
+        Self { response, citations }
+    }
+
+    /// The generated text
+    pub fn text(&self) -> &str {
+        &self.response.text
+    }
+
+    /// All citations whose span covers the given character offset
+    pub fn citations_at(&self, offset: u32) -> Vec<&AnnotatedCitation> {
+        self.citations.iter().filter(|c| c.covers(offset)).collect()
+    }
+
+    /// Cited documents in order of first citation, without duplicates
+    pub fn cited_documents(&self) -> Vec<&Document> {
+        let mut seen: Vec<&Document> = Vec::new();
+        for doc in self.citations.iter().flat_map(|c| &c.documents) {
+            if !seen.iter().any(|s| s.id == doc.id) {
+                seen.push(doc);
+            }
+        }
+        seen
+    }
+}
+
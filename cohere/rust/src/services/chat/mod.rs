@@ -5,16 +5,27 @@
 //! - Streaming responses
 //! - Tool use and function calling
 //! - RAG (Retrieval Augmented Generation)
+//!
+//! Both the v1 (`/v1/chat`) and v2 (`/v2/chat`) Chat APIs are supported; the
+//! v2 service lives in [`v2`] and is selected through
+//! [`ChatApiVersion`](crate::config::ChatApiVersion).
 
+mod citations;
 mod service;
 mod stream;
 mod types;
 mod validation;
+pub mod v2;
 
+pub use citations::{AnnotatedCitation, GroundedChatResponse};
 pub use service::{ChatService, ChatServiceImpl};
 pub use stream::{ChatStream, ChatStreamEvent};
 pub use types::{
     ChatMessage, ChatRequest, ChatRequestBuilder, ChatResponse, Citation, Connector, Document,
     MessageRole, SearchQuery, SearchResult, Tool, ToolCall, ToolResult,
 };
-pub use validation::validate_chat_request;
+pub use v2::{
+    ChatV2Content, ChatV2Message, ChatV2Request, ChatV2RequestBuilder, ChatV2Response,
+    ChatV2Service, ChatV2ServiceImpl, ChatV2Stream, ChatV2StreamEvent, ChatV2Usage,
+};
+pub use validation::{validate_chat_request, validate_chat_v2_request};
@@ -1,10 +1,11 @@
 //! Chat service implementation.
 
+use super::citations::GroundedChatResponse;
 use super::stream::ChatStream;
-use super::types::{ChatRequest, ChatResponse};
+use super::types::{ChatRequest, ChatResponse, Connector};
 use super::validation::validate_chat_request;
 use crate::auth::AuthManager;
-use crate::errors::{CohereError, CohereResult};
+use crate::errors::{CohereError, CohereResult, ValidationDetail};
 use crate::transport::HttpTransport;
 use async_trait::async_trait;
 use http::{HeaderMap, Method};
@@ -19,6 +20,30 @@ pub trait ChatService: Send + Sync {
 
     /// Send a chat message and get a streaming response
     async fn chat_stream(&self, request: ChatRequest) -> CohereResult<ChatStream>;
+
+    /// Send a chat message grounded by connectors and map each citation to
+    /// the documents it references
+    async fn chat_with_connectors(
+        &self,
+        message: &str,
+        connectors: Vec<Connector>,
+    ) -> CohereResult<GroundedChatResponse> {
+        if connectors.is_empty() {
+            return Err(CohereError::Validation {
+                message: "At least one connector is required".to_string(),
+                details: vec![ValidationDetail {
+                    field: "connectors".to_string(),
+                    message: "must not be empty".to_string(),
+                    value: None,
+                }],
+            });
+        }
+
+        let request = ChatRequest::builder(message).connectors(connectors).build();
+        let response = self.chat(request).await?;
+
+        Ok(GroundedChatResponse::from_response(response))
+    }
 }
 
 /// Implementation of the Chat service
@@ -130,7 +130,11 @@ pub struct Document {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     /// Document text content
+    #[serde(default)]
     pub text: String,
+    /// Excerpt returned for connector-fetched documents
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
     /// Document title
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
@@ -145,6 +149,7 @@ impl Document {
         Self {
             id: None,
             text: text.into(),
+            snippet: None,
             title: None,
             url: None,
         }
@@ -220,7 +225,7 @@ pub struct SearchResult {
 }
 
 /// Citation in a response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Citation {
     /// Start position in the text
     pub start: u32,
@@ -0,0 +1,18 @@
+//! Chat service for the v2 Chat API.
+//!
+//! The v2 endpoint (`/v2/chat`) replaces v1's `message` + `chat_history`
+//! pair with a single `messages` array and streams content, tool plans and
+//! tool calls as separate `content-*` / `tool-call-*` events. Requests and
+//! responses are expressed with the same [`ChatMessage`](super::ChatMessage),
+//! [`Tool`](super::Tool) and [`ToolCall`](super::ToolCall) types as v1, so a
+//! conversation can move between the two API versions unchanged.
+
+mod service;
+mod stream;
+mod types;
+
+pub use service::{ChatV2Service, ChatV2ServiceImpl};
+pub use stream::{ChatV2Stream, ChatV2StreamEvent};
+pub use types::{
+    ChatV2Content, ChatV2Message, ChatV2Request, ChatV2RequestBuilder, ChatV2Response, ChatV2Usage,
+};
@@ -0,0 +1,254 @@
+//! v2 Chat service implementation.
+
+use super::stream::ChatV2Stream;
+use super::types::{ChatV2Request, ChatV2Response};
+use crate::auth::AuthManager;
+use crate::errors::{CohereError, CohereResult};
+use crate::services::chat::validation::validate_chat_v2_request;
+use crate::transport::HttpTransport;
+use async_trait::async_trait;
+use http::{HeaderMap, Method};
+use std::sync::Arc;
+use url::Url;
+
+/// v2 Chat service trait for testability
+#[async_trait]
+pub trait ChatV2Service: Send + Sync {
+    /// Send a conversation and get a response
+    async fn chat(&self, request: ChatV2Request) -> CohereResult<ChatV2Response>;
+
+    /// Send a conversation and get a streaming response
+    async fn chat_stream(&self, request: ChatV2Request) -> CohereResult<ChatV2Stream>;
+}
+
+/// Implementation of the v2 Chat service
+pub struct ChatV2ServiceImpl {
+    transport: Arc<dyn HttpTransport>,
+    auth_manager: Arc<dyn AuthManager>,
+    base_url: Url,
+}
+
+impl ChatV2ServiceImpl {
+    /// Create a new v2 Chat service
+    pub fn new(
+        transport: Arc<dyn HttpTransport>,
+        auth_manager: Arc<dyn AuthManager>,
+        base_url: Url,
+    ) -> Self {
+        Self {
+            transport,
+            auth_manager,
+            base_url,
+        }
+    }
+
+    /// Build headers for a request
+    fn build_headers(&self) -> HeaderMap {
+        self.auth_manager.get_headers()
+    }
+
+    /// Build the chat endpoint URL
+    fn chat_url(&self) -> CohereResult<String> {
+        self.base_url
+            .join("/v2/chat")
+            .map(|u| u.to_string())
+            .map_err(|e| CohereError::Configuration {
+                message: format!("Invalid URL: {}", e),
+            })
+    }
+}
+
+#[async_trait]
+impl ChatV2Service for ChatV2ServiceImpl {
+    async fn chat(&self, mut request: ChatV2Request) -> CohereResult<ChatV2Response> {
+        validate_chat_v2_request(&request)?;
+
+        request.stream = Some(false);
+
+        let url = self.chat_url()?;
+        let headers = self.build_headers();
+        let body = serde_json::to_vec(&request)?;
+
+        let response = self
+            .transport
+            .execute(Method::POST, url, headers, Some(body))
+            .await?;
+
+        let chat_response: ChatV2Response = serde_json::from_slice(&response.body)?;
+
+        Ok(chat_response)
+    }
+
+    async fn chat_stream(&self, mut request: ChatV2Request) -> CohereResult<ChatV2Stream> {
+        validate_chat_v2_request(&request)?;
+
+        request.stream = Some(true);
+
+        let url = self.chat_url()?;
+        let mut headers = self.build_headers();
+        headers.insert("accept", "text/event-stream".parse().unwrap());
+        let body = serde_json::to_vec(&request)?;
+
+        let stream = self
+            .transport
+            .execute_stream(Method::POST, url, headers, Some(body))
+            .await?;
+
+        Ok(ChatV2Stream::new(stream))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::sse_chat_v2_stream_data;
+    use crate::mocks::{MockClientBuilder, MockResponse};
+    use crate::services::chat::v2::ChatV2StreamEvent;
+    use crate::services::chat::{ChatMessage, Tool, ToolResult};
+    use crate::types::FinishReason;
+    use futures::StreamExt;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_chat_v2_turn() {
+        let (service, transport) = MockClientBuilder::new()
+            .with_response(MockResponse::json(&json!({
+                "id": "resp-1",
+                "finish_reason": "COMPLETE",
+                "message": {
+                    "role": "assistant",
+                    "content": [{"type": "text", "text": "Hello! How can I help?"}]
+                },
+                "usage": {
+                    "billed_units": {"input_tokens": 5, "output_tokens": 6},
+                    "tokens": {"input_tokens": 71, "output_tokens": 6}
+                }
+            })))
+            .build(ChatV2ServiceImpl::new);
+
+        let request = ChatV2Request::new(
+            "command-r-plus",
+            vec![ChatMessage::system("Be friendly."), ChatMessage::user("Hi")],
+        );
+        let response = service.chat(request).await.unwrap();
+
+        assert_eq!(response.id, "resp-1");
+        assert_eq!(response.text(), "Hello! How can I help?");
+        assert_eq!(response.finish_reason, Some(FinishReason::Complete));
+        assert!(response.tool_calls().is_empty());
+        let usage = response.usage.unwrap();
+        assert_eq!(usage.billed_units.unwrap().output_tokens, 6);
+        assert_eq!(usage.tokens.unwrap().input_tokens, 71);
+
+        let requests = transport.get_requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].url.ends_with("/v2/chat"));
+        let body: serde_json::Value =
+            serde_json::from_slice(requests[0].body.as_ref().unwrap()).unwrap();
+        assert_eq!(body["model"], "command-r-plus");
+        assert_eq!(body["stream"], false);
+        assert_eq!(
+            body["messages"],
+            json!([
+                {"role": "system", "content": "Be friendly."},
+                {"role": "user", "content": "Hi"}
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chat_v2_streamed_turn_with_tool_calls() {
+        let (service, transport) = MockClientBuilder::new()
+            .with_response(MockResponse::sse(&sse_chat_v2_stream_data()))
+            .build(ChatV2ServiceImpl::new);
+
+        let request = ChatV2Request::builder(
+            "command-r-plus",
+            vec![ChatMessage::user(
+                "What's the weather in Toronto and Paris?",
+            )],
+        )
+        .tools(vec![Tool::new("get_weather", "Look up the weather")])
+        .build();
+        let mut stream = service.chat_stream(request).await.unwrap();
+
+        let mut events = Vec::new();
+        while let Some(event) = stream.next().await {
+            events.push(event.unwrap());
+        }
+
+        assert!(matches!(
+            events.first(),
+            Some(ChatV2StreamEvent::MessageStart { id: Some(id) }) if id == "resp-2"
+        ));
+        match events.last() {
+            Some(ChatV2StreamEvent::MessageEnd { finish_reason, .. }) => {
+                assert_eq!(*finish_reason, Some(FinishReason::ToolUse));
+            }
+            other => panic!("Expected MessageEnd, got {:?}", other),
+        }
+        let ended: Vec<_> = events
+            .iter()
+            .filter_map(|e| match e {
+                ChatV2StreamEvent::ToolCallEnd { index, tool_call } => Some((*index, tool_call)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(ended.len(), 2);
+        assert_eq!(ended[1].0, 1);
+
+        assert_eq!(
+            stream.tool_plan(),
+            "I will look up the weather in both cities."
+        );
+        let calls = stream.tool_calls();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].id, "call_a");
+        assert_eq!(calls[0].name, "get_weather");
+        assert_eq!(calls[0].parameters, json!({"location": "Toronto"}));
+        assert_eq!(calls[1].parameters, json!({"location": "Paris"}));
+
+        // The streamed calls round-trip into the next v2 request
+        let mut assistant = ChatMessage::chatbot(stream.tool_plan());
+        assistant.tool_calls = Some(calls.to_vec());
+        let results = calls
+            .iter()
+            .map(|call| ToolResult {
+                call: call.clone(),
+                outputs: vec![json!({"temperature": 20})],
+            })
+            .collect();
+        let follow_up = ChatV2Request::new(
+            "command-r-plus",
+            vec![
+                ChatMessage::user("What's the weather in Toronto and Paris?"),
+                assistant,
+                ChatMessage::tool(results),
+            ],
+        );
+        let body = serde_json::to_value(&follow_up).unwrap();
+        assert_eq!(
+            body["messages"][1]["tool_calls"][1]["function"]["arguments"],
+            "{\"location\":\"Paris\"}"
+        );
+        assert_eq!(body["messages"][2]["tool_call_id"], "call_a");
+        assert_eq!(body["messages"][3]["tool_call_id"], "call_b");
+
+        let requests = transport.get_requests();
+        let body: serde_json::Value =
+            serde_json::from_slice(requests[0].body.as_ref().unwrap()).unwrap();
+        assert_eq!(body["stream"], true);
+        assert_eq!(body["tools"][0]["type"], "function");
+    }
+
+    #[tokio::test]
+    async fn test_chat_v2_requires_model() {
+        let (service, transport) = MockClientBuilder::new().build(ChatV2ServiceImpl::new);
+
+        let request = ChatV2Request::new("", vec![ChatMessage::user("Hi")]);
+        let result = service.chat(request).await;
+
+        assert!(matches!(result, Err(CohereError::Validation { .. })));
+        assert!(transport.get_requests().is_empty());
+    }
+}
@@ -0,0 +1,359 @@
+//! Streaming support for the v2 Chat API.
+
+use super::types::{tool_call_from_wire, ChatV2Usage};
+use crate::errors::{CohereError, CohereResult};
+use crate::services::chat::ToolCall;
+use crate::transport::sse::{SseEvent, SseParser};
+use crate::types::FinishReason;
+use bytes::Bytes;
+use futures::stream::Stream;
+use serde::Deserialize;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Events from a v2 chat stream
+#[derive(Debug, Clone)]
+pub enum ChatV2StreamEvent {
+    /// Stream started
+    MessageStart {
+        /// Response ID
+        id: Option<String>,
+    },
+    /// A content item started
+    ContentStart {
+        /// Content index
+        index: usize,
+    },
+    /// Text generated
+    ContentDelta {
+        /// Content index
+        index: usize,
+        /// Generated text chunk
+        text: String,
+    },
+    /// A content item finished
+    ContentEnd {
+        /// Content index
+        index: usize,
+    },
+    /// Part of the model's plan before calling tools
+    ToolPlanDelta {
+        /// Plan text chunk
+        text: String,
+    },
+    /// A tool call started
+    ToolCallStart {
+        /// Tool call index
+        index: usize,
+        /// Tool call ID
+        id: String,
+        /// Name of the tool
+        name: String,
+    },
+    /// A fragment of a tool call's JSON arguments
+    ToolCallDelta {
+        /// Tool call index
+        index: usize,
+        /// Raw arguments fragment
+        arguments: String,
+    },
+    /// A tool call finished
+    ToolCallEnd {
+        /// Tool call index
+        index: usize,
+        /// The assembled tool call
+        tool_call: ToolCall,
+    },
+    /// Stream ended
+    MessageEnd {
+        /// Finish reason
+        finish_reason: Option<FinishReason>,
+        /// Token usage
+        usage: Option<ChatV2Usage>,
+    },
+}
+
+/// Internal streaming event types from the v2 API
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+#[serde(rename_all = "kebab-case")]
+enum StreamEvent {
+    MessageStart {
+        id: Option<String>,
+    },
+    ContentStart {
+        #[serde(default)]
+        index: usize,
+    },
+    ContentDelta {
+        #[serde(default)]
+        index: usize,
+        delta: Delta,
+    },
+    ContentEnd {
+        #[serde(default)]
+        index: usize,
+    },
+    ToolPlanDelta {
+        delta: Delta,
+    },
+    ToolCallStart {
+        #[serde(default)]
+        index: usize,
+        delta: Delta,
+    },
+    ToolCallDelta {
+        #[serde(default)]
+        index: usize,
+        delta: Delta,
+    },
+    ToolCallEnd {
+        #[serde(default)]
+        index: usize,
+    },
+    MessageEnd {
+        #[serde(default)]
+        delta: MessageEndDelta,
+    },
+    /// Citation events and anything added to the API later
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Delta {
+    #[serde(default)]
+    message: DeltaMessage,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct DeltaMessage {
+    #[serde(default)]
+    content: Option<DeltaContent>,
+    #[serde(default)]
+    tool_plan: Option<String>,
+    #[serde(default)]
+    tool_calls: Option<DeltaToolCall>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct DeltaContent {
+    #[serde(default)]
+    text: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct DeltaToolCall {
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    function: DeltaFunction,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct DeltaFunction {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    arguments: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct MessageEndDelta {
+    #[serde(default)]
+    finish_reason: Option<FinishReason>,
+    #[serde(default)]
+    usage: Option<ChatV2Usage>,
+}
+
+/// Tool call whose arguments are still streaming
+#[derive(Debug, Default)]
+struct PendingToolCall {
+    id: String,
+    name: String,
+    arguments: String,
+}
+
+/// A stream of v2 chat events
+pub struct ChatV2Stream {
+    inner: Pin<Box<dyn Stream<Item = CohereResult<Bytes>> + Send>>,
+    parser: SseParser,
+    pending_events: VecDeque<ChatV2StreamEvent>,
+    done: bool,
+    id: Option<String>,
+    accumulated_text: String,
+    tool_plan: String,
+    pending_tool_calls: Vec<Option<PendingToolCall>>,
+    tool_calls: Vec<ToolCall>,
+}
+
+impl ChatV2Stream {
+    /// Create a new v2 chat stream
+    pub fn new(inner: Pin<Box<dyn Stream<Item = CohereResult<Bytes>> + Send>>) -> Self {
+        Self {
+            inner,
+            parser: SseParser::new(),
+            pending_events: VecDeque::new(),
+            done: false,
+            id: None,
+            accumulated_text: String::new(),
+            tool_plan: String::new(),
+            pending_tool_calls: Vec::new(),
+            tool_calls: Vec::new(),
+        }
+    }
+
+    /// Get accumulated text so far
+    pub fn accumulated_text(&self) -> &str {
+        &self.accumulated_text
+    }
+
+    /// Get the accumulated tool plan so far
+    pub fn tool_plan(&self) -> &str {
+        &self.tool_plan
+    }
+
+    /// Get the tool calls completed so far, in the v1 shape
+    pub fn tool_calls(&self) -> &[ToolCall] {
+        &self.tool_calls
+    }
+
+    /// Get the response ID if available
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    fn pending_tool_call(&mut self, index: usize) -> CohereResult<&mut PendingToolCall> {
+        self.pending_tool_calls
+            .get_mut(index)
+            .and_then(Option::as_mut)
+            .ok_or_else(|| CohereError::StreamError {
+                message: format!("Event for unknown tool call {}", index),
+            })
+    }
+
+    /// Parse an SSE event into a v2 chat stream event
+    fn parse_event(&mut self, sse: SseEvent) -> CohereResult<Option<ChatV2StreamEvent>> {
+        if sse.data == "[DONE]" {
+            return Ok(None);
+        }
+
+        let event: StreamEvent =
+            serde_json::from_str(&sse.data).map_err(|e| CohereError::StreamError {
+                message: format!("Failed to parse stream event: {}", e),
+            })?;
+
+        let chat_event = match event {
+            StreamEvent::MessageStart { id } => {
+                self.id = id.clone();
+                ChatV2StreamEvent::MessageStart { id }
+            }
+            StreamEvent::ContentStart { index } => ChatV2StreamEvent::ContentStart { index },
+            StreamEvent::ContentDelta { index, delta } => {
+                let text = delta
+                    .message
+                    .content
+                    .and_then(|c| c.text)
+                    .unwrap_or_default();
+                self.accumulated_text.push_str(&text);
+                ChatV2StreamEvent::ContentDelta { index, text }
+            }
+            StreamEvent::ContentEnd { index } => ChatV2StreamEvent::ContentEnd { index },
+            StreamEvent::ToolPlanDelta { delta } => {
+                let text = delta.message.tool_plan.unwrap_or_default();
+                self.tool_plan.push_str(&text);
+                ChatV2StreamEvent::ToolPlanDelta { text }
+            }
+            StreamEvent::ToolCallStart { index, delta } => {
+                let call = delta.message.tool_calls.unwrap_or_default();
+                let pending = PendingToolCall {
+                    id: call.id.unwrap_or_default(),
+                    name: call.function.name.unwrap_or_default(),
+                    arguments: call.function.arguments.unwrap_or_default(),
+                };
+                let event = ChatV2StreamEvent::ToolCallStart {
+                    index,
+                    id: pending.id.clone(),
+                    name: pending.name.clone(),
+                };
+                if self.pending_tool_calls.len() <= index {
+                    self.pending_tool_calls.resize_with(index + 1, || None);
+                }
+                self.pending_tool_calls[index] = Some(pending);
+                event
+            }
+            StreamEvent::ToolCallDelta { index, delta } => {
+                let arguments = delta
+                    .message
+                    .tool_calls
+                    .and_then(|c| c.function.arguments)
+                    .unwrap_or_default();
+                self.pending_tool_call(index)?
+                    .arguments
+                    .push_str(&arguments);
+                ChatV2StreamEvent::ToolCallDelta { index, arguments }
+            }
+            StreamEvent::ToolCallEnd { index } => {
+                self.pending_tool_call(index)?;
+                let pending = self.pending_tool_calls[index].take().unwrap_or_default();
+                let tool_call = tool_call_from_wire(pending.id, pending.name, &pending.arguments)
+                    .map_err(|e| CohereError::StreamError {
+                    message: format!("Invalid arguments for tool call {}: {}", index, e),
+                })?;
+                self.tool_calls.push(tool_call.clone());
+                ChatV2StreamEvent::ToolCallEnd { index, tool_call }
+            }
+            StreamEvent::MessageEnd { delta } => {
+                self.done = true;
+                ChatV2StreamEvent::MessageEnd {
+                    finish_reason: delta.finish_reason,
+                    usage: delta.usage,
+                }
+            }
+            StreamEvent::Other => return Ok(None),
+        };
+
+        Ok(Some(chat_event))
+    }
+}
+
+impl Stream for ChatV2Stream {
+    type Item = CohereResult<ChatV2StreamEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            // Return pending events first
+            if let Some(event) = self.pending_events.pop_front() {
+                return Poll::Ready(Some(Ok(event)));
+            }
+
+            if self.done {
+                return Poll::Ready(None);
+            }
+
+            match Pin::new(&mut self.inner).poll_next(cx) {
+                Poll::Ready(Some(Ok(bytes))) => {
+                    let events = self.parser.feed(&bytes);
+                    for sse in events {
+                        if let Some(event) = self.parse_event(sse)? {
+                            self.pending_events.push_back(event);
+                        }
+                    }
+                }
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(None) => {
+                    // Stream ended
+                    self.done = true;
+                    if let Some(sse) = self.parser.flush() {
+                        if let Some(event) = self.parse_event(sse)? {
+                            return Poll::Ready(Some(Ok(event)));
+                        }
+                    }
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
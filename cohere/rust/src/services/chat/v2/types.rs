@@ -0,0 +1,664 @@
+//! Types for the v2 Chat API.
+//!
+//! v2 describes tool calls as `{id, type: "function", function: {name,
+//! arguments}}` with the arguments encoded as a JSON string, whereas v1 uses
+//! `{id, name, parameters}` with a JSON object. The request is serialized and
+//! the response deserialized through private wire types so callers only ever
+//! see the v1 shapes.
+
+use crate::services::chat::{ChatMessage, Document, MessageRole, Tool, ToolCall, ToolResult};
+use crate::types::{BilledUnits, FinishReason, Usage};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Chat request for the v2 API
+#[derive(Debug, Clone)]
+pub struct ChatV2Request {
+    /// Model to use (required by v2)
+    pub model: String,
+    /// Conversation so far, oldest first, including the latest user turn
+    pub messages: Vec<ChatMessage>,
+    /// Tools for function calling
+    pub tools: Option<Vec<Tool>>,
+    /// Documents for RAG
+    pub documents: Option<Vec<Document>>,
+    /// Whether to stream the response
+    pub stream: Option<bool>,
+    /// Temperature
+    pub temperature: Option<f32>,
+    /// Maximum tokens to generate
+    pub max_tokens: Option<u32>,
+    /// Top-k sampling
+    pub k: Option<u32>,
+    /// Top-p (nucleus) sampling
+    pub p: Option<f32>,
+    /// Stop sequences
+    pub stop_sequences: Option<Vec<String>>,
+    /// Frequency penalty
+    pub frequency_penalty: Option<f32>,
+    /// Presence penalty
+    pub presence_penalty: Option<f32>,
+    /// Random seed for reproducibility
+    pub seed: Option<u64>,
+}
+
+impl ChatV2Request {
+    /// Create a new v2 chat request
+    pub fn new(model: impl Into<String>, messages: Vec<ChatMessage>) -> Self {
+        Self {
+            model: model.into(),
+            messages,
+            tools: None,
+            documents: None,
+            stream: None,
+            temperature: None,
+            max_tokens: None,
+            k: None,
+            p: None,
+            stop_sequences: None,
+            frequency_penalty: None,
+            presence_penalty: None,
+            seed: None,
+        }
+    }
+
+    /// Create a builder
+    pub fn builder(model: impl Into<String>, messages: Vec<ChatMessage>) -> ChatV2RequestBuilder {
+        ChatV2RequestBuilder::new(model, messages)
+    }
+}
+
+impl Serialize for ChatV2Request {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        WireRequest::from(self).serialize(serializer)
+    }
+}
+
+/// Builder for ChatV2Request
+#[derive(Debug, Clone)]
+pub struct ChatV2RequestBuilder {
+    request: ChatV2Request,
+}
+
+impl ChatV2RequestBuilder {
+    /// Create a new builder
+    pub fn new(model: impl Into<String>, messages: Vec<ChatMessage>) -> Self {
+        Self {
+            request: ChatV2Request::new(model, messages),
+        }
+    }
+
+    /// Append a message to the conversation
+    pub fn message(mut self, message: ChatMessage) -> Self {
+        self.request.messages.push(message);
+        self
+    }
+
+    /// Add tools
+    pub fn tools(mut self, tools: Vec<Tool>) -> Self {
+        self.request.tools = Some(tools);
+        self
+    }
+
+    /// Add documents for RAG
+    pub fn documents(mut self, docs: Vec<Document>) -> Self {
+        self.request.documents = Some(docs);
+        self
+    }
+
+    /// Set temperature
+    pub fn temperature(mut self, temp: f32) -> Self {
+        self.request.temperature = Some(temp);
+        self
+    }
+
+    /// Set max tokens
+    pub fn max_tokens(mut self, max: u32) -> Self {
+        self.request.max_tokens = Some(max);
+        self
+    }
+
+    /// Set top-k
+    pub fn k(mut self, k: u32) -> Self {
+        self.request.k = Some(k);
+        self
+    }
+
+    /// Set top-p
+    pub fn p(mut self, p: f32) -> Self {
+        self.request.p = Some(p);
+        self
+    }
+
+    /// Set stop sequences
+    pub fn stop_sequences(mut self, sequences: Vec<String>) -> Self {
+        self.request.stop_sequences = Some(sequences);
+        self
+    }
+
+    /// Set seed for reproducibility
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.request.seed = Some(seed);
+        self
+    }
+
+    /// Build the request
+    pub fn build(self) -> ChatV2Request {
+        self.request
+    }
+}
+
+/// A content item in a v2 assistant message
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatV2Content {
+    /// Content type (currently always `text`)
+    #[serde(rename = "type")]
+    pub content_type: String,
+    /// Text content
+    #[serde(default)]
+    pub text: Option<String>,
+}
+
+/// Assistant message returned by the v2 API
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChatV2Message {
+    /// Content items
+    #[serde(default)]
+    pub content: Vec<ChatV2Content>,
+    /// The model's plan before calling tools
+    #[serde(default)]
+    pub tool_plan: Option<String>,
+    /// Tool calls, translated to the v1 shape
+    #[serde(default, deserialize_with = "deserialize_tool_calls")]
+    pub tool_calls: Option<Vec<ToolCall>>,
+}
+
+/// Token usage reported by the v2 API
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ChatV2Usage {
+    /// Billed units
+    #[serde(default)]
+    pub billed_units: Option<BilledUnits>,
+    /// Tokens actually processed
+    #[serde(default)]
+    pub tokens: Option<Usage>,
+}
+
+/// Chat response from the v2 API
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChatV2Response {
+    /// Response ID
+    pub id: String,
+    /// Finish reason
+    #[serde(default)]
+    pub finish_reason: Option<FinishReason>,
+    /// The assistant's reply
+    pub message: ChatV2Message,
+    /// Token usage
+    #[serde(default)]
+    pub usage: Option<ChatV2Usage>,
+}
+
+impl ChatV2Response {
+    /// Concatenated text content of the reply
+    pub fn text(&self) -> String {
+        self.message
+            .content
+            .iter()
+            .filter_map(|c| c.text.as_deref())
+            .collect()
+    }
+
+    /// Tool calls requested by the model
+    pub fn tool_calls(&self) -> &[ToolCall] {
+        self.message.tool_calls.as_deref().unwrap_or_default()
+    }
+
+    /// The reply as a chat message, ready to append to the conversation
+    ///
+    /// When the model calls tools the v2 `tool_plan` becomes the message
+    /// text, mirroring how v1 reports it.
+    pub fn to_chat_message(&self) -> ChatMessage {
+        let mut message = if self.tool_calls().is_empty() {
+            ChatMessage::chatbot(self.text())
+        } else {
+            ChatMessage::chatbot(self.message.tool_plan.clone().unwrap_or_default())
+        };
+        message.tool_calls = self.message.tool_calls.clone();
+        message
+    }
+}
+
+/// Convert a v1 tool call to the v2 wire shape
+fn tool_call_to_wire(call: &ToolCall) -> WireToolCall {
+    WireToolCall {
+        id: call.id.clone(),
+        call_type: "function".to_string(),
+        function: WireFunction {
+            name: call.name.clone(),
+            arguments: call.parameters.to_string(),
+        },
+    }
+}
+
+/// Convert a v2 tool call back to the v1 shape
+///
+/// Tools without parameters may stream no arguments at all, which is treated
+/// as an empty object.
+pub(super) fn tool_call_from_wire(
+    id: String,
+    name: String,
+    arguments: &str,
+) -> Result<ToolCall, serde_json::Error> {
+    let parameters = if arguments.trim().is_empty() {
+        serde_json::Value::Object(Default::default())
+    } else {
+        serde_json::from_str(arguments)?
+    };
+    Ok(ToolCall {
+        id,
+        name,
+        parameters,
+    })
+}
+
+fn deserialize_tool_calls<'de, D>(deserializer: D) -> Result<Option<Vec<ToolCall>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let calls: Option<Vec<WireToolCall>> = Option::deserialize(deserializer)?;
+    calls
+        .map(|calls| {
+            calls
+                .into_iter()
+                .map(|c| {
+                    tool_call_from_wire(c.id, c.function.name, &c.function.arguments)
+                        .map_err(serde::de::Error::custom)
+                })
+                .collect()
+        })
+        .transpose()
+}
+
+/// Convert v1 `parameter_definitions` to a JSON schema
+///
+/// v1 describes parameters as `{name: {type, description, required}}`; v2
+/// expects a JSON schema. Values that already look like a schema are passed
+/// through unchanged.
+fn parameters_to_schema(definitions: &serde_json::Value) -> serde_json::Value {
+    let Some(map) = definitions.as_object() else {
+        return definitions.clone();
+    };
+    if map.contains_key("type") || map.contains_key("$schema") {
+        return definitions.clone();
+    }
+
+    let mut properties = serde_json::Map::new();
+    let mut required = Vec::new();
+    for (name, definition) in map {
+        let mut property = definition.clone();
+        if let Some(property) = property.as_object_mut() {
+            if property.remove("required") == Some(serde_json::Value::Bool(true)) {
+                required.push(serde_json::Value::String(name.clone()));
+            }
+        }
+        properties.insert(name.clone(), property);
+    }
+
+    serde_json::json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    })
+}
+
+#[derive(Serialize)]
+struct WireRequest<'a> {
+    model: &'a str,
+    messages: Vec<WireMessage<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<WireTool<'a>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    documents: Option<Vec<WireDocument<'a>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stream: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    k: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop_sequences: Option<&'a [String]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    frequency_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    presence_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
+}
+
+impl<'a> From<&'a ChatV2Request> for WireRequest<'a> {
+    fn from(request: &'a ChatV2Request) -> Self {
+        Self {
+            model: &request.model,
+            messages: request
+                .messages
+                .iter()
+                .flat_map(WireMessage::from_v1)
+                .collect(),
+            tools: request
+                .tools
+                .as_ref()
+                .map(|tools| tools.iter().map(WireTool::from).collect()),
+            documents: request
+                .documents
+                .as_ref()
+                .map(|docs| docs.iter().map(WireDocument::from).collect()),
+            stream: request.stream,
+            temperature: request.temperature,
+            max_tokens: request.max_tokens,
+            k: request.k,
+            p: request.p,
+            stop_sequences: request.stop_sequences.as_deref(),
+            frequency_penalty: request.frequency_penalty,
+            presence_penalty: request.presence_penalty,
+            seed: request.seed,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "role", rename_all = "lowercase")]
+enum WireMessage<'a> {
+    User {
+        content: &'a str,
+    },
+    Assistant {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        content: Option<&'a str>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tool_plan: Option<&'a str>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tool_calls: Option<Vec<WireToolCall>>,
+    },
+    System {
+        content: &'a str,
+    },
+    Tool {
+        tool_call_id: &'a str,
+        content: Vec<WireToolContent>,
+    },
+}
+
+impl<'a> WireMessage<'a> {
+    /// A v1 message maps to one v2 message, except tool results which v2
+    /// expects as one `tool` message per call.
+    fn from_v1(message: &'a ChatMessage) -> Vec<Self> {
+        match message.role {
+            MessageRole::User => vec![Self::User {
+                content: &message.message,
+            }],
+            MessageRole::System => vec![Self::System {
+                content: &message.message,
+            }],
+            MessageRole::Chatbot => {
+                let tool_calls = message
+                    .tool_calls
+                    .as_ref()
+                    .filter(|calls| !calls.is_empty())
+                    .map(|calls| calls.iter().map(tool_call_to_wire).collect::<Vec<_>>());
+                let text = Some(message.message.as_str()).filter(|m| !m.is_empty());
+                if tool_calls.is_some() {
+                    vec![Self::Assistant {
+                        content: None,
+                        tool_plan: text,
+                        tool_calls,
+                    }]
+                } else {
+                    vec![Self::Assistant {
+                        content: text,
+                        tool_plan: None,
+                        tool_calls: None,
+                    }]
+                }
+            }
+            MessageRole::Tool => message
+                .tool_results
+                .iter()
+                .flatten()
+                .map(Self::from_tool_result)
+                .collect(),
+        }
+    }
+
+    fn from_tool_result(result: &'a ToolResult) -> Self {
+        Self::Tool {
+            tool_call_id: &result.call.id,
+            content: result
+                .outputs
+                .iter()
+                .map(|output| WireToolContent::Document {
+                    document: WireToolDocument {
+                        data: output.to_string(),
+                    },
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum WireToolContent {
+    Document { document: WireToolDocument },
+}
+
+#[derive(Serialize)]
+struct WireToolDocument {
+    data: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct WireToolCall {
+    #[serde(default)]
+    id: String,
+    #[serde(rename = "type", default = "function_type")]
+    call_type: String,
+    function: WireFunction,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct WireFunction {
+    name: String,
+    #[serde(default)]
+    arguments: String,
+}
+
+fn function_type() -> String {
+    "function".to_string()
+}
+
+#[derive(Serialize)]
+struct WireTool<'a> {
+    #[serde(rename = "type")]
+    tool_type: &'static str,
+    function: WireToolFunction<'a>,
+}
+
+#[derive(Serialize)]
+struct WireToolFunction<'a> {
+    name: &'a str,
+    description: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parameters: Option<serde_json::Value>,
+}
+
+impl<'a> From<&'a Tool> for WireTool<'a> {
+    fn from(tool: &'a Tool) -> Self {
+        Self {
+            tool_type: "function",
+            function: WireToolFunction {
+                name: &tool.name,
+                description: &tool.description,
+                parameters: tool
+                    .parameter_definitions
+                    .as_ref()
+                    .map(parameters_to_schema),
+            },
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct WireDocument<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<&'a str>,
+    data: WireDocumentData<'a>,
+}
+
+#[derive(Serialize)]
+struct WireDocumentData<'a> {
+    text: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<&'a str>,
+}
+
+impl<'a> From<&'a Document> for WireDocument<'a> {
+    fn from(doc: &'a Document) -> Self {
+        Self {
+            id: doc.id.as_deref(),
+            data: WireDocumentData {
+                text: &doc.text,
+                title: doc.title.as_deref(),
+                url: doc.url.as_deref(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn weather_call() -> ToolCall {
+        ToolCall {
+            id: "call_1".to_string(),
+            name: "get_weather".to_string(),
+            parameters: json!({"location": "Toronto"}),
+        }
+    }
+
+    #[test]
+    fn test_request_serializes_v2_messages() {
+        let mut assistant = ChatMessage::chatbot("I will look up the weather.");
+        assistant.tool_calls = Some(vec![weather_call()]);
+
+        let request = ChatV2Request::new(
+            "command-r-plus",
+            vec![
+                ChatMessage::system("Be brief."),
+                ChatMessage::user("Weather in Toronto?"),
+                assistant,
+                ChatMessage::tool(vec![ToolResult {
+                    call: weather_call(),
+                    outputs: vec![json!({"temperature": 21})],
+                }]),
+            ],
+        );
+
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(body["model"], "command-r-plus");
+        assert_eq!(
+            body["messages"][0],
+            json!({"role": "system", "content": "Be brief."})
+        );
+        assert_eq!(body["messages"][1]["role"], "user");
+        assert_eq!(
+            body["messages"][2],
+            json!({
+                "role": "assistant",
+                "tool_plan": "I will look up the weather.",
+                "tool_calls": [{
+                    "id": "call_1",
+                    "type": "function",
+                    "function": {"name": "get_weather", "arguments": "{\"location\":\"Toronto\"}"}
+                }]
+            })
+        );
+        assert_eq!(
+            body["messages"][3],
+            json!({
+                "role": "tool",
+                "tool_call_id": "call_1",
+                "content": [{"type": "document", "document": {"data": "{\"temperature\":21}"}}]
+            })
+        );
+        assert!(body.get("chat_history").is_none());
+        assert!(body.get("message").is_none());
+    }
+
+    #[test]
+    fn test_tool_parameter_definitions_become_schema() {
+        let tool = Tool::new("get_weather", "Look up the weather").with_parameters(json!({
+            "location": {"type": "str", "description": "City name", "required": true}
+        }));
+        let request = ChatV2Request::builder("command-r-plus", vec![ChatMessage::user("Hi")])
+            .tools(vec![tool])
+            .build();
+
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            body["tools"][0],
+            json!({
+                "type": "function",
+                "function": {
+                    "name": "get_weather",
+                    "description": "Look up the weather",
+                    "parameters": {
+                        "type": "object",
+                        "properties": {
+                            "location": {"type": "str", "description": "City name"}
+                        },
+                        "required": ["location"]
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_response_translates_tool_calls() {
+        let response: ChatV2Response = serde_json::from_value(json!({
+            "id": "resp-1",
+            "finish_reason": "TOOL_CALL",
+            "message": {
+                "role": "assistant",
+                "tool_plan": "I will look up the weather.",
+                "tool_calls": [{
+                    "id": "call_1",
+                    "type": "function",
+                    "function": {"name": "get_weather", "arguments": "{\"location\":\"Toronto\"}"}
+                }]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(response.finish_reason, Some(FinishReason::ToolUse));
+        assert_eq!(response.tool_calls().len(), 1);
+        assert_eq!(response.tool_calls()[0].name, "get_weather");
+        assert_eq!(
+            response.tool_calls()[0].parameters,
+            json!({"location": "Toronto"})
+        );
+
+        let message = response.to_chat_message();
+        assert_eq!(message.role, MessageRole::Chatbot);
+        assert_eq!(message.message, "I will look up the weather.");
+        assert_eq!(message.tool_calls.unwrap().len(), 1);
+    }
+}
@@ -1,6 +1,7 @@
 //! Validation for chat requests.
 
 use super::types::ChatRequest;
+use super::v2::ChatV2Request;
 use crate::errors::{CohereError, CohereResult, ValidationDetail};
 
 /// Validate a chat request
@@ -124,6 +125,68 @@ pub fn validate_chat_request(request: &ChatRequest) -> CohereResult<()> {
     }
 }
 
+/// Validate a v2 chat request
+pub fn validate_chat_v2_request(request: &ChatV2Request) -> CohereResult<()> {
+    let mut errors = Vec::new();
+
+    // v2 has no default model
+    if request.model.is_empty() {
+        errors.push(ValidationDetail::new("model", "Model is required for the v2 Chat API"));
+    }
+
+    if request.messages.is_empty() {
+        errors.push(ValidationDetail::new("messages", "Messages cannot be empty"));
+    }
+
+    if let Some(temp) = request.temperature {
+        if !(0.0..=1.0).contains(&temp) {
+            errors.push(ValidationDetail::with_value(
+                "temperature",
+                "Temperature must be between 0.0 and 1.0",
+                temp.to_string(),
+            ));
+        }
+    }
+
+    if request.max_tokens == Some(0) {
+        errors.push(ValidationDetail::with_value(
+            "max_tokens",
+            "max_tokens must be greater than 0",
+            "0",
+        ));
+    }
+
+    if let Some(p) = request.p {
+        if !(0.01..=0.99).contains(&p) {
+            errors.push(ValidationDetail::with_value(
+                "p",
+                "p must be between 0.01 and 0.99",
+                p.to_string(),
+            ));
+        }
+    }
+
+    if let Some(ref tools) = request.tools {
+        for (i, tool) in tools.iter().enumerate() {
+            if tool.name.is_empty() {
+                errors.push(ValidationDetail::new(
+                    format!("tools[{}].name", i),
+                    "Tool name cannot be empty",
+                ));
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(CohereError::Validation {
+            message: format!("Chat request validation failed: {} error(s)", errors.len()),
+            details: errors,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3,7 +3,10 @@
 mod service;
 mod types;
 
-pub use service::{ClassifyService, ClassifyServiceImpl};
+pub use service::{
+    is_fine_tuned_model, ClassifyService, ClassifyServiceImpl, MAX_CLASSIFY_INPUTS,
+    MIN_EXAMPLES_PER_LABEL,
+};
 pub use types::{
     ClassificationResult, ClassifyExample, ClassifyRequest, ClassifyRequestBuilder,
     ClassifyResponse, LabelConfidence,
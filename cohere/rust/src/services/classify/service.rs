@@ -1,18 +1,35 @@
 //! Classify service implementation.
 
-use super::types::{ClassifyRequest, ClassifyResponse};
+use super::types::{ClassifyExample, ClassifyRequest, ClassifyResponse};
 use crate::auth::AuthManager;
 use crate::errors::{CohereError, CohereResult, ValidationDetail};
 use crate::transport::HttpTransport;
 use async_trait::async_trait;
 use http::{HeaderMap, Method};
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use url::Url;
 
+/// Maximum number of inputs the API accepts per classify call
+pub const MAX_CLASSIFY_INPUTS: usize = 96;
+
+/// Minimum number of examples required for each label
+pub const MIN_EXAMPLES_PER_LABEL: usize = 2;
+
+/// Check whether a model ID refers to a fine-tuned classifier
+///
+/// Fine-tuned models carry their training examples and need no inline examples.
+pub fn is_fine_tuned_model(model: &str) -> bool {
+    model.ends_with("-ft")
+}
+
 /// Classify service trait for testability
 #[async_trait]
 pub trait ClassifyService: Send + Sync {
     /// Classify texts
+    ///
+    /// Inputs beyond [`MAX_CLASSIFY_INPUTS`] are sent in consecutive calls and
+    /// the classifications are returned in input order.
     async fn classify(&self, request: ClassifyRequest) -> CohereResult<ClassifyResponse>;
 }
 
@@ -60,16 +77,10 @@ impl ClassifyServiceImpl {
             errors.push(ValidationDetail::new("inputs", "Inputs cannot be empty"));
         }
 
-        if request.inputs.len() > 96 {
-            errors.push(ValidationDetail::with_value(
-                "inputs",
-                "Cannot classify more than 96 inputs at once",
-                request.inputs.len().to_string(),
-            ));
-        }
+        let fine_tuned = request.model.as_deref().is_some_and(is_fine_tuned_model);
 
-        // Need either examples or preset
-        if request.examples.is_none() && request.preset.is_none() {
+        // Need either examples, preset or a fine-tuned model
+        if request.examples.is_none() && request.preset.is_none() && !fine_tuned {
             errors.push(ValidationDetail::new(
                 "examples/preset",
                 "Either examples or preset must be provided",
@@ -77,7 +88,7 @@ impl ClassifyServiceImpl {
         }
 
         // Validate examples if provided
-        if let Some(ref examples) = request.examples {
+        if let Some(examples) = request.examples.as_ref().filter(|_| !fine_tuned) {
             if examples.len() < 2 {
                 errors.push(ValidationDetail::with_value(
                     "examples",
@@ -96,6 +107,12 @@ impl ClassifyServiceImpl {
                 ));
             }
 
+            if let Some(detail) = Self::under_populated_labels(examples) {
+                errors.push(detail);
+            }
+        }
+
+        if let Some(ref examples) = request.examples {
             for (i, example) in examples.iter().enumerate() {
                 if example.text.is_empty() {
                     errors.push(ValidationDetail::new(
@@ -124,14 +141,37 @@ impl ClassifyServiceImpl {
             })
         }
     }
-}
 
-#[async_trait]
-impl ClassifyService for ClassifyServiceImpl {
-    async fn classify(&self, request: ClassifyRequest) -> CohereResult<ClassifyResponse> {
-        // Validate request
-        self.validate(&request)?;
+    /// Report labels with fewer than [`MIN_EXAMPLES_PER_LABEL`] examples
+    fn under_populated_labels(examples: &[ClassifyExample]) -> Option<ValidationDetail> {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for example in examples.iter().filter(|e| !e.label.is_empty()) {
+            *counts.entry(example.label.as_str()).or_default() += 1;
+        }
+
+        let sparse: Vec<String> = counts
+            .into_iter()
+            .filter(|(_, count)| *count < MIN_EXAMPLES_PER_LABEL)
+            .map(|(label, count)| format!("{} ({})", label, count))
+            .collect();
+
+        if sparse.is_empty() {
+            return None;
+        }
+
+        let sparse = sparse.join(", ");
+        Some(ValidationDetail::with_value(
+            "examples",
+            format!(
+                "Each label needs at least {} examples; under-populated labels: {}",
+                MIN_EXAMPLES_PER_LABEL, sparse
+            ),
+            sparse,
+        ))
+    }
 
+    /// Send a single classify call
+    async fn send(&self, request: &ClassifyRequest) -> CohereResult<ClassifyResponse> {
         // Build URL
         let url = self.classify_url()?;
 
@@ -139,7 +179,7 @@ impl ClassifyService for ClassifyServiceImpl {
         let headers = self.build_headers();
 
         // Serialize request body
-        let body = serde_json::to_vec(&request)?;
+        let body = serde_json::to_vec(request)?;
 
         // Execute request
         let response = self
@@ -154,10 +194,39 @@ impl ClassifyService for ClassifyServiceImpl {
     }
 }
 
+#[async_trait]
+impl ClassifyService for ClassifyServiceImpl {
+    async fn classify(&self, request: ClassifyRequest) -> CohereResult<ClassifyResponse> {
+        // Validate request
+        self.validate(&request)?;
+
+        if request.inputs.len() <= MAX_CLASSIFY_INPUTS {
+            return self.send(&request).await;
+        }
+
+        // Split oversized requests; id and meta come from the first chunk
+        let mut responses = Vec::new();
+        for inputs in request.inputs.chunks(MAX_CLASSIFY_INPUTS) {
+            let chunk = ClassifyRequest {
+                inputs: inputs.to_vec(),
+                ..request.clone()
+            };
+            responses.push(self.send(&chunk).await?);
+        }
+
+        let mut responses = responses.into_iter();
+        let mut merged = responses.next().expect("inputs are not empty");
+        for response in responses {
+            merged.classifications.extend(response.classifications);
+        }
+
+        Ok(merged)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::services::classify::types::ClassifyExample;
 
     fn create_service() -> ClassifyServiceImpl {
         let transport = Arc::new(MockTransport);
@@ -170,7 +239,9 @@ mod tests {
         let service = create_service();
         let request = ClassifyRequest::builder(vec!["text1".to_string()])
             .add_example("positive example", "positive")
+            .add_example("another positive example", "positive")
             .add_example("negative example", "negative")
+            .add_example("another negative example", "negative")
             .build();
 
         assert!(service.validate(&request).is_ok());
@@ -193,6 +264,16 @@ mod tests {
         assert!(service.validate(&request).is_err());
     }
 
+    #[test]
+    fn test_validate_fine_tuned_model_without_examples() {
+        let service = create_service();
+        let request = ClassifyRequest::builder(vec!["text".to_string()])
+            .model("2f4c1a8e-7b3d-4e5f-9a6b-1c2d3e4f5a6b-ft")
+            .build();
+
+        assert!(service.validate(&request).is_ok());
+    }
+
     // Mock implementations
     struct MockTransport;
 
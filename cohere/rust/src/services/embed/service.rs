@@ -1,12 +1,14 @@
 //! Embed service implementation.
 
-use super::types::{EmbedJob, EmbedJobRequest, EmbedRequest, EmbedResponse};
+use super::types::{EmbedJob, EmbedJobRequest, EmbedJobStatus, EmbedRequest, EmbedResponse};
 use crate::auth::AuthManager;
 use crate::errors::{CohereError, CohereResult, ValidationDetail};
 use crate::transport::HttpTransport;
 use async_trait::async_trait;
 use http::{HeaderMap, Method};
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
 use url::Url;
 
 /// Embed service trait for testability
@@ -26,6 +28,71 @@ pub trait EmbedService: Send + Sync {
 
     /// Cancel an embed job
     async fn cancel_embed_job(&self, job_id: &str) -> CohereResult<()>;
+
+    /// Create an embed job and wait for it to finish
+    ///
+    /// The job is polled every `poll_interval` and `on_progress` is called
+    /// with each observed state, including the initial one. Returns the
+    /// completed job, `CohereError::JobFailed` if the job fails or is
+    /// cancelled, or `CohereError::Timeout` once `timeout` has elapsed since
+    /// the call started. The job itself is left running on timeout.
+    async fn run_embed_job_to_completion(
+        &self,
+        request: EmbedJobRequest,
+        poll_interval: Duration,
+        timeout: Duration,
+        on_progress: &(dyn for<'j> Fn(&'j EmbedJob) + Send + Sync),
+    ) -> CohereResult<EmbedJob> {
+        if poll_interval.is_zero() {
+            return Err(CohereError::Validation {
+                message: "Embed job poll interval must be greater than zero".to_string(),
+                details: vec![ValidationDetail::new(
+                    "poll_interval",
+                    "Poll interval must be greater than zero",
+                )],
+            });
+        }
+
+        let deadline = Instant::now() + timeout;
+        let timed_out = |job_id: Option<&str>| CohereError::Timeout {
+            message: match job_id {
+                Some(id) => format!("Embed job {} did not complete within {:?}", id, timeout),
+                None => format!("Embed job was not created within {:?}", timeout),
+            },
+        };
+
+        let mut job = tokio::time::timeout_at(deadline, self.create_embed_job(request))
+            .await
+            .map_err(|_| timed_out(None))??;
+
+        loop {
+            on_progress(&job);
+
+            match job.status {
+                EmbedJobStatus::Complete => return Ok(job),
+                EmbedJobStatus::Failed | EmbedJobStatus::Cancelled => {
+                    let reason = job.failure_reason.clone().unwrap_or_else(|| {
+                        format!("job status is {:?}", job.status).to_lowercase()
+                    });
+                    return Err(CohereError::JobFailed {
+                        job_id: job.job_id,
+                        reason,
+                    });
+                }
+                EmbedJobStatus::Processing => {}
+            }
+
+            let wake_at = (Instant::now() + poll_interval).min(deadline);
+            tokio::time::sleep_until(wake_at).await;
+            if wake_at >= deadline {
+                return Err(timed_out(Some(&job.job_id)));
+            }
+
+            job = tokio::time::timeout_at(deadline, self.get_embed_job(&job.job_id))
+                .await
+                .map_err(|_| timed_out(Some(&job.job_id)))??;
+        }
+    }
 }
 
 /// Implementation of the Embed service
@@ -211,6 +278,7 @@ impl EmbedService for EmbedServiceImpl {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mocks::MockResponse;
 
     #[test]
     fn test_validate_valid_request() {
@@ -246,6 +314,121 @@ mod tests {
         assert!(service.validate(&request).is_err());
     }
 
+    fn job_json(status: &str) -> serde_json::Value {
+        serde_json::json!({"job_id": "job-1", "status": status})
+    }
+
+    #[tokio::test]
+    async fn test_run_embed_job_to_completion() {
+        let (service, transport) = crate::mocks::MockClientBuilder::new()
+            .with_response(MockResponse::json(&job_json("processing")))
+            .with_response(MockResponse::json(&job_json("processing")))
+            .with_response(MockResponse::json(&job_json("complete")))
+            .build(EmbedServiceImpl::new);
+
+        let seen = parking_lot::Mutex::new(Vec::new());
+        let job = service
+            .run_embed_job_to_completion(
+                EmbedJobRequest::new("dataset-1"),
+                Duration::from_millis(10),
+                Duration::from_secs(5),
+                &|job| seen.lock().push(job.status),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(job.status, EmbedJobStatus::Complete);
+        assert_eq!(
+            *seen.lock(),
+            vec![
+                EmbedJobStatus::Processing,
+                EmbedJobStatus::Processing,
+                EmbedJobStatus::Complete
+            ]
+        );
+
+        let requests = transport.get_requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].method, Method::POST);
+        assert!(requests[1].url.ends_with("/v1/embed-jobs/job-1"));
+        assert_eq!(requests[2].method, Method::GET);
+    }
+
+    #[tokio::test]
+    async fn test_run_embed_job_stops_on_failure() {
+        let (service, transport) = crate::mocks::MockClientBuilder::new()
+            .with_response(MockResponse::json(&job_json("processing")))
+            .with_response(MockResponse::json(&serde_json::json!({
+                "job_id": "job-1",
+                "status": "failed",
+                "failure_reason": "dataset has no text field"
+            })))
+            .with_response(MockResponse::json(&job_json("complete")))
+            .build(EmbedServiceImpl::new);
+
+        let result = service
+            .run_embed_job_to_completion(
+                EmbedJobRequest::new("dataset-1"),
+                Duration::from_millis(10),
+                Duration::from_secs(5),
+                &|_| {},
+            )
+            .await;
+
+        match result {
+            Err(CohereError::JobFailed { job_id, reason }) => {
+                assert_eq!(job_id, "job-1");
+                assert_eq!(reason, "dataset has no text field");
+            }
+            other => panic!("Expected JobFailed, got {:?}", other),
+        }
+        assert_eq!(transport.get_requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_run_embed_job_times_out() {
+        let builder = crate::mocks::MockClientBuilder::new();
+        for _ in 0..20 {
+            builder
+                .transport()
+                .add_response(MockResponse::json(&job_json("processing")));
+        }
+        let (service, transport) = builder.build(EmbedServiceImpl::new);
+
+        let started = std::time::Instant::now();
+        let result = service
+            .run_embed_job_to_completion(
+                EmbedJobRequest::new("dataset-1"),
+                Duration::from_millis(20),
+                Duration::from_millis(50),
+                &|_| {},
+            )
+            .await;
+
+        assert!(matches!(result, Err(CohereError::Timeout { .. })));
+        assert!(started.elapsed() >= Duration::from_millis(50));
+        // Polling honours the interval rather than spinning until the deadline
+        assert!(transport.get_requests().len() <= 4);
+    }
+
+    #[tokio::test]
+    async fn test_run_embed_job_rejects_zero_interval() {
+        let (service, transport) =
+            crate::mocks::MockClientBuilder::new().build(EmbedServiceImpl::new);
+
+        let result = service
+            .run_embed_job_to_completion(
+                EmbedJobRequest::new("dataset-1"),
+                Duration::ZERO,
+                Duration::from_secs(1),
+                &|_| {},
+            )
+            .await;
+
+        assert!(matches!(result, Err(CohereError::Validation { .. })));
+        assert!(transport.get_requests().is_empty());
+    }
+
     // Mock implementations
     struct MockTransport;
 
@@ -351,6 +351,9 @@ pub struct EmbedJob {
     /// Truncation used
     #[serde(default)]
     pub truncate: Option<TruncateOption>,
+    /// Failure reason (when failed)
+    #[serde(default)]
+    pub failure_reason: Option<String>,
     /// API metadata
     #[serde(default)]
     pub meta: Option<ApiMeta>,
@@ -14,6 +14,45 @@ use url::Url;
 pub trait RerankService: Send + Sync {
     /// Rerank documents against a query
     async fn rerank(&self, request: RerankRequest) -> CohereResult<RerankResponse>;
+
+    /// Rerank documents, keeping only results at or above `min_relevance`
+    ///
+    /// Results are sorted by relevance (highest first), limited to the
+    /// request's `top_n`, and keep their original document indices. If no
+    /// result meets the threshold the response has an empty result list.
+    async fn rerank_filtered(
+        &self,
+        request: RerankRequest,
+        min_relevance: f64,
+    ) -> CohereResult<RerankResponse> {
+        if !min_relevance.is_finite() {
+            return Err(CohereError::Validation {
+                message: "Rerank request validation failed: 1 error(s)".to_string(),
+                details: vec![ValidationDetail::with_value(
+                    "min_relevance",
+                    "min_relevance must be a finite number",
+                    min_relevance.to_string(),
+                )],
+            });
+        }
+
+        let top_n = request.top_n;
+        let mut response = self.rerank(request).await?;
+
+        response
+            .results
+            .retain(|r| r.relevance_score >= min_relevance);
+        response.results.sort_by(|a, b| {
+            b.relevance_score
+                .partial_cmp(&a.relevance_score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        if let Some(top_n) = top_n {
+            response.results.truncate(top_n as usize);
+        }
+
+        Ok(response)
+    }
 }
 
 /// Implementation of the Rerank service
@@ -1,9 +1,11 @@
 //! Summarize service for text summarization.
 
 mod service;
+mod stream;
 mod types;
 
 pub use service::{SummarizeService, SummarizeServiceImpl};
+pub use stream::{collect_summary, SummarizeStream, SummarizeStreamEvent};
 pub use types::{
     SummarizeExtractiveness, SummarizeFormat, SummarizeLength, SummarizeRequest,
     SummarizeRequestBuilder, SummarizeResponse,
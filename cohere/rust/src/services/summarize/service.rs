@@ -1,5 +1,6 @@
 //! Summarize service implementation.
 
+use super::stream::SummarizeStream;
 use super::types::{SummarizeRequest, SummarizeResponse};
 use crate::auth::AuthManager;
 use crate::errors::{CohereError, CohereResult, ValidationDetail};
@@ -14,6 +15,9 @@ use url::Url;
 pub trait SummarizeService: Send + Sync {
     /// Summarize text
     async fn summarize(&self, request: SummarizeRequest) -> CohereResult<SummarizeResponse>;
+
+    /// Summarize text with streaming
+    async fn summarize_stream(&self, request: SummarizeRequest) -> CohereResult<SummarizeStream>;
 }
 
 /// Implementation of the Summarize service
@@ -94,10 +98,13 @@ impl SummarizeServiceImpl {
 
 #[async_trait]
 impl SummarizeService for SummarizeServiceImpl {
-    async fn summarize(&self, request: SummarizeRequest) -> CohereResult<SummarizeResponse> {
+    async fn summarize(&self, mut request: SummarizeRequest) -> CohereResult<SummarizeResponse> {
         // Validate request
         self.validate(&request)?;
 
+        // Ensure stream is disabled
+        request.stream = None;
+
         // Build URL
         let url = self.summarize_url()?;
 
@@ -118,6 +125,35 @@ impl SummarizeService for SummarizeServiceImpl {
 
         Ok(summarize_response)
     }
+
+    async fn summarize_stream(
+        &self,
+        mut request: SummarizeRequest,
+    ) -> CohereResult<SummarizeStream> {
+        // Validate request
+        self.validate(&request)?;
+
+        // Enable streaming
+        request.stream = Some(true);
+
+        // Build URL
+        let url = self.summarize_url()?;
+
+        // Build headers
+        let mut headers = self.build_headers();
+        headers.insert("accept", "text/event-stream".parse().unwrap());
+
+        // Serialize request body
+        let body = serde_json::to_vec(&request)?;
+
+        // Execute streaming request
+        let stream = self
+            .transport
+            .execute_stream(Method::POST, url, headers, Some(body))
+            .await?;
+
+        Ok(SummarizeStream::new(stream))
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,211 @@
+//! Streaming support for Summarize service.
+
+use super::types::SummarizeResponse;
+use crate::errors::{CohereError, CohereResult};
+use crate::transport::sse::{SseEvent, SseParser};
+use crate::types::{ApiMeta, FinishReason};
+use bytes::Bytes;
+use futures::stream::Stream;
+use serde::Deserialize;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Events from a summarize stream
+#[derive(Debug, Clone)]
+pub enum SummarizeStreamEvent {
+    /// Summary text generated
+    TextGeneration {
+        /// Summary text chunk
+        text: String,
+    },
+    /// Stream ended
+    StreamEnd {
+        /// Finish reason
+        finish_reason: Option<FinishReason>,
+        /// Final summary assembled from the streamed chunks
+        response: SummarizeResponse,
+    },
+}
+
+/// Internal streaming event types from Cohere API
+#[derive(Debug, Deserialize)]
+#[serde(tag = "event_type")]
+#[serde(rename_all = "kebab-case")]
+enum StreamEvent {
+    StreamStart {
+        #[serde(default)]
+        id: Option<String>,
+    },
+    TextGeneration {
+        text: String,
+    },
+    StreamEnd {
+        #[serde(default)]
+        finish_reason: Option<FinishReason>,
+        #[serde(default)]
+        response: Option<StreamEndResponse>,
+    },
+    #[serde(other)]
+    Other,
+}
+
+/// Response metadata sent with the stream-end event
+#[derive(Debug, Deserialize)]
+struct StreamEndResponse {
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    meta: Option<ApiMeta>,
+}
+
+/// A stream of summarize events
+pub struct SummarizeStream {
+    inner: Pin<Box<dyn Stream<Item = CohereResult<Bytes>> + Send>>,
+    parser: SseParser,
+    pending_events: VecDeque<SummarizeStreamEvent>,
+    done: bool,
+    id: Option<String>,
+    accumulated_text: String,
+    response: Option<SummarizeResponse>,
+}
+
+impl SummarizeStream {
+    /// Create a new summarize stream
+    pub fn new(inner: Pin<Box<dyn Stream<Item = CohereResult<Bytes>> + Send>>) -> Self {
+        Self {
+            inner,
+            parser: SseParser::new(),
+            pending_events: VecDeque::new(),
+            done: false,
+            id: None,
+            accumulated_text: String::new(),
+            response: None,
+        }
+    }
+
+    /// Get accumulated summary text so far
+    pub fn accumulated_text(&self) -> &str {
+        &self.accumulated_text
+    }
+
+    /// Get the final response once the stream has ended
+    pub fn response(&self) -> Option<&SummarizeResponse> {
+        self.response.as_ref()
+    }
+
+    /// Build the end event from everything received so far
+    fn finish(
+        &mut self,
+        finish_reason: Option<FinishReason>,
+        end: Option<StreamEndResponse>,
+    ) -> SummarizeStreamEvent {
+        self.done = true;
+
+        let (id, meta) = match end {
+            Some(end) => (end.id.or_else(|| self.id.clone()), end.meta),
+            None => (self.id.clone(), None),
+        };
+        let response = SummarizeResponse {
+            id,
+            summary: self.accumulated_text.clone(),
+            meta,
+        };
+        self.response = Some(response.clone());
+
+        SummarizeStreamEvent::StreamEnd {
+            finish_reason,
+            response,
+        }
+    }
+
+    /// Parse an SSE event into a summarize stream event
+    fn parse_event(&mut self, sse: SseEvent) -> CohereResult<Option<SummarizeStreamEvent>> {
+        // `SseEvent::is_done` also matches `event: stream-end`, which carries
+        // the response metadata here, so only the `[DONE]` sentinel is skipped
+        if self.done || sse.data == "[DONE]" {
+            return Ok(None);
+        }
+
+        let event: StreamEvent =
+            serde_json::from_str(&sse.data).map_err(|e| CohereError::StreamError {
+                message: format!("Failed to parse stream event: {}", e),
+            })?;
+
+        let summarize_event = match event {
+            StreamEvent::StreamStart { id } => {
+                self.id = id;
+                return Ok(None);
+            }
+            StreamEvent::TextGeneration { text } => {
+                self.accumulated_text.push_str(&text);
+                SummarizeStreamEvent::TextGeneration { text }
+            }
+            StreamEvent::StreamEnd {
+                finish_reason,
+                response,
+            } => self.finish(finish_reason, response),
+            StreamEvent::Other => return Ok(None),
+        };
+
+        Ok(Some(summarize_event))
+    }
+}
+
+impl Stream for SummarizeStream {
+    type Item = CohereResult<SummarizeStreamEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(event) = self.pending_events.pop_front() {
+                return Poll::Ready(Some(Ok(event)));
+            }
+
+            if self.done {
+                return Poll::Ready(None);
+            }
+
+            match Pin::new(&mut self.inner).poll_next(cx) {
+                Poll::Ready(Some(Ok(bytes))) => {
+                    let events = self.parser.feed(&bytes);
+                    for sse in events {
+                        if let Some(event) = self.parse_event(sse)? {
+                            self.pending_events.push_back(event);
+                        }
+                    }
+                }
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(None) => {
+                    // A trailing event without a blank line is still delivered
+                    if let Some(sse) = self.parser.flush() {
+                        if let Some(event) = self.parse_event(sse)? {
+                            self.pending_events.push_back(event);
+                        }
+                    }
+                    // End the stream with the accumulated summary even if the
+                    // server closed the connection without a stream-end event
+                    if !self.done {
+                        let event = self.finish(None, None);
+                        self.pending_events.push_back(event);
+                    }
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Drain a summarize stream into the final response
+pub async fn collect_summary(mut stream: SummarizeStream) -> CohereResult<SummarizeResponse> {
+    use futures::StreamExt;
+
+    while let Some(event) = stream.next().await {
+        if let SummarizeStreamEvent::StreamEnd { response, .. } = event? {
+            return Ok(response);
+        }
+    }
+
+    Err(CohereError::StreamError {
+        message: "Summarize stream ended without a summary".to_string(),
+    })
+}
@@ -82,6 +82,9 @@ pub struct SummarizeRequest {
     /// Additional command/prompt
     #[serde(skip_serializing_if = "Option::is_none")]
     pub additional_command: Option<String>,
+    /// Whether to stream the response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<bool>,
 }
 
 impl SummarizeRequest {
@@ -95,6 +98,7 @@ impl SummarizeRequest {
             extractiveness: None,
             temperature: None,
             additional_command: None,
+            stream: None,
         }
     }
 
@@ -0,0 +1,90 @@
+//! Transport decorator that records billed units into a usage ledger.
+
+use crate::errors::CohereResult;
+use crate::observability::UsageLedger;
+use crate::transport::{HttpTransport, TransportResponse};
+use crate::types::BilledUnits;
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::stream::Stream;
+use http::{HeaderMap, Method};
+use serde::Deserialize;
+use std::pin::Pin;
+use std::sync::Arc;
+use url::Url;
+
+/// The parts of a response body that may carry billed units
+///
+/// v1 endpoints report them under `meta`, the v2 chat endpoint under `usage`.
+#[derive(Deserialize)]
+struct BilledUnitsEnvelope {
+    #[serde(default)]
+    meta: Option<BilledUnitsHolder>,
+    #[serde(default)]
+    usage: Option<BilledUnitsHolder>,
+}
+
+#[derive(Deserialize)]
+struct BilledUnitsHolder {
+    #[serde(default)]
+    billed_units: Option<BilledUnits>,
+}
+
+/// Wraps a transport and records every successful call in a [`UsageLedger`]
+///
+/// Streaming calls are counted but their billed units are not, since they
+/// only arrive in the final stream event.
+pub struct MeteredTransport {
+    inner: Arc<dyn HttpTransport>,
+    ledger: Arc<UsageLedger>,
+}
+
+impl MeteredTransport {
+    /// Wrap `inner`, recording into `ledger`
+    pub fn new(inner: Arc<dyn HttpTransport>, ledger: Arc<UsageLedger>) -> Self {
+        Self { inner, ledger }
+    }
+
+    fn billed_units(body: &[u8]) -> Option<BilledUnits> {
+        let envelope: BilledUnitsEnvelope = serde_json::from_slice(body).ok()?;
+        envelope
+            .meta
+            .and_then(|m| m.billed_units)
+            .or_else(|| envelope.usage.and_then(|u| u.billed_units))
+    }
+}
+
+#[async_trait]
+impl HttpTransport for MeteredTransport {
+    async fn send(
+        &self,
+        method: Method,
+        url: Url,
+        headers: HeaderMap,
+        body: Option<Bytes>,
+    ) -> CohereResult<TransportResponse> {
+        let endpoint = url.path().to_string();
+        let response = self.inner.send(method, url, headers, body).await?;
+        if (200..300).contains(&response.status) {
+            self.ledger
+                .record(&endpoint, Self::billed_units(&response.body).as_ref());
+        }
+        Ok(response)
+    }
+
+    async fn send_streaming(
+        &self,
+        method: Method,
+        url: Url,
+        headers: HeaderMap,
+        body: Option<Bytes>,
+    ) -> CohereResult<Pin<Box<dyn Stream<Item = CohereResult<Bytes>> + Send>>> {
+        let endpoint = url.path().to_string();
+        let stream = self
+            .inner
+            .send_streaming(method, url, headers, body)
+            .await?;
+        self.ledger.record(&endpoint, None);
+        Ok(stream)
+    }
+}
@@ -1,7 +1,9 @@
 //! HTTP transport layer for the Cohere API.
 
 mod http_transport;
+mod metered;
 mod sse;
 
 pub use http_transport::{HttpTransport, ReqwestTransport, TransportResponse};
+pub use metered::MeteredTransport;
 pub use sse::{SseEvent, SseParser, SseStream};
@@ -168,6 +168,7 @@ pub enum FinishReason {
     #[serde(alias = "FILTERED")]
     ContentFiltered,
     /// Tool use requested
+    #[serde(alias = "TOOL_CALL")]
     ToolUse,
 }
 
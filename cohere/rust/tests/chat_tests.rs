@@ -1,8 +1,12 @@
 //! Tests for the Chat service.
 
-use cohere_client::fixtures::{api_meta, chat_response, sse_chat_stream_data};
+use cohere_client::fixtures::{
+    api_meta, chat_response, connector_chat_response_json, sse_chat_stream_data,
+};
 use cohere_client::mocks::{MockClientBuilder, MockHttpTransport, MockResponse};
-use cohere_client::services::chat::{ChatMessage, ChatRequest, ChatServiceImpl, MessageRole};
+use cohere_client::services::chat::{
+    ChatMessage, ChatRequest, ChatService, ChatServiceImpl, Connector, MessageRole,
+};
 use cohere_client::errors::CohereError;
 use std::sync::Arc;
 
@@ -98,6 +102,50 @@ async fn test_chat_validation_invalid_temperature() {
     }
 }
 
+#[tokio::test]
+async fn test_chat_with_connectors_maps_citations_to_documents() {
+    let (service, transport) = MockClientBuilder::new()
+        .with_response(MockResponse::json(&connector_chat_response_json()))
+        .build(|t, a, u| ChatServiceImpl::new(t, a, u));
+
+    let grounded = service
+        .chat_with_connectors("When was Rust 1.0 released?", vec![Connector::web_search()])
+        .await
+        .unwrap();
+
+    assert_eq!(grounded.citations.len(), 2);
+
+    let first = &grounded.citations[0];
+    assert_eq!((first.citation.start, first.citation.end), (0, 33));
+    assert_eq!(first.documents.len(), 1);
+    assert_eq!(first.documents[0].id.as_deref(), Some("web-search_0"));
+    assert!(first.unresolved_document_ids.is_empty());
+
+    let second = &grounded.citations[1];
+    assert_eq!(second.documents.len(), 1);
+    assert_eq!(second.documents[0].title.as_deref(), Some("Rust (programming language)"));
+    assert_eq!(second.unresolved_document_ids, vec!["web-search_9".to_string()]);
+
+    // "May" falls inside both spans
+    assert_eq!(grounded.citations_at(26).len(), 2);
+    assert_eq!(grounded.cited_documents().len(), 2);
+
+    let body: serde_json::Value =
+        serde_json::from_slice(transport.get_requests()[0].body.as_ref().unwrap()).unwrap();
+    assert_eq!(body["connectors"][0]["id"], "web-search");
+}
+
+#[tokio::test]
+async fn test_chat_with_connectors_requires_a_connector() {
+    let (service, transport) = MockClientBuilder::new()
+        .build(|t, a, u| ChatServiceImpl::new(t, a, u));
+
+    let result = service.chat_with_connectors("Hello", vec![]).await;
+
+    assert!(matches!(result, Err(CohereError::Validation { .. })));
+    assert!(transport.get_requests().is_empty());
+}
+
 #[tokio::test]
 async fn test_chat_handles_error_response() {
     let (service, _) = MockClientBuilder::new()
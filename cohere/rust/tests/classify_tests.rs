@@ -0,0 +1,78 @@
+//! Tests for the Classify service.
+
+use cohere_client::errors::CohereError;
+use cohere_client::fixtures::classify_examples;
+use cohere_client::mocks::{MockClientBuilder, MockResponse};
+use cohere_client::services::classify::{
+    ClassifyRequest, ClassifyService, ClassifyServiceImpl, MAX_CLASSIFY_INPUTS,
+};
+use serde_json::json;
+
+fn classifications(inputs: &[String]) -> serde_json::Value {
+    let classifications: Vec<_> = inputs
+        .iter()
+        .map(|input| json!({ "input": input, "prediction": "positive", "confidence": 0.9 }))
+        .collect();
+    json!({ "id": "classify-789", "classifications": classifications })
+}
+
+#[tokio::test]
+async fn test_classify_rejects_under_populated_labels() {
+    let (service, transport) = MockClientBuilder::new().build(ClassifyServiceImpl::new);
+
+    let request = ClassifyRequest::builder(vec!["Is this any good?".to_string()])
+        .examples(classify_examples())
+        .add_example("It's fine, I guess", "neutral")
+        .add_example("Shipping was slow", "shipping")
+        .build();
+    let err = service.classify(request).await.unwrap_err();
+
+    match err {
+        CohereError::Validation { details, .. } => {
+            let detail = details
+                .iter()
+                .find(|d| d.message.contains("under-populated"))
+                .expect("missing under-populated label detail");
+            assert_eq!(detail.value.as_deref(), Some("neutral (1), shipping (1)"));
+        }
+        other => panic!("Expected validation error, got {:?}", other),
+    }
+    assert!(transport.get_requests().is_empty());
+}
+
+#[tokio::test]
+async fn test_classify_chunks_inputs_over_limit() {
+    let inputs: Vec<String> = (0..MAX_CLASSIFY_INPUTS + 4)
+        .map(|i| format!("review {}", i))
+        .collect();
+    let (first, second) = inputs.split_at(MAX_CLASSIFY_INPUTS);
+
+    let (service, transport) = MockClientBuilder::new()
+        .with_response(MockResponse::json(&classifications(first)))
+        .with_response(MockResponse::json(&classifications(second)))
+        .build(ClassifyServiceImpl::new);
+
+    let request = ClassifyRequest::builder(inputs.clone())
+        .examples(classify_examples())
+        .build();
+    let response = service.classify(request).await.unwrap();
+
+    let requests = transport.get_requests();
+    assert_eq!(requests.len(), 2);
+    let sent: Vec<usize> = requests
+        .iter()
+        .map(|r| {
+            let body: serde_json::Value = serde_json::from_slice(r.body.as_ref().unwrap()).unwrap();
+            body["inputs"].as_array().unwrap().len()
+        })
+        .collect();
+    assert_eq!(sent, vec![MAX_CLASSIFY_INPUTS, 4]);
+
+    let classified: Vec<&str> = response
+        .classifications
+        .iter()
+        .map(|c| c.input.as_str())
+        .collect();
+    assert_eq!(classified, inputs.iter().map(String::as_str).collect::<Vec<_>>());
+    assert_eq!(response.id, Some("classify-789".to_string()));
+}
@@ -0,0 +1,126 @@
+//! Tests for the Rerank service.
+
+use cohere_client::errors::CohereError;
+use cohere_client::mocks::{MockClientBuilder, MockResponse};
+use cohere_client::services::rerank::{
+    RerankDocument, RerankRequest, RerankService, RerankServiceImpl,
+};
+use serde_json::json;
+
+fn documents() -> Vec<RerankDocument> {
+    vec![
+        RerankDocument::text("Paris is the capital of France"),
+        RerankDocument::text("Bananas are rich in potassium"),
+        RerankDocument::text("France borders Spain and Italy"),
+        RerankDocument::text("The Eiffel Tower is in Paris"),
+        RerankDocument::text("Rust is a systems programming language"),
+    ]
+}
+
+/// Rerank results deliberately out of relevance order
+fn unsorted_results() -> serde_json::Value {
+    json!({
+        "id": "rerank-456",
+        "results": [
+            { "index": 1, "relevance_score": 0.02 },
+            { "index": 3, "relevance_score": 0.81 },
+            { "index": 0, "relevance_score": 0.97 },
+            { "index": 4, "relevance_score": 0.10 },
+            { "index": 2, "relevance_score": 0.55 }
+        ]
+    })
+}
+
+#[tokio::test]
+async fn test_rerank_filtered_drops_results_below_threshold() {
+    let (service, transport) = MockClientBuilder::new()
+        .with_response(MockResponse::json(&unsorted_results()))
+        .build(|t, a, u| RerankServiceImpl::new(t, a, u));
+
+    let docs = documents();
+    let request = RerankRequest::new("What is the capital of France?", docs.clone());
+    let response = service.rerank_filtered(request, 0.5).await.unwrap();
+
+    let indices: Vec<usize> = response.results.iter().map(|r| r.index).collect();
+    assert_eq!(indices, vec![0, 3, 2]);
+    assert_eq!(response.id, Some("rerank-456".to_string()));
+
+    // Indices refer to positions in the input documents
+    let texts: Vec<&str> = response
+        .results
+        .iter()
+        .map(|r| docs[r.index].text_content())
+        .collect();
+    assert_eq!(
+        texts,
+        vec![
+            "Paris is the capital of France",
+            "The Eiffel Tower is in Paris",
+            "France borders Spain and Italy",
+        ]
+    );
+
+    let requests = transport.get_requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].url.contains("/rerank"));
+}
+
+#[tokio::test]
+async fn test_rerank_filtered_threshold_is_inclusive() {
+    let (service, _) = MockClientBuilder::new()
+        .with_response(MockResponse::json(&unsorted_results()))
+        .build(|t, a, u| RerankServiceImpl::new(t, a, u));
+
+    let request = RerankRequest::new("query", documents());
+    let response = service.rerank_filtered(request, 0.81).await.unwrap();
+
+    let indices: Vec<usize> = response.results.iter().map(|r| r.index).collect();
+    assert_eq!(indices, vec![0, 3]);
+}
+
+#[tokio::test]
+async fn test_rerank_filtered_respects_top_n() {
+    let (service, transport) = MockClientBuilder::new()
+        .with_response(MockResponse::json(&unsorted_results()))
+        .build(|t, a, u| RerankServiceImpl::new(t, a, u));
+
+    let request = RerankRequest::builder("query", documents()).top_n(2).build();
+    let response = service.rerank_filtered(request, 0.05).await.unwrap();
+
+    let indices: Vec<usize> = response.results.iter().map(|r| r.index).collect();
+    assert_eq!(indices, vec![0, 3]);
+
+    let requests = transport.get_requests();
+    let body = String::from_utf8_lossy(requests[0].body.as_ref().unwrap()).to_string();
+    assert!(body.contains("\"top_n\":2"));
+}
+
+#[tokio::test]
+async fn test_rerank_filtered_all_below_threshold_is_empty() {
+    let (service, _) = MockClientBuilder::new()
+        .with_response(MockResponse::json(&unsorted_results()))
+        .build(|t, a, u| RerankServiceImpl::new(t, a, u));
+
+    let request = RerankRequest::new("query", documents());
+    let response = service.rerank_filtered(request, 0.99).await.unwrap();
+
+    assert!(response.results.is_empty());
+}
+
+#[tokio::test]
+async fn test_rerank_filtered_rejects_nan_threshold() {
+    let (service, transport) = MockClientBuilder::new()
+        .with_response(MockResponse::json(&unsorted_results()))
+        .build(|t, a, u| RerankServiceImpl::new(t, a, u));
+
+    let request = RerankRequest::new("query", documents());
+    let result = service.rerank_filtered(request, f64::NAN).await;
+
+    match result {
+        Err(CohereError::Validation { details, .. }) => {
+            assert_eq!(details[0].field, "min_relevance");
+        }
+        other => panic!("Expected validation error, got {:?}", other),
+    }
+    assert!(transport.get_requests().is_empty());
+}
@@ -0,0 +1,106 @@
+//! Tests for the Summarize service.
+
+use cohere_client::fixtures::sse_summarize_stream_data;
+use cohere_client::mocks::{MockClientBuilder, MockResponse};
+use cohere_client::services::summarize::{
+    collect_summary, SummarizeExtractiveness, SummarizeFormat, SummarizeLength, SummarizeRequest,
+    SummarizeService, SummarizeServiceImpl, SummarizeStreamEvent,
+};
+use cohere_client::types::FinishReason;
+use futures::StreamExt;
+
+fn long_text() -> String {
+    "Rust is a multi-paradigm, general-purpose programming language that emphasizes \
+     performance, type safety, and concurrency. "
+        .repeat(4)
+}
+
+#[tokio::test]
+async fn test_summarize_stream_concatenates_chunks() {
+    let (service, transport) = MockClientBuilder::new()
+        .with_response(MockResponse::sse(&sse_summarize_stream_data()))
+        .build(SummarizeServiceImpl::new);
+
+    let request = SummarizeRequest::builder(long_text())
+        .format(SummarizeFormat::Bullets)
+        .length(SummarizeLength::Short)
+        .extractiveness(SummarizeExtractiveness::Low)
+        .build();
+    let mut stream = service.summarize_stream(request).await.unwrap();
+
+    let mut deltas = Vec::new();
+    let mut end = None;
+    while let Some(event) = stream.next().await {
+        match event.unwrap() {
+            SummarizeStreamEvent::TextGeneration { text } => deltas.push(text),
+            SummarizeStreamEvent::StreamEnd {
+                finish_reason,
+                response,
+            } => end = Some((finish_reason, response)),
+        }
+    }
+
+    let expected = "- Rust is a systems language\n\
+                    - It guarantees memory safety\n\
+                    - It has no garbage collector";
+    assert_eq!(deltas.len(), 3);
+    assert_eq!(deltas.concat(), expected);
+
+    let (finish_reason, response) = end.expect("stream should end with a summary");
+    assert_eq!(finish_reason, Some(FinishReason::Complete));
+    assert_eq!(response.summary, expected);
+    assert_eq!(response.id.as_deref(), Some("sum-stream-123"));
+    let billed = response.meta.unwrap().billed_units.unwrap();
+    assert_eq!(billed.input_tokens, 320);
+    assert_eq!(billed.output_tokens, 24);
+
+    let requests = transport.get_requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].url.ends_with("/v1/summarize"));
+    let body: serde_json::Value =
+        serde_json::from_slice(requests[0].body.as_ref().unwrap()).unwrap();
+    assert_eq!(body["stream"], true);
+    assert_eq!(body["format"], "bullets");
+    assert_eq!(body["length"], "short");
+    assert_eq!(body["extractiveness"], "low");
+}
+
+#[tokio::test]
+async fn test_summarize_stream_single_chunk_terminates() {
+    // One text event and no stream-end, with no trailing blank line
+    let events = vec![
+        r#"data: {"event_type": "text-generation", "text": "Rust is fast and safe."}"#.to_string(),
+    ];
+    let (service, _transport) = MockClientBuilder::new()
+        .with_response(MockResponse::sse(&events))
+        .build(SummarizeServiceImpl::new);
+
+    let stream = service
+        .summarize_stream(SummarizeRequest::new(long_text()))
+        .await
+        .unwrap();
+    let response = collect_summary(stream).await.unwrap();
+
+    assert_eq!(response.summary, "Rust is fast and safe.");
+    assert!(response.meta.is_none());
+}
+
+#[tokio::test]
+async fn test_summarize_does_not_stream() {
+    let (service, transport) = MockClientBuilder::new()
+        .with_response(MockResponse::json(&serde_json::json!({
+            "id": "sum-1",
+            "summary": "Rust is fast and safe."
+        })))
+        .build(SummarizeServiceImpl::new);
+
+    let response = service
+        .summarize(SummarizeRequest::new(long_text()))
+        .await
+        .unwrap();
+
+    assert_eq!(response.summary, "Rust is fast and safe.");
+    let body: serde_json::Value =
+        serde_json::from_slice(transport.get_requests()[0].body.as_ref().unwrap()).unwrap();
+    assert!(body.get("stream").is_none());
+}
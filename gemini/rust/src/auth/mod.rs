@@ -1,12 +1,17 @@
 //! Authentication module for Gemini API.
 
+use std::sync::Arc;
 use crate::config::{GeminiConfig, AuthMethod};
+use crate::error::{ConfigurationError, GeminiError};
 use secrecy::{SecretString, ExposeSecret};
 
 /// Authentication manager for Gemini API.
 pub trait AuthManager: Send + Sync {
     /// Get the authentication header name and value.
-    fn get_auth_header(&self) -> Option<(String, String)>;
+    ///
+    /// Fails if credentials cannot be obtained, e.g. when an access token
+    /// provider errors.
+    fn get_auth_header(&self) -> Result<Option<(String, String)>, GeminiError>;
 
     /// Get the authentication query parameter.
     fn get_auth_query_param(&self) -> Option<(String, String)>;
@@ -34,14 +39,14 @@ impl ApiKeyAuthManager {
 }
 
 impl AuthManager for ApiKeyAuthManager {
-    fn get_auth_header(&self) -> Option<(String, String)> {
-        match self.auth_method {
+    fn get_auth_header(&self) -> Result<Option<(String, String)>, GeminiError> {
+        Ok(match self.auth_method {
             AuthMethod::Header => Some((
                 "x-goog-api-key".to_string(),
                 self.api_key.expose_secret().to_string(),
             )),
-            AuthMethod::QueryParam => None,
-        }
+            AuthMethod::QueryParam | AuthMethod::VertexOauth => None,
+        })
     }
 
     fn get_auth_query_param(&self) -> Option<(String, String)> {
@@ -50,7 +55,7 @@ impl AuthManager for ApiKeyAuthManager {
                 "key".to_string(),
                 self.api_key.expose_secret().to_string(),
             )),
-            AuthMethod::Header => None,
+            AuthMethod::Header | AuthMethod::VertexOauth => None,
         }
     }
 
@@ -62,6 +67,84 @@ impl AuthManager for ApiKeyAuthManager {
     }
 }
 
+/// Source of OAuth access tokens for Vertex AI.
+///
+/// Implementations are responsible for caching and refreshing tokens; the
+/// provider is asked for a token on every request.
+pub trait AccessTokenProvider: Send + Sync {
+    /// Get a currently valid access token.
+    fn access_token(&self) -> Result<SecretString, GeminiError>;
+}
+
+/// Access token provider that always returns the same token.
+pub struct StaticTokenProvider {
+    token: SecretString,
+}
+
+impl StaticTokenProvider {
+    /// Create a provider for a fixed access token.
+    pub fn new(token: SecretString) -> Self {
+        Self { token }
+    }
+}
+
+impl AccessTokenProvider for StaticTokenProvider {
+    fn access_token(&self) -> Result<SecretString, GeminiError> {
+        Ok(self.token.clone())
+    }
+}
+
+/// Bearer token authentication manager for Vertex AI.
+#[derive(Clone)]
+pub struct VertexOAuthManager {
+    token_provider: Arc<dyn AccessTokenProvider>,
+}
+
+impl VertexOAuthManager {
+    /// Create a new Vertex AI auth manager.
+    pub fn new(token_provider: Arc<dyn AccessTokenProvider>) -> Self {
+        Self { token_provider }
+    }
+
+    /// Create from config.
+    pub fn from_config(config: &GeminiConfig) -> Result<Self, GeminiError> {
+        let token_provider = config.access_token_provider.clone().ok_or_else(|| {
+            ConfigurationError::InvalidConfiguration {
+                message: "Vertex AI mode requires an access token provider".to_string(),
+            }
+        })?;
+        Ok(Self::new(token_provider))
+    }
+}
+
+impl AuthManager for VertexOAuthManager {
+    fn get_auth_header(&self) -> Result<Option<(String, String)>, GeminiError> {
+        let token = self.token_provider.access_token()?;
+        Ok(Some((
+            "Authorization".to_string(),
+            format!("Bearer {}", token.expose_secret()),
+        )))
+    }
+
+    fn get_auth_query_param(&self) -> Option<(String, String)> {
+        None
+    }
+
+    fn clone_box(&self) -> Box<dyn AuthManager> {
+        Box::new(self.clone())
+    }
+}
+
+/// Create the auth manager matching the configured authentication method.
+pub fn create_auth_manager(config: &GeminiConfig) -> Result<Arc<dyn AuthManager>, GeminiError> {
+    match config.auth_method {
+        AuthMethod::VertexOauth => Ok(Arc::new(VertexOAuthManager::from_config(config)?)),
+        AuthMethod::Header | AuthMethod::QueryParam => {
+            Ok(Arc::new(ApiKeyAuthManager::from_config(config)))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,7 +156,7 @@ mod tests {
             AuthMethod::Header,
         );
 
-        let header = manager.get_auth_header();
+        let header = manager.get_auth_header().unwrap();
         assert!(header.is_some());
         let (name, value) = header.unwrap();
         assert_eq!(name, "x-goog-api-key");
@@ -89,7 +172,7 @@ mod tests {
             AuthMethod::QueryParam,
         );
 
-        assert!(manager.get_auth_header().is_none());
+        assert!(manager.get_auth_header().unwrap().is_none());
 
         let param = manager.get_auth_query_param();
         assert!(param.is_some());
@@ -97,4 +180,35 @@ mod tests {
         assert_eq!(name, "key");
         assert_eq!(value, "test-key");
     }
+
+    #[test]
+    fn test_vertex_bearer_auth() {
+        let manager = VertexOAuthManager::new(Arc::new(StaticTokenProvider::new(
+            SecretString::new("ya29.token".into()),
+        )));
+
+        let (name, value) = manager.get_auth_header().unwrap().unwrap();
+        assert_eq!(name, "Authorization");
+        assert_eq!(value, "Bearer ya29.token");
+
+        assert!(manager.get_auth_query_param().is_none());
+    }
+
+    struct FailingTokenProvider;
+
+    impl AccessTokenProvider for FailingTokenProvider {
+        fn access_token(&self) -> Result<SecretString, GeminiError> {
+            Err(ConfigurationError::InvalidConfiguration {
+                message: "no credentials".to_string(),
+            }
+            .into())
+        }
+    }
+
+    #[test]
+    fn test_vertex_token_failure_is_propagated() {
+        let manager = VertexOAuthManager::new(Arc::new(FailingTokenProvider));
+
+        assert!(manager.get_auth_header().is_err());
+    }
 }
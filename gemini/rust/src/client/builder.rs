@@ -5,8 +5,11 @@ use std::sync::Arc;
 use std::time::Duration;
 use url::Url;
 
-use crate::auth::{ApiKeyAuthManager, AuthManager};
-use crate::config::{AuthMethod, GeminiConfig, DEFAULT_API_VERSION, DEFAULT_BASE_URL, DEFAULT_TIMEOUT_SECS};
+use crate::auth::{create_auth_manager, AccessTokenProvider, AuthManager};
+use crate::config::{
+    AuthMethod, GeminiConfig, VertexConfig, DEFAULT_API_VERSION, DEFAULT_BASE_URL,
+    DEFAULT_TIMEOUT_SECS, DEFAULT_VERTEX_API_VERSION,
+};
 use crate::error::{ConfigurationError, GeminiError};
 use crate::observability::{DefaultLogger, DefaultMetricsRecorder, DefaultTracer, Logger, MetricsRecorder, Tracer};
 use crate::resilience::{ResilienceConfig, ResilienceOrchestrator};
@@ -41,6 +44,8 @@ pub struct GeminiClientBuilder {
     connect_timeout: Option<Duration>,
     max_retries: Option<u32>,
     auth_method: Option<AuthMethod>,
+    vertex: Option<VertexConfig>,
+    access_token_provider: Option<Arc<dyn AccessTokenProvider>>,
     resilience_config: Option<ResilienceConfig>,
 
     // Injectable dependencies for testing
@@ -61,6 +66,8 @@ impl GeminiClientBuilder {
             connect_timeout: None,
             max_retries: None,
             auth_method: None,
+            vertex: None,
+            access_token_provider: None,
             resilience_config: None,
             transport: None,
             logger: None,
@@ -79,6 +86,8 @@ impl GeminiClientBuilder {
             connect_timeout: Some(config.connect_timeout),
             max_retries: Some(config.max_retries),
             auth_method: Some(config.auth_method),
+            vertex: config.vertex.clone(),
+            access_token_provider: config.access_token_provider.clone(),
             resilience_config: None,
             transport: None,
             logger: None,
@@ -135,6 +144,22 @@ impl GeminiClientBuilder {
         self
     }
 
+    /// Targets Vertex AI in the given project and location.
+    ///
+    /// Requests are authenticated with bearer tokens from the
+    /// [`access_token_provider`](Self::access_token_provider) instead of an API key.
+    pub fn vertex(mut self, project: impl Into<String>, location: impl Into<String>) -> Self {
+        self.vertex = Some(VertexConfig::new(project, location));
+        self.auth_method = Some(AuthMethod::VertexOauth);
+        self
+    }
+
+    /// Sets the access token provider used for Vertex AI.
+    pub fn access_token_provider(mut self, provider: Arc<dyn AccessTokenProvider>) -> Self {
+        self.access_token_provider = Some(provider);
+        self
+    }
+
     /// Sets the resilience configuration.
     pub fn resilience_config(mut self, config: ResilienceConfig) -> Self {
         self.resilience_config = Some(config);
@@ -171,9 +196,12 @@ impl GeminiClientBuilder {
     ///
     /// Returns an error if:
     /// - API key is not provided and not found in environment variables
+    /// - Vertex AI is targeted without an access token provider
     /// - Invalid configuration values
     /// - Failed to create HTTP transport
     pub fn build(self) -> Result<GeminiClientImpl, GeminiError> {
+        let auth_method = self.auth_method.unwrap_or(AuthMethod::Header);
+
         // Resolve API key from multiple sources (order: explicit -> GEMINI_API_KEY -> GOOGLE_API_KEY)
         let api_key = self.api_key
             .or_else(|| {
@@ -181,15 +209,28 @@ impl GeminiClientBuilder {
                     .or_else(|_| std::env::var("GOOGLE_API_KEY"))
                     .ok()
                     .map(|s| SecretString::new(s.into()))
-            })
-            .ok_or(ConfigurationError::MissingApiKey)?;
+            });
+        let api_key = match api_key {
+            Some(key) => key,
+            // Vertex AI authenticates with access tokens instead
+            None if auth_method == AuthMethod::VertexOauth => SecretString::new(String::new()),
+            None => return Err(ConfigurationError::MissingApiKey.into()),
+        };
 
         // Build configuration with defaults
-        let base_url = self.base_url
-            .unwrap_or_else(|| Url::parse(DEFAULT_BASE_URL).expect("Default URL is valid"));
+        let base_url = match (self.base_url, &self.vertex) {
+            (Some(url), _) => url,
+            (None, Some(vertex)) if auth_method == AuthMethod::VertexOauth => vertex.base_url()?,
+            (None, _) => Url::parse(DEFAULT_BASE_URL).expect("Default URL is valid"),
+        };
 
-        let api_version = self.api_version
-            .unwrap_or_else(|| DEFAULT_API_VERSION.to_string());
+        let api_version = self.api_version.unwrap_or_else(|| {
+            if auth_method == AuthMethod::VertexOauth {
+                DEFAULT_VERTEX_API_VERSION.to_string()
+            } else {
+                DEFAULT_API_VERSION.to_string()
+            }
+        });
 
         let timeout = self.timeout
             .unwrap_or_else(|| Duration::from_secs(DEFAULT_TIMEOUT_SECS));
@@ -199,18 +240,21 @@ impl GeminiClientBuilder {
 
         let max_retries = self.max_retries.unwrap_or(3);
 
-        let auth_method = self.auth_method.unwrap_or(AuthMethod::Header);
-
         // Build full configuration using GeminiConfig builder
-        let config = GeminiConfig::builder()
+        let mut config_builder = GeminiConfig::builder()
             .api_key(api_key.clone())
             .base_url(base_url.as_str())?
             .api_version(&api_version)
             .timeout(timeout)
             .connect_timeout(connect_timeout)
-            .max_retries(max_retries)
-            .auth_method(auth_method)
-            .build()?;
+            .max_retries(max_retries);
+        if let Some(vertex) = self.vertex {
+            config_builder = config_builder.vertex(vertex);
+        }
+        if let Some(provider) = self.access_token_provider {
+            config_builder = config_builder.access_token_provider(provider);
+        }
+        let config = config_builder.auth_method(auth_method).build()?;
 
         // Create transport
         let transport: Arc<dyn HttpTransport> = match self.transport {
@@ -226,9 +270,7 @@ impl GeminiClientBuilder {
         };
 
         // Create auth manager
-        let auth_manager: Arc<dyn AuthManager> = Arc::new(
-            ApiKeyAuthManager::from_config(&config)
-        );
+        let auth_manager: Arc<dyn AuthManager> = create_auth_manager(&config)?;
 
         // Create resilience orchestrator
         let resilience_config = self.resilience_config
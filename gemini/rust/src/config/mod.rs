@@ -1,8 +1,10 @@
 //! Configuration types for the Gemini API client.
 
 use secrecy::SecretString;
+use std::sync::Arc;
 use std::time::Duration;
 use url::Url;
+use crate::auth::AccessTokenProvider;
 use crate::error::{GeminiError, ConfigurationError};
 
 /// Default Gemini API base URL.
@@ -11,6 +13,9 @@ pub const DEFAULT_BASE_URL: &str = "https://generativelanguage.googleapis.com";
 /// Default API version.
 pub const DEFAULT_API_VERSION: &str = "v1beta";
 
+/// Default API version for Vertex AI endpoints.
+pub const DEFAULT_VERTEX_API_VERSION: &str = "v1";
+
 /// Default request timeout (120 seconds).
 pub const DEFAULT_TIMEOUT_SECS: u64 = 120;
 
@@ -28,6 +33,54 @@ pub enum AuthMethod {
     Header,
     /// Use ?key= query parameter.
     QueryParam,
+    /// Use an OAuth bearer token against a Vertex AI endpoint.
+    VertexOauth,
+}
+
+/// Path below the base URL for an endpoint, prefixing model paths when given.
+pub(crate) fn versioned_path(api_version: &str, model_prefix: Option<&str>, path: &str) -> String {
+    match model_prefix {
+        Some(prefix) if path == "models" || path.starts_with("models/") => {
+            format!("{}/{}/{}", api_version, prefix.trim_matches('/'), path)
+        }
+        _ => format!("{}/{}", api_version, path),
+    }
+}
+
+/// Vertex AI project and location.
+///
+/// In Vertex AI mode the client talks to `{location}-aiplatform.googleapis.com`
+/// and model requests are addressed as
+/// `projects/{project}/locations/{location}/publishers/google/models/{model}`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VertexConfig {
+    /// Google Cloud project ID.
+    pub project: String,
+    /// Vertex AI region (e.g., "us-central1").
+    pub location: String,
+}
+
+impl VertexConfig {
+    /// Create a new Vertex AI configuration.
+    pub fn new(project: impl Into<String>, location: impl Into<String>) -> Self {
+        Self {
+            project: project.into(),
+            location: location.into(),
+        }
+    }
+
+    /// Regional Vertex AI endpoint for this location.
+    pub fn base_url(&self) -> Result<Url, GeminiError> {
+        Ok(Url::parse(&format!("https://{}-aiplatform.googleapis.com", self.location))?)
+    }
+
+    /// Path prefix for Google publisher models in this project and location.
+    pub fn publisher_path(&self) -> String {
+        format!(
+            "/projects/{}/locations/{}/publishers/google",
+            self.project, self.location
+        )
+    }
 }
 
 /// Log level for the client.
@@ -118,7 +171,7 @@ impl Default for RateLimitConfig {
 /// Configuration for the Gemini client.
 #[derive(Clone)]
 pub struct GeminiConfig {
-    /// API key (required).
+    /// API key (required unless using Vertex AI).
     pub api_key: SecretString,
     /// Base URL for the API.
     pub base_url: Url,
@@ -150,6 +203,10 @@ pub struct GeminiConfig {
     pub log_level: LogLevel,
     /// Authentication method.
     pub auth_method: AuthMethod,
+    /// Vertex AI project and location, when targeting Vertex AI.
+    pub vertex: Option<VertexConfig>,
+    /// Access token provider for Vertex AI OAuth.
+    pub access_token_provider: Option<Arc<dyn AccessTokenProvider>>,
 }
 
 impl GeminiConfig {
@@ -158,6 +215,38 @@ impl GeminiConfig {
         GeminiConfigBuilder::default()
     }
 
+    /// Whether requests target Vertex AI rather than the Generative Language API.
+    pub fn is_vertex(&self) -> bool {
+        self.auth_method == AuthMethod::VertexOauth
+    }
+
+    /// Full URL of an endpoint path such as `models/gemini-pro:embedContent`.
+    ///
+    /// In Vertex AI mode model paths are rewritten to
+    /// `projects/{project}/locations/{location}/publishers/google/models/...`.
+    pub fn endpoint_url(&self, path: &str) -> String {
+        let prefix = match &self.vertex {
+            Some(vertex) if self.is_vertex() => Some(vertex.publisher_path()),
+            _ => None,
+        };
+        format!(
+            "{}/{}",
+            self.base_url.as_str().trim_end_matches('/'),
+            versioned_path(&self.api_version, prefix.as_deref(), path.trim_start_matches('/'))
+        )
+    }
+
+    /// Fail in Vertex AI mode for an API only the Generative Language API offers.
+    pub fn require_generative_language_api(&self, api: &str) -> Result<(), GeminiError> {
+        if self.is_vertex() {
+            return Err(ConfigurationError::InvalidConfiguration {
+                message: format!("The {} API is not available in Vertex AI mode", api),
+            }
+            .into());
+        }
+        Ok(())
+    }
+
     /// Create configuration from environment variables.
     pub fn from_env() -> Result<Self, GeminiError> {
         let api_key = std::env::var("GEMINI_API_KEY")
@@ -209,6 +298,8 @@ pub struct GeminiConfigBuilder {
     enable_metrics: Option<bool>,
     log_level: Option<LogLevel>,
     auth_method: Option<AuthMethod>,
+    vertex: Option<VertexConfig>,
+    access_token_provider: Option<Arc<dyn AccessTokenProvider>>,
 }
 
 impl GeminiConfigBuilder {
@@ -308,18 +399,67 @@ impl GeminiConfigBuilder {
         self
     }
 
+    /// Target Vertex AI in the given project and location.
+    ///
+    /// This also switches the authentication method to
+    /// [`AuthMethod::VertexOauth`].
+    pub fn vertex(mut self, vertex: VertexConfig) -> Self {
+        self.vertex = Some(vertex);
+        self.auth_method = Some(AuthMethod::VertexOauth);
+        self
+    }
+
+    /// Set the access token provider used for Vertex AI OAuth.
+    pub fn access_token_provider(mut self, provider: Arc<dyn AccessTokenProvider>) -> Self {
+        self.access_token_provider = Some(provider);
+        self
+    }
+
     /// Build the configuration.
     pub fn build(self) -> Result<GeminiConfig, GeminiError> {
-        let api_key = self.api_key
-            .ok_or(ConfigurationError::MissingApiKey)?;
-
-        let base_url = self.base_url
-            .unwrap_or_else(|| Url::parse(DEFAULT_BASE_URL).unwrap());
+        let auth_method = self.auth_method.unwrap_or_default();
+
+        let (api_key, base_url, api_version) = if auth_method == AuthMethod::VertexOauth {
+            let vertex = self.vertex.as_ref().ok_or_else(|| {
+                ConfigurationError::InvalidConfiguration {
+                    message: "Vertex AI mode requires a project and location".to_string(),
+                }
+            })?;
+            if self.access_token_provider.is_none() {
+                return Err(ConfigurationError::InvalidConfiguration {
+                    message: "Vertex AI mode requires an access token provider".to_string(),
+                }
+                .into());
+            }
+
+            let base_url = match self.base_url {
+                Some(url) => url,
+                None => vertex.base_url()?,
+            };
+
+            (
+                self.api_key.unwrap_or_else(|| SecretString::new(String::new())),
+                base_url,
+                self.api_version.unwrap_or_else(|| DEFAULT_VERTEX_API_VERSION.to_string()),
+            )
+        } else {
+            let api_key = self.api_key
+                .ok_or(ConfigurationError::MissingApiKey)?;
+
+            let base_url = self.base_url
+                .unwrap_or_else(|| Url::parse(DEFAULT_BASE_URL).unwrap());
+
+            (
+                api_key,
+                base_url,
+                self.api_version.unwrap_or_else(|| DEFAULT_API_VERSION.to_string()),
+            )
+        };
 
         Ok(GeminiConfig {
             api_key,
             base_url,
-            api_version: self.api_version.unwrap_or_else(|| DEFAULT_API_VERSION.to_string()),
+            api_version,
             timeout: self.timeout.unwrap_or(Duration::from_secs(DEFAULT_TIMEOUT_SECS)),
             connect_timeout: self.connect_timeout.unwrap_or(Duration::from_secs(DEFAULT_CONNECT_TIMEOUT_SECS)),
             max_retries: self.max_retries.unwrap_or(DEFAULT_MAX_RETRIES),
@@ -332,7 +472,9 @@ impl GeminiConfigBuilder {
             enable_tracing: self.enable_tracing.unwrap_or(true),
             enable_metrics: self.enable_metrics.unwrap_or(true),
             log_level: self.log_level.unwrap_or_default(),
-            auth_method: self.auth_method.unwrap_or_default(),
+            auth_method,
+            vertex: self.vertex,
+            access_token_provider: self.access_token_provider,
         })
     }
 }
@@ -340,6 +482,7 @@ impl GeminiConfigBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::auth::StaticTokenProvider;
     use secrecy::ExposeSecret;
 
     #[test]
@@ -376,4 +519,69 @@ mod tests {
         let result = GeminiConfig::builder().build();
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_vertex_config() {
+        let config = GeminiConfig::builder()
+            .vertex(VertexConfig::new("my-project", "us-central1"))
+            .access_token_provider(Arc::new(StaticTokenProvider::new(
+                SecretString::new("ya29.token".into()),
+            )))
+            .build()
+            .unwrap();
+
+        assert!(config.is_vertex());
+        assert_eq!(config.base_url.as_str(), "https://us-central1-aiplatform.googleapis.com/");
+        assert_eq!(config.api_version, "v1");
+        assert!(config.api_key.expose_secret().is_empty());
+    }
+
+    #[test]
+    fn test_vertex_config_requires_token_provider() {
+        let result = GeminiConfig::builder()
+            .vertex(VertexConfig::new("my-project", "us-central1"))
+            .build();
+        assert!(matches!(
+            result,
+            Err(GeminiError::Configuration(ConfigurationError::InvalidConfiguration { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_endpoint_url() {
+        let config = GeminiConfig::builder()
+            .api_key(SecretString::new("test-key".into()))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            config.endpoint_url("models/text-embedding-004:embedContent"),
+            "https://generativelanguage.googleapis.com/v1beta/models/text-embedding-004:embedContent"
+        );
+        assert_eq!(
+            config.endpoint_url("files"),
+            "https://generativelanguage.googleapis.com/v1beta/files"
+        );
+        assert!(config.require_generative_language_api("Files").is_ok());
+    }
+
+    #[test]
+    fn test_endpoint_url_for_vertex() {
+        let config = GeminiConfig::builder()
+            .vertex(VertexConfig::new("my-project", "us-central1"))
+            .access_token_provider(Arc::new(StaticTokenProvider::new(
+                SecretString::new("ya29.token".into()),
+            )))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            config.endpoint_url("models"),
+            "https://us-central1-aiplatform.googleapis.com/v1/projects/my-project/locations/us-central1/publishers/google/models"
+        );
+        assert!(matches!(
+            config.require_generative_language_api("Files"),
+            Err(GeminiError::Configuration(ConfigurationError::InvalidConfiguration { .. }))
+        ));
+    }
 }
@@ -126,6 +126,12 @@ pub enum ResponseError {
 /// Content safety errors.
 #[derive(Error, Debug, Clone)]
 pub enum ContentError {
+    #[error("Prompt blocked: {block_reason}")]
+    PromptBlocked {
+        block_reason: String,
+        safety_ratings: Vec<SafetyRatingInfo>,
+    },
+
     #[error("Content blocked due to safety: {reason:?}")]
     SafetyBlocked {
         reason: String,
@@ -144,6 +150,29 @@ pub enum ContentError {
     UnsupportedContent { mime_type: String },
 }
 
+impl ContentError {
+    /// Returns true if the request itself was rejected via prompt feedback,
+    /// as opposed to a generated candidate being blocked.
+    pub fn is_prompt_block(&self) -> bool {
+        matches!(self, ContentError::PromptBlocked { .. })
+    }
+
+    /// Safety ratings attached to the block, most severe first.
+    pub fn safety_ratings(&self) -> &[SafetyRatingInfo] {
+        match self {
+            ContentError::PromptBlocked { safety_ratings, .. }
+            | ContentError::SafetyBlocked { safety_ratings, .. }
+            | ContentError::RecitationBlocked { safety_ratings } => safety_ratings,
+            _ => &[],
+        }
+    }
+
+    /// Harm category of the rating that triggered the block, if known.
+    pub fn blocked_category(&self) -> Option<&str> {
+        self.safety_ratings().first().map(|r| r.category.as_str())
+    }
+}
+
 /// Information about a safety rating for error reporting.
 #[derive(Debug, Clone)]
 pub struct SafetyRatingInfo {
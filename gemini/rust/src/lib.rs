@@ -68,15 +68,19 @@ pub mod mocks;
 pub mod fixtures;
 
 // Re-exports for convenience
-pub use auth::{ApiKeyAuthManager, AuthManager};
+pub use auth::{
+    create_auth_manager, AccessTokenProvider, ApiKeyAuthManager, AuthManager,
+    StaticTokenProvider, VertexOAuthManager,
+};
 pub use client::{
     create_client, create_client_from_env,
     GeminiClient, GeminiClientFactory, GeminiClientImpl, GeminiClientBuilder
 };
 pub use config::{
     AuthMethod, CircuitBreakerConfig, GeminiConfig, GeminiConfigBuilder, LogLevel,
-    RateLimitConfig, RetryConfig, DEFAULT_API_VERSION, DEFAULT_BASE_URL,
+    RateLimitConfig, RetryConfig, VertexConfig, DEFAULT_API_VERSION, DEFAULT_BASE_URL,
     DEFAULT_CONNECT_TIMEOUT_SECS, DEFAULT_MAX_RETRIES, DEFAULT_TIMEOUT_SECS,
+    DEFAULT_VERTEX_API_VERSION,
 };
 pub use error::{
     // Main error types
@@ -105,14 +109,14 @@ pub use transport::{
 // Type re-exports
 pub use types::{
     // Content types
-    Blob, Content, Part, Role,
+    Blob, Content, ContentBuilder, Part, Role,
     FileData, FunctionCall, FunctionResponse,
     ExecutableCode, CodeExecutionResult,
     // Safety types
     HarmBlockThreshold, HarmCategory, HarmProbability, SafetyRating, SafetySetting,
     // Generation types
     GenerationConfig, FinishReason, UsageMetadata,
-    Candidate, CitationMetadata, CitationSource, GroundingMetadata,
+    Candidate, CitationMetadata, CitationSource, GroundingMetadata, GroundingSource,
     // Tool types
     Tool, ToolConfig, FunctionDeclaration, FunctionCallingConfig, FunctionCallingMode,
     CodeExecution, GoogleSearchRetrieval,
@@ -126,8 +130,9 @@ pub use types::{
     Model, ListModelsParams, ListModelsResponse,
     // File types
     File, FileState, UploadFileRequest, ListFilesParams, ListFilesResponse,
+    ResumableUpload, UploadProgress, UploadStatus,
     // Cached content types
-    CachedContent, CachedContentUsageMetadata,
+    CacheRefreshPolicy, CachedContent, CachedContentUsageMetadata,
     CreateCachedContentRequest, UpdateCachedContentRequest,
     ListCachedContentsParams, ListCachedContentsResponse,
 };
@@ -11,6 +11,7 @@ use futures::stream;
 
 use crate::transport::{HttpTransport, HttpRequest, HttpResponse, HttpMethod, ChunkedStream, TransportError};
 use crate::auth::AuthManager;
+use crate::error::GeminiError;
 
 /// Mock HTTP transport for testing.
 ///
@@ -206,7 +207,7 @@ impl HttpTransport for MockHttpTransport {
 /// use gemini_rust::auth::AuthManager;
 ///
 /// let auth = MockAuthManager::new("test-api-key");
-/// let header = auth.get_auth_header();
+/// let header = auth.get_auth_header().unwrap();
 /// assert_eq!(header, Some(("x-goog-api-key".to_string(), "test-api-key".to_string())));
 /// ```
 #[derive(Clone)]
@@ -235,11 +236,11 @@ impl MockAuthManager {
 }
 
 impl AuthManager for MockAuthManager {
-    fn get_auth_header(&self) -> Option<(String, String)> {
+    fn get_auth_header(&self) -> Result<Option<(String, String)>, GeminiError> {
         if self.use_header {
-            Some(("x-goog-api-key".to_string(), self.api_key.clone()))
+            Ok(Some(("x-goog-api-key".to_string(), self.api_key.clone())))
         } else {
-            None
+            Ok(None)
         }
     }
 
@@ -359,7 +360,7 @@ mod tests {
     fn test_mock_auth_manager_header() {
         let auth = MockAuthManager::new("test-key");
 
-        let header = auth.get_auth_header();
+        let header = auth.get_auth_header().unwrap();
         assert!(header.is_some());
         let (name, value) = header.unwrap();
         assert_eq!(name, "x-goog-api-key");
@@ -372,7 +373,7 @@ mod tests {
     fn test_mock_auth_manager_query_param() {
         let auth = MockAuthManager::with_query_param("test-key");
 
-        assert!(auth.get_auth_header().is_none());
+        assert!(auth.get_auth_header().unwrap().is_none());
 
         let param = auth.get_auth_query_param();
         assert!(param.is_some());
@@ -4,10 +4,11 @@ mod service;
 mod validation;
 
 use async_trait::async_trait;
-use crate::error::GeminiError;
+use std::time::SystemTime;
+use crate::error::{GeminiError, RequestError, ValidationDetail};
 use crate::types::{
-    CachedContent, CreateCachedContentRequest, UpdateCachedContentRequest,
-    ListCachedContentsParams, ListCachedContentsResponse,
+    CacheRefreshPolicy, CachedContent, CreateCachedContentRequest, GenerateContentRequest,
+    UpdateCachedContentRequest, ListCachedContentsParams, ListCachedContentsResponse,
 };
 
 pub use service::CachedContentServiceImpl;
@@ -40,4 +41,44 @@ pub trait CachedContentService: Send + Sync {
 
     /// Delete cached content.
     async fn delete(&self, name: &str) -> Result<(), GeminiError>;
+
+    /// Attach cached content to a generate request for `model`.
+    ///
+    /// Fails with a validation error if the cache was created for a
+    /// different model. If the cache expires within
+    /// `policy.refresh_within`, its TTL is reset to `policy.ttl` before the
+    /// request is returned.
+    async fn prepare_for_generate(
+        &self,
+        name: &str,
+        model: &str,
+        request: GenerateContentRequest,
+        policy: CacheRefreshPolicy,
+    ) -> Result<GenerateContentRequest, GeminiError> {
+        let mut cached = self.get(name).await?;
+
+        if !cached.matches_model(model) {
+            let description = format!(
+                "Cached content {} was created for {}, not {}",
+                name, cached.model, model
+            );
+            return Err(GeminiError::Request(RequestError::ValidationError {
+                message: description.clone(),
+                details: vec![ValidationDetail {
+                    field: "cachedContent".to_string(),
+                    description,
+                }],
+            }));
+        }
+
+        if cached.expires_within(policy.refresh_within, SystemTime::now()) {
+            let update = UpdateCachedContentRequest {
+                ttl: Some(format!("{}s", policy.ttl.as_secs())),
+                expire_time: None,
+            };
+            cached = self.update(name, update).await?;
+        }
+
+        Ok(request.with_cached_content(cached.name.as_deref().unwrap_or(name)))
+    }
 }
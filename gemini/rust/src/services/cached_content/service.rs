@@ -37,35 +37,28 @@ impl CachedContentServiceImpl {
     }
 
     /// Build the base URL for cached contents.
-    fn build_base_url(&self) -> String {
-        format!(
-            "{}/{}/cachedContents",
-            self.config.base_url,
-            self.config.api_version
-        )
+    fn build_base_url(&self) -> Result<String, GeminiError> {
+        self.config.require_generative_language_api("cached content")?;
+        Ok(self.config.endpoint_url("cachedContents"))
     }
 
     /// Build the URL for a specific cached content.
-    fn build_cached_content_url(&self, name: &str) -> String {
-        format!(
-            "{}/{}/{}",
-            self.config.base_url,
-            self.config.api_version,
-            name
-        )
+    fn build_cached_content_url(&self, name: &str) -> Result<String, GeminiError> {
+        self.config.require_generative_language_api("cached content")?;
+        Ok(self.config.endpoint_url(name))
     }
 
     /// Build headers for the request.
-    fn build_headers(&self) -> HashMap<String, String> {
+    fn build_headers(&self) -> Result<HashMap<String, String>, GeminiError> {
         let mut headers = HashMap::new();
         headers.insert("Content-Type".to_string(), "application/json".to_string());
 
         // Add authentication
-        if let Some((name, value)) = self.auth_manager.get_auth_header() {
+        if let Some((name, value)) = self.auth_manager.get_auth_header()? {
             headers.insert(name, value);
         }
 
-        headers
+        Ok(headers)
     }
 
     /// Add auth and pagination query params to URL.
@@ -178,11 +171,11 @@ impl CachedContentService for CachedContentServiceImpl {
         validate_create_request(&request)?;
 
         // Build URL
-        let url = self.build_base_url();
+        let url = self.build_base_url()?;
         let url = self.add_query_params(url, None);
 
         // Build headers
-        let headers = self.build_headers();
+        let headers = self.build_headers()?;
 
         // Serialize request body
         let body_json = serde_json::to_vec(&request)
@@ -221,11 +214,11 @@ impl CachedContentService for CachedContentServiceImpl {
         params: Option<ListCachedContentsParams>,
     ) -> Result<ListCachedContentsResponse, GeminiError> {
         // Build URL
-        let url = self.build_base_url();
+        let url = self.build_base_url()?;
         let url = self.add_query_params(url, params.as_ref());
 
         // Build headers
-        let headers = self.build_headers();
+        let headers = self.build_headers()?;
 
         // Create HTTP request
         let http_request = HttpRequest {
@@ -263,11 +256,11 @@ impl CachedContentService for CachedContentServiceImpl {
             format!("cachedContents/{}", name)
         };
 
-        let url = self.build_cached_content_url(&resource_name);
+        let url = self.build_cached_content_url(&resource_name)?;
         let url = self.add_query_params(url, None);
 
         // Build headers
-        let headers = self.build_headers();
+        let headers = self.build_headers()?;
 
         // Create HTTP request
         let http_request = HttpRequest {
@@ -310,11 +303,11 @@ impl CachedContentService for CachedContentServiceImpl {
             format!("cachedContents/{}", name)
         };
 
-        let url = self.build_cached_content_url(&resource_name);
+        let url = self.build_cached_content_url(&resource_name)?;
         let url = self.add_update_mask(url, &request);
 
         // Build headers
-        let headers = self.build_headers();
+        let headers = self.build_headers()?;
 
         // Serialize request body
         let body_json = serde_json::to_vec(&request)
@@ -359,11 +352,11 @@ impl CachedContentService for CachedContentServiceImpl {
             format!("cachedContents/{}", name)
         };
 
-        let url = self.build_cached_content_url(&resource_name);
+        let url = self.build_cached_content_url(&resource_name)?;
         let url = self.add_query_params(url, None);
 
         // Build headers
-        let headers = self.build_headers();
+        let headers = self.build_headers()?;
 
         // Create HTTP request
         let http_request = HttpRequest {
@@ -13,6 +13,7 @@ use std::pin::Pin;
 use futures::Stream;
 
 pub use service::ContentServiceImpl;
+pub use validation::{known_input_token_limit, validate_token_budget};
 
 /// Type alias for the content stream.
 pub type ContentStream = Pin<Box<dyn Stream<Item = Result<GenerateContentResponse, GeminiError>> + Send>>;
@@ -27,6 +28,19 @@ pub trait ContentService: Send + Sync {
         request: GenerateContentRequest,
     ) -> Result<GenerateContentResponse, GeminiError>;
 
+    /// Generate content, returning blocked responses instead of failing.
+    ///
+    /// `generate` fails with a `ContentError` when the prompt is rejected
+    /// (`PromptBlocked`) or a candidate finishes for safety, recitation or
+    /// prohibited content. This variant returns the raw response in those
+    /// cases so callers can inspect the prompt feedback and candidates
+    /// themselves.
+    async fn generate_allow_blocked(
+        &self,
+        model: &str,
+        request: GenerateContentRequest,
+    ) -> Result<GenerateContentResponse, GeminiError>;
+
     /// Generate content with streaming response.
     async fn generate_stream(
         &self,
@@ -40,4 +54,18 @@ pub trait ContentService: Send + Sync {
         model: &str,
         request: CountTokensRequest,
     ) -> Result<CountTokensResponse, GeminiError>;
+
+    /// Generate content only if the request fits an input token budget.
+    ///
+    /// The whole request (contents including inline and file parts, system
+    /// instruction and tools) is counted with `count_tokens` first and
+    /// compared against `max_input_tokens` and the model's known input limit.
+    /// Requests over the limit fail with a validation error without being
+    /// sent.
+    async fn generate_within_budget(
+        &self,
+        model: &str,
+        request: GenerateContentRequest,
+        max_input_tokens: i32,
+    ) -> Result<GenerateContentResponse, GeminiError>;
 }
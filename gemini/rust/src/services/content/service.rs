@@ -1,13 +1,18 @@
 //! Content service implementation for generating and streaming content with Gemini models.
 
-use std::sync::Arc;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 use async_trait::async_trait;
 use bytes::Bytes;
 use serde_json::json;
 
 use super::{ContentService, ContentStream};
-use super::validation::{validate_generate_request, validate_count_tokens_request};
+use super::validation::{
+    validate_generate_request, validate_count_tokens_request, validate_token_budget,
+};
 use crate::streaming::GeminiChunkParser;
 use crate::auth::AuthManager;
 use crate::config::GeminiConfig;
@@ -18,7 +23,7 @@ use crate::types::{
     GenerateContentRequest, GenerateContentResponse,
     CountTokensRequest, CountTokensResponse,
     GenerationConfig, SafetySetting, FinishReason, BlockReason,
-    PromptFeedback, Candidate,
+    PromptFeedback, Candidate, SafetyRating, HarmProbability,
 };
 
 /// Implementation of the ContentService.
@@ -33,8 +38,13 @@ pub struct ContentServiceImpl {
     logger: Box<dyn Logger>,
     tracer: Box<dyn Tracer>,
     metrics: GeminiMetrics,
+    /// Token counts of requests already checked against a budget
+    token_count_cache: Mutex<HashMap<u64, i32>>,
 }
 
+/// Maximum number of cached token counts before the cache is cleared.
+const TOKEN_COUNT_CACHE_CAPACITY: usize = 1024;
+
 impl ContentServiceImpl {
     /// Create a new content service implementation.
     pub fn new(
@@ -46,11 +56,7 @@ impl ContentServiceImpl {
         metrics: GeminiMetrics,
     ) -> Self {
         // Clone the auth_manager for the request builder
-        let request_builder = RequestBuilder::new(
-            config.base_url.clone(),
-            config.api_version.clone(),
-            auth_manager.clone_box(),
-        );
+        let request_builder = RequestBuilder::from_config(&config, auth_manager.clone_box());
 
         Self {
             config,
@@ -63,6 +69,7 @@ impl ContentServiceImpl {
             logger,
             tracer,
             metrics,
+            token_count_cache: Mutex::new(HashMap::new()),
         }
     }
 
@@ -125,59 +132,27 @@ impl ContentServiceImpl {
 
     /// Check for content safety blocks in the response.
     ///
-    /// This function examines both the prompt feedback and candidate responses
-    /// for any safety-related blocks. It never swallows errors and provides
-    /// detailed safety rating information when content is blocked.
+    /// A block reason in the prompt feedback means the request itself was
+    /// rejected and is reported as [`ContentError::PromptBlocked`]. Otherwise
+    /// candidates are checked for safety, recitation and prohibited-content
+    /// finish reasons. Safety ratings are reported most severe first, so the
+    /// first rating names the category that triggered the block.
     fn check_safety_blocks(&self, response: &GenerateContentResponse) -> Result<(), GeminiError> {
         // Check prompt feedback for blocks
         if let Some(prompt_feedback) = &response.prompt_feedback {
             if let Some(block_reason) = &prompt_feedback.block_reason {
-                return match block_reason {
-                    BlockReason::Safety => {
-                        // Convert safety ratings to our error format
-                        let safety_ratings = prompt_feedback.safety_ratings
-                            .as_ref()
-                            .map(|ratings| {
-                                ratings.iter().map(|r| crate::error::SafetyRatingInfo {
-                                    category: format!("{:?}", r.category),
-                                    probability: format!("{:?}", r.probability),
-                                }).collect()
-                            })
-                            .unwrap_or_default();
-
-                        // Get the primary reason from the first rating
-                        let reason = prompt_feedback.safety_ratings
-                            .as_ref()
-                            .and_then(|ratings| ratings.first())
-                            .map(|r| format!("Safety: {:?} ({})", r.category, format!("{:?}", r.probability)))
-                            .unwrap_or_else(|| "Safety (unspecified)".to_string());
-
-                        tracing::warn!(
-                            block_reason = ?block_reason,
-                            safety_ratings = ?safety_ratings,
-                            "Content blocked due to safety concerns in prompt"
-                        );
-
-                        Err(GeminiError::Content(ContentError::SafetyBlocked {
-                            reason,
-                            safety_ratings,
-                        }))
-                    }
-                    BlockReason::Blocklist | BlockReason::ProhibitedContent => {
-                        tracing::warn!(
-                            block_reason = ?block_reason,
-                            "Content blocked due to prohibited content in prompt"
-                        );
-                        Err(GeminiError::Content(ContentError::ProhibitedContent))
-                    }
-                    _ => {
-                        tracing::debug!(
-                            block_reason = ?block_reason,
-                            "Prompt blocked for non-safety reason"
-                        );
-                        Ok(())
-                    }
-                };
+                let safety_ratings = Self::safety_rating_infos(prompt_feedback.safety_ratings.as_deref());
+
+                tracing::warn!(
+                    block_reason = ?block_reason,
+                    safety_ratings = ?safety_ratings,
+                    "Prompt blocked"
+                );
+
+                return Err(GeminiError::Content(ContentError::PromptBlocked {
+                    block_reason: Self::block_reason_name(block_reason).to_string(),
+                    safety_ratings,
+                }));
             }
         }
 
@@ -187,22 +162,12 @@ impl ContentServiceImpl {
                 if let Some(finish_reason) = &candidate.finish_reason {
                     match finish_reason {
                         FinishReason::Safety => {
-                            // Convert safety ratings to our error format
-                            let safety_ratings = candidate.safety_ratings
-                                .as_ref()
-                                .map(|ratings| {
-                                    ratings.iter().map(|r| crate::error::SafetyRatingInfo {
-                                        category: format!("{:?}", r.category),
-                                        probability: format!("{:?}", r.probability),
-                                    }).collect()
-                                })
-                                .unwrap_or_default();
-
-                            // Get the primary reason from the first rating
-                            let reason = candidate.safety_ratings
-                                .as_ref()
-                                .and_then(|ratings| ratings.first())
-                                .map(|r| format!("Safety: {:?} ({})", r.category, format!("{:?}", r.probability)))
+                            let safety_ratings = Self::safety_rating_infos(candidate.safety_ratings.as_deref());
+
+                            // The most severe rating is the primary reason
+                            let reason = safety_ratings
+                                .first()
+                                .map(|r| format!("Safety: {} ({})", r.category, r.probability))
                                 .unwrap_or_else(|| "Safety (unspecified)".to_string());
 
                             tracing::warn!(
@@ -219,15 +184,7 @@ impl ContentServiceImpl {
                         }
                         FinishReason::Recitation => {
                             // Include safety ratings even for recitation blocks
-                            let safety_ratings = candidate.safety_ratings
-                                .as_ref()
-                                .map(|ratings| {
-                                    ratings.iter().map(|r| crate::error::SafetyRatingInfo {
-                                        category: format!("{:?}", r.category),
-                                        probability: format!("{:?}", r.probability),
-                                    }).collect()
-                                })
-                                .unwrap_or_default();
+                            let safety_ratings = Self::safety_rating_infos(candidate.safety_ratings.as_deref());
 
                             tracing::warn!(
                                 candidate_index = index,
@@ -256,32 +213,52 @@ impl ContentServiceImpl {
         Ok(())
     }
 
-    /// Log usage statistics from the response.
-    fn log_usage_statistics(&self, response: &GenerateContentResponse) {
-        if let Some(usage) = &response.usage_metadata {
-            tracing::info!(
-                prompt_tokens = usage.prompt_token_count,
-                completion_tokens = usage.candidates_token_count.unwrap_or(0),
-                total_tokens = usage.total_token_count,
-                cached_tokens = usage.cached_content_token_count.unwrap_or(0),
-                "Content generation usage"
-            );
+    /// Convert safety ratings to the error format, most severe first.
+    fn safety_rating_infos(ratings: Option<&[SafetyRating]>) -> Vec<crate::error::SafetyRatingInfo> {
+        let mut ratings: Vec<&SafetyRating> = ratings.unwrap_or_default().iter().collect();
+        ratings.sort_by_key(|r| std::cmp::Reverse(Self::probability_rank(&r.probability)));
+        ratings
+            .into_iter()
+            .map(|r| crate::error::SafetyRatingInfo {
+                category: format!("{:?}", r.category),
+                probability: format!("{:?}", r.probability),
+            })
+            .collect()
+    }
+
+    /// Severity order of a harm probability.
+    fn probability_rank(probability: &HarmProbability) -> u8 {
+        match probability {
+            HarmProbability::Negligible => 0,
+            HarmProbability::Low => 1,
+            HarmProbability::Medium => 2,
+            HarmProbability::High => 3,
         }
     }
-}
 
-#[async_trait]
-impl ContentService for ContentServiceImpl {
-    async fn generate(
+    /// Wire name of a prompt block reason.
+    fn block_reason_name(block_reason: &BlockReason) -> &'static str {
+        match block_reason {
+            BlockReason::BlockReasonUnspecified => "BLOCK_REASON_UNSPECIFIED",
+            BlockReason::Safety => "SAFETY",
+            BlockReason::Other => "OTHER",
+            BlockReason::Blocklist => "BLOCKLIST",
+            BlockReason::ProhibitedContent => "PROHIBITED_CONTENT",
+        }
+    }
+
+    /// Generate content, optionally returning blocked responses as-is.
+    async fn generate_checked(
         &self,
         model: &str,
         request: GenerateContentRequest,
+        allow_blocked: bool,
     ) -> Result<GenerateContentResponse, GeminiError> {
         // Start tracing span
         let mut span = self.tracer.start_span("gemini.content.generate");
         span.set_attribute("model", model);
         span.set_attribute("service", "content");
-        span.set_attribute("method", "generate");
+        span.set_attribute("method", if allow_blocked { "generate_allow_blocked" } else { "generate" });
 
         let start = Instant::now();
 
@@ -340,22 +317,31 @@ impl ContentService for ContentServiceImpl {
             let duration = start.elapsed();
 
             // Record safety block metrics
-            if let GeminiError::Content(ContentError::SafetyBlocked { reason, .. }) = &e {
-                self.metrics.record_safety_block("content", reason);
+            if let GeminiError::Content(content_error) = &e {
+                if let Some(category) = content_error.blocked_category() {
+                    self.metrics.record_safety_block("content", category);
+                }
             }
 
-            // Log and record error
-            self.logger.warn("Content generation blocked", json!({
-                "error": e.to_string(),
-                "model": model,
-                "duration_ms": duration.as_millis(),
-            }));
+            if allow_blocked {
+                self.logger.warn("Returning blocked content generation response", json!({
+                    "error": e.to_string(),
+                    "model": model,
+                }));
+            } else {
+                // Log and record error
+                self.logger.warn("Content generation blocked", json!({
+                    "error": e.to_string(),
+                    "model": model,
+                    "duration_ms": duration.as_millis(),
+                }));
 
-            span.set_status(SpanStatus::Error(e.to_string()));
-            self.metrics.record_request("content", "generate", status_code, duration.as_millis() as u64);
-            span.end();
+                span.set_status(SpanStatus::Error(e.to_string()));
+                self.metrics.record_request("content", "generate", status_code, duration.as_millis() as u64);
+                span.end();
 
-            return Err(e);
+                return Err(e);
+            }
         }
 
         let duration = start.elapsed();
@@ -399,6 +385,67 @@ impl ContentService for ContentServiceImpl {
         Ok(response)
     }
 
+    /// Build the countTokens body, naming the model inside a nested
+    /// generate request as the API requires.
+    fn count_tokens_body(
+        model: &str,
+        request: &CountTokensRequest,
+    ) -> Result<serde_json::Value, GeminiError> {
+        let mut body = serde_json::to_value(request)?;
+        if let Some(nested) = body
+            .get_mut("generateContentRequest")
+            .and_then(serde_json::Value::as_object_mut)
+        {
+            let name = if model.starts_with("models/") {
+                model.to_string()
+            } else {
+                format!("models/{}", model)
+            };
+            nested.entry("model").or_insert(json!(name));
+        }
+        Ok(body)
+    }
+
+    /// Key identifying a request's token count in the cache.
+    fn token_count_key(model: &str, request: &GenerateContentRequest) -> Result<u64, GeminiError> {
+        let mut hasher = DefaultHasher::new();
+        model.hash(&mut hasher);
+        serde_json::to_string(request)?.hash(&mut hasher);
+        Ok(hasher.finish())
+    }
+
+    /// Log usage statistics from the response.
+    fn log_usage_statistics(&self, response: &GenerateContentResponse) {
+        if let Some(usage) = &response.usage_metadata {
+            tracing::info!(
+                prompt_tokens = usage.prompt_token_count,
+                completion_tokens = usage.candidates_token_count.unwrap_or(0),
+                total_tokens = usage.total_token_count,
+                cached_tokens = usage.cached_content_token_count.unwrap_or(0),
+                "Content generation usage"
+            );
+        }
+    }
+}
+
+#[async_trait]
+impl ContentService for ContentServiceImpl {
+    async fn generate(
+        &self,
+        model: &str,
+        request: GenerateContentRequest,
+    ) -> Result<GenerateContentResponse, GeminiError> {
+        self.generate_checked(model, request, false).await
+    }
+
+    async fn generate_allow_blocked(
+        &self,
+        model: &str,
+        request: GenerateContentRequest,
+    ) -> Result<GenerateContentResponse, GeminiError> {
+        self.generate_checked(model, request, true).await
+    }
+
     async fn generate_stream(
         &self,
         model: &str,
@@ -506,10 +553,11 @@ impl ContentService for ContentServiceImpl {
         let path = endpoints::count_tokens(model);
 
         // 3. Build HTTP request
+        let body = Self::count_tokens_body(model, &request)?;
         let http_request = self.request_builder.build_request(
             HttpMethod::Post,
             &path,
-            Some(&request),
+            Some(&body),
             None,
         )?;
 
@@ -558,6 +606,50 @@ impl ContentService for ContentServiceImpl {
 
         Ok(response)
     }
+
+    async fn generate_within_budget(
+        &self,
+        model: &str,
+        request: GenerateContentRequest,
+        max_input_tokens: i32,
+    ) -> Result<GenerateContentResponse, GeminiError> {
+        // 1. Validate before spending a countTokens call
+        validate_generate_request(&request)?;
+
+        // 2. Count the full request, reusing a cached count when possible
+        let key = Self::token_count_key(model, &request)?;
+        let cached = self.token_count_cache.lock().ok().and_then(|cache| cache.get(&key).copied());
+        let input_tokens = match cached {
+            Some(tokens) => tokens,
+            None => {
+                let count_request = CountTokensRequest {
+                    contents: None,
+                    generate_content_request: Some(Box::new(request.clone())),
+                };
+                let tokens = self.count_tokens(model, count_request).await?.total_tokens;
+                if let Ok(mut cache) = self.token_count_cache.lock() {
+                    if cache.len() >= TOKEN_COUNT_CACHE_CAPACITY {
+                        cache.clear();
+                    }
+                    cache.insert(key, tokens);
+                }
+                tokens
+            }
+        };
+
+        // 3. Enforce the budget
+        if let Err(error) = validate_token_budget(model, input_tokens, max_input_tokens) {
+            self.logger.warn("Request exceeds input token budget", json!({
+                "model": model,
+                "input_tokens": input_tokens,
+                "max_input_tokens": max_input_tokens,
+            }));
+            return Err(error);
+        }
+
+        // 4. Generate
+        self.generate(model, request).await
+    }
 }
 
 #[cfg(test)]
@@ -312,6 +312,62 @@ pub fn validate_count_tokens_request(request: &CountTokensRequest) -> GeminiResu
     Ok(())
 }
 
+/// Known input token limits, matched by model name prefix.
+///
+/// More specific prefixes come first.
+const MODEL_INPUT_TOKEN_LIMITS: &[(&str, i32)] = &[
+    ("gemini-1.5-pro", 2_097_152),
+    ("gemini-1.5-flash", 1_048_576),
+    ("gemini-2.0-flash", 1_048_576),
+    ("gemini-2.5-pro", 1_048_576),
+    ("gemini-2.5-flash", 1_048_576),
+    ("gemini-1.0-pro", 30_720),
+    ("gemini-pro", 30_720),
+];
+
+/// Get the known input token limit for a model, if any.
+///
+/// Accepts both `gemini-1.5-pro` and `models/gemini-1.5-pro` forms.
+pub fn known_input_token_limit(model: &str) -> Option<i32> {
+    let name = model.strip_prefix("models/").unwrap_or(model);
+    MODEL_INPUT_TOKEN_LIMITS
+        .iter()
+        .find(|(prefix, _)| name.starts_with(prefix))
+        .map(|(_, limit)| *limit)
+}
+
+/// Validate a counted request against a token budget and the model's limit.
+pub fn validate_token_budget(
+    model: &str,
+    input_tokens: i32,
+    max_input_tokens: i32,
+) -> GeminiResult<()> {
+    let model_limit = known_input_token_limit(model);
+    let allowed = model_limit.map_or(max_input_tokens, |limit| limit.min(max_input_tokens));
+
+    if input_tokens <= allowed {
+        return Ok(());
+    }
+
+    let limit_source = match model_limit {
+        Some(limit) if limit < max_input_tokens => format!("the {} input limit", model),
+        _ => "the token budget".to_string(),
+    };
+
+    Err(GeminiError::Request(RequestError::ValidationError {
+        message: "Request exceeds input token budget".to_string(),
+        details: vec![ValidationDetail {
+            field: "contents".to_string(),
+            description: format!(
+                "Request has {} input tokens but {} allows {}",
+                input_tokens,
+                limit_source,
+                allowed
+            ),
+        }],
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
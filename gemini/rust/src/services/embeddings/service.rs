@@ -59,36 +59,28 @@ impl EmbeddingsServiceImpl {
     /// Build the URL for embed requests.
     fn build_embed_url(&self, model: &str) -> String {
         let normalized_model = self.normalize_model_name(model);
-        format!(
-            "{}/{}/{}:embedContent",
-            self.config.base_url,
-            self.config.api_version,
-            normalized_model
-        )
+        self.config
+            .endpoint_url(&format!("{}:embedContent", normalized_model))
     }
 
     /// Build the URL for batch embed requests.
     fn build_batch_embed_url(&self, model: &str) -> String {
         let normalized_model = self.normalize_model_name(model);
-        format!(
-            "{}/{}/{}:batchEmbedContents",
-            self.config.base_url,
-            self.config.api_version,
-            normalized_model
-        )
+        self.config
+            .endpoint_url(&format!("{}:batchEmbedContents", normalized_model))
     }
 
     /// Build headers for the request.
-    fn build_headers(&self) -> HashMap<String, String> {
+    fn build_headers(&self) -> Result<HashMap<String, String>, GeminiError> {
         let mut headers = HashMap::new();
         headers.insert("Content-Type".to_string(), "application/json".to_string());
 
         // Add authentication
-        if let Some((name, value)) = self.auth_manager.get_auth_header() {
+        if let Some((name, value)) = self.auth_manager.get_auth_header()? {
             headers.insert(name, value);
         }
 
-        headers
+        Ok(headers)
     }
 
     /// Add auth query param to URL if needed.
@@ -159,7 +151,7 @@ impl EmbeddingsService for EmbeddingsServiceImpl {
         let url = self.add_auth_to_url(url);
 
         // Build headers
-        let headers = self.build_headers();
+        let headers = self.build_headers()?;
 
         // Serialize request body
         let body_json = serde_json::to_vec(&request)
@@ -211,7 +203,7 @@ impl EmbeddingsService for EmbeddingsServiceImpl {
         let url = self.add_auth_to_url(url);
 
         // Build headers
-        let headers = self.build_headers();
+        let headers = self.build_headers()?;
 
         // Create batch request
         let batch_request = BatchEmbedContentsRequest { requests };
@@ -283,4 +275,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_build_embed_url_for_vertex() {
+        use crate::auth::{StaticTokenProvider, VertexOAuthManager};
+        use crate::config::VertexConfig;
+        use crate::transport::ReqwestTransport;
+        use secrecy::SecretString;
+
+        let config = Arc::new(GeminiConfig::builder()
+            .vertex(VertexConfig::new("my-project", "europe-west4"))
+            .access_token_provider(Arc::new(StaticTokenProvider::new(
+                SecretString::new("ya29.token".into()),
+            )))
+            .build()
+            .unwrap());
+        let transport = Arc::new(ReqwestTransport::new(&config).unwrap());
+        let auth = Arc::new(VertexOAuthManager::from_config(&config).unwrap());
+
+        let service = EmbeddingsServiceImpl::new(config, transport, auth);
+
+        assert_eq!(
+            service.build_embed_url("text-embedding-004"),
+            "https://europe-west4-aiplatform.googleapis.com/v1/projects/my-project/locations/europe-west4/publishers/google/models/text-embedding-004:embedContent"
+        );
+    }
+
 }
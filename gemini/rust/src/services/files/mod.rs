@@ -4,11 +4,18 @@ mod service;
 mod validation;
 
 use async_trait::async_trait;
+use bytes::Bytes;
+use std::path::Path;
 use crate::error::GeminiError;
-use crate::types::{File, UploadFileRequest, ListFilesParams, ListFilesResponse};
+use crate::types::{
+    File, UploadFileRequest, ListFilesParams, ListFilesResponse, ResumableUpload,
+    UploadProgress, UploadStatus,
+};
 
-pub use service::FilesServiceImpl;
-pub use validation::{validate_upload_request, validate_file_name, MAX_FILE_SIZE};
+pub use service::{FilesServiceImpl, RESUMABLE_CHUNK_GRANULARITY};
+pub use validation::{
+    validate_upload_request, validate_file_name, validate_resumable_upload, MAX_FILE_SIZE,
+};
 
 /// Service for file upload and management.
 #[async_trait]
@@ -27,4 +34,42 @@ pub trait FilesService: Send + Sync {
 
     /// Delete a file.
     async fn delete(&self, file_name: &str) -> Result<(), GeminiError>;
+
+    /// Start a resumable upload session by sending the file metadata.
+    async fn start_resumable_upload(
+        &self,
+        total_size: u64,
+        mime_type: &str,
+        display_name: Option<String>,
+    ) -> Result<ResumableUpload, GeminiError>;
+
+    /// Send the next chunk of a resumable upload.
+    ///
+    /// The chunk must start at `upload.committed`. On success
+    /// `upload.committed` is updated from the server's response, which may
+    /// be less than the bytes sent.
+    async fn upload_chunk(
+        &self,
+        upload: &mut ResumableUpload,
+        chunk: Bytes,
+    ) -> Result<UploadStatus, GeminiError>;
+
+    /// Ask the server how many bytes of a resumable upload it has committed.
+    async fn query_upload_status(
+        &self,
+        upload: &mut ResumableUpload,
+    ) -> Result<UploadStatus, GeminiError>;
+
+    /// Upload a file from disk in chunks, resuming after failed chunks.
+    ///
+    /// `chunk_size` must be a non-zero multiple of
+    /// [`RESUMABLE_CHUNK_GRANULARITY`]. `on_progress` is called after every
+    /// committed chunk.
+    async fn upload_file_resumable(
+        &self,
+        path: &Path,
+        mime_type: &str,
+        chunk_size: usize,
+        on_progress: &(dyn Fn(UploadProgress) + Send + Sync),
+    ) -> Result<File, GeminiError>;
 }
@@ -1,22 +1,35 @@
 //! Files service implementation for Gemini API.
 
 use super::FilesService;
-use super::validation::{validate_upload_request, validate_file_name, MAX_FILE_SIZE};
+use super::validation::{
+    validate_upload_request, validate_file_name, validate_resumable_upload, MAX_FILE_SIZE,
+};
 use crate::auth::AuthManager;
 use crate::config::GeminiConfig;
 use crate::error::{GeminiError, GeminiResult, RequestError, ResourceError};
-use crate::transport::{HttpTransport, HttpRequest, HttpMethod};
-use crate::types::{File, UploadFileRequest, ListFilesParams, ListFilesResponse, FileState};
+use crate::transport::{HttpTransport, HttpRequest, HttpMethod, HttpResponse};
+use crate::types::{
+    File, UploadFileRequest, ListFilesParams, ListFilesResponse, FileState, ResumableUpload,
+    UploadProgress, UploadStatus,
+};
 use async_trait::async_trait;
 use bytes::Bytes;
 use serde_json::json;
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
 
 /// Upload base URL - different from regular API base URL.
 const UPLOAD_BASE_URL: &str = "https://generativelanguage.googleapis.com/upload";
 
+/// Resumable upload chunks (except the last) must be a multiple of 256 KiB.
+pub const RESUMABLE_CHUNK_GRANULARITY: usize = 256 * 1024;
+
+/// Status code the upload server uses for "resume incomplete".
+const RESUME_INCOMPLETE: u16 = 308;
+
 /// Implementation of the Files service.
 pub struct FilesServiceImpl {
     config: Arc<GeminiConfig>,
@@ -39,48 +52,42 @@ impl FilesServiceImpl {
     }
 
     /// Build the upload URL.
-    fn build_upload_url(&self) -> String {
-        format!(
+    fn build_upload_url(&self) -> Result<String, GeminiError> {
+        self.config.require_generative_language_api("Files")?;
+        Ok(format!(
             "{}/{}/files",
             UPLOAD_BASE_URL,
             self.config.api_version
-        )
+        ))
     }
 
     /// Build the URL for file operations.
-    fn build_file_url(&self, file_name: &str) -> String {
-        format!(
-            "{}/{}/{}",
-            self.config.base_url,
-            self.config.api_version,
-            file_name
-        )
+    fn build_file_url(&self, file_name: &str) -> Result<String, GeminiError> {
+        self.config.require_generative_language_api("Files")?;
+        Ok(self.config.endpoint_url(file_name))
     }
 
     /// Build the list files URL.
-    fn build_list_url(&self) -> String {
-        format!(
-            "{}/{}/files",
-            self.config.base_url,
-            self.config.api_version
-        )
+    fn build_list_url(&self) -> Result<String, GeminiError> {
+        self.config.require_generative_language_api("Files")?;
+        Ok(self.config.endpoint_url("files"))
     }
 
     /// Build headers for JSON requests.
-    fn build_headers(&self) -> HashMap<String, String> {
+    fn build_headers(&self) -> Result<HashMap<String, String>, GeminiError> {
         let mut headers = HashMap::new();
         headers.insert("Content-Type".to_string(), "application/json".to_string());
 
         // Add authentication
-        if let Some((name, value)) = self.auth_manager.get_auth_header() {
+        if let Some((name, value)) = self.auth_manager.get_auth_header()? {
             headers.insert(name, value);
         }
 
-        headers
+        Ok(headers)
     }
 
     /// Build headers for multipart upload.
-    fn build_multipart_headers(&self, boundary: &str) -> HashMap<String, String> {
+    fn build_multipart_headers(&self, boundary: &str) -> Result<HashMap<String, String>, GeminiError> {
         let mut headers = HashMap::new();
         headers.insert(
             "Content-Type".to_string(),
@@ -89,11 +96,11 @@ impl FilesServiceImpl {
         headers.insert("X-Goog-Upload-Protocol".to_string(), "multipart".to_string());
 
         // Add authentication
-        if let Some((name, value)) = self.auth_manager.get_auth_header() {
+        if let Some((name, value)) = self.auth_manager.get_auth_header()? {
             headers.insert(name, value);
         }
 
-        headers
+        Ok(headers)
     }
 
     /// Add auth and pagination query params to URL.
@@ -203,6 +210,78 @@ impl FilesServiceImpl {
         })
     }
 
+    /// Build the URL that starts a resumable upload session.
+    fn build_resumable_start_url(&self) -> Result<String, GeminiError> {
+        let url = self.add_query_params(self.build_upload_url()?, None);
+        let separator = if url.contains('?') { '&' } else { '?' };
+        Ok(format!("{}{}uploadType=resumable", url, separator))
+    }
+
+    /// Build headers for a request against an upload session URL.
+    fn build_session_headers(&self, content_range: String) -> Result<HashMap<String, String>, GeminiError> {
+        let mut headers = HashMap::new();
+        headers.insert("Content-Range".to_string(), content_range);
+
+        // Add authentication
+        if let Some((name, value)) = self.auth_manager.get_auth_header()? {
+            headers.insert(name, value);
+        }
+
+        Ok(headers)
+    }
+
+    /// Send a request, mapping transport failures to network errors.
+    async fn send_request(&self, http_request: HttpRequest) -> GeminiResult<HttpResponse> {
+        self.transport.send(http_request)
+            .await
+            .map_err(|e| GeminiError::Network(crate::error::NetworkError::ConnectionFailed {
+                message: e.to_string(),
+            }))
+    }
+
+    /// Interpret the response to a chunk upload or status query.
+    fn parse_upload_status(
+        &self,
+        upload: &mut ResumableUpload,
+        response: &HttpResponse,
+    ) -> GeminiResult<UploadStatus> {
+        match response.status {
+            200 | 201 => {
+                // The upload endpoint wraps the file as `{"file": {...}}`
+                let value: serde_json::Value = serde_json::from_slice(&response.body)?;
+                let file: File = match value.get("file") {
+                    Some(file) => serde_json::from_value(file.clone())?,
+                    None => serde_json::from_value(value)?,
+                };
+                upload.committed = upload.total_size;
+                Ok(UploadStatus::Complete(file))
+            }
+            RESUME_INCOMPLETE => {
+                // `Range: bytes=0-N` means N + 1 bytes are stored; no header means none
+                let committed = match find_header(&response.headers, "range") {
+                    Some(range) => parse_committed_range(range)
+                        .filter(|committed| *committed <= upload.total_size)
+                        .ok_or_else(|| {
+                            GeminiError::Response(crate::error::ResponseError::UnexpectedFormat {
+                                message: format!("Invalid Range header: {}", range),
+                            })
+                        })?,
+                    None => 0,
+                };
+                upload.committed = committed;
+                Ok(UploadStatus::InProgress { committed })
+            }
+            status => Err(self.parse_error(status, &response.body)),
+        }
+    }
+
+    /// Backoff before retrying a failed chunk.
+    fn chunk_retry_delay(&self, attempt: u32) -> Duration {
+        let retry = &self.config.retry_config;
+        let factor = retry.multiplier.powi(attempt.saturating_sub(1) as i32);
+        std::cmp::min(retry.initial_delay.mul_f64(factor), retry.max_delay)
+    }
+
     /// Upload file bytes with MIME type and display name.
     pub async fn upload_bytes(
         &self,
@@ -264,11 +343,11 @@ impl FilesService for FilesServiceImpl {
         let boundary = format!("----gemini_boundary_{}", uuid::Uuid::new_v4());
 
         // Build URL
-        let url = self.build_upload_url();
+        let url = self.build_upload_url()?;
         let url = self.add_query_params(url, None);
 
         // Build headers
-        let headers = self.build_multipart_headers(&boundary);
+        let headers = self.build_multipart_headers(&boundary)?;
 
         // Create multipart body
         let body = self.create_multipart_body(&request, &boundary);
@@ -303,11 +382,11 @@ impl FilesService for FilesServiceImpl {
         params: Option<ListFilesParams>,
     ) -> Result<ListFilesResponse, GeminiError> {
         // Build URL
-        let url = self.build_list_url();
+        let url = self.build_list_url()?;
         let url = self.add_query_params(url, params.as_ref());
 
         // Build headers
-        let headers = self.build_headers();
+        let headers = self.build_headers()?;
 
         // Create HTTP request
         let http_request = HttpRequest {
@@ -345,11 +424,11 @@ impl FilesService for FilesServiceImpl {
             format!("files/{}", file_name)
         };
 
-        let url = self.build_file_url(&name);
+        let url = self.build_file_url(&name)?;
         let url = self.add_query_params(url, None);
 
         // Build headers
-        let headers = self.build_headers();
+        let headers = self.build_headers()?;
 
         // Create HTTP request
         let http_request = HttpRequest {
@@ -387,11 +466,11 @@ impl FilesService for FilesServiceImpl {
             format!("files/{}", file_name)
         };
 
-        let url = self.build_file_url(&name);
+        let url = self.build_file_url(&name)?;
         let url = self.add_query_params(url, None);
 
         // Build headers
-        let headers = self.build_headers();
+        let headers = self.build_headers()?;
 
         // Create HTTP request
         let http_request = HttpRequest {
@@ -415,6 +494,195 @@ impl FilesService for FilesServiceImpl {
 
         Ok(())
     }
+
+    async fn start_resumable_upload(
+        &self,
+        total_size: u64,
+        mime_type: &str,
+        display_name: Option<String>,
+    ) -> Result<ResumableUpload, GeminiError> {
+        validate_resumable_upload(total_size, mime_type)?;
+
+        // Build headers
+        let mut headers = self.build_headers()?;
+        headers.insert("X-Upload-Content-Type".to_string(), mime_type.to_string());
+        headers.insert("X-Upload-Content-Length".to_string(), total_size.to_string());
+
+        let metadata = json!({
+            "file": {
+                "display_name": display_name
+            }
+        });
+
+        // Create HTTP request
+        let http_request = HttpRequest {
+            method: HttpMethod::Post,
+            url: self.build_resumable_start_url()?,
+            headers,
+            body: Some(Bytes::from(metadata.to_string())),
+        };
+
+        // Send request
+        let response = self.send_request(http_request).await?;
+
+        // Check status
+        if response.status != 200 {
+            return Err(self.parse_error(response.status, &response.body));
+        }
+
+        // The session URL comes back in the Location header
+        let upload_url = find_header(&response.headers, "location")
+            .or_else(|| find_header(&response.headers, "x-goog-upload-url"))
+            .ok_or_else(|| GeminiError::Response(crate::error::ResponseError::UnexpectedFormat {
+                message: "Resumable upload response has no upload URL".to_string(),
+            }))?;
+
+        Ok(ResumableUpload {
+            upload_url: upload_url.to_string(),
+            total_size,
+            committed: 0,
+        })
+    }
+
+    async fn upload_chunk(
+        &self,
+        upload: &mut ResumableUpload,
+        chunk: Bytes,
+    ) -> Result<UploadStatus, GeminiError> {
+        let start = upload.committed;
+        let end = start + chunk.len() as u64;
+        if chunk.is_empty() || end > upload.total_size {
+            return Err(GeminiError::Request(RequestError::InvalidParameter {
+                parameter: "chunk".to_string(),
+                message: format!(
+                    "Chunk of {} bytes at offset {} does not fit a {} byte upload",
+                    chunk.len(),
+                    start,
+                    upload.total_size
+                ),
+            }));
+        }
+
+        let content_range = format!("bytes {}-{}/{}", start, end - 1, upload.total_size);
+        let http_request = HttpRequest {
+            method: HttpMethod::Put,
+            url: upload.upload_url.clone(),
+            headers: self.build_session_headers(content_range)?,
+            body: Some(chunk),
+        };
+
+        let response = self.send_request(http_request).await?;
+        self.parse_upload_status(upload, &response)
+    }
+
+    async fn query_upload_status(
+        &self,
+        upload: &mut ResumableUpload,
+    ) -> Result<UploadStatus, GeminiError> {
+        let content_range = format!("bytes */{}", upload.total_size);
+        let http_request = HttpRequest {
+            method: HttpMethod::Put,
+            url: upload.upload_url.clone(),
+            headers: self.build_session_headers(content_range)?,
+            body: None,
+        };
+
+        let response = self.send_request(http_request).await?;
+        self.parse_upload_status(upload, &response)
+    }
+
+    async fn upload_file_resumable(
+        &self,
+        path: &Path,
+        mime_type: &str,
+        chunk_size: usize,
+        on_progress: &(dyn Fn(UploadProgress) + Send + Sync),
+    ) -> Result<File, GeminiError> {
+        if chunk_size == 0 || chunk_size % RESUMABLE_CHUNK_GRANULARITY != 0 {
+            return Err(GeminiError::Request(RequestError::InvalidParameter {
+                parameter: "chunk_size".to_string(),
+                message: format!(
+                    "Chunk size must be a non-zero multiple of {} bytes",
+                    RESUMABLE_CHUNK_GRANULARITY
+                ),
+            }));
+        }
+
+        let io_error = |e: std::io::Error| GeminiError::Request(RequestError::InvalidParameter {
+            parameter: "path".to_string(),
+            message: format!("{}: {}", path.display(), e),
+        });
+
+        let mut file = tokio::fs::File::open(path).await.map_err(io_error)?;
+        let total_size = file.metadata().await.map_err(io_error)?.len();
+        let display_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned());
+
+        let mut upload = self
+            .start_resumable_upload(total_size, mime_type, display_name)
+            .await?;
+        let max_attempts = self.config.retry_config.max_attempts;
+        let mut failed_attempts = 0;
+
+        loop {
+            // Read the next chunk from the committed offset
+            let length = std::cmp::min(chunk_size as u64, upload.total_size - upload.committed);
+            let mut buffer = vec![0; length as usize];
+            file.seek(std::io::SeekFrom::Start(upload.committed))
+                .await
+                .map_err(io_error)?;
+            file.read_exact(&mut buffer).await.map_err(io_error)?;
+
+            let mut result = self.upload_chunk(&mut upload, Bytes::from(buffer)).await;
+
+            // After a failure, find out what the server kept before sending more
+            while let Err(error) = result {
+                if !is_retryable_upload_error(&error) || failed_attempts >= max_attempts {
+                    return Err(error);
+                }
+                failed_attempts += 1;
+                tokio::time::sleep(self.chunk_retry_delay(failed_attempts)).await;
+                result = self.query_upload_status(&mut upload).await;
+            }
+
+            match result? {
+                UploadStatus::Complete(file) => {
+                    on_progress(UploadProgress {
+                        bytes_uploaded: upload.total_size,
+                        total_bytes: upload.total_size,
+                    });
+                    return Ok(file);
+                }
+                UploadStatus::InProgress { committed } => {
+                    failed_attempts = 0;
+                    on_progress(UploadProgress {
+                        bytes_uploaded: committed,
+                        total_bytes: upload.total_size,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Look up a response header case-insensitively.
+fn find_header<'a>(headers: &'a HashMap<String, String>, name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// Parse a `Range: bytes=0-N` header into the number of committed bytes.
+fn parse_committed_range(range: &str) -> Option<u64> {
+    let last = range.trim().strip_prefix("bytes=")?.split_once('-')?.1;
+    last.trim().parse::<u64>().ok().map(|n| n + 1)
+}
+
+/// Chunk failures worth resuming: network errors, rate limits and 5xx responses.
+fn is_retryable_upload_error(error: &GeminiError) -> bool {
+    error.is_retryable() || matches!(error, GeminiError::Server(_))
 }
 
 mod urlencoding {
@@ -72,6 +72,45 @@ pub fn validate_upload_request(request: &UploadFileRequest) -> GeminiResult<()>
     Ok(())
 }
 
+/// Validate the metadata of a resumable upload.
+pub fn validate_resumable_upload(total_size: u64, mime_type: &str) -> GeminiResult<()> {
+    let mut details = Vec::new();
+
+    if mime_type.is_empty() {
+        details.push(ValidationDetail {
+            field: "mime_type".to_string(),
+            description: "MIME type must not be empty".to_string(),
+        });
+    }
+
+    if total_size == 0 {
+        details.push(ValidationDetail {
+            field: "total_size".to_string(),
+            description: "File must not be empty".to_string(),
+        });
+    }
+
+    if total_size > MAX_FILE_SIZE as u64 {
+        details.push(ValidationDetail {
+            field: "total_size".to_string(),
+            description: format!(
+                "File size {} bytes exceeds maximum of {} bytes (2GB)",
+                total_size,
+                MAX_FILE_SIZE
+            ),
+        });
+    }
+
+    if !details.is_empty() {
+        return Err(GeminiError::Request(RequestError::ValidationError {
+            message: "Invalid resumable upload".to_string(),
+            details,
+        }));
+    }
+
+    Ok(())
+}
+
 /// Validate file name format.
 pub fn validate_file_name(name: &str) -> GeminiResult<()> {
     let mut details = Vec::new();
@@ -116,11 +116,7 @@ impl ModelsServiceImpl {
 
     /// Build the URL for listing models.
     fn build_list_url(&self, params: &Option<ListModelsParams>) -> String {
-        let mut url = format!(
-            "{}/{}/models",
-            self.config.base_url,
-            self.config.api_version
-        );
+        let mut url = self.config.endpoint_url("models");
 
         // Add query parameters if present
         if let Some(params) = params {
@@ -145,25 +141,20 @@ impl ModelsServiceImpl {
     /// Build the URL for getting a specific model.
     fn build_get_url(&self, name: &str) -> String {
         let normalized_name = self.normalize_model_name(name);
-        format!(
-            "{}/{}/{}",
-            self.config.base_url,
-            self.config.api_version,
-            normalized_name
-        )
+        self.config.endpoint_url(&normalized_name)
     }
 
     /// Build headers for the request.
-    fn build_headers(&self) -> HashMap<String, String> {
+    fn build_headers(&self) -> Result<HashMap<String, String>, GeminiError> {
         let mut headers = HashMap::new();
         headers.insert("Content-Type".to_string(), "application/json".to_string());
 
         // Add authentication
-        if let Some((name, value)) = self.auth_manager.get_auth_header() {
+        if let Some((name, value)) = self.auth_manager.get_auth_header()? {
             headers.insert(name, value);
         }
 
-        headers
+        Ok(headers)
     }
 
     /// Add auth query param to URL if needed.
@@ -254,7 +245,7 @@ impl ModelsService for ModelsServiceImpl {
         let url = self.add_auth_to_url(url);
 
         // Build headers
-        let headers = self.build_headers();
+        let headers = self.build_headers()?;
 
         // Create HTTP request
         let http_request = HttpRequest {
@@ -296,7 +287,7 @@ impl ModelsService for ModelsServiceImpl {
         let url = self.add_auth_to_url(url);
 
         // Build headers
-        let headers = self.build_headers();
+        let headers = self.build_headers()?;
 
         // Create HTTP request
         let http_request = HttpRequest {
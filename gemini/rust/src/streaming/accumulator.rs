@@ -1,32 +1,95 @@
 //! Stream accumulator for combining streaming response chunks.
 //!
-//! Provides utilities for accumulating and combining GenerateContentResponse chunks
+//! Provides utilities for accumulating and combining `GenerateContentResponse` chunks
 //! from the Gemini streaming API into a single complete response.
 
-use crate::types::{GenerateContentResponse, Candidate, Content, Part, UsageMetadata};
+use crate::types::{
+    Candidate, Content, FunctionCall, GenerateContentResponse, GroundingMetadata, Part,
+    UsageMetadata,
+};
 
 /// Accumulator for combining streaming response chunks.
 ///
-/// The Gemini streaming API returns multiple GenerateContentResponse chunks,
+/// The Gemini streaming API returns multiple `GenerateContentResponse` chunks,
 /// each containing partial content. This accumulator combines them into a
 /// single complete response by:
-/// - Concatenating text parts from candidates
+/// - Concatenating consecutive text parts from candidates
+/// - Reassembling function calls whose arguments are split across chunks
+/// - Merging grounding metadata from every chunk
 /// - Merging usage metadata (using the final chunk's values)
 /// - Preserving the last finish reason and safety ratings
+///
+/// A `functionCall` part with a name starts a new call; a part without a name
+/// continues the call started most recently in the same candidate. Argument
+/// fragments may be JSON objects (merged key by key) or pieces of JSON text
+/// (concatenated and parsed once the call completes). Calls complete when
+/// their candidate reports a finish reason, or when the accumulator is
+/// finalized.
 pub struct StreamAccumulator {
     /// Accumulated candidates
     candidates: Vec<Candidate>,
+    /// Function calls still receiving arguments, per candidate
+    pending_calls: Vec<Vec<PendingFunctionCall>>,
+    /// Function calls completed so far, across all candidates
+    function_calls: Vec<FunctionCall>,
     /// Final usage metadata (from last chunk)
     usage_metadata: Option<UsageMetadata>,
     /// Model version
     model_version: Option<String>,
 }
 
+/// A function call whose arguments are still streaming.
+struct PendingFunctionCall {
+    /// Index of the call's part in the candidate's content
+    part_index: usize,
+    /// Name of the function
+    name: String,
+    /// Arguments received as JSON objects
+    args: serde_json::Map<String, serde_json::Value>,
+    /// Arguments received as fragments of JSON text
+    args_text: String,
+}
+
+impl PendingFunctionCall {
+    fn add_args(&mut self, args: serde_json::Value) {
+        match args {
+            serde_json::Value::Object(map) => self.args.extend(map),
+            serde_json::Value::String(fragment) => self.args_text.push_str(&fragment),
+            _ => {}
+        }
+    }
+
+    /// Build the complete call.
+    ///
+    /// Text fragments that do not form valid JSON are kept as a string so
+    /// the caller can still inspect what the model produced.
+    fn complete(mut self) -> FunctionCall {
+        let args = if self.args_text.trim().is_empty() {
+            serde_json::Value::Object(self.args)
+        } else {
+            match serde_json::from_str(&self.args_text) {
+                Ok(serde_json::Value::Object(map)) => {
+                    self.args.extend(map);
+                    serde_json::Value::Object(self.args)
+                }
+                Ok(value) if self.args.is_empty() => value,
+                _ => serde_json::Value::String(self.args_text),
+            }
+        };
+        FunctionCall {
+            name: self.name,
+            args,
+        }
+    }
+}
+
 impl StreamAccumulator {
     /// Create a new stream accumulator.
     pub fn new() -> Self {
         Self {
             candidates: Vec::new(),
+            pending_calls: Vec::new(),
+            function_calls: Vec::new(),
             usage_metadata: None,
             model_version: None,
         }
@@ -36,9 +99,13 @@ impl StreamAccumulator {
     ///
     /// This combines the chunk's content with the accumulated state:
     /// - Text parts are concatenated
+    /// - Function call fragments are buffered
+    /// - Grounding metadata is merged
     /// - Usage metadata is updated (last one wins)
     /// - Safety ratings and finish reasons are updated from each chunk
-    pub fn add_chunk(&mut self, chunk: GenerateContentResponse) {
+    ///
+    /// Returns the function calls completed by this chunk.
+    pub fn add_chunk(&mut self, chunk: GenerateContentResponse) -> Vec<FunctionCall> {
         // Update usage metadata (last chunk wins)
         if chunk.usage_metadata.is_some() {
             self.usage_metadata = chunk.usage_metadata;
@@ -50,31 +117,42 @@ impl StreamAccumulator {
         }
 
         // Process candidates
+        let mut completed = Vec::new();
         if let Some(new_candidates) = chunk.candidates {
-            for (idx, new_candidate) in new_candidates.into_iter().enumerate() {
-                if idx >= self.candidates.len() {
-                    // New candidate, add it
-                    self.candidates.push(new_candidate);
-                } else {
-                    // Merge with existing candidate
-                    self.merge_candidate(idx, new_candidate);
-                }
+            for (position, new_candidate) in new_candidates.into_iter().enumerate() {
+                let idx = new_candidate
+                    .index
+                    .and_then(|index| usize::try_from(index).ok())
+                    .unwrap_or(position);
+                completed.extend(self.merge_candidate(idx, new_candidate));
             }
         }
+        completed
     }
 
-    /// Merge a new candidate with an existing one at the given index.
-    fn merge_candidate(&mut self, idx: usize, new_candidate: Candidate) {
+    /// Merge a new candidate into the candidate at the given index.
+    fn merge_candidate(&mut self, idx: usize, new_candidate: Candidate) -> Vec<FunctionCall> {
+        while self.candidates.len() <= idx {
+            self.candidates.push(Candidate {
+                content: Content {
+                    role: None,
+                    parts: Vec::new(),
+                },
+                finish_reason: None,
+                safety_ratings: None,
+                citation_metadata: None,
+                grounding_metadata: None,
+                index: None,
+                token_count: None,
+            });
+            self.pending_calls.push(Vec::new());
+        }
+
         let existing = &mut self.candidates[idx];
+        let pending = &mut self.pending_calls[idx];
 
         // Merge content parts
-        if let Some(new_content) = new_candidate.content {
-            if let Some(ref mut existing_content) = existing.content {
-                self.merge_content(existing_content, new_content);
-            } else {
-                existing.content = Some(new_content);
-            }
-        }
+        Self::merge_content(&mut existing.content, new_candidate.content, pending);
 
         // Update finish reason (last one wins)
         if new_candidate.finish_reason.is_some() {
@@ -91,9 +169,14 @@ impl StreamAccumulator {
             existing.citation_metadata = new_candidate.citation_metadata;
         }
 
-        // Update grounding metadata (last one wins)
-        if new_candidate.grounding_metadata.is_some() {
-            existing.grounding_metadata = new_candidate.grounding_metadata;
+        // Merge grounding metadata
+        if let Some(new_grounding) = new_candidate.grounding_metadata {
+            Self::merge_grounding(
+                existing
+                    .grounding_metadata
+                    .get_or_insert_with(GroundingMetadata::default),
+                new_grounding,
+            );
         }
 
         // Update token count (last one wins)
@@ -105,34 +188,51 @@ impl StreamAccumulator {
         if new_candidate.index.is_some() {
             existing.index = new_candidate.index;
         }
+
+        // A finished candidate receives no more argument fragments
+        if existing.finish_reason.is_some() {
+            self.complete_calls(idx)
+        } else {
+            Vec::new()
+        }
     }
 
     /// Merge content parts from a new content into existing content.
-    fn merge_content(&mut self, existing: &mut Content, new: Content) {
-        // Merge parts
-        if let Some(new_parts) = new.parts {
-            if let Some(ref mut existing_parts) = existing.parts {
-                for (idx, new_part) in new_parts.into_iter().enumerate() {
-                    if idx >= existing_parts.len() {
-                        // New part, add it
-                        existing_parts.push(new_part);
+    fn merge_content(existing: &mut Content, new: Content, pending: &mut Vec<PendingFunctionCall>) {
+        for new_part in new.parts {
+            match new_part {
+                Part::Text { text: new_text } => {
+                    if let Some(Part::Text { text }) = existing.parts.last_mut() {
+                        text.push_str(&new_text);
                     } else {
-                        // Merge text parts
-                        if let Part::Text { text: new_text } = new_part {
-                            if let Part::Text { ref mut text } = existing_parts[idx] {
-                                text.push_str(&new_text);
-                            } else {
-                                // Different part type, replace
-                                existing_parts[idx] = Part::Text { text: new_text };
-                            }
-                        } else {
-                            // Non-text parts replace the existing part
-                            existing_parts[idx] = new_part;
-                        }
+                        existing.parts.push(Part::Text { text: new_text });
                     }
                 }
-            } else {
-                existing.parts = Some(new_parts);
+                Part::FunctionCall { function_call } if function_call.name.is_empty() => {
+                    // Continuation of the most recent call
+                    if let Some(call) = pending.last_mut() {
+                        call.add_args(function_call.args);
+                    }
+                }
+                Part::FunctionCall { function_call } => {
+                    // Reserve the call's position; arguments are filled in on completion
+                    let part_index = existing.parts.len();
+                    existing.parts.push(Part::FunctionCall {
+                        function_call: FunctionCall {
+                            name: function_call.name.clone(),
+                            args: serde_json::Value::Null,
+                        },
+                    });
+                    let mut call = PendingFunctionCall {
+                        part_index,
+                        name: function_call.name,
+                        args: serde_json::Map::new(),
+                        args_text: String::new(),
+                    };
+                    call.add_args(function_call.args);
+                    pending.push(call);
+                }
+                other => existing.parts.push(other),
             }
         }
 
@@ -142,10 +242,57 @@ impl StreamAccumulator {
         }
     }
 
+    /// Merge grounding metadata, keeping sources from every chunk.
+    fn merge_grounding(existing: &mut GroundingMetadata, new: GroundingMetadata) {
+        if let Some(queries) = new.web_search_queries {
+            let existing_queries = existing.web_search_queries.get_or_insert_with(Vec::new);
+            for query in queries {
+                if !existing_queries.contains(&query) {
+                    existing_queries.push(query);
+                }
+            }
+        }
+        if new.search_entry_point.is_some() {
+            existing.search_entry_point = new.search_entry_point;
+        }
+        if let Some(chunks) = new.grounding_chunks {
+            existing
+                .grounding_chunks
+                .get_or_insert_with(Vec::new)
+                .extend(chunks);
+        }
+        if let Some(supports) = new.grounding_supports {
+            existing
+                .grounding_supports
+                .get_or_insert_with(Vec::new)
+                .extend(supports);
+        }
+    }
+
+    /// Complete the pending function calls of the candidate at `idx`.
+    fn complete_calls(&mut self, idx: usize) -> Vec<FunctionCall> {
+        let mut completed = Vec::new();
+        for pending in std::mem::take(&mut self.pending_calls[idx]) {
+            let part_index = pending.part_index;
+            let call = pending.complete();
+            self.candidates[idx].content.parts[part_index] = Part::FunctionCall {
+                function_call: call.clone(),
+            };
+            completed.push(call);
+        }
+        self.function_calls.extend(completed.iter().cloned());
+        completed
+    }
+
     /// Finalize and return the accumulated response.
     ///
     /// This consumes the accumulator and returns the final combined response.
-    pub fn finalize(self) -> GenerateContentResponse {
+    /// Function calls still pending (e.g. the stream ended without a finish
+    /// reason) are completed with the arguments received so far.
+    pub fn finalize(mut self) -> GenerateContentResponse {
+        for idx in 0..self.candidates.len() {
+            self.complete_calls(idx);
+        }
         GenerateContentResponse {
             candidates: if self.candidates.is_empty() {
                 None
@@ -163,6 +310,11 @@ impl StreamAccumulator {
         &self.candidates
     }
 
+    /// Get the function calls completed so far, in the order they finished.
+    pub fn function_calls(&self) -> &[FunctionCall] {
+        &self.function_calls
+    }
+
     /// Get a reference to the usage metadata.
     pub fn usage_metadata(&self) -> Option<&UsageMetadata> {
         self.usage_metadata.as_ref()
@@ -178,7 +330,7 @@ impl Default for StreamAccumulator {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{Role, FinishReason};
+    use crate::types::{FinishReason, Role};
 
     #[test]
     fn test_accumulator_empty() {
@@ -194,12 +346,12 @@ mod tests {
 
         let chunk = GenerateContentResponse {
             candidates: Some(vec![Candidate {
-                content: Some(Content {
-                    parts: Some(vec![Part::Text {
+                content: Content {
+                    parts: vec![Part::Text {
                         text: "Hello".to_string(),
-                    }]),
+                    }],
                     role: Some(Role::Model),
-                }),
+                },
                 finish_reason: Some(FinishReason::Stop),
                 safety_ratings: None,
                 citation_metadata: None,
@@ -233,12 +385,12 @@ mod tests {
         // First chunk
         let chunk1 = GenerateContentResponse {
             candidates: Some(vec![Candidate {
-                content: Some(Content {
-                    parts: Some(vec![Part::Text {
+                content: Content {
+                    parts: vec![Part::Text {
                         text: "Hello".to_string(),
-                    }]),
+                    }],
                     role: Some(Role::Model),
-                }),
+                },
                 finish_reason: None,
                 safety_ratings: None,
                 citation_metadata: None,
@@ -259,12 +411,12 @@ mod tests {
         // Second chunk
         let chunk2 = GenerateContentResponse {
             candidates: Some(vec![Candidate {
-                content: Some(Content {
-                    parts: Some(vec![Part::Text {
+                content: Content {
+                    parts: vec![Part::Text {
                         text: " World".to_string(),
-                    }]),
+                    }],
                     role: Some(Role::Model),
-                }),
+                },
                 finish_reason: Some(FinishReason::Stop),
                 safety_ratings: None,
                 citation_metadata: None,
@@ -291,14 +443,10 @@ mod tests {
         assert_eq!(candidates.len(), 1);
 
         // Check concatenated text
-        if let Some(Content { parts: Some(parts), .. }) = &candidates[0].content {
-            if let Part::Text { text } = &parts[0] {
-                assert_eq!(text, "Hello World");
-            } else {
-                panic!("Expected text part");
-            }
+        if let Part::Text { text } = &candidates[0].content.parts[0] {
+            assert_eq!(text, "Hello World");
         } else {
-            panic!("Expected content with parts");
+            panic!("Expected text part");
         }
 
         // Check final usage metadata (from last chunk)
@@ -22,6 +22,8 @@
 //!
 //! The `StreamAccumulator` combines multiple streaming chunks into a single response:
 //! - Concatenates text parts from candidates
+//! - Reassembles function calls whose arguments arrive split across chunks
+//! - Merges grounding metadata from every chunk
 //! - Merges usage metadata (using the final chunk's values)
 //! - Preserves the last finish reason and safety ratings
 //!
@@ -40,7 +42,9 @@
 //!
 //!     while let Some(result) = parser.next().await {
 //!         match result {
-//!             Ok(chunk) => accumulator.add_chunk(chunk),
+//!             Ok(chunk) => {
+//!                 accumulator.add_chunk(chunk);
+//!             }
 //!             Err(e) => eprintln!("Error: {:?}", e),
 //!         }
 //!     }
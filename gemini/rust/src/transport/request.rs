@@ -9,6 +9,7 @@ use std::collections::HashMap;
 use url::Url;
 
 use crate::auth::AuthManager;
+use crate::config::{versioned_path, GeminiConfig};
 use crate::error::GeminiError;
 use super::http::{HttpRequest, HttpMethod};
 
@@ -16,6 +17,7 @@ use super::http::{HttpRequest, HttpMethod};
 ///
 /// The `RequestBuilder` handles:
 /// - URL construction with API version prefixes
+/// - Vertex AI publisher paths for model endpoints
 /// - Authentication via the configured auth manager
 /// - Header management (Content-Type, custom headers)
 /// - Request body serialization
@@ -27,6 +29,8 @@ pub struct RequestBuilder {
     api_version: String,
     /// Authentication manager.
     auth_manager: Box<dyn AuthManager>,
+    /// Prefix for model paths (e.g., Vertex AI publisher path).
+    model_path_prefix: Option<String>,
 }
 
 impl RequestBuilder {
@@ -68,9 +72,30 @@ impl RequestBuilder {
             base_url,
             api_version,
             auth_manager,
+            model_path_prefix: None,
         }
     }
 
+    /// Creates a request builder for the endpoint described by a configuration.
+    ///
+    /// In Vertex AI mode, model paths are rewritten to
+    /// `projects/{project}/locations/{location}/publishers/google/models/...`.
+    pub fn from_config(config: &GeminiConfig, auth_manager: Box<dyn AuthManager>) -> Self {
+        let builder = Self::new(config.base_url.clone(), config.api_version.clone(), auth_manager);
+        match &config.vertex {
+            Some(vertex) if config.is_vertex() => {
+                builder.with_model_path_prefix(vertex.publisher_path())
+            }
+            _ => builder,
+        }
+    }
+
+    /// Sets a prefix to prepend to model endpoint paths.
+    pub fn with_model_path_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.model_path_prefix = Some(prefix.into());
+        self
+    }
+
     /// Builds a complete URL for the given path.
     ///
     /// This method:
@@ -111,7 +136,8 @@ impl RequestBuilder {
         let path = path.trim_start_matches('/');
 
         // Construct the full path with API version
-        let full_path = format!("{}/{}", self.api_version, path);
+        let full_path =
+            versioned_path(&self.api_version, self.model_path_prefix.as_deref(), path);
 
         // Join with base URL
         let mut url = self.base_url.join(&full_path)?;
@@ -195,7 +221,7 @@ impl RequestBuilder {
         }
 
         // Add authentication header if needed
-        if let Some((key, value)) = self.auth_manager.get_auth_header() {
+        if let Some((key, value)) = self.auth_manager.get_auth_header()? {
             headers.insert(key, value);
         }
 
@@ -330,6 +356,39 @@ mod tests {
         assert_eq!(url1, url2);
     }
 
+    #[test]
+    fn test_build_url_for_vertex() {
+        use crate::auth::{StaticTokenProvider, VertexOAuthManager};
+        use crate::config::VertexConfig;
+        use std::sync::Arc;
+
+        let config = GeminiConfig::builder()
+            .vertex(VertexConfig::new("my-project", "europe-west4"))
+            .access_token_provider(Arc::new(StaticTokenProvider::new(
+                SecretString::new("ya29.token".into()),
+            )))
+            .build()
+            .unwrap();
+        let auth_manager = VertexOAuthManager::from_config(&config).unwrap();
+        let builder = RequestBuilder::from_config(&config, Box::new(auth_manager));
+
+        let url = builder.build_url("/models/gemini-1.5-pro:generateContent").unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://europe-west4-aiplatform.googleapis.com/v1/projects/my-project/locations/europe-west4/publishers/google/models/gemini-1.5-pro:generateContent"
+        );
+
+        let request = builder
+            .build_streaming_request("/models/gemini-1.5-pro:streamGenerateContent", &TestBody {
+                message: "test".to_string(),
+            })
+            .unwrap();
+        assert_eq!(
+            request.headers.get("Authorization").map(String::as_str),
+            Some("Bearer ya29.token")
+        );
+    }
+
     #[test]
     fn test_build_request_with_body() {
         let builder = create_test_builder(AuthMethod::Header);
@@ -0,0 +1,215 @@
+//! Fluent builder for multimodal content.
+//!
+//! [`ContentBuilder`] assembles a [`Content`] from text, inline blobs and
+//! file references, in the order the parts are added.
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use super::content::{Blob, Content, FileData, Part, Role};
+use super::generation::GenerateContentRequest;
+use crate::error::ContentError;
+
+/// Image MIME types accepted as inline data.
+const IMAGE_MIME_TYPES: &[&str] = &[
+    "image/png",
+    "image/jpeg",
+    "image/webp",
+    "image/heic",
+    "image/heif",
+];
+
+/// Audio MIME types accepted as inline data.
+const AUDIO_MIME_TYPES: &[&str] = &[
+    "audio/wav",
+    "audio/mp3",
+    "audio/mpeg",
+    "audio/aiff",
+    "audio/aac",
+    "audio/ogg",
+    "audio/flac",
+];
+
+/// Additional MIME types accepted for file references.
+const DOCUMENT_MIME_TYPES: &[&str] = &[
+    "application/pdf",
+    "application/json",
+    "application/x-javascript",
+    "application/x-python",
+    "text/plain",
+    "text/html",
+    "text/css",
+    "text/csv",
+    "text/markdown",
+    "text/xml",
+    "text/rtf",
+    "text/javascript",
+    "text/x-python",
+    "video/mp4",
+    "video/mpeg",
+    "video/mov",
+    "video/avi",
+    "video/x-flv",
+    "video/mpg",
+    "video/webm",
+    "video/wmv",
+    "video/3gpp",
+];
+
+/// Builds a [`Content`] message part by part.
+///
+/// Parts keep the order in which they were added. The first invalid MIME type
+/// is remembered and reported by [`ContentBuilder::build`], so calls can be
+/// chained without intermediate error handling.
+///
+/// # Example
+///
+/// ```
+/// use integrations_gemini::types::ContentBuilder;
+///
+/// let request = ContentBuilder::user()
+///     .text("What is in this picture?")
+///     .inline_image(&[0x89, 0x50, 0x4e, 0x47], "image/png")
+///     .into_request()?;
+/// assert_eq!(request.contents[0].parts.len(), 2);
+/// # Ok::<(), integrations_gemini::ContentError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct ContentBuilder {
+    role: Option<Role>,
+    parts: Vec<Part>,
+    error: Option<ContentError>,
+}
+
+impl ContentBuilder {
+    /// Creates a builder for a message with the given role.
+    #[must_use]
+    pub fn new(role: Role) -> Self {
+        Self {
+            role: Some(role),
+            parts: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Creates a builder for a user message.
+    #[must_use]
+    pub fn user() -> Self {
+        Self::new(Role::User)
+    }
+
+    /// Creates a builder for a model message.
+    #[must_use]
+    pub fn model() -> Self {
+        Self::new(Role::Model)
+    }
+
+    /// Appends a text part.
+    #[must_use]
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.parts.push(Part::Text { text: text.into() });
+        self
+    }
+
+    /// Appends an inline image, base64-encoding `bytes`.
+    #[must_use]
+    pub fn inline_image(self, bytes: impl AsRef<[u8]>, mime_type: &str) -> Self {
+        self.inline_blob(bytes.as_ref(), mime_type, IMAGE_MIME_TYPES)
+    }
+
+    /// Appends inline audio, base64-encoding `bytes`.
+    #[must_use]
+    pub fn audio(self, bytes: impl AsRef<[u8]>, mime_type: &str) -> Self {
+        self.inline_blob(bytes.as_ref(), mime_type, AUDIO_MIME_TYPES)
+    }
+
+    /// Appends a reference to a file uploaded through the Files API.
+    #[must_use]
+    pub fn file(mut self, file_uri: impl Into<String>, mime_type: &str) -> Self {
+        let Some(mime_type) = self.check_mime_type(
+            mime_type,
+            &[IMAGE_MIME_TYPES, AUDIO_MIME_TYPES, DOCUMENT_MIME_TYPES],
+        ) else {
+            return self;
+        };
+        self.parts.push(Part::FileData {
+            file_data: FileData {
+                mime_type: Some(mime_type),
+                file_uri: file_uri.into(),
+            },
+        });
+        self
+    }
+
+    /// Finishes the message.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ContentError::UnsupportedContent`] for the first part whose
+    /// MIME type is not supported by the Gemini API.
+    pub fn build(self) -> Result<Content, ContentError> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(Content {
+                role: self.role,
+                parts: self.parts,
+            }),
+        }
+    }
+
+    /// Finishes the message and wraps it in a [`GenerateContentRequest`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ContentBuilder::build`].
+    pub fn into_request(self) -> Result<GenerateContentRequest, ContentError> {
+        Ok(GenerateContentRequest::from(self.build()?))
+    }
+
+    fn inline_blob(mut self, bytes: &[u8], mime_type: &str, allowed: &[&str]) -> Self {
+        let Some(mime_type) = self.check_mime_type(mime_type, &[allowed]) else {
+            return self;
+        };
+        self.parts.push(Part::InlineData {
+            inline_data: Blob {
+                mime_type,
+                data: STANDARD.encode(bytes),
+            },
+        });
+        self
+    }
+
+    /// Normalizes `mime_type` and checks it against `allowed`, recording an
+    /// error if it is not listed.
+    fn check_mime_type(&mut self, mime_type: &str, allowed: &[&[&str]]) -> Option<String> {
+        let essence = mime_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        if allowed.iter().any(|set| set.contains(&essence.as_str())) {
+            return Some(essence);
+        }
+        if self.error.is_none() {
+            self.error = Some(ContentError::UnsupportedContent {
+                mime_type: mime_type.to_string(),
+            });
+        }
+        None
+    }
+}
+
+impl From<Content> for GenerateContentRequest {
+    fn from(content: Content) -> Self {
+        Self {
+            contents: vec![content],
+            system_instruction: None,
+            tools: None,
+            tool_config: None,
+            safety_settings: None,
+            generation_config: None,
+            cached_content: None,
+        }
+    }
+}
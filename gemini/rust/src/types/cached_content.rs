@@ -3,6 +3,7 @@
 //! This module contains types for working with cached content in context caching.
 
 use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use super::content::Content;
 use super::tools::Tool;
@@ -33,6 +34,111 @@ pub struct CachedContent {
     pub usage_metadata: Option<CachedContentUsageMetadata>,
 }
 
+impl CachedContent {
+    /// Parse `expire_time` into a point in time.
+    ///
+    /// Returns `None` when the expiration time is missing or not a valid
+    /// RFC 3339 timestamp.
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        self.expire_time.as_deref().and_then(parse_rfc3339)
+    }
+
+    /// Check whether the cache expires within `window` of `now`.
+    ///
+    /// Content without a readable expiration time is treated as expiring so
+    /// that callers refresh it rather than risk using an expired cache.
+    pub fn expires_within(&self, window: Duration, now: SystemTime) -> bool {
+        match self.expires_at() {
+            Some(expires_at) => match expires_at.duration_since(now) {
+                Ok(remaining) => remaining <= window,
+                Err(_) => true,
+            },
+            None => true,
+        }
+    }
+
+    /// Check whether the cache was created for `model`.
+    ///
+    /// The `models/` prefix is ignored on both sides.
+    pub fn matches_model(&self, model: &str) -> bool {
+        let strip = |name: &str| name.trim_start_matches("models/").to_string();
+        strip(&self.model) == strip(model)
+    }
+}
+
+/// When to extend a cache's TTL before reusing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheRefreshPolicy {
+    /// Refresh when the cache expires within this window.
+    pub refresh_within: Duration,
+    /// New TTL to set when refreshing.
+    pub ttl: Duration,
+}
+
+impl Default for CacheRefreshPolicy {
+    fn default() -> Self {
+        Self {
+            refresh_within: Duration::from_secs(5 * 60),
+            ttl: Duration::from_secs(60 * 60),
+        }
+    }
+}
+
+/// Parse an RFC 3339 timestamp such as `2024-01-01T00:00:00.123Z`.
+fn parse_rfc3339(value: &str) -> Option<SystemTime> {
+    let (date, time) = value.split_once(|c| c == 'T' || c == 't')?;
+
+    let mut date_parts = date.splitn(3, '-');
+    let year: i64 = date_parts.next()?.parse().ok()?;
+    let month: u32 = date_parts.next()?.parse().ok()?;
+    let day: u32 = date_parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    let (clock, offset_secs) = if let Some(clock) = time.strip_suffix(|c| c == 'Z' || c == 'z') {
+        (clock, 0)
+    } else {
+        let split = time.rfind(|c| c == '+' || c == '-')?;
+        let (clock, offset) = time.split_at(split);
+        let sign = if offset.starts_with('-') { -1 } else { 1 };
+        let (hours, minutes) = offset[1..].split_once(':')?;
+        let hours: i64 = hours.parse().ok()?;
+        let minutes: i64 = minutes.parse().ok()?;
+        (clock, sign * (hours * 3600 + minutes * 60))
+    };
+
+    let (hms, fraction) = clock.split_once('.').unwrap_or((clock, ""));
+    let mut hms_parts = hms.splitn(3, ':');
+    let hour: i64 = hms_parts.next()?.parse().ok()?;
+    let minute: i64 = hms_parts.next()?.parse().ok()?;
+    let second: i64 = hms_parts.next()?.parse().ok()?;
+
+    // Keep nanosecond precision, dropping any further digits
+    let nanos = if fraction.is_empty() {
+        0
+    } else {
+        let digits: String = fraction.chars().take(9).collect();
+        digits.parse::<u32>().ok()? * 10u32.pow(9 - digits.len() as u32)
+    };
+
+    let secs = days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second
+        - offset_secs;
+    let secs = u64::try_from(secs).ok()?;
+    Some(UNIX_EPOCH + Duration::new(secs, nanos))
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month as i64 + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
 /// Usage metadata for cached content.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct CachedContentUsageMetadata {
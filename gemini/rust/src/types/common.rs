@@ -9,6 +9,7 @@ use super::generation::GenerateContentRequest;
 
 /// Request to count tokens.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct CountTokensRequest {
     /// The contents to count tokens for.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -20,6 +21,7 @@ pub struct CountTokensRequest {
 
 /// Response from counting tokens.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
 pub struct CountTokensResponse {
     /// The total number of tokens.
     pub total_tokens: i32,
@@ -17,31 +17,37 @@ pub enum Part {
     /// Inline binary data.
     InlineData {
         /// The inline data blob.
+        #[serde(alias = "inlineData")]
         inline_data: Blob,
     },
     /// Reference to file data.
     FileData {
         /// The file data reference.
+        #[serde(alias = "fileData")]
         file_data: FileData,
     },
     /// A function call.
     FunctionCall {
         /// The function call details.
+        #[serde(alias = "functionCall")]
         function_call: FunctionCall,
     },
     /// A function response.
     FunctionResponse {
         /// The function response details.
+        #[serde(alias = "functionResponse")]
         function_response: FunctionResponse,
     },
     /// Executable code.
     ExecutableCode {
         /// The executable code details.
+        #[serde(alias = "executableCode")]
         executable_code: ExecutableCode,
     },
     /// Code execution result.
     CodeExecutionResult {
         /// The code execution result details.
+        #[serde(alias = "codeExecutionResult")]
         code_execution_result: CodeExecutionResult,
     },
 }
@@ -50,6 +56,7 @@ pub enum Part {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Blob {
     /// The MIME type of the data.
+    #[serde(alias = "mimeType")]
     pub mime_type: String,
     /// Base64-encoded binary data.
     pub data: String,
@@ -59,9 +66,10 @@ pub struct Blob {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct FileData {
     /// The MIME type of the file.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", alias = "mimeType")]
     pub mime_type: Option<String>,
     /// The URI of the file.
+    #[serde(alias = "fileUri")]
     pub file_uri: String,
 }
 
@@ -69,8 +77,14 @@ pub struct FileData {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct FunctionCall {
     /// The name of the function to call.
+    ///
+    /// Empty on streamed fragments that continue the previous call.
+    #[serde(default)]
     pub name: String,
     /// The arguments to pass to the function.
+    ///
+    /// Streamed fragments may carry a piece of the JSON text as a string.
+    #[serde(default)]
     pub args: serde_json::Value,
 }
 
@@ -62,6 +62,41 @@ pub struct UploadFileRequest {
     pub mime_type: String,
 }
 
+/// State of a resumable upload session.
+///
+/// Serializable so an interrupted upload can be resumed from another process.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResumableUpload {
+    /// The session URL returned when the upload was started.
+    pub upload_url: String,
+    /// The total size of the file in bytes.
+    pub total_size: u64,
+    /// The number of bytes the server has committed.
+    pub committed: u64,
+}
+
+/// Status of a resumable upload after sending a chunk or querying the session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UploadStatus {
+    /// More bytes are expected; `committed` bytes have been stored.
+    InProgress {
+        /// The number of bytes the server has committed.
+        committed: u64,
+    },
+    /// The upload finished and the file was created.
+    Complete(File),
+}
+
+/// Progress of a resumable upload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UploadProgress {
+    /// The number of bytes committed so far.
+    pub bytes_uploaded: u64,
+    /// The total size of the file in bytes.
+    pub total_bytes: u64,
+}
+
 /// Response from listing files.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ListFilesResponse {
@@ -78,6 +78,7 @@ pub struct UsageMetadata {
 
 /// Metadata about citations in the content.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
 pub struct CitationMetadata {
     /// Sources that were cited.
     pub citation_sources: Vec<CitationSource>,
@@ -85,6 +86,7 @@ pub struct CitationMetadata {
 
 /// A source that was cited in the content.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
 pub struct CitationSource {
     /// Start index of the citation.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -98,7 +100,8 @@ pub struct CitationSource {
 }
 
 /// Metadata about grounding sources.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct GroundingMetadata {
     /// Web search queries used for grounding.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -114,8 +117,110 @@ pub struct GroundingMetadata {
     pub grounding_supports: Option<Vec<serde_json::Value>>,
 }
 
+impl GroundingMetadata {
+    /// Extract the search queries, grounding chunks and support segments.
+    ///
+    /// Chunks are returned with their position in `grounding_chunks` so that
+    /// support segments can be linked back to them by index.
+    pub fn sources(&self) -> Vec<GroundingSource> {
+        let queries = self
+            .web_search_queries
+            .iter()
+            .flatten()
+            .cloned()
+            .map(GroundingSource::SearchQuery);
+
+        let chunks = self
+            .grounding_chunks
+            .iter()
+            .flatten()
+            .enumerate()
+            .map(|(index, chunk)| {
+                // Web results carry `web`; retrieval results carry `retrievedContext`.
+                let source = chunk.get("web").or_else(|| chunk.get("retrievedContext"));
+                let field = |name: &str| {
+                    source
+                        .and_then(|s| s.get(name))
+                        .and_then(|v| v.as_str())
+                        .map(str::to_string)
+                };
+                GroundingSource::Chunk {
+                    index,
+                    title: field("title"),
+                    uri: field("uri"),
+                }
+            });
+
+        let supports = self.grounding_supports.iter().flatten().map(|support| {
+            let segment = support.get("segment");
+            let index = |name: &str| {
+                segment
+                    .and_then(|s| s.get(name))
+                    .and_then(serde_json::Value::as_i64)
+                    .and_then(|v| i32::try_from(v).ok())
+            };
+            GroundingSource::Support {
+                text: segment
+                    .and_then(|s| s.get("text"))
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                start_index: index("startIndex"),
+                end_index: index("endIndex"),
+                chunk_indices: support
+                    .get("groundingChunkIndices")
+                    .and_then(|v| v.as_array())
+                    .map(|a| {
+                        a.iter()
+                            .filter_map(serde_json::Value::as_u64)
+                            .filter_map(|i| usize::try_from(i).ok())
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                confidence_scores: support
+                    .get("confidenceScores")
+                    .and_then(|v| v.as_array())
+                    .map(|a| a.iter().filter_map(serde_json::Value::as_f64).collect())
+                    .unwrap_or_default(),
+            }
+        });
+
+        queries.chain(chunks).chain(supports).collect()
+    }
+}
+
+/// A grounding source extracted from [`GroundingMetadata`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroundingSource {
+    /// A web search query the model issued.
+    SearchQuery(String),
+    /// A retrieved source document.
+    Chunk {
+        /// Position of the chunk in the grounding metadata.
+        index: usize,
+        /// Title of the source.
+        title: Option<String>,
+        /// URI of the source.
+        uri: Option<String>,
+    },
+    /// A span of the response text backed by one or more chunks.
+    Support {
+        /// The grounded text.
+        text: String,
+        /// Start index of the segment in the response text.
+        start_index: Option<i32>,
+        /// End index of the segment in the response text.
+        end_index: Option<i32>,
+        /// Indices of the chunks supporting this segment.
+        chunk_indices: Vec<usize>,
+        /// Confidence score for each supporting chunk.
+        confidence_scores: Vec<f64>,
+    },
+}
+
 /// A candidate response from the model.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct Candidate {
     /// The content of the candidate.
     pub content: Content,
@@ -165,6 +270,21 @@ pub struct GenerateContentRequest {
     pub cached_content: Option<String>,
 }
 
+impl GenerateContentRequest {
+    /// Reuse cached content as the prefix of this request.
+    ///
+    /// Accepts either `cachedContents/{id}` or a bare `{id}`.
+    pub fn with_cached_content(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        self.cached_content = Some(if name.starts_with("cachedContents/") {
+            name
+        } else {
+            format!("cachedContents/{}", name)
+        });
+        self
+    }
+}
+
 /// Feedback on why the prompt was blocked or altered.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
@@ -210,3 +330,17 @@ pub struct GenerateContentResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub model_version: Option<String>,
 }
+
+impl GenerateContentResponse {
+    /// Get the grounding sources of the first grounded candidate.
+    ///
+    /// Returns an empty vec when the response has no grounding metadata.
+    pub fn grounding_sources(&self) -> Vec<GroundingSource> {
+        self.candidates
+            .iter()
+            .flatten()
+            .find_map(|candidate| candidate.grounding_metadata.as_ref())
+            .map(GroundingMetadata::sources)
+            .unwrap_or_default()
+    }
+}
@@ -4,6 +4,7 @@
 //! needed to interact with the Gemini API.
 
 // Module declarations
+pub mod builder;
 pub mod cached_content;
 pub mod common;
 pub mod content;
@@ -14,9 +15,12 @@ pub mod models;
 pub mod safety;
 pub mod tools;
 
+// Re-exports for builder types
+pub use builder::ContentBuilder;
+
 // Re-exports for cached content types
 pub use cached_content::{
-    CachedContent, CachedContentUsageMetadata, CreateCachedContentRequest,
+    CacheRefreshPolicy, CachedContent, CachedContentUsageMetadata, CreateCachedContentRequest,
     ListCachedContentsParams, ListCachedContentsResponse, UpdateCachedContentRequest,
 };
 
@@ -35,13 +39,16 @@ pub use embeddings::{
 };
 
 // Re-exports for file types
-pub use files::{File, FileState, ListFilesParams, ListFilesResponse, UploadFileRequest};
+pub use files::{
+    File, FileState, ListFilesParams, ListFilesResponse, ResumableUpload, UploadFileRequest,
+    UploadProgress, UploadStatus,
+};
 
 // Re-exports for generation types
 pub use generation::{
     BlockReason, Candidate, CitationMetadata, CitationSource, FinishReason,
     GenerateContentRequest, GenerateContentResponse, GenerationConfig, GroundingMetadata,
-    PromptFeedback, UsageMetadata,
+    GroundingSource, PromptFeedback, UsageMetadata,
 };
 
 // Re-exports for model types
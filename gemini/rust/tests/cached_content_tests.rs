@@ -4,8 +4,8 @@ use integrations_gemini::mocks::{MockAuthManager, MockHttpTransport};
 use integrations_gemini::services::cached_content::CachedContentServiceImpl;
 use integrations_gemini::services::CachedContentService;
 use integrations_gemini::types::{
-    CreateCachedContentRequest, UpdateCachedContentRequest, ListCachedContentsParams,
-    Content, Part, Role,
+    CacheRefreshPolicy, CreateCachedContentRequest, GenerateContentRequest,
+    UpdateCachedContentRequest, ListCachedContentsParams, Content, Part, Role,
 };
 use integrations_gemini::{GeminiConfig, GeminiError};
 use secrecy::SecretString;
@@ -492,3 +492,111 @@ async fn test_cached_content_network_error() {
         e => panic!("Expected NetworkError, got {:?}", e),
     }
 }
+
+fn generate_request() -> GenerateContentRequest {
+    GenerateContentRequest {
+        contents: vec![Content {
+            role: Some(Role::User),
+            parts: vec![Part::Text { text: "Summarize the document".to_string() }],
+        }],
+        system_instruction: None,
+        tools: None,
+        tool_config: None,
+        safety_settings: None,
+        generation_config: None,
+        cached_content: None,
+    }
+}
+
+#[test]
+fn test_generate_request_serializes_cache_reference() {
+    let request = generate_request().with_cached_content("abc123");
+
+    let json = serde_json::to_value(&request).unwrap();
+    assert_eq!(json["cachedContent"], "cachedContents/abc123");
+
+    let json = serde_json::to_value(generate_request()).unwrap();
+    assert!(json.get("cachedContent").is_none());
+}
+
+#[tokio::test]
+async fn test_prepare_for_generate_refreshes_near_expiry() {
+    // Arrange
+    let transport = Arc::new(MockHttpTransport::new());
+    transport.enqueue_json_response(200, r#"{
+        "name": "cachedContents/abc123",
+        "model": "models/gemini-1.5-pro",
+        "expireTime": "2020-01-01T00:00:00Z"
+    }"#);
+    transport.enqueue_json_response(200, r#"{
+        "name": "cachedContents/abc123",
+        "model": "models/gemini-1.5-pro",
+        "expireTime": "2999-01-01T00:00:00Z"
+    }"#);
+
+    let service = create_test_service(transport.clone());
+
+    // Act
+    let request = service
+        .prepare_for_generate("abc123", "gemini-1.5-pro", generate_request(), CacheRefreshPolicy::default())
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(request.cached_content.as_deref(), Some("cachedContents/abc123"));
+    transport.verify_request_count(2);
+    transport.verify_request(1, integrations_gemini::transport::HttpMethod::Patch, "cachedContents/abc123");
+
+    let requests = transport.get_requests();
+    let body = String::from_utf8(requests[1].body.clone().unwrap().to_vec()).unwrap();
+    assert!(body.contains("3600s"));
+}
+
+#[tokio::test]
+async fn test_prepare_for_generate_skips_refresh_when_fresh() {
+    // Arrange
+    let transport = Arc::new(MockHttpTransport::new());
+    transport.enqueue_json_response(200, r#"{
+        "name": "cachedContents/abc123",
+        "model": "models/gemini-1.5-pro",
+        "expireTime": "2999-01-01T00:00:00.500+02:00"
+    }"#);
+
+    let service = create_test_service(transport.clone());
+
+    // Act
+    let request = service
+        .prepare_for_generate("abc123", "models/gemini-1.5-pro", generate_request(), CacheRefreshPolicy::default())
+        .await;
+
+    // Assert
+    assert!(request.is_ok());
+    transport.verify_request_count(1);
+}
+
+#[tokio::test]
+async fn test_prepare_for_generate_rejects_model_mismatch() {
+    // Arrange
+    let transport = Arc::new(MockHttpTransport::new());
+    transport.enqueue_json_response(200, r#"{
+        "name": "cachedContents/abc123",
+        "model": "models/gemini-1.5-pro",
+        "expireTime": "2999-01-01T00:00:00Z"
+    }"#);
+
+    let service = create_test_service(transport.clone());
+
+    // Act
+    let response = service
+        .prepare_for_generate("abc123", "gemini-1.5-flash", generate_request(), CacheRefreshPolicy::default())
+        .await;
+
+    // Assert
+    match response.unwrap_err() {
+        GeminiError::Request(integrations_gemini::error::RequestError::ValidationError { details, .. }) => {
+            assert_eq!(details[0].field, "cachedContent");
+        }
+        e => panic!("Expected ValidationError, got {:?}", e),
+    }
+    transport.verify_request_count(1);
+}
@@ -0,0 +1,98 @@
+//! Tests for the multimodal content builder.
+
+use integrations_gemini::types::{
+    Blob, Content, ContentBuilder, FileData, GenerateContentRequest, Part, Role,
+};
+use integrations_gemini::ContentError;
+
+#[test]
+fn test_text_image_file_matches_hand_written_content() {
+    let image = [0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a];
+
+    let built = ContentBuilder::user()
+        .text("Compare the chart with the report.")
+        .inline_image(image, "image/png")
+        .file("https://generativelanguage.googleapis.com/v1beta/files/abc-123", "application/pdf")
+        .build()
+        .expect("valid content");
+
+    let expected = Content {
+        role: Some(Role::User),
+        parts: vec![
+            Part::Text {
+                text: "Compare the chart with the report.".to_string(),
+            },
+            Part::InlineData {
+                inline_data: Blob {
+                    mime_type: "image/png".to_string(),
+                    data: "iVBORw0KGgo=".to_string(),
+                },
+            },
+            Part::FileData {
+                file_data: FileData {
+                    mime_type: Some("application/pdf".to_string()),
+                    file_uri: "https://generativelanguage.googleapis.com/v1beta/files/abc-123"
+                        .to_string(),
+                },
+            },
+        ],
+    };
+
+    assert_eq!(built, expected);
+    assert_eq!(
+        serde_json::to_value(&built).unwrap(),
+        serde_json::to_value(&expected).unwrap()
+    );
+}
+
+#[test]
+fn test_audio_and_role() {
+    let content = ContentBuilder::model()
+        .audio(b"RIFF", "Audio/WAV; rate=16000")
+        .build()
+        .expect("valid content");
+
+    assert_eq!(content.role, Some(Role::Model));
+    assert_eq!(
+        content.parts,
+        vec![Part::InlineData {
+            inline_data: Blob {
+                mime_type: "audio/wav".to_string(),
+                data: "UklGRg==".to_string(),
+            },
+        }]
+    );
+}
+
+#[test]
+fn test_unsupported_mime_type_is_reported_on_build() {
+    let result = ContentBuilder::user()
+        .text("Describe this")
+        .inline_image(b"GIF89a", "image/gif")
+        .audio(b"data", "audio/unknown")
+        .build();
+
+    match result {
+        Err(ContentError::UnsupportedContent { mime_type }) => assert_eq!(mime_type, "image/gif"),
+        other => panic!("Expected UnsupportedContent, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_inline_image_rejects_audio_mime_type() {
+    let result = ContentBuilder::user().inline_image(b"ID3", "audio/mp3").build();
+
+    assert!(matches!(result, Err(ContentError::UnsupportedContent { .. })));
+}
+
+#[test]
+fn test_into_request() {
+    let request: GenerateContentRequest = ContentBuilder::user()
+        .text("Hello")
+        .into_request()
+        .expect("valid content");
+
+    assert_eq!(request.contents.len(), 1);
+    assert_eq!(request.contents[0].parts, vec![Part::Text { text: "Hello".to_string() }]);
+    assert!(request.generation_config.is_none());
+}
@@ -1,5 +1,6 @@
 //! Integration tests for content generation service.
 
+use integrations_gemini::fixtures::load_json_fixture;
 use integrations_gemini::mocks::{MockAuthManager, MockHttpTransport};
 use integrations_gemini::services::content::ContentServiceImpl;
 use integrations_gemini::services::ContentService;
@@ -7,9 +8,12 @@ use integrations_gemini::observability::{create_noop_stack, GeminiMetrics};
 use integrations_gemini::types::{
     Content, Part, Role, GenerateContentRequest, CountTokensRequest,
     GenerationConfig, SafetySetting, HarmCategory, HarmBlockThreshold,
-    FinishReason, BlockReason,
+    FinishReason, BlockReason, GenerateContentResponse, GroundingSource,
 };
-use integrations_gemini::{GeminiConfig, GeminiError};
+use integrations_gemini::{
+    GeminiConfig, GeminiError, RequestError, StaticTokenProvider, VertexConfig, VertexOAuthManager,
+};
+use integrations_gemini::transport::HttpMethod;
 use secrecy::SecretString;
 use std::sync::Arc;
 use bytes::Bytes;
@@ -170,16 +174,152 @@ async fn test_generate_content_safety_blocked() {
     let response = service.generate("gemini-1.5-pro", request).await;
 
     // Assert
-    assert!(response.is_err(), "Expected safety block error");
+    assert!(response.is_err(), "Expected prompt block error");
     match response.unwrap_err() {
-        GeminiError::Content(integrations_gemini::error::ContentError::SafetyBlocked { reason, safety_ratings }) => {
-            assert!(reason.contains("Safety"));
+        GeminiError::Content(integrations_gemini::error::ContentError::PromptBlocked { block_reason, safety_ratings }) => {
+            assert_eq!(block_reason, "SAFETY");
             assert_eq!(safety_ratings.len(), 1);
+            assert_eq!(safety_ratings[0].category, "DangerousContent");
+        }
+        e => panic!("Expected ContentError::PromptBlocked, got {:?}", e),
+    }
+}
+
+fn blocked_test_request() -> GenerateContentRequest {
+    GenerateContentRequest {
+        contents: vec![Content {
+            role: Some(Role::User),
+            parts: vec![Part::Text { text: "Unsafe content".to_string() }],
+        }],
+        system_instruction: None,
+        tools: None,
+        tool_config: None,
+        safety_settings: None,
+        generation_config: None,
+        cached_content: None,
+    }
+}
+
+const CANDIDATE_SAFETY_BLOCKED_JSON: &str = r#"{
+    "candidates": [{
+        "content": {"parts": [], "role": "model"},
+        "finishReason": "SAFETY",
+        "index": 0,
+        "safetyRatings": [
+            {"category": "HARM_CATEGORY_HARASSMENT", "probability": "LOW"},
+            {"category": "HARM_CATEGORY_HATE_SPEECH", "probability": "HIGH"},
+            {"category": "HARM_CATEGORY_DANGEROUS_CONTENT", "probability": "NEGLIGIBLE"}
+        ]
+    }]
+}"#;
+
+#[tokio::test]
+async fn test_generate_content_candidate_safety_blocked() {
+    // Arrange
+    let transport = Arc::new(MockHttpTransport::new());
+    transport.enqueue_json_response(200, CANDIDATE_SAFETY_BLOCKED_JSON);
+    let service = create_test_service(transport.clone());
+
+    // Act
+    let error = service.generate("gemini-1.5-pro", blocked_test_request()).await.unwrap_err();
+
+    // Assert
+    match &error {
+        GeminiError::Content(content_error @ integrations_gemini::error::ContentError::SafetyBlocked { reason, safety_ratings }) => {
+            assert!(!content_error.is_prompt_block());
+            assert_eq!(content_error.blocked_category(), Some("HateSpeech"));
+            assert_eq!(reason, "Safety: HateSpeech (High)");
+            let probabilities: Vec<&str> = safety_ratings.iter().map(|r| r.probability.as_str()).collect();
+            assert_eq!(probabilities, vec!["High", "Low", "Negligible"]);
         }
         e => panic!("Expected ContentError::SafetyBlocked, got {:?}", e),
     }
 }
 
+#[tokio::test]
+async fn test_generate_content_prompt_blocked_for_other_reason() {
+    // Arrange
+    let transport = Arc::new(MockHttpTransport::new());
+    transport.enqueue_json_response(200, r#"{
+        "promptFeedback": {"blockReason": "OTHER"}
+    }"#);
+    let service = create_test_service(transport.clone());
+
+    // Act
+    let error = service.generate("gemini-1.5-pro", blocked_test_request()).await.unwrap_err();
+
+    // Assert
+    match &error {
+        GeminiError::Content(content_error @ integrations_gemini::error::ContentError::PromptBlocked { block_reason, .. }) => {
+            assert!(content_error.is_prompt_block());
+            assert_eq!(block_reason, "OTHER");
+            assert_eq!(content_error.blocked_category(), None);
+        }
+        e => panic!("Expected ContentError::PromptBlocked, got {:?}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_generate_content_candidate_prohibited_content() {
+    // Arrange
+    let transport = Arc::new(MockHttpTransport::new());
+    transport.enqueue_json_response(200, r#"{
+        "candidates": [{
+            "content": {"parts": [], "role": "model"},
+            "finishReason": "PROHIBITED_CONTENT"
+        }]
+    }"#);
+    let service = create_test_service(transport.clone());
+
+    // Act
+    let error = service.generate("gemini-1.5-pro", blocked_test_request()).await.unwrap_err();
+
+    // Assert
+    assert!(matches!(
+        error,
+        GeminiError::Content(integrations_gemini::error::ContentError::ProhibitedContent)
+    ));
+}
+
+#[tokio::test]
+async fn test_generate_allow_blocked_returns_prompt_block() {
+    // Arrange
+    let transport = Arc::new(MockHttpTransport::new());
+    transport.enqueue_json_response(200, r#"{
+        "promptFeedback": {
+            "blockReason": "SAFETY",
+            "safetyRatings": [
+                {"category": "HARM_CATEGORY_DANGEROUS_CONTENT", "probability": "HIGH"}
+            ]
+        }
+    }"#);
+    let service = create_test_service(transport.clone());
+
+    // Act
+    let response = service.generate_allow_blocked("gemini-1.5-pro", blocked_test_request()).await.unwrap();
+
+    // Assert
+    assert!(response.candidates.is_none());
+    let feedback = response.prompt_feedback.unwrap();
+    assert_eq!(feedback.block_reason, Some(BlockReason::Safety));
+}
+
+#[tokio::test]
+async fn test_generate_allow_blocked_returns_candidate_block() {
+    // Arrange
+    let transport = Arc::new(MockHttpTransport::new());
+    transport.enqueue_json_response(200, CANDIDATE_SAFETY_BLOCKED_JSON);
+    let service = create_test_service(transport.clone());
+
+    // Act
+    let response = service.generate_allow_blocked("gemini-1.5-pro", blocked_test_request()).await.unwrap();
+
+    // Assert
+    let candidates = response.candidates.unwrap();
+    assert_eq!(candidates[0].finish_reason, Some(FinishReason::Safety));
+    assert_eq!(candidates[0].safety_ratings.as_ref().unwrap().len(), 3);
+}
+
 #[tokio::test]
 async fn test_generate_content_recitation_blocked() {
     // Arrange
@@ -463,3 +603,244 @@ async fn test_generate_with_system_instruction() {
     assert!(body_str.contains("systemInstruction"));
     assert!(body_str.contains("helpful AI assistant"));
 }
+
+/// Build a text + image request for the token budget tests.
+fn create_multimodal_request() -> GenerateContentRequest {
+    GenerateContentRequest {
+        contents: vec![Content {
+            role: Some(Role::User),
+            parts: vec![
+                Part::Text { text: "Describe this image.".to_string() },
+                Part::InlineData {
+                    inline_data: integrations_gemini::types::Blob {
+                        mime_type: "image/png".to_string(),
+                        data: "iVBORw0KGgo=".to_string(),
+                    },
+                },
+            ],
+        }],
+        system_instruction: None,
+        tools: None,
+        tool_config: None,
+        safety_settings: None,
+        generation_config: None,
+        cached_content: None,
+    }
+}
+
+const BUDGET_SUCCESS_JSON: &str = r#"{
+    "candidates": [{
+        "content": {"parts": [{"text": "A small PNG."}], "role": "model"},
+        "finishReason": "STOP",
+        "index": 0
+    }],
+    "usageMetadata": {"promptTokenCount": 263, "candidatesTokenCount": 4, "totalTokenCount": 267}
+}"#;
+
+#[tokio::test]
+async fn test_generate_within_budget_just_under() {
+    // Arrange
+    let transport = Arc::new(MockHttpTransport::new());
+    transport.enqueue_json_response(200, r#"{"totalTokens": 263}"#);
+    transport.enqueue_json_response(200, BUDGET_SUCCESS_JSON);
+    transport.enqueue_json_response(200, BUDGET_SUCCESS_JSON);
+
+    let service = create_test_service(transport.clone());
+
+    // Act
+    let first = service
+        .generate_within_budget("gemini-1.5-pro", create_multimodal_request(), 263)
+        .await;
+    let second = service
+        .generate_within_budget("gemini-1.5-pro", create_multimodal_request(), 263)
+        .await;
+
+    // Assert
+    assert!(first.is_ok(), "Expected request within budget to be sent");
+    assert!(second.is_ok());
+
+    // The token count is cached, so only one countTokens call is made
+    transport.verify_request_count(3);
+    transport.verify_request(0, integrations_gemini::transport::HttpMethod::Post, "countTokens");
+    transport.verify_request(1, integrations_gemini::transport::HttpMethod::Post, "generateContent");
+    transport.verify_request(2, integrations_gemini::transport::HttpMethod::Post, "generateContent");
+
+    // The full request, image included, is counted
+    let requests = transport.get_requests();
+    let body: serde_json::Value =
+        serde_json::from_slice(requests[0].body.as_ref().unwrap()).unwrap();
+    let counted = &body["generateContentRequest"];
+    assert_eq!(counted["model"], "models/gemini-1.5-pro");
+    assert_eq!(counted["contents"][0]["parts"][1]["inline_data"]["mime_type"], "image/png");
+}
+
+#[tokio::test]
+async fn test_generate_within_budget_just_over() {
+    // Arrange
+    let transport = Arc::new(MockHttpTransport::new());
+    transport.enqueue_json_response(200, r#"{"totalTokens": 264}"#);
+
+    let service = create_test_service(transport.clone());
+
+    // Act
+    let response = service
+        .generate_within_budget("gemini-1.5-pro", create_multimodal_request(), 263)
+        .await;
+
+    // Assert
+    match response {
+        Err(GeminiError::Request(RequestError::ValidationError { details, .. })) => {
+            assert_eq!(details.len(), 1);
+            assert_eq!(details[0].field, "contents");
+            assert!(details[0].description.contains("264"));
+            assert!(details[0].description.contains("263"));
+        }
+        other => panic!("Expected ValidationError, got {:?}", other),
+    }
+
+    // Only the countTokens call was made
+    transport.verify_request_count(1);
+}
+
+#[tokio::test]
+async fn test_generate_within_budget_enforces_model_limit() {
+    // Arrange - the budget is larger than gemini-1.0-pro's input window
+    let transport = Arc::new(MockHttpTransport::new());
+    transport.enqueue_json_response(200, r#"{"totalTokens": 30721}"#);
+
+    let service = create_test_service(transport.clone());
+
+    // Act
+    let response = service
+        .generate_within_budget("gemini-1.0-pro", create_multimodal_request(), 100_000)
+        .await;
+
+    // Assert
+    match response {
+        Err(GeminiError::Request(RequestError::ValidationError { details, .. })) => {
+            assert!(details[0].description.contains("30720"));
+        }
+        other => panic!("Expected ValidationError, got {:?}", other),
+    }
+    transport.verify_request_count(1);
+}
+
+#[test]
+fn test_grounding_sources_from_fixture() {
+    // Arrange
+    let response: GenerateContentResponse =
+        load_json_fixture("content/grounded_response.json");
+
+    // Act
+    let sources = response.grounding_sources();
+
+    // Assert
+    assert_eq!(sources.len(), 5);
+    assert_eq!(
+        sources[0],
+        GroundingSource::SearchQuery("who won euro 2024".to_string())
+    );
+    assert_eq!(
+        sources[1],
+        GroundingSource::Chunk {
+            index: 0,
+            title: Some("uefa.com".to_string()),
+            uri: Some("https://www.uefa.com/euro2024/".to_string()),
+        }
+    );
+    assert_eq!(
+        sources[2],
+        GroundingSource::Chunk {
+            index: 1,
+            title: Some("wikipedia.org".to_string()),
+            uri: Some("https://en.wikipedia.org/wiki/UEFA_Euro_2024".to_string()),
+        }
+    );
+    match &sources[3] {
+        GroundingSource::Support {
+            text,
+            start_index,
+            end_index,
+            chunk_indices,
+            confidence_scores,
+        } => {
+            assert_eq!(text, "Spain won Euro 2024");
+            assert_eq!(*start_index, Some(0));
+            assert_eq!(*end_index, Some(20));
+            assert_eq!(chunk_indices, &vec![0, 1]);
+            assert_eq!(confidence_scores, &vec![0.98, 0.95]);
+        }
+        other => panic!("Expected Support, got {:?}", other),
+    }
+    assert!(matches!(
+        &sources[4],
+        GroundingSource::Support { chunk_indices, .. } if chunk_indices == &vec![1]
+    ));
+}
+
+#[test]
+fn test_grounding_sources_without_metadata() {
+    let response: GenerateContentResponse =
+        load_json_fixture("content/success_response.json");
+
+    assert!(response.grounding_sources().is_empty());
+}
+
+#[tokio::test]
+async fn test_generate_content_vertex_mode() {
+    let transport = Arc::new(MockHttpTransport::new());
+    transport.enqueue_json_response(200, r#"{
+        "candidates": [{
+            "content": {"parts": [{"text": "Hello from Vertex"}], "role": "model"},
+            "finishReason": "STOP",
+            "index": 0
+        }]
+    }"#);
+
+    let config = Arc::new(
+        GeminiConfig::builder()
+            .vertex(VertexConfig::new("my-project", "us-central1"))
+            .access_token_provider(Arc::new(StaticTokenProvider::new(
+                SecretString::new("ya29.vertex-token".into()),
+            )))
+            .build()
+            .unwrap()
+    );
+    let auth_manager = Arc::new(VertexOAuthManager::from_config(&config).unwrap());
+    let (logger, tracer, metrics_recorder) = create_noop_stack();
+    let metrics = GeminiMetrics::new(Arc::new(metrics_recorder));
+    let service = ContentServiceImpl::new(
+        config,
+        transport.clone(),
+        auth_manager,
+        logger,
+        tracer,
+        metrics,
+    );
+
+    let request = GenerateContentRequest {
+        contents: vec![Content {
+            role: Some(Role::User),
+            parts: vec![Part::Text { text: "Hello".to_string() }],
+        }],
+        system_instruction: None,
+        tools: None,
+        tool_config: None,
+        safety_settings: None,
+        generation_config: None,
+        cached_content: None,
+    };
+
+    let response = service.generate("gemini-1.5-pro", request).await;
+    assert!(response.is_ok(), "Expected successful response");
+
+    transport.verify_request(
+        0,
+        HttpMethod::Post,
+        "https://us-central1-aiplatform.googleapis.com/v1/projects/my-project/locations/us-central1/publishers/google/models/gemini-1.5-pro:generateContent",
+    );
+    transport.verify_header(0, "Authorization", "Bearer ya29.vertex-token");
+    let request = transport.last_request().unwrap();
+    assert!(!request.headers.contains_key("x-goog-api-key"));
+    assert!(!request.url.contains("key="));
+}
@@ -391,6 +391,29 @@ fn test_content_error_prohibited_content() {
     assert!(error_msg.contains("Prohibited content"));
 }
 
+#[test]
+fn test_content_error_prompt_blocked() {
+    // Arrange
+    let error = ContentError::PromptBlocked {
+        block_reason: "SAFETY".to_string(),
+        safety_ratings: vec![
+            integrations_gemini::error::SafetyRatingInfo {
+                category: "HateSpeech".to_string(),
+                probability: "High".to_string(),
+            },
+        ],
+    };
+
+    // Act
+    let error_msg = error.to_string();
+
+    // Assert
+    assert!(error_msg.contains("Prompt blocked: SAFETY"));
+    assert!(error.is_prompt_block());
+    assert_eq!(error.blocked_category(), Some("HateSpeech"));
+    assert!(!GeminiError::Content(error).is_retryable());
+}
+
 #[test]
 fn test_resource_error_file_not_found() {
     // Arrange
@@ -1,12 +1,18 @@
 //! Integration tests for files service.
 
+use bytes::Bytes;
 use integrations_gemini::mocks::{MockAuthManager, MockHttpTransport};
-use integrations_gemini::services::files::FilesServiceImpl;
+use integrations_gemini::services::files::{FilesServiceImpl, RESUMABLE_CHUNK_GRANULARITY};
 use integrations_gemini::services::FilesService;
-use integrations_gemini::types::{UploadFileRequest, ListFilesParams, FileState};
-use integrations_gemini::{GeminiConfig, GeminiError};
+use integrations_gemini::types::{
+    UploadFileRequest, ListFilesParams, FileState, UploadProgress, UploadStatus,
+};
+use integrations_gemini::{GeminiConfig, GeminiError, HttpMethod, HttpResponse, RetryConfig};
+use integrations_gemini::TransportError;
 use secrecy::SecretString;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 /// Helper to create a test files service with mock transport.
@@ -520,3 +526,209 @@ async fn test_upload_bytes_helper() {
     let file = response.unwrap();
     assert_eq!(file.name, "files/test123");
 }
+
+/// Helper to create a files service that retries chunks without waiting.
+fn create_resumable_test_service(transport: Arc<MockHttpTransport>) -> FilesServiceImpl {
+    let config = Arc::new(
+        GeminiConfig::builder()
+            .api_key(SecretString::new("test-key".into()))
+            .retry_config(RetryConfig {
+                max_attempts: 3,
+                initial_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(1),
+                multiplier: 1.0,
+                jitter: 0.0,
+            })
+            .build()
+            .unwrap()
+    );
+
+    let auth_manager = Arc::new(MockAuthManager::new("test-key"));
+
+    FilesServiceImpl::new(config, transport, auth_manager)
+}
+
+/// Enqueue a response with the given status and headers.
+fn enqueue_with_headers(transport: &MockHttpTransport, status: u16, headers: &[(&str, &str)], body: &str) {
+    transport.enqueue_response(Ok(HttpResponse {
+        status,
+        headers: headers
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect::<HashMap<_, _>>(),
+        body: Bytes::from(body.to_string()),
+    }));
+}
+
+const UPLOAD_URL: &str = "https://generativelanguage.googleapis.com/upload/v1beta/files?upload_id=xyz";
+
+const UPLOADED_FILE_JSON: &str = r#"{"file": {
+    "name": "files/video123",
+    "displayName": "clip.mp4",
+    "mimeType": "video/mp4",
+    "state": "PROCESSING"
+}}"#;
+
+#[tokio::test]
+async fn test_start_resumable_upload() {
+    // Arrange
+    let transport = Arc::new(MockHttpTransport::new());
+    enqueue_with_headers(&transport, 200, &[("location", UPLOAD_URL)], "");
+    let service = create_test_service(transport.clone());
+
+    // Act
+    let upload = service
+        .start_resumable_upload(1024, "video/mp4", Some("clip.mp4".to_string()))
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(upload.upload_url, UPLOAD_URL);
+    assert_eq!(upload.total_size, 1024);
+    assert_eq!(upload.committed, 0);
+
+    let request = transport.last_request().unwrap();
+    assert_eq!(request.method, HttpMethod::Post);
+    assert!(request.url.contains("/upload/"));
+    assert!(request.url.contains("uploadType=resumable"));
+    assert_eq!(request.headers.get("X-Upload-Content-Type").unwrap(), "video/mp4");
+    assert_eq!(request.headers.get("X-Upload-Content-Length").unwrap(), "1024");
+    let body: serde_json::Value = serde_json::from_slice(request.body.as_ref().unwrap()).unwrap();
+    assert_eq!(body["file"]["display_name"], "clip.mp4");
+}
+
+#[tokio::test]
+async fn test_upload_chunk_and_query_status() {
+    // Arrange
+    let transport = Arc::new(MockHttpTransport::new());
+    enqueue_with_headers(&transport, 200, &[("Location", UPLOAD_URL)], "");
+    enqueue_with_headers(&transport, 308, &[("Range", "bytes=0-99")], "");
+    enqueue_with_headers(&transport, 308, &[("Range", "bytes=0-99")], "");
+    enqueue_with_headers(&transport, 200, &[], UPLOADED_FILE_JSON);
+    let service = create_test_service(transport.clone());
+
+    // Act & Assert
+    let mut upload = service.start_resumable_upload(150, "video/mp4", None).await.unwrap();
+
+    let status = service.upload_chunk(&mut upload, Bytes::from(vec![0u8; 100])).await.unwrap();
+    assert_eq!(status, UploadStatus::InProgress { committed: 100 });
+    assert_eq!(upload.committed, 100);
+
+    let status = service.query_upload_status(&mut upload).await.unwrap();
+    assert_eq!(status, UploadStatus::InProgress { committed: 100 });
+
+    let status = service.upload_chunk(&mut upload, Bytes::from(vec![1u8; 50])).await.unwrap();
+    match status {
+        UploadStatus::Complete(file) => assert_eq!(file.name, "files/video123"),
+        other => panic!("Expected Complete, got {:?}", other),
+    }
+
+    let requests = transport.get_requests();
+    assert_eq!(requests[1].method, HttpMethod::Put);
+    assert_eq!(requests[1].url, UPLOAD_URL);
+    assert_eq!(requests[1].headers.get("Content-Range").unwrap(), "bytes 0-99/150");
+    assert_eq!(requests[2].headers.get("Content-Range").unwrap(), "bytes */150");
+    assert!(requests[2].body.is_none());
+    assert_eq!(requests[3].headers.get("Content-Range").unwrap(), "bytes 100-149/150");
+}
+
+#[tokio::test]
+async fn test_upload_file_resumable_resumes_after_chunk_failure() {
+    // Arrange - three chunks; the second fails after the server kept part of it
+    let chunk_size = RESUMABLE_CHUNK_GRANULARITY;
+    let total = chunk_size * 3 + 1000;
+    let data: Vec<u8> = (0..total).map(|i| (i % 251) as u8).collect();
+    let mut file = tempfile::Builder::new().suffix(".mp4").tempfile().unwrap();
+    file.write_all(&data).unwrap();
+
+    let partial = chunk_size + 4096;
+    let transport = Arc::new(MockHttpTransport::new());
+    enqueue_with_headers(&transport, 200, &[("Location", UPLOAD_URL)], "");
+    let first_range = format!("bytes=0-{}", chunk_size - 1);
+    enqueue_with_headers(&transport, 308, &[("Range", first_range.as_str())], "");
+    transport.enqueue_error(TransportError::Connection("connection reset".to_string()));
+    let resumed_range = format!("bytes=0-{}", partial - 1);
+    enqueue_with_headers(&transport, 308, &[("Range", resumed_range.as_str())], "");
+    let second_range = format!("bytes=0-{}", partial + chunk_size - 1);
+    enqueue_with_headers(&transport, 308, &[("Range", second_range.as_str())], "");
+    enqueue_with_headers(&transport, 200, &[], UPLOADED_FILE_JSON);
+
+    let service = create_resumable_test_service(transport.clone());
+    let progress = Mutex::new(Vec::new());
+
+    // Act
+    let uploaded = service
+        .upload_file_resumable(file.path(), "video/mp4", chunk_size, &|p: UploadProgress| {
+            progress.lock().unwrap().push(p.bytes_uploaded);
+        })
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(uploaded.name, "files/video123");
+    let total = total as u64;
+    assert_eq!(
+        *progress.lock().unwrap(),
+        vec![chunk_size as u64, partial as u64, (partial + chunk_size) as u64, total]
+    );
+
+    let requests = transport.get_requests();
+    assert_eq!(requests.len(), 6);
+    let ranges: Vec<&str> = requests[1..]
+        .iter()
+        .map(|r| r.headers.get("Content-Range").unwrap().as_str())
+        .collect();
+    let expected = [
+        format!("bytes 0-{}/{}", chunk_size - 1, total),
+        format!("bytes {}-{}/{}", chunk_size, 2 * chunk_size - 1, total),
+        format!("bytes */{}", total),
+        format!("bytes {}-{}/{}", partial, partial + chunk_size - 1, total),
+        format!("bytes {}-{}/{}", partial + chunk_size, total - 1, total),
+    ];
+    assert_eq!(ranges, expected);
+
+    // The resumed chunk starts at the committed offset, not the failed chunk's start
+    let resumed = requests[4].body.as_ref().unwrap();
+    assert_eq!(resumed.as_ref(), &data[partial..partial + chunk_size]);
+    let last = requests[5].body.as_ref().unwrap();
+    assert_eq!(last.as_ref(), &data[partial + chunk_size..]);
+}
+
+#[tokio::test]
+async fn test_upload_file_resumable_gives_up_after_max_attempts() {
+    // Arrange
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    file.write_all(&[7u8; 10]).unwrap();
+
+    let transport = Arc::new(MockHttpTransport::new());
+    enqueue_with_headers(&transport, 200, &[("Location", UPLOAD_URL)], "");
+    for _ in 0..4 {
+        transport.enqueue_error(TransportError::Connection("connection reset".to_string()));
+    }
+    let service = create_resumable_test_service(transport.clone());
+
+    // Act
+    let result = service
+        .upload_file_resumable(file.path(), "text/plain", RESUMABLE_CHUNK_GRANULARITY, &|_| {})
+        .await;
+
+    // Assert - one chunk attempt plus three status queries
+    assert!(matches!(result, Err(GeminiError::Network(_))));
+    transport.verify_request_count(5);
+}
+
+#[tokio::test]
+async fn test_upload_file_resumable_rejects_unaligned_chunk_size() {
+    // Arrange
+    let transport = Arc::new(MockHttpTransport::new());
+    let service = create_test_service(transport.clone());
+
+    // Act
+    let result = service
+        .upload_file_resumable(std::path::Path::new("missing.mp4"), "video/mp4", 1000, &|_| {})
+        .await;
+
+    // Assert
+    assert!(matches!(result, Err(GeminiError::Request(_))));
+    transport.verify_request_count(0);
+}
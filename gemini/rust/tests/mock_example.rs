@@ -98,7 +98,7 @@ async fn test_mock_auth_manager_header_injection() {
     };
 
     // Act: Apply authentication
-    if let Some((name, value)) = auth.get_auth_header() {
+    if let Some((name, value)) = auth.get_auth_header().unwrap() {
         request.headers.insert(name, value);
     }
 
@@ -1,7 +1,8 @@
 //! Integration tests for streaming JSON parser.
 
-use integrations_gemini::streaming::GeminiChunkParser;
-use integrations_gemini::types::GenerateContentResponse;
+use integrations_gemini::fixtures::load_fixture;
+use integrations_gemini::streaming::{GeminiChunkParser, StreamAccumulator};
+use integrations_gemini::types::{FinishReason, GenerateContentResponse, Part};
 use integrations_gemini::error::GeminiError;
 use bytes::Bytes;
 use futures::stream;
@@ -333,3 +334,58 @@ async fn test_parse_real_world_streaming_pattern() {
     let last_candidate = &responses[3].candidates.as_ref().unwrap()[0];
     assert!(last_candidate.finish_reason.is_some());
 }
+
+#[tokio::test]
+async fn test_accumulate_parallel_function_calls() {
+    // Arrange - Recorded stream with two calls whose args are split across chunks
+    let data = load_fixture("streaming/function_calls_response.txt");
+    let chunks: Vec<Result<Bytes, GeminiError>> = data
+        .as_bytes()
+        .chunks(40)
+        .map(|chunk| Ok(Bytes::copy_from_slice(chunk)))
+        .collect();
+    let mut parser = GeminiChunkParser::new(Box::pin(stream::iter(chunks)));
+    let mut accumulator = StreamAccumulator::new();
+
+    // Act
+    let mut completed = Vec::new();
+    while let Some(result) = parser.next().await {
+        completed.extend(accumulator.add_chunk(result.unwrap()));
+    }
+
+    // Assert - both calls are emitted once the candidate finishes
+    assert_eq!(completed.len(), 2);
+    assert_eq!(completed[0].name, "get_weather");
+    assert_eq!(
+        completed[0].args,
+        serde_json::json!({"location": "Paris", "unit": "celsius"})
+    );
+    assert_eq!(completed[1].name, "get_weather");
+    assert_eq!(completed[1].args, serde_json::json!({"location": "Tokyo"}));
+    assert_eq!(accumulator.function_calls(), completed.as_slice());
+
+    let response = accumulator.finalize();
+    let candidate = &response.candidates.as_ref().unwrap()[0];
+    assert_eq!(candidate.finish_reason, Some(FinishReason::Stop));
+
+    // Text comes first, then the calls in the order they started
+    let parts = &candidate.content.parts;
+    assert_eq!(parts.len(), 3);
+    assert!(matches!(&parts[0], Part::Text { text } if text == "Checking both cities."));
+    assert!(matches!(&parts[1], Part::FunctionCall { function_call } if *function_call == completed[0]));
+    assert!(matches!(&parts[2], Part::FunctionCall { function_call } if *function_call == completed[1]));
+
+    // Grounding metadata is merged across chunks
+    let grounding = candidate.grounding_metadata.as_ref().unwrap();
+    assert_eq!(
+        grounding.web_search_queries,
+        Some(vec!["weather Paris".to_string(), "weather Tokyo".to_string()])
+    );
+    assert_eq!(grounding.grounding_chunks.as_ref().map(Vec::len), Some(1));
+
+    // Usage metadata comes from the final chunk
+    let usage = response.usage_metadata.unwrap();
+    assert_eq!(usage.candidates_token_count, Some(31));
+    assert_eq!(usage.total_token_count, 55);
+    assert_eq!(response.model_version.as_deref(), Some("gemini-1.5-pro"));
+}
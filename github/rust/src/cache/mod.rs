@@ -0,0 +1,212 @@
+//! ETag caching for conditional GET requests.
+//!
+//! GitHub does not count `304 Not Modified` responses against the primary rate
+//! limit, so repeated reads of unchanged resources can be served from a cache
+//! by sending the previously returned ETag in `If-None-Match`.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+
+/// Default time an ETag entry is kept without being revalidated.
+pub const DEFAULT_ETAG_TTL: Duration = Duration::from_secs(300);
+
+/// A cached response body and the ETag it was served with.
+#[derive(Debug, Clone)]
+pub struct CachedResponse {
+    /// ETag returned by GitHub.
+    pub etag: String,
+    /// Raw JSON response body.
+    pub body: Vec<u8>,
+    /// Time after which the entry is discarded.
+    pub expires_at: Instant,
+}
+
+impl CachedResponse {
+    /// Creates a new cached response that expires after `ttl`.
+    pub fn new(etag: impl Into<String>, body: Vec<u8>, ttl: Duration) -> Self {
+        Self {
+            etag: etag.into(),
+            body,
+            expires_at: Instant::now() + ttl,
+        }
+    }
+
+    /// Returns true if the entry has expired.
+    pub fn is_expired(&self) -> bool {
+        Instant::now() >= self.expires_at
+    }
+}
+
+/// Storage backend for ETag cache entries.
+#[async_trait]
+pub trait EtagStore: Send + Sync {
+    /// Gets the entry for a request key.
+    async fn get(&self, key: &str) -> Option<CachedResponse>;
+
+    /// Stores the entry for a request key.
+    async fn put(&self, key: &str, entry: CachedResponse);
+
+    /// Removes the entry for a request key.
+    async fn remove(&self, key: &str);
+}
+
+/// In-memory ETag store.
+#[derive(Debug, Default)]
+pub struct InMemoryEtagStore {
+    entries: RwLock<HashMap<String, CachedResponse>>,
+}
+
+impl InMemoryEtagStore {
+    /// Creates a new in-memory store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of stored entries, including expired ones.
+    pub async fn len(&self) -> usize {
+        self.entries.read().await.len()
+    }
+
+    /// Returns true if the store is empty.
+    pub async fn is_empty(&self) -> bool {
+        self.entries.read().await.is_empty()
+    }
+}
+
+#[async_trait]
+impl EtagStore for InMemoryEtagStore {
+    async fn get(&self, key: &str) -> Option<CachedResponse> {
+        let entry = self.entries.read().await.get(key).cloned()?;
+        if entry.is_expired() {
+            self.entries.write().await.remove(key);
+            return None;
+        }
+        Some(entry)
+    }
+
+    async fn put(&self, key: &str, entry: CachedResponse) {
+        self.entries.write().await.insert(key.to_string(), entry);
+    }
+
+    async fn remove(&self, key: &str) {
+        self.entries.write().await.remove(key);
+    }
+}
+
+/// ETag cache used by the client for conditional GET requests.
+#[derive(Clone)]
+pub struct EtagCache {
+    /// Storage backend.
+    store: Arc<dyn EtagStore>,
+    /// Time an entry is kept without being revalidated.
+    ttl: Duration,
+}
+
+impl EtagCache {
+    /// Creates a cache backed by the given store.
+    pub fn new(store: Arc<dyn EtagStore>, ttl: Duration) -> Self {
+        Self { store, ttl }
+    }
+
+    /// Creates a cache backed by an in-memory store.
+    pub fn in_memory(ttl: Duration) -> Self {
+        Self::new(Arc::new(InMemoryEtagStore::new()), ttl)
+    }
+
+    /// Gets the entry time-to-live.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Builds the cache key for a GET request to `url`.
+    pub fn key(url: &str) -> String {
+        format!("GET {}", url)
+    }
+
+    /// Looks up a cached response.
+    pub async fn lookup(&self, key: &str) -> Option<CachedResponse> {
+        self.store.get(key).await
+    }
+
+    /// Stores a response body with its ETag.
+    pub async fn store(&self, key: &str, etag: impl Into<String>, body: Vec<u8>) {
+        self.store
+            .put(key, CachedResponse::new(etag, body, self.ttl))
+            .await;
+    }
+
+    /// Extends the lifetime of a revalidated entry.
+    pub async fn refresh(&self, key: &str, entry: CachedResponse) {
+        self.store
+            .put(key, CachedResponse::new(entry.etag, entry.body, self.ttl))
+            .await;
+    }
+
+    /// Removes a cached response.
+    pub async fn invalidate(&self, key: &str) {
+        self.store.remove(key).await;
+    }
+}
+
+impl Default for EtagCache {
+    fn default() -> Self {
+        Self::in_memory(DEFAULT_ETAG_TTL)
+    }
+}
+
+impl std::fmt::Debug for EtagCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EtagCache").field("ttl", &self.ttl).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_store_and_lookup() {
+        let cache = EtagCache::default();
+        let key = EtagCache::key("https://api.github.com/user");
+
+        assert!(cache.lookup(&key).await.is_none());
+
+        cache.store(&key, "\"abc\"", b"{}".to_vec()).await;
+        let entry = cache.lookup(&key).await.unwrap();
+        assert_eq!(entry.etag, "\"abc\"");
+        assert_eq!(entry.body, b"{}");
+
+        cache.invalidate(&key).await;
+        assert!(cache.lookup(&key).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_expired_entries_are_dropped() {
+        let store = Arc::new(InMemoryEtagStore::new());
+        let cache = EtagCache::new(store.clone(), Duration::ZERO);
+        let key = EtagCache::key("https://api.github.com/user");
+
+        cache.store(&key, "\"abc\"", b"{}".to_vec()).await;
+        assert!(cache.lookup(&key).await.is_none());
+        assert!(store.is_empty().await);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_extends_expiry() {
+        let cache = EtagCache::in_memory(Duration::from_secs(60));
+        let key = EtagCache::key("https://api.github.com/user");
+
+        let stale = CachedResponse {
+            etag: "\"abc\"".to_string(),
+            body: b"{}".to_vec(),
+            expires_at: Instant::now(),
+        };
+        cache.refresh(&key, stale.clone()).await;
+
+        let entry = cache.lookup(&key).await.unwrap();
+        assert!(entry.expires_at > stale.expires_at);
+    }
+}
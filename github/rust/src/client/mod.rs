@@ -1,15 +1,17 @@
 //! GitHub API client implementation.
 
-use crate::auth::{AuthManager, AuthMethod};
+use crate::auth::{AuthManager, AuthMethod, HttpInstallationTokenFetcher};
+use crate::cache::EtagCache;
 use crate::config::{GitHubConfig, GitHubConfigBuilder};
 use crate::errors::{GitHubError, GitHubErrorKind, GitHubResult, RateLimitInfo};
-use crate::pagination::{Page, PaginationLinks, PaginationParams};
+use crate::pagination::{self, Page, PaginationLinks, PaginationParams};
 use crate::resilience::{CircuitBreaker, RateLimitTracker, ResilienceOrchestrator, RetryExecutor};
 use crate::services::*;
 use chrono::{DateTime, Utc};
-use reqwest::{header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, USER_AGENT}, Client, Method, Response, StatusCode};
+use futures::Stream;
+use reqwest::{header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, ETAG, IF_NONE_MATCH, USER_AGENT}, Client, Method, Response, StatusCode};
 use serde::{de::DeserializeOwned, Serialize};
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
 /// GitHub error response format.
 #[derive(Debug, serde::Deserialize)]
@@ -38,6 +40,10 @@ pub struct GitHubClient {
     auth: Arc<AuthManager>,
     /// Resilience orchestrator.
     resilience: Arc<ResilienceOrchestrator>,
+    /// Most recent GraphQL rate limit snapshot.
+    graphql_rate_limit: RwLock<Option<GraphQLRateLimit>>,
+    /// ETag cache for conditional GET requests.
+    etag_cache: Option<EtagCache>,
 }
 
 impl GitHubClient {
@@ -58,11 +64,17 @@ impl GitHubClient {
                 )
             })?;
 
-        let auth = Arc::new(AuthManager::new(
-            config.auth.clone().ok_or_else(|| {
+        let auth = Arc::new(
+            AuthManager::new(config.auth.clone().ok_or_else(|| {
                 GitHubError::new(GitHubErrorKind::MissingAuth, "Authentication required")
-            })?,
-        ));
+            })?)
+            .with_token_fetcher(Arc::new(HttpInstallationTokenFetcher::new(
+                http.clone(),
+                config.base_url.clone(),
+                config.user_agent.clone(),
+                config.api_version.clone(),
+            ))),
+        );
 
         let retry = RetryExecutor::new(
             config.retry.max_attempts,
@@ -91,9 +103,17 @@ impl GitHubClient {
             config,
             auth,
             resilience,
+            graphql_rate_limit: RwLock::new(None),
+            etag_cache: None,
         })
     }
 
+    /// Enables conditional GET requests backed by the given ETag cache.
+    pub fn with_etag_cache(mut self, cache: EtagCache) -> Self {
+        self.etag_cache = Some(cache);
+        self
+    }
+
     /// Creates a new client builder.
     pub fn builder() -> GitHubClientBuilder {
         GitHubClientBuilder::new()
@@ -104,6 +124,43 @@ impl GitHubClient {
         &self.config.base_url
     }
 
+    /// Gets the client configuration.
+    pub fn config(&self) -> &GitHubConfig {
+        &self.config
+    }
+
+    /// Gets the authentication manager.
+    pub(crate) fn auth_manager(&self) -> &AuthManager {
+        &self.auth
+    }
+
+    /// Gets the ETag cache, if enabled.
+    pub fn etag_cache(&self) -> Option<&EtagCache> {
+        self.etag_cache.as_ref()
+    }
+
+    /// Gets the primary rate limit tracker.
+    pub fn rate_limit_tracker(&self) -> &RateLimitTracker {
+        self.resilience.rate_limit_tracker()
+    }
+
+    /// Gets the most recent GraphQL rate limit snapshot.
+    ///
+    /// Populated from responses whose query selected the `rateLimit` field.
+    pub fn graphql_rate_limit(&self) -> Option<GraphQLRateLimit> {
+        self.graphql_rate_limit
+            .read()
+            .ok()
+            .and_then(|snapshot| snapshot.clone())
+    }
+
+    /// Records a GraphQL rate limit snapshot.
+    pub(crate) fn record_graphql_rate_limit(&self, rate_limit: GraphQLRateLimit) {
+        if let Ok(mut snapshot) = self.graphql_rate_limit.write() {
+            *snapshot = Some(rate_limit);
+        }
+    }
+
     // Service accessors
 
     /// Gets the repositories service.
@@ -111,6 +168,16 @@ impl GitHubClient {
         RepositoriesService::new(self)
     }
 
+    /// Gets the GitHub Apps service.
+    pub fn apps(&self) -> AppsService {
+        AppsService::new(self)
+    }
+
+    /// Gets the contents service.
+    pub fn contents(&self) -> ContentsService {
+        ContentsService::new(self)
+    }
+
     /// Gets the issues service.
     pub fn issues(&self) -> IssuesService {
         IssuesService::new(self)
@@ -136,6 +203,11 @@ impl GitHubClient {
         ActionsService::new(self)
     }
 
+    /// Gets the checks service.
+    pub fn checks(&self) -> ChecksService {
+        ChecksService::new(self)
+    }
+
     /// Gets the gists service.
     pub fn gists(&self) -> GistsService {
         GistsService::new(self)
@@ -156,6 +228,23 @@ impl GitHubClient {
         GraphQLService::new(self)
     }
 
+    /// Executes a GraphQL query and returns its `data` as untyped JSON.
+    ///
+    /// Partial errors returned alongside data are dropped; use
+    /// [`GraphQLService::query`] to inspect them.
+    pub async fn graphql_query(
+        &self,
+        query: &str,
+        variables: Option<serde_json::Value>,
+    ) -> GitHubResult<serde_json::Value> {
+        let response = self
+            .graphql()
+            .query::<serde_json::Value>(query, variables)
+            .await?;
+
+        Ok(response.data.unwrap_or(serde_json::Value::Null))
+    }
+
     // HTTP methods
 
     /// Makes a GET request.
@@ -199,6 +288,42 @@ impl GitHubClient {
             .with_per_page(pagination.per_page.unwrap_or(30)))
     }
 
+    /// Streams every item of a paginated list, following `rel="next"` links.
+    ///
+    /// Handles both bare array responses and wrapped ones such as search results.
+    /// Waits for the rate limit to reset between pages when it is exhausted.
+    pub fn paginate_stream<'a, T, P>(
+        &'a self,
+        path: &str,
+        params: &P,
+    ) -> impl Stream<Item = GitHubResult<T>> + 'a
+    where
+        T: DeserializeOwned + 'a,
+        P: Serialize + ?Sized,
+    {
+        let start_url = self.build_url(path).and_then(|url| {
+            let query_string = serde_urlencoded::to_string(params).map_err(|e| {
+                GitHubError::new(
+                    GitHubErrorKind::InvalidParameter,
+                    format!("Failed to serialize parameters: {}", e),
+                )
+            })?;
+
+            Ok(if query_string.is_empty() {
+                url
+            } else {
+                format!("{}?{}", url, query_string)
+            })
+        });
+
+        match start_url {
+            Ok(url) => futures::future::Either::Left(pagination::paginate_stream(url, move |url| {
+                self.fetch_page(url)
+            })),
+            Err(e) => futures::future::Either::Right(futures::stream::once(async move { Err(e) })),
+        }
+    }
+
     /// Makes a POST request.
     pub async fn post<T: DeserializeOwned, B: Serialize>(&self, path: &str, body: &B) -> GitHubResult<T> {
         self.request(Method::POST, path, Some(body)).await
@@ -254,6 +379,13 @@ impl GitHubClient {
         body: Option<&B>,
     ) -> GitHubResult<T> {
         let url = self.build_url(path)?;
+
+        if method == Method::GET {
+            if let Some(cache) = &self.etag_cache {
+                return self.cached_get(cache, &url).await;
+            }
+        }
+
         let response = self
             .execute_request(method, &url, &[] as &[(&str, &str)], body)
             .await?;
@@ -287,6 +419,12 @@ impl GitHubClient {
             format!("{}?{}", url, query_string)
         };
 
+        if method == Method::GET {
+            if let Some(cache) = &self.etag_cache {
+                return self.cached_get(cache, &full_url).await;
+            }
+        }
+
         let response = self
             .execute_request(method, &full_url, &[] as &[(&str, &str)], body)
             .await?;
@@ -299,6 +437,32 @@ impl GitHubClient {
         })
     }
 
+    async fn fetch_page<T: DeserializeOwned>(&self, url: String) -> GitHubResult<Page<T>> {
+        let response = self
+            .execute_request(Method::GET, &url, &[] as &[(&str, &str)], Option::<&()>::None)
+            .await?;
+
+        let links = PaginationLinks::from_headers(response.headers());
+        let rate_limit = Self::extract_rate_limit(response.headers());
+        let body = response.bytes().await.map_err(|e| {
+            GitHubError::new(
+                GitHubErrorKind::DeserializationError,
+                format!("Failed to read response body: {}", e),
+            )
+        })?;
+
+        let (items, total_count) = pagination::parse_page_items(&body)?;
+        let mut page = Page::new(items, links);
+        if let Some(count) = total_count {
+            page = page.with_total_count(count);
+        }
+        if let Some(info) = rate_limit {
+            page = page.with_rate_limit(info);
+        }
+
+        Ok(page)
+    }
+
     async fn request_no_response<B: Serialize>(
         &self,
         method: Method,
@@ -311,12 +475,93 @@ impl GitHubClient {
         Ok(())
     }
 
+    /// Performs a GET using `If-None-Match`, serving the cached body on `304 Not Modified`.
+    async fn cached_get<T: DeserializeOwned>(&self, cache: &EtagCache, url: &str) -> GitHubResult<T> {
+        let key = EtagCache::key(url);
+        let cached = cache.lookup(&key).await;
+
+        let mut headers = HeaderMap::new();
+        if let Some(value) = cached
+            .as_ref()
+            .and_then(|entry| HeaderValue::from_str(&entry.etag).ok())
+        {
+            headers.insert(IF_NONE_MATCH, value);
+        }
+
+        let response = self
+            .execute_request_with_headers(
+                Method::GET,
+                url,
+                &[] as &[(&str, &str)],
+                Option::<&()>::None,
+                headers,
+            )
+            .await?;
+
+        if response.status() == StatusCode::NOT_MODIFIED {
+            let entry = cached.ok_or_else(|| {
+                GitHubError::new(
+                    GitHubErrorKind::Unknown,
+                    "Received 304 Not Modified without a cached response",
+                )
+            })?;
+
+            let value = serde_json::from_slice(&entry.body).map_err(|e| {
+                GitHubError::new(
+                    GitHubErrorKind::DeserializationError,
+                    format!("Failed to deserialize cached response: {}", e),
+                )
+            })?;
+            cache.refresh(&key, entry).await;
+            return Ok(value);
+        }
+
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(String::from);
+
+        let body = response.bytes().await.map_err(|e| {
+            GitHubError::new(
+                GitHubErrorKind::DeserializationError,
+                format!("Failed to read response body: {}", e),
+            )
+        })?;
+
+        let value = serde_json::from_slice(&body).map_err(|e| {
+            GitHubError::new(
+                GitHubErrorKind::DeserializationError,
+                format!("Failed to deserialize response: {}", e),
+            )
+        })?;
+
+        match etag {
+            Some(etag) => cache.store(&key, etag, body.to_vec()).await,
+            None => cache.invalidate(&key).await,
+        }
+
+        Ok(value)
+    }
+
     async fn execute_request<B: Serialize>(
         &self,
         method: Method,
         url: &str,
         params: &[(&str, &str)],
         body: Option<&B>,
+    ) -> GitHubResult<Response> {
+        self.execute_request_with_headers(method, url, params, body, HeaderMap::new())
+            .await
+    }
+
+    async fn execute_request_with_headers<B: Serialize>(
+        &self,
+        method: Method,
+        url: &str,
+        params: &[(&str, &str)],
+        body: Option<&B>,
+        extra_headers: HeaderMap,
     ) -> GitHubResult<Response> {
         let auth_header = self.auth.get_auth_header().await?;
 
@@ -344,6 +589,7 @@ impl GitHubClient {
                 let user_agent = user_agent.clone();
                 let api_version = api_version.clone();
                 let body_bytes = body_bytes.clone();
+                let extra_headers = extra_headers.clone();
 
                 async move {
                     let mut request = http
@@ -351,7 +597,8 @@ impl GitHubClient {
                         .header(AUTHORIZATION, &auth_header)
                         .header(USER_AGENT, &user_agent)
                         .header(ACCEPT, "application/vnd.github+json")
-                        .header("X-GitHub-Api-Version", &api_version);
+                        .header("X-GitHub-Api-Version", &api_version)
+                        .headers(extra_headers);
 
                     if let Some(bytes) = body_bytes {
                         request = request
@@ -378,9 +625,9 @@ impl GitHubClient {
                     // Extract rate limit info
                     let rate_limit = Self::extract_rate_limit(response.headers());
 
-                    // Check for errors
+                    // Check for errors; 304 answers a conditional request
                     let status = response.status();
-                    if !status.is_success() {
+                    if !status.is_success() && status != StatusCode::NOT_MODIFIED {
                         return Err(Self::handle_error_response(response, rate_limit).await);
                     }
 
@@ -389,7 +636,11 @@ impl GitHubClient {
             })
             .await?;
 
-        // Update rate limit tracker
+        // Update rate limit tracker; 304 responses do not count against the limit
+        if response.status() == StatusCode::NOT_MODIFIED {
+            return Ok(response);
+        }
+
         if let Some(info) = Self::extract_rate_limit(response.headers()) {
             self.resilience.update_rate_limit(&info).await;
         }
@@ -448,14 +699,11 @@ impl GitHubClient {
             .and_then(|v| v.to_str().ok())
             .map(String::from);
 
-        // Check for rate limit errors
-        if status == StatusCode::FORBIDDEN || status == StatusCode::TOO_MANY_REQUESTS {
-            if let Some(info) = rate_limit {
-                if info.remaining == 0 {
-                    return GitHubError::rate_limit(info);
-                }
-            }
-        }
+        let retry_after = response
+            .headers()
+            .get("retry-after")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse().ok());
 
         // Try to parse error body
         let error_body = response.json::<GitHubErrorResponse>().await.ok();
@@ -467,6 +715,18 @@ impl GitHubClient {
 
         let documentation_url = error_body.as_ref().and_then(|e| e.documentation_url.clone());
 
+        // 403 covers primary and secondary rate limits as well as permission failures
+        if status == StatusCode::FORBIDDEN || status == StatusCode::TOO_MANY_REQUESTS {
+            return GitHubError::from_rate_limited_response(
+                status.as_u16(),
+                message,
+                documentation_url,
+                request_id,
+                rate_limit,
+                retry_after,
+            );
+        }
+
         let mut error = GitHubError::from_response(
             status.as_u16(),
             message,
@@ -485,6 +745,7 @@ impl GitHubClient {
 /// Builder for GitHubClient.
 pub struct GitHubClientBuilder {
     config_builder: GitHubConfigBuilder,
+    etag_cache: Option<EtagCache>,
 }
 
 impl GitHubClientBuilder {
@@ -492,6 +753,7 @@ impl GitHubClientBuilder {
     pub fn new() -> Self {
         Self {
             config_builder: GitHubConfig::builder(),
+            etag_cache: None,
         }
     }
 
@@ -536,10 +798,20 @@ impl GitHubClientBuilder {
         self
     }
 
+    /// Enables conditional GET requests with the given ETag cache.
+    pub fn etag_cache(mut self, cache: EtagCache) -> Self {
+        self.etag_cache = Some(cache);
+        self
+    }
+
     /// Builds the client.
     pub fn build(self) -> GitHubResult<GitHubClient> {
         let config = self.config_builder.build()?;
-        GitHubClient::new(config)
+        let client = GitHubClient::new(config)?;
+        Ok(match self.etag_cache {
+            Some(cache) => client.with_etag_cache(cache),
+            None => client,
+        })
     }
 }
 
@@ -580,4 +852,122 @@ mod tests {
 
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_etag_cache_serves_not_modified() {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Viewer {
+            login: String,
+            id: u64,
+        }
+
+        let server = MockServer::start().await;
+        let reset = (Utc::now() + chrono::Duration::hours(1)).timestamp().to_string();
+
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .and(header("if-none-match", "\"v1\""))
+            .respond_with(
+                ResponseTemplate::new(304)
+                    .insert_header("etag", "\"v1\"")
+                    .insert_header("x-ratelimit-limit", "5000")
+                    .insert_header("x-ratelimit-remaining", "4998")
+                    .insert_header("x-ratelimit-reset", reset.as_str()),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "login": "octocat", "id": 1 }))
+                    .insert_header("etag", "\"v1\"")
+                    .insert_header("x-ratelimit-limit", "5000")
+                    .insert_header("x-ratelimit-remaining", "4999")
+                    .insert_header("x-ratelimit-reset", reset.as_str()),
+            )
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = GitHubClient::builder()
+            .base_url(server.uri())
+            .pat("ghp_test")
+            .no_retry()
+            .etag_cache(EtagCache::default())
+            .build()
+            .unwrap();
+
+        let first: Viewer = client.get("/user").await.unwrap();
+        assert_eq!(client.rate_limit_tracker().remaining(), 4999);
+
+        let second: Viewer = client.get("/user").await.unwrap();
+        assert_eq!(first, second);
+        assert_eq!(second.login, "octocat");
+        assert_eq!(client.rate_limit_tracker().remaining(), 4999);
+
+        let key = EtagCache::key(&format!("{}/user", server.uri()));
+        let entry = client.etag_cache().unwrap().lookup(&key).await.unwrap();
+        assert_eq!(entry.etag, "\"v1\"");
+    }
+
+    #[tokio::test]
+    async fn test_forbidden_responses_are_classified() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let reset = (Utc::now() + chrono::Duration::hours(1)).timestamp().to_string();
+
+        let forbidden = |message: &str, remaining: &str| {
+            ResponseTemplate::new(403)
+                .set_body_json(serde_json::json!({ "message": message }))
+                .insert_header("x-ratelimit-limit", "5000")
+                .insert_header("x-ratelimit-remaining", remaining)
+                .insert_header("x-ratelimit-reset", reset.as_str())
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/primary"))
+            .respond_with(forbidden("API rate limit exceeded for user ID 1.", "0"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/secondary"))
+            .respond_with(
+                forbidden("You have exceeded a secondary rate limit.", "4000")
+                    .insert_header("retry-after", "42"),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/forbidden"))
+            .respond_with(forbidden("Resource not accessible by integration", "4000"))
+            .mount(&server)
+            .await;
+
+        let client = GitHubClient::builder()
+            .base_url(server.uri())
+            .pat("ghp_test")
+            .no_retry()
+            .build()
+            .unwrap();
+
+        let primary = client.get::<serde_json::Value>("/primary").await.unwrap_err();
+        assert_eq!(*primary.kind(), GitHubErrorKind::PrimaryRateLimitExceeded);
+
+        let secondary = client.get::<serde_json::Value>("/secondary").await.unwrap_err();
+        assert_eq!(*secondary.kind(), GitHubErrorKind::SecondaryRateLimitExceeded);
+        assert_eq!(secondary.retry_after(), Some(42));
+
+        let forbidden = client.get::<serde_json::Value>("/forbidden").await.unwrap_err();
+        assert_eq!(*forbidden.kind(), GitHubErrorKind::Forbidden);
+        assert!(!forbidden.is_retryable());
+    }
 }
@@ -192,6 +192,8 @@ pub struct GitHubError {
     documentation_url: Option<String>,
     /// Rate limit info (if applicable).
     rate_limit: Option<RateLimitInfo>,
+    /// Explicit retry delay in seconds (if applicable).
+    retry_after: Option<u64>,
     /// Underlying cause.
     #[source]
     cause: Option<Box<dyn std::error::Error + Send + Sync>>,
@@ -220,6 +222,7 @@ impl GitHubError {
             request_id: None,
             documentation_url: None,
             rate_limit: None,
+            retry_after: None,
             cause: None,
         }
     }
@@ -248,6 +251,12 @@ impl GitHubError {
         self
     }
 
+    /// Sets the retry delay in seconds.
+    pub fn with_retry_after(mut self, seconds: u64) -> Self {
+        self.retry_after = Some(seconds);
+        self
+    }
+
     /// Sets the underlying cause.
     pub fn with_cause(mut self, cause: impl std::error::Error + Send + Sync + 'static) -> Self {
         self.cause = Some(Box::new(cause));
@@ -281,6 +290,10 @@ impl GitHubError {
 
     /// Returns the retry-after duration in seconds.
     pub fn retry_after(&self) -> Option<u64> {
+        if self.retry_after.is_some() {
+            return self.retry_after;
+        }
+
         self.rate_limit.as_ref().and_then(|r| r.retry_after).or_else(|| {
             if let Some(ref rl) = self.rate_limit {
                 let now = Utc::now();
@@ -331,6 +344,50 @@ impl GitHubError {
         error
     }
 
+    /// Creates an error from a 403 or 429 response, telling rate limits apart
+    /// from permission failures.
+    ///
+    /// A response with `x-ratelimit-remaining: 0` is a primary rate limit. A
+    /// response whose message mentions a secondary rate limit or abuse
+    /// detection, that carries `Retry-After`, or that has status 429 is a
+    /// secondary rate limit; its retry delay is the `Retry-After` value or
+    /// [`SECONDARY_RATE_LIMIT_DEFAULT_RETRY_AFTER`] when absent. Anything else
+    /// is handled by [`GitHubError::from_response`].
+    pub fn from_rate_limited_response(
+        status: u16,
+        message: String,
+        documentation_url: Option<String>,
+        request_id: Option<String>,
+        rate_limit: Option<RateLimitInfo>,
+        retry_after: Option<u64>,
+    ) -> Self {
+        let primary = rate_limit.as_ref().is_some_and(|info| info.remaining == 0);
+        let secondary = !primary
+            && (status == 429 || retry_after.is_some() || is_secondary_rate_limit_message(&message));
+
+        let mut error = if primary {
+            Self::new(GitHubErrorKind::PrimaryRateLimitExceeded, message).with_status(status)
+        } else if secondary {
+            Self::new(GitHubErrorKind::SecondaryRateLimitExceeded, message)
+                .with_status(status)
+                .with_retry_after(retry_after.unwrap_or(SECONDARY_RATE_LIMIT_DEFAULT_RETRY_AFTER))
+        } else {
+            Self::from_response(status, message, None, None)
+        };
+
+        if let Some(url) = documentation_url {
+            error = error.with_documentation_url(url);
+        }
+        if let Some(id) = request_id {
+            error = error.with_request_id(id);
+        }
+        if let Some(info) = rate_limit {
+            error = error.with_rate_limit(info);
+        }
+
+        error
+    }
+
     /// Maps HTTP status code to error kind.
     fn kind_from_status(status: u16) -> GitHubErrorKind {
         match status {
@@ -392,6 +449,17 @@ impl GitHubError {
     }
 }
 
+/// Retry delay in seconds for secondary rate limits without a `Retry-After` header.
+///
+/// GitHub asks clients to wait at least one minute in that case.
+pub const SECONDARY_RATE_LIMIT_DEFAULT_RETRY_AFTER: u64 = 60;
+
+/// Returns true if a response message describes a secondary rate limit.
+fn is_secondary_rate_limit_message(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("secondary rate limit") || message.contains("abuse detection")
+}
+
 /// Type guard for GitHubError.
 pub fn is_github_error(error: &dyn std::error::Error) -> bool {
     error.downcast_ref::<GitHubError>().is_some()
@@ -447,4 +515,79 @@ mod tests {
         assert_eq!(error.documentation_url(), Some("https://docs.github.com"));
         assert_eq!(error.request_id(), Some("req-123"));
     }
+
+    fn rate_limit_info(remaining: u32) -> RateLimitInfo {
+        RateLimitInfo {
+            limit: 5000,
+            remaining,
+            reset_at: Utc::now() + chrono::Duration::seconds(600),
+            retry_after: None,
+            resource: Some("core".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_forbidden_primary_rate_limit() {
+        let error = GitHubError::from_rate_limited_response(
+            403,
+            "API rate limit exceeded for user ID 1.".to_string(),
+            None,
+            Some("req-1".to_string()),
+            Some(rate_limit_info(0)),
+            None,
+        );
+
+        assert_eq!(*error.kind(), GitHubErrorKind::PrimaryRateLimitExceeded);
+        assert_eq!(error.status_code(), Some(403));
+        assert_eq!(error.request_id(), Some("req-1"));
+        assert!(error.is_retryable());
+        assert!(error.retry_after().unwrap() > 500);
+    }
+
+    #[test]
+    fn test_forbidden_secondary_rate_limit() {
+        let error = GitHubError::from_rate_limited_response(
+            403,
+            "You have exceeded a secondary rate limit. Please wait a few minutes before you try again.".to_string(),
+            Some("https://docs.github.com/rest/overview/rate-limits-for-the-rest-api".to_string()),
+            None,
+            Some(rate_limit_info(4321)),
+            None,
+        );
+
+        assert_eq!(*error.kind(), GitHubErrorKind::SecondaryRateLimitExceeded);
+        assert!(error.is_retryable());
+        assert_eq!(
+            error.retry_after(),
+            Some(SECONDARY_RATE_LIMIT_DEFAULT_RETRY_AFTER)
+        );
+
+        let error = GitHubError::from_rate_limited_response(
+            403,
+            "Forbidden".to_string(),
+            None,
+            None,
+            Some(rate_limit_info(4321)),
+            Some(30),
+        );
+
+        assert_eq!(*error.kind(), GitHubErrorKind::SecondaryRateLimitExceeded);
+        assert_eq!(error.retry_after(), Some(30));
+    }
+
+    #[test]
+    fn test_forbidden_permission_failure() {
+        let error = GitHubError::from_rate_limited_response(
+            403,
+            "Resource not accessible by integration".to_string(),
+            None,
+            None,
+            Some(rate_limit_info(4321)),
+            None,
+        );
+
+        assert_eq!(*error.kind(), GitHubErrorKind::Forbidden);
+        assert!(!error.is_retryable());
+        assert!(!is_rate_limit_error(&error));
+    }
 }
@@ -5,6 +5,7 @@
 //! - GraphQL API support with cost-based rate limiting
 //! - Multiple authentication methods (PAT, GitHub App, OAuth, Actions)
 //! - Automatic pagination handling
+//! - ETag-based conditional requests
 //! - Webhook signature verification
 //! - Resilience patterns (retry, circuit breaker, rate limiting)
 //! - Comprehensive observability
@@ -47,6 +48,9 @@ pub mod auth;
 // HTTP client and transport
 pub mod client;
 
+// Conditional request caching
+pub mod cache;
+
 // Pagination handling
 pub mod pagination;
 
@@ -69,6 +73,10 @@ pub mod mocks;
 pub use client::{GitHubClient, GitHubClientBuilder};
 pub use config::{GitHubConfig, GitHubConfigBuilder};
 pub use errors::{GitHubError, GitHubErrorKind, GitHubResult};
-pub use auth::{AuthMethod, AuthManager};
+pub use auth::{
+    AuthManager, AuthMethod, HttpInstallationTokenFetcher, InstallationToken,
+    InstallationTokenFetcher,
+};
+pub use cache::{CachedResponse, EtagCache, EtagStore, InMemoryEtagStore};
 pub use pagination::{Page, PageIterator, PaginationLinks};
 pub use types::*;
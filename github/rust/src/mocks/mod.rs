@@ -1,8 +1,10 @@
 //! Mock implementations for testing GitHub API clients.
 
 use crate::errors::{GitHubError, GitHubErrorKind, GitHubResult, RateLimitInfo};
+use crate::pagination::{self, Page, PaginationLinks};
 use crate::types::*;
 use chrono::{DateTime, Duration, Utc};
+use futures::Stream;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::collections::HashMap;
@@ -437,6 +439,108 @@ impl MockGitHubClient {
         }
     }
 
+    /// Makes a mock paginated GET request.
+    ///
+    /// Accepts a path or an absolute URL such as a `rel="next"` link. Responses are
+    /// matched on the path alone, so successive pages are registered in order on
+    /// the same path; the query string is recorded with the request.
+    pub async fn get_page<T: DeserializeOwned>(&self, url: &str) -> GitHubResult<Page<T>> {
+        let (path, query) = match url::Url::parse(url) {
+            Ok(parsed) => (parsed.path().to_string(), parsed.query().map(String::from)),
+            Err(_) => match url.split_once('?') {
+                Some((path, query)) => (path.to_string(), Some(query.to_string())),
+                None => (url.to_string(), None),
+            },
+        };
+
+        // Record request
+        {
+            let mut requests = self.requests.write().unwrap();
+            requests.push(MockRequest {
+                method: "GET".to_string(),
+                path: path.clone(),
+                query,
+                body: None,
+                timestamp: Utc::now(),
+            });
+        }
+
+        // Get response
+        let key = format!("GET:{}", path);
+        let response = {
+            let mut store = self.responses.write().unwrap();
+            store
+                .responses
+                .get_mut(&key)
+                .and_then(|responses| {
+                    if !responses.is_empty() {
+                        Some(responses.remove(0))
+                    } else {
+                        None
+                    }
+                })
+        };
+
+        let resp = match response {
+            Some(resp) => resp,
+            None => {
+                return match self.default_behavior {
+                    DefaultBehavior::NotFound => Err(GitHubError::not_found(format!(
+                        "No mock response for GET {}",
+                        path
+                    ))),
+                    DefaultBehavior::Error => Err(GitHubError::new(
+                        GitHubErrorKind::Unknown,
+                        format!("No mock response for GET {}", path),
+                    )),
+                    DefaultBehavior::Panic => {
+                        panic!("No mock response for GET {}", path);
+                    }
+                };
+            }
+        };
+
+        if let Some(delay) = resp.delay {
+            tokio::time::sleep(delay).await;
+        }
+
+        if !(200..300).contains(&resp.status) {
+            return Err(GitHubError::from_response(
+                resp.status,
+                resp.body.clone(),
+                None,
+                None,
+            ));
+        }
+
+        let links = resp
+            .headers
+            .get("link")
+            .map(|header| PaginationLinks::from_header(header))
+            .unwrap_or_default();
+
+        let (items, total_count) = pagination::parse_page_items(resp.body.as_bytes())?;
+        let mut page = Page::new(items, links);
+        if let Some(count) = total_count {
+            page = page.with_total_count(count);
+        }
+        if let Some(info) = mock_rate_limit(&resp.headers) {
+            page = page.with_rate_limit(info);
+        }
+
+        Ok(page)
+    }
+
+    /// Streams every item of a mock paginated list, following `rel="next"` links.
+    pub fn paginate_stream<'a, T: DeserializeOwned + 'a>(
+        &'a self,
+        path: &str,
+    ) -> impl Stream<Item = GitHubResult<T>> + 'a {
+        pagination::paginate_stream(path.to_string(), move |url| async move {
+            self.get_page(&url).await
+        })
+    }
+
     /// Gets all recorded requests.
     pub fn requests(&self) -> Vec<MockRequest> {
         self.requests.read().unwrap().clone()
@@ -485,6 +589,21 @@ impl MockGitHubClient {
     }
 }
 
+/// Reads rate limit headers registered on a mock response.
+fn mock_rate_limit(headers: &HashMap<String, String>) -> Option<RateLimitInfo> {
+    let limit = headers.get("x-ratelimit-limit")?.parse().ok()?;
+    let remaining = headers.get("x-ratelimit-remaining")?.parse().ok()?;
+    let reset_timestamp = headers.get("x-ratelimit-reset")?.parse().ok()?;
+
+    Some(RateLimitInfo {
+        limit,
+        remaining,
+        reset_at: DateTime::from_timestamp(reset_timestamp, 0)?,
+        retry_after: headers.get("retry-after").and_then(|v| v.parse().ok()),
+        resource: headers.get("x-ratelimit-resource").cloned(),
+    })
+}
+
 /// Test fixtures for common GitHub types.
 pub mod fixtures {
     use super::*;
@@ -674,6 +793,7 @@ pub mod fixtures {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use futures::{StreamExt, TryStreamExt};
 
     #[tokio::test]
     async fn test_mock_client_basic() {
@@ -714,4 +834,83 @@ mod tests {
         let issue = fixtures::issue("octocat", "hello-world", 1);
         assert_eq!(issue.number, 1);
     }
+
+    #[tokio::test]
+    async fn test_paginate_stream_two_linked_pages() {
+        let mock = MockGitHubClient::new();
+        let reset = (Utc::now() - Duration::minutes(1)).timestamp();
+
+        mock.on_get(
+            "/repos/octocat/hello-world/issues",
+            MockResponse::ok(&serde_json::json!([{ "number": 1 }, { "number": 2 }]))
+                .with_header(
+                    "link",
+                    r#"<https://api.github.com/repos/octocat/hello-world/issues?page=2>; rel="next", <https://api.github.com/repos/octocat/hello-world/issues?page=2>; rel="last""#,
+                )
+                .with_rate_limit(5000, 0, reset),
+        );
+        mock.on_get(
+            "/repos/octocat/hello-world/issues",
+            MockResponse::ok(&serde_json::json!([{ "number": 3 }])).with_header(
+                "link",
+                r#"<https://api.github.com/repos/octocat/hello-world/issues?page=1>; rel="first""#,
+            ),
+        );
+
+        let items: Vec<serde_json::Value> = mock
+            .paginate_stream("/repos/octocat/hello-world/issues")
+            .try_collect()
+            .await
+            .unwrap();
+
+        let numbers: Vec<u64> = items.iter().map(|i| i["number"].as_u64().unwrap()).collect();
+        assert_eq!(numbers, vec![1, 2, 3]);
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].query, None);
+        assert_eq!(requests[1].query.as_deref(), Some("page=2"));
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stream_wrapped_items() {
+        let mock = MockGitHubClient::new();
+        mock.on_get(
+            "/search/issues",
+            MockResponse::ok(&serde_json::json!({
+                "total_count": 2,
+                "incomplete_results": false,
+                "items": [{ "number": 7 }, { "number": 8 }]
+            })),
+        );
+
+        let items: Vec<serde_json::Value> = mock
+            .paginate_stream("/search/issues?q=is%3Aopen")
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(mock.request_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stream_propagates_errors() {
+        let mock = MockGitHubClient::new();
+        mock.on_get(
+            "/orgs/octocat/repos",
+            MockResponse::ok(&serde_json::json!([{ "id": 1 }])).with_header(
+                "link",
+                r#"<https://api.github.com/orgs/octocat/repos?page=2>; rel="next""#,
+            ),
+        );
+        mock.on_get("/orgs/octocat/repos", MockResponse::server_error("boom"));
+
+        let results: Vec<GitHubResult<serde_json::Value>> =
+            mock.paginate_stream("/orgs/octocat/repos").collect().await;
+
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+    }
 }
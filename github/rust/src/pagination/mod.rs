@@ -1,9 +1,12 @@
 //! Pagination handling for GitHub API.
 
-use crate::errors::{GitHubError, GitHubErrorKind, GitHubResult};
+use crate::errors::{GitHubError, GitHubErrorKind, GitHubResult, RateLimitInfo};
+use chrono::{DateTime, Utc};
+use futures::{Stream, TryStreamExt};
 use reqwest::header::HeaderMap;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::collections::HashMap;
+use std::future::Future;
 
 /// Pagination links parsed from Link header.
 #[derive(Debug, Clone, Default)]
@@ -97,6 +100,8 @@ pub struct Page<T> {
     pub per_page: Option<u32>,
     /// Total count (if provided by API).
     pub total_count: Option<u64>,
+    /// Rate limit state reported with this page.
+    pub rate_limit: Option<RateLimitInfo>,
 }
 
 impl<T> Page<T> {
@@ -108,6 +113,7 @@ impl<T> Page<T> {
             page: None,
             per_page: None,
             total_count: None,
+            rate_limit: None,
         }
     }
 
@@ -129,6 +135,12 @@ impl<T> Page<T> {
         self
     }
 
+    /// Sets the rate limit state reported with this page.
+    pub fn with_rate_limit(mut self, info: RateLimitInfo) -> Self {
+        self.rate_limit = Some(info);
+        self
+    }
+
     /// Returns true if there is a next page.
     pub fn has_next(&self) -> bool {
         self.links.has_next()
@@ -165,6 +177,7 @@ impl<T> Page<T> {
             page: self.page,
             per_page: self.per_page,
             total_count: self.total_count,
+            rate_limit: self.rate_limit,
         }
     }
 }
@@ -306,6 +319,101 @@ where
     }
 }
 
+/// Parses the items of a list response.
+///
+/// Most endpoints return a bare JSON array, while others wrap the items in an
+/// object (`items` for search, `workflow_runs`, `repositories`, etc.) next to a
+/// `total_count`. Returns the items and the total count if present.
+pub fn parse_page_items<T: DeserializeOwned>(body: &[u8]) -> GitHubResult<(Vec<T>, Option<u64>)> {
+    let value: serde_json::Value = serde_json::from_slice(body).map_err(|e| {
+        GitHubError::new(
+            GitHubErrorKind::DeserializationError,
+            format!("Failed to deserialize response: {}", e),
+        )
+    })?;
+
+    let (items, total_count) = match value {
+        serde_json::Value::Array(_) => (value, None),
+        serde_json::Value::Object(mut object) => {
+            let total_count = object.get("total_count").and_then(|v| v.as_u64());
+            let key = if object.get("items").is_some_and(|v| v.is_array()) {
+                Some("items".to_string())
+            } else {
+                object
+                    .iter()
+                    .find(|(_, v)| v.is_array())
+                    .map(|(k, _)| k.clone())
+            };
+
+            let items = key.and_then(|k| object.remove(&k)).ok_or_else(|| {
+                GitHubError::new(
+                    GitHubErrorKind::DeserializationError,
+                    "List response object does not contain an array of items",
+                )
+            })?;
+            (items, total_count)
+        }
+        _ => {
+            return Err(GitHubError::new(
+                GitHubErrorKind::DeserializationError,
+                "List response is neither an array nor an object",
+            ))
+        }
+    };
+
+    let items = serde_json::from_value(items).map_err(|e| {
+        GitHubError::new(
+            GitHubErrorKind::DeserializationError,
+            format!("Failed to deserialize page items: {}", e),
+        )
+    })?;
+
+    Ok((items, total_count))
+}
+
+/// Returns how long to wait before the next request when the rate limit is exhausted.
+pub fn rate_limit_wait(info: &RateLimitInfo, now: DateTime<Utc>) -> Option<std::time::Duration> {
+    if info.remaining > 0 {
+        return None;
+    }
+
+    (info.reset_at - now).to_std().ok()
+}
+
+/// Streams the items of every page, starting at `start_url` and following `rel="next"` links.
+///
+/// `fetch` retrieves a single page by URL. When a page reports an exhausted rate
+/// limit, the stream waits until the limit resets before requesting the next page.
+/// The stream ends after the last page or the first error.
+pub fn paginate_stream<T, F, Fut>(start_url: String, fetch: F) -> impl Stream<Item = GitHubResult<T>>
+where
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = GitHubResult<Page<T>>>,
+{
+    futures::stream::try_unfold(
+        (fetch, Some(start_url), None::<RateLimitInfo>),
+        |(mut fetch, next_url, rate_limit)| async move {
+            let url = match next_url {
+                Some(url) => url,
+                None => return Ok(None),
+            };
+
+            if let Some(wait) = rate_limit.as_ref().and_then(|info| rate_limit_wait(info, Utc::now())) {
+                tracing::debug!(wait_ms = wait.as_millis(), "Rate limit exhausted, waiting before next page");
+                tokio::time::sleep(wait).await;
+            }
+
+            let page = fetch(url).await?;
+            let next_url = page.links.next.clone();
+            let rate_limit = page.rate_limit.clone();
+
+            Ok(Some((page.items, (fetch, next_url, rate_limit))))
+        },
+    )
+    .map_ok(|items| futures::stream::iter(items.into_iter().map(Ok)))
+    .try_flatten()
+}
+
 /// Extracts page number from a URL.
 pub fn extract_page_number(url: &str) -> Option<u32> {
     url::Url::parse(url)
@@ -320,6 +428,7 @@ pub fn extract_page_number(url: &str) -> Option<u32> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use futures::StreamExt;
 
     #[test]
     fn test_parse_link_header() {
@@ -380,4 +489,92 @@ mod tests {
         assert_eq!(page.per_page, Some(30));
         assert_eq!(page.total_count, Some(100));
     }
+
+    #[test]
+    fn test_parse_page_items_shapes() {
+        let (items, total): (Vec<u32>, _) = parse_page_items(b"[1, 2, 3]").unwrap();
+        assert_eq!(items, vec![1, 2, 3]);
+        assert_eq!(total, None);
+
+        let search = br#"{"total_count": 40, "incomplete_results": false, "items": [4, 5]}"#;
+        let (items, total): (Vec<u32>, _) = parse_page_items(search).unwrap();
+        assert_eq!(items, vec![4, 5]);
+        assert_eq!(total, Some(40));
+
+        let runs = br#"{"total_count": 1, "workflow_runs": [6]}"#;
+        let (items, _): (Vec<u32>, _) = parse_page_items(runs).unwrap();
+        assert_eq!(items, vec![6]);
+
+        assert!(parse_page_items::<u32>(br#"{"message": "nope"}"#).is_err());
+    }
+
+    #[test]
+    fn test_rate_limit_wait() {
+        let now = Utc::now();
+        let mut info = RateLimitInfo {
+            limit: 5000,
+            remaining: 0,
+            reset_at: now + chrono::Duration::seconds(30),
+            retry_after: None,
+            resource: Some("core".to_string()),
+        };
+
+        assert_eq!(rate_limit_wait(&info, now), Some(std::time::Duration::from_secs(30)));
+
+        info.reset_at = now - chrono::Duration::seconds(1);
+        assert_eq!(rate_limit_wait(&info, now), None);
+
+        info.remaining = 10;
+        info.reset_at = now + chrono::Duration::seconds(30);
+        assert_eq!(rate_limit_wait(&info, now), None);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stream_follows_next_links() {
+        let next = r#"<https://api.github.com/items?page=2>; rel="next""#;
+        let fetched = std::sync::Mutex::new(Vec::new());
+
+        let items: Vec<u32> = paginate_stream("https://api.github.com/items".to_string(), |url| {
+            fetched.lock().unwrap().push(url.clone());
+            async move {
+                Ok(if url.ends_with("page=2") {
+                    Page::new(vec![3], PaginationLinks::default())
+                } else {
+                    Page::new(vec![1, 2], PaginationLinks::from_header(next))
+                })
+            }
+        })
+        .try_collect()
+        .await
+        .unwrap();
+
+        assert_eq!(items, vec![1, 2, 3]);
+        assert_eq!(
+            *fetched.lock().unwrap(),
+            vec![
+                "https://api.github.com/items".to_string(),
+                "https://api.github.com/items?page=2".to_string(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stream_stops_on_error() {
+        let next = r#"<https://api.github.com/items?page=2>; rel="next""#;
+
+        let results: Vec<GitHubResult<u32>> =
+            paginate_stream("https://api.github.com/items".to_string(), |url| async move {
+                if url.ends_with("page=2") {
+                    Err(GitHubError::not_found("gone"))
+                } else {
+                    Ok(Page::new(vec![1], PaginationLinks::from_header(next)))
+                }
+            })
+            .collect()
+            .await;
+
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+    }
 }
@@ -0,0 +1,164 @@
+//! GitHub Apps API operations.
+
+use crate::client::GitHubClient;
+use crate::errors::{GitHubError, GitHubResult};
+use crate::types::Repository;
+use futures::Stream;
+
+/// Service for GitHub App installation operations.
+///
+/// Requires GitHub App authentication with an installation ID, so requests
+/// are sent with an installation access token rather than the app JWT.
+pub struct AppsService<'a> {
+    client: &'a GitHubClient,
+}
+
+impl<'a> AppsService<'a> {
+    /// Creates a new apps service.
+    pub fn new(client: &'a GitHubClient) -> Self {
+        Self { client }
+    }
+
+    /// Streams every repository the installation can access.
+    ///
+    /// `/installation/repositories` wraps each page in an object with
+    /// `total_count` and `repositories` instead of returning a bare array;
+    /// the stream yields the repositories of every page in order.
+    pub fn list_installation_repositories(
+        &self,
+    ) -> impl Stream<Item = GitHubResult<Repository>> + 'a {
+        let client = self.client;
+
+        if !client.auth_manager().requires_installation_token() {
+            return futures::future::Either::Right(futures::stream::once(async {
+                Err(GitHubError::configuration(
+                    "Listing installation repositories requires GitHub App authentication with an installation ID",
+                ))
+            }));
+        }
+
+        futures::future::Either::Left(
+            client.paginate_stream("/installation/repositories", &[("per_page", "100")]),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::{tests::TEST_PRIVATE_KEY, AppAuth, AuthMethod};
+    use crate::errors::GitHubErrorKind;
+    use crate::mocks::fixtures;
+    use futures::TryStreamExt;
+    use secrecy::SecretString;
+    use wiremock::matchers::{header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn installation_client(server: &MockServer) -> GitHubClient {
+        let auth = AuthMethod::App(AppAuth {
+            app_id: 12345,
+            private_key: SecretString::new(TEST_PRIVATE_KEY.to_string()),
+            installation_id: Some(42),
+        });
+
+        GitHubClient::builder()
+            .base_url(server.uri())
+            .auth(auth)
+            .no_retry()
+            .build()
+            .unwrap()
+    }
+
+    fn repositories_page(total_count: u64, names: &[&str]) -> serde_json::Value {
+        let repositories: Vec<_> = names
+            .iter()
+            .map(|name| fixtures::repository("octocat", name))
+            .collect();
+
+        serde_json::json!({
+            "total_count": total_count,
+            "repository_selection": "selected",
+            "repositories": repositories
+        })
+    }
+
+    #[tokio::test]
+    async fn test_list_installation_repositories_follows_wrapped_pages() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/app/installations/42/access_tokens"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "token": "ghs_installation",
+                "expires_at": (chrono::Utc::now() + chrono::Duration::hours(1)).to_rfc3339(),
+                "permissions": { "metadata": "read" }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/installation/repositories"))
+            .and(query_param("page", "2"))
+            .and(header("authorization", "Bearer ghs_installation"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(repositories_page(3, &["gamma"])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/installation/repositories"))
+            .and(query_param("per_page", "100"))
+            .and(header("authorization", "Bearer ghs_installation"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(repositories_page(3, &["alpha", "beta"]))
+                    .insert_header(
+                        "link",
+                        format!(
+                            r#"<{}/installation/repositories?page=2>; rel="next""#,
+                            server.uri()
+                        )
+                        .as_str(),
+                    ),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = installation_client(&server);
+        let repositories: Vec<Repository> = client
+            .apps()
+            .list_installation_repositories()
+            .try_collect()
+            .await
+            .unwrap();
+
+        let names: Vec<&str> = repositories.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(repositories.len(), 3);
+        assert_eq!(names, vec!["alpha", "beta", "gamma"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_installation_repositories_requires_installation_auth() {
+        let server = MockServer::start().await;
+        let client = GitHubClient::builder()
+            .base_url(server.uri())
+            .pat("ghp_test")
+            .no_retry()
+            .build()
+            .unwrap();
+
+        let err = client
+            .apps()
+            .list_installation_repositories()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.kind(), &GitHubErrorKind::InvalidConfiguration);
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+}
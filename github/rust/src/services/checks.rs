@@ -0,0 +1,710 @@
+//! Checks API operations.
+
+use crate::client::GitHubClient;
+use crate::errors::GitHubResult;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of annotations GitHub accepts in a single request.
+pub const MAX_ANNOTATIONS_PER_REQUEST: usize = 50;
+
+/// Service for reporting check runs and check suites.
+///
+/// Requires GitHub App authentication; the Checks API rejects other tokens.
+pub struct ChecksService<'a> {
+    client: &'a GitHubClient,
+}
+
+impl<'a> ChecksService<'a> {
+    /// Creates a new checks service.
+    pub fn new(client: &'a GitHubClient) -> Self {
+        Self { client }
+    }
+
+    // Check runs
+
+    /// Creates a check run.
+    ///
+    /// Annotations beyond the first 50 are sent in follow-up updates, and the
+    /// check run returned by the last of them is returned.
+    pub async fn create_check_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        request: &CreateCheckRunRequest,
+    ) -> GitHubResult<CheckRun> {
+        let (first, rest) = split_output(request.output.as_ref());
+        let create = CreateCheckRunRequest {
+            output: first,
+            ..request.clone()
+        };
+
+        let check_run: CheckRun = self
+            .client
+            .post(&format!("/repos/{}/{}/check-runs", owner, repo), &create)
+            .await?;
+
+        self.send_remaining_annotations(owner, repo, check_run, rest)
+            .await
+    }
+
+    /// Updates a check run.
+    ///
+    /// Annotations are sent 50 per request, as GitHub requires. The first
+    /// request carries every other field; later ones only repeat the output
+    /// title and summary alongside the next batch.
+    pub async fn update_check_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: u64,
+        request: &UpdateCheckRunRequest,
+    ) -> GitHubResult<CheckRun> {
+        let (first, rest) = split_output(request.output.as_ref());
+        let update = UpdateCheckRunRequest {
+            output: first,
+            ..request.clone()
+        };
+
+        let check_run: CheckRun = self
+            .client
+            .patch(&check_run_path(owner, repo, check_run_id), &update)
+            .await?;
+
+        self.send_remaining_annotations(owner, repo, check_run, rest)
+            .await
+    }
+
+    /// Gets a check run.
+    pub async fn get_check_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: u64,
+    ) -> GitHubResult<CheckRun> {
+        self.client
+            .get(&check_run_path(owner, repo, check_run_id))
+            .await
+    }
+
+    /// Lists check runs for a commit SHA, branch or tag.
+    pub async fn list_for_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        git_ref: &str,
+        params: &ListCheckRunsParams,
+    ) -> GitHubResult<CheckRunsResponse> {
+        self.client
+            .get_with_params(
+                &format!("/repos/{}/{}/commits/{}/check-runs", owner, repo, git_ref),
+                params,
+            )
+            .await
+    }
+
+    async fn send_remaining_annotations(
+        &self,
+        owner: &str,
+        repo: &str,
+        mut check_run: CheckRun,
+        batches: Vec<CheckRunOutput>,
+    ) -> GitHubResult<CheckRun> {
+        for output in batches {
+            let update = UpdateCheckRunRequest {
+                output: Some(output),
+                ..Default::default()
+            };
+            check_run = self
+                .client
+                .patch(&check_run_path(owner, repo, check_run.id), &update)
+                .await?;
+        }
+        Ok(check_run)
+    }
+
+    // Check suites
+
+    /// Creates a check suite for a commit.
+    ///
+    /// Only needed when automatic suite creation has been disabled for the
+    /// app; otherwise GitHub creates suites on push.
+    pub async fn create_check_suite(
+        &self,
+        owner: &str,
+        repo: &str,
+        head_sha: &str,
+    ) -> GitHubResult<CheckSuite> {
+        self.client
+            .post(
+                &format!("/repos/{}/{}/check-suites", owner, repo),
+                &CreateCheckSuiteRequest {
+                    head_sha: head_sha.to_string(),
+                },
+            )
+            .await
+    }
+
+    /// Gets a check suite.
+    pub async fn get_check_suite(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: u64,
+    ) -> GitHubResult<CheckSuite> {
+        self.client
+            .get(&format!(
+                "/repos/{}/{}/check-suites/{}",
+                owner, repo, check_suite_id
+            ))
+            .await
+    }
+
+    /// Asks GitHub to send a new `check_suite` webhook with action `rerequested`.
+    pub async fn rerequest_check_suite(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: u64,
+    ) -> GitHubResult<()> {
+        self.client
+            .post_no_response(
+                &format!(
+                    "/repos/{}/{}/check-suites/{}/rerequest",
+                    owner, repo, check_suite_id
+                ),
+                &(),
+            )
+            .await
+    }
+}
+
+fn check_run_path(owner: &str, repo: &str, check_run_id: u64) -> String {
+    format!("/repos/{}/{}/check-runs/{}", owner, repo, check_run_id)
+}
+
+/// Splits an output into the part sent with the first request and follow-up
+/// outputs carrying the remaining annotations.
+fn split_output(output: Option<&CheckRunOutput>) -> (Option<CheckRunOutput>, Vec<CheckRunOutput>) {
+    let output = match output {
+        Some(output) => output,
+        None => return (None, Vec::new()),
+    };
+
+    let mut batches = output.annotations.chunks(MAX_ANNOTATIONS_PER_REQUEST);
+    let first = CheckRunOutput {
+        annotations: batches.next().map(<[_]>::to_vec).unwrap_or_default(),
+        ..output.clone()
+    };
+    let rest = batches
+        .map(|annotations| CheckRunOutput {
+            title: output.title.clone(),
+            summary: output.summary.clone(),
+            text: None,
+            annotations: annotations.to_vec(),
+        })
+        .collect();
+
+    (Some(first), rest)
+}
+
+/// Check run status.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckRunStatus {
+    Queued,
+    InProgress,
+    Completed,
+    Waiting,
+    Requested,
+    Pending,
+}
+
+/// Check run or check suite conclusion.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckConclusion {
+    ActionRequired,
+    Cancelled,
+    Failure,
+    Neutral,
+    Success,
+    Skipped,
+    Stale,
+    TimedOut,
+}
+
+/// Check run output.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckRunOutput {
+    /// Title.
+    pub title: String,
+    /// Summary (Markdown).
+    pub summary: String,
+    /// Details (Markdown).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Annotations; never returned by GitHub, use `annotations_count`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<CheckAnnotation>,
+}
+
+impl CheckRunOutput {
+    /// Creates an output with a title and summary.
+    pub fn new(title: impl Into<String>, summary: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            summary: summary.into(),
+            text: None,
+            annotations: Vec::new(),
+        }
+    }
+}
+
+/// Annotation on a line range of a file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckAnnotation {
+    /// File path relative to the repository root.
+    pub path: String,
+    /// First line.
+    pub start_line: u32,
+    /// Last line.
+    pub end_line: u32,
+    /// First column; only valid when on a single line.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_column: Option<u32>,
+    /// Last column; only valid when on a single line.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<u32>,
+    /// Severity.
+    pub annotation_level: AnnotationLevel,
+    /// Message.
+    pub message: String,
+    /// Title.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Raw details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_details: Option<String>,
+}
+
+impl CheckAnnotation {
+    /// Creates an annotation on a line range.
+    pub fn new(
+        path: impl Into<String>,
+        start_line: u32,
+        end_line: u32,
+        annotation_level: AnnotationLevel,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            start_line,
+            end_line,
+            start_column: None,
+            end_column: None,
+            annotation_level,
+            message: message.into(),
+            title: None,
+            raw_details: None,
+        }
+    }
+}
+
+/// Annotation severity.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AnnotationLevel {
+    Notice,
+    Warning,
+    Failure,
+}
+
+/// Button shown on a check run that sends a `requested_action` webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckRunAction {
+    /// Button text (max 20 characters).
+    pub label: String,
+    /// Tooltip (max 40 characters).
+    pub description: String,
+    /// Identifier sent back in the webhook (max 20 characters).
+    pub identifier: String,
+}
+
+/// Request to create a check run.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateCheckRunRequest {
+    /// Check name.
+    pub name: String,
+    /// Commit SHA.
+    pub head_sha: String,
+    /// URL of the integrator's site with full details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details_url: Option<String>,
+    /// Integrator reference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    /// Status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CheckRunStatus>,
+    /// Started at.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime<Utc>>,
+    /// Conclusion; required when `completed_at` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conclusion: Option<CheckConclusion>,
+    /// Completed at.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<CheckRunOutput>,
+    /// Action buttons (max 3).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<CheckRunAction>,
+}
+
+impl CreateCheckRunRequest {
+    /// Creates a request for a check on a commit.
+    pub fn new(name: impl Into<String>, head_sha: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            head_sha: head_sha.into(),
+            details_url: None,
+            external_id: None,
+            status: None,
+            started_at: None,
+            conclusion: None,
+            completed_at: None,
+            output: None,
+            actions: Vec::new(),
+        }
+    }
+}
+
+/// Request to update a check run.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateCheckRunRequest {
+    /// Check name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// URL of the integrator's site with full details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details_url: Option<String>,
+    /// Integrator reference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    /// Status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CheckRunStatus>,
+    /// Started at.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime<Utc>>,
+    /// Conclusion; setting it marks the run completed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conclusion: Option<CheckConclusion>,
+    /// Completed at.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<CheckRunOutput>,
+    /// Action buttons (max 3).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<CheckRunAction>,
+}
+
+/// Request to create a check suite.
+#[derive(Debug, Clone, Serialize)]
+struct CreateCheckSuiteRequest {
+    head_sha: String,
+}
+
+/// Parameters for listing check runs.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ListCheckRunsParams {
+    /// Filter by check name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check_name: Option<String>,
+    /// Filter by status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CheckRunStatus>,
+    /// Filter by `latest` (default) or `all` runs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// Filter by GitHub App ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_id: Option<u64>,
+    /// Page number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    /// Items per page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+}
+
+/// Response containing check runs.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckRunsResponse {
+    /// Total count.
+    pub total_count: u32,
+    /// Check runs.
+    pub check_runs: Vec<CheckRun>,
+}
+
+/// A check run.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckRun {
+    /// Check run ID.
+    pub id: u64,
+    /// Node ID.
+    pub node_id: Option<String>,
+    /// Name.
+    pub name: String,
+    /// Head SHA.
+    pub head_sha: String,
+    /// External ID.
+    pub external_id: Option<String>,
+    /// Status.
+    pub status: CheckRunStatus,
+    /// Conclusion.
+    pub conclusion: Option<CheckConclusion>,
+    /// URL.
+    pub url: Option<String>,
+    /// HTML URL.
+    pub html_url: Option<String>,
+    /// Details URL.
+    pub details_url: Option<String>,
+    /// Started at.
+    pub started_at: Option<DateTime<Utc>>,
+    /// Completed at.
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Output summary.
+    pub output: Option<CheckRunOutputSummary>,
+    /// Check suite the run belongs to.
+    pub check_suite: Option<CheckSuiteRef>,
+}
+
+/// Check run output as returned by GitHub.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckRunOutputSummary {
+    /// Title.
+    pub title: Option<String>,
+    /// Summary.
+    pub summary: Option<String>,
+    /// Details text.
+    pub text: Option<String>,
+    /// Number of annotations.
+    #[serde(default)]
+    pub annotations_count: u32,
+}
+
+/// Reference to a check suite.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckSuiteRef {
+    /// Check suite ID.
+    pub id: u64,
+}
+
+/// A check suite.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckSuite {
+    /// Check suite ID.
+    pub id: u64,
+    /// Node ID.
+    pub node_id: Option<String>,
+    /// Head branch.
+    pub head_branch: Option<String>,
+    /// Head SHA.
+    pub head_sha: String,
+    /// Status.
+    pub status: Option<CheckRunStatus>,
+    /// Conclusion.
+    pub conclusion: Option<CheckConclusion>,
+    /// URL.
+    pub url: Option<String>,
+    /// Number of check runs in the suite.
+    #[serde(default)]
+    pub latest_check_runs_count: u32,
+    /// Created at.
+    pub created_at: Option<DateTime<Utc>>,
+    /// Updated at.
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn client(server: &MockServer) -> GitHubClient {
+        GitHubClient::builder()
+            .base_url(server.uri())
+            .pat("ghp_test")
+            .no_retry()
+            .build()
+            .unwrap()
+    }
+
+    fn check_run_response(status: &str, conclusion: Option<&str>) -> serde_json::Value {
+        serde_json::json!({
+            "id": 4,
+            "node_id": "MDg6Q2hlY2tSdW40",
+            "name": "lint",
+            "head_sha": "ce587453ced02b1526dfb4cb910479d431683101",
+            "external_id": "build-42",
+            "status": status,
+            "conclusion": conclusion,
+            "url": "https://api.github.com/repos/octocat/hello/check-runs/4",
+            "html_url": "https://github.com/octocat/hello/runs/4",
+            "details_url": "https://ci.example.com/builds/42",
+            "started_at": "2024-01-01T10:00:00Z",
+            "completed_at": null,
+            "output": {
+                "title": "Lint",
+                "summary": "Running",
+                "text": null,
+                "annotations_count": 0
+            },
+            "check_suite": { "id": 5 }
+        })
+    }
+
+    fn annotations(count: u32) -> Vec<CheckAnnotation> {
+        (1..=count)
+            .map(|line| {
+                CheckAnnotation::new(
+                    "src/lib.rs",
+                    line,
+                    line,
+                    AnnotationLevel::Warning,
+                    format!("warning {}", line),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_create_check_run_body() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octocat/hello/check-runs"))
+            .and(body_json(serde_json::json!({
+                "name": "lint",
+                "head_sha": "ce587453ced02b1526dfb4cb910479d431683101",
+                "details_url": "https://ci.example.com/builds/42",
+                "external_id": "build-42",
+                "status": "in_progress",
+                "started_at": "2024-01-01T10:00:00Z",
+                "output": {
+                    "title": "Lint",
+                    "summary": "Running",
+                    "annotations": [{
+                        "path": "src/lib.rs",
+                        "start_line": 3,
+                        "end_line": 3,
+                        "annotation_level": "failure",
+                        "message": "unused variable"
+                    }]
+                },
+                "actions": [{
+                    "label": "Fix",
+                    "description": "Apply suggested fixes",
+                    "identifier": "fix"
+                }]
+            })))
+            .respond_with(
+                ResponseTemplate::new(201).set_body_json(check_run_response("in_progress", None)),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut output = CheckRunOutput::new("Lint", "Running");
+        output.annotations.push(CheckAnnotation::new(
+            "src/lib.rs",
+            3,
+            3,
+            AnnotationLevel::Failure,
+            "unused variable",
+        ));
+
+        let mut request =
+            CreateCheckRunRequest::new("lint", "ce587453ced02b1526dfb4cb910479d431683101");
+        request.details_url = Some("https://ci.example.com/builds/42".to_string());
+        request.external_id = Some("build-42".to_string());
+        request.status = Some(CheckRunStatus::InProgress);
+        request.started_at = Some("2024-01-01T10:00:00Z".parse().unwrap());
+        request.output = Some(output);
+        request.actions.push(CheckRunAction {
+            label: "Fix".to_string(),
+            description: "Apply suggested fixes".to_string(),
+            identifier: "fix".to_string(),
+        });
+
+        let client = client(&server);
+        let check_run = client
+            .checks()
+            .create_check_run("octocat", "hello", &request)
+            .await
+            .unwrap();
+
+        assert_eq!(check_run.id, 4);
+        assert_eq!(check_run.status, CheckRunStatus::InProgress);
+        assert_eq!(check_run.check_suite.unwrap().id, 5);
+    }
+
+    #[tokio::test]
+    async fn test_update_check_run_chunks_annotations() {
+        let server = MockServer::start().await;
+        Mock::given(method("PATCH"))
+            .and(path("/repos/octocat/hello/check-runs/4"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(check_run_response("completed", Some("failure"))),
+            )
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let mut output = CheckRunOutput::new("Lint", "120 warnings");
+        output.text = Some("Details".to_string());
+        output.annotations = annotations(120);
+
+        let request = UpdateCheckRunRequest {
+            conclusion: Some(CheckConclusion::Failure),
+            output: Some(output),
+            ..Default::default()
+        };
+
+        let client = client(&server);
+        let check_run = client
+            .checks()
+            .update_check_run("octocat", "hello", 4, &request)
+            .await
+            .unwrap();
+        assert_eq!(check_run.conclusion, Some(CheckConclusion::Failure));
+
+        let bodies: Vec<serde_json::Value> = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|request| serde_json::from_slice(&request.body).unwrap())
+            .collect();
+        let sizes: Vec<usize> = bodies
+            .iter()
+            .map(|body| body["output"]["annotations"].as_array().unwrap().len())
+            .collect();
+        assert_eq!(sizes, vec![50, 50, 20]);
+
+        // Only the first request carries the other fields
+        assert_eq!(bodies[0]["conclusion"], "failure");
+        assert_eq!(bodies[0]["output"]["text"], "Details");
+        assert!(bodies[1].get("conclusion").is_none());
+        assert!(bodies[2]["output"].get("text").is_none());
+        assert_eq!(bodies[2]["output"]["title"], "Lint");
+        assert_eq!(bodies[2]["output"]["annotations"][0]["start_line"], 101);
+    }
+}
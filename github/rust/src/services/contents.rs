@@ -0,0 +1,350 @@
+//! Repository contents operations.
+
+use crate::client::GitHubClient;
+use crate::errors::{GitHubError, GitHubErrorKind, GitHubResult};
+use crate::services::{CreateOrUpdateFileRequest, FileCommitResponse};
+use crate::types::{Content, ContentType};
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+/// Service for reading and writing single files via the Contents API.
+pub struct ContentsService<'a> {
+    client: &'a GitHubClient,
+}
+
+impl<'a> ContentsService<'a> {
+    /// Creates a new contents service.
+    pub fn new(client: &'a GitHubClient) -> Self {
+        Self { client }
+    }
+
+    /// Gets the contents at a path, which is a listing for directories.
+    pub async fn get(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        git_ref: Option<&str>,
+    ) -> GitHubResult<RepositoryContents> {
+        let params = ContentsParams {
+            git_ref: git_ref.map(String::from),
+        };
+        self.client
+            .get_with_params(&format!("/repos/{}/{}/contents/{}", owner, repo, path), &params)
+            .await
+    }
+
+    /// Gets a file with its content decoded.
+    ///
+    /// Fails if the path is a directory, symlink or submodule, or if the file
+    /// is too large for the Contents API to inline.
+    pub async fn get_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        git_ref: Option<&str>,
+    ) -> GitHubResult<FileContents> {
+        match self.get(owner, repo, path, git_ref).await? {
+            RepositoryContents::Directory(_) => Err(GitHubError::new(
+                GitHubErrorKind::InvalidParameter,
+                format!("'{}' is a directory, not a file", path),
+            )),
+            RepositoryContents::Item(content) => FileContents::from_content(content),
+        }
+    }
+
+    /// Creates or updates a file, returning the commit.
+    ///
+    /// `sha` must be the blob SHA of the file being replaced when updating. A
+    /// missing or stale SHA is reported as a `Conflict` error.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn put_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        content: &[u8],
+        message: &str,
+        sha: Option<&str>,
+        branch: Option<&str>,
+    ) -> GitHubResult<FileCommitResponse> {
+        let request = CreateOrUpdateFileRequest {
+            message: message.to_string(),
+            content: base64::engine::general_purpose::STANDARD.encode(content),
+            sha: sha.map(String::from),
+            branch: branch.map(String::from),
+            committer: None,
+            author: None,
+        };
+
+        self.client
+            .put(&format!("/repos/{}/{}/contents/{}", owner, repo, path), &request)
+            .await
+            .map_err(|e| sha_conflict(e, path, sha))
+    }
+}
+
+/// Rewrites the errors GitHub returns for a missing or outdated SHA.
+///
+/// A stale SHA yields 409, while omitting it for an existing file yields a
+/// 422 that mentions `sha`.
+fn sha_conflict(error: GitHubError, path: &str, sha: Option<&str>) -> GitHubError {
+    let message = match (error.status_code(), sha) {
+        (Some(409), Some(sha)) => format!(
+            "'{}' does not match SHA {}; fetch the current SHA and retry",
+            path, sha
+        ),
+        (Some(409), None) => already_exists(path),
+        (Some(422), None) if error.to_string().contains("sha") => already_exists(path),
+        _ => return error,
+    };
+
+    let mut conflict = GitHubError::new(GitHubErrorKind::Conflict, message).with_status(409);
+    if let Some(id) = error.request_id() {
+        conflict = conflict.with_request_id(id);
+    }
+    conflict.with_cause(error)
+}
+
+fn already_exists(path: &str) -> String {
+    format!("'{}' already exists; pass its current SHA to update it", path)
+}
+
+/// Contents API response, which is an array for directories.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum RepositoryContents {
+    /// Directory listing.
+    Directory(Vec<Content>),
+    /// Single file, symlink or submodule.
+    Item(Content),
+}
+
+/// A file with its content decoded.
+#[derive(Debug, Clone)]
+pub struct FileContents {
+    /// File path.
+    pub path: String,
+    /// Blob SHA, required to update the file.
+    pub sha: String,
+    /// Decoded file content.
+    pub content: Vec<u8>,
+}
+
+impl FileContents {
+    fn from_content(content: Content) -> GitHubResult<Self> {
+        if content.content_type != ContentType::File {
+            return Err(GitHubError::new(
+                GitHubErrorKind::InvalidParameter,
+                format!("'{}' is a {:?}, not a file", content.path, content.content_type),
+            ));
+        }
+
+        // Files over 1 MB come back with an empty body and encoding "none"
+        let encoded = match (content.encoding.as_deref(), content.content) {
+            (Some("base64"), Some(encoded)) => encoded,
+            (encoding, _) => {
+                return Err(GitHubError::new(
+                    GitHubErrorKind::UnexpectedFormat,
+                    format!(
+                        "'{}' content is not inlined (encoding {:?}); use the download URL",
+                        content.path, encoding
+                    ),
+                ))
+            }
+        };
+
+        // GitHub wraps the base64 content at 60 characters
+        let compact: String = encoded.chars().filter(|c| !c.is_whitespace()).collect();
+        let decoded = base64::engine::general_purpose::STANDARD
+            .decode(compact)
+            .map_err(|e| {
+                GitHubError::deserialization(format!(
+                    "Invalid base64 content for '{}': {}",
+                    content.path, e
+                ))
+            })?;
+
+        Ok(Self {
+            path: content.path,
+            sha: content.sha,
+            content: decoded,
+        })
+    }
+}
+
+/// Query parameters for contents requests.
+#[derive(Debug, Clone, Serialize)]
+struct ContentsParams {
+    /// Branch, tag or commit to read from.
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    git_ref: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_json, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn client(server: &MockServer) -> GitHubClient {
+        GitHubClient::builder()
+            .base_url(server.uri())
+            .pat("ghp_test")
+            .no_retry()
+            .build()
+            .unwrap()
+    }
+
+    fn commit_response(path: &str, blob_sha: &str, commit_sha: &str) -> serde_json::Value {
+        serde_json::json!({
+            "content": {
+                "type": "file",
+                "encoding": null,
+                "size": 12,
+                "name": path,
+                "path": path,
+                "content": null,
+                "sha": blob_sha,
+                "url": format!("https://api.github.com/repos/octocat/hello/contents/{}", path),
+                "html_url": format!("https://github.com/octocat/hello/blob/main/{}", path)
+            },
+            "commit": {
+                "sha": commit_sha,
+                "message": "Update file",
+                "html_url": format!("https://github.com/octocat/hello/commit/{}", commit_sha)
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_file_decodes_content() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/hello/contents/docs/README.md"))
+            .and(query_param("ref", "main"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "type": "file",
+                "encoding": "base64",
+                "size": 12,
+                "name": "README.md",
+                "path": "docs/README.md",
+                // Wrapped the way GitHub returns it
+                "content": "SGVsbG8s\nIHdvcmxk\nIQ==\n",
+                "sha": "3d21ec53a331a6f037a91c368710b99387d012c1",
+                "url": "https://api.github.com/repos/octocat/hello/contents/docs/README.md",
+                "html_url": "https://github.com/octocat/hello/blob/main/docs/README.md"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/hello/contents/docs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                "type": "file",
+                "encoding": null,
+                "size": 12,
+                "name": "README.md",
+                "path": "docs/README.md",
+                "content": null,
+                "sha": "3d21ec53a331a6f037a91c368710b99387d012c1",
+                "url": "https://api.github.com/repos/octocat/hello/contents/docs/README.md",
+                "html_url": "https://github.com/octocat/hello/blob/main/docs/README.md"
+            }])))
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let contents = client.contents();
+
+        let file = contents
+            .get_file("octocat", "hello", "docs/README.md", Some("main"))
+            .await
+            .unwrap();
+        assert_eq!(file.content, b"Hello, world!");
+        assert_eq!(file.sha, "3d21ec53a331a6f037a91c368710b99387d012c1");
+
+        let listing = contents.get("octocat", "hello", "docs", None).await.unwrap();
+        assert!(matches!(listing, RepositoryContents::Directory(ref items) if items.len() == 1));
+
+        let err = contents
+            .get_file("octocat", "hello", "docs", None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), &GitHubErrorKind::InvalidParameter);
+    }
+
+    #[tokio::test]
+    async fn test_put_file_creates_with_encoded_content() {
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/repos/octocat/hello/contents/notes.txt"))
+            .and(body_json(serde_json::json!({
+                "message": "Add notes",
+                "content": "SGVsbG8sIHdvcmxkIQ==",
+                "branch": "main"
+            })))
+            .respond_with(
+                ResponseTemplate::new(201)
+                    .set_body_json(commit_response("notes.txt", "blob-1", "commit-1")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let response = client
+            .contents()
+            .put_file(
+                "octocat",
+                "hello",
+                "notes.txt",
+                b"Hello, world!",
+                "Add notes",
+                None,
+                Some("main"),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.commit.sha, "commit-1");
+        assert_eq!(response.content.unwrap().sha, "blob-1");
+    }
+
+    #[tokio::test]
+    async fn test_put_file_with_stale_sha_is_conflict() {
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/repos/octocat/hello/contents/notes.txt"))
+            .and(body_json(serde_json::json!({
+                "message": "Update notes",
+                "content": "VXBkYXRlZA==",
+                "sha": "stale-sha"
+            })))
+            .respond_with(ResponseTemplate::new(409).set_body_json(serde_json::json!({
+                "message": "notes.txt does not match stale-sha",
+                "documentation_url": "https://docs.github.com/rest/repos/contents"
+            })))
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let err = client
+            .contents()
+            .put_file(
+                "octocat",
+                "hello",
+                "notes.txt",
+                b"Updated",
+                "Update notes",
+                Some("stale-sha"),
+                None,
+            )
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.kind(), &GitHubErrorKind::Conflict);
+        assert_eq!(err.status_code(), Some(409));
+        assert!(err.to_string().contains("fetch the current SHA"));
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::client::GitHubClient;
 use crate::errors::{GitHubError, GitHubErrorKind, GitHubResult, RateLimitInfo};
+use chrono::{DateTime, Utc};
 use reqwest::header::HeaderMap;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -68,76 +69,72 @@ impl<'a> GraphQLService<'a> {
             operation_name: operation_name.map(String::from),
         };
 
-        // GitHub's GraphQL endpoint
-        let response = self.client.post::<GraphQLRawResponse, _>("/graphql", &request).await?;
+        // GitHub's GraphQL endpoint; secondary rate limits are retried by the
+        // client's retry executor using their `Retry-After` delay
+        let response: GraphQLRawResponse = self.client.post("/graphql", &request).await?;
 
         // Parse the response
-        let graphql_response = self.parse_graphql_response::<T>(response)?;
+        let graphql_response = Self::parse_graphql_response::<T>(response)?;
+
+        if let Some(ref rate_limit) = graphql_response.rate_limit {
+            self.client.record_graphql_rate_limit(rate_limit.clone());
+        }
 
         Ok(graphql_response)
     }
 
     /// Parses a raw GraphQL response into a typed response.
+    ///
+    /// Errors that accompany usable data are kept on the response; the request
+    /// only fails when the query was rate limited or produced no usable data.
     fn parse_graphql_response<T: serde::de::DeserializeOwned>(
-        &self,
         raw: GraphQLRawResponse,
     ) -> GitHubResult<GraphQLResponse<T>> {
         // Parse errors first
-        let errors = raw.errors.map(|errs| {
-            errs.into_iter()
-                .map(GraphQLError::from)
-                .collect::<Vec<_>>()
-        });
-
-        // If there are errors, check if we should fail fast
-        if let Some(ref err_list) = errors {
-            // Check for critical errors that should fail the request
-            for err in err_list {
-                match err.error_type.as_deref() {
-                    Some("RATE_LIMITED") => {
-                        return Err(GitHubError::new(
-                            GitHubErrorKind::GraphQlRateLimitExceeded,
-                            format!("GraphQL rate limit exceeded: {}", err.message),
-                        ));
-                    }
-                    Some("MAX_NODE_LIMIT_EXCEEDED") => {
-                        return Err(GitHubError::new(
-                            GitHubErrorKind::NodeLimitExceeded,
-                            format!("GraphQL node limit exceeded: {}", err.message),
-                        ));
-                    }
-                    Some("FORBIDDEN") => {
-                        return Err(GitHubError::new(
-                            GitHubErrorKind::Forbidden,
-                            format!("GraphQL forbidden: {}", err.message),
-                        ));
-                    }
-                    Some("NOT_FOUND") => {
-                        return Err(GitHubError::new(
-                            GitHubErrorKind::NotFound,
-                            format!("GraphQL not found: {}", err.message),
-                        ));
-                    }
-                    _ => {}
-                }
-            }
+        let errors = raw
+            .errors
+            .map(|errs| errs.into_iter().map(GraphQLError::from).collect::<Vec<_>>());
+        let first_error = || errors.as_ref().and_then(|errs| errs.first());
+
+        if let Some(err) = errors
+            .iter()
+            .flatten()
+            .find(|err| err.error_type.as_deref() == Some("RATE_LIMITED"))
+        {
+            return Err(err.to_github_error());
         }
 
+        // Extract rate limit information from the requested `rateLimit` field or extensions
+        let rate_limit = raw
+            .data
+            .as_ref()
+            .and_then(|data| data.get("rateLimit"))
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .or_else(|| raw.extensions.and_then(|ext| ext.rate_limit));
+
         // Parse data
-        let data = if let Some(raw_data) = raw.data {
-            Some(serde_json::from_value(raw_data).map_err(|e| {
-                GitHubError::new(
-                    GitHubErrorKind::DeserializationError,
-                    format!("Failed to deserialize GraphQL data: {}", e),
-                )
-            })?)
-        } else {
-            None
+        let data = match raw.data {
+            Some(raw_data) if !raw_data.is_null() => match serde_json::from_value(raw_data) {
+                Ok(data) => Some(data),
+                Err(e) => {
+                    return Err(first_error()
+                        .map(GraphQLError::to_github_error)
+                        .unwrap_or_else(|| {
+                            GitHubError::new(
+                                GitHubErrorKind::DeserializationError,
+                                format!("Failed to deserialize GraphQL data: {}", e),
+                            )
+                        }))
+                }
+            },
+            _ => {
+                if let Some(err) = first_error() {
+                    return Err(err.to_github_error());
+                }
+                None
+            }
         };
 
-        // Extract rate limit information from extensions
-        let rate_limit = raw.extensions.and_then(|ext| ext.rate_limit);
-
         Ok(GraphQLResponse {
             data,
             errors,
@@ -210,9 +207,10 @@ struct GraphQLExtensions {
 }
 
 /// GraphQL-specific rate limit information.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GraphQLRateLimit {
     /// Maximum points allowed per hour.
+    #[serde(default)]
     pub limit: u32,
     /// Points remaining in current window.
     pub remaining: u32,
@@ -222,10 +220,19 @@ pub struct GraphQLRateLimit {
     /// Cost of the current query in points.
     pub cost: Option<u32>,
     /// Node count for the query.
-    #[serde(rename = "nodeCount")]
+    #[serde(rename = "nodeCount", default)]
     pub node_count: Option<u32>,
 }
 
+impl GraphQLRateLimit {
+    /// Parses the reset time.
+    pub fn resets_at(&self) -> Option<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(&self.reset_at)
+            .ok()
+            .map(|dt| dt.with_timezone(&Utc))
+    }
+}
+
 /// Typed GraphQL response.
 #[derive(Debug, Clone)]
 pub struct GraphQLResponse<T> {
@@ -284,6 +291,15 @@ impl<T> GraphQLResponse<T> {
     pub fn remaining_points(&self) -> Option<u32> {
         self.rate_limit.as_ref().map(|rl| rl.remaining)
     }
+
+    /// Converts the GraphQL errors into typed GitHub errors.
+    pub fn github_errors(&self) -> Vec<GitHubError> {
+        self.errors
+            .iter()
+            .flatten()
+            .map(GraphQLError::to_github_error)
+            .collect()
+    }
 }
 
 /// GraphQL error with detailed information.
@@ -302,6 +318,36 @@ pub struct GraphQLError {
     pub extensions: Option<HashMap<String, serde_json::Value>>,
 }
 
+impl GraphQLError {
+    /// Converts the error into a typed GitHub error based on its `type`.
+    pub fn to_github_error(&self) -> GitHubError {
+        let (kind, prefix) = match self.error_type.as_deref() {
+            Some("RATE_LIMITED") => (
+                GitHubErrorKind::GraphQlRateLimitExceeded,
+                "GraphQL rate limit exceeded",
+            ),
+            Some("MAX_NODE_LIMIT_EXCEEDED") => (
+                GitHubErrorKind::NodeLimitExceeded,
+                "GraphQL node limit exceeded",
+            ),
+            Some("FORBIDDEN") => (GitHubErrorKind::Forbidden, "GraphQL forbidden"),
+            Some("NOT_FOUND") => (GitHubErrorKind::NotFound, "GraphQL not found"),
+            Some("INSUFFICIENT_SCOPES") => (
+                GitHubErrorKind::InsufficientScopes,
+                "GraphQL insufficient scopes",
+            ),
+            _ => (GitHubErrorKind::QueryError, "GraphQL query error"),
+        };
+
+        let message = match self.path.as_ref().filter(|path| !path.is_empty()) {
+            Some(path) => format!("{}: {} (at {})", prefix, self.message, path.join(".")),
+            None => format!("{}: {}", prefix, self.message),
+        };
+
+        GitHubError::new(kind, message)
+    }
+}
+
 impl From<GraphQLRawError> for GraphQLError {
     fn from(raw: GraphQLRawError) -> Self {
         Self {
@@ -425,6 +471,7 @@ pub struct PageInfo {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
 
     #[test]
     fn test_graphql_pagination_forward() {
@@ -507,4 +554,130 @@ mod tests {
         let mapped = response.map(|n| n * 2);
         assert_eq!(mapped.data, Some(84));
     }
+
+    fn raw_response(json: serde_json::Value) -> GraphQLRawResponse {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct RepositoryData {
+        repository: Option<serde_json::Value>,
+        viewer: serde_json::Value,
+    }
+
+    #[test]
+    fn test_parse_data_with_partial_error() {
+        let raw = raw_response(serde_json::json!({
+            "data": {
+                "repository": null,
+                "viewer": { "login": "octocat" },
+                "rateLimit": {
+                    "cost": 1,
+                    "remaining": 4999,
+                    "resetAt": "2024-01-01T01:00:00Z"
+                }
+            },
+            "errors": [{
+                "type": "NOT_FOUND",
+                "path": ["repository"],
+                "locations": [{ "line": 2, "column": 3 }],
+                "message": "Could not resolve to a Repository with the name 'octocat/missing'."
+            }]
+        }));
+
+        let response = GraphQLService::parse_graphql_response::<RepositoryData>(raw).unwrap();
+
+        let data = response.data.as_ref().unwrap();
+        assert!(data.repository.is_none());
+        assert_eq!(data.viewer["login"], "octocat");
+
+        assert!(response.has_errors());
+        let errors = response.github_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind(), &GitHubErrorKind::NotFound);
+        assert!(errors[0].to_string().contains("repository"));
+
+        let rate_limit = response.rate_limit.as_ref().unwrap();
+        assert_eq!(rate_limit.cost, Some(1));
+        assert_eq!(rate_limit.remaining, 4999);
+        assert_eq!(
+            rate_limit.resets_at(),
+            Some(Utc.with_ymd_and_hms(2024, 1, 1, 1, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_rate_limit_from_extensions() {
+        let raw = raw_response(serde_json::json!({
+            "data": { "viewer": { "login": "octocat" } },
+            "extensions": {
+                "rateLimit": {
+                    "limit": 5000,
+                    "cost": 3,
+                    "remaining": 4990,
+                    "resetAt": "2024-01-01T01:00:00Z"
+                }
+            }
+        }));
+
+        let response = GraphQLService::parse_graphql_response::<serde_json::Value>(raw).unwrap();
+
+        assert!(!response.has_errors());
+        assert_eq!(response.remaining_points(), Some(4990));
+        assert_eq!(response.rate_limit.unwrap().limit, 5000);
+    }
+
+    #[test]
+    fn test_parse_errors_without_data() {
+        let raw = raw_response(serde_json::json!({
+            "data": null,
+            "errors": [{
+                "type": "INSUFFICIENT_SCOPES",
+                "message": "Your token has not been granted the required scopes."
+            }]
+        }));
+
+        let err = GraphQLService::parse_graphql_response::<serde_json::Value>(raw).unwrap_err();
+        assert_eq!(err.kind(), &GitHubErrorKind::InsufficientScopes);
+    }
+
+    #[test]
+    fn test_parse_rate_limited_fails_even_with_data() {
+        let raw = raw_response(serde_json::json!({
+            "data": { "viewer": null },
+            "errors": [{
+                "type": "RATE_LIMITED",
+                "message": "API rate limit exceeded"
+            }]
+        }));
+
+        let err = GraphQLService::parse_graphql_response::<serde_json::Value>(raw).unwrap_err();
+        assert_eq!(err.kind(), &GitHubErrorKind::GraphQlRateLimitExceeded);
+    }
+
+    #[test]
+    fn test_graphql_error_kinds() {
+        let error = |error_type: Option<&str>| GraphQLError {
+            message: "boom".to_string(),
+            error_type: error_type.map(String::from),
+            path: None,
+            locations: None,
+            extensions: None,
+        };
+
+        assert_eq!(
+            error(Some("MAX_NODE_LIMIT_EXCEEDED"))
+                .to_github_error()
+                .kind(),
+            &GitHubErrorKind::NodeLimitExceeded
+        );
+        assert_eq!(
+            error(Some("FORBIDDEN")).to_github_error().kind(),
+            &GitHubErrorKind::Forbidden
+        );
+        assert_eq!(
+            error(None).to_github_error().kind(),
+            &GitHubErrorKind::QueryError
+        );
+    }
 }
@@ -1,22 +1,28 @@
 //! GitHub API service implementations.
 
 mod repositories;
+mod apps;
+mod contents;
 mod issues;
 mod pull_requests;
 mod users;
 mod organizations;
 mod actions;
+mod checks;
 mod gists;
 mod search;
 mod git_data;
 mod graphql;
 
 pub use repositories::*;
+pub use apps::*;
+pub use contents::*;
 pub use issues::*;
 pub use pull_requests::*;
 pub use users::*;
 pub use organizations::*;
 pub use actions::*;
+pub use checks::*;
 pub use gists::*;
 pub use search::*;
 pub use git_data::*;
@@ -685,6 +685,222 @@ pub struct WorkflowWebhook {
     pub state: String,
 }
 
+/// Release event payload.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReleaseEvent {
+    /// Action (published, created, edited, prereleased, released, etc.).
+    pub action: String,
+    /// Release.
+    pub release: ReleaseWebhook,
+    /// Repository.
+    pub repository: WebhookRepository,
+    /// Sender.
+    pub sender: WebhookSender,
+}
+
+/// Release in webhook payload.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReleaseWebhook {
+    /// Release ID.
+    pub id: u64,
+    /// Node ID.
+    pub node_id: String,
+    /// Tag name.
+    pub tag_name: String,
+    /// Branch or commit the tag was created from.
+    pub target_commitish: String,
+    /// Release name.
+    pub name: Option<String>,
+    /// Release notes.
+    pub body: Option<String>,
+    /// Whether draft.
+    pub draft: bool,
+    /// Whether prerelease.
+    pub prerelease: bool,
+    /// User who created the release.
+    pub author: WebhookSender,
+    /// HTML URL.
+    pub html_url: String,
+    /// Tarball URL.
+    pub tarball_url: Option<String>,
+    /// Zipball URL.
+    pub zipball_url: Option<String>,
+    /// Created at.
+    pub created_at: String,
+    /// Published at.
+    pub published_at: Option<String>,
+    /// Uploaded assets.
+    #[serde(default)]
+    pub assets: Vec<ReleaseAssetWebhook>,
+}
+
+/// Release asset in webhook payload.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReleaseAssetWebhook {
+    /// Asset ID.
+    pub id: u64,
+    /// Node ID.
+    pub node_id: String,
+    /// File name.
+    pub name: String,
+    /// Label.
+    pub label: Option<String>,
+    /// Content type.
+    pub content_type: String,
+    /// State.
+    pub state: String,
+    /// Size in bytes.
+    pub size: u64,
+    /// Number of downloads.
+    pub download_count: u64,
+    /// Download URL.
+    pub browser_download_url: String,
+    /// Uploader.
+    pub uploader: Option<WebhookSender>,
+    /// Created at.
+    pub created_at: String,
+    /// Updated at.
+    pub updated_at: String,
+}
+
+/// Deployment event payload.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeploymentEvent {
+    /// Action.
+    pub action: String,
+    /// Deployment.
+    pub deployment: DeploymentWebhook,
+    /// Workflow that created the deployment (if any).
+    pub workflow: Option<WorkflowWebhook>,
+    /// Workflow run that created the deployment (if any).
+    pub workflow_run: Option<WorkflowRunWebhook>,
+    /// Repository.
+    pub repository: WebhookRepository,
+    /// Sender.
+    pub sender: WebhookSender,
+}
+
+/// Deployment in webhook payload.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeploymentWebhook {
+    /// Deployment ID.
+    pub id: u64,
+    /// Node ID.
+    pub node_id: String,
+    /// Commit SHA.
+    pub sha: String,
+    /// Ref that was deployed.
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    /// Task (e.g. "deploy").
+    pub task: String,
+    /// Target environment.
+    pub environment: String,
+    /// Original environment.
+    pub original_environment: Option<String>,
+    /// Description.
+    pub description: Option<String>,
+    /// Extra payload supplied when the deployment was created.
+    #[serde(default)]
+    pub payload: serde_json::Value,
+    /// Whether the environment is transient.
+    #[serde(default)]
+    pub transient_environment: bool,
+    /// Whether the environment is production.
+    #[serde(default)]
+    pub production_environment: bool,
+    /// User who created the deployment.
+    pub creator: WebhookSender,
+    /// Statuses URL.
+    pub statuses_url: String,
+    /// Created at.
+    pub created_at: String,
+    /// Updated at.
+    pub updated_at: String,
+}
+
+/// Check run event payload.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckRunEvent {
+    /// Action (created, completed, rerequested, requested_action).
+    pub action: String,
+    /// Check run.
+    pub check_run: CheckRunWebhook,
+    /// Action requested by the user (for requested_action).
+    pub requested_action: Option<CheckRunRequestedAction>,
+    /// Repository.
+    pub repository: WebhookRepository,
+    /// Sender.
+    pub sender: WebhookSender,
+}
+
+/// Check run in webhook payload.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckRunWebhook {
+    /// Check run ID.
+    pub id: u64,
+    /// Node ID.
+    pub node_id: String,
+    /// Name.
+    pub name: String,
+    /// Head SHA.
+    pub head_sha: String,
+    /// External ID.
+    pub external_id: Option<String>,
+    /// Status (queued, in_progress, completed).
+    pub status: String,
+    /// Conclusion.
+    pub conclusion: Option<String>,
+    /// HTML URL.
+    pub html_url: Option<String>,
+    /// Details URL.
+    pub details_url: Option<String>,
+    /// Started at.
+    pub started_at: Option<String>,
+    /// Completed at.
+    pub completed_at: Option<String>,
+    /// Output.
+    pub output: Option<CheckRunOutputWebhook>,
+    /// Check suite the run belongs to.
+    pub check_suite: Option<CheckSuiteWebhook>,
+}
+
+/// Check run output in webhook payload.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckRunOutputWebhook {
+    /// Title.
+    pub title: Option<String>,
+    /// Summary.
+    pub summary: Option<String>,
+    /// Details text.
+    pub text: Option<String>,
+    /// Number of annotations.
+    #[serde(default)]
+    pub annotations_count: u32,
+}
+
+/// Check suite summary in a check run webhook payload.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckSuiteWebhook {
+    /// Check suite ID.
+    pub id: u64,
+    /// Head branch.
+    pub head_branch: Option<String>,
+    /// Head SHA.
+    pub head_sha: String,
+    /// Status.
+    pub status: Option<String>,
+    /// Conclusion.
+    pub conclusion: Option<String>,
+}
+
+/// Action requested from a check run.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckRunRequestedAction {
+    /// Integrator-provided identifier of the action.
+    pub identifier: String,
+}
+
 /// Webhook handler trait for processing events.
 pub trait WebhookHandler: Send + Sync {
     /// Handles a push event.
@@ -707,6 +923,21 @@ pub trait WebhookHandler: Send + Sync {
         Ok(())
     }
 
+    /// Handles a release event.
+    fn on_release(&self, _event: &ReleaseEvent) -> GitHubResult<()> {
+        Ok(())
+    }
+
+    /// Handles a deployment event.
+    fn on_deployment(&self, _event: &DeploymentEvent) -> GitHubResult<()> {
+        Ok(())
+    }
+
+    /// Handles a check run event.
+    fn on_check_run(&self, _event: &CheckRunEvent) -> GitHubResult<()> {
+        Ok(())
+    }
+
     /// Handles an unknown event.
     fn on_unknown(&self, _event_type: &str, _payload: &[u8]) -> GitHubResult<()> {
         Ok(())
@@ -778,6 +1009,33 @@ impl<H: WebhookHandler> WebhookProcessor<H> {
                 })?;
                 self.handler.on_workflow_run(&event)
             }
+            WebhookEventType::Release => {
+                let event: ReleaseEvent = serde_json::from_slice(payload).map_err(|e| {
+                    GitHubError::new(
+                        GitHubErrorKind::PayloadParseError,
+                        format!("Failed to parse release event: {}", e),
+                    )
+                })?;
+                self.handler.on_release(&event)
+            }
+            WebhookEventType::Deployment => {
+                let event: DeploymentEvent = serde_json::from_slice(payload).map_err(|e| {
+                    GitHubError::new(
+                        GitHubErrorKind::PayloadParseError,
+                        format!("Failed to parse deployment event: {}", e),
+                    )
+                })?;
+                self.handler.on_deployment(&event)
+            }
+            WebhookEventType::CheckRun => {
+                let event: CheckRunEvent = serde_json::from_slice(payload).map_err(|e| {
+                    GitHubError::new(
+                        GitHubErrorKind::PayloadParseError,
+                        format!("Failed to parse check run event: {}", e),
+                    )
+                })?;
+                self.handler.on_check_run(&event)
+            }
             _ => self.handler.on_unknown(&event_type.to_string(), payload),
         }
     }
@@ -812,4 +1070,234 @@ mod tests {
         assert_eq!("pull_request".parse::<WebhookEventType>().unwrap(), WebhookEventType::PullRequest);
         assert_eq!("unknown_event".parse::<WebhookEventType>().unwrap(), WebhookEventType::Unknown);
     }
+
+    const SECRET: &str = "webhook_secret";
+
+    fn sender() -> serde_json::Value {
+        serde_json::json!({
+            "login": "octocat",
+            "id": 1,
+            "node_id": "MDQ6VXNlcjE=",
+            "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+            "type": "User"
+        })
+    }
+
+    fn repository() -> serde_json::Value {
+        serde_json::json!({
+            "id": 1296269,
+            "node_id": "MDEwOlJlcG9zaXRvcnkxMjk2MjY5",
+            "name": "Hello-World",
+            "full_name": "octocat/Hello-World",
+            "private": false,
+            "owner": sender(),
+            "html_url": "https://github.com/octocat/Hello-World",
+            "description": "This your first repo!",
+            "fork": false,
+            "default_branch": "main"
+        })
+    }
+
+    fn release_payload() -> Vec<u8> {
+        serde_json::to_vec(&serde_json::json!({
+            "action": "published",
+            "release": {
+                "id": 1,
+                "node_id": "MDc6UmVsZWFzZTE=",
+                "tag_name": "v1.0.0",
+                "target_commitish": "main",
+                "name": "v1.0.0",
+                "body": "Description of the release",
+                "draft": false,
+                "prerelease": true,
+                "author": sender(),
+                "html_url": "https://github.com/octocat/Hello-World/releases/v1.0.0",
+                "tarball_url": "https://api.github.com/repos/octocat/Hello-World/tarball/v1.0.0",
+                "zipball_url": "https://api.github.com/repos/octocat/Hello-World/zipball/v1.0.0",
+                "created_at": "2013-02-27T19:35:32Z",
+                "published_at": "2013-02-27T19:35:32Z",
+                "assets": [{
+                    "id": 1,
+                    "node_id": "MDEyOlJlbGVhc2VBc3NldDE=",
+                    "name": "example.zip",
+                    "label": "short description",
+                    "content_type": "application/zip",
+                    "state": "uploaded",
+                    "size": 1024,
+                    "download_count": 42,
+                    "browser_download_url": "https://github.com/octocat/Hello-World/releases/download/v1.0.0/example.zip",
+                    "uploader": sender(),
+                    "created_at": "2013-02-27T19:35:32Z",
+                    "updated_at": "2013-02-27T19:35:32Z"
+                }]
+            },
+            "repository": repository(),
+            "sender": sender()
+        }))
+        .unwrap()
+    }
+
+    fn deployment_payload() -> Vec<u8> {
+        serde_json::to_vec(&serde_json::json!({
+            "action": "created",
+            "deployment": {
+                "id": 42,
+                "node_id": "MDEwOkRlcGxveW1lbnQ0Mg==",
+                "sha": "a84d88e7554fc1fa21bcbc4efae3c782a70d2b9d",
+                "ref": "topic-branch",
+                "task": "deploy",
+                "environment": "production",
+                "original_environment": "production",
+                "description": "Deploy request from hubot",
+                "payload": { "deploy": "migrate" },
+                "production_environment": true,
+                "creator": sender(),
+                "statuses_url": "https://api.github.com/repos/octocat/Hello-World/deployments/42/statuses",
+                "created_at": "2012-07-20T01:19:13Z",
+                "updated_at": "2012-07-20T01:19:13Z"
+            },
+            "workflow": null,
+            "workflow_run": null,
+            "repository": repository(),
+            "sender": sender()
+        }))
+        .unwrap()
+    }
+
+    fn check_run_payload() -> Vec<u8> {
+        serde_json::to_vec(&serde_json::json!({
+            "action": "completed",
+            "check_run": {
+                "id": 4,
+                "node_id": "MDg6Q2hlY2tSdW40",
+                "name": "mighty_readme",
+                "head_sha": "ce587453ced02b1526dfb4cb910479d431683101",
+                "external_id": "42",
+                "status": "completed",
+                "conclusion": "failure",
+                "html_url": "https://github.com/octocat/Hello-World/runs/4",
+                "details_url": "https://example.com",
+                "started_at": "2018-05-04T01:14:52Z",
+                "completed_at": "2018-05-04T01:15:52Z",
+                "output": {
+                    "title": "Mighty Readme report",
+                    "summary": "There are 2 failures",
+                    "text": null,
+                    "annotations_count": 2
+                },
+                "check_suite": {
+                    "id": 5,
+                    "head_branch": "main",
+                    "head_sha": "ce587453ced02b1526dfb4cb910479d431683101",
+                    "status": "completed",
+                    "conclusion": "failure"
+                }
+            },
+            "requested_action": null,
+            "repository": repository(),
+            "sender": sender()
+        }))
+        .unwrap()
+    }
+
+    #[derive(Default)]
+    struct RecordingHandler {
+        calls: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl RecordingHandler {
+        fn record(&self, call: String) {
+            self.calls.lock().unwrap().push(call);
+        }
+
+        fn calls(&self) -> Vec<String> {
+            self.calls.lock().unwrap().clone()
+        }
+    }
+
+    impl WebhookHandler for RecordingHandler {
+        fn on_release(&self, event: &ReleaseEvent) -> GitHubResult<()> {
+            self.record(format!("release:{}", event.release.tag_name));
+            Ok(())
+        }
+
+        fn on_deployment(&self, event: &DeploymentEvent) -> GitHubResult<()> {
+            self.record(format!("deployment:{}", event.deployment.environment));
+            Ok(())
+        }
+
+        fn on_check_run(&self, event: &CheckRunEvent) -> GitHubResult<()> {
+            self.record(format!("check_run:{}", event.check_run.name));
+            Ok(())
+        }
+
+        fn on_unknown(&self, event_type: &str, _payload: &[u8]) -> GitHubResult<()> {
+            self.record(format!("unknown:{}", event_type));
+            Ok(())
+        }
+    }
+
+    fn process(event_type: &str, payload: &[u8]) -> Vec<String> {
+        let processor = WebhookProcessor::new(SECRET, RecordingHandler::default());
+        let signature = compute_signature(SECRET, payload).unwrap();
+        processor.process(event_type, &signature, payload).unwrap();
+        processor.handler.calls()
+    }
+
+    #[test]
+    fn test_parse_release_event() {
+        let event: ReleaseEvent = serde_json::from_slice(&release_payload()).unwrap();
+
+        assert_eq!(event.action, "published");
+        assert_eq!(event.release.tag_name, "v1.0.0");
+        assert!(!event.release.draft);
+        assert!(event.release.prerelease);
+        assert_eq!(event.release.assets.len(), 1);
+        assert_eq!(event.release.assets[0].name, "example.zip");
+        assert_eq!(event.release.assets[0].download_count, 42);
+    }
+
+    #[test]
+    fn test_parse_deployment_event() {
+        let event: DeploymentEvent = serde_json::from_slice(&deployment_payload()).unwrap();
+
+        assert_eq!(event.deployment.git_ref, "topic-branch");
+        assert_eq!(event.deployment.environment, "production");
+        assert!(event.deployment.production_environment);
+        assert!(!event.deployment.transient_environment);
+        assert_eq!(event.deployment.payload["deploy"], "migrate");
+        assert!(event.workflow.is_none());
+    }
+
+    #[test]
+    fn test_parse_check_run_event() {
+        let event: CheckRunEvent = serde_json::from_slice(&check_run_payload()).unwrap();
+
+        assert_eq!(event.action, "completed");
+        assert_eq!(event.check_run.conclusion.as_deref(), Some("failure"));
+        assert_eq!(event.check_run.output.as_ref().unwrap().annotations_count, 2);
+        assert_eq!(
+            event.check_run.check_suite.as_ref().unwrap().head_branch.as_deref(),
+            Some("main")
+        );
+    }
+
+    #[test]
+    fn test_dispatch_new_event_types() {
+        assert_eq!(process("release", &release_payload()), vec!["release:v1.0.0"]);
+        assert_eq!(
+            process("deployment", &deployment_payload()),
+            vec!["deployment:production"]
+        );
+        assert_eq!(
+            process("check_run", &check_run_payload()),
+            vec!["check_run:mighty_readme"]
+        );
+    }
+
+    #[test]
+    fn test_dispatch_falls_back_to_unknown() {
+        let payload = br#"{"action":"created","starred_at":null}"#;
+        assert_eq!(process("star", payload), vec!["unknown:star"]);
+    }
 }
@@ -7,6 +7,7 @@ use crate::transport::{HttpRequest, HttpResponse, HttpMethod, HttpTransport, Req
 use bytes::Bytes;
 use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, USER_AGENT};
 use reqwest::StatusCode;
+use secrecy::ExposeSecret;
 use serde::{de::DeserializeOwned, Serialize};
 use std::sync::Arc;
 use std::time::Duration;
@@ -142,6 +143,66 @@ impl RequestExecutor {
         Ok(response.body)
     }
 
+    /// Executes a request against the upload endpoint and returns the full response.
+    ///
+    /// Unlike [`execute_request_raw`](Self::execute_request_raw), the caller
+    /// supplies the content headers and gets the response headers back, which
+    /// the upload protocols need (e.g. the `Location` of a resumable session).
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - HTTP method
+    /// * `path` - Upload path (relative to the upload URL)
+    /// * `headers` - Additional request headers
+    /// * `body` - Optional request body
+    pub async fn execute_upload(
+        &self,
+        method: HttpMethod,
+        path: &str,
+        mut headers: HeaderMap,
+        body: Option<RequestBody>,
+    ) -> GoogleDriveResult<HttpResponse> {
+        let url = self.build_upload_url(path)?;
+
+        self.add_auth_header(&mut headers).await?;
+        headers.insert(
+            USER_AGENT,
+            HeaderValue::from_str(&self.config.user_agent)
+                .map_err(|e| GoogleDriveError::Request(
+                    crate::errors::RequestError::ValidationError(format!("Invalid user agent: {}", e))
+                ))?
+        );
+
+        let http_request = HttpRequest {
+            method,
+            url,
+            headers,
+            body,
+            timeout: Some(self.config.timeout),
+        };
+
+        let response = self.transport
+            .send(http_request)
+            .await
+            .map_err(GoogleDriveError::from)?;
+
+        if !response.status.is_success() {
+            return Err(self.handle_error_response(response)?);
+        }
+
+        Ok(response)
+    }
+
+    /// Gets the configuration.
+    pub fn config(&self) -> &GoogleDriveConfig {
+        &self.config
+    }
+
+    /// Gets the HTTP transport.
+    pub fn transport(&self) -> Arc<dyn HttpTransport> {
+        self.transport.clone()
+    }
+
     /// Builds a full URL from a path.
     ///
     /// # Arguments
@@ -213,20 +274,22 @@ impl RequestExecutor {
         struct ErrorItem {
             reason: Option<String>,
             message: Option<String>,
+            location: Option<String>,
         }
 
         let error_detail: Option<ErrorResponse> = serde_json::from_slice(&response.body).ok();
 
-        let (message, reason) = error_detail
+        let (message, reason, location) = error_detail
             .as_ref()
             .map(|e| {
-                let reason = e.error.errors.as_ref()
-                    .and_then(|errs| errs.first())
-                    .and_then(|err| err.reason.clone());
-                (e.error.message.clone(), reason)
+                let first = e.error.errors.as_ref().and_then(|errs| errs.first());
+                let reason = first.and_then(|err| err.reason.clone());
+                let location = first.and_then(|err| err.location.clone());
+                (e.error.message.clone(), reason, location)
             })
             .unwrap_or_else(|| (
                 format!("HTTP {}: {}", status.as_u16(), String::from_utf8_lossy(&response.body)),
+                None,
                 None
             ));
 
@@ -239,6 +302,11 @@ impl RequestExecutor {
 
         // Map to domain errors based on status code and reason
         let error = match status {
+            StatusCode::BAD_REQUEST if location.as_deref() == Some("pageToken") => {
+                GoogleDriveError::Request(
+                    crate::errors::RequestError::InvalidPageToken(message)
+                )
+            }
             StatusCode::BAD_REQUEST => {
                 if let Some(ref r) = reason {
                     match r.as_str() {
@@ -289,6 +357,12 @@ impl RequestExecutor {
                         "domainPolicy" => GoogleDriveError::Authorization(
                             AuthorizationError::DomainPolicy(message)
                         ),
+                        "fileNotExportable" => GoogleDriveError::Export(
+                            crate::errors::ExportError::ExportNotSupported(message)
+                        ),
+                        "exportSizeLimitExceeded" => GoogleDriveError::Export(
+                            crate::errors::ExportError::ExportSizeExceeded(message)
+                        ),
                         _ => GoogleDriveError::Authorization(
                             AuthorizationError::Forbidden(message)
                         ),
@@ -227,6 +227,10 @@ pub enum AuthenticationError {
     /// JWT decoding error.
     #[error("JWT decoding error: {0}")]
     JwtDecodingError(String),
+
+    /// Invalid impersonation subject.
+    #[error("Invalid subject: {0}")]
+    InvalidSubject(String),
 }
 
 /// Authorization errors.
@@ -279,6 +283,10 @@ pub enum RequestError {
     /// Invalid MIME type.
     #[error("Invalid MIME type: {0}")]
     InvalidMimeType(String),
+
+    /// Page token is invalid or has expired.
+    #[error("Invalid page token: {0}")]
+    InvalidPageToken(String),
 }
 
 /// Resource errors.
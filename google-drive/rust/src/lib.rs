@@ -112,6 +112,7 @@ pub mod prelude {
         CopyFileRequest, CreateFileRequest, CreateFolderRequest, CreatePermissionRequest,
         ListFilesParams, UpdateFileRequest,
     };
+    pub use crate::types::DriveQuery;
 
     // Errors
     pub use crate::errors::{GoogleDriveError, GoogleDriveResult};
@@ -0,0 +1,175 @@
+//! Test doubles for the transport and authentication layers.
+
+use crate::auth::{AccessToken, AuthProvider};
+use crate::client::RequestExecutor;
+use crate::config::GoogleDriveConfig;
+use crate::errors::{AuthenticationError, TransportError};
+use crate::transport::{
+    ByteStream, HttpMethod, HttpRequest, HttpResponse, HttpTransport, RequestBody,
+};
+use async_trait::async_trait;
+use bytes::Bytes;
+use chrono::{Duration, Utc};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::StatusCode;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use url::Url;
+
+/// A request captured by [`MockTransport`].
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    /// HTTP method.
+    pub method: HttpMethod,
+    /// Request URL.
+    pub url: Url,
+    /// Request headers.
+    pub headers: HeaderMap,
+    /// Request body, if it was sent as bytes.
+    pub body: Option<Bytes>,
+}
+
+impl RecordedRequest {
+    /// Gets a header value as a string.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    /// Gets a query parameter value.
+    pub fn query(&self, name: &str) -> Option<String> {
+        self.url
+            .query_pairs()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.into_owned())
+    }
+}
+
+/// Transport that replays queued responses and records every request.
+#[derive(Default)]
+pub struct MockTransport {
+    responses: Mutex<VecDeque<Result<HttpResponse, TransportError>>>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+impl MockTransport {
+    /// Creates a transport with no queued responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a response with the given status, headers and body.
+    pub fn push_response(&self, status: u16, headers: &[(&str, &str)], body: impl Into<Bytes>) {
+        let mut header_map = HeaderMap::new();
+        for (name, value) in headers {
+            header_map.insert(
+                HeaderName::from_bytes(name.as_bytes()).unwrap(),
+                HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        let response = HttpResponse::new(
+            StatusCode::from_u16(status).unwrap(),
+            header_map,
+            body.into(),
+        );
+        self.responses.lock().unwrap().push_back(Ok(response));
+    }
+
+    /// Queues a JSON response with status 200.
+    pub fn push_json(&self, body: serde_json::Value) {
+        self.push_response(200, &[("content-type", "application/json")], body.to_string());
+    }
+
+    /// Queues a transport failure.
+    pub fn push_error(&self, error: TransportError) {
+        self.responses.lock().unwrap().push_back(Err(error));
+    }
+
+    /// Returns the requests sent so far.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl HttpTransport for MockTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, TransportError> {
+        let body = match request.body {
+            Some(RequestBody::Bytes(bytes)) => Some(bytes),
+            Some(RequestBody::Multipart(multipart)) => Some(multipart.to_bytes()),
+            _ => None,
+        };
+        self.requests.lock().unwrap().push(RecordedRequest {
+            method: request.method,
+            url: request.url,
+            headers: request.headers,
+            body,
+        });
+
+        self.responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .unwrap_or_else(|| Err(TransportError::Http("No mock response queued".to_string())))
+    }
+
+    async fn send_raw(&self, request: HttpRequest) -> Result<Bytes, TransportError> {
+        let response = self.send(request).await?;
+        Ok(response.body)
+    }
+
+    async fn send_streaming(&self, request: HttpRequest) -> Result<ByteStream, TransportError> {
+        let response = self.send(request).await?;
+        Ok(ByteStream::new(futures::stream::once(async move {
+            Ok(response.body)
+        })))
+    }
+}
+
+/// Auth provider that always returns the same token.
+pub struct MockAuthProvider;
+
+#[async_trait]
+impl AuthProvider for MockAuthProvider {
+    async fn get_access_token(&self) -> Result<AccessToken, AuthenticationError> {
+        Ok(AccessToken::new(
+            "mock-token",
+            "Bearer",
+            Utc::now() + Duration::hours(1),
+            vec![],
+        ))
+    }
+
+    async fn refresh_token(&self) -> Result<AccessToken, AuthenticationError> {
+        self.get_access_token().await
+    }
+
+    fn is_expired(&self) -> bool {
+        false
+    }
+}
+
+/// Creates a request executor that sends through `transport`.
+pub fn mock_executor(transport: Arc<MockTransport>) -> Arc<RequestExecutor> {
+    let config = GoogleDriveConfig::builder()
+        .auth_provider(MockAuthProvider)
+        .build()
+        .unwrap();
+    let auth = config.auth_provider.clone();
+    Arc::new(RequestExecutor::new(config, transport, auth))
+}
+
+/// Builds a minimal `drive#file` JSON document.
+pub fn file_json(id: &str, name: &str, mime_type: &str) -> serde_json::Value {
+    serde_json::json!({
+        "kind": "drive#file",
+        "id": id,
+        "name": name,
+        "mimeType": mime_type,
+        "spaces": ["drive"],
+        "version": "1",
+        "createdTime": "2024-01-01T00:00:00Z",
+        "modifiedTime": "2024-01-01T00:00:00Z",
+        "owners": [],
+        "capabilities": {}
+    })
+}
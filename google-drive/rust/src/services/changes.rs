@@ -1,20 +1,27 @@
 //! Change tracking service.
 
-use crate::client::GoogleDriveClient;
-use crate::error::GoogleDriveResult;
+use crate::client::RequestExecutor;
+use crate::errors::{GoogleDriveError, GoogleDriveResult, RequestError};
+use crate::transport::{HttpMethod, RequestBody};
 use crate::types::*;
-use futures::stream::Stream;
+use super::with_query;
+use bytes::Bytes;
+use futures::stream::{self, Stream};
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
 
 /// Service for change tracking.
-pub struct ChangesService<'a> {
-    client: &'a GoogleDriveClient,
+#[derive(Clone)]
+pub struct ChangesService {
+    executor: Arc<RequestExecutor>,
 }
 
-impl<'a> ChangesService<'a> {
+impl ChangesService {
     /// Creates a new changes service.
-    pub fn new(client: &'a GoogleDriveClient) -> Self {
-        Self { client }
+    pub(crate) fn new(executor: Arc<RequestExecutor>) -> Self {
+        Self { executor }
     }
 
     /// Gets the start page token for change tracking.
@@ -22,13 +29,10 @@ impl<'a> ChangesService<'a> {
         &self,
         params: Option<GetStartPageTokenParams>,
     ) -> GoogleDriveResult<StartPageToken> {
-        if let Some(p) = params {
-            self.client
-                .get_with_params("/changes/startPageToken", &p)
-                .await
-        } else {
-            self.client.get("/changes/startPageToken").await
-        }
+        let path = with_query("/changes/startPageToken", &params.unwrap_or_default())?;
+        self.executor
+            .execute_request(HttpMethod::Get, &path, None)
+            .await
     }
 
     /// Lists changes since a page token.
@@ -39,7 +43,10 @@ impl<'a> ChangesService<'a> {
     ) -> GoogleDriveResult<ChangeList> {
         let mut list_params = params.unwrap_or_default();
         list_params.page_token = Some(page_token.to_string());
-        self.client.get_with_params("/changes", &list_params).await
+        let path = with_query("/changes", &list_params)?;
+        self.executor
+            .execute_request(HttpMethod::Get, &path, None)
+            .await
     }
 
     /// Lists all changes with auto-pagination.
@@ -81,14 +88,84 @@ impl<'a> ChangesService<'a> {
         }
     }
 
+    /// Continuously streams changes, polling for new ones.
+    ///
+    /// Starts from `start_token`, or from the current start page token if
+    /// `None`. Each batch of changes is paged through by following
+    /// `nextPageToken`; once the last page returns a `newStartPageToken`,
+    /// the stream waits `poll_interval` and resumes from that token. The
+    /// stream never ends on its own.
+    ///
+    /// Removed files are included and can be told apart with
+    /// [`Change::is_removal`]; they carry a `file_id` but no `file`.
+    ///
+    /// If the page token has expired, a fresh start token is acquired and
+    /// polling continues from there. Changes made between the expired token
+    /// and the fresh one are not replayed. Any other error is yielded and
+    /// ends the stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_token` - Page token to start from, or `None` for "now"
+    /// * `poll_interval` - Delay between polls once caught up
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use integrations_google_drive::*;
+    /// # use futures::{pin_mut, StreamExt};
+    /// # use std::time::Duration;
+    /// # async fn example(client: GoogleDriveClient) -> GoogleDriveResult<()> {
+    /// let changes = client.changes().watch_changes(None, Duration::from_secs(30));
+    /// pin_mut!(changes);
+    ///
+    /// while let Some(change) = changes.next().await {
+    ///     let change = change?;
+    ///     if change.is_removal() {
+    ///         println!("Removed: {}", change.file_id);
+    ///     } else {
+    ///         println!("Changed: {}", change.file_id);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch_changes(
+        &self,
+        start_token: Option<String>,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = GoogleDriveResult<Change>> {
+        let state = WatchState {
+            service: self.clone(),
+            page_token: start_token,
+            poll_interval,
+            wait: false,
+            buffer: VecDeque::new(),
+            done: false,
+        };
+
+        stream::unfold(state, |mut state| async move {
+            let item = state.next_change().await?;
+            Some((item, state))
+        })
+    }
+
     /// Watches for changes via push notifications.
     pub async fn watch(
         &self,
         page_token: &str,
         request: WatchChangesRequest,
     ) -> GoogleDriveResult<Channel> {
-        let path = format!("/changes/watch?pageToken={}", page_token);
-        self.client.post(&path, &request).await
+        let path = format!("/changes/watch?pageToken={}", urlencoding::encode(page_token));
+        let body = serde_json::to_vec(&request).map_err(|e| {
+            GoogleDriveError::Request(RequestError::ValidationError(format!(
+                "Failed to serialize request: {}",
+                e
+            )))
+        })?;
+        self.executor
+            .execute_request(HttpMethod::Post, &path, Some(RequestBody::Bytes(Bytes::from(body))))
+            .await
     }
 
     /// Stops watching for changes.
@@ -97,7 +174,86 @@ impl<'a> ChangesService<'a> {
             id: channel.id.clone(),
             resource_id: channel.resource_id.clone(),
         };
-        self.client.post_no_response("/channels/stop", &request).await
+        let body = serde_json::to_vec(&request).map_err(|e| {
+            GoogleDriveError::Request(RequestError::ValidationError(format!(
+                "Failed to serialize request: {}",
+                e
+            )))
+        })?;
+        self.executor
+            .execute_request_raw(
+                HttpMethod::Post,
+                "/channels/stop",
+                Some(RequestBody::Bytes(Bytes::from(body))),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+/// Polling state behind [`ChangesService::watch_changes`].
+struct WatchState {
+    service: ChangesService,
+    page_token: Option<String>,
+    poll_interval: Duration,
+    wait: bool,
+    buffer: VecDeque<Change>,
+    done: bool,
+}
+
+impl WatchState {
+    async fn next_change(&mut self) -> Option<GoogleDriveResult<Change>> {
+        loop {
+            if let Some(change) = self.buffer.pop_front() {
+                return Some(Ok(change));
+            }
+            if self.done {
+                return None;
+            }
+
+            // A token we just fetched is trusted to be valid; if the server
+            // rejects it anyway, report the error instead of looping.
+            let (token, fresh) = match self.page_token.take() {
+                Some(token) => (token, false),
+                None => match self.service.get_start_page_token(None).await {
+                    Ok(token) => (token.start_page_token, true),
+                    Err(e) => return Some(self.fail(e)),
+                },
+            };
+
+            if self.wait {
+                tokio::time::sleep(self.poll_interval).await;
+                self.wait = false;
+            }
+
+            let params = ListChangesParams {
+                include_removed: Some(true),
+                ..Default::default()
+            };
+
+            match self.service.list(&token, Some(params)).await {
+                Ok(change_list) => {
+                    self.buffer.extend(change_list.changes);
+                    if let Some(next) = change_list.next_page_token {
+                        self.page_token = Some(next);
+                    } else {
+                        // The last page always carries a new start token;
+                        // fall back to the current one if it is missing.
+                        self.page_token = Some(change_list.new_start_page_token.unwrap_or(token));
+                        self.wait = true;
+                    }
+                }
+                Err(GoogleDriveError::Request(RequestError::InvalidPageToken(_))) if !fresh => {
+                    self.page_token = None;
+                }
+                Err(e) => return Some(self.fail(e)),
+            }
+        }
+    }
+
+    fn fail(&mut self, error: GoogleDriveError) -> GoogleDriveResult<Change> {
+        self.done = true;
+        Err(error)
     }
 }
 
@@ -160,3 +316,136 @@ struct StopChannelRequest {
     id: String,
     resource_id: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocks::{file_json, mock_executor, MockTransport};
+    use futures::{pin_mut, StreamExt};
+    use serde_json::json;
+
+    fn change_json(file_id: &str) -> serde_json::Value {
+        json!({
+            "kind": "drive#change",
+            "removed": false,
+            "file": file_json(file_id, "doc.txt", "text/plain"),
+            "fileId": file_id,
+            "time": "2024-01-02T00:00:00Z",
+            "type": "file"
+        })
+    }
+
+    fn removal_json(file_id: &str) -> serde_json::Value {
+        json!({
+            "kind": "drive#change",
+            "removed": true,
+            "fileId": file_id,
+            "time": "2024-01-02T00:00:00Z",
+            "type": "file"
+        })
+    }
+
+    fn start_token_json(token: &str) -> serde_json::Value {
+        json!({ "kind": "drive#startPageToken", "startPageToken": token })
+    }
+
+    #[tokio::test]
+    async fn test_watch_changes_pages_then_polls_new_start_token() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_json(start_token_json("100"));
+        transport.push_json(json!({
+            "kind": "drive#changeList",
+            "nextPageToken": "101",
+            "changes": [change_json("a"), change_json("b")]
+        }));
+        transport.push_json(json!({
+            "kind": "drive#changeList",
+            "newStartPageToken": "102",
+            "changes": [removal_json("c")]
+        }));
+        transport.push_json(json!({
+            "kind": "drive#changeList",
+            "newStartPageToken": "103",
+            "changes": [change_json("d")]
+        }));
+
+        let service = ChangesService::new(mock_executor(transport.clone()));
+        let stream = service.watch_changes(None, Duration::from_millis(10));
+        pin_mut!(stream);
+
+        let mut changes = Vec::new();
+        for _ in 0..4 {
+            changes.push(stream.next().await.unwrap().unwrap());
+        }
+
+        let ids: Vec<_> = changes.iter().map(|c| c.file_id.as_str()).collect();
+        assert_eq!(ids, ["a", "b", "c", "d"]);
+        assert!(!changes[0].is_removal());
+        assert!(changes[2].is_removal());
+        assert!(changes[2].file.is_none());
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 4);
+        assert!(requests[0].url.path().ends_with("/changes/startPageToken"));
+        let tokens: Vec<_> = requests[1..]
+            .iter()
+            .map(|r| r.query("pageToken").unwrap())
+            .collect();
+        assert_eq!(tokens, ["100", "101", "102"]);
+        assert_eq!(requests[1].query("includeRemoved").as_deref(), Some("true"));
+    }
+
+    #[tokio::test]
+    async fn test_watch_changes_reacquires_expired_token() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_response(
+            400,
+            &[("content-type", "application/json")],
+            json!({
+                "error": {
+                    "code": 400,
+                    "message": "Invalid Value",
+                    "errors": [{
+                        "domain": "global",
+                        "reason": "invalid",
+                        "message": "Invalid Value",
+                        "locationType": "parameter",
+                        "location": "pageToken"
+                    }]
+                }
+            })
+            .to_string(),
+        );
+        transport.push_json(start_token_json("500"));
+        transport.push_json(json!({
+            "kind": "drive#changeList",
+            "newStartPageToken": "501",
+            "changes": [change_json("a")]
+        }));
+
+        let service = ChangesService::new(mock_executor(transport.clone()));
+        let stream = service.watch_changes(Some("1".to_string()), Duration::from_millis(10));
+        pin_mut!(stream);
+
+        let change = stream.next().await.unwrap().unwrap();
+        assert_eq!(change.file_id, "a");
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].query("pageToken").as_deref(), Some("1"));
+        assert!(requests[1].url.path().ends_with("/changes/startPageToken"));
+        assert_eq!(requests[2].query("pageToken").as_deref(), Some("500"));
+    }
+
+    #[tokio::test]
+    async fn test_watch_changes_ends_on_other_errors() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_response(404, &[], "not found");
+
+        let service = ChangesService::new(mock_executor(transport));
+        let stream = service.watch_changes(Some("1".to_string()), Duration::from_millis(10));
+        pin_mut!(stream);
+
+        assert!(stream.next().await.unwrap().is_err());
+        assert!(stream.next().await.is_none());
+    }
+}
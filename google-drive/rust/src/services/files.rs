@@ -16,6 +16,9 @@ use bytes::Bytes;
 use futures::Stream;
 use std::sync::Arc;
 
+/// MIME type prefix of Google Workspace files (Docs, Sheets, Slides, ...).
+const GOOGLE_APPS_MIME_PREFIX: &str = "application/vnd.google-apps.";
+
 /// Service for file operations.
 pub struct FilesService {
     executor: Arc<RequestExecutor>,
@@ -157,13 +160,13 @@ impl FilesService {
     ///
     /// ```no_run
     /// # use integrations_google_drive::*;
-    /// # use integrations_google_drive::types::ListFilesParams;
+    /// # use integrations_google_drive::types::{DriveQuery, ListFilesParams};
     /// # async fn example(client: GoogleDriveClient) -> GoogleDriveResult<()> {
     /// let params = ListFilesParams {
-    ///     q: Some("name contains 'report'".to_string()),
     ///     page_size: Some(100),
     ///     ..Default::default()
-    /// };
+    /// }
+    /// .with_query(DriveQuery::new().name_contains("report").trashed(false))?;
     ///
     /// let file_list = client.files().list(Some(params)).await?;
     /// for file in file_list.files {
@@ -551,13 +554,13 @@ impl FilesService {
     ///     ..Default::default()
     /// };
     ///
-    /// let session = client.files()
+    /// let mut session = client.files()
     ///     .create_resumable(metadata, 100_000_000, "application/octet-stream")
     ///     .await?;
     ///
     /// // Upload content in chunks
     /// let content = Bytes::from(vec![0u8; 100_000_000]);
-    /// let file = session.upload(content).await?;
+    /// let file = session.upload_bytes(content).await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -579,6 +582,70 @@ impl FilesService {
         upload_service.initiate_resumable(metadata, content_length, mime_type).await
     }
 
+    /// Uploads a file of unknown size from a reader using a resumable session.
+    ///
+    /// The content is read and sent in `chunk_size` pieces (a multiple of
+    /// 256KB); interrupted chunks are resumed from the committed range. The
+    /// upload MIME type is taken from `metadata.mime_type`, defaulting to
+    /// `application/octet-stream`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use integrations_google_drive::*;
+    /// # use integrations_google_drive::types::CreateFileRequest;
+    /// # async fn example(client: GoogleDriveClient, metadata: CreateFileRequest) -> GoogleDriveResult<()> {
+    /// let reader = tokio::fs::File::open("backup.tar").await.unwrap();
+    /// let file = client.files()
+    ///     .upload_resumable(metadata, reader, 8 * 1024 * 1024)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn upload_resumable<R>(
+        &self,
+        metadata: CreateFileRequest,
+        reader: R,
+        chunk_size: usize,
+    ) -> GoogleDriveResult<DriveFile>
+    where
+        R: tokio::io::AsyncRead + Unpin,
+    {
+        self.upload_resumable_with_progress(metadata, reader, chunk_size, |_| {}).await
+    }
+
+    /// Like [`upload_resumable`](Self::upload_resumable), calling `on_progress`
+    /// after every committed chunk.
+    pub async fn upload_resumable_with_progress<R, F>(
+        &self,
+        metadata: CreateFileRequest,
+        reader: R,
+        chunk_size: usize,
+        on_progress: F,
+    ) -> GoogleDriveResult<DriveFile>
+    where
+        R: tokio::io::AsyncRead + Unpin,
+        F: FnMut(&super::upload::UploadStatus),
+    {
+        // Validate name
+        if metadata.name.is_empty() {
+            return Err(GoogleDriveError::Request(
+                crate::errors::RequestError::MissingParameter("name is required".to_string())
+            ));
+        }
+
+        let mime_type = metadata
+            .mime_type
+            .clone()
+            .unwrap_or_else(|| "application/octet-stream".to_string());
+
+        let upload_service = super::upload::UploadService::new(self.executor.clone());
+        let mut session = upload_service
+            .initiate_resumable_unsized(metadata, &mime_type, chunk_size)
+            .await?;
+        session.upload_reader(reader, on_progress).await
+    }
+
     // ========================================================================
     // Download Operations
     // ========================================================================
@@ -684,6 +751,106 @@ impl FilesService {
             ));
         }
 
+        self.export_raw(file_id, mime_type).await
+    }
+
+    /// Exports a Google Workspace file to the first supported format in `preferred`.
+    ///
+    /// The file's type is looked up and matched against the export formats
+    /// reported by the About resource.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The ID of the Google Workspace file to export
+    /// * `preferred` - Acceptable MIME types, most preferred first
+    ///
+    /// # Returns
+    ///
+    /// The chosen MIME type and the exported content
+    ///
+    /// # Errors
+    ///
+    /// Returns `ExportError::ExportNotSupported` if the file is not a Google
+    /// Workspace file, and `ExportError::InvalidExportFormat` if none of the
+    /// preferred formats is available for its type.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use integrations_google_drive::*;
+    /// # async fn example(client: GoogleDriveClient) -> GoogleDriveResult<()> {
+    /// let (mime_type, content) = client.files()
+    ///     .export_to_best("doc_id", &[
+    ///         "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+    ///         "application/pdf",
+    ///     ])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn export_to_best(
+        &self,
+        file_id: &str,
+        preferred: &[&str],
+    ) -> GoogleDriveResult<(String, Bytes)> {
+        // Validate file ID
+        if file_id.is_empty() {
+            return Err(GoogleDriveError::Request(
+                crate::errors::RequestError::MissingParameter("file_id is required".to_string())
+            ));
+        }
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct FileMimeType {
+            mime_type: String,
+        }
+
+        let path = format!("/files/{}?fields=mimeType", urlencoding::encode(file_id));
+        let file: FileMimeType = self.executor
+            .execute_request(HttpMethod::Get, &path, None)
+            .await?;
+
+        if !file.mime_type.starts_with(GOOGLE_APPS_MIME_PREFIX) {
+            return Err(GoogleDriveError::Export(
+                crate::errors::ExportError::ExportNotSupported(
+                    format!("{} is not a Google Workspace file ({})", file_id, file.mime_type)
+                )
+            ));
+        }
+
+        let about: About = self.executor
+            .execute_request(HttpMethod::Get, "/about?fields=kind,exportFormats", None)
+            .await?;
+        let available = about
+            .export_formats
+            .as_ref()
+            .and_then(|formats| formats.get(&file.mime_type))
+            .filter(|formats| !formats.is_empty())
+            .ok_or_else(|| GoogleDriveError::Export(
+                crate::errors::ExportError::ExportNotSupported(
+                    format!("{} files cannot be exported", file.mime_type)
+                )
+            ))?;
+
+        let mime_type = preferred
+            .iter()
+            .find(|candidate| available.iter().any(|format| format == *candidate))
+            .ok_or_else(|| GoogleDriveError::Export(
+                crate::errors::ExportError::InvalidExportFormat(format!(
+                    "None of [{}] is available for {}; supported: [{}]",
+                    preferred.join(", "),
+                    file.mime_type,
+                    available.join(", ")
+                ))
+            ))?;
+
+        let content = self.export_raw(file_id, mime_type).await?;
+        Ok((mime_type.to_string(), content))
+    }
+
+    /// Sends the export request without validating the format.
+    async fn export_raw(&self, file_id: &str, mime_type: &str) -> GoogleDriveResult<Bytes> {
         let path = format!(
             "/files/{}/export?mimeType={}",
             urlencoding::encode(file_id),
@@ -691,7 +858,7 @@ impl FilesService {
         );
 
         self.executor
-            .execute_request_raw(HttpMethod::GET, &path, None)
+            .execute_request_raw(HttpMethod::Get, &path, None)
             .await
     }
 
@@ -926,6 +1093,80 @@ impl FilesService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::errors::ExportError;
+    use crate::mocks::{mock_executor, MockTransport};
+
+    const GOOGLE_DOC: &str = "application/vnd.google-apps.document";
+
+    fn export_formats_json() -> serde_json::Value {
+        serde_json::json!({
+            "kind": "drive#about",
+            "exportFormats": {
+                GOOGLE_DOC: [
+                    "application/pdf",
+                    "text/plain",
+                    "application/vnd.openxmlformats-officedocument.wordprocessingml.document"
+                ]
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_export_to_best_picks_first_supported_format() {
+        let transport = std::sync::Arc::new(MockTransport::new());
+        transport.push_json(serde_json::json!({ "mimeType": GOOGLE_DOC }));
+        transport.push_json(export_formats_json());
+        transport.push_response(200, &[("content-type", "application/pdf")], "%PDF-1.7");
+
+        let files = FilesService::new(mock_executor(transport.clone()));
+        let (mime_type, content) = files
+            .export_to_best("doc-1", &["application/epub+zip", "application/pdf", "text/plain"])
+            .await
+            .unwrap();
+
+        assert_eq!(mime_type, "application/pdf");
+        assert_eq!(content, Bytes::from("%PDF-1.7"));
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].query("fields").as_deref(), Some("mimeType"));
+        assert_eq!(requests[1].query("fields").as_deref(), Some("kind,exportFormats"));
+        assert_eq!(requests[2].method, HttpMethod::Get);
+        assert!(requests[2].url.path().ends_with("/files/doc-1/export"));
+        assert_eq!(requests[2].query("mimeType").as_deref(), Some("application/pdf"));
+    }
+
+    #[tokio::test]
+    async fn test_export_to_best_rejects_unsupported_format() {
+        let transport = std::sync::Arc::new(MockTransport::new());
+        transport.push_json(serde_json::json!({ "mimeType": GOOGLE_DOC }));
+        transport.push_json(export_formats_json());
+
+        let files = FilesService::new(mock_executor(transport.clone()));
+        let result = files.export_to_best("doc-1", &["text/csv"]).await;
+
+        assert!(matches!(
+            result,
+            Err(GoogleDriveError::Export(ExportError::InvalidExportFormat(_)))
+        ));
+        // No export request is sent
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_export_to_best_rejects_non_workspace_file() {
+        let transport = std::sync::Arc::new(MockTransport::new());
+        transport.push_json(serde_json::json!({ "mimeType": "application/pdf" }));
+
+        let files = FilesService::new(mock_executor(transport.clone()));
+        let result = files.export_to_best("file-1", &["application/pdf"]).await;
+
+        assert!(matches!(
+            result,
+            Err(GoogleDriveError::Export(ExportError::ExportNotSupported(_)))
+        ));
+        assert_eq!(transport.requests().len(), 1);
+    }
 
     #[test]
     fn test_is_valid_export_mime_type() {
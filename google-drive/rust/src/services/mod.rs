@@ -19,3 +19,18 @@ pub use revisions::*;
 pub use changes::*;
 pub use drives::*;
 pub use about::*;
+
+use crate::errors::{GoogleDriveError, GoogleDriveResult, RequestError};
+use serde::Serialize;
+
+/// Appends `params` to `path` as a query string.
+pub(crate) fn with_query<T: Serialize>(path: &str, params: &T) -> GoogleDriveResult<String> {
+    let query = serde_urlencoded::to_string(params).map_err(|e| {
+        GoogleDriveError::Request(RequestError::InvalidParameter(e.to_string()))
+    })?;
+    if query.is_empty() {
+        Ok(path.to_string())
+    } else {
+        Ok(format!("{}?{}", path, query))
+    }
+}
@@ -1,19 +1,26 @@
 //! Permission operations service.
 
-use crate::client::GoogleDriveClient;
-use crate::error::GoogleDriveResult;
+use super::with_query;
+use crate::client::RequestExecutor;
+use crate::errors::{GoogleDriveError, GoogleDriveResult, RequestError};
+use crate::transport::{HttpMethod, RequestBody};
 use crate::types::*;
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use futures::stream::Stream;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 /// Service for permission operations.
-pub struct PermissionsService<'a> {
-    client: &'a GoogleDriveClient,
+#[derive(Clone)]
+pub struct PermissionsService {
+    executor: Arc<RequestExecutor>,
 }
 
-impl<'a> PermissionsService<'a> {
+impl PermissionsService {
     /// Creates a new permissions service.
-    pub fn new(client: &'a GoogleDriveClient) -> Self {
-        Self { client }
+    pub(crate) fn new(executor: Arc<RequestExecutor>) -> Self {
+        Self { executor }
     }
 
     /// Creates a new permission.
@@ -22,8 +29,112 @@ impl<'a> PermissionsService<'a> {
         file_id: &str,
         request: CreatePermissionRequest,
     ) -> GoogleDriveResult<Permission> {
-        let path = format!("/files/{}/permissions", file_id);
-        self.client.post(&path, &request).await
+        self.create_with_params(file_id, request, CreatePermissionParams::default())
+            .await
+    }
+
+    /// Creates a new permission with query parameters such as
+    /// `sendNotificationEmail` or `transferOwnership`.
+    ///
+    /// The request is validated before it is sent, so combinations the API
+    /// would reject (an expiration on an owner, an ownership transfer without
+    /// acknowledgement) fail with a [`RequestError::ValidationError`].
+    pub async fn create_with_params(
+        &self,
+        file_id: &str,
+        request: CreatePermissionRequest,
+        params: CreatePermissionParams,
+    ) -> GoogleDriveResult<Permission> {
+        validate_create_permission(&request, &params, Utc::now())?;
+
+        let path = with_query(&format!("/files/{}/permissions", file_id), &params)?;
+        let body = serde_json::to_vec(&request).map_err(|e| {
+            GoogleDriveError::Request(RequestError::ValidationError(format!(
+                "Failed to serialize request: {}",
+                e
+            )))
+        })?;
+        self.executor
+            .execute_request(HttpMethod::Post, &path, Some(RequestBody::Bytes(Bytes::from(body))))
+            .await
+    }
+
+    /// Shares a file with a single user without sending a notification email.
+    ///
+    /// `expiration` is only accepted for the reader, commenter and writer roles.
+    pub async fn share_with_user(
+        &self,
+        file_id: &str,
+        email: &str,
+        role: PermissionRole,
+        expiration: Option<DateTime<Utc>>,
+    ) -> GoogleDriveResult<Permission> {
+        let request = CreatePermissionRequest {
+            role,
+            permission_type: PermissionType::User,
+            email_address: Some(email.to_string()),
+            domain: None,
+            allow_file_discovery: None,
+            expiration_time: expiration,
+            view: None,
+            pending_owner: None,
+        };
+        let params = CreatePermissionParams {
+            send_notification_email: Some(false),
+            ..Default::default()
+        };
+        self.create_with_params(file_id, request, params).await
+    }
+
+    /// Makes a file readable (or commentable, writable) by anyone with the link.
+    ///
+    /// The file is not made discoverable through search. No
+    /// `sendNotificationEmail` parameter is sent, because the API only accepts
+    /// it for user and group permissions.
+    pub async fn make_public(
+        &self,
+        file_id: &str,
+        role: PermissionRole,
+    ) -> GoogleDriveResult<Permission> {
+        let request = CreatePermissionRequest {
+            role,
+            permission_type: PermissionType::Anyone,
+            email_address: None,
+            domain: None,
+            allow_file_discovery: Some(false),
+            expiration_time: None,
+            view: None,
+            pending_owner: None,
+        };
+        self.create_with_params(file_id, request, CreatePermissionParams::default())
+            .await
+    }
+
+    /// Starts transferring ownership of a file to another user.
+    ///
+    /// The user is added as a writer marked as pending owner, and becomes the
+    /// owner once they accept the transfer. The API requires the notification
+    /// email for ownership transfers, so it is always sent.
+    pub async fn transfer_ownership(
+        &self,
+        file_id: &str,
+        email: &str,
+    ) -> GoogleDriveResult<Permission> {
+        let request = CreatePermissionRequest {
+            role: PermissionRole::Writer,
+            permission_type: PermissionType::User,
+            email_address: Some(email.to_string()),
+            domain: None,
+            allow_file_discovery: None,
+            expiration_time: None,
+            view: None,
+            pending_owner: Some(true),
+        };
+        let params = CreatePermissionParams {
+            transfer_ownership: Some(true),
+            ..Default::default()
+        };
+        self.create_with_params(file_id, request, params).await
     }
 
     /// Lists permissions for a file.
@@ -32,11 +143,47 @@ impl<'a> PermissionsService<'a> {
         file_id: &str,
         params: Option<ListPermissionsParams>,
     ) -> GoogleDriveResult<PermissionList> {
-        let path = format!("/files/{}/permissions", file_id);
-        if let Some(p) = params {
-            self.client.get_with_params(&path, &p).await
-        } else {
-            self.client.get(&path).await
+        let path = with_query(
+            &format!("/files/{}/permissions", file_id),
+            &params.unwrap_or_default(),
+        )?;
+        self.executor
+            .execute_request(HttpMethod::Get, &path, None)
+            .await
+    }
+
+    /// Lists all permissions for a file with auto-pagination.
+    pub fn list_all<'a>(
+        &'a self,
+        file_id: &'a str,
+        params: Option<ListPermissionsParams>,
+    ) -> impl Stream<Item = GoogleDriveResult<Permission>> + 'a {
+        async_stream::stream! {
+            let mut page_token: Option<String> = None;
+
+            loop {
+                let mut current_params = params.clone().unwrap_or_default();
+                current_params.page_token = page_token.clone();
+
+                let result = self.list(file_id, Some(current_params)).await;
+
+                match result {
+                    Ok(permission_list) => {
+                        for permission in permission_list.permissions {
+                            yield Ok(permission);
+                        }
+
+                        page_token = permission_list.next_page_token;
+                        if page_token.is_none() {
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        yield Err(e);
+                        break;
+                    }
+                }
+            }
         }
     }
 
@@ -47,12 +194,13 @@ impl<'a> PermissionsService<'a> {
         permission_id: &str,
         params: Option<GetPermissionParams>,
     ) -> GoogleDriveResult<Permission> {
-        let path = format!("/files/{}/permissions/{}", file_id, permission_id);
-        if let Some(p) = params {
-            self.client.get_with_params(&path, &p).await
-        } else {
-            self.client.get(&path).await
-        }
+        let path = with_query(
+            &format!("/files/{}/permissions/{}", file_id, permission_id),
+            &params.unwrap_or_default(),
+        )?;
+        self.executor
+            .execute_request(HttpMethod::Get, &path, None)
+            .await
     }
 
     /// Updates a permission.
@@ -63,7 +211,15 @@ impl<'a> PermissionsService<'a> {
         request: UpdatePermissionRequest,
     ) -> GoogleDriveResult<Permission> {
         let path = format!("/files/{}/permissions/{}", file_id, permission_id);
-        self.client.patch(&path, &request).await
+        let body = serde_json::to_vec(&request).map_err(|e| {
+            GoogleDriveError::Request(RequestError::ValidationError(format!(
+                "Failed to serialize request: {}",
+                e
+            )))
+        })?;
+        self.executor
+            .execute_request(HttpMethod::Patch, &path, Some(RequestBody::Bytes(Bytes::from(body))))
+            .await
     }
 
     /// Deletes a permission.
@@ -79,31 +235,106 @@ impl<'a> PermissionsService<'a> {
                 path.push_str("?supportsAllDrives=true");
             }
         }
-        self.client.delete(&path).await
+        let _: serde_json::Value = self
+            .executor
+            .execute_request(HttpMethod::Delete, &path, None)
+            .await?;
+        Ok(())
+    }
+}
+
+/// Checks a permission request against the rules the API enforces.
+fn validate_create_permission(
+    request: &CreatePermissionRequest,
+    params: &CreatePermissionParams,
+    now: DateTime<Utc>,
+) -> GoogleDriveResult<()> {
+    let invalid = |message: String| {
+        Err(GoogleDriveError::Request(RequestError::ValidationError(message)))
+    };
+
+    if let Some(expiration) = request.expiration_time {
+        if !matches!(
+            request.role,
+            PermissionRole::Reader | PermissionRole::Commenter | PermissionRole::Writer
+        ) {
+            return invalid(format!(
+                "expiration_time is only allowed for reader, commenter and writer roles, not {:?}",
+                request.role
+            ));
+        }
+        if !matches!(
+            request.permission_type,
+            PermissionType::User | PermissionType::Group
+        ) {
+            return invalid(format!(
+                "expiration_time is only allowed for user and group permissions, not {:?}",
+                request.permission_type
+            ));
+        }
+        if expiration <= now {
+            return invalid(format!(
+                "expiration_time must be in the future, got {}",
+                expiration.to_rfc3339()
+            ));
+        }
+    }
+
+    let transfer = params.transfer_ownership == Some(true);
+    if request.role == PermissionRole::Owner && !transfer {
+        return invalid("granting the owner role requires transfer_ownership".to_string());
+    }
+    if transfer {
+        if request.permission_type != PermissionType::User {
+            return invalid("ownership can only be transferred to a user".to_string());
+        }
+        if request.role != PermissionRole::Owner && request.pending_owner != Some(true) {
+            return invalid(
+                "transfer_ownership requires either the owner role or pending_owner".to_string(),
+            );
+        }
+        if params.send_notification_email == Some(false) {
+            return invalid(
+                "send_notification_email cannot be disabled for ownership transfers".to_string(),
+            );
+        }
+    }
+    if request.pending_owner == Some(true) && request.permission_type != PermissionType::User {
+        return invalid("pending_owner is only allowed for user permissions".to_string());
+    }
+
+    if params.send_notification_email.is_some()
+        && !matches!(
+            request.permission_type,
+            PermissionType::User | PermissionType::Group
+        )
+    {
+        return invalid(
+            "send_notification_email is only allowed for user and group permissions".to_string(),
+        );
     }
+
+    Ok(())
 }
 
 // Request/Response types
 
-/// Request to create a permission.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Query parameters for creating a permission.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
-pub struct CreatePermissionRequest {
-    pub role: String,
-    #[serde(rename = "type")]
-    pub permission_type: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub email_address: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub allow_file_discovery: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_time: Option<String>,
+pub struct CreatePermissionParams {
+    /// Whether to email the user or group. The API defaults to true.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub send_notification_email: Option<bool>,
+    /// Custom message to include in the notification email.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email_message: Option<String>,
+    /// Acknowledges that ownership changes hands.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_ownership: Option<bool>,
+    /// Whether the requesting application supports shared drives.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_all_drives: Option<bool>,
 }
 
 /// Parameters for listing permissions.
@@ -145,3 +376,177 @@ pub struct UpdatePermissionRequest {
 pub struct DeletePermissionParams {
     pub supports_all_drives: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocks::{mock_executor, MockTransport};
+    use chrono::TimeZone;
+    use futures::{pin_mut, StreamExt};
+    use serde_json::json;
+
+    fn permission_json(id: &str, permission_type: &str, role: &str) -> serde_json::Value {
+        json!({
+            "kind": "drive#permission",
+            "id": id,
+            "type": permission_type,
+            "role": role
+        })
+    }
+
+    fn body_json(transport: &MockTransport, index: usize) -> serde_json::Value {
+        let requests = transport.requests();
+        serde_json::from_slice(requests[index].body.as_ref().unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_share_with_user_with_expiration() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_json(permission_json("perm-1", "user", "commenter"));
+        let service = PermissionsService::new(mock_executor(transport.clone()));
+
+        let expiration = Utc.with_ymd_and_hms(2099, 6, 1, 12, 0, 0).unwrap();
+        let permission = service
+            .share_with_user(
+                "file-1",
+                "alice@example.com",
+                PermissionRole::Commenter,
+                Some(expiration),
+            )
+            .await
+            .unwrap();
+        assert_eq!(permission.role, PermissionRole::Commenter);
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].method, HttpMethod::Post);
+        assert!(requests[0].url.path().ends_with("/files/file-1/permissions"));
+        assert_eq!(
+            requests[0].query("sendNotificationEmail").as_deref(),
+            Some("false")
+        );
+        assert_eq!(
+            body_json(&transport, 0),
+            json!({
+                "role": "commenter",
+                "type": "user",
+                "emailAddress": "alice@example.com",
+                "expirationTime": "2099-06-01T12:00:00Z"
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_make_public() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_json(permission_json("anyoneWithLink", "anyone", "reader"));
+        let service = PermissionsService::new(mock_executor(transport.clone()));
+
+        service
+            .make_public("file-1", PermissionRole::Reader)
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].query("sendNotificationEmail"), None);
+        assert_eq!(
+            body_json(&transport, 0),
+            json!({
+                "role": "reader",
+                "type": "anyone",
+                "allowFileDiscovery": false
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expiration_rejected_for_owner_role() {
+        let transport = Arc::new(MockTransport::new());
+        let service = PermissionsService::new(mock_executor(transport.clone()));
+
+        let expiration = Utc.with_ymd_and_hms(2099, 6, 1, 12, 0, 0).unwrap();
+        let err = service
+            .share_with_user(
+                "file-1",
+                "alice@example.com",
+                PermissionRole::Owner,
+                Some(expiration),
+            )
+            .await
+            .unwrap_err();
+
+        match err {
+            GoogleDriveError::Request(RequestError::ValidationError(message)) => {
+                assert!(message.contains("expiration_time"), "{}", message);
+            }
+            other => panic!("expected a validation error, got {:?}", other),
+        }
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_transfer_ownership_marks_pending_owner() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_json(permission_json("perm-2", "user", "writer"));
+        let service = PermissionsService::new(mock_executor(transport.clone()));
+
+        service
+            .transfer_ownership("file-1", "bob@example.com")
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].query("transferOwnership").as_deref(), Some("true"));
+        assert_eq!(requests[0].query("sendNotificationEmail"), None);
+        let body = body_json(&transport, 0);
+        assert_eq!(body["role"], "writer");
+        assert_eq!(body["pendingOwner"], true);
+    }
+
+    #[test]
+    fn test_transfer_ownership_requires_pending_owner() {
+        let request = CreatePermissionRequest {
+            role: PermissionRole::Writer,
+            permission_type: PermissionType::User,
+            email_address: Some("bob@example.com".to_string()),
+            domain: None,
+            allow_file_discovery: None,
+            expiration_time: None,
+            view: None,
+            pending_owner: None,
+        };
+        let params = CreatePermissionParams {
+            transfer_ownership: Some(true),
+            ..Default::default()
+        };
+
+        assert!(validate_create_permission(&request, &params, Utc::now()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_list_all_follows_page_tokens() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_json(json!({
+            "kind": "drive#permissionList",
+            "nextPageToken": "page-2",
+            "permissions": [permission_json("p1", "user", "owner")]
+        }));
+        transport.push_json(json!({
+            "kind": "drive#permissionList",
+            "permissions": [permission_json("p2", "anyone", "reader")]
+        }));
+        let service = PermissionsService::new(mock_executor(transport.clone()));
+
+        let stream = service.list_all("file-1", None);
+        pin_mut!(stream);
+        let mut ids = Vec::new();
+        while let Some(permission) = stream.next().await {
+            ids.push(permission.unwrap().id);
+        }
+
+        assert_eq!(ids, vec!["p1", "p2"]);
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].query("pageToken"), None);
+        assert_eq!(requests[1].query("pageToken").as_deref(), Some("page-2"));
+    }
+}
@@ -16,10 +16,11 @@
 //! use integrations_google_drive::GoogleDriveClient;
 //! use bytes::Bytes;
 //!
-//! # async fn example(client: GoogleDriveClient) -> Result<(), Box<dyn std::error::Error>> {
+//! # use integrations_google_drive::types::CreateFileRequest;
+//! # async fn example(client: GoogleDriveClient, metadata: CreateFileRequest) -> Result<(), Box<dyn std::error::Error>> {
 //! // Initiate a resumable upload
-//! let session = client.files()
-//!     .create_resumable("large_file.zip", 104857600, "application/zip")
+//! let mut session = client.files()
+//!     .create_resumable(metadata, 104857600, "application/zip")
 //!     .await?;
 //!
 //! // Upload content in chunks
@@ -29,14 +30,23 @@
 //! # }
 //! ```
 
+use crate::client::RequestExecutor;
 use crate::errors::*;
-use crate::types::DriveFile;
+use crate::resilience::{calculate_backoff, RetryConfig};
+use crate::transport::{
+    HttpMethod, HttpRequest, HttpTransport, MultipartBody, RequestBody, ReqwestTransport,
+};
+use crate::types::{CreateFileRequest, DriveFile};
 use bytes::Bytes;
 use futures::Stream;
 use futures::StreamExt;
-use reqwest::{Client as HttpClient, StatusCode};
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE, LOCATION};
+use reqwest::StatusCode;
 use std::io;
+use std::sync::Arc;
+use tokio::io::{AsyncRead, AsyncReadExt};
 use tracing::{debug, info, warn};
+use url::Url;
 
 /// Minimum chunk size for resumable uploads (256KB).
 pub const MIN_CHUNK_SIZE: usize = 256 * 1024;
@@ -47,6 +57,134 @@ pub const DEFAULT_CHUNK_SIZE: usize = 8 * 1024 * 1024;
 /// Maximum file size for resumable uploads (5TB).
 pub const MAX_FILE_SIZE: u64 = 5 * 1024 * 1024 * 1024 * 1024;
 
+/// Service that starts uploads against the Drive upload endpoint.
+pub struct UploadService {
+    executor: Arc<RequestExecutor>,
+}
+
+impl UploadService {
+    /// Creates a new upload service.
+    pub(crate) fn new(executor: Arc<RequestExecutor>) -> Self {
+        Self { executor }
+    }
+
+    /// Uploads metadata and content in a single multipart request.
+    pub async fn multipart_upload(
+        &self,
+        metadata: CreateFileRequest,
+        content: Bytes,
+        mime_type: &str,
+    ) -> GoogleDriveResult<DriveFile> {
+        let metadata = Self::serialize_metadata(&metadata)?;
+        let body = MultipartBody::new(metadata, content, mime_type);
+
+        let response = self.executor
+            .execute_upload(
+                HttpMethod::Post,
+                "/files?uploadType=multipart",
+                HeaderMap::new(),
+                Some(RequestBody::Multipart(body)),
+            )
+            .await?;
+
+        serde_json::from_slice(&response.body)
+            .map_err(|e| GoogleDriveError::Response(ResponseError::DeserializationError(
+                format!("Failed to deserialize file response: {}", e)
+            )))
+    }
+
+    /// Initiates a resumable upload session for `content_length` bytes.
+    ///
+    /// The session uses the chunk size from the client configuration.
+    pub async fn initiate_resumable(
+        &self,
+        metadata: CreateFileRequest,
+        content_length: u64,
+        mime_type: &str,
+    ) -> GoogleDriveResult<ResumableUploadSession> {
+        if content_length > MAX_FILE_SIZE {
+            return Err(GoogleDriveError::Upload(UploadError::UploadSizeExceeded(
+                format!("File size {} exceeds maximum of {} bytes (5TB)", content_length, MAX_FILE_SIZE)
+            )));
+        }
+
+        let chunk_size = self.executor.config().upload_chunk_size;
+        self.initiate(metadata, Some(content_length), mime_type, chunk_size).await
+    }
+
+    /// Initiates a resumable upload session whose size is not known up front.
+    ///
+    /// The total size is declared with the final chunk.
+    pub async fn initiate_resumable_unsized(
+        &self,
+        metadata: CreateFileRequest,
+        mime_type: &str,
+        chunk_size: usize,
+    ) -> GoogleDriveResult<ResumableUploadSession> {
+        self.initiate(metadata, None, mime_type, chunk_size).await
+    }
+
+    /// Sends the `uploadType=resumable` request and builds a session from the
+    /// returned session URI.
+    async fn initiate(
+        &self,
+        metadata: CreateFileRequest,
+        content_length: Option<u64>,
+        mime_type: &str,
+        chunk_size: usize,
+    ) -> GoogleDriveResult<ResumableUploadSession> {
+        let body = Self::serialize_metadata(&metadata)?;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/json; charset=UTF-8"),
+        );
+        headers.insert(
+            "X-Upload-Content-Type",
+            HeaderValue::from_str(mime_type).map_err(|_| GoogleDriveError::Request(
+                RequestError::InvalidMimeType(mime_type.to_string())
+            ))?,
+        );
+        if let Some(length) = content_length {
+            headers.insert("X-Upload-Content-Length", HeaderValue::from(length));
+        }
+
+        debug!(name = %metadata.name, content_length = ?content_length, "Initiating resumable upload");
+
+        let response = self.executor
+            .execute_upload(
+                HttpMethod::Post,
+                "/files?uploadType=resumable",
+                headers,
+                Some(RequestBody::Bytes(body)),
+            )
+            .await?;
+
+        let upload_uri = response
+            .headers
+            .get(LOCATION)
+            .and_then(|v| v.to_str().ok())
+            .ok_or_else(|| GoogleDriveError::Upload(UploadError::UploadFailed(
+                "Missing Location header in resumable upload response".to_string()
+            )))?
+            .to_string();
+
+        info!(upload_uri = %upload_uri, "Resumable upload session created");
+
+        ResumableUploadSession::with_size(upload_uri, content_length, chunk_size)
+            .map(|session| session.with_transport(self.executor.transport()))
+    }
+
+    fn serialize_metadata(metadata: &CreateFileRequest) -> GoogleDriveResult<Bytes> {
+        serde_json::to_vec(metadata)
+            .map(Bytes::from)
+            .map_err(|e| GoogleDriveError::Request(RequestError::ValidationError(
+                format!("Failed to serialize file metadata: {}", e)
+            )))
+    }
+}
+
 /// Resumable upload session for large files (up to 5TB).
 ///
 /// This struct manages a resumable upload session, allowing large files to be
@@ -62,8 +200,8 @@ pub struct ResumableUploadSession {
     /// The resumable upload URI received from the server.
     upload_uri: String,
 
-    /// Total size of the file being uploaded.
-    total_size: u64,
+    /// Total size of the file being uploaded, if known yet.
+    total_size: Option<u64>,
 
     /// Number of bytes successfully uploaded.
     bytes_uploaded: u64,
@@ -71,8 +209,11 @@ pub struct ResumableUploadSession {
     /// Chunk size for uploads (must be multiple of 256KB).
     chunk_size: usize,
 
-    /// HTTP client for making requests.
-    http_client: HttpClient,
+    /// HTTP transport for making requests.
+    transport: Arc<dyn HttpTransport>,
+
+    /// Backoff used between resume attempts.
+    retry_config: RetryConfig,
 }
 
 impl ResumableUploadSession {
@@ -86,6 +227,19 @@ impl ResumableUploadSession {
     /// # Errors
     /// Returns an error if the chunk size is not a multiple of 256KB.
     pub fn new(upload_uri: String, total_size: u64, chunk_size: usize) -> GoogleDriveResult<Self> {
+        Self::with_size(upload_uri, Some(total_size), chunk_size)
+    }
+
+    /// Creates a session for an upload whose total size is only known once
+    /// the final chunk is sent.
+    ///
+    /// # Errors
+    /// Returns an error if the chunk size is not a multiple of 256KB.
+    pub fn new_unsized(upload_uri: String, chunk_size: usize) -> GoogleDriveResult<Self> {
+        Self::with_size(upload_uri, None, chunk_size)
+    }
+
+    fn with_size(upload_uri: String, total_size: Option<u64>, chunk_size: usize) -> GoogleDriveResult<Self> {
         // Validate chunk size (must be multiple of 256KB)
         if chunk_size < MIN_CHUNK_SIZE {
             return Err(GoogleDriveError::Upload(UploadError::ChunkSizeMismatch(
@@ -100,28 +254,43 @@ impl ResumableUploadSession {
         }
 
         // Validate total size
-        if total_size > MAX_FILE_SIZE {
+        if let Some(total_size) = total_size.filter(|&size| size > MAX_FILE_SIZE) {
             return Err(GoogleDriveError::Upload(UploadError::UploadSizeExceeded(
                 format!("File size {} exceeds maximum of {} bytes (5TB)", total_size, MAX_FILE_SIZE)
             )));
         }
 
+        let transport = ReqwestTransport::default().map_err(GoogleDriveError::from)?;
+
         Ok(Self {
             upload_uri,
             total_size,
             bytes_uploaded: 0,
             chunk_size,
-            http_client: HttpClient::new(),
+            transport: Arc::new(transport),
+            retry_config: RetryConfig::default(),
         })
     }
 
+    /// Sends requests through the given transport instead of a default client.
+    pub fn with_transport(mut self, transport: Arc<dyn HttpTransport>) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    /// Sets the backoff used between resume attempts in [`upload_reader`](Self::upload_reader).
+    pub fn with_retry_config(mut self, retry_config: RetryConfig) -> Self {
+        self.retry_config = retry_config;
+        self
+    }
+
     /// Gets the resumable upload URI.
     pub fn upload_uri(&self) -> &str {
         &self.upload_uri
     }
 
-    /// Gets the total size of the upload.
-    pub fn total_size(&self) -> u64 {
+    /// Gets the total size of the upload, or `None` if it is not known yet.
+    pub fn total_size(&self) -> Option<u64> {
         self.total_size
     }
 
@@ -164,23 +333,29 @@ impl ResumableUploadSession {
         let end = offset + chunk_len - 1;
 
         // Build Content-Range header: bytes {start}-{end}/{total}
-        let content_range = format!("bytes {}-{}/{}", offset, end, self.total_size);
+        let content_range = format!("bytes {}-{}/{}", offset, end, self.total_size_header());
 
         debug!(
             upload_uri = %self.upload_uri,
             offset = offset,
             chunk_size = chunk_len,
-            total_size = self.total_size,
+            total_size = ?self.total_size,
             "Uploading chunk"
         );
 
+        let mut headers = HeaderMap::new();
+        headers.insert("Content-Length", HeaderValue::from(chunk_len));
+        headers.insert("Content-Range", Self::header_value(&content_range)?);
+
         // Send PUT request with chunk
-        let response = self.http_client
-            .put(&self.upload_uri)
-            .header("Content-Length", chunk_len.to_string())
-            .header("Content-Range", &content_range)
-            .body(chunk)
-            .send()
+        let response = self.transport
+            .send(HttpRequest {
+                method: HttpMethod::Put,
+                url: self.parsed_upload_uri()?,
+                headers,
+                body: Some(RequestBody::Bytes(chunk)),
+                timeout: None,
+            })
             .await
             .map_err(|e| {
                 warn!(error = %e, "Failed to upload chunk");
@@ -189,35 +364,33 @@ impl ResumableUploadSession {
                 ))
             })?;
 
-        let status = response.status();
+        let status = response.status;
 
         match status {
             // Upload complete
             StatusCode::OK | StatusCode::CREATED => {
+                let total_size = self.total_size.unwrap_or(offset + chunk_len);
                 info!(
-                    total_size = self.total_size,
+                    total_size = total_size,
                     "Resumable upload completed successfully"
                 );
 
-                let file = response
-                    .json::<DriveFile>()
-                    .await
+                let file = serde_json::from_slice::<DriveFile>(&response.body)
                     .map_err(|e| GoogleDriveError::Response(ResponseError::DeserializationError(
                         format!("Failed to deserialize file response: {}", e)
                     )))?;
 
-                self.bytes_uploaded = self.total_size;
+                self.bytes_uploaded = total_size;
                 Ok(UploadChunkResult::Complete(file))
             }
 
             // Resume incomplete - more chunks needed
             StatusCode::PERMANENT_REDIRECT => {
-                let bytes_received = Self::parse_range_header(&response)?;
+                let bytes_received = Self::parse_range_header(&response.headers)?;
 
                 debug!(
                     bytes_received = bytes_received,
-                    total_size = self.total_size,
-                    percent = (bytes_received as f64 / self.total_size as f64 * 100.0),
+                    total_size = ?self.total_size,
                     "Upload in progress"
                 );
 
@@ -235,7 +408,7 @@ impl ResumableUploadSession {
 
             // Client errors (400-499)
             StatusCode::BAD_REQUEST | StatusCode::FORBIDDEN | StatusCode::UNAUTHORIZED => {
-                let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+                let error_text = String::from_utf8_lossy(&response.body);
                 warn!(status = %status, error = %error_text, "Upload request failed");
                 Err(GoogleDriveError::Upload(UploadError::UploadFailed(
                     format!("Upload failed with status {}: {}", status, error_text)
@@ -297,7 +470,7 @@ impl ResumableUploadSession {
         let mut offset = self.bytes_uploaded;
 
         info!(
-            total_size = self.total_size,
+            total_size = ?self.total_size,
             chunk_size = self.chunk_size,
             "Starting stream upload"
         );
@@ -326,6 +499,7 @@ impl ResumableUploadSession {
         // Upload remaining data (last chunk can be smaller)
         if !buffer.is_empty() {
             let upload_chunk = Bytes::from(buffer);
+            self.total_size.get_or_insert(offset + upload_chunk.len() as u64);
             match self.upload_chunk(upload_chunk, offset).await? {
                 UploadChunkResult::Complete(file) => {
                     info!("Upload completed with final chunk");
@@ -356,21 +530,25 @@ impl ResumableUploadSession {
     /// # Returns
     /// The uploaded file metadata on success.
     pub async fn upload_bytes(&mut self, content: Bytes) -> GoogleDriveResult<DriveFile> {
-        if content.len() as u64 != self.total_size {
-            return Err(GoogleDriveError::Upload(UploadError::InvalidUploadRequest(
-                format!(
-                    "Content size {} does not match declared total size {}",
-                    content.len(),
-                    self.total_size
-                )
-            )));
+        match self.total_size {
+            Some(total_size) if content.len() as u64 != total_size => {
+                return Err(GoogleDriveError::Upload(UploadError::InvalidUploadRequest(
+                    format!(
+                        "Content size {} does not match declared total size {}",
+                        content.len(),
+                        total_size
+                    )
+                )));
+            }
+            Some(_) => {}
+            None => self.total_size = Some(content.len() as u64),
         }
 
         let mut offset = self.bytes_uploaded;
         let content_len = content.len();
 
         info!(
-            total_size = self.total_size,
+            total_size = content_len,
             chunk_size = self.chunk_size,
             num_chunks = (content_len + self.chunk_size - 1) / self.chunk_size,
             "Starting byte upload"
@@ -418,48 +596,58 @@ impl ResumableUploadSession {
     pub async fn query_status(&self) -> GoogleDriveResult<UploadStatus> {
         debug!(
             upload_uri = %self.upload_uri,
-            total_size = self.total_size,
+            total_size = ?self.total_size,
             "Querying upload status"
         );
 
+        let mut headers = HeaderMap::new();
+        headers.insert("Content-Length", HeaderValue::from_static("0"));
+        headers.insert(
+            "Content-Range",
+            Self::header_value(&format!("bytes */{}", self.total_size_header()))?,
+        );
+
         // Send empty PUT with Content-Range: bytes */total
-        let response = self.http_client
-            .put(&self.upload_uri)
-            .header("Content-Length", "0")
-            .header("Content-Range", format!("bytes */{}", self.total_size))
-            .send()
+        let response = self.transport
+            .send(HttpRequest {
+                method: HttpMethod::Put,
+                url: self.parsed_upload_uri()?,
+                headers,
+                body: None,
+                timeout: None,
+            })
             .await
             .map_err(|e| GoogleDriveError::Network(NetworkError::ConnectionFailed(
                 format!("Failed to query status: {}", e)
             )))?;
 
-        let status = response.status();
+        let status = response.status;
+        let total_size = self.total_size.unwrap_or(0);
 
         match status {
             // Upload complete
             StatusCode::OK | StatusCode::CREATED => {
                 info!("Upload status: complete");
                 Ok(UploadStatus {
-                    bytes_received: self.total_size,
-                    total_size: self.total_size,
+                    bytes_received: self.total_size.unwrap_or(self.bytes_uploaded),
+                    total_size,
                     is_complete: true,
                 })
             }
 
             // Resume incomplete
             StatusCode::PERMANENT_REDIRECT => {
-                let bytes_received = Self::parse_range_header(&response)?;
+                let bytes_received = Self::parse_range_header(&response.headers)?;
 
                 debug!(
                     bytes_received = bytes_received,
-                    total_size = self.total_size,
-                    percent = (bytes_received as f64 / self.total_size as f64 * 100.0),
+                    total_size = ?self.total_size,
                     "Upload status: in progress"
                 );
 
                 Ok(UploadStatus {
                     bytes_received,
-                    total_size: self.total_size,
+                    total_size,
                     is_complete: false,
                 })
             }
@@ -527,16 +715,21 @@ impl ResumableUploadSession {
         debug!(upload_uri = %self.upload_uri, "Cancelling upload");
 
         // Send DELETE to upload URI
-        let response = self.http_client
-            .delete(&self.upload_uri)
-            .send()
+        let response = self.transport
+            .send(HttpRequest {
+                method: HttpMethod::Delete,
+                url: self.parsed_upload_uri()?,
+                headers: HeaderMap::new(),
+                body: None,
+                timeout: None,
+            })
             .await
             .map_err(|e| GoogleDriveError::Network(NetworkError::ConnectionFailed(
                 format!("Failed to cancel upload: {}", e)
             )))?;
 
         // Accept 204 No Content or 499 Client Closed Request
-        let status = response.status();
+        let status = response.status;
         if status == StatusCode::NO_CONTENT || status.as_u16() == 499 {
             info!("Upload cancelled successfully");
             Ok(())
@@ -547,18 +740,159 @@ impl ResumableUploadSession {
         }
     }
 
+    /// Uploads everything read from `reader`, chunk by chunk.
+    ///
+    /// Each chunk is held in memory until the server has committed it. When a
+    /// chunk fails with a retryable error the committed range is queried and
+    /// the upload continues from there, up to `max_attempts` times per chunk
+    /// from the session's retry configuration. If the server commits only part
+    /// of a chunk, the rest is sent again.
+    ///
+    /// When the session's total size is unknown it is declared with the final
+    /// chunk. `on_progress` is called after every committed chunk.
+    ///
+    /// # Returns
+    /// The uploaded file metadata on success.
+    pub async fn upload_reader<R, F>(&mut self, mut reader: R, mut on_progress: F) -> GoogleDriveResult<DriveFile>
+    where
+        R: AsyncRead + Unpin,
+        F: FnMut(&UploadStatus),
+    {
+        let read_error = |e: io::Error| GoogleDriveError::Upload(UploadError::UploadInterrupted(
+            format!("Failed to read upload source: {}", e)
+        ));
+
+        info!(
+            total_size = ?self.total_size,
+            chunk_size = self.chunk_size,
+            "Starting reader upload"
+        );
+
+        let mut next = Self::read_chunk(&mut reader, self.chunk_size).await.map_err(read_error)?;
+
+        loop {
+            let chunk = next;
+            let chunk_start = self.bytes_uploaded;
+            let chunk_end = chunk_start + chunk.len() as u64;
+
+            // Read ahead so the final chunk can declare the total size
+            next = if chunk.len() < self.chunk_size {
+                Bytes::new()
+            } else {
+                Self::read_chunk(&mut reader, self.chunk_size).await.map_err(read_error)?
+            };
+            if chunk.is_empty() {
+                return Err(GoogleDriveError::Upload(if chunk_start == 0 {
+                    UploadError::InvalidUploadRequest("Cannot upload an empty source".to_string())
+                } else {
+                    UploadError::UploadFailed("Upload incomplete after all data sent".to_string())
+                }));
+            }
+            if next.is_empty() {
+                self.total_size.get_or_insert(chunk_end);
+            }
+
+            let mut attempt = 0;
+            while self.bytes_uploaded < chunk_end {
+                let pending = chunk.slice((self.bytes_uploaded - chunk_start) as usize..);
+
+                match self.upload_chunk(pending, self.bytes_uploaded).await {
+                    Ok(UploadChunkResult::Complete(file)) => {
+                        on_progress(&self.status(true));
+                        info!("Upload completed from reader");
+                        return Ok(file);
+                    }
+                    Ok(UploadChunkResult::InProgress { bytes_received }) if bytes_received < chunk_start => {
+                        return Err(GoogleDriveError::Upload(UploadError::UploadFailed(
+                            format!(
+                                "Server reports {} bytes committed, before the current chunk at {}",
+                                bytes_received, chunk_start
+                            )
+                        )));
+                    }
+                    Ok(UploadChunkResult::InProgress { .. }) => {}
+                    Err(e) if e.is_retryable() && attempt < self.retry_config.max_attempts => {
+                        attempt += 1;
+                        warn!(error = %e, attempt = attempt, "Chunk upload failed, resuming");
+                        tokio::time::sleep(calculate_backoff(attempt, &self.retry_config)).await;
+
+                        let status = self.resume().await?;
+                        if status.is_complete || status.bytes_received < chunk_start {
+                            return Err(GoogleDriveError::Upload(UploadError::UploadFailed(
+                                format!(
+                                    "Server reports {} bytes committed, outside the current chunk at {}",
+                                    status.bytes_received, chunk_start
+                                )
+                            )));
+                        }
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+
+            on_progress(&self.status(false));
+        }
+    }
+
+    /// Reads up to `chunk_size` bytes, stopping early only at the end of `reader`.
+    async fn read_chunk<R>(reader: &mut R, chunk_size: usize) -> io::Result<Bytes>
+    where
+        R: AsyncRead + Unpin,
+    {
+        let mut buffer = vec![0u8; chunk_size];
+        let mut filled = 0;
+
+        while filled < chunk_size {
+            match reader.read(&mut buffer[filled..]).await? {
+                0 => break,
+                n => filled += n,
+            }
+        }
+
+        buffer.truncate(filled);
+        Ok(Bytes::from(buffer))
+    }
+
+    /// Current progress as an [`UploadStatus`].
+    fn status(&self, is_complete: bool) -> UploadStatus {
+        UploadStatus {
+            bytes_received: self.bytes_uploaded,
+            total_size: self.total_size.unwrap_or(0),
+            is_complete,
+        }
+    }
+
+    /// Total size as used in `Content-Range`, `*` while unknown.
+    fn total_size_header(&self) -> String {
+        self.total_size
+            .map(|size| size.to_string())
+            .unwrap_or_else(|| "*".to_string())
+    }
+
+    fn parsed_upload_uri(&self) -> GoogleDriveResult<Url> {
+        Url::parse(&self.upload_uri).map_err(|e| GoogleDriveError::Upload(
+            UploadError::InvalidUploadRequest(format!("Invalid upload URI: {}", e))
+        ))
+    }
+
+    fn header_value(value: &str) -> GoogleDriveResult<HeaderValue> {
+        HeaderValue::from_str(value).map_err(|e| GoogleDriveError::Upload(
+            UploadError::InvalidUploadRequest(format!("Invalid header value: {}", e))
+        ))
+    }
+
     /// Parses the Range header from a 308 response.
     ///
     /// The Range header format is: `bytes=0-{last_byte_received}`
     /// We add 1 to convert from the last byte position to the count of bytes received.
-    fn parse_range_header(response: &reqwest::Response) -> GoogleDriveResult<u64> {
-        let range_header = response
-            .headers()
-            .get("Range")
-            .and_then(|v| v.to_str().ok())
-            .ok_or_else(|| GoogleDriveError::Upload(UploadError::InvalidUploadRequest(
-                "Missing Range header in 308 response".to_string()
-            )))?;
+    /// A 308 without a Range header means no bytes have been received yet.
+    fn parse_range_header(headers: &HeaderMap) -> GoogleDriveResult<u64> {
+        let Some(range_header) = headers.get("Range") else {
+            return Ok(0);
+        };
+        let range_header = range_header.to_str().map_err(|_| GoogleDriveError::Upload(
+            UploadError::InvalidUploadRequest("Invalid Range header in 308 response".to_string())
+        ))?;
 
         // Format: "bytes=0-42" means bytes 0 through 42 inclusive (43 bytes total)
         let bytes_received = range_header
@@ -589,7 +923,7 @@ pub struct UploadStatus {
     /// Number of bytes successfully received by the server.
     pub bytes_received: u64,
 
-    /// Total size of the file being uploaded.
+    /// Total size of the file being uploaded (0 while it is not known).
     pub total_size: u64,
 
     /// Whether the upload is complete.
@@ -615,6 +949,142 @@ impl UploadStatus {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mocks::{file_json, mock_executor, MockTransport};
+    use crate::services::FilesService;
+    use std::time::Duration;
+
+    const SESSION_URI: &str = "https://upload.example.com/upload/drive/v3/files?upload_id=session-1";
+
+    fn metadata(name: &str) -> CreateFileRequest {
+        serde_json::from_value(serde_json::json!({ "name": name })).unwrap()
+    }
+
+    fn no_backoff() -> RetryConfig {
+        RetryConfig {
+            initial_backoff: Duration::ZERO,
+            jitter: false,
+            ..RetryConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_upload_resumable_sends_chunks_until_complete() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_response(200, &[("location", SESSION_URI)], "");
+        transport.push_response(308, &[("range", "bytes=0-262143")], "");
+        transport.push_response(308, &[("range", "bytes=0-524287")], "");
+        transport.push_json(file_json("file-1", "backup.bin", "application/octet-stream"));
+
+        let files = FilesService::new(mock_executor(transport.clone()));
+        let content = vec![7u8; 600 * 1024];
+        let mut progress = Vec::new();
+
+        let file = files
+            .upload_resumable_with_progress(
+                metadata("backup.bin"),
+                &content[..],
+                MIN_CHUNK_SIZE,
+                |status| progress.push(status.bytes_received),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(file.id, "file-1");
+        assert_eq!(progress, vec![262144, 524288, 614400]);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 4);
+
+        let initiate = &requests[0];
+        assert_eq!(initiate.method, HttpMethod::Post);
+        assert_eq!(initiate.query("uploadType").as_deref(), Some("resumable"));
+        assert_eq!(initiate.header("authorization"), Some("Bearer mock-token"));
+        assert_eq!(initiate.header("x-upload-content-type"), Some("application/octet-stream"));
+        assert!(initiate.header("x-upload-content-length").is_none());
+
+        let ranges: Vec<_> = requests[1..]
+            .iter()
+            .map(|r| {
+                assert_eq!(r.method, HttpMethod::Put);
+                assert_eq!(r.url.as_str(), SESSION_URI);
+                r.header("content-range").unwrap().to_string()
+            })
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![
+                "bytes 0-262143/*",
+                "bytes 262144-524287/*",
+                "bytes 524288-614399/614400",
+            ]
+        );
+        assert_eq!(requests[3].body.as_ref().unwrap().len(), 88 * 1024);
+    }
+
+    #[tokio::test]
+    async fn test_upload_reader_resumes_from_committed_range() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_response(308, &[("range", "bytes=0-262143")], "");
+        transport.push_error(crate::errors::TransportError::Network("connection reset".to_string()));
+        transport.push_response(308, &[("range", "bytes=0-299999")], "");
+        transport.push_json(file_json("file-2", "data.bin", "application/octet-stream"));
+
+        let mut session = ResumableUploadSession::new(SESSION_URI.to_string(), 512 * 1024, MIN_CHUNK_SIZE)
+            .unwrap()
+            .with_transport(transport.clone())
+            .with_retry_config(no_backoff());
+        let content = vec![1u8; 512 * 1024];
+
+        let file = session.upload_reader(&content[..], |_| {}).await.unwrap();
+
+        assert_eq!(file.id, "file-2");
+        assert_eq!(session.bytes_uploaded(), 512 * 1024);
+
+        let requests = transport.requests();
+        let ranges: Vec<_> = requests
+            .iter()
+            .map(|r| r.header("content-range").unwrap())
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![
+                "bytes 0-262143/524288",
+                "bytes 262144-524287/524288",
+                "bytes */524288",
+                "bytes 300000-524287/524288",
+            ]
+        );
+        assert!(requests[2].body.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_upload_reader_gives_up_on_expired_session() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_response(404, &[], "");
+
+        let mut session = ResumableUploadSession::new_unsized(SESSION_URI.to_string(), MIN_CHUNK_SIZE)
+            .unwrap()
+            .with_transport(transport.clone())
+            .with_retry_config(no_backoff());
+
+        let result = session.upload_reader(&b"small file"[..], |_| {}).await;
+
+        assert!(matches!(
+            result,
+            Err(GoogleDriveError::Upload(UploadError::ResumableUploadExpired(_)))
+        ));
+        assert_eq!(transport.requests().len(), 1);
+        assert_eq!(transport.requests()[0].header("content-range"), Some("bytes 0-9/10"));
+    }
+
+    #[test]
+    fn test_parse_range_header() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(ResumableUploadSession::parse_range_header(&headers).unwrap(), 0);
+
+        headers.insert("Range", HeaderValue::from_static("bytes=0-42"));
+        assert_eq!(ResumableUploadSession::parse_range_header(&headers).unwrap(), 43);
+    }
 
     #[test]
     fn test_chunk_size_validation() {
@@ -4,6 +4,10 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+mod query;
+
+pub use query::DriveQuery;
+
 /// Google Drive file representation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -736,6 +740,16 @@ pub struct Change {
     pub drive: Option<Drive>,
 }
 
+impl Change {
+    /// Returns true if the file was removed from the change set, either by
+    /// deletion or because the user lost access to it.
+    ///
+    /// Removals carry no `file` metadata, only the `file_id`.
+    pub fn is_removal(&self) -> bool {
+        self.removed
+    }
+}
+
 /// Change list response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -0,0 +1,355 @@
+//! Builder for the `q` search parameter of `files.list`.
+//!
+//! Drive's query language compares fields against single-quoted string
+//! literals. [`DriveQuery`] escapes user values and formats timestamps so
+//! callers never assemble those strings by hand.
+
+use super::ListFilesParams;
+use crate::errors::{GoogleDriveError, GoogleDriveResult, RequestError};
+use chrono::{DateTime, SecondsFormat, Utc};
+
+/// How a term is joined to the terms before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Connective {
+    And,
+    Or,
+}
+
+impl Connective {
+    fn as_str(self) -> &'static str {
+        match self {
+            Connective::And => "and",
+            Connective::Or => "or",
+        }
+    }
+}
+
+/// Builds a Drive search query.
+///
+/// Terms are joined with `and` unless [`or`](Self::or) is called between
+/// them; [`not`](Self::not) negates the next term and [`group`](Self::group)
+/// nests a sub-query in parentheses. Problems such as a dangling `or` are
+/// reported by [`build`](Self::build).
+///
+/// # Example
+///
+/// ```
+/// use integrations_google_drive::types::DriveQuery;
+///
+/// let q = DriveQuery::new()
+///     .name_contains("Valentine's Day")
+///     .and()
+///     .trashed(false)
+///     .build()?;
+/// assert_eq!(q, r"name contains 'Valentine\'s Day' and trashed = false");
+/// # Ok::<(), integrations_google_drive::GoogleDriveError>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DriveQuery {
+    query: String,
+    pending: Option<Connective>,
+    negate_next: bool,
+    error: Option<String>,
+}
+
+impl DriveQuery {
+    /// Creates an empty query.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Joins the next term with `and` (the default).
+    pub fn and(self) -> Self {
+        self.connect(Connective::And)
+    }
+
+    /// Joins the next term with `or`.
+    pub fn or(self) -> Self {
+        self.connect(Connective::Or)
+    }
+
+    /// Negates the next term.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(mut self) -> Self {
+        if self.negate_next {
+            self.record_error("`not` applied twice to the same term");
+        }
+        self.negate_next = true;
+        self
+    }
+
+    /// Matches files whose name is exactly `name`.
+    pub fn name_equals(self, name: &str) -> Self {
+        self.term(format!("name = {}", quote(name)))
+    }
+
+    /// Matches files whose name contains `text`.
+    pub fn name_contains(self, text: &str) -> Self {
+        self.term(format!("name contains {}", quote(text)))
+    }
+
+    /// Matches files whose name, description or content contains `text`.
+    pub fn full_text_contains(self, text: &str) -> Self {
+        self.term(format!("fullText contains {}", quote(text)))
+    }
+
+    /// Matches files of the given MIME type.
+    pub fn mime_type(mut self, mime_type: &str) -> Self {
+        if !mime_type.contains('/') {
+            self.record_error(format!("invalid MIME type: {}", mime_type));
+        }
+        self.term(format!("mimeType = {}", quote(mime_type)))
+    }
+
+    /// Matches folders.
+    pub fn is_folder(self) -> Self {
+        self.mime_type("application/vnd.google-apps.folder")
+    }
+
+    /// Matches files directly inside the folder with ID `parent_id`.
+    pub fn in_parent(mut self, parent_id: &str) -> Self {
+        if parent_id.is_empty() {
+            self.record_error("parent ID must not be empty");
+        }
+        self.term(format!("{} in parents", quote(parent_id)))
+    }
+
+    /// Matches files by whether they are in the trash.
+    pub fn trashed(self, trashed: bool) -> Self {
+        self.term(format!("trashed = {}", trashed))
+    }
+
+    /// Matches files by whether they are starred.
+    pub fn starred(self, starred: bool) -> Self {
+        self.term(format!("starred = {}", starred))
+    }
+
+    /// Matches files modified after `time`.
+    pub fn modified_after(self, time: DateTime<Utc>) -> Self {
+        self.term(format!("modifiedTime > {}", quote_time(time)))
+    }
+
+    /// Matches files modified before `time`.
+    pub fn modified_before(self, time: DateTime<Utc>) -> Self {
+        self.term(format!("modifiedTime < {}", quote_time(time)))
+    }
+
+    /// Matches files created after `time`.
+    pub fn created_after(self, time: DateTime<Utc>) -> Self {
+        self.term(format!("createdTime > {}", quote_time(time)))
+    }
+
+    /// Adds `query` as a single parenthesized term.
+    pub fn group(mut self, query: DriveQuery) -> Self {
+        match query.build() {
+            Ok(inner) => self.term(format!("({})", inner)),
+            Err(e) => {
+                self.record_error(e.to_string());
+                self
+            }
+        }
+    }
+
+    /// Finishes the query.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RequestError::InvalidQuery`] if the query is empty, ends in
+    /// a connective or `not`, or was given an invalid value.
+    pub fn build(self) -> GoogleDriveResult<String> {
+        let error = if let Some(error) = self.error {
+            Some(error)
+        } else if self.query.is_empty() {
+            Some("query has no terms".to_string())
+        } else if self.pending.is_some() || self.negate_next {
+            Some("query ends with an operator".to_string())
+        } else {
+            None
+        };
+
+        match error {
+            Some(error) => Err(GoogleDriveError::Request(RequestError::InvalidQuery(error))),
+            None => Ok(self.query),
+        }
+    }
+
+    fn connect(mut self, connective: Connective) -> Self {
+        if self.query.is_empty() {
+            self.record_error(format!("`{}` before the first term", connective.as_str()));
+        } else if self.pending.is_some() {
+            self.record_error(format!("`{}` follows another operator", connective.as_str()));
+        }
+        self.pending = Some(connective);
+        self
+    }
+
+    fn term(mut self, term: String) -> Self {
+        if !self.query.is_empty() {
+            let connective = self.pending.unwrap_or(Connective::And);
+            self.query.push(' ');
+            self.query.push_str(connective.as_str());
+            self.query.push(' ');
+        }
+        if self.negate_next {
+            self.query.push_str("not ");
+        }
+        self.query.push_str(&term);
+        self.pending = None;
+        self.negate_next = false;
+        self
+    }
+
+    fn record_error(&mut self, error: impl Into<String>) {
+        if self.error.is_none() {
+            self.error = Some(error.into());
+        }
+    }
+}
+
+impl ListFilesParams {
+    /// Sets `q` from a [`DriveQuery`].
+    ///
+    /// # Errors
+    ///
+    /// See [`DriveQuery::build`].
+    pub fn with_query(mut self, query: DriveQuery) -> GoogleDriveResult<Self> {
+        self.q = Some(query.build()?);
+        Ok(self)
+    }
+}
+
+/// Quotes `value` as a query string literal, escaping `\` and `'`.
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('\'');
+    for c in value.chars() {
+        if c == '\\' || c == '\'' {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('\'');
+    quoted
+}
+
+/// Quotes `time` as an RFC 3339 literal in UTC.
+fn quote_time(time: DateTime<Utc>) -> String {
+    format!("'{}'", time.to_rfc3339_opts(SecondsFormat::Secs, true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_documented_examples() {
+        assert_eq!(
+            DriveQuery::new().name_equals("hello").build().unwrap(),
+            "name = 'hello'"
+        );
+        assert_eq!(
+            DriveQuery::new()
+                .name_contains("hello")
+                .and()
+                .not()
+                .name_contains("goodbye")
+                .build()
+                .unwrap(),
+            "name contains 'hello' and not name contains 'goodbye'"
+        );
+        assert_eq!(
+            DriveQuery::new().is_folder().build().unwrap(),
+            "mimeType = 'application/vnd.google-apps.folder'"
+        );
+        assert_eq!(
+            DriveQuery::new().in_parent("1234567").build().unwrap(),
+            "'1234567' in parents"
+        );
+        assert_eq!(
+            DriveQuery::new()
+                .modified_after(Utc.with_ymd_and_hms(2012, 6, 4, 12, 0, 0).unwrap())
+                .build()
+                .unwrap(),
+            "modifiedTime > '2012-06-04T12:00:00Z'"
+        );
+        assert_eq!(
+            DriveQuery::new()
+                .full_text_contains("hello")
+                .and()
+                .trashed(false)
+                .build()
+                .unwrap(),
+            "fullText contains 'hello' and trashed = false"
+        );
+    }
+
+    #[test]
+    fn test_escapes_quotes_and_backslashes() {
+        assert_eq!(
+            DriveQuery::new().name_contains("Valentine's Day").build().unwrap(),
+            r"name contains 'Valentine\'s Day'"
+        );
+        assert_eq!(
+            DriveQuery::new().name_equals(r"C:\temp").build().unwrap(),
+            r"name = 'C:\\temp'"
+        );
+    }
+
+    #[test]
+    fn test_or_and_group() {
+        let q = DriveQuery::new()
+            .in_parent("root")
+            .and()
+            .group(
+                DriveQuery::new()
+                    .mime_type("image/png")
+                    .or()
+                    .mime_type("image/jpeg"),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            q,
+            "'root' in parents and (mimeType = 'image/png' or mimeType = 'image/jpeg')"
+        );
+    }
+
+    #[test]
+    fn test_terms_default_to_and() {
+        let q = DriveQuery::new().starred(true).trashed(false).build().unwrap();
+        assert_eq!(q, "starred = true and trashed = false");
+    }
+
+    #[test]
+    fn test_invalid_queries() {
+        let invalid = [
+            DriveQuery::new(),
+            DriveQuery::new().or().trashed(false),
+            DriveQuery::new().trashed(false).and(),
+            DriveQuery::new().trashed(false).and().or().starred(true),
+            DriveQuery::new().trashed(false).not(),
+            DriveQuery::new().mime_type("pdf"),
+            DriveQuery::new().in_parent(""),
+            DriveQuery::new().group(DriveQuery::new()),
+        ];
+
+        for query in invalid {
+            assert!(matches!(
+                query.build(),
+                Err(GoogleDriveError::Request(RequestError::InvalidQuery(_)))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_list_files_params_with_query() {
+        let params = ListFilesParams::default()
+            .with_query(DriveQuery::new().name_contains("report"))
+            .unwrap();
+        assert_eq!(params.q.as_deref(), Some("name contains 'report'"));
+
+        assert!(ListFilesParams::default().with_query(DriveQuery::new()).is_err());
+    }
+}
@@ -133,6 +133,15 @@ pub enum GroqError {
         message: String,
     },
 
+    /// Structured output did not match the requested JSON schema.
+    #[error("Schema validation failed at {path}: {message}")]
+    SchemaValidation {
+        /// Description of the first violation.
+        message: String,
+        /// JSON Pointer to the offending value.
+        path: String,
+    },
+
     /// Circuit breaker open.
     #[error("Circuit breaker open: service temporarily unavailable")]
     CircuitOpen,
@@ -89,16 +89,16 @@ pub use errors::{GroqError, GroqResult};
 // Type re-exports
 pub use types::chat::{
     ChatChunk, ChatRequest, ChatResponse, Choice, ChunkChoice, Delta, Message,
-    AssistantMessage, Content, ContentPart, FinishReason, ImageDetail, ImageUrl,
+    AssistantMessage, Content, ContentPart, FinishReason, ImageDetail, ImageUrl, JsonSchema,
     ResponseFormat, ResponseFormatType, Role, StreamOptions, Usage,
 };
 pub use types::audio::{
-    AudioFormat, Granularity, Segment, TranscriptionRequest, TranscriptionResponse,
+    AudioFormat, Granularity, Segment, Sentence, TranscriptionRequest, TranscriptionResponse,
     TranslationRequest, TranslationResponse, Word,
 };
-pub use types::models::{Model, ModelList};
+pub use types::models::{Model, ModelCapabilities, ModelList};
 pub use types::tools::{FunctionCall, FunctionDefinition, Tool, ToolCall, ToolChoice};
-pub use types::common::GroqMetadata;
+pub use types::common::{GroqMetadata, Timings};
 
 /// Mock implementations for testing.
 #[cfg(any(test, feature = "mocks"))]
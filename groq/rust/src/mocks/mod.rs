@@ -10,8 +10,8 @@ use std::sync::{Arc, Mutex};
 use crate::auth::AuthProvider;
 use crate::errors::GroqError;
 use crate::transport::{
-    HttpMethod, HttpRequest, HttpResponse, HttpTransport, MultipartRequest, StreamingResponse,
-    TransportError,
+    HttpMethod, HttpRequest, HttpResponse, HttpTransport, MultipartPart, MultipartRequest,
+    StreamingResponse, TransportError,
 };
 
 /// Mock HTTP transport for testing.
@@ -30,6 +30,8 @@ pub struct RecordedRequest {
     pub path: String,
     /// Request body.
     pub body: Option<Vec<u8>>,
+    /// Multipart form parts.
+    pub parts: Option<Vec<MultipartPart>>,
     /// Request headers.
     pub headers: HashMap<String, String>,
 }
@@ -155,11 +157,19 @@ impl MockTransport {
         }
     }
 
-    fn record_request(&self, method: HttpMethod, path: &str, body: Option<Vec<u8>>, headers: HashMap<String, String>) {
+    fn record_request(
+        &self,
+        method: HttpMethod,
+        path: &str,
+        body: Option<Vec<u8>>,
+        parts: Option<Vec<MultipartPart>>,
+        headers: HashMap<String, String>,
+    ) {
         self.requests.lock().unwrap().push(RecordedRequest {
             method,
             path: path.to_string(),
             body,
+            parts,
             headers,
         });
     }
@@ -178,6 +188,7 @@ impl HttpTransport for MockTransport {
             request.method,
             &request.path,
             request.body.clone(),
+            None,
             request.headers.clone(),
         );
 
@@ -197,6 +208,7 @@ impl HttpTransport for MockTransport {
             request.method,
             &request.path,
             request.body.clone(),
+            None,
             request.headers.clone(),
         );
 
@@ -221,6 +233,7 @@ impl HttpTransport for MockTransport {
             HttpMethod::Post,
             &request.path,
             None,
+            Some(request.parts.clone()),
             request.headers.clone(),
         );
 
@@ -310,6 +323,7 @@ pub mod fixtures {
                 prompt_tokens: 10,
                 completion_tokens: 5,
                 total_tokens: 15,
+                queue_time: Some(0.0005),
                 prompt_time: Some(0.001),
                 completion_time: Some(0.002),
                 total_time: Some(0.003),
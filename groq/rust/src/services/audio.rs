@@ -10,7 +10,8 @@ use crate::errors::{ApiErrorResponse, GroqError, RateLimitType};
 use crate::resilience::{RateLimitManager, ResilienceOrchestrator};
 use crate::transport::{HttpResponse, HttpTransport, MultipartPart, MultipartRequest};
 use crate::types::audio::{
-    TranscriptionRequest, TranscriptionResponse, TranslationRequest, TranslationResponse,
+    AudioFormat, Granularity, TranscriptionRequest, TranscriptionResponse, TranslationRequest,
+    TranslationResponse, Word,
 };
 
 /// Audio transcription and translation service.
@@ -83,6 +84,46 @@ impl AudioService {
         self.parse_response(response)
     }
 
+    /// Transcribes audio and returns its words with start and end times.
+    ///
+    /// Forces word-level timestamp granularity and the `verbose_json`
+    /// response format, the only format that carries timestamps. Use
+    /// [`Sentence::group`](crate::types::audio::Sentence::group) to re-chunk
+    /// the words into sentences.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the request asks for a response format
+    /// other than `verbose_json`, and a serialization error if the response
+    /// has no word timestamps.
+    #[instrument(skip(self, request), fields(model = %request.model))]
+    pub async fn transcribe_with_words(
+        &self,
+        mut request: TranscriptionRequest,
+    ) -> Result<Vec<Word>, GroqError> {
+        match request.response_format {
+            None | Some(AudioFormat::VerboseJson) => {}
+            Some(format) => {
+                return Err(GroqError::validation_param(
+                    format!(
+                        "Word timestamps require the verbose_json response format, not {}",
+                        format.as_str()
+                    ),
+                    "response_format",
+                    Some(format.as_str().to_string()),
+                ));
+            }
+        }
+
+        request.response_format = Some(AudioFormat::VerboseJson);
+        request.timestamp_granularities = Some(vec![Granularity::Word]);
+
+        let response = self.transcribe(request).await?;
+        response.words.ok_or_else(|| GroqError::Serialization {
+            message: "Transcription response has no word timestamps".to_string(),
+        })
+    }
+
     /// Translates audio to English text.
     #[instrument(skip(self, request), fields(model = %request.model))]
     pub async fn translate(
@@ -333,3 +374,109 @@ impl std::fmt::Debug for AudioService {
         f.debug_struct("AudioService").finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocks::{MockAuth, MockTransport};
+    use crate::types::audio::Sentence;
+
+    fn service(transport: Arc<MockTransport>) -> AudioService {
+        AudioService::new(
+            transport,
+            Arc::new(MockAuth::default()),
+            Arc::new(ResilienceOrchestrator::default()),
+            Arc::new(RwLock::new(RateLimitManager::new())),
+        )
+    }
+
+    fn request() -> TranscriptionRequest {
+        TranscriptionRequest::new(vec![1, 2, 3], "audio.mp3", "whisper-large-v3")
+    }
+
+    #[tokio::test]
+    async fn test_transcribe_with_words() {
+        let transport = Arc::new(MockTransport::new());
+        transport.queue_json(&serde_json::json!({
+            "task": "transcribe",
+            "language": "English",
+            "duration": 3.2,
+            "text": "Good morning. How are you today?",
+            "words": [
+                { "word": "Good", "start": 0.0, "end": 0.3 },
+                { "word": "morning.", "start": 0.3, "end": 0.8 },
+                { "word": "How", "start": 1.2, "end": 1.4 },
+                { "word": "are", "start": 1.4, "end": 1.5 },
+                { "word": "you", "start": 1.5, "end": 1.7 },
+                { "word": "today?", "start": 1.7, "end": 2.2 }
+            ]
+        }));
+
+        let words = service(Arc::clone(&transport))
+            .transcribe_with_words(request())
+            .await
+            .unwrap();
+
+        assert_eq!(words.len(), 6);
+        assert_eq!(words[1].word, "morning.");
+        assert_eq!(words[5].end, 2.2);
+
+        let sentences = Sentence::group(&words);
+        assert_eq!(sentences.len(), 2);
+        assert_eq!(sentences[0].text, "Good morning.");
+        assert_eq!((sentences[0].start, sentences[0].end), (0.0, 0.8));
+        assert_eq!(sentences[1].text, "How are you today?");
+        assert_eq!((sentences[1].start, sentences[1].end), (1.2, 2.2));
+
+        assert_eq!(transport.last_request().unwrap().path, "audio/transcriptions");
+    }
+
+    #[tokio::test]
+    async fn test_transcribe_with_words_forces_word_granularity() {
+        let transport = Arc::new(MockTransport::new());
+        transport.queue_json(&serde_json::json!({ "text": "", "words": [] }));
+
+        let mut request = request();
+        request.timestamp_granularities = Some(vec![Granularity::Segment]);
+
+        let words = service(Arc::clone(&transport))
+            .transcribe_with_words(request)
+            .await
+            .unwrap();
+        assert!(words.is_empty());
+
+        let parts = transport.last_request().unwrap().parts.unwrap();
+        let text_parts: Vec<_> = parts
+            .iter()
+            .filter_map(|part| match part {
+                MultipartPart::Text { name, value } => Some((name.as_str(), value.as_str())),
+                MultipartPart::File { .. } => None,
+            })
+            .filter(|(name, _)| name.starts_with("response_format") || name.starts_with("timestamp"))
+            .collect();
+
+        assert_eq!(
+            text_parts,
+            [("response_format", "verbose_json"), ("timestamp_granularities[]", "word")]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_transcribe_with_words_rejects_unsupported_format() {
+        let transport = Arc::new(MockTransport::new());
+        let mut request = request();
+        request.response_format = Some(AudioFormat::Srt);
+
+        let result = service(Arc::clone(&transport))
+            .transcribe_with_words(request)
+            .await;
+
+        match result {
+            Err(GroqError::Validation { param, .. }) => {
+                assert_eq!(param.as_deref(), Some("response_format"));
+            }
+            other => panic!("Expected Validation error, got {:?}", other),
+        }
+        assert_eq!(transport.request_count(), 0);
+    }
+}
@@ -10,7 +10,10 @@ use crate::auth::AuthProvider;
 use crate::errors::{ApiErrorResponse, GroqError, RateLimitType};
 use crate::resilience::{RateLimitManager, ResilienceOrchestrator};
 use crate::transport::{ChatStream, HttpMethod, HttpRequest, HttpResponse, HttpTransport};
-use crate::types::chat::{ChatRequest, ChatResponse};
+use crate::types::chat::{ChatRequest, ChatResponse, ResponseFormatType};
+use crate::types::models::ModelCapabilities;
+use crate::types::schema;
+use serde::de::DeserializeOwned;
 
 /// Chat completions service.
 pub struct ChatService {
@@ -41,6 +44,7 @@ impl ChatService {
     pub async fn create(&self, request: ChatRequest) -> Result<ChatResponse, GroqError> {
         // Validate request
         request.validate()?;
+        Self::check_capabilities(&request)?;
 
         // Check rate limits
         {
@@ -82,6 +86,7 @@ impl ChatService {
     pub async fn create_stream(&self, request: ChatRequest) -> Result<ChatStream, GroqError> {
         // Validate request
         request.validate()?;
+        Self::check_capabilities(&request)?;
 
         // Ensure stream is enabled
         let mut stream_request = request;
@@ -131,6 +136,7 @@ impl ChatService {
         timeout: Duration,
     ) -> Result<ChatResponse, GroqError> {
         request.validate()?;
+        Self::check_capabilities(&request)?;
 
         let mut http_request = self.build_request(&request, false)?;
         http_request.timeout = Some(timeout);
@@ -155,6 +161,45 @@ impl ChatService {
         self.parse_response(response)
     }
 
+    /// Creates a chat completion and deserializes its content into `T`.
+    ///
+    /// The request must use JSON mode or a JSON schema response format.
+    /// When a schema is given, the content is validated against it before
+    /// deserializing, so a non-conforming reply fails with
+    /// [`GroqError::SchemaValidation`] rather than a less specific
+    /// deserialization error.
+    #[instrument(skip(self, request), fields(model = %request.model))]
+    pub async fn create_structured<T: DeserializeOwned>(
+        &self,
+        request: ChatRequest,
+    ) -> Result<T, GroqError> {
+        let json_schema = match &request.response_format {
+            Some(format) if format.type_ == ResponseFormatType::JsonSchema => {
+                format.json_schema.as_ref().map(|s| s.schema.clone())
+            }
+            Some(format) if format.type_ == ResponseFormatType::JsonObject => None,
+            _ => {
+                return Err(GroqError::validation_param(
+                    "Structured output requires a JSON response format",
+                    "response_format",
+                    None,
+                ))
+            }
+        };
+
+        let response = self.create(request).await?;
+        let content = response.content().ok_or_else(|| GroqError::Serialization {
+            message: "Response has no content".to_string(),
+        })?;
+
+        let value: serde_json::Value = serde_json::from_str(content)?;
+        if let Some(ref json_schema) = json_schema {
+            schema::validate(json_schema, &value)?;
+        }
+
+        Ok(serde_json::from_value(value)?)
+    }
+
     /// Builds an HTTP request from a chat request.
     fn build_request(&self, request: &ChatRequest, streaming: bool) -> Result<HttpRequest, GroqError> {
         let body = serde_json::to_vec(request).map_err(|e| GroqError::Validation {
@@ -208,6 +253,21 @@ impl ChatService {
         self.parse_error_status(response.status, &response.headers)
     }
 
+    /// Checks the request against the target model's capabilities.
+    ///
+    /// Models missing from the capability table are sent unchecked, so newly
+    /// released models work without a client update.
+    fn check_capabilities(request: &ChatRequest) -> Result<(), GroqError> {
+        if request.skip_capability_check {
+            return Ok(());
+        }
+
+        match ModelCapabilities::lookup(&request.model) {
+            Some(capabilities) => request.validate_capabilities(&capabilities),
+            None => Ok(()),
+        }
+    }
+
     /// Maps an API error to internal error type.
     fn map_error(
         &self,
@@ -319,6 +379,184 @@ impl std::fmt::Debug for ChatService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mocks::{fixtures, MockAuth, MockTransport};
+    use crate::types::chat::Message;
+    use crate::types::tools::Tool;
+    use serde::Deserialize;
+
+    fn service(transport: Arc<MockTransport>) -> ChatService {
+        ChatService::new(
+            transport,
+            Arc::new(MockAuth::default()),
+            Arc::new(ResilienceOrchestrator::default()),
+            Arc::new(RwLock::new(RateLimitManager::new())),
+        )
+    }
+
+    fn chat_response_json(content: &str) -> serde_json::Value {
+        let response = fixtures::chat_response(content);
+        serde_json::json!({
+            "id": response.id,
+            "object": response.object,
+            "created": response.created,
+            "model": response.model,
+            "choices": [{
+                "index": 0,
+                "message": { "role": "assistant", "content": content },
+                "finish_reason": "stop"
+            }],
+            "usage": { "prompt_tokens": 10, "completion_tokens": 5, "total_tokens": 15 }
+        })
+    }
+
+    fn city_request() -> ChatRequest {
+        ChatRequest::builder()
+            .model("llama-3.3-70b-versatile")
+            .user("Name a city")
+            .json_schema(
+                "city",
+                serde_json::json!({
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string" },
+                        "population": { "type": "integer" }
+                    },
+                    "required": ["name", "population"],
+                    "additionalProperties": false
+                }),
+                true,
+            )
+            .build()
+            .unwrap()
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct City {
+        name: String,
+        population: u64,
+    }
+
+    #[tokio::test]
+    async fn test_create_structured_valid_response() {
+        let transport = Arc::new(MockTransport::new());
+        transport.queue_json(&chat_response_json(r#"{"name":"Lagos","population":15388000}"#));
+
+        let city: City = service(Arc::clone(&transport))
+            .create_structured(city_request())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            city,
+            City {
+                name: "Lagos".to_string(),
+                population: 15_388_000
+            }
+        );
+
+        let body: serde_json::Value =
+            serde_json::from_slice(&transport.last_request().unwrap().body.unwrap()).unwrap();
+        assert_eq!(body["response_format"]["type"], "json_schema");
+        assert_eq!(body["response_format"]["json_schema"]["strict"], true);
+    }
+
+    #[tokio::test]
+    async fn test_create_structured_schema_violation() {
+        let transport = Arc::new(MockTransport::new());
+        transport.queue_json(&chat_response_json(r#"{"name":"Lagos","population":"many"}"#));
+
+        let result = service(transport)
+            .create_structured::<City>(city_request())
+            .await;
 
-    // Tests would use mocks - see mocks module
+        match result {
+            Err(GroqError::SchemaValidation { path, .. }) => assert_eq!(path, "/population"),
+            other => panic!("Expected SchemaValidation, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_structured_requires_json_format() {
+        let transport = Arc::new(MockTransport::new());
+        let request = ChatRequest::builder()
+            .model("llama-3.3-70b-versatile")
+            .user("Hello")
+            .build()
+            .unwrap();
+
+        let result = service(Arc::clone(&transport))
+            .create_structured::<City>(request)
+            .await;
+
+        assert!(matches!(result, Err(GroqError::Validation { .. })));
+        assert_eq!(transport.request_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_create_rejects_image_for_text_model() {
+        let transport = Arc::new(MockTransport::new());
+        let request = ChatRequest::builder()
+            .model("llama-3.1-8b-instant")
+            .message(Message::user_with_image(
+                "What is in this picture?",
+                "https://example.com/cat.png",
+            ))
+            .build()
+            .unwrap();
+
+        let result = service(Arc::clone(&transport)).create(request).await;
+
+        match result {
+            Err(GroqError::Validation { message, param, .. }) => {
+                assert!(message.contains("does not support image input"));
+                assert_eq!(param.as_deref(), Some("messages[0].content"));
+            }
+            other => panic!("Expected Validation, got {:?}", other),
+        }
+        assert_eq!(transport.request_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_create_allows_tools_for_capable_model() {
+        let transport = Arc::new(MockTransport::new());
+        transport.queue_json(&chat_response_json("It is sunny."));
+        let request = ChatRequest::builder()
+            .model("llama-3.3-70b-versatile")
+            .user("What's the weather in Paris?")
+            .tool(Tool::function(
+                "get_weather",
+                "Get the current weather",
+                serde_json::json!({
+                    "type": "object",
+                    "properties": { "city": { "type": "string" } },
+                    "required": ["city"]
+                }),
+            ))
+            .build()
+            .unwrap();
+
+        let response = service(Arc::clone(&transport)).create(request).await.unwrap();
+
+        assert_eq!(response.content(), Some("It is sunny."));
+        assert_eq!(transport.request_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_create_unknown_model_is_sent_unchecked() {
+        let transport = Arc::new(MockTransport::new());
+        transport.queue_json(&chat_response_json("Hi"));
+        let request = ChatRequest::builder()
+            .model("my-fine-tune")
+            .user("Hello")
+            .tool(Tool::function(
+                "get_weather",
+                "Get the current weather",
+                serde_json::json!({ "type": "object", "properties": {} }),
+            ))
+            .build()
+            .unwrap();
+
+        assert!(service(Arc::clone(&transport)).create(request).await.is_ok());
+        assert_eq!(transport.request_count(), 1);
+    }
 }
@@ -341,7 +341,7 @@ impl TranslationRequestBuilder {
 }
 
 /// Audio response format.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AudioFormat {
     /// JSON format with full structure.
@@ -455,7 +455,7 @@ pub struct Segment {
 }
 
 /// Word with timestamps.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct Word {
     /// Word text.
     pub word: String,
@@ -467,6 +467,71 @@ pub struct Word {
     pub end: f64,
 }
 
+/// A run of words ending in sentence punctuation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sentence {
+    /// Words joined with single spaces.
+    pub text: String,
+
+    /// Start time of the first word in seconds.
+    pub start: f64,
+
+    /// End time of the last word in seconds.
+    pub end: f64,
+
+    /// The words in this sentence.
+    pub words: Vec<Word>,
+}
+
+impl Sentence {
+    /// Groups words into sentences.
+    ///
+    /// A sentence ends at a word ending in `.`, `?` or `!`, ignoring
+    /// trailing quotes and brackets. Words after the last such word form
+    /// a final, unterminated sentence.
+    pub fn group(words: &[Word]) -> Vec<Sentence> {
+        let mut sentences = Vec::new();
+        let mut current: Vec<Word> = Vec::new();
+
+        for word in words {
+            current.push(word.clone());
+            if ends_sentence(&word.word) {
+                sentences.push(Self::from_words(std::mem::take(&mut current)));
+            }
+        }
+
+        if !current.is_empty() {
+            sentences.push(Self::from_words(current));
+        }
+
+        sentences
+    }
+
+    fn from_words(words: Vec<Word>) -> Self {
+        let text = words
+            .iter()
+            .map(|w| w.word.trim())
+            .filter(|w| !w.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        Self {
+            text,
+            start: words.first().map_or(0.0, |w| w.start),
+            end: words.last().map_or(0.0, |w| w.end),
+            words,
+        }
+    }
+}
+
+/// Returns true if `word` ends with sentence punctuation.
+fn ends_sentence(word: &str) -> bool {
+    word.trim_end_matches(|c: char| {
+        c.is_whitespace() || matches!(c, '"' | '\'' | ')' | ']' | '»' | '”' | '’')
+    })
+    .ends_with(['.', '?', '!', '…'])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -538,4 +603,37 @@ mod tests {
         assert_eq!(response.language, Some("en".to_string()));
         assert_eq!(response.duration, Some(2.5));
     }
+
+    fn word(text: &str, start: f64, end: f64) -> Word {
+        Word {
+            word: text.to_string(),
+            start,
+            end,
+        }
+    }
+
+    #[test]
+    fn test_sentence_grouping() {
+        let words = vec![
+            word("Hi", 0.0, 0.2),
+            word("there.", 0.2, 0.5),
+            word("Ready?", 0.7, 1.0),
+            word("He", 1.2, 1.3),
+            word("said", 1.3, 1.5),
+            word("\"go!\"", 1.5, 1.8),
+            word("and", 2.0, 2.1),
+            word("then", 2.1, 2.4),
+        ];
+
+        let sentences = Sentence::group(&words);
+        let texts: Vec<_> = sentences.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, ["Hi there.", "Ready?", "He said \"go!\"", "and then"]);
+
+        assert_eq!(sentences[0].start, 0.0);
+        assert_eq!(sentences[0].end, 0.5);
+        assert_eq!(sentences[2].words.len(), 3);
+        assert_eq!(sentences[3].end, 2.4);
+
+        assert!(Sentence::group(&[]).is_empty());
+    }
 }
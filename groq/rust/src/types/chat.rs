@@ -2,7 +2,9 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::common::GroqMetadata;
+use super::common::{GroqMetadata, Timings};
+use super::models::known::LLAMA_3_2_11B_VISION;
+use super::models::ModelCapabilities;
 use super::tools::{ToolCall, ToolCallDelta, ToolChoice, Tool};
 use crate::errors::GroqError;
 
@@ -66,6 +68,10 @@ pub struct ChatRequest {
     /// Stream options.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stream_options: Option<StreamOptions>,
+
+    /// Skips the model capability check, e.g. when the table is out of date.
+    #[serde(skip)]
+    pub skip_capability_check: bool,
 }
 
 impl ChatRequest {
@@ -87,6 +93,7 @@ impl ChatRequest {
             user: None,
             stream: None,
             stream_options: None,
+            skip_capability_check: false,
         }
     }
 
@@ -153,6 +160,10 @@ impl ChatRequest {
             }
         }
 
+        if let Some(ref format) = self.response_format {
+            format.validate()?;
+        }
+
         // Validate messages
         for (i, msg) in self.messages.iter().enumerate() {
             if let Err(e) = msg.validate() {
@@ -179,6 +190,57 @@ impl ChatRequest {
 
         Ok(())
     }
+
+    /// Validates the request against the features the model supports.
+    pub fn validate_capabilities(&self, capabilities: &ModelCapabilities) -> Result<(), GroqError> {
+        if !capabilities.supports_vision {
+            for (i, msg) in self.messages.iter().enumerate() {
+                if msg.has_images() {
+                    return Err(GroqError::validation_param(
+                        format!(
+                            "Model '{}' does not support image input; use a vision model such as {}",
+                            self.model, LLAMA_3_2_11B_VISION
+                        ),
+                        format!("messages[{}].content", i),
+                        None,
+                    ));
+                }
+            }
+        }
+
+        if !capabilities.supports_tools && self.tools.as_ref().is_some_and(|t| !t.is_empty()) {
+            return Err(GroqError::validation_param(
+                format!("Model '{}' does not support tool calling", self.model),
+                "tools",
+                None,
+            ));
+        }
+
+        if let Some(ref format) = self.response_format {
+            if format.type_ != ResponseFormatType::Text && !capabilities.supports_json_mode {
+                return Err(GroqError::validation_param(
+                    format!("Model '{}' does not support JSON response formats", self.model),
+                    "response_format",
+                    None,
+                ));
+            }
+        }
+
+        if let Some(max_tokens) = self.max_tokens {
+            if max_tokens > capabilities.context_window {
+                return Err(GroqError::validation_param(
+                    format!(
+                        "max_tokens exceeds the {} token context window of model '{}'",
+                        capabilities.context_window, self.model
+                    ),
+                    "max_tokens",
+                    Some(max_tokens.to_string()),
+                ));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// Chat request builder.
@@ -199,6 +261,7 @@ pub struct ChatRequestBuilder {
     user: Option<String>,
     stream: Option<bool>,
     stream_options: Option<StreamOptions>,
+    skip_capability_check: bool,
 }
 
 impl ChatRequestBuilder {
@@ -281,9 +344,18 @@ impl ChatRequestBuilder {
 
     /// Enables JSON mode.
     pub fn json_mode(mut self) -> Self {
-        self.response_format = Some(ResponseFormat {
-            type_: ResponseFormatType::JsonObject,
-        });
+        self.response_format = Some(ResponseFormat::json_object());
+        self
+    }
+
+    /// Requests output matching a JSON schema.
+    pub fn json_schema(
+        mut self,
+        name: impl Into<String>,
+        schema: serde_json::Value,
+        strict: bool,
+    ) -> Self {
+        self.response_format = Some(ResponseFormat::json_schema(name, schema, strict));
         self
     }
 
@@ -331,6 +403,12 @@ impl ChatRequestBuilder {
         self
     }
 
+    /// Skips the model capability check when the request is sent.
+    pub fn skip_capability_check(mut self) -> Self {
+        self.skip_capability_check = true;
+        self
+    }
+
     /// Builds the request.
     pub fn build(self) -> Result<ChatRequest, GroqError> {
         let model = self.model.ok_or_else(|| {
@@ -361,6 +439,7 @@ impl ChatRequestBuilder {
             user: self.user,
             stream: self.stream,
             stream_options: self.stream_options,
+            skip_capability_check: self.skip_capability_check,
         };
 
         request.validate()?;
@@ -465,6 +544,16 @@ impl Message {
         }
     }
 
+    /// Returns true if the message contains an image part.
+    pub fn has_images(&self) -> bool {
+        match &self.content {
+            Content::Text(_) => false,
+            Content::Parts(parts) => parts
+                .iter()
+                .any(|part| matches!(part, ContentPart::ImageUrl { .. })),
+        }
+    }
+
     /// Validates the message.
     pub fn validate(&self) -> Result<(), String> {
         // Tool messages require tool_call_id
@@ -560,16 +649,105 @@ pub struct ResponseFormat {
     /// Format type.
     #[serde(rename = "type")]
     pub type_: ResponseFormatType,
+
+    /// Schema (for `json_schema`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub json_schema: Option<JsonSchema>,
+}
+
+impl ResponseFormat {
+    /// Creates a plain text format.
+    pub fn text() -> Self {
+        Self {
+            type_: ResponseFormatType::Text,
+            json_schema: None,
+        }
+    }
+
+    /// Creates a JSON mode format.
+    pub fn json_object() -> Self {
+        Self {
+            type_: ResponseFormatType::JsonObject,
+            json_schema: None,
+        }
+    }
+
+    /// Creates a JSON schema format.
+    pub fn json_schema(name: impl Into<String>, schema: serde_json::Value, strict: bool) -> Self {
+        Self {
+            type_: ResponseFormatType::JsonSchema,
+            json_schema: Some(JsonSchema {
+                name: name.into(),
+                description: None,
+                schema,
+                strict: Some(strict),
+            }),
+        }
+    }
+
+    /// Validates the format.
+    pub fn validate(&self) -> Result<(), GroqError> {
+        match (&self.type_, &self.json_schema) {
+            (ResponseFormatType::JsonSchema, None) => Err(GroqError::validation_param(
+                "json_schema is required for the json_schema response format",
+                "response_format.json_schema",
+                None,
+            )),
+            (ResponseFormatType::JsonSchema, Some(schema)) => {
+                if schema.name.is_empty() {
+                    return Err(GroqError::validation_param(
+                        "Schema name is required",
+                        "response_format.json_schema.name",
+                        None,
+                    ));
+                }
+                if !schema.schema.is_object() {
+                    return Err(GroqError::validation_param(
+                        "Schema must be a JSON object",
+                        "response_format.json_schema.schema",
+                        None,
+                    ));
+                }
+                Ok(())
+            }
+            (_, Some(_)) => Err(GroqError::validation_param(
+                "json_schema is only allowed with the json_schema response format",
+                "response_format.json_schema",
+                None,
+            )),
+            (_, None) => Ok(()),
+        }
+    }
 }
 
 /// Response format type.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ResponseFormatType {
     /// Plain text.
     Text,
     /// JSON object.
     JsonObject,
+    /// JSON matching a schema.
+    JsonSchema,
+}
+
+/// JSON schema for structured output.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonSchema {
+    /// Schema name.
+    pub name: String,
+
+    /// Schema description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The JSON Schema itself.
+    pub schema: serde_json::Value,
+
+    /// Whether the model must follow the schema exactly.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
 }
 
 /// Stream options.
@@ -626,6 +804,17 @@ impl ChatResponse {
         self.choices.first().map(|c| c.finish_reason)
     }
 
+    /// Gets the LPU timing breakdown.
+    ///
+    /// Prefers the `x_groq` usage block and falls back to the timing fields
+    /// in `usage`. Returns `None` if the response carries neither.
+    pub fn timings(&self) -> Option<Timings> {
+        self.x_groq
+            .as_ref()
+            .and_then(GroqMetadata::timings)
+            .or_else(|| self.usage.timings())
+    }
+
     /// Builds a response from streaming chunks.
     pub fn from_chunks(chunks: Vec<ChatChunk>) -> Result<Self, GroqError> {
         if chunks.is_empty() {
@@ -783,6 +972,10 @@ pub struct Usage {
     /// Total tokens.
     pub total_tokens: u32,
 
+    /// Queue time (Groq).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue_time: Option<f64>,
+
     /// Prompt time (Groq).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub prompt_time: Option<f64>,
@@ -796,6 +989,18 @@ pub struct Usage {
     pub total_time: Option<f64>,
 }
 
+impl Usage {
+    /// Gets the timing fields as [`Timings`].
+    pub fn timings(&self) -> Option<Timings> {
+        Timings::from_secs(
+            self.queue_time,
+            self.prompt_time,
+            self.completion_time,
+            self.total_time,
+        )
+    }
+}
+
 /// Streaming chunk.
 #[derive(Debug, Clone, Deserialize)]
 pub struct ChatChunk {
@@ -864,6 +1069,7 @@ pub struct Delta {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::Duration;
 
     #[test]
     fn test_chat_request_builder() {
@@ -970,4 +1176,102 @@ mod tests {
         assert_eq!(response.content(), Some("Hello!"));
         assert_eq!(response.finish_reason(), Some(FinishReason::Stop));
     }
+
+    #[test]
+    fn test_json_schema_response_format_serialization() {
+        let request = ChatRequest::builder()
+            .model("llama-3.3-70b-versatile")
+            .user("Hello")
+            .json_schema("greeting", serde_json::json!({ "type": "object" }), true)
+            .build()
+            .unwrap();
+
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            body["response_format"],
+            serde_json::json!({
+                "type": "json_schema",
+                "json_schema": {
+                    "name": "greeting",
+                    "schema": { "type": "object" },
+                    "strict": true
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_json_schema_response_format_validation() {
+        let mut format = ResponseFormat::json_schema("greeting", serde_json::json!(true), false);
+        assert!(format.validate().is_err());
+
+        format.json_schema = None;
+        assert!(format.validate().is_err());
+
+        assert!(ResponseFormat::json_object().validate().is_ok());
+    }
+
+    fn response_fixture(x_groq: Option<serde_json::Value>) -> ChatResponse {
+        let mut json = serde_json::json!({
+            "id": "chatcmpl-123",
+            "object": "chat.completion",
+            "created": 1705312345,
+            "model": "llama-3.3-70b-versatile",
+            "choices": [{
+                "index": 0,
+                "message": { "role": "assistant", "content": "Hello!" },
+                "finish_reason": "stop"
+            }],
+            "usage": {
+                "prompt_tokens": 10,
+                "completion_tokens": 5,
+                "total_tokens": 15
+            }
+        });
+        if let Some(x_groq) = x_groq {
+            json["x_groq"] = x_groq;
+        }
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_timings_from_x_groq() {
+        let response = response_fixture(Some(serde_json::json!({
+            "id": "req_01",
+            "usage": {
+                "queue_time": 0.25,
+                "prompt_time": 0.5,
+                "completion_time": 1.0,
+                "total_time": 1.5
+            }
+        })));
+
+        let timings = response.timings().unwrap();
+        assert_eq!(timings.queue_time, Some(Duration::from_millis(250)));
+        assert_eq!(timings.prompt_time, Some(Duration::from_millis(500)));
+        assert_eq!(timings.completion_time, Some(Duration::from_secs(1)));
+        assert_eq!(timings.total_time, Some(Duration::from_millis(1500)));
+        assert_eq!(timings.compute_time(), Some(Duration::from_millis(1500)));
+    }
+
+    #[test]
+    fn test_timings_from_usage() {
+        let mut response = response_fixture(Some(serde_json::json!({ "id": "req_01" })));
+        assert!(response.timings().is_none());
+
+        response.usage.queue_time = Some(0.125);
+        response.usage.total_time = Some(0.5);
+
+        let timings = response.timings().unwrap();
+        assert_eq!(timings.queue_time, Some(Duration::from_millis(125)));
+        assert_eq!(timings.prompt_time, None);
+        assert_eq!(timings.compute_time(), None);
+    }
+
+    #[test]
+    fn test_timings_absent() {
+        let response = response_fixture(None);
+        assert!(response.x_groq.is_none());
+        assert!(response.timings().is_none());
+    }
 }
@@ -1,5 +1,7 @@
 //! Common types shared across the Groq API.
 
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
 /// Groq-specific metadata returned in responses.
@@ -14,6 +16,19 @@ pub struct GroqMetadata {
     pub usage: Option<GroqUsage>,
 }
 
+impl GroqMetadata {
+    /// Gets the LPU timing breakdown, if the usage block carries one.
+    pub fn timings(&self) -> Option<Timings> {
+        let usage = self.usage.as_ref()?;
+        Timings::from_secs(
+            usage.queue_time,
+            usage.prompt_time,
+            usage.completion_time,
+            usage.total_time,
+        )
+    }
+}
+
 /// Groq-specific timing information.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct GroqUsage {
@@ -33,3 +48,49 @@ pub struct GroqUsage {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub total_time: Option<f64>,
 }
+
+/// Per-request timing reported by Groq.
+///
+/// Queue time is spent waiting for an LPU; prompt and completion time are
+/// spent computing. Fields the response omitted are `None`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Timings {
+    /// Time spent in queue.
+    pub queue_time: Option<Duration>,
+
+    /// Time spent processing the prompt.
+    pub prompt_time: Option<Duration>,
+
+    /// Time spent generating the completion.
+    pub completion_time: Option<Duration>,
+
+    /// Total time.
+    pub total_time: Option<Duration>,
+}
+
+impl Timings {
+    /// Builds timings from values in seconds, or `None` if all are absent.
+    pub(crate) fn from_secs(
+        queue_time: Option<f64>,
+        prompt_time: Option<f64>,
+        completion_time: Option<f64>,
+        total_time: Option<f64>,
+    ) -> Option<Self> {
+        let to_duration = |secs: Option<f64>| secs.and_then(|s| Duration::try_from_secs_f64(s).ok());
+        let timings = Self {
+            queue_time: to_duration(queue_time),
+            prompt_time: to_duration(prompt_time),
+            completion_time: to_duration(completion_time),
+            total_time: to_duration(total_time),
+        };
+        (timings != Self::default()).then_some(timings)
+    }
+
+    /// Gets the compute time (prompt plus completion).
+    pub fn compute_time(&self) -> Option<Duration> {
+        match (self.prompt_time, self.completion_time) {
+            (None, None) => None,
+            (prompt, completion) => Some(prompt.unwrap_or_default() + completion.unwrap_or_default()),
+        }
+    }
+}
@@ -7,4 +7,5 @@ pub mod audio;
 pub mod chat;
 pub mod common;
 pub mod models;
+pub mod schema;
 pub mod tools;
@@ -30,6 +30,72 @@ pub struct Model {
     pub public_apps: Option<bool>,
 }
 
+impl Model {
+    /// Returns the model's capabilities from the built-in table.
+    ///
+    /// The context window reported by the API takes precedence over the
+    /// table's value.
+    pub fn capabilities(&self) -> Option<ModelCapabilities> {
+        let mut capabilities = ModelCapabilities::lookup(&self.id)?;
+        if let Some(context_window) = self.context_window {
+            capabilities.context_window = context_window;
+        }
+        Some(capabilities)
+    }
+}
+
+/// Features a chat model supports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModelCapabilities {
+    /// Accepts image content parts.
+    pub supports_vision: bool,
+
+    /// Accepts tool definitions.
+    pub supports_tools: bool,
+
+    /// Accepts JSON object and JSON schema response formats.
+    pub supports_json_mode: bool,
+
+    /// Context window size in tokens.
+    pub context_window: u32,
+}
+
+impl ModelCapabilities {
+    const fn new(vision: bool, tools: bool, json_mode: bool, context_window: u32) -> Self {
+        Self {
+            supports_vision: vision,
+            supports_tools: tools,
+            supports_json_mode: json_mode,
+            context_window,
+        }
+    }
+
+    /// Looks up a chat model in the built-in capability table.
+    pub fn lookup(model_id: &str) -> Option<Self> {
+        CAPABILITIES
+            .iter()
+            .find(|(id, _)| *id == model_id)
+            .map(|(_, capabilities)| *capabilities)
+    }
+
+    /// Returns the IDs of all models in the capability table.
+    pub fn known_models() -> Vec<&'static str> {
+        CAPABILITIES.iter().map(|(id, _)| *id).collect()
+    }
+}
+
+/// Capability table for the well-known chat models.
+const CAPABILITIES: &[(&str, ModelCapabilities)] = &[
+    (known::LLAMA_3_3_70B_VERSATILE, ModelCapabilities::new(false, true, true, 131_072)),
+    (known::LLAMA_3_1_70B_VERSATILE, ModelCapabilities::new(false, true, true, 131_072)),
+    (known::LLAMA_3_1_8B_INSTANT, ModelCapabilities::new(false, true, true, 131_072)),
+    (known::LLAMA_GUARD_3_8B, ModelCapabilities::new(false, false, false, 8_192)),
+    (known::MIXTRAL_8X7B, ModelCapabilities::new(false, true, true, 32_768)),
+    (known::GEMMA_2_9B_IT, ModelCapabilities::new(false, true, true, 8_192)),
+    (known::LLAMA_3_2_90B_VISION, ModelCapabilities::new(true, true, true, 8_192)),
+    (known::LLAMA_3_2_11B_VISION, ModelCapabilities::new(true, true, true, 8_192)),
+];
+
 /// Model list response.
 #[derive(Debug, Clone, Deserialize)]
 pub struct ModelList {
@@ -153,6 +219,29 @@ mod tests {
         assert_eq!(list.by_owner("meta").len(), 1);
     }
 
+    #[test]
+    fn test_model_capabilities() {
+        let vision = ModelCapabilities::lookup(known::LLAMA_3_2_11B_VISION).unwrap();
+        assert!(vision.supports_vision);
+
+        let text = ModelCapabilities::lookup(known::LLAMA_3_1_8B_INSTANT).unwrap();
+        assert!(!text.supports_vision);
+        assert!(text.supports_tools);
+
+        assert!(ModelCapabilities::lookup("whisper-large-v3").is_none());
+
+        // The API's context window overrides the table
+        let json = r#"{
+            "id": "gemma2-9b-it",
+            "object": "model",
+            "created": 1699999999,
+            "owned_by": "google",
+            "context_window": 16384
+        }"#;
+        let model: Model = serde_json::from_str(json).unwrap();
+        assert_eq!(model.capabilities().unwrap().context_window, 16_384);
+    }
+
     #[test]
     fn test_known_models() {
         assert_eq!(known::LLAMA_3_3_70B_VERSATILE, "llama-3.3-70b-versatile");
@@ -0,0 +1,249 @@
+//! JSON Schema validation for structured outputs.
+//!
+//! Implements the subset of JSON Schema used by structured output schemas:
+//! `type`, `enum`, `const`, `properties`, `required`, `additionalProperties`,
+//! `items`, `anyOf`, the string/array length bounds and numeric bounds.
+//! Other keywords are ignored.
+
+use serde_json::Value;
+
+use crate::errors::GroqError;
+
+/// Validates `value` against `schema`.
+///
+/// # Errors
+///
+/// Returns [`GroqError::SchemaValidation`] describing the first violation
+/// found, with a JSON Pointer to the offending value.
+pub fn validate(schema: &Value, value: &Value) -> Result<(), GroqError> {
+    validate_at(schema, value, "").map_err(|(path, message)| GroqError::SchemaValidation {
+        message,
+        path: if path.is_empty() { "/".to_string() } else { path },
+    })
+}
+
+/// A violation: JSON Pointer and description.
+type Violation = (String, String);
+
+fn validate_at(schema: &Value, value: &Value, path: &str) -> Result<(), Violation> {
+    let schema = match schema {
+        Value::Bool(true) => return Ok(()),
+        Value::Bool(false) => return Err((path.to_string(), "no value is allowed".to_string())),
+        Value::Object(schema) => schema,
+        _ => return Ok(()),
+    };
+
+    let fail = |message: String| Err((path.to_string(), message));
+
+    if let Some(expected) = schema.get("type") {
+        let matches = match expected {
+            Value::String(name) => has_type(value, name),
+            Value::Array(names) => names
+                .iter()
+                .filter_map(Value::as_str)
+                .any(|name| has_type(value, name)),
+            _ => true,
+        };
+        if !matches {
+            return fail(format!("expected {}, got {}", expected, type_name(value)));
+        }
+    }
+
+    if let Some(Value::Array(allowed)) = schema.get("enum") {
+        if !allowed.contains(value) {
+            return fail(format!("{} is not one of {}", value, Value::Array(allowed.clone())));
+        }
+    }
+
+    if let Some(constant) = schema.get("const") {
+        if constant != value {
+            return fail(format!("expected {}, got {}", constant, value));
+        }
+    }
+
+    if let Some(Value::Array(options)) = schema.get("anyOf") {
+        if !options.iter().any(|option| validate_at(option, value, path).is_ok()) {
+            return fail("does not match any schema in anyOf".to_string());
+        }
+    }
+
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::Array(required)) = schema.get("required") {
+                for name in required.iter().filter_map(Value::as_str) {
+                    if !object.contains_key(name) {
+                        return fail(format!("missing required property \"{}\"", name));
+                    }
+                }
+            }
+
+            let properties = schema.get("properties").and_then(Value::as_object);
+            for (name, property) in object {
+                let property_path = format!("{}/{}", path, escape_pointer(name));
+                match properties.and_then(|p| p.get(name)) {
+                    Some(property_schema) => validate_at(property_schema, property, &property_path)?,
+                    None => match schema.get("additionalProperties") {
+                        Some(Value::Bool(false)) => {
+                            return fail(format!("unexpected property \"{}\"", name));
+                        }
+                        Some(additional) => validate_at(additional, property, &property_path)?,
+                        None => {}
+                    },
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(message) = check_bound(schema, "minItems", items.len(), |n, min| n >= min)
+                .or_else(|| check_bound(schema, "maxItems", items.len(), |n, max| n <= max))
+            {
+                return fail(message);
+            }
+
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    validate_at(item_schema, item, &format!("{}/{}", path, index))?;
+                }
+            }
+        }
+        Value::String(text) => {
+            let len = text.chars().count();
+            if let Some(message) = check_bound(schema, "minLength", len, |n, min| n >= min)
+                .or_else(|| check_bound(schema, "maxLength", len, |n, max| n <= max))
+            {
+                return fail(message);
+            }
+        }
+        Value::Number(number) => {
+            if let Some(n) = number.as_f64() {
+                if let Some(min) = schema.get("minimum").and_then(Value::as_f64) {
+                    if n < min {
+                        return fail(format!("{} is less than the minimum of {}", n, min));
+                    }
+                }
+                if let Some(max) = schema.get("maximum").and_then(Value::as_f64) {
+                    if n > max {
+                        return fail(format!("{} is greater than the maximum of {}", n, max));
+                    }
+                }
+            }
+        }
+        Value::Bool(_) | Value::Null => {}
+    }
+
+    Ok(())
+}
+
+/// Checks a length keyword, returning a message if it is violated.
+fn check_bound(
+    schema: &serde_json::Map<String, Value>,
+    keyword: &str,
+    len: usize,
+    ok: impl Fn(u64, u64) -> bool,
+) -> Option<String> {
+    let bound = schema.get(keyword).and_then(Value::as_u64)?;
+    if ok(len as u64, bound) {
+        None
+    } else {
+        Some(format!("length {} violates {} of {}", len, keyword, bound))
+    }
+}
+
+fn has_type(value: &Value, name: &str) -> bool {
+    match name {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Object(_) => "object",
+        Value::Array(_) => "array",
+        Value::String(_) => "string",
+        Value::Number(_) => "number",
+        Value::Bool(_) => "boolean",
+        Value::Null => "null",
+    }
+}
+
+/// Escapes a property name for use in a JSON Pointer.
+fn escape_pointer(name: &str) -> String {
+    name.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn person_schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string", "minLength": 1 },
+                "age": { "type": "integer", "minimum": 0 },
+                "tags": { "type": "array", "items": { "type": "string" } },
+                "role": { "enum": ["admin", "user"] }
+            },
+            "required": ["name", "age"],
+            "additionalProperties": false
+        })
+    }
+
+    fn violation(value: Value) -> (String, String) {
+        match validate(&person_schema(), &value) {
+            Err(GroqError::SchemaValidation { path, message }) => (path, message),
+            other => panic!("Expected SchemaValidation, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_valid_value() {
+        let value = json!({ "name": "Ada", "age": 36, "tags": ["math"], "role": "admin" });
+        assert!(validate(&person_schema(), &value).is_ok());
+    }
+
+    #[test]
+    fn test_reports_first_violation_with_path() {
+        let (path, message) = violation(json!({ "name": "Ada" }));
+        assert_eq!(path, "/");
+        assert!(message.contains("\"age\""));
+
+        let (path, _) = violation(json!({ "name": "Ada", "age": 1.5 }));
+        assert_eq!(path, "/age");
+
+        let (path, _) = violation(json!({ "name": "Ada", "age": 3, "tags": ["a", 2] }));
+        assert_eq!(path, "/tags/1");
+
+        let (path, _) = violation(json!({ "name": "Ada", "age": 3, "role": "root" }));
+        assert_eq!(path, "/role");
+
+        let (path, message) = violation(json!({ "name": "Ada", "age": 3, "extra": true }));
+        assert_eq!(path, "/");
+        assert!(message.contains("\"extra\""));
+
+        let (path, _) = violation(json!({ "name": "", "age": 3 }));
+        assert_eq!(path, "/name");
+
+        let (path, _) = violation(json!({ "name": "Ada", "age": -1 }));
+        assert_eq!(path, "/age");
+    }
+
+    #[test]
+    fn test_nullable_type_and_any_of() {
+        let schema = json!({ "type": ["string", "null"] });
+        assert!(validate(&schema, &Value::Null).is_ok());
+        assert!(validate(&schema, &json!(1)).is_err());
+
+        let schema = json!({ "anyOf": [{ "type": "integer" }, { "const": "none" }] });
+        assert!(validate(&schema, &json!(4)).is_ok());
+        assert!(validate(&schema, &json!("none")).is_ok());
+        assert!(validate(&schema, &json!("some")).is_err());
+    }
+}
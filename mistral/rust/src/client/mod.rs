@@ -13,8 +13,11 @@ use crate::resilience::{
 use crate::services::{
     AgentsService, BatchService, ChatService, DefaultAgentsService, DefaultBatchService,
     DefaultChatService, DefaultEmbeddingsService, DefaultFilesService, DefaultFineTuningService,
-    DefaultModelsService, EmbeddingsService, FilesService, FineTuningService, ModelsService,
+    DefaultModelsService, EmbeddingsService, FilesService, FimService, FineTuningService,
+    ModelsService,
 };
+use crate::services::fim::DefaultFimService;
+use crate::services::ocr::{DefaultOcrService, OcrService};
 use crate::transport::{ReqwestTransport, TransportConfig};
 
 /// The main Mistral client.
@@ -86,11 +89,21 @@ impl MistralClient {
         DefaultEmbeddingsService::new(self.transport.as_ref())
     }
 
+    /// Returns the FIM (fill-in-the-middle) service.
+    pub fn fim(&self) -> impl FimService + '_ {
+        DefaultFimService::new(self.transport.as_ref())
+    }
+
     /// Returns the models service.
     pub fn models(&self) -> impl ModelsService + '_ {
         DefaultModelsService::new(self.transport.as_ref())
     }
 
+    /// Returns the OCR (document understanding) service.
+    pub fn ocr(&self) -> impl OcrService + '_ {
+        DefaultOcrService::new(self.transport.as_ref())
+    }
+
     /// Returns the files service.
     pub fn files(&self) -> impl FilesService + '_ {
         DefaultFilesService::new(self.transport.as_ref())
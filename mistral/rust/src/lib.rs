@@ -60,8 +60,12 @@ pub use types::chat::{
 };
 pub use types::common::{Usage, FinishReason, Role};
 pub use types::embeddings::{EmbeddingRequest, EmbeddingResponse};
+pub use types::fim::{FimRequest, FimResponse};
 pub use types::models::{Model, ModelListResponse};
-pub use types::tools::{Tool, ToolCall, ToolChoice, FunctionDefinition};
+pub use types::ocr::{OcrRequest, OcrResponse};
+pub use types::tools::{Tool, ToolCall, ToolCallDelta, ToolChoice, FunctionDefinition};
+pub use types::batch::{BatchInputRequest, BatchJob, BatchResults, BatchStatus};
+pub use services::BatchService;
 
 /// Mock implementations for testing.
 #[cfg(any(test, feature = "mocks"))]
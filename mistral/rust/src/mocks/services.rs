@@ -12,7 +12,9 @@ use crate::services::{
     ListBatchJobsParams, ListFineTuningJobsParams, ModelsService,
 };
 use crate::types::agents::{AgentCompletionChunk, AgentCompletionRequest, AgentCompletionResponse};
-use crate::types::batch::{BatchJob, BatchListResponse, CreateBatchRequest};
+use crate::types::batch::{
+    BatchInputRequest, BatchJob, BatchListResponse, BatchResults, CreateBatchRequest,
+};
 use crate::types::chat::{ChatCompletionChunk, ChatCompletionRequest, ChatCompletionResponse};
 use crate::types::embeddings::{EmbeddingRequest, EmbeddingResponse};
 use crate::types::files::{
@@ -410,6 +412,8 @@ impl AgentsService for MockAgentsService {
 /// Mock batch service.
 pub struct MockBatchService {
     batches: Arc<Mutex<HashMap<String, BatchJob>>>,
+    created: Arc<Mutex<Vec<BatchJob>>>,
+    results: Arc<Mutex<HashMap<String, BatchResults>>>,
 }
 
 impl Default for MockBatchService {
@@ -423,6 +427,8 @@ impl MockBatchService {
     pub fn new() -> Self {
         Self {
             batches: Arc::new(Mutex::new(HashMap::new())),
+            created: Arc::new(Mutex::new(Vec::new())),
+            results: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -430,6 +436,16 @@ impl MockBatchService {
     pub fn add_batch(&self, batch: BatchJob) {
         self.batches.lock().unwrap().insert(batch.id.clone(), batch);
     }
+
+    /// Sets the batch returned by `create_batch`.
+    pub fn set_created_batch(&self, batch: BatchJob) {
+        self.created.lock().unwrap().push(batch);
+    }
+
+    /// Sets the results returned by `download_results` for a batch.
+    pub fn set_results(&self, batch_id: impl Into<String>, results: BatchResults) {
+        self.results.lock().unwrap().insert(batch_id.into(), results);
+    }
 }
 
 #[async_trait]
@@ -464,6 +480,38 @@ impl BatchService for MockBatchService {
     async fn cancel(&self, batch_id: &str) -> MistralResult<BatchJob> {
         self.retrieve(batch_id).await
     }
+
+    async fn create_batch(
+        &self,
+        _requests: Vec<BatchInputRequest>,
+        _endpoint: &str,
+        _model: &str,
+    ) -> MistralResult<BatchJob> {
+        let batch = self
+            .created
+            .lock()
+            .unwrap()
+            .pop()
+            .ok_or_else(|| MistralError::Internal {
+                message: "No mock batch configured".to_string(),
+                request_id: None,
+            })?;
+
+        self.add_batch(batch.clone());
+        Ok(batch)
+    }
+
+    async fn download_results(&self, batch_id: &str) -> MistralResult<BatchResults> {
+        self.results
+            .lock()
+            .unwrap()
+            .get(batch_id)
+            .cloned()
+            .ok_or_else(|| MistralError::NotFound {
+                message: format!("Results for batch {} not found", batch_id),
+                resource: Some(batch_id.to_string()),
+            })
+    }
 }
 
 #[cfg(test)]
@@ -1,9 +1,13 @@
 //! Batch processing service.
 
 use async_trait::async_trait;
+use std::time::{Duration, Instant};
 
 use crate::errors::MistralError;
-use crate::types::batch::{BatchJob, BatchListResponse, CreateBatchRequest};
+use crate::types::batch::{
+    encode_jsonl, BatchInputRequest, BatchJob, BatchListResponse, BatchResults, CreateBatchRequest,
+};
+use crate::types::files::FileObject;
 
 /// Query parameters for listing batch jobs.
 #[derive(Debug, Default)]
@@ -122,6 +126,53 @@ pub trait BatchService: Send + Sync {
 
     /// Cancels a batch job.
     async fn cancel(&self, batch_id: &str) -> Result<BatchJob, MistralError>;
+
+    /// Uploads `requests` as a JSONL input file and starts a batch job for them.
+    async fn create_batch(
+        &self,
+        requests: Vec<BatchInputRequest>,
+        endpoint: &str,
+        model: &str,
+    ) -> Result<BatchJob, MistralError>;
+
+    /// Retrieves a batch job for status polling.
+    async fn get_batch(&self, batch_id: &str) -> Result<BatchJob, MistralError> {
+        self.retrieve(batch_id).await
+    }
+
+    /// Downloads and parses the output and error files of a batch job.
+    ///
+    /// Failed requests are returned against their `custom_id` rather than
+    /// failing the call.
+    async fn download_results(&self, batch_id: &str) -> Result<BatchResults, MistralError>;
+
+    /// Creates a batch, polls it until it finishes and downloads its results.
+    ///
+    /// Returns [`MistralError::Timeout`] if the job has not finished within
+    /// `timeout`; the job keeps running server-side.
+    async fn run_batch_to_completion(
+        &self,
+        requests: Vec<BatchInputRequest>,
+        endpoint: &str,
+        model: &str,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<BatchResults, MistralError> {
+        let started = Instant::now();
+        let mut job = self.create_batch(requests, endpoint, model).await?;
+
+        while !job.status.is_terminal() {
+            if started.elapsed() + poll_interval > timeout {
+                return Err(MistralError::Timeout {
+                    message: format!("batch {} did not finish within {:?}", job.id, timeout),
+                });
+            }
+            tokio::time::sleep(poll_interval).await;
+            job = self.get_batch(&job.id).await?;
+        }
+
+        self.download_results(&job.id).await
+    }
 }
 
 /// Default implementation of the batch service.
@@ -198,6 +249,41 @@ where
                 body: String::from_utf8_lossy(&response).to_string(),
             })
     }
+
+    async fn create_batch(
+        &self,
+        requests: Vec<BatchInputRequest>,
+        endpoint: &str,
+        model: &str,
+    ) -> Result<BatchJob, MistralError> {
+        let input = encode_jsonl(&requests)?;
+        let response = self.transport
+            .post_multipart("/v1/files", input, "batch_input.jsonl", "batch")
+            .await?;
+
+        let file: FileObject = serde_json::from_slice(&response)
+            .map_err(|e| MistralError::Deserialization {
+                message: e.to_string(),
+                body: String::from_utf8_lossy(&response).to_string(),
+            })?;
+
+        self.create(CreateBatchRequest::new(vec![file.id], endpoint, model)).await
+    }
+
+    async fn download_results(&self, batch_id: &str) -> Result<BatchResults, MistralError> {
+        let job = self.retrieve(batch_id).await?;
+        let mut results = BatchResults::default();
+
+        for file_id in job.output_file.iter().chain(job.error_file.iter()) {
+            let path = format!("/v1/files/{}/content", file_id);
+            let content = self.transport
+                .get(&path)
+                .await?;
+            results.extend_from_jsonl(&content)?;
+        }
+
+        Ok(results)
+    }
 }
 
 #[cfg(test)]
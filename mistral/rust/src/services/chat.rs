@@ -1,11 +1,14 @@
 //! Chat completion service.
 
 use async_trait::async_trait;
-use futures::Stream;
+use futures::{Stream, StreamExt};
+use std::collections::BTreeMap;
 use std::pin::Pin;
 
-use crate::errors::MistralError;
+use crate::errors::{MistralError, MistralResult};
 use crate::types::chat::{ChatCompletionChunk, ChatCompletionRequest, ChatCompletionResponse};
+use crate::types::common::{FinishReason, Usage};
+use crate::types::tools::ToolCall;
 
 /// Chat service trait for chat completions.
 #[async_trait]
@@ -69,6 +72,183 @@ where
     }
 }
 
+/// An event produced by [`accumulate_stream`].
+#[derive(Debug, Clone)]
+pub enum ChatStreamEvent {
+    /// A fragment of assistant text.
+    Content(String),
+    /// Complete tool calls, emitted when the model finishes with `tool_calls`.
+    ToolCalls(Vec<ToolCall>),
+    /// The stream has ended.
+    Done {
+        /// The full assistant text.
+        content: String,
+        /// Final token usage, if the server reported it.
+        usage: Option<Usage>,
+        /// Why the model stopped.
+        finish_reason: Option<FinishReason>,
+    },
+}
+
+/// Reassembles a streamed chat completion from its chunks.
+///
+/// Tool call fragments are matched by `index`, so parallel tool calls are
+/// rebuilt independently even when their fragments interleave. Only the
+/// first choice is accumulated.
+#[derive(Debug, Default)]
+pub struct ChatStreamAccumulator {
+    content: String,
+    tool_calls: BTreeMap<u32, PartialToolCall>,
+    usage: Option<Usage>,
+    finish_reason: Option<FinishReason>,
+}
+
+/// A tool call whose fragments are still arriving.
+#[derive(Debug, Default)]
+struct PartialToolCall {
+    id: String,
+    tool_type: Option<String>,
+    name: String,
+    arguments: String,
+}
+
+impl ChatStreamAccumulator {
+    /// Creates an empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a chunk, returning the complete tool calls if it finished with `tool_calls`.
+    pub fn push(&mut self, chunk: &ChatCompletionChunk) -> MistralResult<Option<Vec<ToolCall>>> {
+        if let Some(usage) = &chunk.usage {
+            self.usage = Some(usage.clone());
+        }
+
+        let mut completed = None;
+        for choice in chunk.choices.iter().filter(|c| c.index == 0) {
+            if let Some(content) = &choice.delta.content {
+                self.content.push_str(content);
+            }
+
+            for (position, delta) in choice.delta.tool_calls.iter().flatten().enumerate() {
+                // Mistral sends whole tool calls without an index; fall back to their position
+                let index = delta.index.or_else(|| u32::try_from(position).ok()).unwrap_or_default();
+                let partial = self.tool_calls.entry(index).or_default();
+                if let Some(id) = &delta.id {
+                    partial.id.clone_from(id);
+                }
+                if let Some(tool_type) = &delta.tool_type {
+                    partial.tool_type = Some(tool_type.clone());
+                }
+                if let Some(function) = &delta.function {
+                    if let Some(name) = &function.name {
+                        partial.name.push_str(name);
+                    }
+                    if let Some(arguments) = &function.arguments {
+                        partial.arguments.push_str(arguments);
+                    }
+                }
+            }
+
+            if let Some(reason) = choice.finish_reason {
+                self.finish_reason = Some(reason);
+                if reason == FinishReason::ToolCalls {
+                    completed = Some(self.tool_calls()?);
+                }
+            }
+        }
+
+        Ok(completed)
+    }
+
+    /// Returns the tool calls received so far, in index order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MistralError::Stream`] if a tool call has no ID or name, or
+    /// its arguments are not valid JSON.
+    pub fn tool_calls(&self) -> MistralResult<Vec<ToolCall>> {
+        self.tool_calls
+            .iter()
+            .map(|(index, partial)| {
+                if partial.id.is_empty() || partial.name.is_empty() {
+                    return Err(MistralError::Stream {
+                        message: format!("tool call {} is missing its ID or function name", index),
+                    });
+                }
+                let arguments = if partial.arguments.is_empty() {
+                    "{}".to_string()
+                } else {
+                    partial.arguments.clone()
+                };
+                if let Err(e) = serde_json::from_str::<serde_json::Value>(&arguments) {
+                    return Err(MistralError::Stream {
+                        message: format!("tool call {} has invalid arguments: {}", partial.id, e),
+                    });
+                }
+
+                let mut call = ToolCall::new(partial.id.clone(), partial.name.clone(), arguments);
+                if let Some(tool_type) = &partial.tool_type {
+                    call.tool_type.clone_from(tool_type);
+                }
+                Ok(call)
+            })
+            .collect()
+    }
+
+    /// Returns the assistant text received so far.
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// Returns the usage reported by the server, if any.
+    pub fn usage(&self) -> Option<&Usage> {
+        self.usage.as_ref()
+    }
+
+    /// Returns the finish reason, once the model has stopped.
+    pub fn finish_reason(&self) -> Option<FinishReason> {
+        self.finish_reason
+    }
+}
+
+/// Turns a chunk stream into text fragments, complete tool calls and a final summary.
+///
+/// Pass the stream returned by [`ChatService::create_stream`].
+pub fn accumulate_stream<S>(stream: S) -> impl Stream<Item = MistralResult<ChatStreamEvent>> + Send
+where
+    S: Stream<Item = MistralResult<ChatCompletionChunk>> + Send + Unpin,
+{
+    async_stream::try_stream! {
+        let mut stream = stream;
+        let mut accumulator = ChatStreamAccumulator::new();
+
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            let text = chunk
+                .choices
+                .iter()
+                .find(|c| c.index == 0)
+                .and_then(|c| c.delta.content.clone())
+                .filter(|text| !text.is_empty());
+
+            let tool_calls = accumulator.push(&chunk)?;
+            if let Some(text) = text {
+                yield ChatStreamEvent::Content(text);
+            }
+            if let Some(tool_calls) = tool_calls {
+                yield ChatStreamEvent::ToolCalls(tool_calls);
+            }
+        }
+
+        yield ChatStreamEvent::Done {
+            content: accumulator.content,
+            usage: accumulator.usage,
+            finish_reason: accumulator.finish_reason,
+        };
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -91,4 +271,78 @@ mod tests {
         assert!(json.contains("mistral-large-latest"));
         assert!(json.contains("Hello!"));
     }
+
+    /// Two parallel tool calls whose argument fragments interleave.
+    const TOOL_CALL_TRANSCRIPT: &str = r#"data: {"id":"c1","object":"chat.completion.chunk","model":"mistral-large-latest","created":1700000000,"choices":[{"index":0,"delta":{"role":"assistant","content":"Checking both cities."}}]}
+
+data: {"id":"c1","object":"chat.completion.chunk","model":"mistral-large-latest","created":1700000000,"choices":[{"index":0,"delta":{"tool_calls":[{"index":0,"id":"call_paris","type":"function","function":{"name":"get_weather","arguments":""}}]}}]}
+
+data: {"id":"c1","object":"chat.completion.chunk","model":"mistral-large-latest","created":1700000000,"choices":[{"index":0,"delta":{"tool_calls":[{"index":1,"id":"call_tokyo","type":"function","function":{"name":"get_weather","arguments":"{\"ci"}}]}}]}
+
+data: {"id":"c1","object":"chat.completion.chunk","model":"mistral-large-latest","created":1700000000,"choices":[{"index":0,"delta":{"tool_calls":[{"index":0,"function":{"arguments":"{\"city\": \"Pa"}}]}}]}
+
+data: {"id":"c1","object":"chat.completion.chunk","model":"mistral-large-latest","created":1700000000,"choices":[{"index":0,"delta":{"tool_calls":[{"index":1,"function":{"arguments":"ty\": \"Tokyo\"}"}},{"index":0,"function":{"arguments":"ris\"}"}}]}}]}
+
+data: {"id":"c1","object":"chat.completion.chunk","model":"mistral-large-latest","created":1700000000,"choices":[{"index":0,"delta":{"content":""},"finish_reason":"tool_calls"}],"usage":{"prompt_tokens":84,"completion_tokens":41,"total_tokens":125}}
+
+data: [DONE]
+"#;
+
+    fn transcript_chunks(transcript: &str) -> Vec<MistralResult<ChatCompletionChunk>> {
+        transcript
+            .lines()
+            .filter_map(|line| line.strip_prefix("data: "))
+            .filter(|data| *data != "[DONE]")
+            .map(|data| Ok(serde_json::from_str(data).unwrap()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_accumulate_stream_reassembles_parallel_tool_calls() {
+        let events: Vec<ChatStreamEvent> =
+            accumulate_stream(futures::stream::iter(transcript_chunks(TOOL_CALL_TRANSCRIPT)))
+                .map(Result::unwrap)
+                .collect()
+                .await;
+
+        assert!(matches!(&events[0], ChatStreamEvent::Content(text) if text == "Checking both cities."));
+
+        let ChatStreamEvent::ToolCalls(calls) = &events[1] else {
+            panic!("Expected tool calls, got {:?}", events[1]);
+        };
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].id, "call_paris");
+        assert_eq!(calls[1].id, "call_tokyo");
+        for call in calls {
+            assert_eq!(call.tool_type, "function");
+            assert_eq!(call.function.name, "get_weather");
+        }
+        let paris: serde_json::Value = calls[0].function.parse_arguments().unwrap();
+        let tokyo: serde_json::Value = calls[1].function.parse_arguments().unwrap();
+        assert_eq!(paris, serde_json::json!({ "city": "Paris" }));
+        assert_eq!(tokyo, serde_json::json!({ "city": "Tokyo" }));
+
+        let ChatStreamEvent::Done { content, usage, finish_reason } = &events[2] else {
+            panic!("Expected done, got {:?}", events[2]);
+        };
+        assert_eq!(content, "Checking both cities.");
+        assert_eq!(usage.as_ref().map(|u| u.total_tokens), Some(125));
+        assert_eq!(*finish_reason, Some(FinishReason::ToolCalls));
+        assert_eq!(events.len(), 3);
+    }
+
+    #[test]
+    fn test_accumulator_rejects_truncated_arguments() {
+        let mut chunks = transcript_chunks(TOOL_CALL_TRANSCRIPT);
+        // Drop the chunk carrying the tail of both argument strings
+        chunks.remove(4).unwrap();
+
+        let mut accumulator = ChatStreamAccumulator::new();
+        let results: Vec<_> = chunks
+            .iter()
+            .map(|chunk| accumulator.push(chunk.as_ref().unwrap()))
+            .collect();
+
+        assert!(matches!(results.last(), Some(Err(MistralError::Stream { .. }))));
+    }
 }
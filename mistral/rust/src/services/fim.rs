@@ -0,0 +1,134 @@
+//! Fill-in-the-middle (FIM) completion service.
+
+use async_trait::async_trait;
+use futures::Stream;
+use std::pin::Pin;
+
+use crate::errors::MistralError;
+use crate::types::chat::ChatCompletionChunk;
+use crate::types::fim::{FimRequest, FimResponse};
+
+/// FIM service trait for code infilling.
+#[async_trait]
+pub trait FimService: Send + Sync {
+    /// Creates a FIM completion.
+    async fn create(&self, request: FimRequest) -> Result<FimResponse, MistralError>;
+
+    /// Creates a streaming FIM completion.
+    async fn create_stream(
+        &self,
+        request: FimRequest,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<ChatCompletionChunk, MistralError>> + Send>>, MistralError>;
+}
+
+/// Default implementation of the FIM service.
+pub struct DefaultFimService<T> {
+    transport: T,
+}
+
+impl<T> DefaultFimService<T> {
+    /// Creates a new FIM service.
+    pub fn new(transport: T) -> Self {
+        Self { transport }
+    }
+}
+
+#[async_trait]
+impl<T> FimService for DefaultFimService<T>
+where
+    T: crate::transport::HttpTransport + Send + Sync,
+{
+    async fn create(&self, request: FimRequest) -> Result<FimResponse, MistralError> {
+        request.validate()?;
+
+        let body = serde_json::to_vec(&request)
+            .map_err(|e| MistralError::Serialization { message: e.to_string() })?;
+
+        let response = self.transport
+            .post("/v1/fim/completions", body)
+            .await?;
+
+        serde_json::from_slice(&response)
+            .map_err(|e| MistralError::Deserialization {
+                message: e.to_string(),
+                body: String::from_utf8_lossy(&response).to_string(),
+            })
+    }
+
+    async fn create_stream(
+        &self,
+        mut request: FimRequest,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<ChatCompletionChunk, MistralError>> + Send>>, MistralError> {
+        request.validate()?;
+        request.stream = Some(true);
+
+        let body = serde_json::to_vec(&request)
+            .map_err(|e| MistralError::Serialization { message: e.to_string() })?;
+
+        let stream = self.transport
+            .post_stream("/v1/fim/completions", body)
+            .await?;
+
+        Ok(stream)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocks::transport::MockResponse;
+    use crate::mocks::MockTransport;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_fim_completion() {
+        let transport = MockTransport::new();
+        transport.enqueue_response(MockResponse::json(json!({
+            "id": "fim-1",
+            "object": "chat.completion",
+            "model": "codestral-latest",
+            "created": 1700000000,
+            "choices": [{
+                "index": 0,
+                "message": { "role": "assistant", "content": "return a + b" },
+                "finish_reason": "stop"
+            }],
+            "usage": { "prompt_tokens": 12, "completion_tokens": 5, "total_tokens": 17 }
+        })));
+        let service = DefaultFimService::new(transport);
+
+        let response = service
+            .create(FimRequest::new("codestral-latest", "def add(a, b):\n    ").with_suffix("\n"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.text(), Some("return a + b"));
+        assert_eq!(response.usage.total_tokens, 17);
+
+        let request = service.transport.last_request().unwrap();
+        assert_eq!(request.url, "/v1/fim/completions");
+        let body: serde_json::Value = serde_json::from_slice(&request.body.unwrap()).unwrap();
+        assert_eq!(body["prompt"], "def add(a, b):\n    ");
+        assert_eq!(body["suffix"], "\n");
+    }
+
+    #[tokio::test]
+    async fn test_fim_rejects_unsupported_model() {
+        let transport = MockTransport::new();
+        let service = DefaultFimService::new(transport);
+
+        let err = service
+            .create(FimRequest::new("mistral-large-latest", "fn main() {"))
+            .await
+            .unwrap_err();
+
+        match err {
+            MistralError::Validation { message, errors } => {
+                assert!(message.contains("mistral-large-latest"));
+                assert_eq!(errors[0].field, "model");
+            }
+            other => panic!("Expected Validation, got {:?}", other),
+        }
+        assert!(service.transport.get_requests().is_empty());
+    }
+}
@@ -2,7 +2,9 @@
 
 pub mod chat;
 pub mod embeddings;
+pub mod fim;
 pub mod models;
+pub mod ocr;
 pub mod files;
 pub mod fine_tuning;
 pub mod agents;
@@ -11,7 +13,9 @@ pub mod batch;
 // Re-export service traits
 pub use chat::ChatService;
 pub use embeddings::EmbeddingsService;
+pub use fim::FimService;
 pub use models::ModelsService;
+pub use ocr::OcrService;
 pub use files::FilesService;
 pub use fine_tuning::FineTuningService;
 pub use agents::AgentsService;
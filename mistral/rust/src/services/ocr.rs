@@ -0,0 +1,130 @@
+//! OCR (document understanding) service.
+
+use async_trait::async_trait;
+
+use crate::errors::MistralError;
+use crate::types::ocr::{OcrRequest, OcrResponse};
+
+/// OCR service trait for extracting text from documents.
+#[async_trait]
+pub trait OcrService: Send + Sync {
+    /// Processes a document and returns its pages as markdown.
+    async fn process(&self, request: OcrRequest) -> Result<OcrResponse, MistralError>;
+}
+
+/// Default implementation of the OCR service.
+pub struct DefaultOcrService<T> {
+    transport: T,
+}
+
+impl<T> DefaultOcrService<T> {
+    /// Creates a new OCR service.
+    pub fn new(transport: T) -> Self {
+        Self { transport }
+    }
+}
+
+#[async_trait]
+impl<T> OcrService for DefaultOcrService<T>
+where
+    T: crate::transport::HttpTransport + Send + Sync,
+{
+    async fn process(&self, request: OcrRequest) -> Result<OcrResponse, MistralError> {
+        request.validate()?;
+
+        let body = serde_json::to_vec(&request)
+            .map_err(|e| MistralError::Serialization { message: e.to_string() })?;
+
+        let response = self.transport
+            .post("/v1/ocr", body)
+            .await?;
+
+        serde_json::from_slice(&response)
+            .map_err(|e| MistralError::Deserialization {
+                message: e.to_string(),
+                body: String::from_utf8_lossy(&response).to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocks::transport::MockResponse;
+    use crate::mocks::MockTransport;
+    use serde_json::json;
+
+    fn multi_page_fixture() -> serde_json::Value {
+        json!({
+            "model": "mistral-ocr-2505",
+            "pages": [
+                {
+                    "index": 0,
+                    "markdown": "# Quarterly Report\n\n![img-0.jpeg](img-0.jpeg)",
+                    "images": [{
+                        "id": "img-0.jpeg",
+                        "top_left_x": 120,
+                        "top_left_y": 340,
+                        "bottom_right_x": 980,
+                        "bottom_right_y": 1020,
+                        "image_base64": null
+                    }],
+                    "dimensions": { "dpi": 200, "height": 2200, "width": 1700 }
+                },
+                {
+                    "index": 1,
+                    "markdown": "| Quarter | Revenue |\n|---|---|\n| Q1 | 1.2M |",
+                    "images": [],
+                    "dimensions": { "dpi": 200, "height": 2200, "width": 1700 }
+                }
+            ],
+            "usage_info": { "pages_processed": 2, "doc_size_bytes": 48213 }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_ocr_multi_page_document() {
+        let transport = MockTransport::new();
+        transport.enqueue_response(MockResponse::json(multi_page_fixture()));
+        let service = DefaultOcrService::new(transport);
+
+        let response = service
+            .process(OcrRequest::from_url("mistral-ocr-latest", "https://example.com/report.pdf"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.pages.len(), 2);
+        assert_eq!(response.pages[1].index, 1);
+        assert!(response.pages[1].markdown.contains("| Q1 | 1.2M |"));
+
+        let image = &response.pages[0].images[0];
+        assert_eq!(image.id, "img-0.jpeg");
+        assert_eq!((image.top_left_x, image.top_left_y), (120, 340));
+        assert_eq!((image.bottom_right_x, image.bottom_right_y), (980, 1020));
+        assert!(image.image_base64.is_none());
+        assert_eq!(response.pages[0].dimensions.as_ref().unwrap().dpi, 200);
+        assert_eq!(response.usage_info.unwrap().pages_processed, 2);
+        assert!(response.markdown().starts_with("# Quarterly Report"));
+
+        let request = service.transport.last_request().unwrap();
+        assert_eq!(request.url, "/v1/ocr");
+        let body: serde_json::Value = serde_json::from_slice(&request.body.unwrap()).unwrap();
+        assert_eq!(body["document"]["document_url"], "https://example.com/report.pdf");
+    }
+
+    #[tokio::test]
+    async fn test_ocr_rejects_both_document_sources() {
+        let transport = MockTransport::new();
+        let service = DefaultOcrService::new(transport);
+
+        let mut request = OcrRequest::from_pdf_bytes("mistral-ocr-latest", b"%PDF-1.7");
+        request.document_url = Some("https://example.com/report.pdf".to_string());
+        let err = service.process(request).await.unwrap_err();
+
+        match err {
+            MistralError::Validation { errors, .. } => assert_eq!(errors[0].field, "document"),
+            other => panic!("Expected Validation, got {:?}", other),
+        }
+        assert!(service.transport.get_requests().is_empty());
+    }
+}
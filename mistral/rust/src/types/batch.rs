@@ -1,8 +1,11 @@
 //! Batch processing types for Mistral API.
 
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::errors::{MistralError, MistralResult};
+
 /// Batch job status.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -23,6 +26,16 @@ pub enum BatchStatus {
     Cancelling,
 }
 
+impl BatchStatus {
+    /// Returns true if the batch will make no further progress.
+    pub fn is_terminal(self) -> bool {
+        matches!(
+            self,
+            BatchStatus::Success | BatchStatus::Failed | BatchStatus::TimedOut | BatchStatus::Cancelled
+        )
+    }
+}
+
 /// Batch job creation request.
 #[derive(Debug, Clone, Serialize)]
 pub struct CreateBatchRequest {
@@ -200,6 +213,30 @@ impl BatchInputRequest {
     }
 }
 
+/// Encodes batch requests as JSONL, one request per line.
+pub fn encode_jsonl(requests: &[BatchInputRequest]) -> MistralResult<Vec<u8>> {
+    let mut out = Vec::new();
+    for request in requests {
+        serde_json::to_writer(&mut out, request)
+            .map_err(|e| MistralError::Serialization { message: e.to_string() })?;
+        out.push(b'\n');
+    }
+    Ok(out)
+}
+
+/// Decodes a JSONL file, skipping blank lines.
+pub fn decode_jsonl<T: DeserializeOwned>(data: &[u8]) -> MistralResult<Vec<T>> {
+    String::from_utf8_lossy(data)
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line).map_err(|e| MistralError::Serialization {
+                message: format!("invalid batch line: {}", e),
+            })
+        })
+        .collect()
+}
+
 /// A single response from a batch output file.
 #[derive(Debug, Clone, Deserialize)]
 pub struct BatchOutputResponse {
@@ -223,7 +260,7 @@ pub struct BatchResponseData {
 }
 
 /// Error from a batch request.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 pub struct BatchError {
     /// Error code.
     pub code: String,
@@ -231,6 +268,102 @@ pub struct BatchError {
     pub message: String,
 }
 
+impl BatchOutputResponse {
+    /// Converts the line into a result, treating non-2xx responses as errors.
+    pub fn into_result(self) -> Result<BatchResponseData, BatchError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        match self.response {
+            Some(response) if (200..300).contains(&response.status_code) => Ok(response),
+            Some(response) => Err(BatchError {
+                code: response.status_code.to_string(),
+                message: response
+                    .body
+                    .get("message")
+                    .and_then(serde_json::Value::as_str)
+                    .map_or_else(|| response.body.to_string(), ToString::to_string),
+            }),
+            None => Err(BatchError {
+                code: "missing_response".to_string(),
+                message: "batch output line has neither a response nor an error".to_string(),
+            }),
+        }
+    }
+}
+
+/// Results of a batch, keyed by `custom_id`.
+///
+/// A failed request is recorded against its own `custom_id`; it does not
+/// fail the batch as a whole.
+#[derive(Debug, Clone, Default)]
+pub struct BatchResults {
+    results: HashMap<String, Result<BatchResponseData, BatchError>>,
+}
+
+impl BatchResults {
+    /// Parses a batch output or error file.
+    pub fn from_jsonl(data: &[u8]) -> MistralResult<Self> {
+        let mut results = Self::default();
+        results.extend_from_jsonl(data)?;
+        Ok(results)
+    }
+
+    /// Adds the lines of another output or error file.
+    pub fn extend_from_jsonl(&mut self, data: &[u8]) -> MistralResult<()> {
+        for line in decode_jsonl::<BatchOutputResponse>(data)? {
+            let custom_id = line.custom_id.clone();
+            self.results.insert(custom_id, line.into_result());
+        }
+        Ok(())
+    }
+
+    /// Returns the raw result for a request.
+    pub fn get(&self, custom_id: &str) -> Option<&Result<BatchResponseData, BatchError>> {
+        self.results.get(custom_id)
+    }
+
+    /// Returns the response for a request deserialized as `T`.
+    ///
+    /// A body that does not match `T` is reported as a `BatchError` with
+    /// code `invalid_response`.
+    pub fn response<T: DeserializeOwned>(&self, custom_id: &str) -> Option<Result<T, BatchError>> {
+        let result = self.results.get(custom_id)?;
+        Some(match result {
+            Ok(data) => serde_json::from_value(data.body.clone()).map_err(|e| BatchError {
+                code: "invalid_response".to_string(),
+                message: e.to_string(),
+            }),
+            Err(error) => Err(error.clone()),
+        })
+    }
+
+    /// Iterates over the IDs of requests that succeeded.
+    pub fn succeeded(&self) -> impl Iterator<Item = &str> {
+        self.results
+            .iter()
+            .filter(|(_, result)| result.is_ok())
+            .map(|(id, _)| id.as_str())
+    }
+
+    /// Iterates over requests that failed, with their errors.
+    pub fn failed(&self) -> impl Iterator<Item = (&str, &BatchError)> {
+        self.results
+            .iter()
+            .filter_map(|(id, result)| result.as_ref().err().map(|e| (id.as_str(), e)))
+    }
+
+    /// Returns the number of results.
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    /// Returns true if there are no results.
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -305,4 +438,50 @@ mod tests {
         assert_eq!(batch.status, BatchStatus::Running);
         assert_eq!(batch.total_requests, Some(100));
     }
+
+    #[test]
+    fn test_jsonl_round_trip() {
+        let requests = vec![
+            BatchInputRequest::new("req-1", json!({ "messages": [{"role": "user", "content": "Hi"}] })),
+            BatchInputRequest::new("req-2", json!({ "messages": [{"role": "user", "content": "a\nb"}] })),
+        ];
+
+        let encoded = encode_jsonl(&requests).unwrap();
+        assert_eq!(encoded.iter().filter(|&&b| b == b'\n').count(), 2);
+
+        let decoded: Vec<BatchInputRequest> = decode_jsonl(&encoded).unwrap();
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[0].custom_id, "req-1");
+        assert_eq!(decoded[1].custom_id, "req-2");
+        assert_eq!(decoded[1].body, requests[1].body);
+    }
+
+    #[test]
+    fn test_mixed_results_are_keyed_by_custom_id() {
+        let output = br#"{"id":"r1","custom_id":"ok","response":{"status_code":200,"body":{"id":"cmpl-1","object":"chat.completion","model":"mistral-small-latest","created":1700000000,"choices":[{"index":0,"message":{"role":"assistant","content":"Hello"},"finish_reason":"stop"}],"usage":{"prompt_tokens":5,"completion_tokens":1,"total_tokens":6}}}}
+{"id":"r2","custom_id":"bad-request","response":{"status_code":400,"body":{"message":"Invalid model","object":"error"}}}
+
+"#;
+        let errors = br#"{"id":"r3","custom_id":"timed-out","error":{"code":"timeout","message":"Request timed out"}}
+"#;
+
+        let mut results = BatchResults::from_jsonl(output).unwrap();
+        results.extend_from_jsonl(errors).unwrap();
+        assert_eq!(results.len(), 3);
+
+        let response: crate::types::chat::ChatCompletionResponse =
+            results.response("ok").unwrap().unwrap();
+        assert_eq!(response.choices[0].message.content.as_deref(), Some("Hello"));
+
+        let error = results.response::<serde_json::Value>("bad-request").unwrap().unwrap_err();
+        assert_eq!(error.code, "400");
+        assert_eq!(error.message, "Invalid model");
+
+        let error = results.get("timed-out").unwrap().as_ref().unwrap_err();
+        assert_eq!(error.code, "timeout");
+
+        assert_eq!(results.succeeded().collect::<Vec<_>>(), vec!["ok"]);
+        assert_eq!(results.failed().count(), 2);
+        assert!(results.get("missing").is_none());
+    }
 }
@@ -3,7 +3,7 @@
 use serde::{Deserialize, Serialize};
 
 use super::common::{FinishReason, ResponseFormat, Role, SafePrompt, Usage};
-use super::tools::{Tool, ToolCall, ToolChoice};
+use super::tools::{Tool, ToolCall, ToolCallDelta, ToolChoice};
 
 /// Chat completion request.
 #[derive(Debug, Clone, Serialize)]
@@ -440,9 +440,9 @@ pub struct ContentDelta {
     /// Content text.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content: Option<String>,
-    /// Tool calls.
+    /// Tool call fragments.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tool_calls: Option<Vec<ToolCall>>,
+    pub tool_calls: Option<Vec<ToolCallDelta>>,
 }
 
 #[cfg(test)]
@@ -0,0 +1,153 @@
+//! Fill-in-the-middle (FIM) completion types.
+
+use serde::{Deserialize, Serialize};
+
+use super::chat::ChatChoice;
+use super::common::Usage;
+use crate::errors::{FieldError, MistralError, MistralResult};
+
+/// Returns true if `model` supports fill-in-the-middle completions.
+///
+/// Only the Codestral family does; fine-tuned models (`ft:codestral-...`)
+/// are accepted by their base model.
+pub fn is_fim_model(model: &str) -> bool {
+    let base = model.strip_prefix("ft:").unwrap_or(model);
+    base.starts_with("codestral") && !base.starts_with("codestral-mamba")
+}
+
+/// FIM completion request.
+#[derive(Debug, Clone, Serialize)]
+pub struct FimRequest {
+    /// Model ID to use.
+    pub model: String,
+    /// Code before the cursor.
+    pub prompt: String,
+    /// Code after the cursor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suffix: Option<String>,
+    /// Maximum tokens to generate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+    /// Stop sequences.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop: Option<Vec<String>>,
+    /// Sampling temperature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    /// Whether to stream the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<bool>,
+}
+
+impl FimRequest {
+    /// Creates a new FIM request.
+    pub fn new(model: impl Into<String>, prompt: impl Into<String>) -> Self {
+        Self {
+            model: model.into(),
+            prompt: prompt.into(),
+            suffix: None,
+            max_tokens: None,
+            stop: None,
+            temperature: None,
+            stream: None,
+        }
+    }
+
+    /// Sets the code after the cursor.
+    pub fn with_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = Some(suffix.into());
+        self
+    }
+
+    /// Sets the maximum tokens to generate.
+    pub fn with_max_tokens(mut self, max_tokens: u32) -> Self {
+        self.max_tokens = Some(max_tokens);
+        self
+    }
+
+    /// Adds a stop sequence.
+    pub fn with_stop(mut self, stop: impl Into<String>) -> Self {
+        self.stop.get_or_insert_with(Vec::new).push(stop.into());
+        self
+    }
+
+    /// Sets the sampling temperature.
+    pub fn with_temperature(mut self, temperature: f32) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+
+    /// Checks that the model supports FIM.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MistralError::Validation`] naming the model otherwise.
+    pub fn validate(&self) -> MistralResult<()> {
+        if is_fim_model(&self.model) {
+            return Ok(());
+        }
+        let message = format!(
+            "model '{}' does not support fill-in-the-middle; use a Codestral model such as codestral-latest",
+            self.model
+        );
+        Err(MistralError::validation(
+            message.clone(),
+            vec![FieldError {
+                field: "model".to_string(),
+                message,
+                code: "unsupported_model".to_string(),
+            }],
+        ))
+    }
+}
+
+/// FIM completion response.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FimResponse {
+    /// Response ID.
+    pub id: String,
+    /// Object type.
+    pub object: String,
+    /// Model used.
+    pub model: String,
+    /// Creation timestamp.
+    pub created: i64,
+    /// Completion choices.
+    pub choices: Vec<ChatChoice>,
+    /// Token usage.
+    pub usage: Usage,
+}
+
+impl FimResponse {
+    /// Returns the infilled text of the first choice.
+    pub fn text(&self) -> Option<&str> {
+        self.choices.first()?.message.content.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fim_models() {
+        assert!(is_fim_model("codestral-latest"));
+        assert!(is_fim_model("codestral-2501"));
+        assert!(is_fim_model("ft:codestral-latest:abc123"));
+        assert!(!is_fim_model("codestral-mamba-latest"));
+        assert!(!is_fim_model("mistral-large-latest"));
+    }
+
+    #[test]
+    fn test_request_serialization() {
+        let request = FimRequest::new("codestral-latest", "def add(a, b):\n    ")
+            .with_suffix("\n\nprint(add(1, 2))")
+            .with_max_tokens(64)
+            .with_stop("\n\n");
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["suffix"], "\n\nprint(add(1, 2))");
+        assert_eq!(json["stop"], serde_json::json!(["\n\n"]));
+        assert!(json.get("temperature").is_none());
+    }
+}
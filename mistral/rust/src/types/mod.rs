@@ -3,7 +3,9 @@
 pub mod chat;
 pub mod common;
 pub mod embeddings;
+pub mod fim;
 pub mod models;
+pub mod ocr;
 pub mod tools;
 pub mod files;
 pub mod fine_tuning;
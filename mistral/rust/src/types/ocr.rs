@@ -0,0 +1,247 @@
+//! OCR (document understanding) types.
+
+use base64::Engine;
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::errors::{FieldError, MistralError, MistralResult};
+
+/// OCR request.
+///
+/// The document is given either as a remote URL or as inline base64 PDF
+/// bytes; exactly one of the two must be set.
+#[derive(Debug, Clone)]
+pub struct OcrRequest {
+    /// Model ID to use.
+    pub model: String,
+    /// URL of a remote document.
+    pub document_url: Option<String>,
+    /// Base64-encoded PDF bytes.
+    pub document_base64: Option<String>,
+    /// Zero-based pages to process; all pages when unset.
+    pub pages: Option<Vec<u32>>,
+    /// Whether to return extracted images as base64.
+    pub include_image_base64: Option<bool>,
+}
+
+impl OcrRequest {
+    /// Creates a request for a remote document.
+    pub fn from_url(model: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            model: model.into(),
+            document_url: Some(url.into()),
+            document_base64: None,
+            pages: None,
+            include_image_base64: None,
+        }
+    }
+
+    /// Creates a request for an inline PDF, base64-encoding its bytes.
+    pub fn from_pdf_bytes(model: impl Into<String>, bytes: &[u8]) -> Self {
+        Self {
+            model: model.into(),
+            document_url: None,
+            document_base64: Some(base64::engine::general_purpose::STANDARD.encode(bytes)),
+            pages: None,
+            include_image_base64: None,
+        }
+    }
+
+    /// Restricts processing to the given zero-based pages.
+    pub fn with_pages(mut self, pages: Vec<u32>) -> Self {
+        self.pages = Some(pages);
+        self
+    }
+
+    /// Sets whether extracted images are returned as base64.
+    pub fn with_include_image_base64(mut self, include: bool) -> Self {
+        self.include_image_base64 = Some(include);
+        self
+    }
+
+    /// Checks that exactly one document source is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MistralError::Validation`] if both or neither are set.
+    pub fn validate(&self) -> MistralResult<()> {
+        let message = match (&self.document_url, &self.document_base64) {
+            (Some(_), None) | (None, Some(_)) => return Ok(()),
+            (Some(_), Some(_)) => "document_url and document_base64 are mutually exclusive",
+            (None, None) => "one of document_url or document_base64 is required",
+        };
+        Err(MistralError::validation(
+            message,
+            vec![FieldError {
+                field: "document".to_string(),
+                message: message.to_string(),
+                code: "invalid_document".to_string(),
+            }],
+        ))
+    }
+}
+
+impl Serialize for OcrRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Inline PDFs are sent as a data URL in the same `document_url` chunk
+        let document_url = match (&self.document_url, &self.document_base64) {
+            (Some(url), _) => url.clone(),
+            (None, Some(data)) => format!("data:application/pdf;base64,{}", data),
+            (None, None) => String::new(),
+        };
+
+        let mut state = serializer.serialize_struct("OcrRequest", 4)?;
+        state.serialize_field("model", &self.model)?;
+        state.serialize_field(
+            "document",
+            &DocumentChunk {
+                chunk_type: "document_url",
+                document_url,
+            },
+        )?;
+        if let Some(pages) = &self.pages {
+            state.serialize_field("pages", pages)?;
+        }
+        if let Some(include) = self.include_image_base64 {
+            state.serialize_field("include_image_base64", &include)?;
+        }
+        state.end()
+    }
+}
+
+/// Document chunk as sent on the wire.
+#[derive(Serialize)]
+struct DocumentChunk {
+    #[serde(rename = "type")]
+    chunk_type: &'static str,
+    document_url: String,
+}
+
+/// OCR response.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OcrResponse {
+    /// Model used.
+    pub model: String,
+    /// Processed pages, in document order.
+    pub pages: Vec<OcrPage>,
+    /// Processing usage.
+    #[serde(default)]
+    pub usage_info: Option<OcrUsageInfo>,
+}
+
+impl OcrResponse {
+    /// Joins the markdown of all pages, separated by blank lines.
+    pub fn markdown(&self) -> String {
+        self.pages
+            .iter()
+            .map(|page| page.markdown.as_str())
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+/// A single processed page.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OcrPage {
+    /// Zero-based page index.
+    pub index: u32,
+    /// Extracted page content as markdown.
+    pub markdown: String,
+    /// Images found on the page.
+    #[serde(default)]
+    pub images: Vec<OcrImage>,
+    /// Page dimensions.
+    #[serde(default)]
+    pub dimensions: Option<OcrPageDimensions>,
+}
+
+/// An image extracted from a page, with its bounding box in pixels.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OcrImage {
+    /// Image ID, referenced from the page markdown.
+    pub id: String,
+    /// Left edge of the bounding box.
+    pub top_left_x: u32,
+    /// Top edge of the bounding box.
+    pub top_left_y: u32,
+    /// Right edge of the bounding box.
+    pub bottom_right_x: u32,
+    /// Bottom edge of the bounding box.
+    pub bottom_right_y: u32,
+    /// Base64 image data, when requested.
+    #[serde(default)]
+    pub image_base64: Option<String>,
+}
+
+/// Page dimensions.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OcrPageDimensions {
+    /// Rendering resolution.
+    pub dpi: u32,
+    /// Height in pixels.
+    pub height: u32,
+    /// Width in pixels.
+    pub width: u32,
+}
+
+/// OCR usage information.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OcrUsageInfo {
+    /// Number of pages processed.
+    pub pages_processed: u32,
+    /// Document size in bytes.
+    #[serde(default)]
+    pub doc_size_bytes: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_url_request_serialization() {
+        let request = OcrRequest::from_url("mistral-ocr-latest", "https://example.com/report.pdf")
+            .with_pages(vec![0, 2])
+            .with_include_image_base64(true);
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "model": "mistral-ocr-latest",
+                "document": {
+                    "type": "document_url",
+                    "document_url": "https://example.com/report.pdf"
+                },
+                "pages": [0, 2],
+                "include_image_base64": true
+            })
+        );
+    }
+
+    #[test]
+    fn test_base64_request_serialization() {
+        let request = OcrRequest::from_pdf_bytes("mistral-ocr-latest", b"%PDF-1.7");
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json["document"]["document_url"],
+            "data:application/pdf;base64,JVBERi0xLjc="
+        );
+        assert!(json.get("pages").is_none());
+        assert!(json.get("include_image_base64").is_none());
+    }
+
+    #[test]
+    fn test_document_sources_are_mutually_exclusive() {
+        let mut request = OcrRequest::from_url("mistral-ocr-latest", "https://example.com/a.pdf");
+        assert!(request.validate().is_ok());
+
+        request.document_base64 = Some("JVBERi0xLjc=".to_string());
+        assert!(request.validate().is_err());
+
+        request.document_url = None;
+        request.document_base64 = None;
+        assert!(request.validate().is_err());
+    }
+}
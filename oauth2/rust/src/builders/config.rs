@@ -5,7 +5,10 @@
 use std::time::Duration;
 
 use crate::error::{ConfigurationError, OAuth2Error};
-use crate::types::{ClientAuthMethod, ClientCredentials, GrantType, OAuth2Config, ProviderConfig};
+use crate::types::{
+    ClientAssertionConfig, ClientAuthMethod, ClientCredentials, GrantType, OAuth2Config,
+    ProviderConfig,
+};
 use secrecy::SecretString;
 
 /// OAuth2 configuration builder.
@@ -28,6 +31,7 @@ pub struct OAuth2ConfigBuilder {
     timeout: Duration,
     enable_pkce: bool,
     enable_state: bool,
+    client_assertion: ClientAssertionConfig,
 }
 
 impl OAuth2ConfigBuilder {
@@ -59,6 +63,12 @@ impl OAuth2ConfigBuilder {
         self
     }
 
+    /// Set validity window for signed client assertions (`private_key_jwt`).
+    pub fn client_assertion_config(mut self, config: ClientAssertionConfig) -> Self {
+        self.client_assertion = config;
+        self
+    }
+
     /// Set authorization endpoint.
     pub fn authorization_endpoint(mut self, endpoint: impl Into<String>) -> Self {
         self.authorization_endpoint = Some(endpoint.into());
@@ -219,6 +229,7 @@ impl OAuth2ConfigBuilder {
             timeout: self.timeout,
             enable_pkce: self.enable_pkce,
             enable_state: self.enable_state,
+            client_assertion: self.client_assertion,
         })
     }
 }
@@ -9,6 +9,7 @@ use crate::core::{
     InMemoryStateManager, PkceGenerator, ReqwestHttpTransport, StateManager,
 };
 use crate::error::OAuth2Error;
+use crate::telemetry::{NoOpMetrics, OAuth2Metrics};
 use crate::flows::{
     AuthorizationCodeFlow, AuthorizationCodeFlowImpl, ClientCredentialsFlow,
     ClientCredentialsFlowImpl, ClientCredentialsRequest, DeviceAuthorizationFlow,
@@ -37,6 +38,7 @@ pub struct OAuth2Client<
     state_manager: Arc<S>,
     pkce_generator: Arc<P>,
     token_storage: Arc<TS>,
+    metrics: Arc<dyn OAuth2Metrics>,
 }
 
 impl OAuth2Client<ReqwestHttpTransport, InMemoryStateManager, DefaultPkceGenerator, InMemoryTokenStorage>
@@ -54,6 +56,7 @@ impl OAuth2Client<ReqwestHttpTransport, InMemoryStateManager, DefaultPkceGenerat
             state_manager,
             pkce_generator,
             token_storage,
+            metrics: Arc::new(NoOpMetrics),
         })
     }
 }
@@ -75,9 +78,16 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator, TS: TokenStorage>
             state_manager: Arc::new(state_manager),
             pkce_generator: Arc::new(pkce_generator),
             token_storage: Arc::new(token_storage),
+            metrics: Arc::new(NoOpMetrics),
         }
     }
 
+    /// Record grants from every flow and token refresh through the given metrics.
+    pub fn with_metrics(mut self, metrics: Arc<dyn OAuth2Metrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     /// Get the OAuth2 configuration.
     pub fn config(&self) -> &OAuth2Config {
         &self.config
@@ -86,12 +96,16 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator, TS: TokenStorage>
     // ========== Authorization Code Flow ==========
 
     /// Build authorization URL for the standard Authorization Code flow.
-    pub fn build_authorization_url(&self, params: AuthorizationParams) -> AuthorizationUrl {
+    pub fn build_authorization_url(
+        &self,
+        params: AuthorizationParams,
+    ) -> Result<AuthorizationUrl, OAuth2Error> {
         let flow = AuthorizationCodeFlowImpl::new(
             self.config.clone(),
             self.transport.clone(),
             self.state_manager.clone(),
-        );
+        )
+        .with_metrics(self.metrics.clone());
         flow.build_authorization_url(params)
     }
 
@@ -104,7 +118,8 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator, TS: TokenStorage>
             self.config.clone(),
             self.transport.clone(),
             self.state_manager.clone(),
-        );
+        )
+        .with_metrics(self.metrics.clone());
         flow.exchange_code(request).await
     }
 
@@ -117,7 +132,8 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator, TS: TokenStorage>
             self.config.clone(),
             self.transport.clone(),
             self.state_manager.clone(),
-        );
+        )
+        .with_metrics(self.metrics.clone());
         flow.handle_callback(callback).await
     }
 
@@ -132,13 +148,14 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator, TS: TokenStorage>
     pub fn build_pkce_authorization_url(
         &self,
         params: PkceAuthorizationParams,
-    ) -> PkceAuthorizationUrl {
+    ) -> Result<PkceAuthorizationUrl, OAuth2Error> {
         let flow = PkceAuthorizationCodeFlowImpl::new(
             self.config.clone(),
             self.transport.clone(),
             self.state_manager.clone(),
             self.pkce_generator.clone(),
-        );
+        )
+        .with_metrics(self.metrics.clone());
         flow.build_authorization_url(params)
     }
 
@@ -153,7 +170,8 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator, TS: TokenStorage>
             self.transport.clone(),
             self.state_manager.clone(),
             self.pkce_generator.clone(),
-        );
+        )
+        .with_metrics(self.metrics.clone());
         flow.exchange_code(request, code_verifier).await
     }
 
@@ -168,7 +186,8 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator, TS: TokenStorage>
             self.transport.clone(),
             self.state_manager.clone(),
             self.pkce_generator.clone(),
-        );
+        )
+        .with_metrics(self.metrics.clone());
         flow.handle_callback(callback, code_verifier).await
     }
 
@@ -179,7 +198,8 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator, TS: TokenStorage>
         &self,
         request: ClientCredentialsRequest,
     ) -> Result<TokenResponse, OAuth2Error> {
-        let flow = ClientCredentialsFlowImpl::new(self.config.clone(), self.transport.clone());
+        let flow = ClientCredentialsFlowImpl::new(self.config.clone(), self.transport.clone())
+            .with_metrics(self.metrics.clone());
         flow.request_token(request).await
     }
 
@@ -190,17 +210,20 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator, TS: TokenStorage>
         &self,
         params: DeviceCodeParams,
     ) -> Result<DeviceAuthorizationResponse, OAuth2Error> {
-        let flow = DeviceAuthorizationFlowImpl::new(self.config.clone(), self.transport.clone());
+        let flow = DeviceAuthorizationFlowImpl::new(self.config.clone(), self.transport.clone())
+            .with_metrics(self.metrics.clone());
         flow.request_device_code(params).await
     }
 
-    /// Poll for token in device flow.
+    /// Poll once for token in device flow.
     pub async fn poll_device_token(
         &self,
         device_code: &str,
+        interval: std::time::Duration,
     ) -> Result<crate::types::DeviceTokenResult, OAuth2Error> {
-        let flow = DeviceAuthorizationFlowImpl::new(self.config.clone(), self.transport.clone());
-        flow.poll_for_token(device_code).await
+        let flow = DeviceAuthorizationFlowImpl::new(self.config.clone(), self.transport.clone())
+            .with_metrics(self.metrics.clone());
+        flow.poll_once(device_code, interval).await
     }
 
     /// Poll for token until complete or expired.
@@ -210,8 +233,9 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator, TS: TokenStorage>
         interval: std::time::Duration,
         expires_in: std::time::Duration,
     ) -> Result<TokenResponse, OAuth2Error> {
-        let flow = DeviceAuthorizationFlowImpl::new(self.config.clone(), self.transport.clone());
-        flow.poll_until_complete(device_code, interval, expires_in)
+        let flow = DeviceAuthorizationFlowImpl::new(self.config.clone(), self.transport.clone())
+            .with_metrics(self.metrics.clone());
+        flow.poll_for_token(device_code, interval, expires_in)
             .await
     }
 
@@ -224,7 +248,8 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator, TS: TokenStorage>
             TokenManagerConfig::default(),
             self.transport.clone(),
             self.token_storage.clone(),
-        );
+        )
+        .with_metrics(self.metrics.clone());
         manager.get_access_token(key).await
     }
 
@@ -239,7 +264,8 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator, TS: TokenStorage>
             TokenManagerConfig::default(),
             self.transport.clone(),
             self.token_storage.clone(),
-        );
+        )
+        .with_metrics(self.metrics.clone());
         manager.store_tokens(key, response).await
     }
 
@@ -250,7 +276,8 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator, TS: TokenStorage>
             TokenManagerConfig::default(),
             self.transport.clone(),
             self.token_storage.clone(),
-        );
+        )
+        .with_metrics(self.metrics.clone());
         manager.refresh_tokens(key).await
     }
 
@@ -261,7 +288,8 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator, TS: TokenStorage>
             TokenManagerConfig::default(),
             self.transport.clone(),
             self.token_storage.clone(),
-        );
+        )
+        .with_metrics(self.metrics.clone());
         manager.delete_tokens(key).await
     }
 
@@ -277,6 +305,21 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator, TS: TokenStorage>
         introspector.introspect(params).await
     }
 
+    /// Introspect the stored access token, updating its timestamps when active.
+    pub async fn introspect_stored_tokens(
+        &self,
+        key: &str,
+    ) -> Result<IntrospectionResponse, OAuth2Error> {
+        let manager = DefaultTokenManager::new(
+            self.config.clone(),
+            TokenManagerConfig::default(),
+            self.transport.clone(),
+            self.token_storage.clone(),
+        )
+        .with_metrics(self.metrics.clone());
+        manager.introspect_stored_tokens(key).await
+    }
+
     /// Check if a token is active.
     pub async fn is_token_active(&self, token: &str) -> Result<bool, OAuth2Error> {
         let introspector =
@@ -325,6 +368,8 @@ pub fn oauth2_client(config: OAuth2Config) -> Result<OAuth2Client, OAuth2Error>
 mod tests {
     use super::*;
     use crate::builders::oauth2_config;
+    use crate::core::MockHttpTransport;
+    use crate::telemetry::InMemoryMetrics;
     use crate::types::ClientAuthMethod;
 
     fn create_test_config() -> OAuth2Config {
@@ -356,7 +401,7 @@ mod tests {
             ..Default::default()
         };
 
-        let auth_url = client.build_authorization_url(params);
+        let auth_url = client.build_authorization_url(params).unwrap();
         assert!(auth_url.url.contains("example.com/authorize"));
         assert!(!auth_url.state.is_empty());
     }
@@ -373,7 +418,7 @@ mod tests {
             ..Default::default()
         };
 
-        let auth_url = client.build_pkce_authorization_url(params);
+        let auth_url = client.build_pkce_authorization_url(params).unwrap();
         assert!(auth_url.url.contains("example.com/authorize"));
         assert!(!auth_url.code_verifier.is_empty());
         assert!(!auth_url.code_challenge.is_empty());
@@ -389,4 +434,37 @@ mod tests {
         assert!(!pkce.code_challenge.is_empty());
         assert_eq!(pkce.code_challenge_method, PkceMethod::S256);
     }
+
+    #[tokio::test]
+    async fn test_exchange_code_records_grant_result() {
+        let transport = MockHttpTransport::new();
+        transport.queue_json_response(
+            200,
+            &serde_json::json!({"access_token": "access", "token_type": "Bearer"}),
+        );
+        let metrics = Arc::new(InMemoryMetrics::new());
+        let client = OAuth2Client::with_components(
+            create_test_config(),
+            transport,
+            InMemoryStateManager::new(),
+            DefaultPkceGenerator::new(),
+            InMemoryTokenStorage::new(),
+        )
+        .with_metrics(metrics.clone());
+
+        client
+            .exchange_code(CodeExchangeRequest {
+                code: "auth-code".to_string(),
+                redirect_uri: "https://example.com/callback".to_string(),
+                state: None,
+                resource: Vec::new(),
+            })
+            .await
+            .unwrap();
+
+        let results = metrics.get_entries_by_name("oauth2_grant_results_total");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].labels.get("grant_type").map(String::as_str), Some("auth_code"));
+        assert_eq!(results[0].labels.get("outcome").map(String::as_str), Some("success"));
+    }
 }
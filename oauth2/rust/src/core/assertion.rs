@@ -0,0 +1,260 @@
+//! Client Assertions
+//!
+//! RFC 7523 - JWT Bearer client authentication (`private_key_jwt`).
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::error::{ConfigurationError, OAuth2Error};
+use crate::types::{
+    ClientAssertionConfig, ClientAssertionKey, ClientAuthMethod, JwtSigningAlgorithm, OAuth2Config,
+};
+
+/// `client_assertion_type` value for JWT bearer assertions.
+pub const CLIENT_ASSERTION_TYPE: &str = "urn:ietf:params:oauth:client-assertion-type:jwt-bearer";
+
+/// Claims carried in a client assertion.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientAssertionClaims {
+    /// Issuer (the client ID).
+    pub iss: String,
+    /// Subject (the client ID).
+    pub sub: String,
+    /// Audience (the token endpoint).
+    pub aud: String,
+    /// Unique identifier preventing replay.
+    pub jti: String,
+    /// Issued at (seconds since epoch).
+    pub iat: u64,
+    /// Not before (seconds since epoch).
+    pub nbf: u64,
+    /// Expiration (seconds since epoch).
+    pub exp: u64,
+}
+
+impl ClientAssertionClaims {
+    /// Build claims for `client_id` targeting `audience`, issued now.
+    pub fn new(client_id: &str, audience: &str, config: &ClientAssertionConfig) -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let issued_at = now.saturating_sub(config.clock_skew.as_secs());
+
+        Self {
+            iss: client_id.to_string(),
+            sub: client_id.to_string(),
+            aud: audience.to_string(),
+            jti: generate_jti(),
+            iat: issued_at,
+            nbf: issued_at,
+            exp: now + config.lifetime.as_secs(),
+        }
+    }
+}
+
+fn generate_jti() -> String {
+    let bytes: [u8; 16] = rand::thread_rng().gen();
+    base64::Engine::encode(&base64::engine::general_purpose::URL_SAFE_NO_PAD, bytes)
+}
+
+/// Sign client assertion claims.
+#[cfg(feature = "jwt")]
+pub fn sign_client_assertion(
+    claims: &ClientAssertionClaims,
+    key: &ClientAssertionKey,
+    algorithm: JwtSigningAlgorithm,
+    kid: Option<&str>,
+) -> Result<String, OAuth2Error> {
+    use jsonwebtoken::{Algorithm, EncodingKey, Header};
+
+    let (alg, encoding_key) = match algorithm {
+        JwtSigningAlgorithm::RS256 => (Algorithm::RS256, EncodingKey::from_rsa_pem(key.pem())),
+        JwtSigningAlgorithm::ES256 => (Algorithm::ES256, EncodingKey::from_ec_pem(key.pem())),
+    };
+    let encoding_key = encoding_key.map_err(|e| {
+        OAuth2Error::Configuration(ConfigurationError::InvalidConfig {
+            message: format!("Invalid {} client assertion key: {}", algorithm.as_str(), e),
+        })
+    })?;
+
+    let mut header = Header::new(alg);
+    header.kid = kid.map(String::from);
+
+    jsonwebtoken::encode(&header, claims, &encoding_key).map_err(|e| {
+        OAuth2Error::Configuration(ConfigurationError::InvalidConfig {
+            message: format!("Failed to sign client assertion: {}", e),
+        })
+    })
+}
+
+/// Sign client assertion claims.
+#[cfg(not(feature = "jwt"))]
+pub fn sign_client_assertion(
+    _claims: &ClientAssertionClaims,
+    _key: &ClientAssertionKey,
+    _algorithm: JwtSigningAlgorithm,
+    _kid: Option<&str>,
+) -> Result<String, OAuth2Error> {
+    Err(OAuth2Error::Configuration(
+        ConfigurationError::InvalidConfig {
+            message: "private_key_jwt requires the `jwt` feature".to_string(),
+        },
+    ))
+}
+
+/// Form parameters authenticating the client with a signed assertion.
+///
+/// Returns an empty list unless the configured auth method is `PrivateKeyJwt`.
+/// The token endpoint is used as the audience for every request.
+pub fn client_assertion_params(
+    config: &OAuth2Config,
+) -> Result<Vec<(&'static str, String)>, OAuth2Error> {
+    let ClientAuthMethod::PrivateKeyJwt {
+        key,
+        algorithm,
+        kid,
+    } = &config.credentials.auth_method
+    else {
+        return Ok(Vec::new());
+    };
+
+    let claims = ClientAssertionClaims::new(
+        &config.credentials.client_id,
+        &config.provider.token_endpoint,
+        &config.client_assertion,
+    );
+    let assertion = sign_client_assertion(&claims, key, *algorithm, kid.as_deref())?;
+
+    Ok(vec![
+        ("client_assertion_type", CLIENT_ASSERTION_TYPE.to_string()),
+        ("client_assertion", assertion),
+    ])
+}
+
+#[cfg(all(test, feature = "jwt"))]
+mod tests {
+    use super::*;
+    use crate::core::test_keys::{EC_PRIVATE_KEY, EC_PUBLIC_KEY, RSA_PRIVATE_KEY, RSA_PUBLIC_KEY};
+    use crate::types::ClientCredentials;
+    use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+    use std::time::Duration;
+
+    fn config_with(algorithm: JwtSigningAlgorithm, pem: &str) -> OAuth2Config {
+        let mut config = OAuth2Config::default();
+        config.provider.token_endpoint = "https://auth.example.com/token".to_string();
+        config.credentials = ClientCredentials {
+            client_id: "client-123".to_string(),
+            client_secret: None,
+            auth_method: ClientAuthMethod::PrivateKeyJwt {
+                key: ClientAssertionKey::from_pem(pem),
+                algorithm,
+                kid: Some("key-1".to_string()),
+            },
+        };
+        config
+    }
+
+    fn assertion_from(params: &[(&'static str, String)]) -> String {
+        assert_eq!(
+            params[0],
+            ("client_assertion_type", CLIENT_ASSERTION_TYPE.to_string())
+        );
+        assert_eq!(params[1].0, "client_assertion");
+        params[1].1.clone()
+    }
+
+    fn validation(alg: Algorithm) -> Validation {
+        let mut validation = Validation::new(alg);
+        validation.set_audience(&["https://auth.example.com/token"]);
+        validation.set_issuer(&["client-123"]);
+        validation
+    }
+
+    #[test]
+    fn test_rs256_assertion_claims_and_signature() {
+        let config = config_with(JwtSigningAlgorithm::RS256, RSA_PRIVATE_KEY);
+        let assertion = assertion_from(&client_assertion_params(&config).unwrap());
+
+        let header = decode_header(&assertion).unwrap();
+        assert_eq!(header.alg, Algorithm::RS256);
+        assert_eq!(header.kid.as_deref(), Some("key-1"));
+
+        let key = DecodingKey::from_rsa_pem(RSA_PUBLIC_KEY.as_bytes()).unwrap();
+        let claims =
+            decode::<ClientAssertionClaims>(&assertion, &key, &validation(Algorithm::RS256))
+                .unwrap()
+                .claims;
+        assert_eq!(claims.iss, "client-123");
+        assert_eq!(claims.sub, "client-123");
+        assert_eq!(claims.aud, "https://auth.example.com/token");
+        assert!(!claims.jti.is_empty());
+        assert_eq!(claims.exp - claims.iat, 90);
+    }
+
+    #[test]
+    fn test_es256_assertion_signature() {
+        let config = config_with(JwtSigningAlgorithm::ES256, EC_PRIVATE_KEY);
+        let assertion = assertion_from(&client_assertion_params(&config).unwrap());
+
+        let key = DecodingKey::from_ec_pem(EC_PUBLIC_KEY.as_bytes()).unwrap();
+        let claims =
+            decode::<ClientAssertionClaims>(&assertion, &key, &validation(Algorithm::ES256))
+                .unwrap()
+                .claims;
+        assert_eq!(claims.aud, "https://auth.example.com/token");
+    }
+
+    #[test]
+    fn test_assertion_rejects_wrong_key() {
+        let config = config_with(JwtSigningAlgorithm::RS256, RSA_PRIVATE_KEY);
+        let assertion = assertion_from(&client_assertion_params(&config).unwrap());
+
+        let key = DecodingKey::from_ec_pem(EC_PUBLIC_KEY.as_bytes()).unwrap();
+        assert!(
+            decode::<ClientAssertionClaims>(&assertion, &key, &validation(Algorithm::ES256))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_clock_skew_window() {
+        let window = ClientAssertionConfig {
+            lifetime: Duration::from_secs(120),
+            clock_skew: Duration::from_secs(10),
+        };
+        let claims = ClientAssertionClaims::new("client", "aud", &window);
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        assert!(claims.iat <= now - 10);
+        assert_eq!(claims.nbf, claims.iat);
+        assert_eq!(claims.exp - claims.iat, 130);
+    }
+
+    #[test]
+    fn test_unique_jti() {
+        let window = ClientAssertionConfig::default();
+        let first = ClientAssertionClaims::new("client", "aud", &window);
+        let second = ClientAssertionClaims::new("client", "aud", &window);
+        assert_ne!(first.jti, second.jti);
+    }
+
+    #[test]
+    fn test_no_params_for_secret_auth() {
+        let config = OAuth2Config::default();
+        assert!(client_assertion_params(&config).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_key_is_configuration_error() {
+        let config = config_with(JwtSigningAlgorithm::RS256, "not a key");
+        assert!(matches!(
+            client_assertion_params(&config),
+            Err(OAuth2Error::Configuration(_))
+        ));
+    }
+}
@@ -0,0 +1,635 @@
+//! ID Token Validation
+//!
+//! OpenID Connect Core 1.0 Section 3.1.3.7 - ID token validation against the
+//! provider JWKS.
+
+use jsonwebtoken::jwk::{AlgorithmParameters, Jwk, JwkSet};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::core::transport::{HttpMethod, HttpRequest, HttpTransport};
+use crate::error::{ConfigurationError, OAuth2Error, ProtocolError};
+use crate::types::{OIDCDiscoveryDocument, StateMetadata};
+
+/// Claims carried in an OIDC ID token.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IdTokenClaims {
+    /// Issuer identifier.
+    pub iss: String,
+    /// Subject identifier.
+    pub sub: String,
+    /// Audiences (a single string or an array in the token).
+    #[serde(deserialize_with = "deserialize_audience")]
+    pub aud: Vec<String>,
+    /// Expiration (seconds since epoch).
+    pub exp: u64,
+    /// Issued at (seconds since epoch).
+    pub iat: u64,
+    /// Not before (seconds since epoch).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nbf: Option<u64>,
+    /// Nonce from the authorization request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+    /// Authorized party.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub azp: Option<String>,
+    /// Any other claims (email, name, ...).
+    #[serde(flatten)]
+    pub additional: HashMap<String, serde_json::Value>,
+}
+
+fn deserialize_audience<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Audience {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match Audience::deserialize(deserializer)? {
+        Audience::One(aud) => vec![aud],
+        Audience::Many(aud) => aud,
+    })
+}
+
+/// Cached JWKS.
+struct JwksCacheEntry {
+    keys: JwkSet,
+    fetched_at: Instant,
+    expires_at: Instant,
+}
+
+/// Validates ID tokens issued by an OIDC provider.
+///
+/// The provider JWKS is cached; a token signed with an unknown `kid` triggers
+/// one re-fetch so that key rotation is picked up without waiting for expiry.
+/// Such re-fetches happen at most once per refresh interval, so tokens with
+/// bogus `kid`s cannot hammer the provider.
+pub struct IdTokenValidator<T: HttpTransport> {
+    transport: T,
+    issuer: String,
+    client_id: String,
+    jwks_uri: String,
+    clock_skew: Duration,
+    jwks_cache_ttl: Duration,
+    jwks_refresh_interval: Duration,
+    jwks: Mutex<Option<JwksCacheEntry>>,
+}
+
+impl<T: HttpTransport> IdTokenValidator<T> {
+    /// Create validator for `issuer` and `client_id` using keys from `jwks_uri`.
+    pub fn new(
+        transport: T,
+        issuer: impl Into<String>,
+        client_id: impl Into<String>,
+        jwks_uri: impl Into<String>,
+    ) -> Self {
+        Self {
+            transport,
+            issuer: issuer.into(),
+            client_id: client_id.into(),
+            jwks_uri: jwks_uri.into(),
+            clock_skew: Duration::from_secs(60),
+            jwks_cache_ttl: Duration::from_secs(3600), // 1 hour
+            jwks_refresh_interval: Duration::from_secs(30),
+            jwks: Mutex::new(None),
+        }
+    }
+
+    /// Create validator from a discovery document.
+    pub fn from_discovery(
+        transport: T,
+        document: &OIDCDiscoveryDocument,
+        client_id: impl Into<String>,
+    ) -> Result<Self, OAuth2Error> {
+        let jwks_uri = document.jwks_uri.clone().ok_or_else(|| {
+            OAuth2Error::Configuration(ConfigurationError::MissingRequired {
+                field: "jwks_uri".to_string(),
+            })
+        })?;
+
+        Ok(Self::new(transport, &document.issuer, client_id, jwks_uri))
+    }
+
+    /// Set allowance for clock drift when checking `exp` and `nbf`.
+    pub fn with_clock_skew(mut self, clock_skew: Duration) -> Self {
+        self.clock_skew = clock_skew;
+        self
+    }
+
+    /// Set how long fetched keys are cached.
+    pub fn with_jwks_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.jwks_cache_ttl = ttl;
+        self
+    }
+
+    /// Set the minimum time between re-fetches triggered by an unknown `kid`.
+    pub fn with_jwks_refresh_interval(mut self, interval: Duration) -> Self {
+        self.jwks_refresh_interval = interval;
+        self
+    }
+
+    /// Validate an ID token against the nonce stored with the authorization state.
+    pub async fn validate(
+        &self,
+        id_token: &str,
+        state: &StateMetadata,
+    ) -> Result<IdTokenClaims, OAuth2Error> {
+        self.validate_with_nonce(id_token, state.nonce.as_deref())
+            .await
+    }
+
+    /// Validate an ID token, checking `nonce` only when one is expected.
+    pub async fn validate_with_nonce(
+        &self,
+        id_token: &str,
+        expected_nonce: Option<&str>,
+    ) -> Result<IdTokenClaims, OAuth2Error> {
+        let header = jsonwebtoken::decode_header(id_token).map_err(|e| {
+            OAuth2Error::Protocol(ProtocolError::InvalidIdToken {
+                message: e.to_string(),
+            })
+        })?;
+
+        if !matches!(header.alg, Algorithm::RS256 | Algorithm::ES256) {
+            return Err(OAuth2Error::Protocol(ProtocolError::UnsupportedAlgorithm {
+                alg: format!("{:?}", header.alg),
+            }));
+        }
+
+        let jwk = self.signing_key(header.kid.as_deref(), header.alg).await?;
+        let claims = verify_signature(id_token, &jwk, header.alg)?;
+
+        self.validate_claims(&claims, expected_nonce)?;
+
+        Ok(claims)
+    }
+
+    /// Clear cached keys.
+    pub fn clear_jwks_cache(&self) {
+        *self.jwks.lock().unwrap() = None;
+    }
+
+    fn validate_claims(
+        &self,
+        claims: &IdTokenClaims,
+        expected_nonce: Option<&str>,
+    ) -> Result<(), OAuth2Error> {
+        if claims.iss.trim_end_matches('/') != self.issuer.trim_end_matches('/') {
+            return Err(OAuth2Error::Protocol(ProtocolError::IssuerMismatch {
+                expected: self.issuer.clone(),
+                actual: claims.iss.clone(),
+            }));
+        }
+
+        let authorized_party_mismatch = claims.aud.len() > 1
+            && claims.azp.as_deref().is_some_and(|azp| azp != self.client_id);
+        if !claims.aud.contains(&self.client_id) || authorized_party_mismatch {
+            return Err(OAuth2Error::Protocol(ProtocolError::AudienceMismatch {
+                expected: self.client_id.clone(),
+                actual: claims.aud.clone(),
+            }));
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let skew = self.clock_skew.as_secs();
+
+        if now > claims.exp.saturating_add(skew) {
+            return Err(OAuth2Error::Protocol(ProtocolError::IdTokenExpired {
+                exp: claims.exp,
+            }));
+        }
+
+        if let Some(nbf) = claims.nbf {
+            if now.saturating_add(skew) < nbf {
+                return Err(OAuth2Error::Protocol(ProtocolError::IdTokenNotYetValid {
+                    nbf,
+                }));
+            }
+        }
+
+        if let Some(expected) = expected_nonce {
+            if claims.nonce.as_deref() != Some(expected) {
+                return Err(OAuth2Error::Protocol(ProtocolError::NonceMismatch {
+                    expected: expected.to_string(),
+                    actual: claims.nonce.clone(),
+                }));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn signing_key(&self, kid: Option<&str>, alg: Algorithm) -> Result<Jwk, OAuth2Error> {
+        if let Some(entry) = self.jwks.lock().unwrap().as_ref() {
+            if Instant::now() < entry.expires_at {
+                if let Some(jwk) = find_key(&entry.keys, kid, alg) {
+                    return Ok(jwk);
+                }
+                if entry.fetched_at.elapsed() < self.jwks_refresh_interval {
+                    return Err(unknown_signing_key(kid));
+                }
+            }
+        }
+
+        // Unknown kid or expired cache: the provider may have rotated keys.
+        let keys = self.fetch_jwks().await?;
+        let jwk = find_key(&keys, kid, alg);
+
+        let now = Instant::now();
+        *self.jwks.lock().unwrap() = Some(JwksCacheEntry {
+            keys,
+            fetched_at: now,
+            expires_at: now + self.jwks_cache_ttl,
+        });
+
+        jwk.ok_or_else(|| unknown_signing_key(kid))
+    }
+
+    async fn fetch_jwks(&self) -> Result<JwkSet, OAuth2Error> {
+        let request = HttpRequest {
+            method: HttpMethod::Get,
+            url: self.jwks_uri.clone(),
+            headers: [("accept".to_string(), "application/json".to_string())]
+                .into_iter()
+                .collect(),
+            body: None,
+            timeout: None,
+        };
+
+        let response = self.transport.send(request).await?;
+
+        if response.status != 200 {
+            return Err(OAuth2Error::Protocol(ProtocolError::InvalidResponse {
+                message: format!("JWKS request failed with status {}", response.status),
+            }));
+        }
+
+        serde_json::from_str(&response.body).map_err(|e| {
+            OAuth2Error::Protocol(ProtocolError::InvalidJson {
+                message: e.to_string(),
+            })
+        })
+    }
+}
+
+fn unknown_signing_key(kid: Option<&str>) -> OAuth2Error {
+    OAuth2Error::Protocol(ProtocolError::UnknownSigningKey {
+        kid: kid.map(String::from),
+    })
+}
+
+/// Find the key for `kid`, or the first key usable with `alg` when the token has no `kid`.
+fn find_key(keys: &JwkSet, kid: Option<&str>, alg: Algorithm) -> Option<Jwk> {
+    match kid {
+        Some(kid) => keys.find(kid).cloned(),
+        None => keys
+            .keys
+            .iter()
+            .find(|jwk| {
+                matches!(
+                    (&jwk.algorithm, alg),
+                    (AlgorithmParameters::RSA(_), Algorithm::RS256)
+                        | (AlgorithmParameters::EllipticCurve(_), Algorithm::ES256)
+                )
+            })
+            .cloned(),
+    }
+}
+
+fn verify_signature(
+    id_token: &str,
+    jwk: &Jwk,
+    alg: Algorithm,
+) -> Result<IdTokenClaims, OAuth2Error> {
+    let key = DecodingKey::from_jwk(jwk).map_err(|e| {
+        OAuth2Error::Protocol(ProtocolError::InvalidSignature {
+            message: format!("Unusable signing key: {}", e),
+        })
+    })?;
+
+    // Claims are checked separately so each failure gets its own error.
+    let mut validation = Validation::new(alg);
+    validation.validate_exp = false;
+    validation.validate_nbf = false;
+    validation.validate_aud = false;
+    validation.required_spec_claims.clear();
+
+    jsonwebtoken::decode::<IdTokenClaims>(id_token, &key, &validation)
+        .map(|data| data.claims)
+        .map_err(|e| match e.kind() {
+            jsonwebtoken::errors::ErrorKind::InvalidSignature
+            | jsonwebtoken::errors::ErrorKind::InvalidAlgorithm
+            | jsonwebtoken::errors::ErrorKind::InvalidEcdsaKey
+            | jsonwebtoken::errors::ErrorKind::InvalidRsaKey(_) => {
+                OAuth2Error::Protocol(ProtocolError::InvalidSignature {
+                    message: e.to_string(),
+                })
+            }
+            _ => OAuth2Error::Protocol(ProtocolError::InvalidIdToken {
+                message: e.to_string(),
+            }),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::discovery::create_mock_discovery_document;
+    use crate::core::test_keys::{
+        EC_JWK_X, EC_JWK_Y, EC_PRIVATE_KEY, RSA_JWK_E, RSA_JWK_N, RSA_PRIVATE_KEY,
+    };
+    use crate::core::transport::MockHttpTransport;
+    use jsonwebtoken::{EncodingKey, Header};
+    use serde_json::json;
+
+    const ISSUER: &str = "https://auth.example.com";
+    const CLIENT_ID: &str = "client-123";
+    const NONCE: &str = "n-0S6_WzA2Mj";
+
+    fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
+    fn jwks(rsa_kid: &str) -> serde_json::Value {
+        json!({
+            "keys": [
+                { "kty": "RSA", "kid": rsa_kid, "use": "sig", "alg": "RS256", "n": RSA_JWK_N, "e": RSA_JWK_E },
+                { "kty": "EC", "kid": "ec-1", "use": "sig", "alg": "ES256", "crv": "P-256", "x": EC_JWK_X, "y": EC_JWK_Y }
+            ]
+        })
+    }
+
+    fn claims() -> serde_json::Value {
+        json!({
+            "iss": ISSUER,
+            "sub": "user-1",
+            "aud": CLIENT_ID,
+            "exp": now() + 300,
+            "iat": now(),
+            "nonce": NONCE,
+            "email": "user@example.com"
+        })
+    }
+
+    fn sign_rs256(kid: &str, claims: &serde_json::Value) -> String {
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = Some(kid.to_string());
+        let key = EncodingKey::from_rsa_pem(RSA_PRIVATE_KEY.as_bytes()).unwrap();
+        jsonwebtoken::encode(&header, claims, &key).unwrap()
+    }
+
+    fn validator() -> IdTokenValidator<MockHttpTransport> {
+        let transport = MockHttpTransport::new();
+        transport.queue_json_response(200, &jwks("rsa-1"));
+        IdTokenValidator::new(
+            transport,
+            ISSUER,
+            CLIENT_ID,
+            format!("{}/.well-known/jwks.json", ISSUER),
+        )
+    }
+
+    fn state() -> StateMetadata {
+        StateMetadata::new(
+            "https://app.example.com/callback".to_string(),
+            vec!["openid".to_string()],
+        )
+        .with_nonce(NONCE.to_string())
+    }
+
+    async fn validate_claims(claims: serde_json::Value) -> Result<IdTokenClaims, OAuth2Error> {
+        validator()
+            .validate(&sign_rs256("rsa-1", &claims), &state())
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_valid_rs256_token() {
+        let validator = validator();
+        let token = sign_rs256("rsa-1", &claims());
+
+        let claims = validator.validate(&token, &state()).await.unwrap();
+        assert_eq!(claims.iss, ISSUER);
+        assert_eq!(claims.sub, "user-1");
+        assert_eq!(claims.aud, vec![CLIENT_ID]);
+        assert_eq!(claims.nonce.as_deref(), Some(NONCE));
+        assert_eq!(claims.additional["email"], "user@example.com");
+
+        // Keys are cached between validations
+        validator.validate(&token, &state()).await.unwrap();
+        let requests = validator.transport.get_requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].url, "https://auth.example.com/.well-known/jwks.json");
+    }
+
+    #[tokio::test]
+    async fn test_valid_es256_token() {
+        let mut header = Header::new(Algorithm::ES256);
+        header.kid = Some("ec-1".to_string());
+        let key = EncodingKey::from_ec_pem(EC_PRIVATE_KEY.as_bytes()).unwrap();
+        let token = jsonwebtoken::encode(&header, &claims(), &key).unwrap();
+
+        let claims = validator().validate(&token, &state()).await.unwrap();
+        assert_eq!(claims.sub, "user-1");
+    }
+
+    #[tokio::test]
+    async fn test_from_discovery_uses_jwks_uri() {
+        let transport = MockHttpTransport::new();
+        transport.queue_json_response(200, &jwks("rsa-1"));
+        let document = create_mock_discovery_document(ISSUER);
+        let validator = IdTokenValidator::from_discovery(transport, &document, CLIENT_ID).unwrap();
+
+        validator
+            .validate(&sign_rs256("rsa-1", &claims()), &state())
+            .await
+            .unwrap();
+
+        let mut document = create_mock_discovery_document(ISSUER);
+        document.jwks_uri = None;
+        assert!(matches!(
+            IdTokenValidator::from_discovery(MockHttpTransport::new(), &document, CLIENT_ID),
+            Err(OAuth2Error::Configuration(ConfigurationError::MissingRequired { .. }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_kid_refetches_rotated_keys() {
+        let validator = validator().with_jwks_refresh_interval(Duration::ZERO);
+        validator
+            .validate(&sign_rs256("rsa-1", &claims()), &state())
+            .await
+            .unwrap();
+
+        // Provider rotates to a new kid
+        validator
+            .transport
+            .queue_json_response(200, &jwks("rsa-2"));
+        validator
+            .validate(&sign_rs256("rsa-2", &claims()), &state())
+            .await
+            .unwrap();
+        assert_eq!(validator.transport.get_requests().len(), 2);
+
+        // A kid missing even after re-fetch is rejected
+        validator
+            .transport
+            .queue_json_response(200, &jwks("rsa-2"));
+        let result = validator
+            .validate(&sign_rs256("rsa-3", &claims()), &state())
+            .await;
+        assert!(matches!(
+            result,
+            Err(OAuth2Error::Protocol(ProtocolError::UnknownSigningKey { kid: Some(ref kid) }))
+                if kid == "rsa-3"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_kid_refetch_is_rate_limited() {
+        let validator = validator();
+
+        for kid in ["rsa-2", "rsa-3"] {
+            let result = validator
+                .validate(&sign_rs256(kid, &claims()), &state())
+                .await;
+            assert!(matches!(
+                result,
+                Err(OAuth2Error::Protocol(ProtocolError::UnknownSigningKey { .. }))
+            ));
+        }
+        assert_eq!(validator.transport.get_requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_signature() {
+        // Swap in the payload of a different token while keeping the signature
+        let mut forged_claims = claims();
+        forged_claims["sub"] = json!("admin");
+        let token = sign_rs256("rsa-1", &claims());
+        let forged = sign_rs256("rsa-1", &forged_claims);
+        let (_, signature) = token.rsplit_once('.').unwrap();
+        let (forged_signed, _) = forged.rsplit_once('.').unwrap();
+        let tampered = format!("{}.{}", forged_signed, signature);
+
+        let result = validator().validate(&tampered, &state()).await;
+        assert!(matches!(
+            result,
+            Err(OAuth2Error::Protocol(ProtocolError::InvalidSignature { .. }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_algorithm() {
+        let key = EncodingKey::from_secret(b"secret");
+        let token = jsonwebtoken::encode(&Header::new(Algorithm::HS256), &claims(), &key).unwrap();
+
+        let result = validator().validate(&token, &state()).await;
+        assert!(matches!(
+            result,
+            Err(OAuth2Error::Protocol(ProtocolError::UnsupportedAlgorithm { .. }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_issuer_mismatch() {
+        let mut claims = claims();
+        claims["iss"] = json!("https://evil.example.com");
+
+        assert!(matches!(
+            validate_claims(claims).await,
+            Err(OAuth2Error::Protocol(ProtocolError::IssuerMismatch { ref actual, .. }))
+                if actual == "https://evil.example.com"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_audience_mismatch() {
+        let mut claims = claims();
+        claims["aud"] = json!(["other-client"]);
+
+        assert!(matches!(
+            validate_claims(claims).await,
+            Err(OAuth2Error::Protocol(ProtocolError::AudienceMismatch { .. }))
+        ));
+
+        let mut claims = self::claims();
+        claims["aud"] = json!([CLIENT_ID, "other-client"]);
+        claims["azp"] = json!("other-client");
+
+        assert!(matches!(
+            validate_claims(claims).await,
+            Err(OAuth2Error::Protocol(ProtocolError::AudienceMismatch { .. }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_expired_token() {
+        let mut claims = claims();
+        claims["exp"] = json!(now() - 120);
+
+        assert!(matches!(
+            validate_claims(claims).await,
+            Err(OAuth2Error::Protocol(ProtocolError::IdTokenExpired { .. }))
+        ));
+
+        // Within the allowed clock skew
+        let mut claims = self::claims();
+        claims["exp"] = json!(now() - 30);
+        assert!(validate_claims(claims).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_token_not_yet_valid() {
+        let mut claims = claims();
+        claims["nbf"] = json!(now() + 300);
+
+        assert!(matches!(
+            validate_claims(claims).await,
+            Err(OAuth2Error::Protocol(ProtocolError::IdTokenNotYetValid { .. }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_nonce_mismatch() {
+        let mut claims = claims();
+        claims["nonce"] = json!("replayed-nonce");
+
+        assert!(matches!(
+            validate_claims(claims).await,
+            Err(OAuth2Error::Protocol(ProtocolError::NonceMismatch { ref actual, .. }))
+                if actual.as_deref() == Some("replayed-nonce")
+        ));
+
+        let mut claims = self::claims();
+        claims.as_object_mut().unwrap().remove("nonce");
+
+        assert!(matches!(
+            validate_claims(claims).await,
+            Err(OAuth2Error::Protocol(ProtocolError::NonceMismatch { actual: None, .. }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_malformed_token() {
+        let result = validator().validate("not-a-jwt", &state()).await;
+        assert!(matches!(
+            result,
+            Err(OAuth2Error::Protocol(ProtocolError::InvalidIdToken { .. }))
+        ));
+    }
+}
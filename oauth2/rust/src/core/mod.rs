@@ -6,8 +6,19 @@ pub mod transport;
 pub mod state;
 pub mod pkce;
 pub mod discovery;
+pub mod assertion;
+pub mod resource;
+#[cfg(feature = "jwt")]
+pub mod id_token;
+
+#[cfg(test)]
+pub(crate) mod test_keys;
 
 pub use transport::*;
 pub use state::*;
 pub use pkce::*;
 pub use discovery::*;
+pub use assertion::*;
+pub use resource::*;
+#[cfg(feature = "jwt")]
+pub use id_token::*;
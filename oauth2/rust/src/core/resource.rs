@@ -0,0 +1,82 @@
+//! Resource Indicators
+//!
+//! RFC 8707 - Resource Indicators for OAuth 2.0.
+
+use url::Url;
+
+use crate::error::{AuthorizationError, OAuth2Error};
+
+/// Validate a resource indicator.
+///
+/// RFC 8707 Section 2 requires an absolute URI without a fragment component.
+pub fn validate_resource_indicator(resource: &str) -> Result<(), OAuth2Error> {
+    let url = Url::parse(resource).map_err(|e| invalid_target(resource, e.to_string()))?;
+
+    if url.fragment().is_some() {
+        return Err(invalid_target(resource, "must not contain a fragment"));
+    }
+
+    Ok(())
+}
+
+/// Validate resource indicators and build one `resource` parameter for each.
+pub fn resource_params(resource: &[String]) -> Result<Vec<(&'static str, String)>, OAuth2Error> {
+    resource
+        .iter()
+        .map(|r| {
+            validate_resource_indicator(r)?;
+            Ok(("resource", r.clone()))
+        })
+        .collect()
+}
+
+fn invalid_target(resource: &str, reason: impl Into<String>) -> OAuth2Error {
+    OAuth2Error::Authorization(AuthorizationError::InvalidTarget {
+        resource: resource.to_string(),
+        reason: reason.into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_absolute_uri_accepted() {
+        assert!(validate_resource_indicator("https://api.example.com").is_ok());
+        assert!(validate_resource_indicator("https://api.example.com/v1?tenant=a").is_ok());
+        assert!(validate_resource_indicator("urn:example:resource").is_ok());
+    }
+
+    #[test]
+    fn test_relative_uri_rejected() {
+        let err = validate_resource_indicator("/api/v1").unwrap_err();
+        assert!(matches!(
+            err,
+            OAuth2Error::Authorization(AuthorizationError::InvalidTarget { .. })
+        ));
+    }
+
+    #[test]
+    fn test_fragment_rejected() {
+        assert!(validate_resource_indicator("https://api.example.com/#section").is_err());
+        assert!(validate_resource_indicator("https://api.example.com/#").is_err());
+    }
+
+    #[test]
+    fn test_resource_params_repeated() {
+        let resource = vec![
+            "https://a.example.com".to_string(),
+            "https://b.example.com".to_string(),
+        ];
+
+        let params = resource_params(&resource).unwrap();
+        assert_eq!(
+            params,
+            vec![
+                ("resource", "https://a.example.com".to_string()),
+                ("resource", "https://b.example.com".to_string()),
+            ]
+        );
+    }
+}
@@ -48,6 +48,23 @@ impl OAuth2Error {
         }
     }
 
+    /// Get error category for metric labels.
+    ///
+    /// Separates transport failures (`network`), malformed responses
+    /// (`protocol`) and OAuth2 errors returned by the server (`provider`).
+    pub fn category(&self) -> &'static str {
+        match self {
+            Self::Configuration(_) => "configuration",
+            Self::Authorization(_) => "authorization",
+            Self::Token(_) => "token",
+            Self::DeviceFlow(_) => "device_flow",
+            Self::Network(_) => "network",
+            Self::Storage(_) => "storage",
+            Self::Protocol(_) => "protocol",
+            Self::Provider(_) => "provider",
+        }
+    }
+
     /// Check if error is retryable.
     pub fn is_retryable(&self) -> bool {
         match self {
@@ -133,6 +150,9 @@ pub enum AuthorizationError {
         error_uri: Option<String>,
     },
 
+    #[error("Invalid resource indicator {resource}: {reason}")]
+    InvalidTarget { resource: String, reason: String },
+
     #[error("Server error: {message}")]
     ServerError { message: String },
 
@@ -227,6 +247,36 @@ pub enum ProtocolError {
 
     #[error("Invalid JSON: {message}")]
     InvalidJson { message: String },
+
+    #[error("Invalid ID token: {message}")]
+    InvalidIdToken { message: String },
+
+    #[error("Unsupported ID token algorithm: {alg}")]
+    UnsupportedAlgorithm { alg: String },
+
+    #[error("No signing key found for kid: {kid:?}")]
+    UnknownSigningKey { kid: Option<String> },
+
+    #[error("Invalid ID token signature: {message}")]
+    InvalidSignature { message: String },
+
+    #[error("ID token issuer mismatch: expected {expected}, got {actual}")]
+    IssuerMismatch { expected: String, actual: String },
+
+    #[error("ID token audience does not include {expected}")]
+    AudienceMismatch { expected: String, actual: Vec<String> },
+
+    #[error("ID token expired at {exp}")]
+    IdTokenExpired { exp: u64 },
+
+    #[error("ID token not valid before {nbf}")]
+    IdTokenNotYetValid { nbf: u64 },
+
+    #[error("ID token nonce mismatch")]
+    NonceMismatch {
+        expected: String,
+        actual: Option<String>,
+    },
 }
 
 /// Storage error.
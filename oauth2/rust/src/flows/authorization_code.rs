@@ -7,8 +7,13 @@ use base64::Engine;
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::core::{HttpMethod, HttpRequest, HttpTransport, StateManager};
+use crate::core::{
+    client_assertion_params, resource_params, HttpMethod, HttpRequest, HttpTransport,
+    StateManager,
+};
 use crate::error::{AuthorizationError, OAuth2Error, ProviderError};
+use crate::telemetry::metrics::GrantTimer;
+use crate::telemetry::{GrantTypeLabels, NoOpMetrics, OAuth2Metrics};
 use crate::types::{
     AuthorizationParams, AuthorizationUrl, CallbackParams, ClientAuthMethod,
     CodeExchangeRequest, OAuth2Config, TokenResponse,
@@ -19,7 +24,12 @@ use crate::error::create_error_from_response;
 #[async_trait]
 pub trait AuthorizationCodeFlow: Send + Sync {
     /// Build authorization URL for user redirect.
-    fn build_authorization_url(&self, params: AuthorizationParams) -> AuthorizationUrl;
+    ///
+    /// Fails if a resource indicator is not an absolute URI without a fragment.
+    fn build_authorization_url(
+        &self,
+        params: AuthorizationParams,
+    ) -> Result<AuthorizationUrl, OAuth2Error>;
 
     /// Exchange authorization code for tokens.
     async fn exchange_code(&self, request: CodeExchangeRequest) -> Result<TokenResponse, OAuth2Error>;
@@ -33,6 +43,7 @@ pub struct AuthorizationCodeFlowImpl<T: HttpTransport, S: StateManager> {
     config: OAuth2Config,
     transport: Arc<T>,
     state_manager: Arc<S>,
+    metrics: Arc<dyn OAuth2Metrics>,
 }
 
 impl<T: HttpTransport, S: StateManager> AuthorizationCodeFlowImpl<T, S> {
@@ -42,9 +53,16 @@ impl<T: HttpTransport, S: StateManager> AuthorizationCodeFlowImpl<T, S> {
             config,
             transport,
             state_manager,
+            metrics: Arc::new(NoOpMetrics),
         }
     }
 
+    /// Record code exchanges through the given metrics.
+    pub fn with_metrics(mut self, metrics: Arc<dyn OAuth2Metrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     fn build_auth_url_params(&self, params: &AuthorizationParams) -> HashMap<String, String> {
         let mut url_params = HashMap::new();
 
@@ -80,7 +98,7 @@ impl<T: HttpTransport, S: StateManager> AuthorizationCodeFlowImpl<T, S> {
         url_params
     }
 
-    fn build_token_request_body(&self, request: &CodeExchangeRequest) -> String {
+    fn build_token_request_body(&self, request: &CodeExchangeRequest) -> Result<String, OAuth2Error> {
         let mut params = vec![
             ("grant_type", "authorization_code".to_string()),
             ("code", request.code.clone()),
@@ -96,11 +114,17 @@ impl<T: HttpTransport, S: StateManager> AuthorizationCodeFlowImpl<T, S> {
             }
         }
 
-        params
+        // Resource indicators
+        params.extend(resource_params(&request.resource)?);
+
+        // Signed client assertion if using private_key_jwt
+        params.extend(client_assertion_params(&self.config)?);
+
+        Ok(params
             .into_iter()
             .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(&v)))
             .collect::<Vec<_>>()
-            .join("&")
+            .join("&"))
     }
 
     fn build_token_request_headers(&self) -> HashMap<String, String> {
@@ -124,11 +148,44 @@ impl<T: HttpTransport, S: StateManager> AuthorizationCodeFlowImpl<T, S> {
 
         headers
     }
+
+    async fn send_token_request(
+        &self,
+        request: &CodeExchangeRequest,
+    ) -> Result<TokenResponse, OAuth2Error> {
+        let body = self.build_token_request_body(request)?;
+        let headers = self.build_token_request_headers();
+
+        let http_request = HttpRequest {
+            method: HttpMethod::Post,
+            url: self.config.provider.token_endpoint.clone(),
+            headers,
+            body: Some(body),
+            timeout: Some(self.config.timeout),
+        };
+
+        let response = self.transport.send(http_request).await?;
+
+        if response.status != 200 {
+            return Err(create_error_from_response(response.status, &response.body));
+        }
+
+        let token_response: TokenResponse = serde_json::from_str(&response.body)
+            .map_err(|e| OAuth2Error::Protocol(crate::error::ProtocolError::InvalidJson {
+                message: e.to_string(),
+            }))?;
+
+        Ok(token_response)
+    }
 }
 
 #[async_trait]
 impl<T: HttpTransport, S: StateManager> AuthorizationCodeFlow for AuthorizationCodeFlowImpl<T, S> {
-    fn build_authorization_url(&self, params: AuthorizationParams) -> AuthorizationUrl {
+    fn build_authorization_url(
+        &self,
+        params: AuthorizationParams,
+    ) -> Result<AuthorizationUrl, OAuth2Error> {
+        let resource = resource_params(&params.resource)?;
         let mut url_params = self.build_auth_url_params(&params);
 
         // Generate state if not provided
@@ -136,48 +193,35 @@ impl<T: HttpTransport, S: StateManager> AuthorizationCodeFlow for AuthorizationC
             let metadata = crate::types::StateMetadata::new(
                 params.redirect_uri.clone(),
                 params.scopes.clone().unwrap_or_else(|| self.config.default_scopes.clone()),
-            );
+            )
+            .with_resource(params.resource.clone());
             self.state_manager.generate(metadata)
         });
 
         url_params.insert("state".to_string(), state.clone());
 
-        // Build URL
+        // Build URL; resource indicators are repeated, so they follow the map
         let query = url_params
             .into_iter()
+            .chain(resource.into_iter().map(|(k, v)| (k.to_string(), v)))
             .map(|(k, v)| format!("{}={}", urlencoding::encode(&k), urlencoding::encode(&v)))
             .collect::<Vec<_>>()
             .join("&");
 
         let url = format!("{}?{}", self.config.provider.authorization_endpoint, query);
 
-        AuthorizationUrl { url, state }
+        Ok(AuthorizationUrl { url, state })
     }
 
     async fn exchange_code(&self, request: CodeExchangeRequest) -> Result<TokenResponse, OAuth2Error> {
-        let body = self.build_token_request_body(&request);
-        let headers = self.build_token_request_headers();
-
-        let http_request = HttpRequest {
-            method: HttpMethod::Post,
-            url: self.config.provider.token_endpoint.clone(),
-            headers,
-            body: Some(body),
-            timeout: Some(self.config.timeout),
-        };
-
-        let response = self.transport.send(http_request).await?;
-
-        if response.status != 200 {
-            return Err(create_error_from_response(response.status, &response.body));
-        }
-
-        let token_response: TokenResponse = serde_json::from_str(&response.body)
-            .map_err(|e| OAuth2Error::Protocol(crate::error::ProtocolError::InvalidJson {
-                message: e.to_string(),
-            }))?;
-
-        Ok(token_response)
+        let timer = GrantTimer::start(
+            self.metrics.as_ref(),
+            &self.config,
+            GrantTypeLabels::AUTHORIZATION_CODE,
+        );
+        let result = self.send_token_request(&request).await;
+        timer.finish(&result);
+        result
     }
 
     async fn handle_callback(&self, callback: CallbackParams) -> Result<TokenResponse, OAuth2Error> {
@@ -217,6 +261,7 @@ impl<T: HttpTransport, S: StateManager> AuthorizationCodeFlow for AuthorizationC
             code,
             redirect_uri: metadata.redirect_uri,
             state: Some(state),
+            resource: metadata.resource,
         })
         .await
     }
@@ -263,12 +308,15 @@ impl MockAuthorizationCodeFlow {
 
 #[async_trait]
 impl AuthorizationCodeFlow for MockAuthorizationCodeFlow {
-    fn build_authorization_url(&self, params: AuthorizationParams) -> AuthorizationUrl {
+    fn build_authorization_url(
+        &self,
+        params: AuthorizationParams,
+    ) -> Result<AuthorizationUrl, OAuth2Error> {
         self.build_url_history.lock().unwrap().push(params.clone());
-        AuthorizationUrl {
+        Ok(AuthorizationUrl {
             url: format!("https://mock.example.com/authorize?redirect_uri={}", params.redirect_uri),
             state: "mock-state".to_string(),
-        }
+        })
     }
 
     async fn exchange_code(&self, request: CodeExchangeRequest) -> Result<TokenResponse, OAuth2Error> {
@@ -307,6 +355,7 @@ impl AuthorizationCodeFlow for MockAuthorizationCodeFlow {
             code: callback.code.unwrap_or_default(),
             redirect_uri: "https://example.com/callback".to_string(),
             state: callback.state,
+            resource: Vec::new(),
         })
         .await
     }
@@ -320,6 +369,7 @@ pub fn create_mock_authorization_code_flow() -> MockAuthorizationCodeFlow {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{InMemoryStateManager, MockHttpTransport};
 
     #[tokio::test]
     async fn test_mock_authorization_code_flow() {
@@ -331,7 +381,7 @@ mod tests {
             ..Default::default()
         };
 
-        let url = flow.build_authorization_url(params);
+        let url = flow.build_authorization_url(params).unwrap();
         assert!(url.url.contains("mock.example.com"));
         assert_eq!(url.state, "mock-state");
 
@@ -347,10 +397,104 @@ mod tests {
             code: "test-code".to_string(),
             redirect_uri: "https://example.com/callback".to_string(),
             state: Some("test-state".to_string()),
+            resource: Vec::new(),
         };
 
         let response = flow.exchange_code(request).await.unwrap();
         assert_eq!(response.access_token, "mock-access-token");
         assert_eq!(response.token_type, "Bearer");
     }
+
+    const API_A: &str = "https://api-a.example.com";
+    const API_B: &str = "https://api-b.example.com/v1";
+
+    fn resource_flow(
+        transport: Arc<MockHttpTransport>,
+    ) -> AuthorizationCodeFlowImpl<MockHttpTransport, InMemoryStateManager> {
+        let mut config = OAuth2Config::default();
+        config.provider.authorization_endpoint = "https://auth.example.com/authorize".to_string();
+        config.provider.token_endpoint = "https://auth.example.com/token".to_string();
+        config.credentials.client_id = "client".to_string();
+        config.credentials.auth_method = ClientAuthMethod::None;
+
+        AuthorizationCodeFlowImpl::new(config, transport, Arc::new(InMemoryStateManager::new()))
+    }
+
+    fn resources() -> Vec<String> {
+        vec![API_A.to_string(), API_B.to_string()]
+    }
+
+    #[tokio::test]
+    async fn test_resource_indicators_in_authorization_url_and_token_request() {
+        let transport = Arc::new(MockHttpTransport::new());
+        transport.queue_json_response(
+            200,
+            &serde_json::json!({"access_token": "audience-token", "token_type": "Bearer"}),
+        );
+        let flow = resource_flow(transport.clone());
+
+        let auth_url = flow
+            .build_authorization_url(AuthorizationParams {
+                redirect_uri: "https://app.example.com/callback".to_string(),
+                resource: resources(),
+                ..Default::default()
+            })
+            .unwrap();
+
+        let url = url::Url::parse(&auth_url.url).unwrap();
+        let sent: Vec<String> = url
+            .query_pairs()
+            .filter(|(k, _)| k == "resource")
+            .map(|(_, v)| v.into_owned())
+            .collect();
+        assert_eq!(sent, resources());
+
+        // The resources requested at authorization are sent again on exchange
+        flow.handle_callback(CallbackParams {
+            code: Some("auth-code".to_string()),
+            state: Some(auth_url.state),
+            error: None,
+            error_description: None,
+            error_uri: None,
+        })
+        .await
+        .unwrap();
+
+        let request = transport.get_last_request().unwrap();
+        let sent: Vec<String> = url::form_urlencoded::parse(request.body.unwrap().as_bytes())
+            .filter(|(k, _)| k == "resource")
+            .map(|(_, v)| v.into_owned())
+            .collect();
+        assert_eq!(sent, resources());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_resource_indicator_rejected() {
+        let transport = Arc::new(MockHttpTransport::new());
+        let flow = resource_flow(transport.clone());
+
+        let result = flow.build_authorization_url(AuthorizationParams {
+            redirect_uri: "https://app.example.com/callback".to_string(),
+            resource: vec!["https://api.example.com/#fragment".to_string()],
+            ..Default::default()
+        });
+        assert!(matches!(
+            result,
+            Err(OAuth2Error::Authorization(AuthorizationError::InvalidTarget { .. }))
+        ));
+
+        let result = flow
+            .exchange_code(CodeExchangeRequest {
+                code: "auth-code".to_string(),
+                redirect_uri: "https://app.example.com/callback".to_string(),
+                state: None,
+                resource: vec!["api/v1".to_string()],
+            })
+            .await;
+        assert!(matches!(
+            result,
+            Err(OAuth2Error::Authorization(AuthorizationError::InvalidTarget { .. }))
+        ));
+        assert!(transport.get_requests().is_empty());
+    }
 }
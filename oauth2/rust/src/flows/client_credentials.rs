@@ -7,8 +7,12 @@ use base64::Engine;
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::core::{HttpMethod, HttpRequest, HttpTransport};
+use crate::core::{
+    client_assertion_params, resource_params, HttpMethod, HttpRequest, HttpTransport,
+};
 use crate::error::{OAuth2Error, ProtocolError};
+use crate::telemetry::metrics::GrantTimer;
+use crate::telemetry::{GrantTypeLabels, NoOpMetrics, OAuth2Metrics};
 use crate::types::{ClientAuthMethod, OAuth2Config, TokenResponse};
 use crate::error::create_error_from_response;
 
@@ -17,6 +21,8 @@ use crate::error::create_error_from_response;
 pub struct ClientCredentialsRequest {
     /// Requested scopes.
     pub scopes: Option<Vec<String>>,
+    /// Resource indicators (RFC 8707) the token is requested for.
+    pub resource: Vec<String>,
     /// Additional parameters.
     pub extra_params: HashMap<String, String>,
 }
@@ -35,15 +41,26 @@ pub trait ClientCredentialsFlow: Send + Sync {
 pub struct ClientCredentialsFlowImpl<T: HttpTransport> {
     config: OAuth2Config,
     transport: Arc<T>,
+    metrics: Arc<dyn OAuth2Metrics>,
 }
 
 impl<T: HttpTransport> ClientCredentialsFlowImpl<T> {
     /// Create new Client Credentials Flow.
     pub fn new(config: OAuth2Config, transport: Arc<T>) -> Self {
-        Self { config, transport }
+        Self {
+            config,
+            transport,
+            metrics: Arc::new(NoOpMetrics),
+        }
+    }
+
+    /// Record token grants through the given metrics.
+    pub fn with_metrics(mut self, metrics: Arc<dyn OAuth2Metrics>) -> Self {
+        self.metrics = metrics;
+        self
     }
 
-    fn build_request_body(&self, request: &ClientCredentialsRequest) -> String {
+    fn build_request_body(&self, request: &ClientCredentialsRequest) -> Result<String, OAuth2Error> {
         let mut params = vec![("grant_type", "client_credentials".to_string())];
 
         // Scopes
@@ -66,16 +83,22 @@ impl<T: HttpTransport> ClientCredentialsFlowImpl<T> {
             }
         }
 
+        // Resource indicators
+        params.extend(resource_params(&request.resource)?);
+
         // Extra parameters
         for (key, value) in &request.extra_params {
             params.push((key.as_str(), value.clone()));
         }
 
-        params
+        // Signed client assertion if using private_key_jwt
+        params.extend(client_assertion_params(&self.config)?);
+
+        Ok(params
             .into_iter()
             .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(&v)))
             .collect::<Vec<_>>()
-            .join("&")
+            .join("&"))
     }
 
     fn build_request_headers(&self) -> HashMap<String, String> {
@@ -102,15 +125,12 @@ impl<T: HttpTransport> ClientCredentialsFlowImpl<T> {
 
         headers
     }
-}
 
-#[async_trait]
-impl<T: HttpTransport> ClientCredentialsFlow for ClientCredentialsFlowImpl<T> {
-    async fn request_token(
+    async fn send_token_request(
         &self,
-        request: ClientCredentialsRequest,
+        request: &ClientCredentialsRequest,
     ) -> Result<TokenResponse, OAuth2Error> {
-        let body = self.build_request_body(&request);
+        let body = self.build_request_body(request)?;
         let headers = self.build_request_headers();
 
         let http_request = HttpRequest {
@@ -137,6 +157,23 @@ impl<T: HttpTransport> ClientCredentialsFlow for ClientCredentialsFlowImpl<T> {
     }
 }
 
+#[async_trait]
+impl<T: HttpTransport> ClientCredentialsFlow for ClientCredentialsFlowImpl<T> {
+    async fn request_token(
+        &self,
+        request: ClientCredentialsRequest,
+    ) -> Result<TokenResponse, OAuth2Error> {
+        let timer = GrantTimer::start(
+            self.metrics.as_ref(),
+            &self.config,
+            GrantTypeLabels::CLIENT_CREDENTIALS,
+        );
+        let result = self.send_token_request(&request).await;
+        timer.finish(&result);
+        result
+    }
+}
+
 /// Mock Client Credentials Flow for testing.
 #[derive(Default)]
 pub struct MockClientCredentialsFlow {
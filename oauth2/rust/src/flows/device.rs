@@ -7,15 +7,21 @@ use base64::Engine;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::time::Instant;
 
-use crate::core::{HttpMethod, HttpRequest, HttpTransport};
+use crate::core::{client_assertion_params, HttpMethod, HttpRequest, HttpTransport};
 use crate::error::{DeviceFlowError, OAuth2Error, ProtocolError};
+use crate::telemetry::metrics::GrantTimer;
+use crate::telemetry::{GrantTypeLabels, NoOpMetrics, OAuth2Metrics};
 use crate::types::{
     ClientAuthMethod, DeviceAuthorizationResponse, DeviceCodeParams, DeviceTokenResult,
     OAuth2Config, TokenResponse,
 };
 use crate::error::create_error_from_response;
 
+/// Interval increase required after a `slow_down` response (RFC 8628 section 3.5).
+const SLOW_DOWN_INCREMENT: Duration = Duration::from_secs(5);
+
 /// Device Authorization Flow interface.
 #[async_trait]
 pub trait DeviceAuthorizationFlow: Send + Sync {
@@ -25,31 +31,88 @@ pub trait DeviceAuthorizationFlow: Send + Sync {
         params: DeviceCodeParams,
     ) -> Result<DeviceAuthorizationResponse, OAuth2Error>;
 
-    /// Poll for token using device code.
-    async fn poll_for_token(
+    /// Poll the token endpoint once using device code.
+    ///
+    /// `interval` is the interval the caller is currently polling at, used to
+    /// compute the new interval when the server asks to slow down.
+    async fn poll_once(
         &self,
         device_code: &str,
+        interval: Duration,
     ) -> Result<DeviceTokenResult, OAuth2Error>;
 
-    /// Poll for token with automatic retry until completion or expiration.
-    async fn poll_until_complete(
+    /// Poll for token until the user completes authorization.
+    ///
+    /// Waits `interval` between polls, adding 5 seconds each time the server
+    /// responds with `slow_down`, and keeps waiting on `authorization_pending`.
+    /// Gives up with `PollingTimeout` once the next poll would fall after
+    /// `expires_in`, the lifetime of the device code.
+    async fn poll_for_token(
         &self,
         device_code: &str,
         interval: Duration,
         expires_in: Duration,
-    ) -> Result<TokenResponse, OAuth2Error>;
+    ) -> Result<TokenResponse, OAuth2Error> {
+        let start = Instant::now();
+        let deadline = start + expires_in;
+        let mut current_interval = interval;
+
+        loop {
+            let next_poll = Instant::now() + current_interval;
+            if next_poll > deadline {
+                return Err(OAuth2Error::DeviceFlow(DeviceFlowError::PollingTimeout {
+                    elapsed: start.elapsed(),
+                }));
+            }
+
+            // Wait before polling
+            tokio::time::sleep_until(next_poll).await;
+
+            match self.poll_once(device_code, current_interval).await? {
+                DeviceTokenResult::Success(token) => return Ok(token),
+                DeviceTokenResult::Pending => {
+                    // Continue polling at current interval
+                    continue;
+                }
+                DeviceTokenResult::SlowDown { .. } => {
+                    current_interval += SLOW_DOWN_INCREMENT;
+                    continue;
+                }
+                DeviceTokenResult::AccessDenied => {
+                    return Err(OAuth2Error::DeviceFlow(DeviceFlowError::AccessDenied));
+                }
+                DeviceTokenResult::Expired => {
+                    return Err(OAuth2Error::DeviceFlow(DeviceFlowError::ExpiredToken));
+                }
+            }
+        }
+    }
 }
 
 /// Device Authorization Flow implementation.
 pub struct DeviceAuthorizationFlowImpl<T: HttpTransport> {
     config: OAuth2Config,
     transport: Arc<T>,
+    metrics: Arc<dyn OAuth2Metrics>,
 }
 
 impl<T: HttpTransport> DeviceAuthorizationFlowImpl<T> {
     /// Create new Device Authorization Flow.
     pub fn new(config: OAuth2Config, transport: Arc<T>) -> Self {
-        Self { config, transport }
+        Self {
+            config,
+            transport,
+            metrics: Arc::new(NoOpMetrics),
+        }
+    }
+
+    /// Record token polls through the given metrics.
+    ///
+    /// Every poll counts as a token request; `authorization_pending` and
+    /// `slow_down` responses record latency but no grant outcome.
+    pub fn with_metrics(mut self, metrics: Arc<dyn OAuth2Metrics>) -> Self {
+        self.metrics = metrics;
+        self
     }
 
     fn build_device_code_request_body(&self, params: &DeviceCodeParams) -> String {
@@ -81,7 +144,7 @@ impl<T: HttpTransport> DeviceAuthorizationFlowImpl<T> {
             .join("&")
     }
 
-    fn build_token_request_body(&self, device_code: &str) -> String {
+    fn build_token_request_body(&self, device_code: &str) -> Result<String, OAuth2Error> {
         let mut params = vec![
             ("grant_type", "urn:ietf:params:oauth:grant-type:device_code".to_string()),
             ("device_code", device_code.to_string()),
@@ -96,11 +159,14 @@ impl<T: HttpTransport> DeviceAuthorizationFlowImpl<T> {
             }
         }
 
-        params
+        // Signed client assertion if using private_key_jwt
+        params.extend(client_assertion_params(&self.config)?);
+
+        Ok(params
             .into_iter()
             .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(&v)))
             .collect::<Vec<_>>()
-            .join("&")
+            .join("&"))
     }
 
     fn build_request_headers(&self) -> HashMap<String, String> {
@@ -128,35 +194,68 @@ impl<T: HttpTransport> DeviceAuthorizationFlowImpl<T> {
         headers
     }
 
-    fn parse_poll_error(&self, body: &str) -> DeviceTokenResult {
+    fn parse_poll_error(
+        &self,
+        body: &str,
+        interval: Duration,
+    ) -> Result<DeviceTokenResult, OAuth2Error> {
         // Try to parse as OAuth error response
         #[derive(serde::Deserialize)]
         struct ErrorResponse {
             error: String,
-            #[serde(default)]
-            error_description: Option<String>,
         }
 
-        if let Ok(error_resp) = serde_json::from_str::<ErrorResponse>(body) {
-            match error_resp.error.as_str() {
-                "authorization_pending" => DeviceTokenResult::Pending,
-                "slow_down" => DeviceTokenResult::SlowDown,
-                "access_denied" => DeviceTokenResult::AccessDenied {
-                    error_description: error_resp.error_description,
-                },
-                "expired_token" => DeviceTokenResult::Expired,
-                _ => DeviceTokenResult::Error(OAuth2Error::DeviceFlow(
-                    DeviceFlowError::DeviceCodeError {
-                        error: error_resp.error,
-                        error_description: error_resp.error_description,
-                    },
-                )),
-            }
-        } else {
-            DeviceTokenResult::Error(OAuth2Error::Protocol(ProtocolError::InvalidJson {
+        let error_resp = serde_json::from_str::<ErrorResponse>(body).map_err(|_| {
+            OAuth2Error::Protocol(ProtocolError::InvalidJson {
                 message: "Failed to parse error response".to_string(),
-            }))
+            })
+        })?;
+
+        match error_resp.error.as_str() {
+            "authorization_pending" => Ok(DeviceTokenResult::Pending),
+            "slow_down" => Ok(DeviceTokenResult::SlowDown {
+                new_interval: (interval + SLOW_DOWN_INCREMENT).as_secs(),
+            }),
+            "access_denied" => Ok(DeviceTokenResult::AccessDenied),
+            "expired_token" => Ok(DeviceTokenResult::Expired),
+            _ => Err(create_error_from_response(400, body)),
+        }
+    }
+
+    async fn send_poll_request(
+        &self,
+        device_code: &str,
+        interval: Duration,
+    ) -> Result<DeviceTokenResult, OAuth2Error> {
+        let body = self.build_token_request_body(device_code)?;
+        let headers = self.build_request_headers();
+
+        let http_request = HttpRequest {
+            method: HttpMethod::Post,
+            url: self.config.provider.token_endpoint.clone(),
+            headers,
+            body: Some(body),
+            timeout: Some(self.config.timeout),
+        };
+
+        let response = self.transport.send(http_request).await?;
+
+        if response.status == 200 {
+            let token_response: TokenResponse =
+                serde_json::from_str(&response.body).map_err(|e| {
+                    OAuth2Error::Protocol(ProtocolError::InvalidJson {
+                        message: e.to_string(),
+                    })
+                })?;
+            return Ok(DeviceTokenResult::Success(token_response));
         }
+
+        // Handle polling errors (400 status is expected during polling)
+        if response.status == 400 {
+            return self.parse_poll_error(&response.body, interval);
+        }
+
+        Err(create_error_from_response(response.status, &response.body))
     }
 }
 
@@ -203,79 +302,23 @@ impl<T: HttpTransport> DeviceAuthorizationFlow for DeviceAuthorizationFlowImpl<T
         Ok(device_response)
     }
 
-    async fn poll_for_token(&self, device_code: &str) -> Result<DeviceTokenResult, OAuth2Error> {
-        let body = self.build_token_request_body(device_code);
-        let headers = self.build_request_headers();
-
-        let http_request = HttpRequest {
-            method: HttpMethod::Post,
-            url: self.config.provider.token_endpoint.clone(),
-            headers,
-            body: Some(body),
-            timeout: Some(self.config.timeout),
-        };
-
-        let response = self.transport.send(http_request).await?;
-
-        if response.status == 200 {
-            let token_response: TokenResponse =
-                serde_json::from_str(&response.body).map_err(|e| {
-                    OAuth2Error::Protocol(ProtocolError::InvalidJson {
-                        message: e.to_string(),
-                    })
-                })?;
-            return Ok(DeviceTokenResult::Success(token_response));
-        }
-
-        // Handle polling errors (400 status is expected during polling)
-        if response.status == 400 {
-            return Ok(self.parse_poll_error(&response.body));
-        }
-
-        Err(create_error_from_response(response.status, &response.body))
-    }
-
-    async fn poll_until_complete(
+    async fn poll_once(
         &self,
         device_code: &str,
         interval: Duration,
-        expires_in: Duration,
-    ) -> Result<TokenResponse, OAuth2Error> {
-        let start = std::time::Instant::now();
-        let mut current_interval = interval;
-
-        loop {
-            // Check for expiration
-            if start.elapsed() >= expires_in {
-                return Err(OAuth2Error::DeviceFlow(DeviceFlowError::DeviceCodeExpired));
+    ) -> Result<DeviceTokenResult, OAuth2Error> {
+        let timer = GrantTimer::start(self.metrics.as_ref(), &self.config, GrantTypeLabels::DEVICE);
+        let result = self.send_poll_request(device_code, interval).await;
+        match &result {
+            Ok(DeviceTokenResult::Pending) | Ok(DeviceTokenResult::SlowDown { .. }) => {
+                timer.finish_pending()
             }
-
-            // Wait before polling
-            tokio::time::sleep(current_interval).await;
-
-            match self.poll_for_token(device_code).await? {
-                DeviceTokenResult::Success(token) => return Ok(token),
-                DeviceTokenResult::Pending => {
-                    // Continue polling at current interval
-                    continue;
-                }
-                DeviceTokenResult::SlowDown => {
-                    // Increase interval by 5 seconds as per RFC 8628
-                    current_interval += Duration::from_secs(5);
-                    continue;
-                }
-                DeviceTokenResult::AccessDenied { error_description } => {
-                    return Err(OAuth2Error::DeviceFlow(DeviceFlowError::DeviceCodeError {
-                        error: "access_denied".to_string(),
-                        error_description,
-                    }));
-                }
-                DeviceTokenResult::Expired => {
-                    return Err(OAuth2Error::DeviceFlow(DeviceFlowError::DeviceCodeExpired));
-                }
-                DeviceTokenResult::Error(e) => return Err(e),
+            Ok(DeviceTokenResult::AccessDenied) | Ok(DeviceTokenResult::Expired) => {
+                timer.finish_with(Some("device_flow"))
             }
+            _ => timer.finish(&result),
         }
+        result
     }
 }
 
@@ -365,7 +408,11 @@ impl DeviceAuthorizationFlow for MockDeviceAuthorizationFlow {
         })
     }
 
-    async fn poll_for_token(&self, device_code: &str) -> Result<DeviceTokenResult, OAuth2Error> {
+    async fn poll_once(
+        &self,
+        device_code: &str,
+        _interval: Duration,
+    ) -> Result<DeviceTokenResult, OAuth2Error> {
         self.poll_history
             .lock()
             .unwrap()
@@ -399,43 +446,6 @@ impl DeviceAuthorizationFlow for MockDeviceAuthorizationFlow {
 
         Ok(DeviceTokenResult::Pending)
     }
-
-    async fn poll_until_complete(
-        &self,
-        device_code: &str,
-        interval: Duration,
-        expires_in: Duration,
-    ) -> Result<TokenResponse, OAuth2Error> {
-        let start = std::time::Instant::now();
-        let mut current_interval = interval;
-
-        loop {
-            if start.elapsed() >= expires_in {
-                return Err(OAuth2Error::DeviceFlow(DeviceFlowError::DeviceCodeExpired));
-            }
-
-            tokio::time::sleep(current_interval).await;
-
-            match self.poll_for_token(device_code).await? {
-                DeviceTokenResult::Success(token) => return Ok(token),
-                DeviceTokenResult::Pending => continue,
-                DeviceTokenResult::SlowDown => {
-                    current_interval += Duration::from_secs(5);
-                    continue;
-                }
-                DeviceTokenResult::AccessDenied { error_description } => {
-                    return Err(OAuth2Error::DeviceFlow(DeviceFlowError::DeviceCodeError {
-                        error: "access_denied".to_string(),
-                        error_description,
-                    }));
-                }
-                DeviceTokenResult::Expired => {
-                    return Err(OAuth2Error::DeviceFlow(DeviceFlowError::DeviceCodeExpired));
-                }
-                DeviceTokenResult::Error(e) => return Err(e),
-            }
-        }
-    }
 }
 
 /// Create mock Device Authorization Flow for testing.
@@ -446,6 +456,123 @@ pub fn create_mock_device_authorization_flow() -> MockDeviceAuthorizationFlow {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{HttpResponse, MockHttpTransport};
+
+    /// Transport that records the (paused) clock time of every poll.
+    struct TimedTransport {
+        inner: MockHttpTransport,
+        polled_at: std::sync::Mutex<Vec<Instant>>,
+    }
+
+    #[async_trait]
+    impl HttpTransport for TimedTransport {
+        async fn send(&self, request: HttpRequest) -> Result<HttpResponse, OAuth2Error> {
+            self.polled_at.lock().unwrap().push(Instant::now());
+            self.inner.send(request).await
+        }
+    }
+
+    fn timed_flow() -> (
+        DeviceAuthorizationFlowImpl<TimedTransport>,
+        Arc<TimedTransport>,
+    ) {
+        let mut config = OAuth2Config::default();
+        config.provider.token_endpoint = "https://auth.example.com/token".to_string();
+        config.credentials.client_id = "client".to_string();
+        config.credentials.auth_method = ClientAuthMethod::None;
+
+        let transport = Arc::new(TimedTransport {
+            inner: MockHttpTransport::new(),
+            polled_at: std::sync::Mutex::new(Vec::new()),
+        });
+        (
+            DeviceAuthorizationFlowImpl::new(config, transport.clone()),
+            transport,
+        )
+    }
+
+    fn poll_error(error: &str) -> serde_json::Value {
+        serde_json::json!({ "error": error })
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_poll_for_token_pending_then_slow_down_then_success() {
+        let (flow, transport) = timed_flow();
+        // Responses are popped from the back of the queue
+        transport.inner.queue_json_response(
+            200,
+            &serde_json::json!({
+                "access_token": "device-token",
+                "token_type": "Bearer",
+                "expires_in": 3600
+            }),
+        );
+        transport.inner.queue_json_response(400, &poll_error("slow_down"));
+        transport.inner.queue_json_response(400, &poll_error("authorization_pending"));
+
+        let start = Instant::now();
+        let token = flow
+            .poll_for_token("device-code", Duration::from_secs(5), Duration::from_secs(600))
+            .await
+            .unwrap();
+        assert_eq!(token.access_token, "device-token");
+
+        let offsets: Vec<u64> = transport
+            .polled_at
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|at| (*at - start).as_secs())
+            .collect();
+        // Pending keeps the interval, slow_down adds 5 seconds to it
+        assert_eq!(offsets, vec![5, 10, 20]);
+
+        let request = transport.inner.get_last_request().unwrap();
+        let body = request.body.unwrap();
+        assert!(body.contains("device_code=device-code"));
+        assert!(body.contains("grant_type=urn%3Aietf%3Aparams%3Aoauth%3Agrant-type%3Adevice_code"));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_poll_for_token_stops_at_expiry() {
+        let (flow, transport) = timed_flow();
+        transport.inner.set_default_response(HttpResponse {
+            status: 400,
+            status_text: "Bad Request".to_string(),
+            headers: HashMap::new(),
+            body: poll_error("authorization_pending").to_string(),
+        });
+
+        let err = flow
+            .poll_for_token("device-code", Duration::from_secs(5), Duration::from_secs(12))
+            .await
+            .unwrap_err();
+
+        match err {
+            OAuth2Error::DeviceFlow(DeviceFlowError::PollingTimeout { elapsed }) => {
+                assert_eq!(elapsed, Duration::from_secs(10));
+            }
+            other => panic!("Expected polling timeout, got {:?}", other),
+        }
+        // A third poll at 15s would fall after the 12s lifetime
+        assert_eq!(transport.inner.get_requests().len(), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_poll_for_token_access_denied() {
+        let (flow, transport) = timed_flow();
+        transport.inner.queue_json_response(400, &poll_error("access_denied"));
+
+        let err = flow
+            .poll_for_token("device-code", Duration::from_secs(5), Duration::from_secs(600))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            OAuth2Error::DeviceFlow(DeviceFlowError::AccessDenied)
+        ));
+    }
 
     #[tokio::test]
     async fn test_mock_device_code_request() {
@@ -469,7 +596,7 @@ mod tests {
     async fn test_mock_poll_pending() {
         let flow = MockDeviceAuthorizationFlow::new();
 
-        let result = flow.poll_for_token("test-device-code").await.unwrap();
+        let result = flow.poll_once("test-device-code", Duration::from_secs(5)).await.unwrap();
         assert!(matches!(result, DeviceTokenResult::Pending));
 
         let history = flow.get_poll_history();
@@ -483,11 +610,11 @@ mod tests {
         flow.set_polls_until_success(2);
 
         // First poll should be pending
-        let result = flow.poll_for_token("test-code").await.unwrap();
+        let result = flow.poll_once("test-code", Duration::from_secs(5)).await.unwrap();
         assert!(matches!(result, DeviceTokenResult::Pending));
 
         // Second poll should succeed
-        let result = flow.poll_for_token("test-code").await.unwrap();
+        let result = flow.poll_once("test-code", Duration::from_secs(5)).await.unwrap();
         match result {
             DeviceTokenResult::Success(token) => {
                 assert_eq!(token.access_token, "mock-device-token");
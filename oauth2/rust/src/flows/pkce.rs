@@ -7,8 +7,13 @@ use base64::Engine;
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::core::{HttpMethod, HttpRequest, HttpTransport, PkceGenerator, StateManager};
+use crate::core::{
+    client_assertion_params, resource_params, HttpMethod, HttpRequest, HttpTransport,
+    PkceGenerator, StateManager,
+};
 use crate::error::{AuthorizationError, OAuth2Error};
+use crate::telemetry::metrics::GrantTimer;
+use crate::telemetry::{GrantTypeLabels, NoOpMetrics, OAuth2Metrics};
 use crate::types::{
     CallbackParams, ClientAuthMethod, CodeExchangeRequest, OAuth2Config,
     PkceAuthorizationParams, PkceAuthorizationUrl, PkceMethod, PkceParams, TokenResponse,
@@ -19,7 +24,12 @@ use crate::error::create_error_from_response;
 #[async_trait]
 pub trait PkceAuthorizationCodeFlow: Send + Sync {
     /// Build authorization URL with PKCE challenge.
-    fn build_authorization_url(&self, params: PkceAuthorizationParams) -> PkceAuthorizationUrl;
+    ///
+    /// Fails if a resource indicator is not an absolute URI without a fragment.
+    fn build_authorization_url(
+        &self,
+        params: PkceAuthorizationParams,
+    ) -> Result<PkceAuthorizationUrl, OAuth2Error>;
 
     /// Exchange authorization code for tokens with PKCE verifier.
     async fn exchange_code(
@@ -42,6 +52,7 @@ pub struct PkceAuthorizationCodeFlowImpl<T: HttpTransport, S: StateManager, P: P
     transport: Arc<T>,
     state_manager: Arc<S>,
     pkce_generator: Arc<P>,
+    metrics: Arc<dyn OAuth2Metrics>,
 }
 
 impl<T: HttpTransport, S: StateManager, P: PkceGenerator> PkceAuthorizationCodeFlowImpl<T, S, P> {
@@ -57,9 +68,16 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator> PkceAuthorizationCodeF
             transport,
             state_manager,
             pkce_generator,
+            metrics: Arc::new(NoOpMetrics),
         }
     }
 
+    /// Record code exchanges through the given metrics.
+    pub fn with_metrics(mut self, metrics: Arc<dyn OAuth2Metrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     /// Generate PKCE parameters.
     pub fn generate_pkce(&self, method: PkceMethod) -> PkceParams {
         self.pkce_generator.generate(method)
@@ -114,7 +132,7 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator> PkceAuthorizationCodeF
         url_params
     }
 
-    fn build_token_request_body(&self, request: &CodeExchangeRequest, code_verifier: &str) -> String {
+    fn build_token_request_body(&self, request: &CodeExchangeRequest, code_verifier: &str) -> Result<String, OAuth2Error> {
         let mut params = vec![
             ("grant_type", "authorization_code".to_string()),
             ("code", request.code.clone()),
@@ -131,11 +149,17 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator> PkceAuthorizationCodeF
             }
         }
 
-        params
+        // Resource indicators
+        params.extend(resource_params(&request.resource)?);
+
+        // Signed client assertion if using private_key_jwt
+        params.extend(client_assertion_params(&self.config)?);
+
+        Ok(params
             .into_iter()
             .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(&v)))
             .collect::<Vec<_>>()
-            .join("&")
+            .join("&"))
     }
 
     fn build_token_request_headers(&self) -> HashMap<String, String> {
@@ -162,13 +186,49 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator> PkceAuthorizationCodeF
 
         headers
     }
+
+    async fn send_token_request(
+        &self,
+        request: &CodeExchangeRequest,
+        code_verifier: &str,
+    ) -> Result<TokenResponse, OAuth2Error> {
+        let body = self.build_token_request_body(request, code_verifier)?;
+        let headers = self.build_token_request_headers();
+
+        let http_request = HttpRequest {
+            method: HttpMethod::Post,
+            url: self.config.provider.token_endpoint.clone(),
+            headers,
+            body: Some(body),
+            timeout: Some(self.config.timeout),
+        };
+
+        let response = self.transport.send(http_request).await?;
+
+        if response.status != 200 {
+            return Err(create_error_from_response(response.status, &response.body));
+        }
+
+        let token_response: TokenResponse = serde_json::from_str(&response.body).map_err(|e| {
+            OAuth2Error::Protocol(crate::error::ProtocolError::InvalidJson {
+                message: e.to_string(),
+            })
+        })?;
+
+        Ok(token_response)
+    }
 }
 
 #[async_trait]
 impl<T: HttpTransport, S: StateManager, P: PkceGenerator> PkceAuthorizationCodeFlow
     for PkceAuthorizationCodeFlowImpl<T, S, P>
 {
-    fn build_authorization_url(&self, params: PkceAuthorizationParams) -> PkceAuthorizationUrl {
+    fn build_authorization_url(
+        &self,
+        params: PkceAuthorizationParams,
+    ) -> Result<PkceAuthorizationUrl, OAuth2Error> {
+        let resource = resource_params(&params.resource)?;
+
         // Generate PKCE parameters
         let pkce_method = params.pkce_method.unwrap_or(PkceMethod::S256);
         let pkce = self.generate_pkce(pkce_method);
@@ -183,28 +243,30 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator> PkceAuthorizationCodeF
                     .scopes
                     .clone()
                     .unwrap_or_else(|| self.config.default_scopes.clone()),
-            );
+            )
+            .with_resource(params.resource.clone());
             self.state_manager.generate(metadata)
         });
 
         url_params.insert("state".to_string(), state.clone());
 
-        // Build URL
+        // Build URL; resource indicators are repeated, so they follow the map
         let query = url_params
             .into_iter()
+            .chain(resource.into_iter().map(|(k, v)| (k.to_string(), v)))
             .map(|(k, v)| format!("{}={}", urlencoding::encode(&k), urlencoding::encode(&v)))
             .collect::<Vec<_>>()
             .join("&");
 
         let url = format!("{}?{}", self.config.provider.authorization_endpoint, query);
 
-        PkceAuthorizationUrl {
+        Ok(PkceAuthorizationUrl {
             url,
             state,
             code_verifier: pkce.code_verifier,
             code_challenge: pkce.code_challenge,
             code_challenge_method: pkce.code_challenge_method,
-        }
+        })
     }
 
     async fn exchange_code(
@@ -212,30 +274,14 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator> PkceAuthorizationCodeF
         request: CodeExchangeRequest,
         code_verifier: &str,
     ) -> Result<TokenResponse, OAuth2Error> {
-        let body = self.build_token_request_body(&request, code_verifier);
-        let headers = self.build_token_request_headers();
-
-        let http_request = HttpRequest {
-            method: HttpMethod::Post,
-            url: self.config.provider.token_endpoint.clone(),
-            headers,
-            body: Some(body),
-            timeout: Some(self.config.timeout),
-        };
-
-        let response = self.transport.send(http_request).await?;
-
-        if response.status != 200 {
-            return Err(create_error_from_response(response.status, &response.body));
-        }
-
-        let token_response: TokenResponse = serde_json::from_str(&response.body).map_err(|e| {
-            OAuth2Error::Protocol(crate::error::ProtocolError::InvalidJson {
-                message: e.to_string(),
-            })
-        })?;
-
-        Ok(token_response)
+        let timer = GrantTimer::start(
+            self.metrics.as_ref(),
+            &self.config,
+            GrantTypeLabels::AUTHORIZATION_CODE,
+        );
+        let result = self.send_token_request(&request, code_verifier).await;
+        timer.finish(&result);
+        result
     }
 
     async fn handle_callback(
@@ -280,6 +326,7 @@ impl<T: HttpTransport, S: StateManager, P: PkceGenerator> PkceAuthorizationCodeF
                 code,
                 redirect_uri: metadata.redirect_uri,
                 state: Some(state),
+                resource: metadata.resource,
             },
             code_verifier,
         )
@@ -340,7 +387,10 @@ impl MockPkceAuthorizationCodeFlow {
 
 #[async_trait]
 impl PkceAuthorizationCodeFlow for MockPkceAuthorizationCodeFlow {
-    fn build_authorization_url(&self, params: PkceAuthorizationParams) -> PkceAuthorizationUrl {
+    fn build_authorization_url(
+        &self,
+        params: PkceAuthorizationParams,
+    ) -> Result<PkceAuthorizationUrl, OAuth2Error> {
         self.build_url_history.lock().unwrap().push(params.clone());
 
         let pkce = self.next_pkce.lock().unwrap().take().unwrap_or(PkceParams {
@@ -349,7 +399,7 @@ impl PkceAuthorizationCodeFlow for MockPkceAuthorizationCodeFlow {
             code_challenge_method: PkceMethod::S256,
         });
 
-        PkceAuthorizationUrl {
+        Ok(PkceAuthorizationUrl {
             url: format!(
                 "https://mock.example.com/authorize?redirect_uri={}",
                 params.redirect_uri
@@ -358,7 +408,7 @@ impl PkceAuthorizationCodeFlow for MockPkceAuthorizationCodeFlow {
             code_verifier: pkce.code_verifier,
             code_challenge: pkce.code_challenge,
             code_challenge_method: pkce.code_challenge_method,
-        }
+        })
     }
 
     async fn exchange_code(
@@ -412,6 +462,7 @@ impl PkceAuthorizationCodeFlow for MockPkceAuthorizationCodeFlow {
                 code: callback.code.unwrap_or_default(),
                 redirect_uri: "https://example.com/callback".to_string(),
                 state: callback.state,
+                resource: Vec::new(),
             },
             code_verifier,
         )
@@ -439,7 +490,7 @@ mod tests {
             ..Default::default()
         };
 
-        let url = flow.build_authorization_url(params);
+        let url = flow.build_authorization_url(params).unwrap();
         assert!(url.url.contains("mock.example.com"));
         assert_eq!(url.state, "mock-state");
         assert!(!url.code_verifier.is_empty());
@@ -456,6 +507,7 @@ mod tests {
             code: "test-code".to_string(),
             redirect_uri: "https://example.com/callback".to_string(),
             state: Some("test-state".to_string()),
+            resource: Vec::new(),
         };
 
         let response = flow
@@ -11,7 +11,9 @@
 //! - Token Refresh (RFC 6749 Section 6)
 //! - Token Introspection (RFC 7662)
 //! - Token Revocation (RFC 7009)
+//! - JWT Client Authentication (RFC 7523)
 //! - OIDC Discovery (RFC 8414)
+//! - OIDC ID Token Validation (`jwt` feature)
 //!
 //! # Example
 //!
@@ -41,7 +43,7 @@
 //!             scopes: Some(vec!["openid".to_string(), "profile".to_string()]),
 //!             ..Default::default()
 //!         }
-//!     );
+//!     )?;
 //!
 //!     println!("Authorization URL: {}", auth_url.url);
 //!     println!("Code verifier (save this): {}", auth_url.code_verifier);
@@ -89,8 +91,8 @@ pub use error::{
 // Re-export types
 pub use types::{
     // Config
-    ClientAuthMethod, ClientCredentials, GrantType, OAuth2Config, OIDCDiscoveryDocument,
-    ProviderConfig,
+    ClientAssertionConfig, ClientAssertionKey, ClientAuthMethod, ClientCredentials, GrantType,
+    JwtSigningAlgorithm, OAuth2Config, OIDCDiscoveryDocument, ProviderConfig,
     // Token
     AccessToken, RefreshTokenParams, StoredTokens, TokenResponse,
     // Auth
@@ -116,8 +118,15 @@ pub use core::{
     DefaultPkceGenerator, MockPkceGenerator, PkceGenerator,
     // Discovery
     DefaultDiscoveryClient, DiscoveryClient, MockDiscoveryClient,
+    // Client Assertion
+    client_assertion_params, sign_client_assertion, ClientAssertionClaims,
+    CLIENT_ASSERTION_TYPE,
 };
 
+// Re-export ID token validation
+#[cfg(feature = "jwt")]
+pub use core::{IdTokenClaims, IdTokenValidator};
+
 // Re-export flows
 pub use flows::{
     // Authorization Code
@@ -134,9 +143,9 @@ pub use flows::{
 // Re-export token management
 pub use token::{
     // Storage
-    InMemoryTokenStorage, MockTokenStorage, TokenStorage,
+    FileTokenStorage, InMemoryTokenStorage, MockTokenStorage, TokenStorage,
     // Manager
-    DefaultTokenManager, MockTokenManager, TokenManager, TokenManagerConfig,
+    resource_token_key, DefaultTokenManager, MockTokenManager, TokenManager, TokenManagerConfig,
     // Introspection
     DefaultTokenIntrospector, MockTokenIntrospector, TokenIntrospector,
     // Revocation
@@ -160,8 +169,8 @@ pub use resilience::{
 // Re-export telemetry
 pub use telemetry::{
     // Metrics
-    create_in_memory_metrics, no_op_metrics, Counter, Gauge, Histogram, InMemoryMetrics,
-    MetricEntry, MetricLabels, NoOpMetrics, OAuth2Metrics,
+    create_in_memory_metrics, no_op_metrics, Counter, Gauge, GrantTypeLabels, Histogram,
+    InMemoryMetrics, MetricEntry, MetricLabels, NoOpMetrics, OAuth2Metrics, TOKEN_ENDPOINT,
     // Tracing
     create_in_memory_tracer, no_op_tracer, InMemorySpan, InMemoryTracer, NoOpSpan, NoOpTracer,
     OAuth2SpanAttributes, OAuth2SpanNames, Span, SpanAttributes, SpanStatus, Tracer,
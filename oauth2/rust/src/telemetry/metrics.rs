@@ -4,6 +4,10 @@
 
 use std::collections::HashMap;
 use std::sync::Mutex;
+use std::time::Instant;
+
+use crate::error::OAuth2Error;
+use crate::types::OAuth2Config;
 
 /// Metric labels.
 pub type MetricLabels = HashMap<String, String>;
@@ -35,6 +39,19 @@ pub trait Histogram: Send + Sync {
     fn record(&self, value: f64, labels: &MetricLabels);
 }
 
+/// Grant type labels for token endpoint metrics.
+pub struct GrantTypeLabels;
+
+impl GrantTypeLabels {
+    pub const AUTHORIZATION_CODE: &'static str = "auth_code";
+    pub const REFRESH: &'static str = "refresh";
+    pub const CLIENT_CREDENTIALS: &'static str = "client_credentials";
+    pub const DEVICE: &'static str = "device";
+}
+
+/// Endpoint label for token endpoint request durations.
+pub const TOKEN_ENDPOINT: &str = "token";
+
 /// OAuth2 metrics interface.
 pub trait OAuth2Metrics: Send + Sync {
     /// Record authorization request.
@@ -63,6 +80,80 @@ pub trait OAuth2Metrics: Send + Sync {
 
     /// Set active tokens gauge.
     fn set_active_tokens(&self, provider: &str, count: u64);
+
+    /// Record the outcome of a token grant.
+    ///
+    /// `error_category` is `None` on success, otherwise the
+    /// [`OAuth2Error::category`] of the failure. By default this reports
+    /// through `record_token_refresh` and `record_error`.
+    fn record_grant_result(&self, provider: &str, grant_type: &str, error_category: Option<&str>) {
+        if grant_type == GrantTypeLabels::REFRESH {
+            self.record_token_refresh(provider, error_category.is_none());
+        }
+        if let Some(category) = error_category {
+            self.record_error(category, provider);
+        }
+    }
+}
+
+/// Measures a single token endpoint request.
+///
+/// Counts the attempt when started; `finish` records the latency and outcome.
+pub(crate) struct GrantTimer<'a> {
+    metrics: &'a dyn OAuth2Metrics,
+    provider: String,
+    grant_type: &'static str,
+    started: Instant,
+}
+
+impl<'a> GrantTimer<'a> {
+    /// Start measuring a grant against the configured provider.
+    pub(crate) fn start(
+        metrics: &'a dyn OAuth2Metrics,
+        config: &OAuth2Config,
+        grant_type: &'static str,
+    ) -> Self {
+        let provider = provider_label(config);
+        metrics.record_token_request(&provider, grant_type);
+        Self {
+            metrics,
+            provider,
+            grant_type,
+            started: Instant::now(),
+        }
+    }
+
+    /// Record latency only, for responses that are neither success nor failure.
+    pub(crate) fn finish_pending(self) {
+        let elapsed = self.started.elapsed().as_secs_f64() * 1000.0;
+        self.metrics.record_request_duration(TOKEN_ENDPOINT, elapsed);
+    }
+
+    /// Record latency and the outcome of the grant.
+    pub(crate) fn finish<R>(self, result: &Result<R, OAuth2Error>) {
+        let error_category = result.as_ref().err().map(OAuth2Error::category);
+        self.finish_with(error_category);
+    }
+
+    /// Record latency and an explicit outcome.
+    pub(crate) fn finish_with(self, error_category: Option<&str>) {
+        let metrics = self.metrics;
+        let provider = self.provider.clone();
+        let grant_type = self.grant_type;
+        self.finish_pending();
+        metrics.record_grant_result(&provider, grant_type, error_category);
+    }
+}
+
+/// Provider label for a configuration: the issuer, or the token endpoint host.
+fn provider_label(config: &OAuth2Config) -> String {
+    if let Some(issuer) = &config.provider.issuer {
+        return issuer.clone();
+    }
+    url::Url::parse(&config.provider.token_endpoint)
+        .ok()
+        .and_then(|url| url.host_str().map(String::from))
+        .unwrap_or_else(|| config.provider.token_endpoint.clone())
 }
 
 /// No-op metrics implementation.
@@ -78,6 +169,7 @@ impl OAuth2Metrics for NoOpMetrics {
     fn record_error(&self, _error_type: &str, _provider: &str) {}
     fn record_circuit_breaker_state(&self, _provider: &str, _state: &str) {}
     fn set_active_tokens(&self, _provider: &str, _count: u64) {}
+    fn record_grant_result(&self, _provider: &str, _grant_type: &str, _error_category: Option<&str>) {}
 }
 
 /// No-op metrics singleton.
@@ -209,6 +301,18 @@ impl OAuth2Metrics for InMemoryMetrics {
         labels.insert("provider".to_string(), provider.to_string());
         self.record("oauth2_active_tokens", count as f64, labels);
     }
+
+    fn record_grant_result(&self, provider: &str, grant_type: &str, error_category: Option<&str>) {
+        let mut labels = MetricLabels::new();
+        labels.insert("provider".to_string(), provider.to_string());
+        labels.insert("grant_type".to_string(), grant_type.to_string());
+        let outcome = if error_category.is_some() { "failure" } else { "success" };
+        labels.insert("outcome".to_string(), outcome.to_string());
+        if let Some(category) = error_category {
+            labels.insert("error_category".to_string(), category.to_string());
+        }
+        self.record("oauth2_grant_results_total", 1.0, labels);
+    }
 }
 
 /// Create in-memory metrics for testing.
@@ -247,6 +351,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_default_grant_result_reports_refresh_and_error() {
+        struct RefreshOnly(InMemoryMetrics);
+
+        impl OAuth2Metrics for RefreshOnly {
+            fn record_authorization_request(&self, _provider: &str) {}
+            fn record_token_request(&self, _provider: &str, _grant_type: &str) {}
+            fn record_token_refresh(&self, provider: &str, success: bool) {
+                self.0.record_token_refresh(provider, success);
+            }
+            fn record_token_revocation(&self, _provider: &str, _success: bool) {}
+            fn record_token_introspection(&self, _provider: &str, _active: bool) {}
+            fn record_request_duration(&self, _endpoint: &str, _duration_ms: f64) {}
+            fn record_error(&self, error_type: &str, provider: &str) {
+                self.0.record_error(error_type, provider);
+            }
+            fn record_circuit_breaker_state(&self, _provider: &str, _state: &str) {}
+            fn set_active_tokens(&self, _provider: &str, _count: u64) {}
+        }
+
+        let metrics = RefreshOnly(InMemoryMetrics::new());
+        metrics.record_grant_result("google", GrantTypeLabels::REFRESH, Some("network"));
+        metrics.record_grant_result("google", GrantTypeLabels::CLIENT_CREDENTIALS, None);
+
+        let refreshes = metrics.0.get_entries_by_name("oauth2_token_refreshes_total");
+        assert_eq!(refreshes.len(), 1);
+        assert_eq!(refreshes[0].labels.get("success"), Some(&"false".to_string()));
+
+        let errors = metrics.0.get_entries_by_name("oauth2_errors_total");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].labels.get("error_type"), Some(&"network".to_string()));
+    }
+
     #[test]
     fn test_clear_entries() {
         let metrics = InMemoryMetrics::new();
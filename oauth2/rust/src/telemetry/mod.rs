@@ -14,8 +14,8 @@ pub mod tracing;
 
 // Metrics
 pub use metrics::{
-    create_in_memory_metrics, no_op_metrics, Counter, Gauge, Histogram, InMemoryMetrics,
-    MetricEntry, MetricLabels, NoOpMetrics, OAuth2Metrics,
+    create_in_memory_metrics, no_op_metrics, Counter, Gauge, GrantTypeLabels, Histogram,
+    InMemoryMetrics, MetricEntry, MetricLabels, NoOpMetrics, OAuth2Metrics, TOKEN_ENDPOINT,
 };
 
 // Tracing
@@ -0,0 +1,501 @@
+//! File Token Storage
+//!
+//! Token storage persisted to a single file, encrypted at rest with AES-256-GCM.
+
+use async_trait::async_trait;
+use fs2::FileExt;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::error::{ConfigurationError, OAuth2Error, StorageError};
+use crate::token::TokenStorage;
+use crate::types::StoredTokens;
+
+/// Associated data binding ciphertexts to this storage format.
+const AAD: &[u8] = b"oauth2-integration/file-token-storage/v1";
+
+/// Encrypted on-disk token storage.
+///
+/// All entries live in one file, written atomically (temp file + rename) while
+/// holding an exclusive lock on a sidecar `.lock` file, so multiple storage
+/// instances and processes can share the same path.
+pub struct FileTokenStorage {
+    store: Arc<FileStore>,
+}
+
+struct FileStore {
+    path: PathBuf,
+    lock_path: PathBuf,
+    key: LessSafeKey,
+    rng: SystemRandom,
+}
+
+impl FileTokenStorage {
+    /// Create storage at `path` using a 256-bit encryption key.
+    pub fn new(path: impl Into<PathBuf>, key: &[u8]) -> Result<Self, OAuth2Error> {
+        let unbound = UnboundKey::new(&AES_256_GCM, key).map_err(|_| {
+            OAuth2Error::Configuration(ConfigurationError::InvalidConfig {
+                message: "Token storage encryption key must be 32 bytes".to_string(),
+            })
+        })?;
+
+        let path = path.into();
+        let mut lock_path = path.clone().into_os_string();
+        lock_path.push(".lock");
+
+        Ok(Self {
+            store: Arc::new(FileStore {
+                path,
+                lock_path: PathBuf::from(lock_path),
+                key: LessSafeKey::new(unbound),
+                rng: SystemRandom::new(),
+            }),
+        })
+    }
+
+    /// Path of the token file.
+    pub fn path(&self) -> &Path {
+        &self.store.path
+    }
+
+    /// Run a read-only operation under a shared lock.
+    async fn read<R, F>(&self, f: F) -> Result<R, OAuth2Error>
+    where
+        R: Send + 'static,
+        F: FnOnce(HashMap<String, StoredTokens>) -> R + Send + 'static,
+    {
+        let store = Arc::clone(&self.store);
+        run_blocking(move || {
+            let _lock = store.lock(false)?;
+            Ok(f(store.load()?))
+        })
+        .await
+    }
+
+    /// Run a read-modify-write operation under an exclusive lock.
+    async fn update<R, F>(&self, f: F) -> Result<R, OAuth2Error>
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut HashMap<String, StoredTokens>) -> R + Send + 'static,
+    {
+        let store = Arc::clone(&self.store);
+        run_blocking(move || {
+            let _lock = store.lock(true)?;
+            let mut entries = store.load()?;
+            let result = f(&mut entries);
+            store.save(&entries)?;
+            Ok(result)
+        })
+        .await
+    }
+}
+
+async fn run_blocking<R, F>(f: F) -> Result<R, OAuth2Error>
+where
+    R: Send + 'static,
+    F: FnOnce() -> Result<R, OAuth2Error> + Send + 'static,
+{
+    tokio::task::spawn_blocking(f).await.map_err(|e| {
+        OAuth2Error::Storage(StorageError::WriteFailed {
+            message: format!("Storage task failed: {}", e),
+        })
+    })?
+}
+
+/// Held file lock, released on drop.
+struct LockGuard(File);
+
+impl Drop for LockGuard {
+    fn drop(&mut self) {
+        let _ = self.0.unlock();
+    }
+}
+
+impl FileStore {
+    fn lock(&self, exclusive: bool) -> Result<LockGuard, OAuth2Error> {
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(|e| io_error(&self.path, e, false))?;
+        }
+
+        let file = private_options()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&self.lock_path)
+            .map_err(|e| io_error(&self.lock_path, e, false))?;
+
+        let locked = if exclusive {
+            file.lock_exclusive()
+        } else {
+            file.lock_shared()
+        };
+        locked.map_err(|e| io_error(&self.lock_path, e, false))?;
+
+        Ok(LockGuard(file))
+    }
+
+    /// Load and decrypt all entries; a missing file is an empty store.
+    fn load(&self) -> Result<HashMap<String, StoredTokens>, OAuth2Error> {
+        let data = match fs::read(&self.path) {
+            Ok(data) => data,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(HashMap::new()),
+            Err(e) => return Err(io_error(&self.path, e, true)),
+        };
+
+        let plaintext = self.decrypt(data)?;
+        serde_json::from_slice(&plaintext).map_err(|e| {
+            OAuth2Error::Storage(StorageError::CorruptedData {
+                message: e.to_string(),
+            })
+        })
+    }
+
+    /// Encrypt and atomically replace the token file.
+    fn save(&self, entries: &HashMap<String, StoredTokens>) -> Result<(), OAuth2Error> {
+        let plaintext = serde_json::to_vec(entries).map_err(|e| {
+            OAuth2Error::Storage(StorageError::WriteFailed {
+                message: e.to_string(),
+            })
+        })?;
+        let ciphertext = self.encrypt(plaintext)?;
+
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(format!(".{}.tmp", std::process::id()));
+        let tmp_path = PathBuf::from(tmp_path);
+
+        let write = || -> std::io::Result<()> {
+            let mut file = private_options()
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(&tmp_path)?;
+            file.write_all(&ciphertext)?;
+            file.sync_all()?;
+            fs::rename(&tmp_path, &self.path)
+        };
+
+        write().map_err(|e| {
+            let _ = fs::remove_file(&tmp_path);
+            io_error(&self.path, e, false)
+        })
+    }
+
+    fn encrypt(&self, mut data: Vec<u8>) -> Result<Vec<u8>, OAuth2Error> {
+        let mut nonce = [0u8; NONCE_LEN];
+        self.rng.fill(&mut nonce).map_err(|_| {
+            OAuth2Error::Storage(StorageError::EncryptionFailed {
+                message: "Failed to generate nonce".to_string(),
+            })
+        })?;
+
+        self.key
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(AAD),
+                &mut data,
+            )
+            .map_err(|_| {
+                OAuth2Error::Storage(StorageError::EncryptionFailed {
+                    message: "AES-GCM seal failed".to_string(),
+                })
+            })?;
+
+        let mut output = nonce.to_vec();
+        output.extend_from_slice(&data);
+        Ok(output)
+    }
+
+    fn decrypt(&self, mut data: Vec<u8>) -> Result<Vec<u8>, OAuth2Error> {
+        if data.len() < NONCE_LEN {
+            return Err(OAuth2Error::Storage(StorageError::CorruptedData {
+                message: "Token file is truncated".to_string(),
+            }));
+        }
+
+        let mut ciphertext = data.split_off(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(&data).map_err(|_| {
+            OAuth2Error::Storage(StorageError::CorruptedData {
+                message: "Invalid nonce".to_string(),
+            })
+        })?;
+
+        let plaintext_len = self
+            .key
+            .open_in_place(nonce, Aad::from(AAD), &mut ciphertext)
+            .map_err(|_| {
+                OAuth2Error::Storage(StorageError::DecryptionFailed {
+                    message: "Wrong key or corrupted token file".to_string(),
+                })
+            })?
+            .len();
+
+        ciphertext.truncate(plaintext_len);
+        Ok(ciphertext)
+    }
+}
+
+/// Open options creating files readable only by the owner.
+fn private_options() -> OpenOptions {
+    #[allow(unused_mut)]
+    let mut options = OpenOptions::new();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options
+}
+
+fn io_error(path: &Path, error: std::io::Error, reading: bool) -> OAuth2Error {
+    let storage_error = if error.kind() == ErrorKind::PermissionDenied {
+        StorageError::PermissionDenied {
+            path: path.display().to_string(),
+        }
+    } else if reading {
+        StorageError::ReadFailed {
+            message: format!("{}: {}", path.display(), error),
+        }
+    } else {
+        StorageError::WriteFailed {
+            message: format!("{}: {}", path.display(), error),
+        }
+    };
+    OAuth2Error::Storage(storage_error)
+}
+
+#[async_trait]
+impl TokenStorage for FileTokenStorage {
+    async fn store(&self, key: &str, tokens: StoredTokens) -> Result<(), OAuth2Error> {
+        let key = key.to_string();
+        self.update(move |entries| {
+            entries.insert(key, tokens);
+        })
+        .await
+    }
+
+    async fn retrieve(&self, key: &str) -> Result<Option<StoredTokens>, OAuth2Error> {
+        let key = key.to_string();
+        self.read(move |mut entries| entries.remove(&key)).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<bool, OAuth2Error> {
+        let key = key.to_string();
+        self.update(move |entries| entries.remove(&key).is_some())
+            .await
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool, OAuth2Error> {
+        let key = key.to_string();
+        self.read(move |entries| entries.contains_key(&key)).await
+    }
+
+    async fn list_keys(&self) -> Result<Vec<String>, OAuth2Error> {
+        self.read(|entries| entries.into_keys().collect()).await
+    }
+
+    async fn clear(&self) -> Result<(), OAuth2Error> {
+        self.update(|entries| entries.clear()).await
+    }
+
+    async fn clear_expired(&self) -> Result<u32, OAuth2Error> {
+        self.update(|entries| {
+            let initial_count = entries.len();
+            entries.retain(|_, stored| !stored.is_expired(std::time::Duration::ZERO));
+            (initial_count - entries.len()) as u32
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TokenResponse;
+
+    const KEY: [u8; 32] = [7u8; 32];
+
+    fn create_test_tokens(access_token: &str) -> StoredTokens {
+        StoredTokens::from_response(&TokenResponse {
+            access_token: access_token.to_string(),
+            token_type: "Bearer".to_string(),
+            expires_in: Some(3600),
+            refresh_token: Some("test-refresh-token".to_string()),
+            scope: Some("openid profile".to_string()),
+            id_token: None,
+            extra: HashMap::new(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_across_instances() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tokens.bin");
+
+        let storage = FileTokenStorage::new(&path, &KEY).unwrap();
+        storage
+            .store("user1", create_test_tokens("token-1"))
+            .await
+            .unwrap();
+        drop(storage);
+
+        let reopened = FileTokenStorage::new(&path, &KEY).unwrap();
+        let retrieved = reopened.retrieve("user1").await.unwrap().unwrap();
+        assert_eq!(retrieved.access_token, "token-1");
+        assert_eq!(
+            retrieved.refresh_token.as_deref(),
+            Some("test-refresh-token")
+        );
+        assert_eq!(
+            reopened.list_keys().await.unwrap(),
+            vec!["user1".to_string()]
+        );
+
+        // Tokens must not be stored in plaintext
+        let raw = fs::read(&path).unwrap();
+        assert!(!raw.windows(7).any(|w| w == b"token-1"));
+    }
+
+    #[tokio::test]
+    async fn test_missing_file_returns_none() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = FileTokenStorage::new(dir.path().join("absent.bin"), &KEY).unwrap();
+
+        assert!(storage.retrieve("user1").await.unwrap().is_none());
+        assert!(!storage.exists("user1").await.unwrap());
+        assert!(!storage.path().exists());
+    }
+
+    #[tokio::test]
+    async fn test_wrong_key_fails_to_decrypt() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tokens.bin");
+
+        let storage = FileTokenStorage::new(&path, &KEY).unwrap();
+        storage
+            .store("user1", create_test_tokens("token-1"))
+            .await
+            .unwrap();
+
+        let wrong = FileTokenStorage::new(&path, &[9u8; 32]).unwrap();
+        let result = wrong.retrieve("user1").await;
+        assert!(matches!(
+            result,
+            Err(OAuth2Error::Storage(StorageError::DecryptionFailed { .. }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_corrupted_file_fails_to_decrypt() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tokens.bin");
+
+        let storage = FileTokenStorage::new(&path, &KEY).unwrap();
+        storage
+            .store("user1", create_test_tokens("token-1"))
+            .await
+            .unwrap();
+
+        let mut raw = fs::read(&path).unwrap();
+        let last = raw.len() - 1;
+        raw[last] ^= 0xff;
+        fs::write(&path, raw).unwrap();
+
+        assert!(matches!(
+            storage.retrieve("user1").await,
+            Err(OAuth2Error::Storage(StorageError::DecryptionFailed { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_rejects_short_key() {
+        assert!(FileTokenStorage::new("tokens.bin", &[0u8; 16]).is_err());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_file_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tokens.bin");
+
+        let storage = FileTokenStorage::new(&path, &KEY).unwrap();
+        storage
+            .store("user1", create_test_tokens("token-1"))
+            .await
+            .unwrap();
+
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_writes_are_not_lost() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tokens.bin");
+
+        // Separate instances only share the on-disk lock
+        let handles: Vec<_> = (0..16)
+            .map(|i| {
+                let storage = FileTokenStorage::new(&path, &KEY).unwrap();
+                tokio::spawn(async move {
+                    storage
+                        .store(
+                            &format!("user{}", i),
+                            create_test_tokens(&format!("token-{}", i)),
+                        )
+                        .await
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+
+        let storage = FileTokenStorage::new(&path, &KEY).unwrap();
+        let mut keys = storage.list_keys().await.unwrap();
+        keys.sort();
+        assert_eq!(keys.len(), 16);
+        assert_eq!(
+            storage
+                .retrieve("user7")
+                .await
+                .unwrap()
+                .unwrap()
+                .access_token,
+            "token-7"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_and_clear() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = FileTokenStorage::new(dir.path().join("tokens.bin"), &KEY).unwrap();
+
+        storage
+            .store("user1", create_test_tokens("token-1"))
+            .await
+            .unwrap();
+        storage
+            .store("user2", create_test_tokens("token-2"))
+            .await
+            .unwrap();
+
+        assert!(storage.delete("user1").await.unwrap());
+        assert!(!storage.delete("user1").await.unwrap());
+        assert_eq!(
+            storage.list_keys().await.unwrap(),
+            vec!["user2".to_string()]
+        );
+
+        storage.clear().await.unwrap();
+        assert!(storage.list_keys().await.unwrap().is_empty());
+    }
+}
@@ -5,14 +5,19 @@
 use async_trait::async_trait;
 use base64::Engine;
 use std::collections::HashMap;
-use std::sync::Arc;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
-use crate::core::{HttpMethod, HttpRequest, HttpTransport};
+use crate::core::{
+    client_assertion_params, resource_params, HttpMethod, HttpRequest, HttpTransport,
+};
 use crate::error::{OAuth2Error, ProtocolError, TokenError};
-use crate::token::TokenStorage;
+use crate::telemetry::metrics::GrantTimer;
+use crate::telemetry::{GrantTypeLabels, NoOpMetrics, OAuth2Metrics};
+use crate::token::{DefaultTokenIntrospector, TokenIntrospector, TokenStorage};
 use crate::types::{
-    ClientAuthMethod, OAuth2Config, RefreshTokenParams, StoredTokens, TokenResponse,
+    ClientAuthMethod, IntrospectionParams, IntrospectionResponse, OAuth2Config,
+    RefreshTokenParams, StoredTokens, TokenResponse, TokenTypeHint,
 };
 use crate::error::create_error_from_response;
 
@@ -38,6 +43,24 @@ pub trait TokenManager: Send + Sync {
     async fn get_stored_tokens(&self, key: &str) -> Result<Option<StoredTokens>, OAuth2Error>;
 }
 
+/// Storage key for tokens issued for the given resource indicators (RFC 8707).
+///
+/// Tokens for different resources are audience-restricted and must not be
+/// mixed, so each resource set gets its own entry under `key`. The order of
+/// `resource` does not matter; with no resources the key is returned as is.
+pub fn resource_token_key(key: &str, resource: &[String]) -> String {
+    if resource.is_empty() {
+        return key.to_string();
+    }
+
+    let mut resource: Vec<&str> = resource.iter().map(String::as_str).collect();
+    resource.sort_unstable();
+    resource.dedup();
+
+    // Resource indicators cannot contain a fragment, so '#' is unambiguous
+    format!("{}#{}", key, resource.join(" "))
+}
+
 /// Token manager configuration.
 #[derive(Debug, Clone)]
 pub struct TokenManagerConfig {
@@ -62,6 +85,9 @@ pub struct DefaultTokenManager<T: HttpTransport, S: TokenStorage> {
     manager_config: TokenManagerConfig,
     transport: Arc<T>,
     storage: Arc<S>,
+    /// Per-key locks so only one refresh runs at a time for a given key.
+    refresh_locks: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+    metrics: Arc<dyn OAuth2Metrics>,
 }
 
 impl<T: HttpTransport, S: TokenStorage> DefaultTokenManager<T, S> {
@@ -77,28 +103,150 @@ impl<T: HttpTransport, S: TokenStorage> DefaultTokenManager<T, S> {
             manager_config,
             transport,
             storage,
+            refresh_locks: Mutex::new(HashMap::new()),
+            metrics: Arc::new(NoOpMetrics),
         }
     }
 
-    fn now_ms() -> u64 {
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis() as u64
+    /// Record refresh grants through the given metrics.
+    pub fn with_metrics(mut self, metrics: Arc<dyn OAuth2Metrics>) -> Self {
+        self.metrics = metrics;
+        self
     }
 
-    fn is_token_expired(&self, expires_at: Option<u64>) -> bool {
-        match expires_at {
-            Some(exp) => {
-                let now = Self::now_ms();
-                let buffer_ms = self.manager_config.refresh_buffer.as_millis() as u64;
-                now + buffer_ms >= exp
-            }
-            None => false, // No expiration = never expires
+    /// Get an access token with more than `refresh_buffer` lifetime remaining.
+    ///
+    /// Refreshes transparently when the stored token is expiring. Concurrent
+    /// callers for the same key share a single refresh: the first performs the
+    /// grant while the others wait and then read the newly stored token.
+    pub async fn get_valid_access_token(&self, key: &str) -> Result<String, OAuth2Error> {
+        let stored = self.load_tokens(key).await?;
+        if !self.is_token_expired(&stored) {
+            return Ok(stored.access_token);
+        }
+
+        let lock = self.refresh_lock(key);
+        let _guard = lock.lock().await;
+
+        // Another caller may have refreshed while we waited for the lock
+        let stored = self.load_tokens(key).await?;
+        if !self.is_token_expired(&stored) {
+            return Ok(stored.access_token);
+        }
+
+        let refreshed = self.refresh_tokens(key).await?;
+        Ok(refreshed.access_token)
+    }
+
+    /// Store tokens issued for the given resource indicators.
+    ///
+    /// The tokens are kept separately from those stored under `key` for any
+    /// other resource set, and later refreshes request the same resources.
+    pub async fn store_tokens_for_resource(
+        &self,
+        key: &str,
+        resource: &[String],
+        response: TokenResponse,
+    ) -> Result<(), OAuth2Error> {
+        resource_params(resource)?;
+
+        let mut stored = self.token_response_to_stored(response);
+        stored.resource = resource.to_vec();
+        self.storage.store(&resource_token_key(key, resource), stored).await
+    }
+
+    /// Get a valid access token issued for the given resource indicators.
+    pub async fn get_access_token_for_resource(
+        &self,
+        key: &str,
+        resource: &[String],
+    ) -> Result<String, OAuth2Error> {
+        self.get_access_token(&resource_token_key(key, resource)).await
+    }
+
+    /// Introspect the access token stored under `key` (RFC 7662).
+    ///
+    /// When the server reports the token active, its `exp` and `iat` replace
+    /// the stored timestamps, so later expiry checks follow the server.
+    pub async fn introspect_stored_tokens(
+        &self,
+        key: &str,
+    ) -> Result<IntrospectionResponse, OAuth2Error> {
+        let mut stored = self.load_tokens(key).await?;
+
+        let introspector =
+            DefaultTokenIntrospector::new(self.oauth_config.clone(), self.transport.clone());
+        let response = introspector
+            .introspect(IntrospectionParams {
+                token: stored.access_token.clone(),
+                token_type_hint: Some(TokenTypeHint::AccessToken),
+            })
+            .await?;
+
+        if response.active {
+            stored.apply_introspection(&response);
+            self.storage.store(key, stored).await?;
         }
+
+        Ok(response)
+    }
+
+    fn refresh_lock(&self, key: &str) -> Arc<tokio::sync::Mutex<()>> {
+        self.refresh_locks
+            .lock()
+            .unwrap()
+            .entry(key.to_string())
+            .or_default()
+            .clone()
+    }
+
+    async fn request_refresh(
+        &self,
+        refresh_token: &str,
+        resource: &[String],
+    ) -> Result<TokenResponse, OAuth2Error> {
+        let body = self.build_refresh_request_body(refresh_token, resource)?;
+        let headers = self.build_refresh_request_headers();
+
+        let http_request = HttpRequest {
+            method: HttpMethod::Post,
+            url: self.oauth_config.provider.token_endpoint.clone(),
+            headers,
+            body: Some(body),
+            timeout: Some(self.oauth_config.timeout),
+        };
+
+        let response = self.transport.send(http_request).await?;
+
+        if response.status != 200 {
+            return Err(create_error_from_response(response.status, &response.body));
+        }
+
+        serde_json::from_str(&response.body).map_err(|e| {
+            OAuth2Error::Protocol(ProtocolError::InvalidJson {
+                message: e.to_string(),
+            })
+        })
+    }
+
+    async fn load_tokens(&self, key: &str) -> Result<StoredTokens, OAuth2Error> {
+        self.storage.retrieve(key).await?.ok_or_else(|| {
+            OAuth2Error::Token(TokenError::NotFound {
+                key: key.to_string(),
+            })
+        })
     }
 
-    fn build_refresh_request_body(&self, refresh_token: &str) -> String {
+    fn is_token_expired(&self, stored: &StoredTokens) -> bool {
+        // No expiration = never expires
+        stored.is_expired(self.manager_config.refresh_buffer)
+    }
+
+    fn build_refresh_request_body(
+        &self,
+        refresh_token: &str,
+        resource: &[String],
+    ) -> Result<String, OAuth2Error> {
         let mut params = vec![
             ("grant_type", "refresh_token".to_string()),
             ("refresh_token", refresh_token.to_string()),
@@ -116,11 +264,17 @@ impl<T: HttpTransport, S: TokenStorage> DefaultTokenManager<T, S> {
             }
         }
 
-        params
+        // Resource indicators the tokens were originally issued for
+        params.extend(resource_params(resource)?);
+
+        // Signed client assertion if using private_key_jwt
+        params.extend(client_assertion_params(&self.oauth_config)?);
+
+        Ok(params
             .into_iter()
             .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(&v)))
             .collect::<Vec<_>>()
-            .join("&")
+            .join("&"))
     }
 
     fn build_refresh_request_headers(&self) -> HashMap<String, String> {
@@ -149,41 +303,22 @@ impl<T: HttpTransport, S: TokenStorage> DefaultTokenManager<T, S> {
     }
 
     fn token_response_to_stored(&self, response: TokenResponse) -> StoredTokens {
-        let now = Self::now_ms();
-        let expires_at = response.expires_in.map(|exp| now + (exp as u64 * 1000));
-
-        StoredTokens {
-            access_token: response.access_token,
-            token_type: response.token_type,
-            refresh_token: response.refresh_token,
-            access_token_expires_at: expires_at,
-            refresh_token_expires_at: None, // Most providers don't include this
-            scope: response.scope,
-            id_token: response.id_token,
-            created_at: now,
-            updated_at: now,
-        }
+        StoredTokens::from_response(&response)
     }
 }
 
 #[async_trait]
 impl<T: HttpTransport, S: TokenStorage> TokenManager for DefaultTokenManager<T, S> {
     async fn get_access_token(&self, key: &str) -> Result<String, OAuth2Error> {
-        let stored = self
-            .storage
-            .retrieve(key)
-            .await?
-            .ok_or_else(|| OAuth2Error::Token(TokenError::TokenNotFound))?;
+        if self.manager_config.auto_refresh {
+            return self.get_valid_access_token(key).await;
+        }
+
+        let stored = self.load_tokens(key).await?;
 
         // Check if token is expired or about to expire
-        if self.is_token_expired(stored.access_token_expires_at) {
-            if self.manager_config.auto_refresh {
-                // Try to refresh
-                let refreshed = self.refresh_tokens(key).await?;
-                return Ok(refreshed.access_token);
-            } else {
-                return Err(OAuth2Error::Token(TokenError::TokenExpired));
-            }
+        if self.is_token_expired(&stored) {
+            return Err(OAuth2Error::Token(TokenError::Expired));
         }
 
         Ok(stored.access_token)
@@ -195,49 +330,32 @@ impl<T: HttpTransport, S: TokenStorage> TokenManager for DefaultTokenManager<T,
     }
 
     async fn refresh_tokens(&self, key: &str) -> Result<TokenResponse, OAuth2Error> {
-        let stored = self
-            .storage
-            .retrieve(key)
-            .await?
-            .ok_or_else(|| OAuth2Error::Token(TokenError::TokenNotFound))?;
-
-        let refresh_token = stored.refresh_token.ok_or_else(|| {
-            OAuth2Error::Token(TokenError::RefreshFailed {
-                message: "No refresh token available".to_string(),
-            })
-        })?;
+        let stored = self.load_tokens(key).await?;
 
-        let body = self.build_refresh_request_body(&refresh_token);
-        let headers = self.build_refresh_request_headers();
+        let refresh_token = stored
+            .refresh_token
+            .clone()
+            .ok_or(OAuth2Error::Token(TokenError::NoRefreshToken))?;
 
-        let http_request = HttpRequest {
-            method: HttpMethod::Post,
-            url: self.oauth_config.provider.token_endpoint.clone(),
-            headers,
-            body: Some(body),
-            timeout: Some(self.oauth_config.timeout),
-        };
-
-        let response = self.transport.send(http_request).await?;
-
-        if response.status != 200 {
-            return Err(create_error_from_response(response.status, &response.body));
-        }
-
-        let mut token_response: TokenResponse =
-            serde_json::from_str(&response.body).map_err(|e| {
-                OAuth2Error::Protocol(ProtocolError::InvalidJson {
-                    message: e.to_string(),
-                })
-            })?;
+        let timer = GrantTimer::start(
+            self.metrics.as_ref(),
+            &self.oauth_config,
+            GrantTypeLabels::REFRESH,
+        );
+        let result = self.request_refresh(&refresh_token, &stored.resource).await;
+        timer.finish(&result);
+        let mut token_response = result?;
 
-        // Preserve refresh token if not returned in response
+        // Preserve refresh token if not returned in response; a returned one
+        // replaces it (refresh token rotation)
         if token_response.refresh_token.is_none() {
             token_response.refresh_token = Some(refresh_token);
         }
 
-        // Store updated tokens
-        self.store_tokens(key, token_response.clone()).await?;
+        // Store updated tokens, still bound to the same resources
+        let mut refreshed = self.token_response_to_stored(token_response.clone());
+        refreshed.resource = stored.resource;
+        self.storage.store(key, refreshed).await?;
 
         Ok(token_response)
     }
@@ -248,8 +366,7 @@ impl<T: HttpTransport, S: TokenStorage> TokenManager for DefaultTokenManager<T,
 
     async fn has_valid_tokens(&self, key: &str) -> Result<bool, OAuth2Error> {
         match self.storage.retrieve(key).await? {
-            Some(stored) => Ok(!self.is_token_expired(stored.access_token_expires_at)
-                || stored.refresh_token.is_some()),
+            Some(stored) => Ok(!self.is_token_expired(&stored) || stored.refresh_token.is_some()),
             None => Ok(false),
         }
     }
@@ -343,7 +460,11 @@ impl TokenManager for MockTokenManager {
             .unwrap()
             .get(key)
             .map(|t| t.access_token.clone())
-            .ok_or_else(|| OAuth2Error::Token(TokenError::TokenNotFound))
+            .ok_or_else(|| {
+                OAuth2Error::Token(TokenError::NotFound {
+                    key: key.to_string(),
+                })
+            })
     }
 
     async fn store_tokens(&self, key: &str, response: TokenResponse) -> Result<(), OAuth2Error> {
@@ -354,22 +475,7 @@ impl TokenManager for MockTokenManager {
             .unwrap()
             .push((key.to_string(), response.clone()));
 
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64;
-
-        let stored = StoredTokens {
-            access_token: response.access_token,
-            token_type: response.token_type,
-            refresh_token: response.refresh_token,
-            access_token_expires_at: response.expires_in.map(|exp| now + (exp as u64 * 1000)),
-            refresh_token_expires_at: None,
-            scope: response.scope,
-            id_token: response.id_token,
-            created_at: now,
-            updated_at: now,
-        };
+        let stored = StoredTokens::from_response(&response);
 
         self.tokens.lock().unwrap().insert(key.to_string(), stored);
         Ok(())
@@ -419,24 +525,399 @@ pub fn create_mock_token_manager() -> MockTokenManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{HttpResponse, MockHttpTransport};
+    use crate::telemetry::InMemoryMetrics;
+    use crate::token::InMemoryTokenStorage;
 
     fn create_test_tokens() -> StoredTokens {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64;
+        create_tokens_expiring_in("test-access-token", 3600)
+    }
 
-        StoredTokens {
-            access_token: "test-access-token".to_string(),
+    fn create_tokens_expiring_in(access_token: &str, expires_in: u64) -> StoredTokens {
+        StoredTokens::from_response(&TokenResponse {
+            access_token: access_token.to_string(),
             token_type: "Bearer".to_string(),
+            expires_in: Some(expires_in),
             refresh_token: Some("test-refresh-token".to_string()),
-            access_token_expires_at: Some(now + 3600000),
-            refresh_token_expires_at: None,
             scope: Some("openid".to_string()),
             id_token: None,
-            created_at: now,
-            updated_at: now,
+            extra: HashMap::new(),
+        })
+    }
+
+    /// Transport that yields before responding so concurrent callers overlap.
+    struct DelayedTransport(MockHttpTransport);
+
+    #[async_trait]
+    impl HttpTransport for DelayedTransport {
+        async fn send(&self, request: HttpRequest) -> Result<HttpResponse, OAuth2Error> {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            self.0.send(request).await
+        }
+    }
+
+    fn create_manager<T: HttpTransport>(
+        transport: Arc<T>,
+        storage: Arc<InMemoryTokenStorage>,
+    ) -> DefaultTokenManager<T, InMemoryTokenStorage> {
+        let mut config = OAuth2Config::default();
+        config.provider.token_endpoint = "https://auth.example.com/token".to_string();
+        config.provider.introspection_endpoint =
+            Some("https://auth.example.com/introspect".to_string());
+        config.credentials.client_id = "client".to_string();
+        config.credentials.auth_method = ClientAuthMethod::None;
+
+        DefaultTokenManager::new(config, TokenManagerConfig::default(), transport, storage)
+    }
+
+    fn form_param(request: &HttpRequest, name: &str) -> Option<String> {
+        let body = request.body.as_deref().unwrap_or_default();
+        url::form_urlencoded::parse(body.as_bytes())
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.into_owned())
+    }
+
+    #[tokio::test]
+    async fn test_valid_token_returned_without_refresh() {
+        let transport = Arc::new(MockHttpTransport::new());
+        let storage = Arc::new(InMemoryTokenStorage::new());
+        storage.store("user1", create_test_tokens()).await.unwrap();
+
+        let manager = create_manager(transport.clone(), storage);
+        let token = manager.get_valid_access_token("user1").await.unwrap();
+
+        assert_eq!(token, "test-access-token");
+        assert!(transport.get_requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_expiring_token_is_refreshed_and_persisted() {
+        let transport = Arc::new(MockHttpTransport::new());
+        transport.queue_json_response(
+            200,
+            &serde_json::json!({
+                "access_token": "fresh-token",
+                "token_type": "Bearer",
+                "expires_in": 3600
+            }),
+        );
+        let storage = Arc::new(InMemoryTokenStorage::new());
+        // Within the 5 minute refresh buffer
+        storage
+            .store("user1", create_tokens_expiring_in("stale-token", 60))
+            .await
+            .unwrap();
+
+        let manager = create_manager(transport.clone(), storage.clone());
+        let token = manager.get_valid_access_token("user1").await.unwrap();
+        assert_eq!(token, "fresh-token");
+
+        let request = transport.get_last_request().unwrap();
+        assert_eq!(form_param(&request, "grant_type").as_deref(), Some("refresh_token"));
+        assert_eq!(
+            form_param(&request, "refresh_token").as_deref(),
+            Some("test-refresh-token")
+        );
+
+        // Refresh token preserved when the provider does not rotate it
+        let stored = storage.retrieve("user1").await.unwrap().unwrap();
+        assert_eq!(stored.access_token, "fresh-token");
+        assert_eq!(stored.refresh_token.as_deref(), Some("test-refresh-token"));
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_callers_share_one_refresh() {
+        let mock = MockHttpTransport::new();
+        mock.queue_json_response(
+            200,
+            &serde_json::json!({
+                "access_token": "fresh-token",
+                "token_type": "Bearer",
+                "expires_in": 3600
+            }),
+        );
+        let transport = Arc::new(DelayedTransport(mock));
+        let storage = Arc::new(InMemoryTokenStorage::new());
+        storage
+            .store("user1", create_tokens_expiring_in("stale-token", 0))
+            .await
+            .unwrap();
+
+        let manager = create_manager(transport.clone(), storage);
+        let results = futures::future::join_all(
+            (0..5).map(|_| manager.get_valid_access_token("user1")),
+        )
+        .await;
+
+        for result in results {
+            assert_eq!(result.unwrap(), "fresh-token");
+        }
+        assert_eq!(transport.0.get_requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_rotated_refresh_token_is_used_next_time() {
+        let transport = Arc::new(MockHttpTransport::new());
+        // Responses are served last-queued first
+        transport.queue_json_response(
+            200,
+            &serde_json::json!({
+                "access_token": "second-token",
+                "token_type": "Bearer",
+                "expires_in": 60,
+                "refresh_token": "rotated-refresh-2"
+            }),
+        );
+        transport.queue_json_response(
+            200,
+            &serde_json::json!({
+                "access_token": "first-token",
+                "token_type": "Bearer",
+                "expires_in": 60,
+                "refresh_token": "rotated-refresh-1"
+            }),
+        );
+        let storage = Arc::new(InMemoryTokenStorage::new());
+        storage
+            .store("user1", create_tokens_expiring_in("stale-token", 0))
+            .await
+            .unwrap();
+
+        let manager = create_manager(transport.clone(), storage.clone());
+
+        // Tokens expire within the refresh buffer, so each call refreshes
+        assert_eq!(manager.get_valid_access_token("user1").await.unwrap(), "first-token");
+        assert_eq!(manager.get_valid_access_token("user1").await.unwrap(), "second-token");
+
+        let requests = transport.get_requests();
+        assert_eq!(
+            form_param(&requests[0], "refresh_token").as_deref(),
+            Some("test-refresh-token")
+        );
+        assert_eq!(
+            form_param(&requests[1], "refresh_token").as_deref(),
+            Some("rotated-refresh-1")
+        );
+
+        let stored = storage.retrieve("user1").await.unwrap().unwrap();
+        assert_eq!(stored.refresh_token.as_deref(), Some("rotated-refresh-2"));
+    }
+
+    #[tokio::test]
+    async fn test_missing_refresh_token() {
+        let transport = Arc::new(MockHttpTransport::new());
+        let storage = Arc::new(InMemoryTokenStorage::new());
+        let mut tokens = create_tokens_expiring_in("stale-token", 0);
+        tokens.refresh_token = None;
+        storage.store("user1", tokens).await.unwrap();
+
+        let manager = create_manager(transport, storage);
+        let result = manager.get_valid_access_token("user1").await;
+        assert!(matches!(
+            result,
+            Err(OAuth2Error::Token(TokenError::NoRefreshToken))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_success_records_metrics() {
+        let transport = Arc::new(MockHttpTransport::new());
+        transport.queue_json_response(
+            200,
+            &serde_json::json!({
+                "access_token": "fresh-token",
+                "token_type": "Bearer",
+                "expires_in": 3600
+            }),
+        );
+        let storage = Arc::new(InMemoryTokenStorage::new());
+        storage
+            .store("user1", create_tokens_expiring_in("stale-token", 0))
+            .await
+            .unwrap();
+
+        let metrics = Arc::new(InMemoryMetrics::new());
+        let manager = create_manager(transport, storage).with_metrics(metrics.clone());
+        manager.get_valid_access_token("user1").await.unwrap();
+
+        let attempts = metrics.get_entries_by_name("oauth2_token_requests_total");
+        assert_eq!(attempts.len(), 1);
+        assert_eq!(attempts[0].labels.get("grant_type").map(String::as_str), Some("refresh"));
+        assert_eq!(
+            attempts[0].labels.get("provider").map(String::as_str),
+            Some("auth.example.com")
+        );
+
+        let results = metrics.get_entries_by_name("oauth2_grant_results_total");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].labels.get("outcome").map(String::as_str), Some("success"));
+        assert!(results[0].labels.get("error_category").is_none());
+
+        let durations = metrics.get_entries_by_name("oauth2_request_duration_ms");
+        assert_eq!(durations.len(), 1);
+        assert_eq!(durations[0].labels.get("endpoint").map(String::as_str), Some("token"));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_failure_records_error_category() {
+        let transport = Arc::new(MockHttpTransport::new());
+        transport.queue_json_response(
+            400,
+            &serde_json::json!({
+                "error": "invalid_grant",
+                "error_description": "refresh token revoked"
+            }),
+        );
+        let storage = Arc::new(InMemoryTokenStorage::new());
+        storage
+            .store("user1", create_tokens_expiring_in("stale-token", 0))
+            .await
+            .unwrap();
+
+        let metrics = Arc::new(InMemoryMetrics::new());
+        let manager = create_manager(transport, storage).with_metrics(metrics.clone());
+        let result = manager.get_valid_access_token("user1").await;
+        assert!(matches!(result, Err(OAuth2Error::Provider(_))));
+
+        let results = metrics.get_entries_by_name("oauth2_grant_results_total");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].labels.get("grant_type").map(String::as_str), Some("refresh"));
+        assert_eq!(results[0].labels.get("outcome").map(String::as_str), Some("failure"));
+        assert_eq!(
+            results[0].labels.get("error_category").map(String::as_str),
+            Some("provider")
+        );
+        assert_eq!(metrics.get_entries_by_name("oauth2_request_duration_ms").len(), 1);
+    }
+
+    #[test]
+    fn test_resource_token_key() {
+        let api = "https://api.example.com".to_string();
+        let files = "https://files.example.com".to_string();
+
+        assert_eq!(resource_token_key("user1", &[]), "user1");
+        assert_eq!(
+            resource_token_key("user1", &[files.clone(), api.clone()]),
+            resource_token_key("user1", &[api.clone(), files.clone()])
+        );
+        assert_ne!(
+            resource_token_key("user1", &[api]),
+            resource_token_key("user1", &[files])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tokens_stored_separately_per_resource() {
+        let transport = Arc::new(MockHttpTransport::new());
+        let storage = Arc::new(InMemoryTokenStorage::new());
+        let manager = create_manager(transport.clone(), storage.clone());
+
+        let api = vec!["https://api.example.com".to_string()];
+        let files = vec!["https://files.example.com".to_string()];
+        for (resource, access_token) in [(&api, "api-token"), (&files, "files-token")] {
+            let response = TokenResponse {
+                access_token: access_token.to_string(),
+                token_type: "Bearer".to_string(),
+                expires_in: Some(3600),
+                refresh_token: Some("test-refresh-token".to_string()),
+                scope: None,
+                id_token: None,
+                extra: HashMap::new(),
+            };
+            manager
+                .store_tokens_for_resource("user1", resource, response)
+                .await
+                .unwrap();
         }
+
+        assert_eq!(
+            manager.get_access_token_for_resource("user1", &api).await.unwrap(),
+            "api-token"
+        );
+        assert_eq!(
+            manager.get_access_token_for_resource("user1", &files).await.unwrap(),
+            "files-token"
+        );
+        assert!(storage.retrieve("user1").await.unwrap().is_none());
+        assert!(transport.get_requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_requests_stored_resource() {
+        let transport = Arc::new(MockHttpTransport::new());
+        transport.queue_json_response(
+            200,
+            &serde_json::json!({
+                "access_token": "fresh-api-token",
+                "token_type": "Bearer",
+                "expires_in": 3600
+            }),
+        );
+        let storage = Arc::new(InMemoryTokenStorage::new());
+        let api = vec!["https://api.example.com".to_string()];
+        let key = resource_token_key("user1", &api);
+        let mut tokens = create_tokens_expiring_in("stale-token", 0);
+        tokens.resource = api.clone();
+        storage.store(&key, tokens).await.unwrap();
+
+        let manager = create_manager(transport.clone(), storage.clone());
+        let token = manager
+            .get_access_token_for_resource("user1", &api)
+            .await
+            .unwrap();
+        assert_eq!(token, "fresh-api-token");
+
+        let request = transport.get_last_request().unwrap();
+        assert_eq!(
+            form_param(&request, "resource").as_deref(),
+            Some("https://api.example.com")
+        );
+
+        let stored = storage.retrieve(&key).await.unwrap().unwrap();
+        assert_eq!(stored.resource, api);
+    }
+
+    #[tokio::test]
+    async fn test_introspection_updates_stored_timestamps() {
+        let transport = Arc::new(MockHttpTransport::new());
+        transport.queue_json_response(
+            200,
+            &serde_json::json!({
+                "active": true,
+                "iat": 1735686000,
+                "exp": 1735689600
+            }),
+        );
+        let storage = Arc::new(InMemoryTokenStorage::new());
+        storage.store("user1", create_test_tokens()).await.unwrap();
+
+        let manager = create_manager(transport.clone(), storage.clone());
+        let response = manager.introspect_stored_tokens("user1").await.unwrap();
+        assert!(response.active);
+
+        let request = transport.get_last_request().unwrap();
+        assert_eq!(request.url, "https://auth.example.com/introspect");
+        assert_eq!(form_param(&request, "token").as_deref(), Some("test-access-token"));
+
+        let stored = storage.retrieve("user1").await.unwrap().unwrap();
+        assert_eq!(stored.obtained_at.timestamp(), 1735686000);
+        assert_eq!(stored.expires_at.map(|e| e.timestamp()), Some(1735689600));
+    }
+
+    #[tokio::test]
+    async fn test_inactive_introspection_leaves_stored_tokens() {
+        let transport = Arc::new(MockHttpTransport::new());
+        transport.queue_json_response(200, &serde_json::json!({ "active": false }));
+        let storage = Arc::new(InMemoryTokenStorage::new());
+        let tokens = create_test_tokens();
+        storage.store("user1", tokens.clone()).await.unwrap();
+
+        let manager = create_manager(transport, storage.clone());
+        let response = manager.introspect_stored_tokens("user1").await.unwrap();
+        assert!(!response.active);
+
+        let stored = storage.retrieve("user1").await.unwrap().unwrap();
+        assert_eq!(stored.expires_at, tokens.expires_at);
     }
 
     #[tokio::test]
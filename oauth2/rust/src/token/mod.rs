@@ -4,11 +4,13 @@
 //!
 //! This module provides:
 //!
-//! - **Token Storage**: Secure storage implementations for OAuth2 tokens
+//! - **Token Storage**: Secure storage implementations for OAuth2 tokens, including
+//!   encrypted on-disk persistence
 //! - **Token Manager**: Token lifecycle management with automatic refresh
 //! - **Token Introspection**: RFC 7662 token introspection
 //! - **Token Revocation**: RFC 7009 token revocation
 
+pub mod file_storage;
 pub mod introspection;
 pub mod manager;
 pub mod revocation;
@@ -20,10 +22,13 @@ pub use storage::{
     InMemoryTokenStorage, MockTokenStorage, TokenStorage,
 };
 
+// File Token Storage
+pub use file_storage::FileTokenStorage;
+
 // Token Manager
 pub use manager::{
-    create_mock_token_manager, DefaultTokenManager, MockTokenManager, TokenManager,
-    TokenManagerConfig,
+    create_mock_token_manager, resource_token_key, DefaultTokenManager, MockTokenManager,
+    TokenManager, TokenManagerConfig,
 };
 
 // Token Introspection
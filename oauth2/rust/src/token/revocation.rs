@@ -7,7 +7,7 @@ use base64::Engine;
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::core::{HttpMethod, HttpRequest, HttpTransport};
+use crate::core::{client_assertion_params, HttpMethod, HttpRequest, HttpTransport};
 use crate::error::{OAuth2Error, TokenError};
 use crate::types::{ClientAuthMethod, OAuth2Config, RevocationParams};
 use crate::error::create_error_from_response;
@@ -37,7 +37,7 @@ impl<T: HttpTransport> DefaultTokenRevoker<T> {
         Self { config, transport }
     }
 
-    fn build_request_body(&self, params: &RevocationParams) -> String {
+    fn build_request_body(&self, params: &RevocationParams) -> Result<String, OAuth2Error> {
         let mut request_params = vec![("token", params.token.clone())];
 
         if let Some(hint) = &params.token_type_hint {
@@ -53,11 +53,14 @@ impl<T: HttpTransport> DefaultTokenRevoker<T> {
             }
         }
 
-        request_params
+        // Signed client assertion if using private_key_jwt
+        request_params.extend(client_assertion_params(&self.config)?);
+
+        Ok(request_params
             .into_iter()
             .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(&v)))
             .collect::<Vec<_>>()
-            .join("&")
+            .join("&"))
     }
 
     fn build_request_headers(&self) -> HashMap<String, String> {
@@ -101,7 +104,7 @@ impl<T: HttpTransport> TokenRevoker for DefaultTokenRevoker<T> {
                     })
                 })?;
 
-        let body = self.build_request_body(&params);
+        let body = self.build_request_body(&params)?;
         let headers = self.build_request_headers();
 
         let http_request = HttpRequest {
@@ -5,7 +5,7 @@
 use async_trait::async_trait;
 use std::collections::HashMap;
 use std::sync::Mutex;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::time::Duration;
 
 use crate::error::{OAuth2Error, StorageError};
 use crate::types::StoredTokens;
@@ -47,13 +47,6 @@ impl InMemoryTokenStorage {
             tokens: Mutex::new(HashMap::new()),
         }
     }
-
-    fn now_ms() -> u64 {
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis() as u64
-    }
 }
 
 impl Default for InMemoryTokenStorage {
@@ -99,15 +92,9 @@ impl TokenStorage for InMemoryTokenStorage {
 
     async fn clear_expired(&self) -> Result<u32, OAuth2Error> {
         let mut tokens = self.tokens.lock().unwrap();
-        let now = Self::now_ms();
         let initial_count = tokens.len();
 
-        tokens.retain(|_, stored| {
-            stored
-                .access_token_expires_at
-                .map(|exp| exp > now)
-                .unwrap_or(true)
-        });
+        tokens.retain(|_, stored| !stored.is_expired(Duration::ZERO));
 
         Ok((initial_count - tokens.len()) as u32)
     }
@@ -290,18 +277,8 @@ impl TokenStorage for MockTokenStorage {
         self.check_error()?;
 
         let mut tokens = self.tokens.lock().unwrap();
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis() as u64;
-
         let initial_count = tokens.len();
-        tokens.retain(|_, stored| {
-            stored
-                .access_token_expires_at
-                .map(|exp| exp > now)
-                .unwrap_or(true)
-        });
+        tokens.retain(|_, stored| !stored.is_expired(Duration::ZERO));
 
         Ok((initial_count - tokens.len()) as u32)
     }
@@ -320,24 +297,18 @@ pub fn create_mock_token_storage() -> MockTokenStorage {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::TokenResponse;
 
     fn create_test_tokens() -> StoredTokens {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64;
-
-        StoredTokens {
+        StoredTokens::from_response(&TokenResponse {
             access_token: "test-access-token".to_string(),
             token_type: "Bearer".to_string(),
+            expires_in: Some(3600), // 1 hour from now
             refresh_token: Some("test-refresh-token".to_string()),
-            access_token_expires_at: Some(now + 3600000), // 1 hour from now
-            refresh_token_expires_at: None,
             scope: Some("openid profile".to_string()),
             id_token: None,
-            created_at: now,
-            updated_at: now,
-        }
+            extra: HashMap::new(),
+        })
     }
 
     #[tokio::test]
@@ -18,6 +18,8 @@ pub struct AuthorizationParams {
     pub login_hint: Option<String>,
     /// Prompt behavior.
     pub prompt: Option<Prompt>,
+    /// Resource indicators (RFC 8707) for audience-restricted tokens.
+    pub resource: Vec<String>,
     /// Additional parameters.
     pub extra_params: HashMap<String, String>,
 }
@@ -35,6 +37,8 @@ pub struct PkceAuthorizationParams {
     pub login_hint: Option<String>,
     /// Prompt behavior.
     pub prompt: Option<Prompt>,
+    /// Resource indicators (RFC 8707) for audience-restricted tokens.
+    pub resource: Vec<String>,
     /// Additional parameters.
     pub extra_params: HashMap<String, String>,
 }
@@ -68,6 +72,8 @@ pub struct CodeExchangeRequest {
     pub redirect_uri: String,
     /// State parameter.
     pub state: Option<String>,
+    /// Resource indicators (RFC 8707) the token is requested for.
+    pub resource: Vec<String>,
 }
 
 /// PKCE code exchange request.
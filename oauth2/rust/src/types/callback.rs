@@ -74,6 +74,10 @@ pub struct StateMetadata {
     pub created_at: u64,
     /// Custom data.
     pub custom_data: Option<String>,
+    /// OIDC nonce sent in the authorization request.
+    pub nonce: Option<String>,
+    /// Resource indicators sent in the authorization request.
+    pub resource: Vec<String>,
 }
 
 impl StateMetadata {
@@ -88,6 +92,8 @@ impl StateMetadata {
                 .unwrap()
                 .as_millis() as u64,
             custom_data: None,
+            nonce: None,
+            resource: Vec::new(),
         }
     }
 
@@ -103,6 +109,18 @@ impl StateMetadata {
         self
     }
 
+    /// Add OIDC nonce.
+    pub fn with_nonce(mut self, nonce: String) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    /// Add resource indicators.
+    pub fn with_resource(mut self, resource: Vec<String>) -> Self {
+        self.resource = resource;
+        self
+    }
+
     /// Check if state has expired.
     pub fn is_expired(&self, max_age_ms: u64) -> bool {
         let now = std::time::SystemTime::now()
@@ -2,7 +2,7 @@
 //!
 //! OAuth2 client configuration types.
 
-use secrecy::SecretString;
+use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 use url::Url;
@@ -22,6 +22,8 @@ pub struct OAuth2Config {
     pub auto_refresh: bool,
     /// Refresh tokens this many seconds before expiry.
     pub refresh_threshold_secs: u64,
+    /// Timing of signed client assertions (`private_key_jwt`).
+    pub client_assertion: ClientAssertionConfig,
 }
 
 impl Default for OAuth2Config {
@@ -33,6 +35,25 @@ impl Default for OAuth2Config {
             timeout: Duration::from_secs(30),
             auto_refresh: true,
             refresh_threshold_secs: 300,
+            client_assertion: ClientAssertionConfig::default(),
+        }
+    }
+}
+
+/// Validity window for signed client assertions (RFC 7523).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClientAssertionConfig {
+    /// How long the assertion is valid after it is issued.
+    pub lifetime: Duration,
+    /// Allowance for clock drift; `iat`/`nbf` are backdated by this amount.
+    pub clock_skew: Duration,
+}
+
+impl Default for ClientAssertionConfig {
+    fn default() -> Self {
+        Self {
+            lifetime: Duration::from_secs(60),
+            clock_skew: Duration::from_secs(30),
         }
     }
 }
@@ -96,17 +117,75 @@ impl std::fmt::Debug for ClientCredentials {
 }
 
 /// Client authentication method.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ClientAuthMethod {
     /// client_id and client_secret in request body.
     ClientSecretPost,
     /// HTTP Basic Authentication header.
     ClientSecretBasic,
+    /// Signed JWT client assertion (RFC 7523 `private_key_jwt`).
+    #[serde(skip)]
+    PrivateKeyJwt {
+        /// Private key used to sign the assertion.
+        key: ClientAssertionKey,
+        /// Signing algorithm.
+        algorithm: JwtSigningAlgorithm,
+        /// Key ID advertised in the JWT header.
+        kid: Option<String>,
+    },
     /// No client authentication (public client).
     None,
 }
 
+/// PEM-encoded private key for signing client assertions.
+#[derive(Clone)]
+pub struct ClientAssertionKey(SecretString);
+
+impl ClientAssertionKey {
+    /// Create from a PEM-encoded PKCS#8 (or PKCS#1 RSA) private key.
+    pub fn from_pem(pem: impl Into<String>) -> Self {
+        Self(SecretString::new(pem.into()))
+    }
+
+    /// Get the PEM bytes.
+    pub fn pem(&self) -> &[u8] {
+        self.0.expose_secret().as_bytes()
+    }
+}
+
+impl PartialEq for ClientAssertionKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.expose_secret() == other.0.expose_secret()
+    }
+}
+
+impl Eq for ClientAssertionKey {}
+
+impl std::fmt::Debug for ClientAssertionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ClientAssertionKey([REDACTED])")
+    }
+}
+
+/// Signing algorithm for client assertions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JwtSigningAlgorithm {
+    /// RSASSA-PKCS1-v1_5 using SHA-256.
+    RS256,
+    /// ECDSA using P-256 and SHA-256.
+    ES256,
+}
+
+impl JwtSigningAlgorithm {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::RS256 => "RS256",
+            Self::ES256 => "ES256",
+        }
+    }
+}
+
 impl Default for ClientAuthMethod {
     fn default() -> Self {
         Self::ClientSecretBasic
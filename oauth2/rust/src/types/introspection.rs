@@ -2,6 +2,7 @@
 //!
 //! Types for OAuth2 Token Introspection (RFC 7662) and Revocation (RFC 7009).
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -101,6 +102,16 @@ impl IntrospectionResponse {
         })
     }
 
+    /// Expiration as an absolute timestamp.
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.exp.and_then(|exp| DateTime::from_timestamp(exp, 0))
+    }
+
+    /// Issue time as an absolute timestamp.
+    pub fn issued_at(&self) -> Option<DateTime<Utc>> {
+        self.iat.and_then(|iat| DateTime::from_timestamp(iat, 0))
+    }
+
     /// Get scopes as vector.
     pub fn scopes(&self) -> Vec<String> {
         self.scope
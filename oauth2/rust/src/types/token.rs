@@ -7,6 +7,8 @@ use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use super::IntrospectionResponse;
+
 /// Token response from authorization server.
 #[derive(Clone, Debug, Deserialize)]
 pub struct TokenResponse {
@@ -43,7 +45,11 @@ pub struct StoredTokens {
     pub access_token: String,
     /// Token type.
     pub token_type: String,
-    /// Expiration time.
+    /// When the token was issued to us.
+    #[serde(default)]
+    pub obtained_at: DateTime<Utc>,
+    /// Expiration time (`obtained_at + expires_in`); `None` if the provider
+    /// did not send `expires_in`, in which case the token never expires.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub expires_at: Option<DateTime<Utc>>,
     /// Refresh token (encrypted in storage).
@@ -54,6 +60,9 @@ pub struct StoredTokens {
     /// ID token (OIDC).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub id_token: Option<String>,
+    /// Resource indicators (RFC 8707) the tokens were issued for.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resource: Vec<String>,
     /// When tokens were stored.
     pub stored_at: DateTime<Utc>,
     /// Additional metadata.
@@ -64,10 +73,14 @@ pub struct StoredTokens {
 impl StoredTokens {
     /// Create from token response.
     pub fn from_response(response: &TokenResponse) -> Self {
-        let now = Utc::now();
+        Self::from_response_at(response, Utc::now())
+    }
+
+    /// Create from token response obtained at the given time.
+    pub fn from_response_at(response: &TokenResponse, obtained_at: DateTime<Utc>) -> Self {
         let expires_at = response
             .expires_in
-            .map(|secs| now + Duration::seconds(secs as i64));
+            .map(|secs| obtained_at + Duration::seconds(secs as i64));
 
         let scopes = response
             .scope
@@ -78,27 +91,50 @@ impl StoredTokens {
         Self {
             access_token: response.access_token.clone(),
             token_type: response.token_type.clone(),
+            obtained_at,
             expires_at,
             refresh_token: response.refresh_token.clone(),
             scopes,
             id_token: response.id_token.clone(),
-            stored_at: now,
+            resource: Vec::new(),
+            stored_at: Utc::now(),
             metadata: HashMap::new(),
         }
     }
 
-    /// Check if token is expired.
-    pub fn is_expired(&self) -> bool {
+    /// Update timestamps from an authoritative introspection response.
+    ///
+    /// `exp` replaces `expires_at` and `iat` replaces `obtained_at`; fields the
+    /// server omits are left unchanged.
+    pub fn apply_introspection(&mut self, response: &IntrospectionResponse) {
+        if let Some(issued_at) = response.issued_at() {
+            self.obtained_at = issued_at;
+        }
+        if let Some(expires_at) = response.expires_at() {
+            self.expires_at = Some(expires_at);
+        }
+    }
+
+    /// Check if token is expired, or will be within `skew`.
+    pub fn is_expired(&self, skew: std::time::Duration) -> bool {
+        self.is_expired_at(Utc::now(), skew)
+    }
+
+    /// Check if token is expired at `now`, or will be within `skew`.
+    pub fn is_expired_at(&self, now: DateTime<Utc>, skew: std::time::Duration) -> bool {
+        let skew = Duration::from_std(skew).unwrap_or(Duration::MAX);
         self.expires_at
-            .map(|exp| exp <= Utc::now())
+            .map(|exp| {
+                exp <= now
+                    .checked_add_signed(skew)
+                    .unwrap_or(DateTime::<Utc>::MAX_UTC)
+            })
             .unwrap_or(false)
     }
 
     /// Check if token is expiring soon.
     pub fn is_expiring_soon(&self, threshold_secs: i64) -> bool {
-        self.expires_at
-            .map(|exp| exp <= Utc::now() + Duration::seconds(threshold_secs))
-            .unwrap_or(false)
+        self.is_expired(std::time::Duration::from_secs(threshold_secs.max(0) as u64))
     }
 
     /// Check if has refresh token.
@@ -264,10 +300,12 @@ mod tests {
         let mut stored = StoredTokens {
             access_token: "test".to_string(),
             token_type: "Bearer".to_string(),
+            obtained_at: Utc::now(),
             expires_at: Some(Utc::now() + Duration::seconds(30)),
             refresh_token: None,
             scopes: Vec::new(),
             id_token: None,
+            resource: Vec::new(),
             stored_at: Utc::now(),
             metadata: HashMap::new(),
         };
@@ -279,6 +317,81 @@ mod tests {
 
         // Already expired
         stored.expires_at = Some(Utc::now() - Duration::seconds(10));
-        assert!(stored.is_expired());
+        assert!(stored.is_expired(std::time::Duration::ZERO));
+    }
+
+    fn response_expiring_in(expires_in: Option<u64>) -> TokenResponse {
+        TokenResponse {
+            access_token: "test-token".to_string(),
+            token_type: "Bearer".to_string(),
+            expires_in,
+            refresh_token: None,
+            scope: None,
+            id_token: None,
+            extra: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_expires_at_is_absolute() {
+        let obtained_at = Utc::now() - Duration::hours(2);
+        let stored = StoredTokens::from_response_at(&response_expiring_in(Some(3600)), obtained_at);
+
+        assert_eq!(stored.obtained_at, obtained_at);
+        assert_eq!(
+            stored.expires_at,
+            Some(obtained_at + Duration::seconds(3600))
+        );
+        assert!(stored.is_expired(std::time::Duration::ZERO));
+
+        // Survives a storage round trip
+        let json = serde_json::to_string(&stored).unwrap();
+        let restored: StoredTokens = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.expires_at, stored.expires_at);
+        assert!(restored.is_expired(std::time::Duration::ZERO));
+    }
+
+    #[test]
+    fn test_is_expired_skew_boundary() {
+        let obtained_at = Utc::now();
+        let stored = StoredTokens::from_response_at(&response_expiring_in(Some(100)), obtained_at);
+        let skew = std::time::Duration::from_secs(10);
+
+        // 90s in: exactly `skew` before expiry counts as expired
+        assert!(!stored.is_expired_at(obtained_at + Duration::seconds(89), skew));
+        assert!(stored.is_expired_at(obtained_at + Duration::seconds(90), skew));
+        assert!(stored.is_expired_at(obtained_at + Duration::seconds(91), skew));
+
+        // Without skew the boundary is expires_at itself
+        assert!(!stored.is_expired_at(
+            obtained_at + Duration::seconds(99),
+            std::time::Duration::ZERO
+        ));
+        assert!(stored.is_expired_at(
+            obtained_at + Duration::seconds(100),
+            std::time::Duration::ZERO
+        ));
+    }
+
+    #[test]
+    fn test_missing_expires_in_never_expires() {
+        let stored = StoredTokens::from_response(&response_expiring_in(None));
+
+        assert!(stored.expires_at.is_none());
+        assert!(!stored.is_expired(std::time::Duration::from_secs(u64::MAX)));
+        assert!(!stored.is_expired_at(Utc::now() + Duration::days(3650), std::time::Duration::ZERO));
+        assert_eq!(stored.remaining_lifetime(), None);
+    }
+
+    #[test]
+    fn test_apply_introspection() {
+        let mut stored = StoredTokens::from_response(&response_expiring_in(None));
+        let response: IntrospectionResponse =
+            serde_json::from_str(r#"{"active": true, "iat": 1735686000, "exp": 1735689600}"#)
+                .unwrap();
+
+        stored.apply_introspection(&response);
+        assert_eq!(stored.obtained_at.timestamp(), 1735686000);
+        assert_eq!(stored.expires_at.map(|e| e.timestamp()), Some(1735689600));
     }
 }
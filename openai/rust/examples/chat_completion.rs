@@ -49,7 +49,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             .message
             .content
             .as_ref()
-            .unwrap_or(&"No content".to_string())
+            .map(|content| content.text())
+            .unwrap_or_else(|| "No content".to_string())
     );
 
     // Print usage information
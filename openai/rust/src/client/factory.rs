@@ -63,8 +63,8 @@ impl OpenAIClientBuilder {
             .unwrap_or_else(|| Arc::new(crate::auth::OpenAIAuthManager::new(config.clone())));
 
         let resilience = self.resilience.unwrap_or_else(|| {
-            Arc::new(crate::resilience::OpenAIResilienceOrchestrator::new(
-                &config,
+            Arc::new(crate::resilience::DefaultResilienceOrchestrator::new(
+                crate::resilience::ResilienceConfig::from_client_config(&config),
             ))
         });
 
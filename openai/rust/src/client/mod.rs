@@ -1,10 +1,12 @@
 mod client_impl;
 mod config;
 mod factory;
+mod moderated;
 
 pub use client_impl::OpenAIClientImpl;
 pub use config::OpenAIConfig;
 pub use factory::OpenAIClientBuilder;
+pub use moderated::{ModerationDecision, OpenAIClientExt};
 
 use crate::errors::OpenAIResult;
 use crate::services::{
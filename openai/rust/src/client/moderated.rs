@@ -0,0 +1,173 @@
+use crate::client::OpenAIClient;
+use crate::errors::{OpenAIError, OpenAIResult};
+use crate::services::chat::{ChatCompletionRequest, ChatCompletionResponse, ChatMessageRole};
+use crate::services::moderations::{ModerationRequest, ModerationResult};
+use async_trait::async_trait;
+
+/// What to do with a chat request whose input was flagged by moderation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModerationDecision {
+    Block,
+    Proceed,
+}
+
+#[async_trait]
+pub trait OpenAIClientExt: OpenAIClient {
+    /// Runs the latest user message through moderation before sending the request.
+    ///
+    /// Only text is moderated; image parts of multimodal messages are skipped.
+    /// If any category is flagged, `on_flag` decides whether to send the request
+    /// anyway or fail with [`OpenAIError::ModerationFlagged`].
+    async fn chat_moderated<F>(
+        &self,
+        request: ChatCompletionRequest,
+        on_flag: F,
+    ) -> OpenAIResult<ChatCompletionResponse>
+    where
+        F: FnOnce(&ModerationResult) -> ModerationDecision + Send,
+    {
+        let input = request
+            .messages
+            .iter()
+            .rev()
+            .find(|message| message.role == ChatMessageRole::User)
+            .and_then(|message| message.content.as_ref())
+            .map(|content| content.text())
+            .filter(|text| !text.trim().is_empty());
+
+        if let Some(input) = input {
+            let moderation = self.moderations().create(ModerationRequest::new(input)).await?;
+
+            if let Some(result) = moderation.results.into_iter().find(|r| r.flagged) {
+                if on_flag(&result) == ModerationDecision::Block {
+                    return Err(OpenAIError::ModerationFlagged {
+                        categories: result.flagged_categories(),
+                        category_scores: result.category_scores.to_map(),
+                    });
+                }
+            }
+        }
+
+        self.chat().create(request).await
+    }
+}
+
+impl<C: OpenAIClient + ?Sized> OpenAIClientExt for C {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{OpenAIClientImpl, OpenAIConfig};
+    use crate::fixtures::*;
+    use crate::mocks::{MockAuthManager, MockHttpTransport, MockResilienceOrchestrator};
+    use crate::services::chat::{ChatMessage, ContentPart};
+    use http::Method;
+    use std::sync::Arc;
+
+    fn create_test_client(transport: MockHttpTransport) -> OpenAIClientImpl {
+        OpenAIClientImpl::new(
+            OpenAIConfig::new("sk-test"),
+            Arc::new(transport),
+            Arc::new(MockAuthManager::new()),
+            Arc::new(MockResilienceOrchestrator::passthrough()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_chat_moderated_unflagged_message_proceeds() {
+        let transport = MockHttpTransport::new()
+            .with_json_response(moderation_response_safe())
+            .with_json_response(chat_completion_response());
+        let client = create_test_client(transport.clone());
+
+        let request = ChatCompletionRequest::new(
+            "gpt-4o",
+            vec![
+                ChatMessage::system("You are helpful"),
+                ChatMessage::user_with_parts(vec![
+                    ContentPart::text("Describe this picture"),
+                    ContentPart::image_url("https://example.com/cat.png"),
+                ]),
+            ],
+        );
+
+        let mut called = false;
+        let response = client
+            .chat_moderated(request, |_| {
+                called = true;
+                ModerationDecision::Block
+            })
+            .await
+            .unwrap();
+
+        assert!(!called);
+        assert_eq!(response.choices.len(), 1);
+        assert!(transport.verify_request_with_body(
+            Method::POST,
+            "/moderations",
+            r#""input":"Describe this picture""#
+        ));
+        assert!(transport.verify_request(Method::POST, "/chat/completions"));
+    }
+
+    #[tokio::test]
+    async fn test_chat_moderated_flagged_message_is_blocked() {
+        let transport = MockHttpTransport::new().with_json_response(moderation_response_flagged());
+        let client = create_test_client(transport.clone());
+
+        let request = ChatCompletionRequest::new(
+            "gpt-4o",
+            vec![
+                ChatMessage::user("Hello"),
+                ChatMessage::assistant("Hi there!"),
+                ChatMessage::user("Inappropriate content"),
+            ],
+        );
+
+        let err = client
+            .chat_moderated(request, |result| {
+                assert!(result.categories.hate);
+                ModerationDecision::Block
+            })
+            .await
+            .unwrap_err();
+
+        match err {
+            OpenAIError::ModerationFlagged {
+                categories,
+                category_scores,
+            } => {
+                assert_eq!(
+                    categories,
+                    vec!["hate", "hate/threatening", "harassment", "harassment/threatening"]
+                );
+                assert_eq!(category_scores["hate"], 0.95);
+                assert_eq!(category_scores.len(), 11);
+            }
+            other => panic!("expected ModerationFlagged, got {:?}", other),
+        }
+        assert!(transport.verify_request_with_body(
+            Method::POST,
+            "/moderations",
+            "Inappropriate content"
+        ));
+        assert_eq!(transport.request_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_chat_moderated_flagged_message_can_proceed() {
+        let transport = MockHttpTransport::new()
+            .with_json_response(moderation_response_flagged())
+            .with_json_response(chat_completion_response());
+        let client = create_test_client(transport.clone());
+
+        let request = ChatCompletionRequest::new("gpt-4o", vec![ChatMessage::user("Borderline")]);
+
+        let response = client
+            .chat_moderated(request, |_| ModerationDecision::Proceed)
+            .await;
+
+        assert!(response.is_ok());
+        assert_eq!(transport.request_count(), 2);
+    }
+}
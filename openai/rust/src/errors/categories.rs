@@ -1,4 +1,5 @@
 use std::fmt;
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Error, Debug, Clone)]
@@ -94,7 +95,7 @@ pub enum RateLimitError {
     #[error("Too many requests: {message}")]
     TooManyRequests {
         message: String,
-        retry_after_secs: Option<u64>,
+        retry_after: Option<Duration>,
     },
 
     #[error("Token limit exceeded: {0}")]
@@ -102,9 +103,9 @@ pub enum RateLimitError {
 }
 
 impl RateLimitError {
-    pub fn retry_after(&self) -> Option<u64> {
+    pub fn retry_after(&self) -> Option<Duration> {
         match self {
-            RateLimitError::TooManyRequests { retry_after_secs, .. } => *retry_after_secs,
+            RateLimitError::TooManyRequests { retry_after, .. } => *retry_after,
             _ => None,
         }
     }
@@ -160,13 +161,13 @@ mod tests {
     fn test_rate_limit_retry_after() {
         let error = RateLimitError::TooManyRequests {
             message: "Rate limit exceeded".to_string(),
-            retry_after_secs: Some(30),
+            retry_after: Some(Duration::from_secs(30)),
         };
-        assert_eq!(error.retry_after(), Some(30));
+        assert_eq!(error.retry_after(), Some(Duration::from_secs(30)));
 
         let error_no_retry = RateLimitError::TooManyRequests {
             message: "Rate limit exceeded".to_string(),
-            retry_after_secs: None,
+            retry_after: None,
         };
         assert_eq!(error_no_retry.retry_after(), None);
     }
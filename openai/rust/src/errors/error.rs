@@ -2,6 +2,7 @@ use crate::errors::categories::{
     AuthenticationError, ConfigurationError, NetworkError, RateLimitError, ServerError,
     ValidationError,
 };
+use std::collections::BTreeMap;
 use std::fmt;
 use thiserror::Error;
 
@@ -47,6 +48,21 @@ pub enum OpenAIError {
     #[error("Stream error: {0}")]
     Stream(String),
 
+    #[error("Stream truncated after {bytes_received} bytes: {message}")]
+    StreamTruncated { bytes_received: u64, message: String },
+
+    #[error("Model refused the request: {0}")]
+    Refusal(String),
+
+    #[error("Input flagged by moderation: {}", categories.join(", "))]
+    ModerationFlagged {
+        categories: Vec<String>,
+        category_scores: BTreeMap<String, f64>,
+    },
+
+    #[error("I/O error: {0}")]
+    Io(String),
+
     #[error("Unknown error: {0}")]
     Unknown(String),
 }
@@ -59,6 +75,7 @@ impl OpenAIError {
                 | OpenAIError::Network(_)
                 | OpenAIError::Server(ServerError::ServiceUnavailable(_))
                 | OpenAIError::Server(ServerError::InternalError(_))
+                | OpenAIError::Server(ServerError::BadGateway(_))
                 | OpenAIError::Timeout { .. }
         )
     }
@@ -71,6 +88,14 @@ impl OpenAIError {
         matches!(self, OpenAIError::RateLimit(_))
     }
 
+    pub fn is_refusal(&self) -> bool {
+        matches!(self, OpenAIError::Refusal(_))
+    }
+
+    pub fn is_moderation_flagged(&self) -> bool {
+        matches!(self, OpenAIError::ModerationFlagged { .. })
+    }
+
     pub fn error_code(&self) -> Option<&str> {
         match self {
             OpenAIError::Request { error_code, .. } => error_code.as_deref(),
@@ -117,6 +142,12 @@ impl From<serde_json::Error> for OpenAIError {
     }
 }
 
+impl From<std::io::Error> for OpenAIError {
+    fn from(err: std::io::Error) -> Self {
+        OpenAIError::Io(err.to_string())
+    }
+}
+
 impl From<url::ParseError> for OpenAIError {
     fn from(err: url::ParseError) -> Self {
         OpenAIError::Configuration(ConfigurationError::InvalidBaseUrl(err.to_string()))
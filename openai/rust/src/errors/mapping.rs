@@ -2,6 +2,7 @@ use crate::errors::{
     AuthenticationError, OpenAIError, RateLimitError, ServerError, ValidationError,
 };
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct OpenAIErrorResponse {
@@ -100,7 +101,7 @@ impl ErrorMapper {
                 };
                 error = OpenAIError::RateLimit(RateLimitError::TooManyRequests {
                     message: msg,
-                    retry_after_secs: Some(retry_after),
+                    retry_after: Some(retry_after),
                 });
             }
         }
@@ -129,12 +130,72 @@ impl ErrorMapper {
         }
     }
 
-    /// Extracts retry-after header value in seconds
-    pub fn extract_retry_after(headers: &http::HeaderMap) -> Option<u64> {
-        headers
-            .get("retry-after")
-            .and_then(|v| v.to_str().ok())
-            .and_then(|s| s.parse().ok())
+    /// Extracts the wait requested by `retry-after-ms`, `retry-after` or the
+    /// `x-ratelimit-reset-*` headers
+    ///
+    /// A reset header only counts when its `x-ratelimit-remaining-*` header is
+    /// zero, unless no limit reports being exhausted, in which case the
+    /// longest reset is used.
+    pub fn extract_retry_after(headers: &http::HeaderMap) -> Option<Duration> {
+        if let Some(ms) = header_str(headers, "retry-after-ms").and_then(|v| v.parse::<f64>().ok()) {
+            return Some(Duration::from_secs_f64(ms.max(0.0) / 1000.0));
+        }
+
+        if let Some(secs) = header_str(headers, "retry-after").and_then(|v| v.parse::<f64>().ok()) {
+            return Some(Duration::from_secs_f64(secs.max(0.0)));
+        }
+
+        let requests = rate_limit_reset(headers, "requests");
+        let tokens = rate_limit_reset(headers, "tokens");
+
+        let exhausted = [requests, tokens]
+            .into_iter()
+            .filter_map(|reset| reset.filter(|(remaining, _)| *remaining == Some(0)))
+            .map(|(_, delay)| delay)
+            .max();
+
+        exhausted.or_else(|| {
+            [requests, tokens]
+                .into_iter()
+                .flatten()
+                .map(|(_, delay)| delay)
+                .max()
+        })
+    }
+
+    /// Parses OpenAI reset durations such as `20ms`, `1s` or `6m0s`
+    pub fn parse_reset_duration(value: &str) -> Option<Duration> {
+        let value = value.trim();
+        if value.is_empty() {
+            return None;
+        }
+
+        let mut total = 0.0;
+        let mut rest = value;
+
+        while !rest.is_empty() {
+            let number_end = rest
+                .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+                .unwrap_or(rest.len());
+            let number: f64 = rest[..number_end].parse().ok()?;
+            rest = &rest[number_end..];
+
+            let unit_end = rest
+                .find(|c: char| c.is_ascii_digit() || c == '.')
+                .unwrap_or(rest.len());
+            let seconds_per_unit = match &rest[..unit_end] {
+                "ms" => 0.001,
+                "s" | "" => 1.0,
+                "m" => 60.0,
+                "h" => 3600.0,
+                _ => return None,
+            };
+            rest = &rest[unit_end..];
+
+            total += number * seconds_per_unit;
+        }
+
+        Some(Duration::from_secs_f64(total))
     }
 
     /// Parses error response from JSON body
@@ -149,9 +210,30 @@ impl ErrorMapper {
     }
 }
 
+fn rate_limit_reset(headers: &http::HeaderMap, limit: &str) -> Option<(Option<u64>, Duration)> {
+    let reset = header_str(headers, &format!("x-ratelimit-reset-{}", limit))
+        .and_then(ErrorMapper::parse_reset_duration)?;
+    let remaining = header_str(headers, &format!("x-ratelimit-remaining-{}", limit))
+        .and_then(|v| v.parse().ok());
+    Some((remaining, reset))
+}
+
+fn header_str<'a>(headers: &'a http::HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|v| v.to_str().ok()).map(str::trim)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use http::{HeaderMap, HeaderValue};
+
+    fn rate_limited(headers: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in headers {
+            map.insert(*name, HeaderValue::from_static(value));
+        }
+        map
+    }
 
     #[test]
     fn test_map_status_code_401() {
@@ -171,4 +253,64 @@ mod tests {
             ErrorMapper::map_error_type("invalid_request_error", "Invalid request".to_string());
         assert!(matches!(error, OpenAIError::Validation(_)));
     }
+
+    #[test]
+    fn test_parse_reset_duration() {
+        assert_eq!(ErrorMapper::parse_reset_duration("1s"), Some(Duration::from_secs(1)));
+        assert_eq!(ErrorMapper::parse_reset_duration("20ms"), Some(Duration::from_millis(20)));
+        assert_eq!(ErrorMapper::parse_reset_duration("6m0s"), Some(Duration::from_secs(360)));
+        assert_eq!(ErrorMapper::parse_reset_duration("1h2m3s"), Some(Duration::from_secs(3723)));
+        assert_eq!(ErrorMapper::parse_reset_duration("0.5s"), Some(Duration::from_millis(500)));
+        assert_eq!(ErrorMapper::parse_reset_duration("7"), Some(Duration::from_secs(7)));
+        assert_eq!(ErrorMapper::parse_reset_duration("soon"), None);
+        assert_eq!(ErrorMapper::parse_reset_duration(""), None);
+    }
+
+    #[test]
+    fn test_retry_after_header_takes_precedence() {
+        let headers = rate_limited(&[
+            ("retry-after", "2"),
+            ("x-ratelimit-remaining-requests", "0"),
+            ("x-ratelimit-reset-requests", "30s"),
+        ]);
+        assert_eq!(ErrorMapper::extract_retry_after(&headers), Some(Duration::from_secs(2)));
+
+        let headers = rate_limited(&[("retry-after-ms", "1500"), ("retry-after", "2")]);
+        assert_eq!(ErrorMapper::extract_retry_after(&headers), Some(Duration::from_millis(1500)));
+    }
+
+    #[test]
+    fn test_exhausted_rate_limit_reset_is_used() {
+        let headers = rate_limited(&[
+            ("x-ratelimit-remaining-requests", "12"),
+            ("x-ratelimit-reset-requests", "120ms"),
+            ("x-ratelimit-remaining-tokens", "0"),
+            ("x-ratelimit-reset-tokens", "6s"),
+        ]);
+        assert_eq!(ErrorMapper::extract_retry_after(&headers), Some(Duration::from_secs(6)));
+
+        let headers = rate_limited(&[
+            ("x-ratelimit-reset-requests", "1s"),
+            ("x-ratelimit-reset-tokens", "0m45s"),
+        ]);
+        assert_eq!(ErrorMapper::extract_retry_after(&headers), Some(Duration::from_secs(45)));
+
+        assert_eq!(ErrorMapper::extract_retry_after(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn test_map_429_with_reset_headers() {
+        let headers = rate_limited(&[
+            ("x-ratelimit-remaining-tokens", "0"),
+            ("x-ratelimit-reset-tokens", "250ms"),
+        ]);
+        let error = ErrorMapper::map_status_with_headers(429, &headers, "");
+
+        match error {
+            OpenAIError::RateLimit(rate_limit_error) => {
+                assert_eq!(rate_limit_error.retry_after(), Some(Duration::from_millis(250)));
+            }
+            other => panic!("Expected rate limit error, got {:?}", other),
+        }
+    }
 }
@@ -43,3 +43,10 @@ pub fn translation_response() -> serde_json::Value {
 pub fn speech_audio_bytes() -> Vec<u8> {
     vec![0xFF, 0xF3, 0x44, 0xC4] // Minimal MP3 header
 }
+
+/// Longer speech audio buffer for exercising chunked streaming
+pub fn speech_audio_stream_bytes() -> Vec<u8> {
+    let mut audio = speech_audio_bytes();
+    audio.extend((0..4096u32).map(|i| (i % 251) as u8));
+    audio
+}
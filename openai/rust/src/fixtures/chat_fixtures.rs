@@ -186,6 +186,30 @@ pub fn chat_completion_response_with_fingerprint() -> serde_json::Value {
     })
 }
 
+/// Sample chat completion response where the model refused a structured output request
+pub fn chat_completion_response_with_refusal() -> serde_json::Value {
+    json!({
+        "id": "chatcmpl-130",
+        "object": "chat.completion",
+        "created": 1677652288,
+        "model": "gpt-4o-2024-08-06",
+        "choices": [{
+            "index": 0,
+            "message": {
+                "role": "assistant",
+                "content": null,
+                "refusal": "I'm sorry, I can't help with that request."
+            },
+            "finish_reason": "stop"
+        }],
+        "usage": {
+            "prompt_tokens": 20,
+            "completion_tokens": 10,
+            "total_tokens": 30
+        }
+    })
+}
+
 /// Builder for creating custom chat completion responses
 pub struct ChatCompletionResponseBuilder {
     id: String,
@@ -94,6 +94,43 @@ pub fn embeddings_response_custom_dimensions() -> serde_json::Value {
     })
 }
 
+/// Floats encoded by [`embeddings_response_base64`]
+pub const BASE64_EMBEDDING_VALUES: [f32; 4] = [0.5, -1.25, 0.0023064255, 3.0];
+
+/// Sample embeddings response with `encoding_format: base64`
+pub fn embeddings_response_base64() -> serde_json::Value {
+    json!({
+        "object": "list",
+        "data": [{
+            "object": "embedding",
+            "embedding": "AAAAPwAAoL9mJxc7AABAQA==",
+            "index": 0
+        }],
+        "model": "text-embedding-3-small",
+        "usage": {
+            "prompt_tokens": 4,
+            "total_tokens": 4
+        }
+    })
+}
+
+/// The same embedding as [`embeddings_response_base64`] as a float array
+pub fn embeddings_response_float_array() -> serde_json::Value {
+    json!({
+        "object": "list",
+        "data": [{
+            "object": "embedding",
+            "embedding": BASE64_EMBEDDING_VALUES,
+            "index": 0
+        }],
+        "model": "text-embedding-3-small",
+        "usage": {
+            "prompt_tokens": 4,
+            "total_tokens": 4
+        }
+    })
+}
+
 /// Builder for creating custom embeddings responses
 pub struct EmbeddingsResponseBuilder {
     model: String,
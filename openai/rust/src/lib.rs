@@ -11,17 +11,24 @@ pub mod mocks;
 #[cfg(test)]
 pub mod fixtures;
 
-pub use client::{OpenAIClient, OpenAIClientBuilder, OpenAIClientImpl};
+pub use client::{
+    ModerationDecision, OpenAIClient, OpenAIClientBuilder, OpenAIClientExt, OpenAIClientImpl,
+};
 pub use errors::{OpenAIError, OpenAIResult};
 pub use types::OpenAIConfig;
 
 pub use services::{
     audio::{
         AudioService, SpeechRequest, TranscriptionRequest, TranslationRequest,
-        TranscriptionResponse, SpeechVoice, SpeechResponseFormat, AudioResponseFormat,
+        TranscriptionResponse, SpeechVoice, SpeechResponseFormat, SpeechStreamFormat,
+        AudioResponseFormat,
     },
     batches::{BatchService, BatchRequest, BatchStatus},
-    chat::{ChatCompletionRequest, ChatCompletionResponse, ChatCompletionService, ChatMessage},
+    chat::{
+        ChatCompletionRequest, ChatCompletionResponse, ChatCompletionService,
+        ChatCompletionServiceExt, ChatMessage, ChatMessageContent, ContentPart, JsonSchemaFormat,
+        ParsedChatCompletion, ResponseFormat, StructuredOutput,
+    },
     embeddings::{EmbeddingsRequest, EmbeddingsResponse, EmbeddingsService},
     files::{
         FileObject, FilePurpose, FileService, FileUploadRequest, FileListResponse,
@@ -45,9 +52,11 @@ pub use services::assistants::{
 pub use services::fine_tuning::{FineTuningJob, FineTuningService};
 
 pub mod prelude {
-    pub use crate::client::{OpenAIClient, OpenAIClientBuilder};
+    pub use crate::client::{OpenAIClient, OpenAIClientBuilder, OpenAIClientExt};
     pub use crate::errors::{OpenAIError, OpenAIResult};
-    pub use crate::services::chat::{ChatCompletionRequest, ChatCompletionService, ChatMessage};
+    pub use crate::services::chat::{
+        ChatCompletionRequest, ChatCompletionService, ChatCompletionServiceExt, ChatMessage,
+    };
     pub use crate::services::embeddings::{EmbeddingsRequest, EmbeddingsService};
     pub use crate::types::OpenAIConfig;
 }
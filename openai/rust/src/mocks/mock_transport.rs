@@ -22,6 +22,7 @@ struct MockHttpTransportInner {
     stream_responses: VecDeque<MockStreamResponse>,
     file_upload_responses: VecDeque<OpenAIResult<serde_json::Value>>,
     file_download_responses: VecDeque<OpenAIResult<Bytes>>,
+    byte_stream_responses: VecDeque<Vec<OpenAIResult<Bytes>>>,
     requests: Vec<MockRequest>,
 }
 
@@ -51,6 +52,7 @@ impl MockHttpTransport {
                 stream_responses: VecDeque::new(),
                 file_upload_responses: VecDeque::new(),
                 file_download_responses: VecDeque::new(),
+                byte_stream_responses: VecDeque::new(),
                 requests: Vec::new(),
             })),
         }
@@ -102,6 +104,13 @@ impl MockHttpTransport {
         self
     }
 
+    /// Add a raw byte stream response yielding the given chunks in order
+    pub fn with_byte_stream_response(self, chunks: Vec<OpenAIResult<Bytes>>) -> Self {
+        let mut inner = self.inner.lock().unwrap();
+        inner.byte_stream_responses.push_back(chunks);
+        self
+    }
+
     /// Get the list of requests made
     pub fn requests(&self) -> Vec<MockRequest> {
         let inner = self.inner.lock().unwrap();
@@ -143,6 +152,7 @@ impl MockHttpTransport {
         inner.stream_responses.clear();
         inner.file_upload_responses.clear();
         inner.file_download_responses.clear();
+        inner.byte_stream_responses.clear();
         inner.requests.clear();
     }
 }
@@ -284,6 +294,36 @@ impl HttpTransport for MockHttpTransport {
             .pop_front()
             .ok_or_else(|| OpenAIError::internal("No mock file download response configured"))?
     }
+
+    async fn request_byte_stream<T>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&T>,
+        _headers: Option<HeaderMap>,
+    ) -> OpenAIResult<BoxStream<Bytes>>
+    where
+        T: Serialize + Send + Sync,
+    {
+        // Record the request
+        let body_str = body.and_then(|b| serde_json::to_string(b).ok());
+        {
+            let mut inner = self.inner.lock().unwrap();
+            inner.requests.push(MockRequest {
+                method: method.clone(),
+                path: path.to_string(),
+                body: body_str,
+            });
+        }
+
+        let mut inner = self.inner.lock().unwrap();
+        let chunks = inner
+            .byte_stream_responses
+            .pop_front()
+            .ok_or_else(|| OpenAIError::internal("No mock byte stream response configured"))?;
+
+        Ok(Box::pin(stream::iter(chunks)))
+    }
 }
 
 #[cfg(test)]
@@ -28,6 +28,16 @@ pub struct ResilienceConfig {
     pub circuit_breaker_timeout: Duration,
 }
 
+impl ResilienceConfig {
+    /// Default resilience settings with the client's configured retry count
+    pub fn from_client_config(config: &crate::client::OpenAIConfig) -> Self {
+        Self {
+            max_retries: config.max_retries,
+            ..Default::default()
+        }
+    }
+}
+
 impl Default for ResilienceConfig {
     fn default() -> Self {
         Self {
@@ -216,7 +226,7 @@ impl ResilienceOrchestrator for DefaultResilienceOrchestrator {
 
                     let delay = if let OpenAIError::RateLimit(ref rate_err) = e {
                         rate_err.retry_after()
-                            .map(Duration::from_secs)
+                            .map(|delay| delay.min(self.config.max_delay))
                             .unwrap_or_else(|| self.calculate_delay(attempt))
                     } else {
                         self.calculate_delay(attempt)
@@ -326,6 +336,123 @@ mod tests {
         assert!(result.unwrap_err().is_authentication_error());
     }
 
+    #[tokio::test]
+    async fn test_resilience_orchestrator_waits_for_retry_after() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let config = ResilienceConfig {
+            max_retries: 2,
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_secs(1),
+            multiplier: 2.0,
+            jitter: false,
+            circuit_breaker_enabled: false,
+            circuit_breaker_threshold: 5,
+            circuit_breaker_timeout: Duration::from_secs(30),
+        };
+
+        let orchestrator = DefaultResilienceOrchestrator::new(config);
+
+        let attempts = AtomicU32::new(0);
+        let started = std::time::Instant::now();
+        let result = orchestrator.execute(|| {
+            let count = attempts.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if count == 0 {
+                    Err(OpenAIError::RateLimit(RateLimitError::TooManyRequests {
+                        message: "Rate limit reached".to_string(),
+                        retry_after: Some(Duration::from_millis(50)),
+                    }))
+                } else {
+                    Ok(42)
+                }
+            }
+        }).await;
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        assert!(started.elapsed() >= Duration::from_millis(50));
+    }
+
+    fn fast_retry_config() -> ResilienceConfig {
+        ResilienceConfig {
+            max_retries: 1,
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(20),
+            multiplier: 2.0,
+            jitter: false,
+            circuit_breaker_enabled: false,
+            circuit_breaker_threshold: 5,
+            circuit_breaker_timeout: Duration::from_secs(30),
+        }
+    }
+
+    /// Number of attempts made when the first one fails with `status`
+    async fn attempts_after_status(status: u16) -> u32 {
+        use crate::errors::ErrorMapper;
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let orchestrator = DefaultResilienceOrchestrator::new(fast_retry_config());
+        let attempts = AtomicU32::new(0);
+        let _ = orchestrator.execute(|| {
+            let count = attempts.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if count == 0 {
+                    Err(ErrorMapper::map_status_code(status, None))
+                } else {
+                    Ok(())
+                }
+            }
+        }).await;
+
+        attempts.load(Ordering::SeqCst)
+    }
+
+    #[tokio::test]
+    async fn test_transient_statuses_are_retried() {
+        for status in [429, 500, 502, 503] {
+            assert_eq!(attempts_after_status(status).await, 2, "status {}", status);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_client_errors_are_not_retried() {
+        for status in [400, 401] {
+            assert_eq!(attempts_after_status(status).await, 1, "status {}", status);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_is_clamped_to_max_delay() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let orchestrator = DefaultResilienceOrchestrator::new(fast_retry_config());
+        let attempts = AtomicU32::new(0);
+        let started = std::time::Instant::now();
+        let result = orchestrator.execute(|| {
+            let count = attempts.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if count == 0 {
+                    Err(OpenAIError::RateLimit(RateLimitError::TooManyRequests {
+                        message: "Rate limit reached".to_string(),
+                        retry_after: Some(Duration::from_secs(30)),
+                    }))
+                } else {
+                    Ok(42)
+                }
+            }
+        }).await;
+
+        assert_eq!(result.unwrap(), 42);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_resilience_config_uses_client_max_retries() {
+        let client_config = crate::client::OpenAIConfig::new("sk-test").with_max_retries(5);
+        assert_eq!(ResilienceConfig::from_client_config(&client_config).max_retries, 5);
+    }
+
     #[test]
     fn test_calculate_delay() {
         let config = ResilienceConfig {
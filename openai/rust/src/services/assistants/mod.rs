@@ -17,4 +17,7 @@ pub use types::{
     ToolResources, CodeInterpreterResources, FileSearchResources,
     CreateAssistantRequest, AssistantListResponse, AssistantDeleteResponse,
 };
-pub use vector_stores::{VectorStore, VectorStoreService, VectorStoreServiceImpl};
+pub use vector_stores::{
+    FileBatchFailure, FileBatchResult, FileCount, VectorStore, VectorStoreFile,
+    VectorStoreFileBatch, VectorStoreFileError, VectorStoreService, VectorStoreServiceImpl,
+};
@@ -138,3 +138,126 @@ async fn test_create_assistant_authentication_error() {
     let result = service.create(request).await;
     assert!(result.is_err());
 }
+
+fn file_batch_response(status: &str, completed: u32, failed: u32, in_progress: u32) -> serde_json::Value {
+    json!({
+        "id": "vsfb_abc123",
+        "object": "vector_store.file_batch",
+        "created_at": 1699061776,
+        "vector_store_id": "vs_abc123",
+        "status": status,
+        "file_counts": {
+            "in_progress": in_progress,
+            "completed": completed,
+            "failed": failed,
+            "cancelled": 0,
+            "total": completed + failed + in_progress
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_create_file_batch_success() {
+    let mock_transport = MockHttpTransport::new()
+        .with_json_response(file_batch_response("in_progress", 0, 0, 2));
+
+    let service = VectorStoreServiceImpl::new(
+        Arc::new(mock_transport.clone()),
+        Arc::new(MockAuthManager::new()),
+        Arc::new(MockResilienceOrchestrator::passthrough()),
+    );
+
+    let batch = service
+        .create_file_batch("vs_abc123", vec!["file-1".to_string(), "file-2".to_string()])
+        .await
+        .unwrap();
+
+    assert_eq!(batch.id, "vsfb_abc123");
+    assert!(!batch.is_terminal());
+    assert!(mock_transport.verify_request_with_body(
+        Method::POST,
+        "/vector_stores/vs_abc123/file_batches",
+        r#""file_ids":["file-1","file-2"]"#,
+    ));
+}
+
+#[tokio::test]
+async fn test_poll_file_batch_completed_with_failure() {
+    let mock_transport = MockHttpTransport::new()
+        .with_json_response(file_batch_response("in_progress", 1, 0, 1))
+        .with_json_response(file_batch_response("completed", 1, 1, 0))
+        .with_json_response(json!({
+            "object": "list",
+            "data": [{
+                "id": "file-2",
+                "object": "vector_store.file",
+                "created_at": 1699061776,
+                "vector_store_id": "vs_abc123",
+                "status": "failed",
+                "last_error": {
+                    "code": "unsupported_file",
+                    "message": "The file type is not supported."
+                }
+            }],
+            "first_id": "file-2",
+            "last_id": "file-2",
+            "has_more": false
+        }));
+
+    let service = VectorStoreServiceImpl::new(
+        Arc::new(mock_transport.clone()),
+        Arc::new(MockAuthManager::new()),
+        Arc::new(MockResilienceOrchestrator::passthrough()),
+    );
+
+    let result = service
+        .poll_batch_until_complete(
+            "vs_abc123",
+            "vsfb_abc123",
+            std::time::Duration::from_millis(1),
+            std::time::Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.batch.status, "completed");
+    assert_eq!(result.completed, 1);
+    assert_eq!(result.failed, 1);
+    assert!(result.has_failures());
+    assert_eq!(result.failures.len(), 1);
+    assert_eq!(result.failures[0].file_id, "file-2");
+    assert_eq!(result.failures[0].code.as_deref(), Some("unsupported_file"));
+    assert_eq!(
+        result.failures[0].message.as_deref(),
+        Some("The file type is not supported.")
+    );
+
+    assert_eq!(mock_transport.request_count(), 3);
+    assert!(mock_transport.verify_request(
+        Method::GET,
+        "/vector_stores/vs_abc123/file_batches/vsfb_abc123/files?filter=failed"
+    ));
+}
+
+#[tokio::test]
+async fn test_poll_file_batch_times_out() {
+    let mock_transport = MockHttpTransport::new()
+        .with_json_response(file_batch_response("in_progress", 0, 0, 1));
+
+    let service = VectorStoreServiceImpl::new(
+        Arc::new(mock_transport),
+        Arc::new(MockAuthManager::new()),
+        Arc::new(MockResilienceOrchestrator::passthrough()),
+    );
+
+    let result = service
+        .poll_batch_until_complete(
+            "vs_abc123",
+            "vsfb_abc123",
+            std::time::Duration::from_millis(50),
+            std::time::Duration::from_millis(10),
+        )
+        .await;
+
+    assert!(matches!(result, Err(OpenAIError::Timeout { .. })));
+}
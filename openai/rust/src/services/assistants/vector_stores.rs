@@ -1,5 +1,5 @@
 use crate::auth::AuthManager;
-use crate::errors::OpenAIResult;
+use crate::errors::{OpenAIError, OpenAIResult};
 use crate::resilience::ResilienceOrchestrator;
 use crate::transport::HttpTransport;
 use crate::types::{DeletionStatus, ListResponse};
@@ -8,6 +8,7 @@ use http::Method;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VectorStore {
@@ -32,12 +33,95 @@ pub struct FileCount {
     pub total: u32,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VectorStoreFileBatch {
+    pub id: String,
+    pub object: String,
+    pub created_at: i64,
+    pub vector_store_id: String,
+    pub status: String,
+    pub file_counts: FileCount,
+}
+
+impl VectorStoreFileBatch {
+    pub fn is_terminal(&self) -> bool {
+        matches!(self.status.as_str(), "completed" | "failed" | "cancelled")
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VectorStoreFile {
+    pub id: String,
+    pub object: String,
+    pub created_at: i64,
+    pub vector_store_id: String,
+    pub status: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<VectorStoreFileError>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VectorStoreFileError {
+    pub code: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct FileBatchFailure {
+    pub file_id: String,
+    pub code: Option<String>,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FileBatchResult {
+    pub batch: VectorStoreFileBatch,
+    pub completed: u32,
+    pub failed: u32,
+    pub cancelled: u32,
+    pub failures: Vec<FileBatchFailure>,
+}
+
+impl FileBatchResult {
+    pub fn has_failures(&self) -> bool {
+        self.failed > 0
+    }
+}
+
 #[async_trait]
 pub trait VectorStoreService: Send + Sync {
     async fn create(&self, name: &str) -> OpenAIResult<VectorStore>;
     async fn retrieve(&self, vector_store_id: &str) -> OpenAIResult<VectorStore>;
     async fn delete(&self, vector_store_id: &str) -> OpenAIResult<DeletionStatus>;
     async fn list(&self) -> OpenAIResult<ListResponse<VectorStore>>;
+
+    async fn create_file_batch(
+        &self,
+        vector_store_id: &str,
+        file_ids: Vec<String>,
+    ) -> OpenAIResult<VectorStoreFileBatch>;
+
+    async fn retrieve_file_batch(
+        &self,
+        vector_store_id: &str,
+        batch_id: &str,
+    ) -> OpenAIResult<VectorStoreFileBatch>;
+
+    async fn list_file_batch_files(
+        &self,
+        vector_store_id: &str,
+        batch_id: &str,
+        filter: Option<&str>,
+    ) -> OpenAIResult<ListResponse<VectorStoreFile>>;
+
+    async fn poll_batch_until_complete(
+        &self,
+        vector_store_id: &str,
+        batch_id: &str,
+        interval: Duration,
+        timeout: Duration,
+    ) -> OpenAIResult<FileBatchResult>;
 }
 
 pub struct VectorStoreServiceImpl {
@@ -131,6 +215,130 @@ impl VectorStoreService for VectorStoreServiceImpl {
             })
             .await
     }
+
+    async fn create_file_batch(
+        &self,
+        vector_store_id: &str,
+        file_ids: Vec<String>,
+    ) -> OpenAIResult<VectorStoreFileBatch> {
+        let mut headers = http::HeaderMap::new();
+        self.auth_manager.apply_auth(&mut headers).await?;
+        headers.insert("OpenAI-Beta", "assistants=v2".parse().unwrap());
+
+        let path = format!("/vector_stores/{}/file_batches", vector_store_id);
+        let body = serde_json::json!({ "file_ids": file_ids });
+
+        self.resilience
+            .execute(async {
+                self.transport
+                    .request(Method::POST, &path, Some(&body), Some(headers.clone()))
+                    .await
+            })
+            .await
+    }
+
+    async fn retrieve_file_batch(
+        &self,
+        vector_store_id: &str,
+        batch_id: &str,
+    ) -> OpenAIResult<VectorStoreFileBatch> {
+        let mut headers = http::HeaderMap::new();
+        self.auth_manager.apply_auth(&mut headers).await?;
+        headers.insert("OpenAI-Beta", "assistants=v2".parse().unwrap());
+
+        let path = format!("/vector_stores/{}/file_batches/{}", vector_store_id, batch_id);
+        self.resilience
+            .execute(async {
+                self.transport
+                    .request::<(), VectorStoreFileBatch>(
+                        Method::GET,
+                        &path,
+                        None,
+                        Some(headers.clone()),
+                    )
+                    .await
+            })
+            .await
+    }
+
+    async fn list_file_batch_files(
+        &self,
+        vector_store_id: &str,
+        batch_id: &str,
+        filter: Option<&str>,
+    ) -> OpenAIResult<ListResponse<VectorStoreFile>> {
+        let mut headers = http::HeaderMap::new();
+        self.auth_manager.apply_auth(&mut headers).await?;
+        headers.insert("OpenAI-Beta", "assistants=v2".parse().unwrap());
+
+        let mut path = format!(
+            "/vector_stores/{}/file_batches/{}/files",
+            vector_store_id, batch_id
+        );
+        if let Some(filter) = filter {
+            path.push_str(&format!("?filter={}", filter));
+        }
+
+        self.resilience
+            .execute(async {
+                self.transport
+                    .request::<(), ListResponse<VectorStoreFile>>(
+                        Method::GET,
+                        &path,
+                        None,
+                        Some(headers.clone()),
+                    )
+                    .await
+            })
+            .await
+    }
+
+    async fn poll_batch_until_complete(
+        &self,
+        vector_store_id: &str,
+        batch_id: &str,
+        interval: Duration,
+        timeout: Duration,
+    ) -> OpenAIResult<FileBatchResult> {
+        let started = Instant::now();
+
+        let batch = loop {
+            let batch = self.retrieve_file_batch(vector_store_id, batch_id).await?;
+            if batch.is_terminal() {
+                break batch;
+            }
+
+            if started.elapsed() + interval > timeout {
+                return Err(OpenAIError::Timeout {
+                    timeout_ms: timeout.as_millis() as u64,
+                });
+            }
+            tokio::time::sleep(interval).await;
+        };
+
+        let failures = if batch.file_counts.failed > 0 {
+            self.list_file_batch_files(vector_store_id, batch_id, Some("failed"))
+                .await?
+                .data
+                .into_iter()
+                .map(|file| FileBatchFailure {
+                    file_id: file.id,
+                    code: file.last_error.as_ref().map(|e| e.code.clone()),
+                    message: file.last_error.map(|e| e.message),
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Ok(FileBatchResult {
+            completed: batch.file_counts.completed,
+            failed: batch.file_counts.failed,
+            cancelled: batch.file_counts.cancelled,
+            failures,
+            batch,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -8,6 +8,7 @@ mod tests;
 pub use service::{AudioService, AudioServiceImpl};
 pub use types::{
     SpeechRequest, TranscriptionRequest, TranslationRequest, TranscriptionResponse,
-    SpeechVoice, SpeechResponseFormat, AudioResponseFormat, Word, Segment,
+    SpeechVoice, SpeechResponseFormat, SpeechStreamFormat, AudioResponseFormat, Word, Segment,
+    SPEECH_MODELS, SPEECH_MODEL_GPT_4O_MINI_TTS, SPEECH_MODEL_TTS_1, SPEECH_MODEL_TTS_1_HD,
 };
 pub use validation::AudioRequestValidator;
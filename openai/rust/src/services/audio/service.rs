@@ -1,18 +1,36 @@
 use crate::auth::AuthManager;
-use crate::errors::OpenAIResult;
+use crate::errors::{OpenAIError, OpenAIResult};
 use crate::resilience::ResilienceOrchestrator;
-use crate::services::audio::{SpeechRequest, TranscriptionRequest, TranscriptionResponse, TranslationRequest};
-use crate::transport::HttpTransport;
+use crate::services::audio::{
+    AudioRequestValidator, SpeechRequest, SpeechStreamFormat, TranscriptionRequest,
+    TranscriptionResponse, TranslationRequest,
+};
+use crate::transport::{BoxStream, HttpTransport};
 use async_trait::async_trait;
 use bytes::Bytes;
+use futures::StreamExt;
 use http::Method;
 use std::sync::Arc;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
 
 #[async_trait]
 pub trait AudioService: Send + Sync {
     async fn transcribe(&self, request: TranscriptionRequest) -> OpenAIResult<TranscriptionResponse>;
     async fn translate(&self, request: TranslationRequest) -> OpenAIResult<TranscriptionResponse>;
     async fn speech(&self, request: SpeechRequest) -> OpenAIResult<Bytes>;
+
+    /// Streams speech audio chunks as they are generated
+    ///
+    /// A stream that ends early yields `OpenAIError::StreamTruncated` with the
+    /// number of bytes received so far.
+    async fn create_speech_stream(&self, request: SpeechRequest) -> OpenAIResult<BoxStream<Bytes>>;
+
+    /// Streams speech audio into a writer, returning the number of bytes written
+    async fn speech_to_writer(
+        &self,
+        request: SpeechRequest,
+        writer: &mut (dyn AsyncWrite + Unpin + Send),
+    ) -> OpenAIResult<u64>;
 }
 
 pub struct AudioServiceImpl {
@@ -112,6 +130,62 @@ impl AudioService for AudioServiceImpl {
             })
             .await
     }
+
+    async fn create_speech_stream(
+        &self,
+        mut request: SpeechRequest,
+    ) -> OpenAIResult<BoxStream<Bytes>> {
+        request.stream_format.get_or_insert(SpeechStreamFormat::Audio);
+        AudioRequestValidator::validate_stream(&request)?;
+
+        let mut headers = http::HeaderMap::new();
+        self.auth_manager.apply_auth(&mut headers).await?;
+
+        // Only the initial response is retried; a stream that fails midway is not replayed
+        let stream = self
+            .resilience
+            .execute(|| async {
+                self.transport
+                    .request_byte_stream(Method::POST, "/audio/speech", Some(&request), Some(headers.clone()))
+                    .await
+            })
+            .await?;
+
+        Ok(track_truncation(stream))
+    }
+
+    async fn speech_to_writer(
+        &self,
+        request: SpeechRequest,
+        writer: &mut (dyn AsyncWrite + Unpin + Send),
+    ) -> OpenAIResult<u64> {
+        let mut stream = self.create_speech_stream(request).await?;
+
+        let mut written = 0u64;
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            writer.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().await?;
+
+        Ok(written)
+    }
+}
+
+/// Reports a stream error as truncation, carrying the bytes received before it
+fn track_truncation(stream: BoxStream<Bytes>) -> BoxStream<Bytes> {
+    let mut bytes_received = 0u64;
+    Box::pin(stream.map(move |chunk| match chunk {
+        Ok(bytes) => {
+            bytes_received += bytes.len() as u64;
+            Ok(bytes)
+        }
+        Err(err) => Err(OpenAIError::StreamTruncated {
+            bytes_received,
+            message: err.to_string(),
+        }),
+    }))
 }
 
 #[cfg(test)]
@@ -5,6 +5,7 @@ use crate::errors::OpenAIError;
 use crate::fixtures::*;
 use crate::mocks::{MockAuthManager, MockHttpTransport, MockResilienceOrchestrator};
 use bytes::Bytes;
+use futures::StreamExt;
 use http::Method;
 use std::sync::Arc;
 
@@ -113,3 +114,83 @@ async fn test_transcription_authentication_error() {
     let result = service.transcribe(request).await;
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_speech_stream_chunks_match_fixture() {
+    let audio = speech_audio_stream_bytes();
+    let chunks = audio
+        .chunks(1000)
+        .map(|chunk| Ok(Bytes::copy_from_slice(chunk)))
+        .collect();
+    let mock_transport = MockHttpTransport::new().with_byte_stream_response(chunks);
+
+    let service = create_test_service(
+        mock_transport.clone(),
+        MockAuthManager::new(),
+        MockResilienceOrchestrator::passthrough(),
+    );
+
+    let request = SpeechRequest::new("Hello world", SpeechVoice::Alloy)
+        .with_response_format(SpeechResponseFormat::Pcm);
+    let mut stream = service.create_speech_stream(request).await.unwrap();
+
+    let mut received = Vec::new();
+    let mut chunk_count = 0;
+    while let Some(chunk) = stream.next().await {
+        received.extend_from_slice(&chunk.unwrap());
+        chunk_count += 1;
+    }
+
+    assert_eq!(received, audio);
+    assert_eq!(chunk_count, 5);
+    assert!(mock_transport.verify_request_with_body(
+        Method::POST,
+        "/audio/speech",
+        "\"stream_format\":\"audio\""
+    ));
+}
+
+#[tokio::test]
+async fn test_speech_to_writer_reports_truncated_stream() {
+    let audio = speech_audio_stream_bytes();
+    let mock_transport = MockHttpTransport::new().with_byte_stream_response(vec![
+        Ok(Bytes::copy_from_slice(&audio[..1000])),
+        Ok(Bytes::copy_from_slice(&audio[1000..1500])),
+        Err(OpenAIError::Stream("connection closed before message completed".to_string())),
+    ]);
+
+    let service = create_test_service(
+        mock_transport,
+        MockAuthManager::new(),
+        MockResilienceOrchestrator::passthrough(),
+    );
+
+    let mut writer = Vec::new();
+    let request = SpeechRequest::new("Hello world", SpeechVoice::Alloy);
+    let result = service.speech_to_writer(request, &mut writer).await;
+
+    match result {
+        Err(OpenAIError::StreamTruncated { bytes_received, .. }) => {
+            assert_eq!(bytes_received, 1500)
+        }
+        other => panic!("Expected StreamTruncated, got {:?}", other),
+    }
+    assert_eq!(writer, &audio[..1500]);
+}
+
+#[tokio::test]
+async fn test_speech_stream_rejects_unsupported_voice() {
+    let mock_transport = MockHttpTransport::new();
+
+    let service = create_test_service(
+        mock_transport.clone(),
+        MockAuthManager::new(),
+        MockResilienceOrchestrator::passthrough(),
+    );
+
+    let request = SpeechRequest::new("Hello world", SpeechVoice::Ballad);
+    let result = service.create_speech_stream(request).await;
+
+    assert!(matches!(result, Err(OpenAIError::Validation(_))));
+    assert_eq!(mock_transport.request_count(), 0);
+}
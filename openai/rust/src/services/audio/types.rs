@@ -16,13 +16,39 @@ pub enum AudioResponseFormat {
 #[serde(rename_all = "lowercase")]
 pub enum SpeechVoice {
     Alloy,
+    Ash,
+    Ballad,
+    Coral,
     Echo,
     Fable,
     Onyx,
     Nova,
+    Sage,
     Shimmer,
+    Verse,
 }
 
+impl SpeechVoice {
+    /// Whether the voice is available on the given speech model
+    pub fn is_supported_by(&self, model: &str) -> bool {
+        match self {
+            SpeechVoice::Ballad | SpeechVoice::Verse => model == SPEECH_MODEL_GPT_4O_MINI_TTS,
+            _ => SPEECH_MODELS.contains(&model),
+        }
+    }
+}
+
+pub const SPEECH_MODEL_TTS_1: &str = "tts-1";
+pub const SPEECH_MODEL_TTS_1_HD: &str = "tts-1-hd";
+pub const SPEECH_MODEL_GPT_4O_MINI_TTS: &str = "gpt-4o-mini-tts";
+
+/// Models accepted by the speech endpoint
+pub const SPEECH_MODELS: &[&str] = &[
+    SPEECH_MODEL_TTS_1,
+    SPEECH_MODEL_TTS_1_HD,
+    SPEECH_MODEL_GPT_4O_MINI_TTS,
+];
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum SpeechResponseFormat {
@@ -34,6 +60,16 @@ pub enum SpeechResponseFormat {
     Pcm,
 }
 
+/// How speech audio is delivered when streaming
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpeechStreamFormat {
+    /// Raw audio bytes in the chosen response format
+    Audio,
+    /// Server-sent events carrying base64 audio deltas
+    Sse,
+}
+
 #[derive(Debug, Clone)]
 pub struct TranscriptionRequest {
     pub file: Bytes,
@@ -95,21 +131,35 @@ pub struct SpeechRequest {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub speed: Option<f32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream_format: Option<SpeechStreamFormat>,
 }
 
 impl SpeechRequest {
     pub fn new(input: impl Into<String>, voice: SpeechVoice) -> Self {
         Self {
-            model: "tts-1".to_string(),
+            model: SPEECH_MODEL_TTS_1.to_string(),
             input: input.into(),
             voice,
             response_format: None,
             speed: None,
+            stream_format: None,
         }
     }
 
     pub fn with_hd(mut self) -> Self {
-        self.model = "tts-1-hd".to_string();
+        self.model = SPEECH_MODEL_TTS_1_HD.to_string();
+        self
+    }
+
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = model.into();
+        self
+    }
+
+    pub fn with_response_format(mut self, format: SpeechResponseFormat) -> Self {
+        self.response_format = Some(format);
         self
     }
 
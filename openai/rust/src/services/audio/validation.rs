@@ -1,5 +1,7 @@
 use crate::errors::{OpenAIError, OpenAIResult, ValidationError};
-use crate::services::audio::SpeechRequest;
+use crate::services::audio::{
+    SpeechRequest, SpeechStreamFormat, SPEECH_MODELS, SPEECH_MODEL_GPT_4O_MINI_TTS,
+};
 
 pub struct AudioRequestValidator;
 
@@ -30,12 +32,46 @@ impl AudioRequestValidator {
 
         Ok(())
     }
+
+    /// Validates a speech request for streaming, including the model, voice and stream format
+    pub fn validate_stream(request: &SpeechRequest) -> OpenAIResult<()> {
+        Self::validate(request)?;
+
+        if !SPEECH_MODELS.contains(&request.model.as_str()) {
+            return Err(OpenAIError::Validation(ValidationError::InvalidModel(format!(
+                "{} is not a speech model; expected one of {}",
+                request.model,
+                SPEECH_MODELS.join(", ")
+            ))));
+        }
+
+        if !request.voice.is_supported_by(&request.model) {
+            return Err(OpenAIError::Validation(ValidationError::InvalidParameter {
+                parameter: "voice".to_string(),
+                reason: format!(
+                    "voice {:?} is not available for model {}",
+                    request.voice, request.model
+                ),
+            }));
+        }
+
+        if request.stream_format == Some(SpeechStreamFormat::Sse)
+            && request.model != SPEECH_MODEL_GPT_4O_MINI_TTS
+        {
+            return Err(OpenAIError::Validation(ValidationError::InvalidParameter {
+                parameter: "stream_format".to_string(),
+                reason: format!("sse streaming is not supported by model {}", request.model),
+            }));
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::services::audio::AudioVoice;
+    use crate::services::audio::{AudioVoice, SpeechVoice};
 
     #[test]
     fn test_validate_valid_request() {
@@ -48,4 +84,25 @@ mod tests {
         let request = SpeechRequest::new("tts-1", "", AudioVoice::Alloy);
         assert!(AudioRequestValidator::validate(&request).is_err());
     }
+
+    #[test]
+    fn test_validate_stream_voice_model_combination() {
+        let request = SpeechRequest::new("Hello", SpeechVoice::Verse);
+        assert!(matches!(
+            AudioRequestValidator::validate_stream(&request),
+            Err(OpenAIError::Validation(ValidationError::InvalidParameter { .. }))
+        ));
+
+        let request = request.with_model(SPEECH_MODEL_GPT_4O_MINI_TTS);
+        assert!(AudioRequestValidator::validate_stream(&request).is_ok());
+    }
+
+    #[test]
+    fn test_validate_stream_unknown_model() {
+        let request = SpeechRequest::new("Hello", SpeechVoice::Alloy).with_model("gpt-4o");
+        assert!(matches!(
+            AudioRequestValidator::validate_stream(&request),
+            Err(OpenAIError::Validation(ValidationError::InvalidModel(_)))
+        ));
+    }
 }
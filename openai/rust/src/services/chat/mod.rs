@@ -1,5 +1,6 @@
 mod service;
 mod stream;
+mod structured;
 mod types;
 mod validation;
 
@@ -8,10 +9,12 @@ mod tests;
 
 pub use service::{ChatCompletionService, ChatCompletionServiceImpl};
 pub use stream::ChatCompletionStream;
+pub use structured::{ChatCompletionServiceExt, ParsedChatCompletion, StructuredOutput};
 pub use types::{
-    ChatCompletionRequest, ChatCompletionResponse, ChatMessage, ChatMessageRole,
+    ChatCompletionRequest, ChatCompletionResponse, ChatMessage, ChatMessageContent, ChatMessageRole,
+    ContentPart, ImageUrl,
     ChatCompletionChoice, FinishReason, FunctionCall, ToolCall, ToolChoice,
     ChatCompletionChunk, ChatChunkChoice, ChatDelta, ToolCallDelta, FunctionCallDelta,
-    Tool, FunctionDefinition, ResponseFormat,
+    Tool, FunctionDefinition, ResponseFormat, JsonSchemaFormat, StreamOptions,
 };
 pub use validation::ChatRequestValidator;
@@ -61,9 +61,14 @@ impl ChatCompletionService for ChatCompletionServiceImpl {
         let mut headers = http::HeaderMap::new();
         self.auth_manager.apply_auth(&mut headers).await?;
 
+        // Only the initial response is retried; a stream that fails midway is not replayed
         let stream = self
-            .transport
-            .request_stream(Method::POST, "/chat/completions", Some(&request), Some(headers))
+            .resilience
+            .execute(|| async {
+                self.transport
+                    .request_stream(Method::POST, "/chat/completions", Some(&request), Some(headers.clone()))
+                    .await
+            })
             .await?;
 
         Ok(ChatCompletionStream::new(stream))
@@ -1,5 +1,6 @@
 use crate::errors::{OpenAIError, OpenAIResult};
 use crate::services::chat::{ChatCompletionChunk, ChatMessage, ChatMessageRole};
+use crate::types::Usage;
 use bytes::Bytes;
 use futures::Stream;
 use pin_project_lite::pin_project;
@@ -19,33 +20,43 @@ impl ChatCompletionStream {
         S: Stream<Item = Result<Bytes, OpenAIError>> + Send + 'static,
     {
         let parsed_stream = futures::stream::unfold(
-            (stream, String::new()),
-            |(mut stream, mut buffer)| async move {
+            (Box::pin(stream), String::new(), false),
+            |(mut stream, mut buffer, mut done)| async move {
                 use futures::StreamExt;
 
                 loop {
+                    if done {
+                        return None;
+                    }
+
+                    while let Some((event, rest)) = split_event(&buffer) {
+                        buffer = rest;
+
+                        match parse_event(&event) {
+                            SseEvent::Chunk(result) => {
+                                return Some((result, (stream, buffer, done)));
+                            }
+                            SseEvent::Done => return None,
+                            SseEvent::Ignored => {}
+                        }
+                    }
+
                     match stream.next().await {
                         Some(Ok(bytes)) => {
                             buffer.push_str(&String::from_utf8_lossy(&bytes));
-
-                            while let Some(pos) = buffer.find("\n\n") {
-                                let event = buffer[..pos].to_string();
-                                buffer = buffer[pos + 2..].to_string();
-
-                                if let Some(data) = event.strip_prefix("data: ") {
-                                    let data = data.trim();
-                                    if data == "[DONE]" {
-                                        return None;
-                                    }
-                                    match serde_json::from_str::<ChatCompletionChunk>(data) {
-                                        Ok(chunk) => return Some((Ok(chunk), (stream, buffer))),
-                                        Err(e) => return Some((Err(OpenAIError::Deserialization(e.to_string())), (stream, buffer))),
-                                    }
-                                }
+                            if buffer.contains('\r') {
+                                buffer = buffer.replace("\r\n", "\n");
                             }
                         }
-                        Some(Err(e)) => return Some((Err(e), (stream, buffer))),
-                        None => return None,
+                        Some(Err(e)) => return Some((Err(e), (stream, buffer, done))),
+                        None => {
+                            done = true;
+                            let event = std::mem::take(&mut buffer);
+                            return match parse_event(&event) {
+                                SseEvent::Chunk(result) => Some((result, (stream, buffer, done))),
+                                SseEvent::Done | SseEvent::Ignored => None,
+                            };
+                        }
                     }
                 }
             },
@@ -69,6 +80,57 @@ impl ChatCompletionStream {
         }
         Ok(content)
     }
+
+    pub async fn collect_with_usage(mut self) -> OpenAIResult<(String, Option<Usage>)> {
+        use futures::StreamExt;
+        let mut content = String::new();
+        let mut usage = None;
+        while let Some(result) = self.next().await {
+            let chunk = result?;
+            if let Some(u) = chunk.usage {
+                usage = Some(u);
+            }
+            for choice in chunk.choices {
+                if let Some(c) = choice.delta.content {
+                    content.push_str(&c);
+                }
+            }
+        }
+        Ok((content, usage))
+    }
+}
+
+enum SseEvent {
+    Chunk(OpenAIResult<ChatCompletionChunk>),
+    Done,
+    Ignored,
+}
+
+fn split_event(buffer: &str) -> Option<(String, String)> {
+    let pos = buffer.find("\n\n")?;
+    Some((buffer[..pos].to_string(), buffer[pos + 2..].to_string()))
+}
+
+fn parse_event(event: &str) -> SseEvent {
+    let data = event
+        .lines()
+        .filter_map(|line| line.strip_prefix("data:"))
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let data = data.trim();
+
+    if data.is_empty() {
+        return SseEvent::Ignored;
+    }
+    if data == "[DONE]" {
+        return SseEvent::Done;
+    }
+
+    SseEvent::Chunk(
+        serde_json::from_str::<ChatCompletionChunk>(data)
+            .map_err(|e| OpenAIError::Deserialization(e.to_string())),
+    )
 }
 
 impl Stream for ChatCompletionStream {
@@ -88,4 +150,73 @@ mod tests {
         fn assert_send<T: Send>() {}
         assert_send::<ChatCompletionStream>();
     }
+
+    const USAGE_TRANSCRIPT: &str = concat!(
+        "data: {\"id\":\"chatcmpl-1\",\"object\":\"chat.completion.chunk\",\"created\":1,\"model\":\"gpt-4o\",",
+        "\"choices\":[{\"index\":0,\"delta\":{\"role\":\"assistant\",\"content\":\"Hel\"},\"finish_reason\":null}],\"usage\":null}\n\n",
+        "data: {\"id\":\"chatcmpl-1\",\"object\":\"chat.completion.chunk\",\"created\":1,\"model\":\"gpt-4o\",",
+        "\"choices\":[{\"index\":0,\"delta\":{\"content\":\"lo\"},\"finish_reason\":null}],\"usage\":null}\n\n",
+        "data: {\"id\":\"chatcmpl-1\",\"object\":\"chat.completion.chunk\",\"created\":1,\"model\":\"gpt-4o\",",
+        "\"choices\":[{\"index\":0,\"delta\":{},\"finish_reason\":\"stop\"}],\"usage\":null}\n\n",
+        "data: {\"id\":\"chatcmpl-1\",\"object\":\"chat.completion.chunk\",\"created\":1,\"model\":\"gpt-4o\",",
+        "\"choices\":[],\"usage\":{\"prompt_tokens\":9,\"completion_tokens\":2,\"total_tokens\":11}}\n\n",
+        "data: [DONE]\n\n",
+    );
+
+    fn byte_stream(
+        transcript: &str,
+        chunk_size: usize,
+    ) -> impl Stream<Item = Result<Bytes, OpenAIError>> + Send + 'static {
+        let pieces: Vec<Result<Bytes, OpenAIError>> = transcript
+            .as_bytes()
+            .chunks(chunk_size)
+            .map(|c| Ok(Bytes::copy_from_slice(c)))
+            .collect();
+        futures::stream::iter(pieces)
+    }
+
+    #[tokio::test]
+    async fn test_stream_surfaces_usage_chunk_last() {
+        use futures::StreamExt;
+
+        let chunks: Vec<ChatCompletionChunk> = ChatCompletionStream::new(byte_stream(USAGE_TRANSCRIPT, 7))
+            .map(|c| c.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(chunks.len(), 4);
+        assert!(chunks[..3].iter().all(|c| !c.is_usage_only()));
+
+        let last = chunks.last().unwrap();
+        assert!(last.is_usage_only());
+        let usage = last.usage.as_ref().unwrap();
+        assert_eq!(usage.prompt_tokens, 9);
+        assert_eq!(usage.completion_tokens, Some(2));
+        assert_eq!(usage.total_tokens, 11);
+    }
+
+    #[tokio::test]
+    async fn test_collect_with_usage() {
+        let (content, usage) = ChatCompletionStream::new(byte_stream(USAGE_TRANSCRIPT, 64))
+            .collect_with_usage()
+            .await
+            .unwrap();
+
+        assert_eq!(content, "Hello");
+        assert_eq!(usage.unwrap().total_tokens, 11);
+    }
+
+    #[tokio::test]
+    async fn test_stream_without_usage_chunk() {
+        let end = USAGE_TRANSCRIPT.rfind("data: {").unwrap();
+        let transcript = format!("{}data: [DONE]\n\n", &USAGE_TRANSCRIPT[..end]);
+
+        let (content, usage) = ChatCompletionStream::new(byte_stream(&transcript, 64))
+            .collect_with_usage()
+            .await
+            .unwrap();
+
+        assert_eq!(content, "Hello");
+        assert!(usage.is_none());
+    }
 }
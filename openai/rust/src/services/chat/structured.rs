@@ -0,0 +1,79 @@
+use crate::errors::{OpenAIError, OpenAIResult};
+use crate::services::chat::{
+    ChatCompletionRequest, ChatCompletionResponse, ChatCompletionService, JsonSchemaFormat,
+    ResponseFormat,
+};
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+
+pub trait StructuredOutput: DeserializeOwned {
+    fn schema_name() -> String;
+
+    fn json_schema() -> serde_json::Value;
+
+    fn response_format() -> JsonSchemaFormat {
+        JsonSchemaFormat::strict(Self::schema_name(), Self::json_schema())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ParsedChatCompletion<T> {
+    pub parsed: T,
+    pub response: ChatCompletionResponse,
+}
+
+impl<T: DeserializeOwned> ParsedChatCompletion<T> {
+    pub fn from_response(response: ChatCompletionResponse) -> OpenAIResult<Self> {
+        let message = &response
+            .choices
+            .first()
+            .ok_or_else(|| OpenAIError::Deserialization("response contained no choices".to_string()))?
+            .message;
+
+        if let Some(refusal) = &message.refusal {
+            return Err(OpenAIError::Refusal(refusal.clone()));
+        }
+
+        let content = message.content.as_ref().and_then(|c| c.as_text()).ok_or_else(|| {
+            OpenAIError::Deserialization("assistant message has no content to parse".to_string())
+        })?;
+
+        let parsed = serde_json::from_str(content).map_err(|e| {
+            OpenAIError::Deserialization(format!("failed to parse structured output: {}", e))
+        })?;
+
+        Ok(Self { parsed, response })
+    }
+}
+
+#[async_trait]
+pub trait ChatCompletionServiceExt: ChatCompletionService {
+    async fn create_parsed<T>(
+        &self,
+        request: ChatCompletionRequest,
+    ) -> OpenAIResult<ParsedChatCompletion<T>>
+    where
+        T: StructuredOutput + Send,
+    {
+        self.create_parsed_with_schema(request, T::response_format())
+            .await
+    }
+
+    async fn create_parsed_with_schema<T>(
+        &self,
+        mut request: ChatCompletionRequest,
+        schema: JsonSchemaFormat,
+    ) -> OpenAIResult<ParsedChatCompletion<T>>
+    where
+        T: DeserializeOwned + Send,
+    {
+        request.response_format = Some(ResponseFormat::JsonSchema {
+            json_schema: schema,
+        });
+
+        let response = self.create(request).await?;
+        ParsedChatCompletion::from_response(response)
+    }
+}
+
+impl<S: ChatCompletionService + ?Sized> ChatCompletionServiceExt for S {}
@@ -454,13 +454,127 @@ fn test_chat_completion_request_builder() {
 fn test_chat_message_builders() {
     let system_msg = ChatMessage::system("You are helpful");
     assert_eq!(system_msg.role, ChatMessageRole::System);
-    assert_eq!(system_msg.content, Some("You are helpful".to_string()));
+    assert_eq!(system_msg.content, Some("You are helpful".into()));
 
     let user_msg = ChatMessage::user("Hello");
     assert_eq!(user_msg.role, ChatMessageRole::User);
-    assert_eq!(user_msg.content, Some("Hello".to_string()));
+    assert_eq!(user_msg.content, Some("Hello".into()));
 
     let assistant_msg = ChatMessage::assistant("Hi there!");
     assert_eq!(assistant_msg.role, ChatMessageRole::Assistant);
-    assert_eq!(assistant_msg.content, Some("Hi there!".to_string()));
+    assert_eq!(assistant_msg.content, Some("Hi there!".into()));
+}
+
+#[derive(Debug, serde::Deserialize, PartialEq)]
+struct WeatherReport {
+    city: String,
+    temperature_c: f64,
+}
+
+impl StructuredOutput for WeatherReport {
+    fn schema_name() -> String {
+        "weather_report".to_string()
+    }
+
+    fn json_schema() -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "city": {"type": "string"},
+                "temperature_c": {"type": "number"}
+            },
+            "required": ["city", "temperature_c"],
+            "additionalProperties": false
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_chat_completion_create_parsed_success() {
+    let mock_transport = MockHttpTransport::new().with_json_response(
+        ChatCompletionResponseBuilder::new()
+            .with_content(r#"{"city":"Paris","temperature_c":21.5}"#)
+            .build(),
+    );
+
+    let service = create_test_service(
+        mock_transport.clone(),
+        MockAuthManager::new(),
+        MockResilienceOrchestrator::passthrough(),
+    );
+
+    let request = ChatCompletionRequest::new(
+        "gpt-4o",
+        vec![ChatMessage::user("What's the weather in Paris?")],
+    );
+
+    let result = service.create_parsed::<WeatherReport>(request).await.unwrap();
+
+    assert_eq!(
+        result.parsed,
+        WeatherReport {
+            city: "Paris".to_string(),
+            temperature_c: 21.5,
+        }
+    );
+    assert!(mock_transport.verify_request_with_body(
+        Method::POST,
+        "/chat/completions",
+        r#""response_format":{"type":"json_schema","json_schema":{"name":"weather_report""#,
+    ));
+    assert!(mock_transport.verify_request_with_body(
+        Method::POST,
+        "/chat/completions",
+        r#""strict":true"#,
+    ));
+}
+
+#[tokio::test]
+async fn test_chat_completion_create_parsed_refusal() {
+    let mock_transport = MockHttpTransport::new()
+        .with_json_response(chat_completion_response_with_refusal());
+
+    let service = create_test_service(
+        mock_transport,
+        MockAuthManager::new(),
+        MockResilienceOrchestrator::passthrough(),
+    );
+
+    let request = ChatCompletionRequest::new(
+        "gpt-4o",
+        vec![ChatMessage::user("Do something disallowed")],
+    );
+
+    let result = service.create_parsed::<WeatherReport>(request).await;
+
+    match result {
+        Err(OpenAIError::Refusal(message)) => {
+            assert_eq!(message, "I'm sorry, I can't help with that request.");
+        }
+        other => panic!("expected refusal error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_chat_completion_create_parsed_invalid_content() {
+    let mock_transport = MockHttpTransport::new().with_json_response(
+        ChatCompletionResponseBuilder::new()
+            .with_content(r#"{"city":"Paris"}"#)
+            .build(),
+    );
+
+    let service = create_test_service(
+        mock_transport,
+        MockAuthManager::new(),
+        MockResilienceOrchestrator::passthrough(),
+    );
+
+    let request = ChatCompletionRequest::new(
+        "gpt-4o",
+        vec![ChatMessage::user("What's the weather in Paris?")],
+    );
+
+    let result = service.create_parsed::<WeatherReport>(request).await;
+
+    assert!(matches!(result, Err(OpenAIError::Deserialization(_))));
 }
@@ -19,6 +19,9 @@ pub struct ChatCompletionRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stream: Option<bool>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream_options: Option<StreamOptions>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stop: Option<Vec<String>>,
 
@@ -50,10 +53,16 @@ pub struct ChatCompletionRequest {
     pub seed: Option<i64>,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StreamOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_usage: Option<bool>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChatMessage {
     pub role: ChatMessageRole,
-    pub content: Option<String>,
+    pub content: Option<ChatMessageContent>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
@@ -63,6 +72,90 @@ pub struct ChatMessage {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tool_call_id: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refusal: Option<String>,
+}
+
+/// Message content, either plain text or a list of text and image parts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ChatMessageContent {
+    Text(String),
+    Parts(Vec<ContentPart>),
+}
+
+impl ChatMessageContent {
+    /// Returns the plain text, or `None` for multi-part content.
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            ChatMessageContent::Text(text) => Some(text),
+            ChatMessageContent::Parts(_) => None,
+        }
+    }
+
+    /// Returns the text parts joined by newlines, skipping images.
+    pub fn text(&self) -> String {
+        match self {
+            ChatMessageContent::Text(text) => text.clone(),
+            ChatMessageContent::Parts(parts) => parts
+                .iter()
+                .filter_map(|part| match part {
+                    ContentPart::Text { text } => Some(text.as_str()),
+                    ContentPart::ImageUrl { .. } => None,
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+        }
+    }
+}
+
+impl From<String> for ChatMessageContent {
+    fn from(text: String) -> Self {
+        ChatMessageContent::Text(text)
+    }
+}
+
+impl From<&str> for ChatMessageContent {
+    fn from(text: &str) -> Self {
+        ChatMessageContent::Text(text.to_string())
+    }
+}
+
+impl From<Vec<ContentPart>> for ChatMessageContent {
+    fn from(parts: Vec<ContentPart>) -> Self {
+        ChatMessageContent::Parts(parts)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentPart {
+    Text { text: String },
+    ImageUrl { image_url: ImageUrl },
+}
+
+impl ContentPart {
+    pub fn text(text: impl Into<String>) -> Self {
+        ContentPart::Text { text: text.into() }
+    }
+
+    pub fn image_url(url: impl Into<String>) -> Self {
+        ContentPart::ImageUrl {
+            image_url: ImageUrl {
+                url: url.into(),
+                detail: None,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImageUrl {
+    pub url: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -151,11 +244,50 @@ pub struct FunctionCall {
     pub arguments: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub enum ResponseFormat {
     Text,
     JsonObject,
+    JsonSchema { json_schema: JsonSchemaFormat },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JsonSchemaFormat {
+    pub name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    pub schema: serde_json::Value,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
+}
+
+impl JsonSchemaFormat {
+    pub fn new(name: impl Into<String>, schema: serde_json::Value) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            schema,
+            strict: None,
+        }
+    }
+
+    pub fn strict(name: impl Into<String>, schema: serde_json::Value) -> Self {
+        Self::new(name, schema).with_strict(true)
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = Some(strict);
+        self
+    }
 }
 
 impl ChatCompletionRequest {
@@ -167,6 +299,7 @@ impl ChatCompletionRequest {
             top_p: None,
             n: None,
             stream: None,
+            stream_options: None,
             stop: None,
             max_tokens: None,
             presence_penalty: None,
@@ -194,36 +327,62 @@ impl ChatCompletionRequest {
         self.tools = Some(tools);
         self
     }
+
+    pub fn with_include_usage(mut self) -> Self {
+        self.stream_options = Some(StreamOptions {
+            include_usage: Some(true),
+        });
+        self
+    }
+
+    pub fn with_response_format(mut self, response_format: ResponseFormat) -> Self {
+        self.response_format = Some(response_format);
+        self
+    }
 }
 
 impl ChatMessage {
     pub fn system(content: impl Into<String>) -> Self {
         Self {
             role: ChatMessageRole::System,
-            content: Some(content.into()),
+            content: Some(ChatMessageContent::Text(content.into())),
             name: None,
             tool_calls: None,
             tool_call_id: None,
+            refusal: None,
         }
     }
 
     pub fn user(content: impl Into<String>) -> Self {
         Self {
             role: ChatMessageRole::User,
-            content: Some(content.into()),
+            content: Some(ChatMessageContent::Text(content.into())),
+            name: None,
+            tool_calls: None,
+            tool_call_id: None,
+            refusal: None,
+        }
+    }
+
+    pub fn user_with_parts(parts: Vec<ContentPart>) -> Self {
+        Self {
+            role: ChatMessageRole::User,
+            content: Some(ChatMessageContent::Parts(parts)),
             name: None,
             tool_calls: None,
             tool_call_id: None,
+            refusal: None,
         }
     }
 
     pub fn assistant(content: impl Into<String>) -> Self {
         Self {
             role: ChatMessageRole::Assistant,
-            content: Some(content.into()),
+            content: Some(ChatMessageContent::Text(content.into())),
             name: None,
             tool_calls: None,
             tool_call_id: None,
+            refusal: None,
         }
     }
 }
@@ -239,6 +398,15 @@ pub struct ChatCompletionChunk {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub system_fingerprint: Option<String>,
+
+    #[serde(default)]
+    pub usage: Option<Usage>,
+}
+
+impl ChatCompletionChunk {
+    pub fn is_usage_only(&self) -> bool {
+        self.choices.is_empty() && self.usage.is_some()
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -288,7 +456,28 @@ mod tests {
     fn test_chat_message_builders() {
         let msg = ChatMessage::user("Hello");
         assert_eq!(msg.role, ChatMessageRole::User);
-        assert_eq!(msg.content, Some("Hello".to_string()));
+        assert_eq!(msg.content, Some("Hello".into()));
+    }
+
+    #[test]
+    fn test_multipart_content_serialization() {
+        let msg = ChatMessage::user_with_parts(vec![
+            ContentPart::text("What is in this image?"),
+            ContentPart::image_url("https://example.com/cat.png"),
+        ]);
+
+        let value = serde_json::to_value(&msg).unwrap();
+        assert_eq!(
+            value["content"],
+            serde_json::json!([
+                {"type": "text", "text": "What is in this image?"},
+                {"type": "image_url", "image_url": {"url": "https://example.com/cat.png"}}
+            ])
+        );
+        assert_eq!(msg.content.unwrap().text(), "What is in this image?");
+
+        let value = serde_json::to_value(ChatMessage::user("Hello")).unwrap();
+        assert_eq!(value["content"], "Hello");
     }
 
     #[test]
@@ -304,4 +493,40 @@ mod tests {
         assert_eq!(request.temperature, Some(0.7));
         assert_eq!(request.max_tokens, Some(100));
     }
+
+    #[test]
+    fn test_stream_options_serialization() {
+        let request = ChatCompletionRequest::new("gpt-4", vec![ChatMessage::user("Hello")])
+            .with_include_usage();
+
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(value["stream_options"], serde_json::json!({"include_usage": true}));
+
+        let value = serde_json::to_value(ChatCompletionRequest::new("gpt-4", vec![])).unwrap();
+        assert!(value.get("stream_options").is_none());
+    }
+
+    #[test]
+    fn test_response_format_serialization() {
+        let text = serde_json::to_value(ResponseFormat::Text).unwrap();
+        assert_eq!(text, serde_json::json!({"type": "text"}));
+
+        let format = ResponseFormat::JsonSchema {
+            json_schema: JsonSchemaFormat::strict(
+                "weather",
+                serde_json::json!({"type": "object"}),
+            ),
+        };
+        assert_eq!(
+            serde_json::to_value(&format).unwrap(),
+            serde_json::json!({
+                "type": "json_schema",
+                "json_schema": {
+                    "name": "weather",
+                    "schema": {"type": "object"},
+                    "strict": true
+                }
+            })
+        );
+    }
 }
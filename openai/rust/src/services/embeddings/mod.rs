@@ -6,5 +6,8 @@ mod validation;
 mod tests;
 
 pub use service::{EmbeddingsService, EmbeddingsServiceImpl};
-pub use types::{EmbeddingsRequest, EmbeddingsResponse, Embedding, EmbeddingInput, EmbeddingUsage};
+pub use types::{
+    EmbeddingsRequest, EmbeddingsResponse, Embedding, EmbeddingInput, EmbeddingUsage,
+    EncodingFormat,
+};
 pub use validation::EmbeddingsRequestValidator;
@@ -1,7 +1,9 @@
 use crate::auth::AuthManager;
 use crate::errors::OpenAIResult;
 use crate::resilience::ResilienceOrchestrator;
-use crate::services::embeddings::{EmbeddingsRequest, EmbeddingsResponse};
+use crate::services::embeddings::{
+    EmbeddingsRequest, EmbeddingsRequestValidator, EmbeddingsResponse,
+};
 use crate::transport::HttpTransport;
 use async_trait::async_trait;
 use http::Method;
@@ -35,6 +37,8 @@ impl EmbeddingsServiceImpl {
 #[async_trait]
 impl EmbeddingsService for EmbeddingsServiceImpl {
     async fn create(&self, request: EmbeddingsRequest) -> OpenAIResult<EmbeddingsResponse> {
+        EmbeddingsRequestValidator::validate(&request)?;
+
         let mut headers = http::HeaderMap::new();
         self.auth_manager.apply_auth(&mut headers).await?;
 
@@ -260,3 +260,49 @@ async fn test_embeddings_3_large_model() {
 
     assert!(result.is_ok());
 }
+
+#[tokio::test]
+async fn test_embeddings_base64_matches_float_array() {
+    let mock_transport = MockHttpTransport::new()
+        .with_json_response(embeddings_response_base64());
+
+    let service = create_test_service(
+        mock_transport.clone(),
+        MockAuthManager::new(),
+        MockResilienceOrchestrator::passthrough(),
+    );
+
+    let request = EmbeddingsRequest::new("text-embedding-3-small", "Test")
+        .with_encoding_format(EncodingFormat::Base64);
+
+    let response = service.create(request).await.unwrap();
+
+    assert!(mock_transport.verify_request_with_body(
+        Method::POST,
+        "/embeddings",
+        "\"encoding_format\":\"base64\""
+    ));
+
+    let float_response: EmbeddingsResponse =
+        serde_json::from_value(embeddings_response_float_array()).unwrap();
+    assert_eq!(response.data[0].embedding, float_response.data[0].embedding);
+    assert_eq!(response.data[0].embedding, BASE64_EMBEDDING_VALUES.to_vec());
+}
+
+#[tokio::test]
+async fn test_embeddings_dimensions_rejected_for_unsupported_model() {
+    let mock_transport = MockHttpTransport::new();
+
+    let service = create_test_service(
+        mock_transport.clone(),
+        MockAuthManager::new(),
+        MockResilienceOrchestrator::passthrough(),
+    );
+
+    let request = EmbeddingsRequest::new("text-embedding-ada-002", "Test").with_dimensions(512);
+
+    let result = service.create(request).await;
+
+    assert!(matches!(result, Err(OpenAIError::Validation(_))));
+    assert_eq!(mock_transport.request_count(), 0);
+}
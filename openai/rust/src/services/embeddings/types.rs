@@ -1,4 +1,17 @@
-use serde::{Deserialize, Serialize};
+use base64::Engine;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Wire format for returned embeddings.
+///
+/// `Base64` roughly halves the response size; the vectors are decoded back
+/// into floats on deserialization so [`Embedding::embedding`] is the same
+/// either way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EncodingFormat {
+    Float,
+    Base64,
+}
 
 #[derive(Debug, Clone, Serialize)]
 pub struct EmbeddingsRequest {
@@ -6,7 +19,7 @@ pub struct EmbeddingsRequest {
     pub input: EmbeddingInput,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encoding_format: Option<String>,
+    pub encoding_format: Option<EncodingFormat>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dimensions: Option<u32>,
@@ -35,6 +48,7 @@ pub struct EmbeddingsResponse {
 #[derive(Debug, Clone, Deserialize)]
 pub struct Embedding {
     pub object: String,
+    #[serde(deserialize_with = "deserialize_embedding")]
     pub embedding: Vec<f32>,
     pub index: u32,
 }
@@ -70,6 +84,50 @@ impl EmbeddingsRequest {
         self.dimensions = Some(dims);
         self
     }
+
+    pub fn with_encoding_format(mut self, format: EncodingFormat) -> Self {
+        self.encoding_format = Some(format);
+        self
+    }
+}
+
+/// Accepts either a JSON array of floats or a base64 string of
+/// little-endian `f32` values.
+fn deserialize_embedding<'de, D>(deserializer: D) -> Result<Vec<f32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawEmbedding {
+        Float(Vec<f32>),
+        Base64(String),
+    }
+
+    match RawEmbedding::deserialize(deserializer)? {
+        RawEmbedding::Float(values) => Ok(values),
+        RawEmbedding::Base64(encoded) => {
+            decode_base64_embedding(&encoded).map_err(serde::de::Error::custom)
+        }
+    }
+}
+
+fn decode_base64_embedding(encoded: &str) -> Result<Vec<f32>, String> {
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|e| format!("invalid base64 embedding: {}", e))?;
+
+    if bytes.len() % 4 != 0 {
+        return Err(format!(
+            "base64 embedding has {} bytes, expected a multiple of 4",
+            bytes.len()
+        ));
+    }
+
+    Ok(bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect())
 }
 
 #[cfg(test)]
@@ -88,6 +146,21 @@ mod tests {
         assert_eq!(request.dimensions, Some(512));
     }
 
+    #[test]
+    fn test_encoding_format_serialization() {
+        let request = EmbeddingsRequest::new("text-embedding-3-small", "Hello world")
+            .with_encoding_format(EncodingFormat::Base64);
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["encoding_format"], "base64");
+    }
+
+    #[test]
+    fn test_decode_base64_embedding_rejects_partial_floats() {
+        let encoded = base64::engine::general_purpose::STANDARD.encode([0u8; 6]);
+        assert!(decode_base64_embedding(&encoded).is_err());
+    }
+
     #[test]
     fn test_embedding_input_serialization() {
         let input = EmbeddingInput::Multiple(vec!["hello".to_string(), "world".to_string()]);
@@ -18,10 +18,22 @@ impl EmbeddingsRequestValidator {
                     reason: "must be greater than 0".to_string(),
                 }));
             }
+
+            if !Self::supports_dimensions(&request.model) {
+                return Err(OpenAIError::Validation(ValidationError::InvalidParameter {
+                    parameter: "dimensions".to_string(),
+                    reason: format!("not supported by {}", request.model),
+                }));
+            }
         }
 
         Ok(())
     }
+
+    /// Only the text-embedding-3 family can shorten its embeddings.
+    fn supports_dimensions(model: &str) -> bool {
+        model.starts_with("text-embedding-3-")
+    }
 }
 
 #[cfg(test)]
@@ -43,4 +55,13 @@ mod tests {
         let request = EmbeddingsRequest::new("", EmbeddingInput::Single("test".to_string()));
         assert!(EmbeddingsRequestValidator::validate(&request).is_err());
     }
+
+    #[test]
+    fn test_validate_dimensions_model_support() {
+        let request = EmbeddingsRequest::new("text-embedding-3-large", "test").with_dimensions(256);
+        assert!(EmbeddingsRequestValidator::validate(&request).is_ok());
+
+        let request = EmbeddingsRequest::new("text-embedding-ada-002", "test").with_dimensions(256);
+        assert!(EmbeddingsRequestValidator::validate(&request).is_err());
+    }
 }
@@ -1,12 +1,16 @@
 use crate::auth::AuthManager;
-use crate::errors::OpenAIResult;
+use crate::errors::{OpenAIError, OpenAIResult};
 use crate::resilience::ResilienceOrchestrator;
 use crate::services::images::{
-    ImageEditRequest, ImageGenerationRequest, ImageResponse, ImageVariationRequest,
+    ImageEditRequest, ImageGenerationRequest, ImageRequestValidator, ImageResponse,
+    ImageResponseFormat, ImageVariationRequest,
 };
 use crate::transport::HttpTransport;
 use async_trait::async_trait;
+use base64::Engine;
+use bytes::Bytes;
 use http::Method;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 #[async_trait]
@@ -14,6 +18,11 @@ pub trait ImageService: Send + Sync {
     async fn generate(&self, request: ImageGenerationRequest) -> OpenAIResult<ImageResponse>;
     async fn edit(&self, request: ImageEditRequest) -> OpenAIResult<ImageResponse>;
     async fn variation(&self, request: ImageVariationRequest) -> OpenAIResult<ImageResponse>;
+    async fn generate_to_files(
+        &self,
+        request: ImageGenerationRequest,
+        dir: &Path,
+    ) -> OpenAIResult<Vec<PathBuf>>;
 }
 
 pub struct ImageServiceImpl {
@@ -127,6 +136,45 @@ impl ImageService for ImageServiceImpl {
             })
             .await
     }
+
+    async fn generate_to_files(
+        &self,
+        mut request: ImageGenerationRequest,
+        dir: &Path,
+    ) -> OpenAIResult<Vec<PathBuf>> {
+        request.response_format = Some(ImageResponseFormat::B64Json);
+        ImageRequestValidator::validate(&request)?;
+
+        let response = self.generate(request).await?;
+        std::fs::create_dir_all(dir)?;
+
+        let mut paths = Vec::with_capacity(response.data.len());
+        for (index, image) in response.data.iter().enumerate() {
+            let bytes = match (&image.b64_json, &image.url) {
+                (Some(b64_json), _) => decode_image(b64_json)?,
+                (None, Some(url)) => self.transport.download_file(url, None).await?,
+                (None, None) => {
+                    return Err(OpenAIError::Deserialization(format!(
+                        "Image {} has neither b64_json nor url",
+                        index
+                    )))
+                }
+            };
+
+            let path = dir.join(format!("image-{}-{}.png", response.created, index));
+            std::fs::write(&path, &bytes)?;
+            paths.push(path);
+        }
+
+        Ok(paths)
+    }
+}
+
+fn decode_image(b64_json: &str) -> OpenAIResult<Bytes> {
+    base64::engine::general_purpose::STANDARD
+        .decode(b64_json)
+        .map(Bytes::from)
+        .map_err(|e| OpenAIError::Deserialization(format!("Invalid b64_json image data: {}", e)))
 }
 
 #[cfg(test)]
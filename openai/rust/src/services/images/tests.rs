@@ -118,3 +118,85 @@ async fn test_image_generation_invalid_prompt() {
     let result = service.generate(request).await;
     assert!(result.is_err());
 }
+
+fn temp_image_dir(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("openai-images-{}-{}", name, std::process::id()))
+}
+
+#[tokio::test]
+async fn test_generate_to_files_decodes_b64_json() {
+    use base64::Engine;
+
+    let png = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n', 0x00, 0x01, 0x02];
+    let encoded = base64::engine::general_purpose::STANDARD.encode(&png);
+
+    let mock_transport = MockHttpTransport::new().with_json_response(serde_json::json!({
+        "created": 1700000000,
+        "data": [{ "b64_json": encoded }]
+    }));
+
+    let service = create_test_service(
+        mock_transport.clone(),
+        MockAuthManager::new(),
+        MockResilienceOrchestrator::passthrough(),
+    );
+
+    let dir = temp_image_dir("b64");
+    let paths = service
+        .generate_to_files(ImageGenerationRequest::new("A cat"), &dir)
+        .await
+        .unwrap();
+
+    assert_eq!(paths, vec![dir.join("image-1700000000-0.png")]);
+    assert_eq!(std::fs::read(&paths[0]).unwrap(), png);
+    assert!(mock_transport.verify_request_with_body(
+        Method::POST,
+        "/images/generations",
+        r#""response_format":"b64_json""#,
+    ));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_generate_to_files_downloads_urls() {
+    let mock_transport = MockHttpTransport::new()
+        .with_json_response(image_generation_response())
+        .with_file_download_response(Ok(bytes::Bytes::from_static(b"png-bytes")));
+
+    let service = create_test_service(
+        mock_transport.clone(),
+        MockAuthManager::new(),
+        MockResilienceOrchestrator::passthrough(),
+    );
+
+    let dir = temp_image_dir("url");
+    let paths = service
+        .generate_to_files(ImageGenerationRequest::new("A cat"), &dir)
+        .await
+        .unwrap();
+
+    assert_eq!(std::fs::read(&paths[0]).unwrap(), b"png-bytes");
+    assert!(mock_transport.verify_request(Method::GET, "https://example.com/image1.png"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_generate_to_files_rejects_unsupported_options() {
+    let mock_transport = MockHttpTransport::new();
+
+    let service = create_test_service(
+        mock_transport.clone(),
+        MockAuthManager::new(),
+        MockResilienceOrchestrator::passthrough(),
+    );
+
+    let request = ImageGenerationRequest::new("A cat").with_n(3);
+    let result = service
+        .generate_to_files(request, &temp_image_dir("invalid"))
+        .await;
+
+    assert!(matches!(result, Err(OpenAIError::Validation(_))));
+    assert_eq!(mock_transport.request_count(), 0);
+}
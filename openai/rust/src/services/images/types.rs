@@ -130,6 +130,28 @@ impl ImageGenerationRequest {
         self.style = Some(style);
         self
     }
+
+    pub fn with_n(mut self, n: u32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    pub fn with_response_format(mut self, response_format: ImageResponseFormat) -> Self {
+        self.response_format = Some(response_format);
+        self
+    }
+}
+
+impl ImageSize {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImageSize::Size256 => "256x256",
+            ImageSize::Size512 => "512x512",
+            ImageSize::Size1024 => "1024x1024",
+            ImageSize::Size1792x1024 => "1792x1024",
+            ImageSize::Size1024x1792 => "1024x1792",
+        }
+    }
 }
 
 #[cfg(test)]
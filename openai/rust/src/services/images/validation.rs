@@ -1,5 +1,5 @@
 use crate::errors::{OpenAIError, OpenAIResult, ValidationError};
-use crate::services::images::ImageGenerationRequest;
+use crate::services::images::{ImageGenerationRequest, ImageQuality, ImageSize};
 
 pub struct ImageRequestValidator;
 
@@ -22,6 +22,65 @@ impl ImageRequestValidator {
             }
         }
 
+        if let Some(model) = request.model.as_deref() {
+            Self::validate_model_options(model, request)?;
+        }
+
+        Ok(())
+    }
+
+    fn validate_model_options(model: &str, request: &ImageGenerationRequest) -> OpenAIResult<()> {
+        let (max_n, sizes): (u32, &[ImageSize]) = match model {
+            "dall-e-2" => (
+                10,
+                &[ImageSize::Size256, ImageSize::Size512, ImageSize::Size1024],
+            ),
+            "dall-e-3" => (
+                1,
+                &[
+                    ImageSize::Size1024,
+                    ImageSize::Size1792x1024,
+                    ImageSize::Size1024x1792,
+                ],
+            ),
+            _ => return Ok(()),
+        };
+
+        if let Some(n) = request.n {
+            if n > max_n {
+                return Err(OpenAIError::Validation(ValidationError::ValueOutOfRange {
+                    field: "n".to_string(),
+                    min: "1".to_string(),
+                    max: max_n.to_string(),
+                    value: n.to_string(),
+                }));
+            }
+        }
+
+        if let Some(size) = request.size {
+            if !sizes.contains(&size) {
+                return Err(OpenAIError::Validation(ValidationError::InvalidParameter {
+                    parameter: "size".to_string(),
+                    reason: format!("{} is not supported by {}", size.as_str(), model),
+                }));
+            }
+        }
+
+        if model == "dall-e-2" {
+            if request.quality == Some(ImageQuality::Hd) {
+                return Err(OpenAIError::Validation(ValidationError::InvalidParameter {
+                    parameter: "quality".to_string(),
+                    reason: "hd quality is only supported by dall-e-3".to_string(),
+                }));
+            }
+            if request.style.is_some() {
+                return Err(OpenAIError::Validation(ValidationError::InvalidParameter {
+                    parameter: "style".to_string(),
+                    reason: "style is only supported by dall-e-3".to_string(),
+                }));
+            }
+        }
+
         Ok(())
     }
 }
@@ -36,6 +95,31 @@ mod tests {
         assert!(ImageRequestValidator::validate(&request).is_ok());
     }
 
+    #[test]
+    fn test_validate_model_options() {
+        let request = ImageGenerationRequest::new("A cat").with_n(2);
+        assert!(ImageRequestValidator::validate(&request).is_err());
+
+        let request = ImageGenerationRequest::new("A cat").with_size(ImageSize::Size256);
+        assert!(ImageRequestValidator::validate(&request).is_err());
+
+        let request = ImageGenerationRequest::new("A cat")
+            .with_model("dall-e-2")
+            .with_quality(ImageQuality::Hd);
+        assert!(ImageRequestValidator::validate(&request).is_err());
+
+        let request = ImageGenerationRequest::new("A cat")
+            .with_model("dall-e-2")
+            .with_n(4)
+            .with_size(ImageSize::Size512);
+        assert!(ImageRequestValidator::validate(&request).is_ok());
+
+        let request = ImageGenerationRequest::new("A cat")
+            .with_size(ImageSize::Size1792x1024)
+            .with_quality(ImageQuality::Hd);
+        assert!(ImageRequestValidator::validate(&request).is_ok());
+    }
+
     #[test]
     fn test_validate_empty_prompt() {
         let request = ImageGenerationRequest::new("");
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct ModerationRequest {
@@ -82,6 +83,52 @@ pub struct ModerationCategoryScores {
     pub violence_graphic: f64,
 }
 
+impl ModerationResult {
+    /// Returns the API names of the flagged categories.
+    pub fn flagged_categories(&self) -> Vec<String> {
+        let c = &self.categories;
+        [
+            ("hate", c.hate),
+            ("hate/threatening", c.hate_threatening),
+            ("harassment", c.harassment),
+            ("harassment/threatening", c.harassment_threatening),
+            ("self-harm", c.self_harm),
+            ("self-harm/intent", c.self_harm_intent),
+            ("self-harm/instructions", c.self_harm_instructions),
+            ("sexual", c.sexual),
+            ("sexual/minors", c.sexual_minors),
+            ("violence", c.violence),
+            ("violence/graphic", c.violence_graphic),
+        ]
+        .into_iter()
+        .filter(|(_, flagged)| *flagged)
+        .map(|(name, _)| name.to_string())
+        .collect()
+    }
+}
+
+impl ModerationCategoryScores {
+    /// Returns the scores keyed by API category name.
+    pub fn to_map(&self) -> BTreeMap<String, f64> {
+        [
+            ("hate", self.hate),
+            ("hate/threatening", self.hate_threatening),
+            ("harassment", self.harassment),
+            ("harassment/threatening", self.harassment_threatening),
+            ("self-harm", self.self_harm),
+            ("self-harm/intent", self.self_harm_intent),
+            ("self-harm/instructions", self.self_harm_instructions),
+            ("sexual", self.sexual),
+            ("sexual/minors", self.sexual_minors),
+            ("violence", self.violence),
+            ("violence/graphic", self.violence_graphic),
+        ]
+        .into_iter()
+        .map(|(name, score)| (name.to_string(), score))
+        .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
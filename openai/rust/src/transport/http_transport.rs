@@ -71,8 +71,11 @@ impl ReqwestTransport {
         self
     }
 
-    /// Builds a full URL from a path
+    /// Builds a full URL from a path, passing absolute URLs through unchanged
     fn build_url(&self, path: &str) -> String {
+        if path.starts_with("https://") || path.starts_with("http://") {
+            return path.to_string();
+        }
         let path = path.trim_start_matches('/');
         format!("{}/{}", self.base_url.as_str().trim_end_matches('/'), path)
     }
@@ -286,6 +289,43 @@ impl HttpTransport for ReqwestTransport {
         let response = request.send().await?;
         ResponseParser::parse_bytes(response).await
     }
+
+    async fn request_byte_stream<T>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&T>,
+        headers: Option<HeaderMap>,
+    ) -> OpenAIResult<BoxStream<Bytes>>
+    where
+        T: Serialize + Send + Sync,
+    {
+        let url = self.build_url(path);
+        let merged_headers = self.merge_headers(headers);
+
+        let mut request = match method {
+            Method::GET => self.client.get(&url),
+            Method::POST => self.client.post(&url),
+            _ => {
+                return Err(OpenAIError::Network(NetworkError::RequestFailed(
+                    format!("Unsupported HTTP method for streaming: {}", method),
+                )))
+            }
+        };
+
+        // Apply merged headers
+        for (key, value) in merged_headers.iter() {
+            request = request.header(key, value);
+        }
+
+        // Add body if present
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+
+        let response = request.send().await?;
+        StreamHandler::handle_byte_stream(response).await
+    }
 }
 
 #[cfg(test)]
@@ -307,5 +347,45 @@ mod tests {
             transport.build_url("chat/completions"),
             "https://api.openai.com/v1/chat/completions"
         );
+
+        assert_eq!(
+            transport.build_url("https://files.example.com/img-1.png?sig=abc"),
+            "https://files.example.com/img-1.png?sig=abc"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_response_carries_header_delay() {
+        use crate::errors::OpenAIError;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("x-ratelimit-remaining-requests", "0")
+                    .insert_header("x-ratelimit-reset-requests", "50ms"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let transport =
+            ReqwestTransport::with_base_url(&server.uri(), Duration::from_secs(5)).unwrap();
+
+        let result: OpenAIResult<serde_json::Value> = transport
+            .request(Method::POST, "/chat/completions", Some(&serde_json::json!({})), None)
+            .await;
+
+        // The transport does not retry; the resilience layer waits and retries
+        match result {
+            Err(OpenAIError::RateLimit(rate_limit_error)) => {
+                assert_eq!(rate_limit_error.retry_after(), Some(Duration::from_millis(50)));
+            }
+            other => panic!("Expected rate limit error, got {:?}", other.map(|_| ())),
+        }
     }
 }
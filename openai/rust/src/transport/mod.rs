@@ -75,4 +75,14 @@ pub trait HttpTransport: Send + Sync {
     ) -> OpenAIResult<Bytes>
     where
         T: Serialize + Send + Sync;
+
+    async fn request_byte_stream<T>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&T>,
+        headers: Option<HeaderMap>,
+    ) -> OpenAIResult<BoxStream<Bytes>>
+    where
+        T: Serialize + Send + Sync;
 }
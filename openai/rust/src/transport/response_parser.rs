@@ -1,4 +1,4 @@
-use crate::errors::{ErrorMapper, OpenAIError, OpenAIErrorResponse, OpenAIResult};
+use crate::errors::{ErrorMapper, OpenAIError, OpenAIResult};
 use bytes::Bytes;
 use reqwest::Response;
 use serde::de::DeserializeOwned;
@@ -20,24 +20,7 @@ impl ResponseParser {
             })?;
             Ok(parsed)
         } else {
-            let headers = response.headers().clone();
-            let error_response: Option<OpenAIErrorResponse> = response
-                .json()
-                .await
-                .ok();
-
-            let mut error = ErrorMapper::map_status_code(status.as_u16(), error_response);
-
-            if let Some(retry_after) = ErrorMapper::extract_retry_after(&headers) {
-                if let OpenAIError::RateLimit(ref mut rate_limit_error) = error {
-                    *rate_limit_error = crate::errors::RateLimitError::TooManyRequests {
-                        message: "Too many requests".to_string(),
-                        retry_after_secs: Some(retry_after),
-                    };
-                }
-            }
-
-            Err(error)
+            Err(Self::parse_error(response).await)
         }
     }
 
@@ -47,14 +30,22 @@ impl ResponseParser {
         if status.is_success() {
             Ok(response.bytes().await?)
         } else {
-            let error_response: Option<OpenAIErrorResponse> = response.json().await.ok();
-            Err(ErrorMapper::map_status_code(
-                status.as_u16(),
-                error_response,
-            ))
+            Err(Self::parse_error(response).await)
         }
     }
 
+    /// Maps an unsuccessful response to an error
+    ///
+    /// Rate limit errors carry the wait requested by the response headers so
+    /// the resilience layer can honour it when retrying.
+    pub async fn parse_error(response: Response) -> OpenAIError {
+        let status = response.status().as_u16();
+        let headers = response.headers().clone();
+        let body = response.bytes().await.unwrap_or_default();
+
+        ErrorMapper::from_response(status, &headers, &body)
+    }
+
     pub fn parse_json<T: DeserializeOwned>(data: &[u8]) -> OpenAIResult<T> {
         serde_json::from_slice(data).map_err(|e| {
             OpenAIError::Deserialization(format!(
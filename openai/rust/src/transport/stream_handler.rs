@@ -17,11 +17,7 @@ impl StreamHandler {
     {
         let status = response.status();
         if !status.is_success() {
-            let error_response = response.json().await.ok();
-            return Err(crate::errors::ErrorMapper::map_status_code(
-                status.as_u16(),
-                error_response,
-            ));
+            return Err(ResponseParser::parse_error(response).await);
         }
 
         let stream = response.bytes_stream();
@@ -38,6 +34,20 @@ impl StreamHandler {
 
         Ok(Box::pin(parsed_stream))
     }
+
+    /// Streams a raw response body chunk by chunk as it arrives
+    pub async fn handle_byte_stream(response: Response) -> OpenAIResult<BoxStream<Bytes>> {
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ResponseParser::parse_error(response).await);
+        }
+
+        let stream = response
+            .bytes_stream()
+            .map(|chunk| chunk.map_err(|e| OpenAIError::Stream(e.to_string())));
+
+        Ok(Box::pin(stream))
+    }
 }
 
 pin_project! {
@@ -61,7 +61,7 @@ async fn test_chat_completion_integration_success() {
     assert!(result.is_ok());
     let response = result.unwrap();
     assert_eq!(response.id, "chatcmpl-integration-123");
-    assert_eq!(response.choices[0].message.content, Some("Integration test response".to_string()));
+    assert_eq!(response.choices[0].message.content, Some("Integration test response".into()));
 }
 
 #[tokio::test]
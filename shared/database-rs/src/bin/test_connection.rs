@@ -25,12 +25,19 @@ async fn main() {
     println!("RuvVector Database Connectivity Test (Rust)");
     println!("{}", "=".repeat(60));
 
-    let config = DatabaseConfig::default();
+    let config = match DatabaseConfig::from_env() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("\nInvalid database configuration: {}", e);
+            std::process::exit(1);
+        }
+    };
     println!("\nConnection Target:");
     println!("  Host: {}", config.host);
     println!("  Port: {}", config.port);
     println!("  Database: {}", config.database);
     println!("  User: {}", config.user);
+    println!("  SSL Mode: {}", config.ssl_mode);
 
     let db = match RuvectorDatabase::new(config).await {
         Ok(db) => db,
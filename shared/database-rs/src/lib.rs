@@ -1,10 +1,21 @@
 //! RuvVector Postgres Database Connectivity Module
 //! Provides shared database connection utilities for all integrations
 
-use deadpool_postgres::{Config, Pool, Runtime};
+mod tls;
+
+use deadpool_postgres::{Config, Pool, PoolConfig, PoolError, Runtime};
 use std::env;
+use std::fmt;
+use std::future::Future;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
 use thiserror::Error;
-use tokio_postgres::NoTls;
+use tokio_postgres::types::ToSql;
+use tokio_postgres::{NoTls, Row};
+
+/// Base delay between query retries, doubled on each attempt
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(50);
 
 /// Database errors
 #[derive(Error, Debug)]
@@ -17,6 +28,53 @@ pub enum DatabaseError {
     Pool(String),
     #[error("Configuration error: {0}")]
     Config(String),
+    #[error("Timed out after {0:?}")]
+    Timeout(Duration),
+}
+
+/// SSL mode used when connecting to Postgres, mirroring libpq's `sslmode`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SslMode {
+    /// Plain-text connection
+    #[default]
+    Disable,
+    /// Encrypted connection without certificate verification
+    Require,
+    /// Encrypted connection with certificate chain and hostname verification
+    VerifyFull,
+}
+
+impl SslMode {
+    /// Whether this mode requires a TLS connection
+    pub fn is_tls(&self) -> bool {
+        !matches!(self, SslMode::Disable)
+    }
+}
+
+impl FromStr for SslMode {
+    type Err = DatabaseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "disable" => Ok(SslMode::Disable),
+            "require" => Ok(SslMode::Require),
+            "verify-full" => Ok(SslMode::VerifyFull),
+            other => Err(DatabaseError::Config(format!(
+                "Unsupported sslmode '{}': expected disable, require or verify-full",
+                other
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for SslMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SslMode::Disable => write!(f, "disable"),
+            SslMode::Require => write!(f, "require"),
+            SslMode::VerifyFull => write!(f, "verify-full"),
+        }
+    }
 }
 
 /// Database configuration
@@ -28,41 +86,69 @@ pub struct DatabaseConfig {
     pub password: String,
     pub database: String,
     pub max_connections: usize,
-}
-
-impl Default for DatabaseConfig {
-    fn default() -> Self {
-        Self::from_env()
-    }
+    /// Number of retries for queries that fail on a dropped connection or pool timeout
+    pub query_retries: u32,
+    /// Maximum time `ping` waits for a connection and a reply
+    pub ping_timeout: Duration,
+    pub ssl_mode: SslMode,
+    /// PEM file with trusted root certificates; the webpki roots are used when unset
+    pub root_cert_path: Option<PathBuf>,
 }
 
 impl DatabaseConfig {
     /// Create configuration from environment variables
-    pub fn from_env() -> Self {
+    ///
+    /// `sslmode` and `sslrootcert` are read from the `DATABASE_URL` query string,
+    /// or from `POSTGRES_SSLMODE` and `POSTGRES_SSLROOTCERT` otherwise.
+    pub fn from_env() -> Result<Self, DatabaseError> {
         if let Ok(database_url) = env::var("DATABASE_URL") {
             if let Ok(url) = url::Url::parse(&database_url) {
-                return Self {
+                let mut ssl_mode = SslMode::Disable;
+                let mut root_cert_path = None;
+                for (key, value) in url.query_pairs() {
+                    match key.as_ref() {
+                        "sslmode" => ssl_mode = value.parse()?,
+                        "sslrootcert" => root_cert_path = Some(PathBuf::from(value.as_ref())),
+                        _ => {}
+                    }
+                }
+
+                return Ok(Self {
                     host: url.host_str().unwrap_or("localhost").to_string(),
                     port: url.port().unwrap_or(5432),
                     user: url.username().to_string(),
                     password: url.password().unwrap_or("").to_string(),
                     database: url.path().trim_start_matches('/').to_string(),
                     max_connections: 10,
-                };
+                    query_retries: 3,
+                    ping_timeout: Duration::from_secs(5),
+                    ssl_mode,
+                    root_cert_path,
+                });
             }
         }
 
-        Self {
+        let ssl_mode = match env::var("POSTGRES_SSLMODE") {
+            Ok(mode) => mode.parse()?,
+            Err(_) => SslMode::Disable,
+        };
+
+        Ok(Self {
             host: env::var("POSTGRES_HOST").unwrap_or_else(|_| "localhost".to_string()),
             port: env::var("POSTGRES_PORT")
                 .ok()
                 .and_then(|p| p.parse().ok())
                 .unwrap_or(5432),
             user: env::var("POSTGRES_USER").unwrap_or_else(|_| "ruvector".to_string()),
-            password: env::var("POSTGRES_PASSWORD").unwrap_or_else(|_| "ruvector_secret".to_string()),
+            password: env::var("POSTGRES_PASSWORD")
+                .unwrap_or_else(|_| "ruvector_secret".to_string()),
             database: env::var("POSTGRES_DB").unwrap_or_else(|_| "ruvector".to_string()),
             max_connections: 10,
-        }
+            query_retries: 3,
+            ping_timeout: Duration::from_secs(5),
+            ssl_mode,
+            root_cert_path: env::var("POSTGRES_SSLROOTCERT").ok().map(PathBuf::from),
+        })
     }
 }
 
@@ -77,6 +163,19 @@ pub struct ConnectionTestResult {
     pub extensions: Vec<String>,
 }
 
+/// Connection pool occupancy snapshot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolHealth {
+    /// Connections currently open
+    pub size: usize,
+    /// Idle connections ready to be handed out
+    pub available: usize,
+    /// Callers waiting for a connection
+    pub waiting: usize,
+    /// Upper bound on open connections
+    pub max: usize,
+}
+
 /// RuvVector Database Pool Manager
 pub struct RuvectorDatabase {
     pool: Pool,
@@ -92,17 +191,23 @@ impl RuvectorDatabase {
         cfg.user = Some(config.user.clone());
         cfg.password = Some(config.password.clone());
         cfg.dbname = Some(config.database.clone());
+        cfg.pool = Some(PoolConfig::new(config.max_connections));
 
-        let pool = cfg
-            .create_pool(Some(Runtime::Tokio1), NoTls)
-            .map_err(|e| DatabaseError::Pool(e.to_string()))?;
+        let pool = if config.ssl_mode.is_tls() {
+            cfg.ssl_mode = Some(deadpool_postgres::SslMode::Require);
+            let tls = tls::make_connector(config.ssl_mode, config.root_cert_path.as_deref())?;
+            cfg.create_pool(Some(Runtime::Tokio1), tls)
+        } else {
+            cfg.create_pool(Some(Runtime::Tokio1), NoTls)
+        }
+        .map_err(|e| DatabaseError::Pool(e.to_string()))?;
 
         Ok(Self { pool, config })
     }
 
     /// Create with default configuration
     pub async fn with_defaults() -> Result<Self, DatabaseError> {
-        Self::new(DatabaseConfig::default()).await
+        Self::new(DatabaseConfig::from_env()?).await
     }
 
     /// Get a client from the pool
@@ -113,6 +218,28 @@ impl RuvectorDatabase {
             .map_err(|e| DatabaseError::Pool(e.to_string()))
     }
 
+    /// Run a query and map each returned row, retrying on transient connection failures
+    ///
+    /// Connection resets and pool timeouts are retried up to `query_retries` times
+    /// with exponential backoff; SQL errors reported by the server are returned immediately.
+    pub async fn query_with_retry<T, F>(
+        &self,
+        sql: &str,
+        params: &[&(dyn ToSql + Sync)],
+        row_mapper: F,
+    ) -> Result<Vec<T>, DatabaseError>
+    where
+        F: Fn(&Row) -> T,
+    {
+        let rows = retry_transient(self.config.query_retries, || async {
+            let client = self.pool.get().await.map_err(classify_pool_error)?;
+            client.query(sql, params).await.map_err(classify_pg_error)
+        })
+        .await?;
+
+        Ok(rows.iter().map(row_mapper).collect())
+    }
+
     /// Test database connectivity with a connect -> query -> write -> read cycle
     pub async fn test_connection(&self) -> ConnectionTestResult {
         let client = match self.get_client().await {
@@ -240,6 +367,34 @@ impl RuvectorDatabase {
         }
     }
 
+    /// Lightweight liveness check running `SELECT 1`
+    ///
+    /// Returns the round-trip latency including connection checkout, or
+    /// `DatabaseError::Timeout` if no reply arrives within `ping_timeout`.
+    /// Use `test_connection` for a full write/read cycle.
+    pub async fn ping(&self) -> Result<Duration, DatabaseError> {
+        measure_within(self.config.ping_timeout, async {
+            let client = self.get_client().await?;
+            client
+                .simple_query("SELECT 1")
+                .await
+                .map_err(|e| DatabaseError::Query(e.to_string()))?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Get pool occupancy
+    pub fn pool_health(&self) -> PoolHealth {
+        let status = self.pool.status();
+        PoolHealth {
+            size: status.size,
+            available: status.available,
+            waiting: status.waiting,
+            max: status.max_size,
+        }
+    }
+
     /// Get pool statistics
     pub fn pool_stats(&self) -> (usize, usize) {
         (self.pool.status().size, self.pool.status().available)
@@ -250,3 +405,178 @@ impl RuvectorDatabase {
         &self.config
     }
 }
+
+/// Await `fut` for at most `limit`, returning how long it took
+async fn measure_within<F>(limit: Duration, fut: F) -> Result<Duration, DatabaseError>
+where
+    F: Future<Output = Result<(), DatabaseError>>,
+{
+    let start = Instant::now();
+    match tokio::time::timeout(limit, fut).await {
+        Ok(Ok(())) => Ok(start.elapsed()),
+        Ok(Err(e)) => Err(e),
+        Err(_) => Err(DatabaseError::Timeout(limit)),
+    }
+}
+
+/// Outcome of a failed query attempt
+#[derive(Debug)]
+enum Failure {
+    /// The connection was lost or unavailable; the query may succeed if retried
+    Transient(DatabaseError),
+    /// The server rejected the query; retrying will not help
+    Fatal(DatabaseError),
+}
+
+/// Run `op` until it succeeds, fails fatally, or exhausts `max_retries` transient failures
+async fn retry_transient<T, F, Fut>(max_retries: u32, mut op: F) -> Result<T, DatabaseError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, Failure>>,
+{
+    let mut attempt = 0;
+    loop {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(Failure::Fatal(e)) => return Err(e),
+            Err(Failure::Transient(e)) if attempt >= max_retries => return Err(e),
+            Err(Failure::Transient(_)) => {
+                tokio::time::sleep(RETRY_BASE_DELAY * 2u32.saturating_pow(attempt)).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Classify a pool checkout error
+fn classify_pool_error(error: PoolError) -> Failure {
+    match error {
+        PoolError::Timeout(_) => Failure::Transient(DatabaseError::Pool(error.to_string())),
+        PoolError::Backend(e) => match classify_pg_error(e) {
+            Failure::Transient(e) => Failure::Transient(e),
+            Failure::Fatal(e) => Failure::Fatal(DatabaseError::Connection(e.to_string())),
+        },
+        other => Failure::Fatal(DatabaseError::Pool(other.to_string())),
+    }
+}
+
+/// Classify a query error as a dropped connection or a genuine SQL error
+fn classify_pg_error(error: tokio_postgres::Error) -> Failure {
+    if let Some(db_error) = error.as_db_error() {
+        // Class 08 (connection exception) and admin shutdown mean the session went away
+        let code = db_error.code().code();
+        if code.starts_with("08") || code == "57P01" {
+            return Failure::Transient(DatabaseError::Connection(error.to_string()));
+        }
+        return Failure::Fatal(DatabaseError::Query(error.to_string()));
+    }
+
+    let io_error =
+        std::error::Error::source(&error).is_some_and(|source| source.is::<std::io::Error>());
+    if error.is_closed() || io_error {
+        Failure::Transient(DatabaseError::Connection(error.to_string()))
+    } else {
+        Failure::Fatal(DatabaseError::Query(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[tokio::test]
+    async fn retries_transient_failure_then_succeeds() {
+        let calls = AtomicU32::new(0);
+        let result = retry_transient(3, || async {
+            if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                Err(Failure::Transient(DatabaseError::Connection(
+                    "reset".into(),
+                )))
+            } else {
+                Ok(42)
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_fatal_failure() {
+        let calls = AtomicU32::new(0);
+        let result: Result<(), _> = retry_transient(3, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(Failure::Fatal(DatabaseError::Query("syntax error".into())))
+        })
+        .await;
+
+        assert!(matches!(result, Err(DatabaseError::Query(_))));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_retries() {
+        let calls = AtomicU32::new(0);
+        let result: Result<(), _> = retry_transient(2, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(Failure::Transient(DatabaseError::Pool("timeout".into())))
+        })
+        .await;
+
+        assert!(matches!(result, Err(DatabaseError::Pool(_))));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn measure_within_reports_latency() {
+        let latency = measure_within(Duration::from_secs(1), async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        assert!(latency >= Duration::from_millis(20));
+        assert!(latency < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn measure_within_times_out() {
+        let result = measure_within(Duration::from_millis(20), async {
+            tokio::time::sleep(Duration::from_secs(10)).await;
+            Ok(())
+        })
+        .await;
+
+        assert!(matches!(result, Err(DatabaseError::Timeout(d)) if d == Duration::from_millis(20)));
+    }
+
+    #[tokio::test]
+    async fn pool_health_reports_configured_max() {
+        let config = DatabaseConfig {
+            host: "localhost".into(),
+            port: 5432,
+            user: "ruvector".into(),
+            password: String::new(),
+            database: "ruvector".into(),
+            max_connections: 4,
+            query_retries: 0,
+            ping_timeout: Duration::from_millis(10),
+            ssl_mode: SslMode::Disable,
+            root_cert_path: None,
+        };
+        let db = RuvectorDatabase::new(config).await.unwrap();
+
+        assert_eq!(
+            db.pool_health(),
+            PoolHealth {
+                size: 0,
+                available: 0,
+                waiting: 0,
+                max: 4,
+            }
+        );
+    }
+}
@@ -0,0 +1,130 @@
+//! TLS connector construction for the Postgres pool
+
+use crate::{DatabaseError, SslMode};
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::{verify_tls12_signature, verify_tls13_signature, CryptoProvider};
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{ClientConfig, DigitallySignedStruct, RootCertStore, SignatureScheme};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use std::sync::Arc;
+use tokio_postgres_rustls::MakeRustlsConnect;
+
+/// Build a rustls connector for the given SSL mode
+pub(crate) fn make_connector(
+    mode: SslMode,
+    root_cert_path: Option<&Path>,
+) -> Result<MakeRustlsConnect, DatabaseError> {
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let builder = ClientConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()
+        .map_err(|e| DatabaseError::Config(format!("Invalid TLS configuration: {}", e)))?;
+
+    let config = match mode {
+        SslMode::VerifyFull => builder
+            .with_root_certificates(load_roots(root_cert_path)?)
+            .with_no_client_auth(),
+        SslMode::Require => builder
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(NoVerification(provider)))
+            .with_no_client_auth(),
+        SslMode::Disable => {
+            return Err(DatabaseError::Config(
+                "TLS connector requested with sslmode=disable".to_string(),
+            ))
+        }
+    };
+
+    Ok(MakeRustlsConnect::new(config))
+}
+
+/// Load trusted roots from a PEM file, falling back to the bundled webpki roots
+fn load_roots(path: Option<&Path>) -> Result<RootCertStore, DatabaseError> {
+    let mut roots = RootCertStore::empty();
+
+    let Some(path) = path else {
+        roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+        return Ok(roots);
+    };
+
+    let file = File::open(path).map_err(|e| {
+        DatabaseError::Config(format!(
+            "Failed to open root certificate {}: {}",
+            path.display(),
+            e
+        ))
+    })?;
+
+    for cert in rustls_pemfile::certs(&mut BufReader::new(file)) {
+        let cert = cert.map_err(|e| {
+            DatabaseError::Config(format!(
+                "Failed to parse root certificate {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        roots
+            .add(cert)
+            .map_err(|e| DatabaseError::Config(format!("Invalid root certificate: {}", e)))?;
+    }
+
+    if roots.is_empty() {
+        return Err(DatabaseError::Config(format!(
+            "No certificates found in {}",
+            path.display()
+        )));
+    }
+
+    Ok(roots)
+}
+
+/// Certificate verifier for `sslmode=require`: the channel is encrypted but the
+/// server identity is not checked, matching libpq semantics.
+#[derive(Debug)]
+struct NoVerification(Arc<CryptoProvider>);
+
+impl ServerCertVerifier for NoVerification {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
@@ -0,0 +1,35 @@
+//! TLS connectivity tests against a live Postgres
+//!
+//! Run with `DATABASE_URL=postgres://...?sslmode=verify-full cargo test --features tls-integration-tests`
+
+#![cfg(feature = "tls-integration-tests")]
+
+use integrations_database::{DatabaseConfig, RuvectorDatabase, SslMode};
+
+#[tokio::test]
+async fn connects_over_tls() {
+    let config = DatabaseConfig::from_env().expect("valid database configuration");
+    assert!(
+        config.ssl_mode.is_tls(),
+        "DATABASE_URL must set sslmode=require or sslmode=verify-full"
+    );
+
+    let db = RuvectorDatabase::new(config).await.expect("pool creation");
+    let client = db.get_client().await.expect("TLS connection");
+
+    let row = client
+        .query_one("SELECT ssl FROM pg_stat_ssl WHERE pid = pg_backend_pid()", &[])
+        .await
+        .expect("pg_stat_ssl query");
+    assert!(row.get::<_, bool>(0), "connection is not encrypted");
+}
+
+#[tokio::test]
+async fn require_mode_connects_without_verification() {
+    let mut config = DatabaseConfig::from_env().expect("valid database configuration");
+    config.ssl_mode = SslMode::Require;
+    config.root_cert_path = None;
+
+    let db = RuvectorDatabase::new(config).await.expect("pool creation");
+    assert!(db.test_connection().await.success);
+}
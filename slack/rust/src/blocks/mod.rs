@@ -0,0 +1,623 @@
+//! Block Kit builders.
+//!
+//! Typed builders for the common Block Kit layout blocks. Each builder
+//! validates Slack's length limits in `build()` and produces a [`Block`]
+//! ready for [`PostMessageRequest::with_blocks`].
+//!
+//! Elements are typed by where they may appear: a [`ButtonElement`] converts
+//! into an [`ActionElement`] or [`SectionAccessory`] but not a
+//! [`ContextElement`], so a button cannot be placed in a context block.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use slack_client::blocks::{ButtonElement, SectionBlock, TextObject};
+//!
+//! let section = SectionBlock::new()
+//!     .text(TextObject::mrkdwn("Deploy *v1.2.0* to production?"))
+//!     .accessory(ButtonElement::new("deploy", "Deploy").value("v1.2.0"))
+//!     .build()?;
+//! let request = PostMessageRequest::with_blocks("C123", vec![section]);
+//! ```
+//!
+//! [`PostMessageRequest::with_blocks`]: crate::services::messages::PostMessageRequest::with_blocks
+
+use crate::errors::{RequestError, SlackError, SlackResult};
+use crate::types::Block;
+use serde::Serialize;
+
+/// Maximum length of a block ID
+pub const MAX_BLOCK_ID_LEN: usize = 255;
+/// Maximum length of section text
+pub const MAX_SECTION_TEXT_LEN: usize = 3000;
+/// Maximum length of a section field
+pub const MAX_SECTION_FIELD_LEN: usize = 2000;
+/// Maximum number of section fields
+pub const MAX_SECTION_FIELDS: usize = 10;
+/// Maximum length of header text
+pub const MAX_HEADER_TEXT_LEN: usize = 150;
+/// Maximum number of elements in an actions block
+pub const MAX_ACTIONS_ELEMENTS: usize = 25;
+/// Maximum number of elements in a context block
+pub const MAX_CONTEXT_ELEMENTS: usize = 10;
+/// Maximum length of button text
+pub const MAX_BUTTON_TEXT_LEN: usize = 75;
+/// Maximum length of an action ID
+pub const MAX_ACTION_ID_LEN: usize = 255;
+/// Maximum length of a button value
+pub const MAX_BUTTON_VALUE_LEN: usize = 2000;
+/// Maximum length of a button URL
+pub const MAX_BUTTON_URL_LEN: usize = 3000;
+
+/// Text object
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type")]
+pub enum TextObject {
+    /// Plain text
+    #[serde(rename = "plain_text")]
+    PlainText {
+        /// Text content
+        text: String,
+        /// Render emoji shortcodes
+        #[serde(skip_serializing_if = "Option::is_none")]
+        emoji: Option<bool>,
+    },
+    /// Markdown text
+    #[serde(rename = "mrkdwn")]
+    Mrkdwn {
+        /// Text content
+        text: String,
+        /// Skip auto-linking of URLs and mentions
+        #[serde(skip_serializing_if = "Option::is_none")]
+        verbatim: Option<bool>,
+    },
+}
+
+impl TextObject {
+    /// Create plain text
+    pub fn plain(text: impl Into<String>) -> Self {
+        Self::PlainText {
+            text: text.into(),
+            emoji: None,
+        }
+    }
+
+    /// Create plain text with emoji rendering enabled
+    pub fn plain_emoji(text: impl Into<String>) -> Self {
+        Self::PlainText {
+            text: text.into(),
+            emoji: Some(true),
+        }
+    }
+
+    /// Create markdown text
+    pub fn mrkdwn(text: impl Into<String>) -> Self {
+        Self::Mrkdwn {
+            text: text.into(),
+            verbatim: None,
+        }
+    }
+
+    /// Get the text content
+    pub fn text(&self) -> &str {
+        match self {
+            Self::PlainText { text, .. } | Self::Mrkdwn { text, .. } => text,
+        }
+    }
+}
+
+/// Button style
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ButtonStyle {
+    /// Green button for affirmative actions
+    Primary,
+    /// Red button for destructive actions
+    Danger,
+}
+
+/// Button element
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ButtonElement {
+    text: TextObject,
+    action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    style: Option<ButtonStyle>,
+}
+
+impl ButtonElement {
+    /// Create a button with plain text label
+    pub fn new(action_id: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            text: TextObject::plain(text),
+            action_id: action_id.into(),
+            value: None,
+            url: None,
+            style: None,
+        }
+    }
+
+    /// Render emoji shortcodes in the label
+    pub fn emoji(mut self, emoji: bool) -> Self {
+        if let TextObject::PlainText { emoji: ref mut e, .. } = self.text {
+            *e = Some(emoji);
+        }
+        self
+    }
+
+    /// Set value sent with the interaction payload
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Set URL to open on click
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Set style
+    pub fn style(mut self, style: ButtonStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    fn validate(&self) -> SlackResult<()> {
+        check_len("button text", self.text.text(), 1, MAX_BUTTON_TEXT_LEN)?;
+        check_len("action_id", &self.action_id, 1, MAX_ACTION_ID_LEN)?;
+        if let Some(ref value) = self.value {
+            check_len("button value", value, 0, MAX_BUTTON_VALUE_LEN)?;
+        }
+        if let Some(ref url) = self.url {
+            check_len("button url", url, 1, MAX_BUTTON_URL_LEN)?;
+        }
+        Ok(())
+    }
+}
+
+/// Image element
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImageElement {
+    image_url: String,
+    alt_text: String,
+}
+
+impl ImageElement {
+    /// Create an image element
+    pub fn new(image_url: impl Into<String>, alt_text: impl Into<String>) -> Self {
+        Self {
+            image_url: image_url.into(),
+            alt_text: alt_text.into(),
+        }
+    }
+}
+
+/// Element allowed in an actions block
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ActionElement {
+    /// Button
+    Button(ButtonElement),
+}
+
+impl From<ButtonElement> for ActionElement {
+    fn from(button: ButtonElement) -> Self {
+        Self::Button(button)
+    }
+}
+
+/// Element allowed as a section accessory
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SectionAccessory {
+    /// Button
+    Button(ButtonElement),
+    /// Image
+    Image(ImageElement),
+}
+
+impl From<ButtonElement> for SectionAccessory {
+    fn from(button: ButtonElement) -> Self {
+        Self::Button(button)
+    }
+}
+
+impl From<ImageElement> for SectionAccessory {
+    fn from(image: ImageElement) -> Self {
+        Self::Image(image)
+    }
+}
+
+/// Element allowed in a context block
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum ContextElement {
+    /// Text
+    Text(TextObject),
+    /// Image
+    Image(ContextImage),
+}
+
+/// Image inside a context block
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename = "image")]
+pub struct ContextImage {
+    image_url: String,
+    alt_text: String,
+}
+
+impl From<TextObject> for ContextElement {
+    fn from(text: TextObject) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<ImageElement> for ContextElement {
+    fn from(image: ImageElement) -> Self {
+        Self::Image(ContextImage {
+            image_url: image.image_url,
+            alt_text: image.alt_text,
+        })
+    }
+}
+
+/// Section block
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SectionBlock {
+    #[serde(skip)]
+    block_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<TextObject>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fields: Vec<TextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    accessory: Option<SectionAccessory>,
+}
+
+impl SectionBlock {
+    /// Create an empty section
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set block ID
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+
+    /// Set text
+    pub fn text(mut self, text: TextObject) -> Self {
+        self.text = Some(text);
+        self
+    }
+
+    /// Add a field, rendered in two columns
+    pub fn field(mut self, field: TextObject) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Set accessory
+    pub fn accessory(mut self, accessory: impl Into<SectionAccessory>) -> Self {
+        self.accessory = Some(accessory.into());
+        self
+    }
+
+    /// Validate and build the block
+    pub fn build(self) -> SlackResult<Block> {
+        if self.text.is_none() && self.fields.is_empty() {
+            return Err(invalid("section requires text or fields"));
+        }
+        if let Some(ref text) = self.text {
+            check_len("section text", text.text(), 1, MAX_SECTION_TEXT_LEN)?;
+        }
+        if self.fields.len() > MAX_SECTION_FIELDS {
+            return Err(invalid(format!(
+                "section has {} fields, maximum is {}",
+                self.fields.len(),
+                MAX_SECTION_FIELDS
+            )));
+        }
+        for field in &self.fields {
+            check_len("section field", field.text(), 1, MAX_SECTION_FIELD_LEN)?;
+        }
+        if let Some(SectionAccessory::Button(ref button)) = self.accessory {
+            button.validate()?;
+        }
+        to_block("section", self.block_id.clone(), &self)
+    }
+}
+
+/// Divider block
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DividerBlock {
+    #[serde(skip)]
+    block_id: Option<String>,
+}
+
+impl DividerBlock {
+    /// Create a divider
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set block ID
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+
+    /// Validate and build the block
+    pub fn build(self) -> SlackResult<Block> {
+        to_block("divider", self.block_id.clone(), &self)
+    }
+}
+
+/// Actions block
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ActionsBlock {
+    #[serde(skip)]
+    block_id: Option<String>,
+    elements: Vec<ActionElement>,
+}
+
+impl ActionsBlock {
+    /// Create an empty actions block
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set block ID
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+
+    /// Add an interactive element
+    pub fn element(mut self, element: impl Into<ActionElement>) -> Self {
+        self.elements.push(element.into());
+        self
+    }
+
+    /// Validate and build the block
+    pub fn build(self) -> SlackResult<Block> {
+        check_count("actions", self.elements.len(), MAX_ACTIONS_ELEMENTS)?;
+        for element in &self.elements {
+            match element {
+                ActionElement::Button(button) => button.validate()?,
+            }
+        }
+        to_block("actions", self.block_id.clone(), &self)
+    }
+}
+
+/// Context block
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ContextBlock {
+    #[serde(skip)]
+    block_id: Option<String>,
+    elements: Vec<ContextElement>,
+}
+
+impl ContextBlock {
+    /// Create an empty context block
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set block ID
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+
+    /// Add a text or image element
+    pub fn element(mut self, element: impl Into<ContextElement>) -> Self {
+        self.elements.push(element.into());
+        self
+    }
+
+    /// Validate and build the block
+    pub fn build(self) -> SlackResult<Block> {
+        check_count("context", self.elements.len(), MAX_CONTEXT_ELEMENTS)?;
+        to_block("context", self.block_id.clone(), &self)
+    }
+}
+
+/// Header block
+#[derive(Debug, Clone, Serialize)]
+pub struct HeaderBlock {
+    #[serde(skip)]
+    block_id: Option<String>,
+    text: TextObject,
+}
+
+impl HeaderBlock {
+    /// Create a header with plain text
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            block_id: None,
+            text: TextObject::plain(text),
+        }
+    }
+
+    /// Set block ID
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+
+    /// Validate and build the block
+    pub fn build(self) -> SlackResult<Block> {
+        check_len("header text", self.text.text(), 1, MAX_HEADER_TEXT_LEN)?;
+        to_block("header", self.block_id.clone(), &self)
+    }
+}
+
+fn invalid(message: impl Into<String>) -> SlackError {
+    SlackError::Request(RequestError::InvalidArguments {
+        message: message.into(),
+    })
+}
+
+fn check_len(what: &str, value: &str, min: usize, max: usize) -> SlackResult<()> {
+    let len = value.chars().count();
+    if len < min {
+        return Err(invalid(format!("{} must not be empty", what)));
+    }
+    if len > max {
+        return Err(invalid(format!(
+            "{} is {} characters, maximum is {}",
+            what, len, max
+        )));
+    }
+    Ok(())
+}
+
+fn check_count(block: &str, count: usize, max: usize) -> SlackResult<()> {
+    if count == 0 {
+        return Err(invalid(format!("{} block requires at least one element", block)));
+    }
+    if count > max {
+        return Err(invalid(format!(
+            "{} block has {} elements, maximum is {}",
+            block, count, max
+        )));
+    }
+    Ok(())
+}
+
+fn to_block<T: Serialize>(block_type: &str, block_id: Option<String>, block: &T) -> SlackResult<Block> {
+    if let Some(ref id) = block_id {
+        check_len("block_id", id, 1, MAX_BLOCK_ID_LEN)?;
+    }
+    let data = serde_json::to_value(block).map_err(|e| {
+        SlackError::Request(RequestError::InvalidJson {
+            message: e.to_string(),
+        })
+    })?;
+    Ok(Block {
+        block_type: block_type.to_string(),
+        block_id,
+        data,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_section_with_button_matches_slack_docs() {
+        let block = SectionBlock::new()
+            .text(TextObject::mrkdwn("This is a section block with a button."))
+            .accessory(
+                ButtonElement::new("button-action", "Click Me")
+                    .emoji(true)
+                    .value("click_me_123"),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&block).unwrap(),
+            json!({
+                "type": "section",
+                "text": {
+                    "type": "mrkdwn",
+                    "text": "This is a section block with a button."
+                },
+                "accessory": {
+                    "type": "button",
+                    "text": {
+                        "type": "plain_text",
+                        "text": "Click Me",
+                        "emoji": true
+                    },
+                    "value": "click_me_123",
+                    "action_id": "button-action"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_layout_blocks_serialize() {
+        let blocks = vec![
+            HeaderBlock::new("Budget Performance").build().unwrap(),
+            DividerBlock::new().block_id("divider-1").build().unwrap(),
+            ActionsBlock::new()
+                .element(ButtonElement::new("approve", "Approve").style(ButtonStyle::Primary))
+                .element(ButtonElement::new("deny", "Deny").style(ButtonStyle::Danger))
+                .build()
+                .unwrap(),
+            ContextBlock::new()
+                .element(ImageElement::new("https://example.com/logo.png", "logo"))
+                .element(TextObject::mrkdwn("Posted by <@U123>"))
+                .build()
+                .unwrap(),
+        ];
+
+        assert_eq!(
+            serde_json::to_value(&blocks).unwrap(),
+            json!([
+                { "type": "header", "text": { "type": "plain_text", "text": "Budget Performance" } },
+                { "type": "divider", "block_id": "divider-1" },
+                {
+                    "type": "actions",
+                    "elements": [
+                        {
+                            "type": "button",
+                            "text": { "type": "plain_text", "text": "Approve" },
+                            "action_id": "approve",
+                            "style": "primary"
+                        },
+                        {
+                            "type": "button",
+                            "text": { "type": "plain_text", "text": "Deny" },
+                            "action_id": "deny",
+                            "style": "danger"
+                        }
+                    ]
+                },
+                {
+                    "type": "context",
+                    "elements": [
+                        { "type": "image", "image_url": "https://example.com/logo.png", "alt_text": "logo" },
+                        { "type": "mrkdwn", "text": "Posted by <@U123>" }
+                    ]
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_length_limits() {
+        assert!(HeaderBlock::new("h".repeat(MAX_HEADER_TEXT_LEN)).build().is_ok());
+        assert!(matches!(
+            HeaderBlock::new("h".repeat(MAX_HEADER_TEXT_LEN + 1)).build(),
+            Err(SlackError::Request(RequestError::InvalidArguments { .. }))
+        ));
+        assert!(SectionBlock::new().build().is_err());
+        assert!(SectionBlock::new()
+            .text(TextObject::plain("x".repeat(MAX_SECTION_TEXT_LEN + 1)))
+            .build()
+            .is_err());
+        assert!(ActionsBlock::new().build().is_err());
+        assert!(ActionsBlock::new()
+            .element(ButtonElement::new("a", "b".repeat(MAX_BUTTON_TEXT_LEN + 1)))
+            .build()
+            .is_err());
+        assert!(DividerBlock::new()
+            .block_id("x".repeat(MAX_BLOCK_ID_LEN + 1))
+            .build()
+            .is_err());
+    }
+}
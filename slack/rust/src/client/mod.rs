@@ -11,7 +11,7 @@ use crate::services::{
     MessagesService, OAuthService, PinsService, ReactionsService, RemindersService,
     SearchService, StarsService, TeamService, UsergroupsService, UsersService, ViewsService,
 };
-use crate::transport::{HttpTransport, ReqwestTransport};
+use crate::transport::{HttpTransport, RateLimitedTransport, ReqwestTransport};
 use std::sync::Arc;
 
 /// Trait defining the Slack client interface
@@ -100,7 +100,9 @@ impl SlackClientImpl {
     pub fn new(config: SlackConfig) -> SlackResult<Self> {
         let config = Arc::new(config);
         let auth = AuthManager::new(config.clone());
-        let transport = Arc::new(ReqwestTransport::new(config.timeout)?);
+        let transport = Arc::new(RateLimitedTransport::new(ReqwestTransport::new(
+            config.timeout,
+        )?));
 
         // Create resilience orchestrator for all services
         let resilience = Arc::new(ResilienceOrchestrator::new(Default::default()));
@@ -2,8 +2,13 @@
 //!
 //! Types for handling Slack Events API callbacks.
 
+use crate::errors::{SlackResult, WebhookError};
 use crate::types::{ChannelId, Message, TeamId, Timestamp, UserId};
+use crate::webhooks::SignatureVerifier;
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{debug, warn};
 
 /// Event wrapper from Slack Events API
 #[derive(Debug, Clone, Deserialize)]
@@ -401,9 +406,219 @@ impl UrlVerificationResponse {
     }
 }
 
+/// Handler for Events API callbacks
+#[async_trait]
+pub trait EventHandler: Send + Sync {
+    /// Called for each event callback
+    ///
+    /// Slack expects the HTTP response within 3 seconds, so long-running
+    /// work should be spawned.
+    async fn on_event(&self, callback: EventCallback);
+
+    /// Called when Slack reports the app as rate limited
+    async fn on_app_rate_limited(&self, team_id: &TeamId, minute_rate_limited: i64) {
+        let _ = (team_id, minute_rate_limited);
+    }
+}
+
+/// Result of handling an Events API request body
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventHandlerOutcome {
+    /// URL verification: respond with this challenge verbatim
+    Challenge(String),
+    /// Event callback was dispatched to the handler
+    Dispatched {
+        /// Event ID
+        event_id: String,
+    },
+    /// App rate limited notice was dispatched to the handler
+    AppRateLimited,
+}
+
+impl EventHandlerOutcome {
+    /// Challenge to echo back, if this was a URL verification
+    pub fn challenge(&self) -> Option<&str> {
+        match self {
+            Self::Challenge(challenge) => Some(challenge),
+            _ => None,
+        }
+    }
+}
+
+/// Verifies and dispatches Events API requests
+pub struct EventDispatcher {
+    verifier: SignatureVerifier,
+    handler: Arc<dyn EventHandler>,
+}
+
+impl EventDispatcher {
+    /// Create new dispatcher
+    pub fn new(verifier: SignatureVerifier, handler: Arc<dyn EventHandler>) -> Self {
+        Self { verifier, handler }
+    }
+
+    /// Verify and handle an Events API request body
+    ///
+    /// `timestamp` and `signature` are the `X-Slack-Request-Timestamp` and
+    /// `X-Slack-Signature` headers. A `url_verification` request returns its
+    /// challenge without reaching the handler; event callbacks are parsed and
+    /// passed to [`EventHandler::on_event`].
+    pub async fn handle_event_payload(
+        &self,
+        timestamp: &str,
+        signature: &str,
+        body: &[u8],
+    ) -> SlackResult<EventHandlerOutcome> {
+        self.verifier.verify(timestamp, signature, body)?;
+
+        let event: SlackEvent = serde_json::from_slice(body).map_err(|e| {
+            warn!("Failed to parse event payload: {}", e);
+            WebhookError::InvalidPayload {
+                message: e.to_string(),
+            }
+        })?;
+
+        match event {
+            SlackEvent::UrlVerification { challenge, .. } => {
+                debug!("Answering URL verification challenge");
+                Ok(EventHandlerOutcome::Challenge(challenge))
+            }
+            SlackEvent::EventCallback(callback) => {
+                let event_id = callback.event_id.clone();
+                debug!("Dispatching event {}", event_id);
+                self.handler.on_event(callback).await;
+                Ok(EventHandlerOutcome::Dispatched { event_id })
+            }
+            SlackEvent::AppRateLimited {
+                team_id,
+                minute_rate_limited,
+                ..
+            } => {
+                warn!("App rate limited for team {}", team_id);
+                self.handler
+                    .on_app_rate_limited(&team_id, minute_rate_limited)
+                    .await;
+                Ok(EventHandlerOutcome::AppRateLimited)
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for EventDispatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventDispatcher")
+            .field("verifier", &self.verifier)
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Mutex;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[derive(Default)]
+    struct RecordingHandler {
+        events: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl EventHandler for RecordingHandler {
+        async fn on_event(&self, callback: EventCallback) {
+            self.events.lock().unwrap().push(callback.event_id);
+        }
+    }
+
+    fn signed(verifier: &SignatureVerifier, body: &str) -> (String, String) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            .to_string();
+        let signature = verifier.compute_signature(&timestamp, body.as_bytes());
+        (timestamp, signature)
+    }
+
+    fn dispatcher(handler: Arc<RecordingHandler>) -> EventDispatcher {
+        EventDispatcher::new(SignatureVerifier::new("test_secret"), handler)
+    }
+
+    #[tokio::test]
+    async fn test_url_verification_returns_challenge() {
+        let handler = Arc::new(RecordingHandler::default());
+        let dispatcher = dispatcher(handler.clone());
+        let body = r#"{
+            "token": "Jhj5dZrVaK7ZwHHjRyZWjbDl",
+            "challenge": "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P",
+            "type": "url_verification"
+        }"#;
+        let (timestamp, signature) = signed(&SignatureVerifier::new("test_secret"), body);
+
+        let outcome = dispatcher
+            .handle_event_payload(&timestamp, &signature, body.as_bytes())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            outcome.challenge(),
+            Some("3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P")
+        );
+        assert!(handler.events.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_event_callback_is_dispatched() {
+        let handler = Arc::new(RecordingHandler::default());
+        let dispatcher = dispatcher(handler.clone());
+        let body = r#"{
+            "token": "XXYYZZ",
+            "team_id": "T123",
+            "api_app_id": "A123",
+            "event": {
+                "type": "app_mention",
+                "user": "U123",
+                "text": "<@U0LAN0Z89> is it everything a river should be?",
+                "ts": "1515449522.000016",
+                "channel": "C123",
+                "event_ts": "1515449522000016"
+            },
+            "type": "event_callback",
+            "event_id": "Ev123ABC",
+            "event_time": 1515449522
+        }"#;
+        let (timestamp, signature) = signed(&SignatureVerifier::new("test_secret"), body);
+
+        let outcome = dispatcher
+            .handle_event_payload(&timestamp, &signature, body.as_bytes())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            outcome,
+            EventHandlerOutcome::Dispatched {
+                event_id: "Ev123ABC".to_string()
+            }
+        );
+        assert_eq!(*handler.events.lock().unwrap(), vec!["Ev123ABC".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_unsigned_payload_is_rejected() {
+        let handler = Arc::new(RecordingHandler::default());
+        let dispatcher = dispatcher(handler);
+        let body = r#"{"type":"url_verification","token":"t","challenge":"c"}"#;
+        let (timestamp, _) = signed(&SignatureVerifier::new("test_secret"), body);
+
+        let result = dispatcher
+            .handle_event_payload(&timestamp, "v0=bad", body.as_bytes())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(crate::errors::SlackError::Webhook(WebhookError::InvalidSignature))
+        ));
+    }
 
     #[test]
     fn test_deserialize_url_verification() {
@@ -37,6 +37,7 @@
 
 // Core modules
 pub mod auth;
+pub mod blocks;
 pub mod client;
 pub mod config;
 pub mod errors;
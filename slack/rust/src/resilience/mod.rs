@@ -9,5 +9,7 @@ pub mod retry;
 
 pub use circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitState};
 pub use orchestrator::{ResilienceOrchestrator, ResilienceOrchestratorConfig};
-pub use rate_limiter::{RateLimiter, RateLimiterConfig};
+pub use rate_limiter::{
+    RateLimitTier, RateLimiter, RateLimiterConfig, TierBudget, TieredRateLimiter,
+};
 pub use retry::{RetryConfig, RetryPolicy};
@@ -101,10 +101,110 @@ impl RateLimiterConfig {
     }
 }
 
+/// Slack Web API rate limit tier
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RateLimitTier {
+    /// 1+ requests per minute
+    Tier1,
+    /// 20+ requests per minute
+    Tier2,
+    /// 50+ requests per minute
+    Tier3,
+    /// 100+ requests per minute
+    Tier4,
+}
+
+/// Known method tiers; unlisted methods are treated as Tier 3
+const METHOD_TIERS: &[(&str, RateLimitTier)] = &[
+    ("bookmarks.add", RateLimitTier::Tier2),
+    ("bookmarks.list", RateLimitTier::Tier3),
+    ("chat.delete", RateLimitTier::Tier3),
+    ("chat.getPermalink", RateLimitTier::Tier4),
+    ("chat.postEphemeral", RateLimitTier::Tier4),
+    ("chat.postMessage", RateLimitTier::Tier4),
+    ("chat.update", RateLimitTier::Tier3),
+    ("conversations.archive", RateLimitTier::Tier2),
+    ("conversations.create", RateLimitTier::Tier2),
+    ("conversations.history", RateLimitTier::Tier3),
+    ("conversations.info", RateLimitTier::Tier3),
+    ("conversations.invite", RateLimitTier::Tier3),
+    ("conversations.join", RateLimitTier::Tier3),
+    ("conversations.list", RateLimitTier::Tier2),
+    ("conversations.members", RateLimitTier::Tier4),
+    ("conversations.replies", RateLimitTier::Tier3),
+    ("files.list", RateLimitTier::Tier3),
+    ("files.upload", RateLimitTier::Tier2),
+    ("pins.add", RateLimitTier::Tier2),
+    ("reactions.add", RateLimitTier::Tier3),
+    ("reminders.add", RateLimitTier::Tier2),
+    ("search.messages", RateLimitTier::Tier2),
+    ("team.info", RateLimitTier::Tier3),
+    ("usergroups.list", RateLimitTier::Tier2),
+    ("users.conversations", RateLimitTier::Tier3),
+    ("users.info", RateLimitTier::Tier4),
+    ("users.list", RateLimitTier::Tier2),
+    ("users.lookupByEmail", RateLimitTier::Tier3),
+    ("views.open", RateLimitTier::Tier4),
+    ("views.publish", RateLimitTier::Tier4),
+    ("views.push", RateLimitTier::Tier4),
+    ("views.update", RateLimitTier::Tier4),
+];
+
+impl RateLimitTier {
+    /// All tiers, lowest allowance first
+    pub const ALL: [RateLimitTier; 4] = [
+        RateLimitTier::Tier1,
+        RateLimitTier::Tier2,
+        RateLimitTier::Tier3,
+        RateLimitTier::Tier4,
+    ];
+
+    /// Look up the tier of a Web API method such as `chat.postMessage`
+    pub fn for_method(method: &str) -> Self {
+        METHOD_TIERS
+            .iter()
+            .find(|(name, _)| *name == method)
+            .map(|(_, tier)| *tier)
+            .unwrap_or(RateLimitTier::Tier3)
+    }
+
+    /// Requests per minute allowed by the tier
+    pub fn requests_per_minute(&self) -> u32 {
+        match self {
+            RateLimitTier::Tier1 => 1,
+            RateLimitTier::Tier2 => 20,
+            RateLimitTier::Tier3 => 50,
+            RateLimitTier::Tier4 => 100,
+        }
+    }
+
+    /// Token bucket configuration for the tier
+    pub fn config(&self) -> RateLimiterConfig {
+        match self {
+            RateLimitTier::Tier1 => RateLimiterConfig::tier1(),
+            RateLimitTier::Tier2 => RateLimiterConfig::tier2(),
+            RateLimitTier::Tier3 => RateLimiterConfig::tier3(),
+            RateLimitTier::Tier4 => RateLimiterConfig::tier4(),
+        }
+    }
+
+    /// Tier name as reported by Slack (`tier1` .. `tier4`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RateLimitTier::Tier1 => "tier1",
+            RateLimitTier::Tier2 => "tier2",
+            RateLimitTier::Tier3 => "tier3",
+            RateLimitTier::Tier4 => "tier4",
+        }
+    }
+}
+
 /// Token bucket state
 struct TokenBucket {
     tokens: f64,
     last_refill: Instant,
+    /// Set after a 429; no tokens are handed out before this instant
+    paused_until: Option<Instant>,
     config: RateLimiterConfig,
 }
 
@@ -113,12 +213,36 @@ impl TokenBucket {
         Self {
             tokens: config.max_tokens as f64,
             last_refill: Instant::now(),
+            paused_until: None,
             config,
         }
     }
 
+    fn pause_remaining(&self) -> Duration {
+        self.paused_until
+            .map(|until| until.saturating_duration_since(Instant::now()))
+            .unwrap_or(Duration::ZERO)
+    }
+
+    /// Hold back all tokens for `duration`, then allow a single request
+    fn pause(&mut self, duration: Duration) {
+        let until = Instant::now() + duration;
+        let extends = match self.paused_until {
+            Some(current) => current < until,
+            None => true,
+        };
+        if extends {
+            self.paused_until = Some(until);
+            self.last_refill = until;
+        }
+        self.tokens = 1.0;
+    }
+
     fn refill(&mut self) {
         let now = Instant::now();
+        if now < self.last_refill {
+            return;
+        }
         let elapsed = now.duration_since(self.last_refill).as_secs_f64();
         let new_tokens = elapsed * self.config.refill_rate;
         self.tokens = (self.tokens + new_tokens).min(self.config.max_tokens as f64);
@@ -127,6 +251,9 @@ impl TokenBucket {
 
     fn try_acquire(&mut self, tokens: f64) -> bool {
         self.refill();
+        if !self.pause_remaining().is_zero() {
+            return false;
+        }
         if self.tokens >= tokens {
             self.tokens -= tokens;
             true
@@ -137,6 +264,10 @@ impl TokenBucket {
 
     fn time_until_available(&mut self, tokens: f64) -> Duration {
         self.refill();
+        let paused = self.pause_remaining();
+        if !paused.is_zero() {
+            return paused;
+        }
         if self.tokens >= tokens {
             return Duration::ZERO;
         }
@@ -346,6 +477,150 @@ impl std::fmt::Debug for RateLimiter {
     }
 }
 
+/// Snapshot of one tier's bucket
+#[derive(Debug, Clone, PartialEq)]
+pub struct TierBudget {
+    /// The tier
+    pub tier: RateLimitTier,
+    /// Requests that can be sent right now
+    pub remaining: f64,
+    /// Bucket capacity
+    pub capacity: u32,
+    /// Time left on a pause imposed by a 429 response
+    pub paused_for: Option<Duration>,
+}
+
+/// Rate limiter with one token bucket per Slack rate limit tier
+///
+/// Each Web API method draws from the bucket of its tier (see
+/// [`RateLimitTier::for_method`]). A 429 response pauses the method's tier
+/// for the `Retry-After` duration.
+pub struct TieredRateLimiter {
+    buckets: Mutex<HashMap<RateLimitTier, TokenBucket>>,
+    method_overrides: HashMap<String, RateLimitTier>,
+}
+
+impl TieredRateLimiter {
+    /// Create a limiter using Slack's published tier rates
+    pub fn new() -> Self {
+        let buckets = RateLimitTier::ALL
+            .iter()
+            .map(|tier| (*tier, TokenBucket::new(tier.config())))
+            .collect();
+        Self {
+            buckets: Mutex::new(buckets),
+            method_overrides: HashMap::new(),
+        }
+    }
+
+    /// Replace the bucket configuration of a tier
+    pub fn with_tier_config(self, tier: RateLimitTier, config: RateLimiterConfig) -> Self {
+        self.buckets.lock().insert(tier, TokenBucket::new(config));
+        self
+    }
+
+    /// Assign a method to a tier, overriding the built-in table
+    pub fn with_method_tier(mut self, method: impl Into<String>, tier: RateLimitTier) -> Self {
+        self.method_overrides.insert(method.into(), tier);
+        self
+    }
+
+    /// Tier used for a method
+    pub fn tier_for(&self, method: &str) -> RateLimitTier {
+        self.method_overrides
+            .get(method)
+            .copied()
+            .unwrap_or_else(|| RateLimitTier::for_method(method))
+    }
+
+    /// Wait for a token in the method's tier
+    pub async fn acquire(&self, method: &str) -> SlackResult<()> {
+        let tier = self.tier_for(method);
+        loop {
+            let wait = {
+                let mut buckets = self.buckets.lock();
+                let bucket = buckets
+                    .entry(tier)
+                    .or_insert_with(|| TokenBucket::new(tier.config()));
+
+                if bucket.try_acquire(1.0) {
+                    return Ok(());
+                }
+                if !bucket.config.wait_for_tokens {
+                    return Err(SlackError::RateLimit(RateLimitError::TooManyRequests));
+                }
+
+                let wait = bucket.time_until_available(1.0);
+                if wait > bucket.config.max_wait {
+                    return Err(SlackError::RateLimit(RateLimitError::RateLimited {
+                        retry_after: wait,
+                        tier: Some(tier.as_str().to_string()),
+                    }));
+                }
+                wait
+            };
+
+            debug!(
+                method,
+                tier = tier.as_str(),
+                wait_ms = wait.as_millis(),
+                "Waiting for tier rate limit"
+            );
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Pause the method's tier after a 429 with `Retry-After`
+    pub fn pause(&self, method: &str, retry_after: Duration) {
+        let tier = self.tier_for(method);
+        warn!(
+            method,
+            tier = tier.as_str(),
+            retry_after_secs = retry_after.as_secs_f64(),
+            "Rate limited by Slack, pausing tier"
+        );
+        self.buckets
+            .lock()
+            .entry(tier)
+            .or_insert_with(|| TokenBucket::new(tier.config()))
+            .pause(retry_after);
+    }
+
+    /// Current budget of every tier
+    pub fn budgets(&self) -> Vec<TierBudget> {
+        let mut buckets = self.buckets.lock();
+        let mut budgets: Vec<TierBudget> = buckets
+            .iter_mut()
+            .map(|(tier, bucket)| {
+                bucket.refill();
+                let paused = bucket.pause_remaining();
+                TierBudget {
+                    tier: *tier,
+                    remaining: if paused.is_zero() { bucket.tokens } else { 0.0 },
+                    capacity: bucket.config.max_tokens,
+                    paused_for: (!paused.is_zero()).then_some(paused),
+                }
+            })
+            .collect();
+        budgets.sort_by_key(|budget| budget.tier);
+        budgets
+    }
+}
+
+impl Default for TieredRateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for TieredRateLimiter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TieredRateLimiter")
+            .field("budgets", &self.budgets())
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -407,6 +682,57 @@ mod tests {
         assert_eq!(limiter.remaining("test"), 5.0);
     }
 
+    #[test]
+    fn test_method_tiers() {
+        assert_eq!(RateLimitTier::for_method("chat.postMessage"), RateLimitTier::Tier4);
+        assert_eq!(RateLimitTier::for_method("conversations.history"), RateLimitTier::Tier3);
+        assert_eq!(RateLimitTier::for_method("users.list"), RateLimitTier::Tier2);
+        assert_eq!(RateLimitTier::for_method("unknown.method"), RateLimitTier::Tier3);
+
+        let limiter = TieredRateLimiter::new().with_method_tier("users.list", RateLimitTier::Tier4);
+        assert_eq!(limiter.tier_for("users.list"), RateLimitTier::Tier4);
+    }
+
+    #[tokio::test]
+    async fn test_tiered_limiter_delays_after_tier_budget_spent() {
+        let limiter = TieredRateLimiter::new().with_tier_config(
+            RateLimitTier::Tier3,
+            RateLimiterConfig::new().max_tokens(2).refill_rate(20.0),
+        );
+
+        let start = Instant::now();
+        limiter.acquire("conversations.history").await.unwrap();
+        limiter.acquire("conversations.replies").await.unwrap();
+        assert!(start.elapsed() < Duration::from_millis(20));
+
+        // Both methods share the exhausted Tier 3 bucket
+        limiter.acquire("conversations.history").await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(40));
+
+        // Other tiers are unaffected
+        let before = Instant::now();
+        limiter.acquire("chat.postMessage").await.unwrap();
+        assert!(before.elapsed() < Duration::from_millis(20));
+    }
+
+    #[tokio::test]
+    async fn test_tiered_limiter_pause_blocks_until_retry_after() {
+        let limiter = TieredRateLimiter::new();
+        limiter.pause("chat.postMessage", Duration::from_millis(100));
+
+        let budget = limiter
+            .budgets()
+            .into_iter()
+            .find(|budget| budget.tier == RateLimitTier::Tier4)
+            .unwrap();
+        assert_eq!(budget.remaining, 0.0);
+        assert!(budget.paused_for.is_some());
+
+        let start = Instant::now();
+        limiter.acquire("chat.postMessage").await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
+
     #[test]
     fn test_reset() {
         let limiter = RateLimiter::with_config(RateLimiterConfig::new().max_tokens(10));
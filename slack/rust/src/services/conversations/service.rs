@@ -4,9 +4,11 @@ use super::*;
 use crate::auth::AuthManager;
 use crate::errors::SlackResult;
 use crate::resilience::{DefaultRetryPolicy, ResilienceOrchestrator};
+use crate::services::pagination::paginate;
 use crate::transport::{HttpTransport, TransportRequest};
-use crate::types::ChannelId;
+use crate::types::{Channel, ChannelId};
 use async_trait::async_trait;
+use futures::Stream;
 use std::sync::Arc;
 use tracing::instrument;
 
@@ -96,6 +98,24 @@ impl ConversationsService {
     fn build_url(&self, endpoint: &str) -> String {
         format!("{}/{}", self.base_url.trim_end_matches('/'), endpoint)
     }
+
+    /// List all conversations, following `next_cursor` across pages
+    ///
+    /// Each page is requested with the request's `limit`. Rate-limited pages
+    /// are retried after the `Retry-After` delay.
+    pub fn list_all(&self, request: ListConversationsRequest) -> impl Stream<Item = SlackResult<Channel>> + '_ {
+        paginate(move |cursor| {
+            let request = match cursor {
+                Some(cursor) => request.clone().cursor(cursor),
+                None => request.clone(),
+            };
+            async move {
+                let response = self.list(request).await?;
+                let next_cursor = response.next_cursor().map(str::to_string);
+                Ok((response.channels, next_cursor))
+            }
+        })
+    }
 }
 
 #[async_trait]
@@ -1,14 +1,20 @@
 //! Files service for Slack API.
 //!
 //! Provides methods for uploading, listing, and managing files.
+//!
+//! Uploads use the external upload flow that replaced `files.upload`:
+//! `files.getUploadURLExternal` reserves a file of a declared length, the
+//! bytes are sent to the returned upload URL, and
+//! `files.completeUploadExternal` finalizes the file and shares it.
 
 use crate::auth::AuthManager;
-use crate::errors::SlackResult;
+use crate::errors::{RequestError, ResponseError, SlackError, SlackResult};
 use crate::resilience::{DefaultRetryPolicy, ResilienceOrchestrator};
-use crate::transport::{FileUpload, HttpTransport, MultipartRequest, TransportRequest};
+use crate::transport::{FormRequest, HttpTransport, RawRequest, TransportRequest};
 use crate::types::{ChannelId, Cursor, File, FileId, ResponseMetadata, UserId};
 use async_trait::async_trait;
 use bytes::Bytes;
+use http::HeaderMap;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tracing::instrument;
@@ -22,7 +28,7 @@ pub struct UploadFileRequest {
     pub content: Option<Bytes>,
     /// Filename
     pub filename: Option<String>,
-    /// File type
+    /// File type, sent as the snippet syntax type
     pub filetype: Option<String>,
     /// Initial comment
     pub initial_comment: Option<String>,
@@ -77,13 +83,35 @@ impl UploadFileRequest {
     }
 }
 
-/// Response from files.upload
+/// Response from files.getUploadURLExternal
 #[derive(Debug, Clone, Deserialize)]
-pub struct UploadFileResponse {
+pub struct GetUploadURLExternalResponse {
     /// Success indicator
     pub ok: bool,
-    /// Uploaded file
-    pub file: File,
+    /// URL to send the file content to
+    pub upload_url: String,
+    /// ID of the reserved file
+    pub file_id: FileId,
+}
+
+/// File reference used by files.completeUploadExternal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadedFile {
+    /// File ID
+    pub id: FileId,
+    /// File title
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+/// Response from files.completeUploadExternal
+#[derive(Debug, Clone, Deserialize)]
+pub struct CompleteUploadExternalResponse {
+    /// Success indicator
+    pub ok: bool,
+    /// Completed files
+    #[serde(default)]
+    pub files: Vec<UploadedFile>,
 }
 
 /// Request to list files
@@ -297,8 +325,11 @@ impl RevokePublicURLRequest {
 /// Trait for files service operations
 #[async_trait]
 pub trait FilesServiceTrait: Send + Sync {
-    /// Upload a file
-    async fn upload(&self, request: UploadFileRequest) -> SlackResult<UploadFileResponse>;
+    /// Upload a file and share it to a channel, returning the file ID
+    async fn upload(&self, channel: ChannelId, filename: &str, content: Bytes) -> SlackResult<FileId>;
+
+    /// Upload a file with title, comment and thread options, returning the file ID
+    async fn upload_file(&self, request: UploadFileRequest) -> SlackResult<FileId>;
 
     /// List files
     async fn list(&self, request: ListFilesRequest) -> SlackResult<ListFilesResponse>;
@@ -346,48 +377,139 @@ impl FilesService {
     }
 }
 
+fn invalid_upload(message: impl Into<String>) -> SlackError {
+    SlackError::Request(RequestError::InvalidArguments {
+        message: message.into(),
+    })
+}
+
+/// Check the plain-text reply of the upload URL, which is `OK - <length>`
+/// on success rather than a Slack JSON envelope
+fn check_upload_reply(body: &[u8], length: usize) -> SlackResult<()> {
+    let reply = String::from_utf8_lossy(body);
+    let reply = reply.trim();
+
+    let Some(rest) = reply.strip_prefix("OK") else {
+        return Err(SlackError::Response(ResponseError::UnexpectedResponse {
+            message: format!("File upload rejected: {}", reply),
+        }));
+    };
+
+    if let Ok(received) = rest.trim_start_matches([' ', '-']).parse::<usize>() {
+        if received != length {
+            return Err(SlackError::Response(ResponseError::UnexpectedResponse {
+                message: format!("File upload received {} of {} bytes", received, length),
+            }));
+        }
+    }
+
+    Ok(())
+}
+
 #[async_trait]
 impl FilesServiceTrait for FilesService {
-    #[instrument(skip(self, request))]
-    async fn upload(&self, request: UploadFileRequest) -> SlackResult<UploadFileResponse> {
-        let url = self.build_url("files.upload");
-        let headers = self.auth.get_primary_headers()?;
+    #[instrument(skip(self, content), fields(channel = %channel, length = content.len()))]
+    async fn upload(&self, channel: ChannelId, filename: &str, content: Bytes) -> SlackResult<FileId> {
+        self.upload_file(UploadFileRequest::with_content(content, filename).channels(vec![channel]))
+            .await
+    }
 
-        let mut multipart = MultipartRequest::new(url.clone(), headers);
-
-        if let Some(channels) = &request.channels {
-            multipart = multipart.field(
-                "channels",
-                channels
-                    .iter()
-                    .map(|c| c.as_str())
-                    .collect::<Vec<_>>()
-                    .join(","),
-            );
+    #[instrument(skip(self, request), fields(filename = ?request.filename))]
+    async fn upload_file(&self, request: UploadFileRequest) -> SlackResult<FileId> {
+        let content = request
+            .content
+            .ok_or_else(|| invalid_upload("File content is required"))?;
+        if content.is_empty() {
+            return Err(invalid_upload("File content cannot be empty"));
         }
 
-        if let Some(content) = request.content {
-            let filename = request.filename.unwrap_or_else(|| "file".to_string());
-            multipart = multipart.file(FileUpload::new("file", &filename, content));
+        let channels = request.channels.unwrap_or_default();
+        if request.thread_ts.is_some() && channels.len() != 1 {
+            return Err(invalid_upload(
+                "thread_ts requires exactly one channel to share the file to",
+            ));
         }
 
-        if let Some(filetype) = request.filetype {
-            multipart = multipart.field("filetype", filetype);
-        }
+        let filename = request.filename.unwrap_or_else(|| "file".to_string());
+        let filetype = request.filetype;
+        let length = content.len();
+        let headers = self.auth.get_primary_headers()?;
+        let transport = self.transport.clone();
 
+        // 1. Reserve the file; Slack requires its length up front
+        let url = self.build_url("files.getUploadURLExternal");
+        let reserved: GetUploadURLExternalResponse = self
+            .resilience
+            .execute("files.getUploadURLExternal", &DefaultRetryPolicy, || {
+                let mut request = FormRequest::post(url.clone(), headers.clone())
+                    .field("filename", filename.clone())
+                    .field("length", length.to_string());
+                if let Some(filetype) = &filetype {
+                    request = request.field("snippet_type", filetype.clone());
+                }
+                let transport = transport.clone();
+                async move { transport.send_form(request).await }
+            })
+            .await?;
+
+        // 2. Send the bytes to the upload URL. It is not a Slack API method:
+        // it takes no token and replies with plain text.
+        let reply = self
+            .transport
+            .send_raw(RawRequest::post(reserved.upload_url, HeaderMap::new(), content))
+            .await?;
+        check_upload_reply(&reply, length)?;
+
+        // 3. Complete the upload and share it
+        let files = serde_json::to_string(&[UploadedFile {
+            id: reserved.file_id.clone(),
+            title: request.title,
+        }])
+        .map_err(ResponseError::from)?;
+
+        let mut complete = FormRequest::post(self.build_url("files.completeUploadExternal"), headers)
+            .field("files", files);
+        match channels.as_slice() {
+            [] => {}
+            [channel] => complete = complete.field("channel_id", channel.as_str()),
+            _ => {
+                complete = complete.field(
+                    "channels",
+                    channels
+                        .iter()
+                        .map(|c| c.as_str())
+                        .collect::<Vec<_>>()
+                        .join(","),
+                );
+            }
+        }
         if let Some(comment) = request.initial_comment {
-            multipart = multipart.field("initial_comment", comment);
+            complete = complete.field("initial_comment", comment);
         }
-
         if let Some(thread_ts) = request.thread_ts {
-            multipart = multipart.field("thread_ts", thread_ts);
+            complete = complete.field("thread_ts", thread_ts);
         }
 
-        if let Some(title) = request.title {
-            multipart = multipart.field("title", title);
-        }
-
-        self.transport.send_multipart(multipart).await
+        let completed: CompleteUploadExternalResponse = self
+            .resilience
+            .execute_once("files.completeUploadExternal", || {
+                self.transport.send_form(complete)
+            })
+            .await?;
+
+        completed
+            .files
+            .into_iter()
+            .map(|file| file.id)
+            .find(|id| *id == reserved.file_id)
+            .ok_or_else(|| {
+                SlackError::Response(ResponseError::UnexpectedResponse {
+                    message: format!(
+                        "files.completeUploadExternal did not return file {}",
+                        reserved.file_id
+                    ),
+                })
+            })
     }
 
     #[instrument(skip(self))]
@@ -10,6 +10,7 @@ pub mod conversations;
 pub mod files;
 pub mod messages;
 pub mod oauth;
+pub(crate) mod pagination;
 pub mod pins;
 pub mod reactions;
 pub mod reminders;
@@ -0,0 +1,152 @@
+//! Cursor pagination helpers.
+//!
+//! Slack list methods return `response_metadata.next_cursor`, which is an
+//! empty string (rather than absent) on the last page.
+
+use crate::errors::SlackResult;
+use futures::stream::{self, Stream};
+use std::collections::VecDeque;
+use std::future::Future;
+use tracing::warn;
+
+/// Maximum consecutive rate-limited attempts for a single page
+const MAX_RATE_LIMIT_WAITS: u32 = 5;
+
+/// A page of results and the cursor for the next one
+pub(crate) type Page<T> = (Vec<T>, Option<String>);
+
+struct PageState<F, T> {
+    fetch: F,
+    cursor: Option<String>,
+    buffer: VecDeque<T>,
+    done: bool,
+}
+
+/// Stream items across pages, following `next_cursor` until it is empty
+///
+/// `fetch` is called with `None` for the first page and the previous
+/// cursor afterwards. A rate-limited page waits out `Retry-After` and is
+/// fetched again; any other error is yielded and ends the stream.
+pub(crate) fn paginate<T, F, Fut>(fetch: F) -> impl Stream<Item = SlackResult<T>>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = SlackResult<Page<T>>>,
+{
+    let state = PageState {
+        fetch,
+        cursor: None,
+        buffer: VecDeque::new(),
+        done: false,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(item) = state.buffer.pop_front() {
+                return Some((Ok(item), state));
+            }
+            if state.done {
+                return None;
+            }
+
+            let mut waits = 0;
+            let page = loop {
+                match (state.fetch)(state.cursor.clone()).await {
+                    Err(e) if waits < MAX_RATE_LIMIT_WAITS && e.retry_after().is_some() => {
+                        waits += 1;
+                        let delay = e.retry_after().unwrap_or_default();
+                        warn!(delay_ms = delay.as_millis() as u64, "Page rate limited, waiting");
+                        tokio::time::sleep(delay).await;
+                    }
+                    other => break other,
+                }
+            };
+
+            match page {
+                Ok((items, next_cursor)) => {
+                    state.buffer.extend(items);
+                    state.cursor = next_cursor.filter(|c| !c.is_empty());
+                    state.done = state.cursor.is_none();
+                }
+                Err(e) => {
+                    state.done = true;
+                    state.buffer.clear();
+                    return Some((Err(e), state));
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::{RateLimitError, SlackError};
+    use futures::StreamExt;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    fn pages(
+        responses: Vec<SlackResult<Page<u32>>>,
+    ) -> (
+        Arc<Mutex<Vec<Option<String>>>>,
+        impl FnMut(Option<String>) -> std::future::Ready<SlackResult<Page<u32>>>,
+    ) {
+        let cursors = Arc::new(Mutex::new(Vec::new()));
+        let seen = cursors.clone();
+        let mut responses = VecDeque::from(responses);
+        let fetch = move |cursor| {
+            seen.lock().unwrap().push(cursor);
+            std::future::ready(responses.pop_front().expect("unexpected page request"))
+        };
+        (cursors, fetch)
+    }
+
+    #[tokio::test]
+    async fn test_follows_cursor_until_empty() {
+        let (cursors, fetch) = pages(vec![
+            Ok((vec![1, 2], Some("page2".to_string()))),
+            Ok((vec![3], Some(String::new()))),
+        ]);
+
+        let items: Vec<u32> = paginate(fetch).map(|r| r.unwrap()).collect().await;
+
+        assert_eq!(items, vec![1, 2, 3]);
+        assert_eq!(*cursors.lock().unwrap(), vec![None, Some("page2".to_string())]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_waits_out_rate_limit() {
+        let (cursors, fetch) = pages(vec![
+            Ok((vec![1], Some("page2".to_string()))),
+            Err(SlackError::RateLimit(RateLimitError::RateLimited {
+                retry_after: Duration::from_secs(30),
+                tier: Some("tier2".to_string()),
+            })),
+            Ok((vec![2], None)),
+        ]);
+
+        let started = tokio::time::Instant::now();
+        let items: Vec<u32> = paginate(fetch).map(|r| r.unwrap()).collect().await;
+
+        assert_eq!(items, vec![1, 2]);
+        assert!(started.elapsed() >= Duration::from_secs(30));
+        assert_eq!(cursors.lock().unwrap()[1..], [Some("page2".to_string()), Some("page2".to_string())]);
+    }
+
+    #[tokio::test]
+    async fn test_error_ends_stream() {
+        let (_, fetch) = pages(vec![
+            Ok((vec![1], Some("page2".to_string()))),
+            Err(SlackError::Api {
+                code: "invalid_cursor".to_string(),
+                message: "invalid_cursor".to_string(),
+            }),
+        ]);
+
+        let results: Vec<SlackResult<u32>> = paginate(fetch).collect().await;
+
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+    }
+}
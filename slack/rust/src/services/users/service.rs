@@ -4,8 +4,11 @@ use super::*;
 use crate::auth::AuthManager;
 use crate::errors::SlackResult;
 use crate::resilience::{DefaultRetryPolicy, ResilienceOrchestrator};
+use crate::services::pagination::paginate;
 use crate::transport::{HttpTransport, TransportRequest};
+use crate::types::User;
 use async_trait::async_trait;
+use futures::Stream;
 use std::sync::Arc;
 use tracing::instrument;
 
@@ -71,6 +74,24 @@ impl UsersService {
     fn build_url(&self, endpoint: &str) -> String {
         format!("{}/{}", self.base_url.trim_end_matches('/'), endpoint)
     }
+
+    /// List all users, following `next_cursor` across pages
+    ///
+    /// Each page is requested with the request's `limit`. Rate-limited pages
+    /// are retried after the `Retry-After` delay.
+    pub fn list_all(&self, request: ListUsersRequest) -> impl Stream<Item = SlackResult<User>> + '_ {
+        paginate(move |cursor| {
+            let request = match cursor {
+                Some(cursor) => request.clone().cursor(cursor),
+                None => request.clone(),
+            };
+            async move {
+                let response = self.list(request).await?;
+                let next_cursor = response.next_cursor().map(str::to_string);
+                Ok((response.members, next_cursor))
+            }
+        })
+    }
 }
 
 #[async_trait]
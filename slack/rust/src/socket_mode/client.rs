@@ -6,13 +6,20 @@ use crate::config::SlackConfig;
 use crate::errors::{SlackError, SlackResult, SocketModeError};
 use super::types::*;
 use super::handler::SocketModeHandler;
+use async_trait::async_trait;
 use futures_util::{SinkExt, StreamExt};
+use std::collections::VecDeque;
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::{mpsc, RwLock};
-use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tokio::net::TcpStream;
+use tokio::sync::{mpsc, Notify, RwLock};
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
 use tracing::{debug, error, info, warn};
 
+/// Time allowed for a handler to produce a response payload before a plain
+/// ack is sent instead, leaving headroom inside Slack's 3 second window
+const ACK_DEADLINE: Duration = Duration::from_millis(2500);
+
 /// Socket Mode connection state
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SocketState {
@@ -37,6 +44,8 @@ pub struct SocketModeConfig {
     pub reconnect_delay: Duration,
     /// Maximum reconnect attempts
     pub max_reconnect_attempts: u32,
+    /// Upper bound for the exponential reconnect delay
+    pub max_reconnect_delay: Duration,
     /// Ping interval
     pub ping_interval: Duration,
 }
@@ -48,6 +57,7 @@ impl Default for SocketModeConfig {
             auto_reconnect: true,
             reconnect_delay: Duration::from_secs(5),
             max_reconnect_attempts: 10,
+            max_reconnect_delay: Duration::from_secs(60),
             ping_interval: Duration::from_secs(30),
         }
     }
@@ -79,6 +89,121 @@ impl SocketModeConfig {
         self.max_reconnect_attempts = attempts;
         self
     }
+
+    /// Set max reconnect delay
+    pub fn with_max_reconnect_delay(mut self, delay: Duration) -> Self {
+        self.max_reconnect_delay = delay;
+        self
+    }
+
+    /// Delay before the given reconnect attempt (1-based), doubling each time
+    pub fn backoff_delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.reconnect_delay
+            .saturating_mul(factor)
+            .min(self.max_reconnect_delay)
+    }
+}
+
+/// Frame received from a Socket Mode connection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SocketFrame {
+    /// Text frame
+    Text(String),
+    /// Close frame with optional reason
+    Close(Option<String>),
+}
+
+/// An open Socket Mode connection
+#[async_trait]
+pub trait SocketConnection: Send {
+    /// Receive the next frame, or `None` once the connection has ended
+    async fn recv(&mut self) -> Option<SlackResult<SocketFrame>>;
+
+    /// Send a text frame
+    async fn send(&mut self, text: String) -> SlackResult<()>;
+}
+
+/// Opens Socket Mode connections
+#[async_trait]
+pub trait SocketConnector: Send + Sync {
+    /// Open a new connection
+    async fn connect(&self) -> SlackResult<Box<dyn SocketConnection>>;
+}
+
+/// Connector that opens a WebSocket via `apps.connections.open`
+#[derive(Debug, Clone)]
+pub struct WebSocketConnector {
+    app_token: String,
+}
+
+impl WebSocketConnector {
+    /// Create new connector with app token
+    pub fn new(app_token: impl Into<String>) -> Self {
+        Self {
+            app_token: app_token.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl SocketConnector for WebSocketConnector {
+    async fn connect(&self) -> SlackResult<Box<dyn SocketConnection>> {
+        let url = open_connection_url(&self.app_token).await?;
+        let (stream, _) = connect_async(url.as_str()).await.map_err(|e| {
+            SlackError::SocketMode(SocketModeError::ConnectionFailed {
+                message: e.to_string(),
+            })
+        })?;
+        Ok(Box::new(WebSocketConnection { stream }))
+    }
+}
+
+/// Connection backed by a tungstenite WebSocket
+struct WebSocketConnection {
+    stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+}
+
+#[async_trait]
+impl SocketConnection for WebSocketConnection {
+    async fn recv(&mut self) -> Option<SlackResult<SocketFrame>> {
+        loop {
+            match self.stream.next().await? {
+                Ok(Message::Text(text)) => return Some(Ok(SocketFrame::Text(text))),
+                Ok(Message::Close(frame)) => {
+                    return Some(Ok(SocketFrame::Close(frame.map(|f| f.reason.to_string()))))
+                }
+                // Ping/pong are answered by tungstenite
+                Ok(_) => continue,
+                Err(e) => {
+                    return Some(Err(SlackError::SocketMode(SocketModeError::WebSocket {
+                        message: e.to_string(),
+                    })))
+                }
+            }
+        }
+    }
+
+    async fn send(&mut self, text: String) -> SlackResult<()> {
+        self.stream.send(Message::Text(text)).await.map_err(|e| {
+            SlackError::SocketMode(SocketModeError::WebSocket {
+                message: e.to_string(),
+            })
+        })
+    }
+}
+
+/// How a single connection ended
+enum SessionEnd {
+    /// Shutdown was requested
+    Shutdown,
+    /// Slack asked us to reconnect ahead of closing the socket
+    Refresh,
+    /// The socket closed or failed
+    Closed {
+        /// Whether the connection had received `hello`
+        established: bool,
+    },
 }
 
 /// Socket Mode client for real-time events
@@ -88,17 +213,220 @@ pub struct SocketModeClient {
     handler: Arc<dyn SocketModeHandler>,
     reconnect_attempts: Arc<RwLock<u32>>,
     shutdown_tx: Option<mpsc::Sender<()>>,
+    connector: Arc<dyn SocketConnector>,
+    shutdown: Arc<Notify>,
 }
 
 impl SocketModeClient {
     /// Create new Socket Mode client
     pub fn new(config: SocketModeConfig, handler: Arc<dyn SocketModeHandler>) -> Self {
+        let connector = Arc::new(WebSocketConnector::new(config.app_token.clone()));
         Self {
             config,
             state: Arc::new(RwLock::new(SocketState::Disconnected)),
             handler,
             reconnect_attempts: Arc::new(RwLock::new(0)),
             shutdown_tx: None,
+            connector,
+            shutdown: Arc::new(Notify::new()),
+        }
+    }
+
+    /// Set the connector used by [`run`](Self::run)
+    pub fn with_connector(mut self, connector: Arc<dyn SocketConnector>) -> Self {
+        self.connector = connector;
+        self
+    }
+
+    /// Stop a running [`run`](Self::run) loop
+    pub fn shutdown(&self) {
+        self.shutdown.notify_one();
+    }
+
+    /// Connect and dispatch events until shut down
+    ///
+    /// Envelopes are acknowledged within Slack's 3 second window. Dropped
+    /// connections are re-established with exponential backoff, and a
+    /// `disconnect` frame triggers an immediate reconnect before Slack closes
+    /// the socket. Acks that could not be sent are replayed on the next
+    /// connection.
+    pub async fn run(&self) -> SlackResult<()> {
+        let mut pending_acks = VecDeque::new();
+        let mut attempts: u32 = 0;
+
+        loop {
+            *self.state.write().await = if attempts == 0 {
+                SocketState::Connecting
+            } else {
+                SocketState::Reconnecting
+            };
+
+            let end = match self.connector.connect().await {
+                Ok(mut connection) => {
+                    *self.state.write().await = SocketState::Connected;
+                    self.handler.on_connect().await;
+                    let end = self.serve(connection.as_mut(), &mut pending_acks).await;
+                    *self.state.write().await = SocketState::Disconnected;
+                    self.handler.on_disconnect(None).await;
+                    end?
+                }
+                Err(e) => {
+                    warn!("Socket Mode connection failed: {}", e);
+                    self.handler.on_error(e).await;
+                    SessionEnd::Closed { established: false }
+                }
+            };
+
+            match end {
+                SessionEnd::Shutdown => {
+                    *self.state.write().await = SocketState::Disconnected;
+                    return Ok(());
+                }
+                SessionEnd::Refresh => {
+                    attempts = 0;
+                    continue;
+                }
+                SessionEnd::Closed { established } => {
+                    if established {
+                        attempts = 0;
+                    }
+                    if !self.config.auto_reconnect {
+                        *self.state.write().await = SocketState::Disconnected;
+                        return Err(SlackError::SocketMode(SocketModeError::ConnectionClosed {
+                            reason: "connection lost".to_string(),
+                        }));
+                    }
+                    attempts += 1;
+                    if attempts > self.config.max_reconnect_attempts {
+                        *self.state.write().await = SocketState::Disconnected;
+                        return Err(SlackError::SocketMode(SocketModeError::ReconnectFailed {
+                            attempts: attempts - 1,
+                        }));
+                    }
+                    *self.reconnect_attempts.write().await = attempts;
+                    *self.state.write().await = SocketState::Reconnecting;
+
+                    let delay = self.config.backoff_delay(attempts);
+                    info!(
+                        "Reconnect attempt {} of {} in {:?}",
+                        attempts, self.config.max_reconnect_attempts, delay
+                    );
+                    tokio::select! {
+                        _ = self.shutdown.notified() => {
+                            *self.state.write().await = SocketState::Disconnected;
+                            return Ok(());
+                        }
+                        _ = tokio::time::sleep(delay) => {}
+                    }
+                }
+            }
+        }
+    }
+
+    /// Read frames from one connection until it ends
+    async fn serve(
+        &self,
+        connection: &mut dyn SocketConnection,
+        pending_acks: &mut VecDeque<String>,
+    ) -> SlackResult<SessionEnd> {
+        let mut established = false;
+
+        while let Some(ack) = pending_acks.pop_front() {
+            if connection.send(ack.clone()).await.is_err() {
+                pending_acks.push_front(ack);
+                return Ok(SessionEnd::Closed { established });
+            }
+        }
+
+        loop {
+            let frame = tokio::select! {
+                _ = self.shutdown.notified() => return Ok(SessionEnd::Shutdown),
+                frame = connection.recv() => frame,
+            };
+
+            let text = match frame {
+                Some(Ok(SocketFrame::Text(text))) => text,
+                Some(Ok(SocketFrame::Close(reason))) => {
+                    warn!("WebSocket closed: {:?}", reason);
+                    return Ok(SessionEnd::Closed { established });
+                }
+                Some(Err(e)) => {
+                    error!("WebSocket error: {}", e);
+                    self.handler.on_error(e).await;
+                    return Ok(SessionEnd::Closed { established });
+                }
+                None => {
+                    warn!("WebSocket stream ended");
+                    return Ok(SessionEnd::Closed { established });
+                }
+            };
+
+            if let Ok(hello) = serde_json::from_str::<HelloMessage>(&text) {
+                if hello.message_type == "hello" {
+                    debug!("Received hello message: {:?}", hello);
+                    established = true;
+                    continue;
+                }
+            }
+
+            if let Ok(disconnect) = serde_json::from_str::<DisconnectMessage>(&text) {
+                if disconnect.message_type == "disconnect" {
+                    debug!("Received disconnect message: {:?}", disconnect);
+                    return match disconnect.reason.as_deref() {
+                        Some("link_disabled") => {
+                            Err(SlackError::SocketMode(SocketModeError::ConnectionClosed {
+                                reason: "link_disabled".to_string(),
+                            }))
+                        }
+                        _ => Ok(SessionEnd::Refresh),
+                    };
+                }
+            }
+
+            let envelope = match serde_json::from_str::<SocketModeEnvelope>(&text) {
+                Ok(envelope) => envelope,
+                Err(e) => {
+                    debug!("Failed to parse message: {} - {}", e, text);
+                    continue;
+                }
+            };
+
+            let ack = serde_json::to_string(&self.dispatch(envelope).await)
+                .expect("ack serialization cannot fail");
+            if let Err(e) = connection.send(ack.clone()).await {
+                warn!("Failed to send acknowledgment, buffering: {}", e);
+                pending_acks.push_back(ack);
+                return Ok(SessionEnd::Closed { established });
+            }
+        }
+    }
+
+    /// Hand an envelope to the handler and build its ack
+    ///
+    /// Envelopes that take no response payload are acked without waiting for
+    /// the handler. Otherwise the handler gets [`ACK_DEADLINE`] to respond
+    /// before a plain ack is sent and it keeps running in the background.
+    async fn dispatch(&self, envelope: SocketModeEnvelope) -> SocketModeAck {
+        let envelope_id = envelope.envelope_id.clone();
+        let accepts_response = envelope.accepts_response_payload;
+        let handler = self.handler.clone();
+        let task = tokio::spawn(async move { handler.handle_envelope(envelope).await });
+
+        if !accepts_response {
+            return SocketModeAck::simple(envelope_id);
+        }
+
+        match tokio::time::timeout(ACK_DEADLINE, task).await {
+            Ok(Ok(Some(payload))) => SocketModeAck::with_payload(envelope_id, payload),
+            Ok(Ok(None)) => SocketModeAck::simple(envelope_id),
+            Ok(Err(e)) => {
+                error!("Handler for envelope {} failed: {}", envelope_id, e);
+                SocketModeAck::simple(envelope_id)
+            }
+            Err(_) => {
+                warn!("Handler for envelope {} missed the ack deadline", envelope_id);
+                SocketModeAck::simple(envelope_id)
+            }
         }
     }
 
@@ -125,6 +453,7 @@ impl SocketModeClient {
 
     /// Disconnect from Socket Mode
     pub async fn disconnect(&mut self) {
+        self.shutdown.notify_one();
         if let Some(tx) = self.shutdown_tx.take() {
             let _ = tx.send(()).await;
         }
@@ -134,38 +463,7 @@ impl SocketModeClient {
 
     /// Get WebSocket URL from Slack
     async fn get_websocket_url(&self) -> SlackResult<String> {
-        let client = reqwest::Client::new();
-        let response = client
-            .post("https://slack.com/api/apps.connections.open")
-            .header("Authorization", format!("Bearer {}", self.config.app_token))
-            .header("Content-Type", "application/x-www-form-urlencoded")
-            .send()
-            .await
-            .map_err(|e| SlackError::SocketMode(SocketModeError::ConnectionFailed {
-                message: e.to_string(),
-            }))?;
-
-        let data: serde_json::Value = response.json().await.map_err(|e| {
-            SlackError::SocketMode(SocketModeError::ConnectionFailed {
-                message: e.to_string(),
-            })
-        })?;
-
-        if !data["ok"].as_bool().unwrap_or(false) {
-            let error = data["error"].as_str().unwrap_or("Unknown error");
-            return Err(SlackError::SocketMode(SocketModeError::ConnectionFailed {
-                message: error.to_string(),
-            }));
-        }
-
-        data["url"]
-            .as_str()
-            .map(|s| s.to_string())
-            .ok_or_else(|| {
-                SlackError::SocketMode(SocketModeError::ConnectionFailed {
-                    message: "No URL in response".to_string(),
-                })
-            })
+        open_connection_url(&self.config.app_token).await
     }
 
     /// Establish WebSocket connection
@@ -318,7 +616,7 @@ impl SocketModeClient {
         *state.write().await = SocketState::Reconnecting;
 
         // Exponential backoff
-        let delay = config.reconnect_delay * 2u32.pow(attempts.saturating_sub(1));
+        let delay = config.backoff_delay(attempts);
         tokio::time::sleep(delay).await;
 
         info!("Reconnect attempt {} of {}", attempts, config.max_reconnect_attempts);
@@ -328,6 +626,42 @@ impl SocketModeClient {
     }
 }
 
+/// Request a WebSocket URL from `apps.connections.open`
+async fn open_connection_url(app_token: &str) -> SlackResult<String> {
+    let client = reqwest::Client::new();
+    let response = client
+        .post("https://slack.com/api/apps.connections.open")
+        .header("Authorization", format!("Bearer {}", app_token))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .send()
+        .await
+        .map_err(|e| SlackError::SocketMode(SocketModeError::ConnectionFailed {
+            message: e.to_string(),
+        }))?;
+
+    let data: serde_json::Value = response.json().await.map_err(|e| {
+        SlackError::SocketMode(SocketModeError::ConnectionFailed {
+            message: e.to_string(),
+        })
+    })?;
+
+    if !data["ok"].as_bool().unwrap_or(false) {
+        let error = data["error"].as_str().unwrap_or("Unknown error");
+        return Err(SlackError::SocketMode(SocketModeError::ConnectionFailed {
+            message: error.to_string(),
+        }));
+    }
+
+    data["url"]
+        .as_str()
+        .map(|s| s.to_string())
+        .ok_or_else(|| {
+            SlackError::SocketMode(SocketModeError::ConnectionFailed {
+                message: "No URL in response".to_string(),
+            })
+        })
+}
+
 impl std::fmt::Debug for SocketModeClient {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("SocketModeClient")
@@ -335,3 +669,273 @@ impl std::fmt::Debug for SocketModeClient {
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+    use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+    struct MockConnection {
+        incoming: UnboundedReceiver<SocketFrame>,
+        outgoing: UnboundedSender<String>,
+    }
+
+    #[async_trait]
+    impl SocketConnection for MockConnection {
+        async fn recv(&mut self) -> Option<SlackResult<SocketFrame>> {
+            self.incoming.recv().await.map(Ok)
+        }
+
+        async fn send(&mut self, text: String) -> SlackResult<()> {
+            self.outgoing.send(text).map_err(|_| {
+                SlackError::SocketMode(SocketModeError::WebSocket {
+                    message: "socket closed".to_string(),
+                })
+            })
+        }
+    }
+
+    /// Test side of a mock connection
+    struct MockSocket {
+        server: UnboundedSender<SocketFrame>,
+        acks: UnboundedReceiver<String>,
+    }
+
+    impl MockSocket {
+        fn send(&self, text: &str) {
+            self.server.send(SocketFrame::Text(text.to_string())).unwrap();
+        }
+
+        async fn next_ack(&mut self) -> serde_json::Value {
+            serde_json::from_str(&self.acks.recv().await.unwrap()).unwrap()
+        }
+    }
+
+    /// Connector that hands out scripted connections, failing once exhausted
+    #[derive(Default)]
+    struct MockConnector {
+        connections: Mutex<VecDeque<Option<MockConnection>>>,
+        connects: AtomicUsize,
+    }
+
+    impl MockConnector {
+        fn push_socket(&self) -> MockSocket {
+            let (server, incoming) = unbounded_channel();
+            let (outgoing, acks) = unbounded_channel();
+            self.connections
+                .lock()
+                .unwrap()
+                .push_back(Some(MockConnection { incoming, outgoing }));
+            MockSocket { server, acks }
+        }
+
+        fn push_failure(&self) {
+            self.connections.lock().unwrap().push_back(None);
+        }
+    }
+
+    #[async_trait]
+    impl SocketConnector for MockConnector {
+        async fn connect(&self) -> SlackResult<Box<dyn SocketConnection>> {
+            self.connects.fetch_add(1, Ordering::SeqCst);
+            match self.connections.lock().unwrap().pop_front().flatten() {
+                Some(connection) => Ok(Box::new(connection)),
+                None => Err(SlackError::SocketMode(SocketModeError::ConnectionFailed {
+                    message: "refused".to_string(),
+                })),
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct CountingHandler {
+        envelopes: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl SocketModeHandler for CountingHandler {
+        async fn handle_envelope(&self, _envelope: SocketModeEnvelope) -> Option<serde_json::Value> {
+            self.envelopes.fetch_add(1, Ordering::SeqCst);
+            Some(serde_json::json!({ "text": "handled" }))
+        }
+    }
+
+    const HELLO: &str = r#"{"type":"hello","num_connections":1}"#;
+
+    fn events_api_envelope(envelope_id: &str) -> String {
+        serde_json::json!({
+            "envelope_id": envelope_id,
+            "type": "events_api",
+            "accepts_response_payload": false,
+            "retry_attempt": 0,
+            "retry_reason": "",
+            "payload": {
+                "token": "token",
+                "team_id": "T123",
+                "api_app_id": "A123",
+                "event": {
+                    "type": "app_mention",
+                    "user": "U123",
+                    "text": "<@U0LAN0Z89> hello",
+                    "ts": "1515449522.000016",
+                    "channel": "C123",
+                    "event_ts": "1515449522000016"
+                },
+                "type": "event_callback",
+                "event_id": "Ev123",
+                "event_time": 1515449522
+            }
+        })
+        .to_string()
+    }
+
+    fn client(
+        config: SocketModeConfig,
+        connector: Arc<MockConnector>,
+        handler: Arc<CountingHandler>,
+    ) -> Arc<SocketModeClient> {
+        Arc::new(SocketModeClient::new(config, handler).with_connector(connector))
+    }
+
+    #[tokio::test]
+    async fn test_run_acks_events_api_envelope() {
+        let connector = Arc::new(MockConnector::default());
+        let handler = Arc::new(CountingHandler::default());
+        let mut socket = connector.push_socket();
+        let client = client(SocketModeConfig::new("xapp-test"), connector, handler.clone());
+
+        let running = tokio::spawn({
+            let client = client.clone();
+            async move { client.run().await }
+        });
+
+        socket.send(HELLO);
+        socket.send(&events_api_envelope("57d6a792-4d35-4d0b-b6aa-3361493e1caf"));
+
+        let ack = socket.next_ack().await;
+        assert_eq!(ack["envelope_id"], "57d6a792-4d35-4d0b-b6aa-3361493e1caf");
+        assert!(ack.get("payload").is_none());
+        assert_eq!(client.state().await, SocketState::Connected);
+
+        client.shutdown();
+        running.await.unwrap().unwrap();
+        assert_eq!(handler.envelopes.load(Ordering::SeqCst), 1);
+        assert_eq!(client.state().await, SocketState::Disconnected);
+    }
+
+    #[tokio::test]
+    async fn test_run_acks_with_handler_payload() {
+        let connector = Arc::new(MockConnector::default());
+        let handler = Arc::new(CountingHandler::default());
+        let mut socket = connector.push_socket();
+        let client = client(SocketModeConfig::new("xapp-test"), connector, handler);
+
+        let running = tokio::spawn({
+            let client = client.clone();
+            async move { client.run().await }
+        });
+
+        socket.send(
+            &serde_json::json!({
+                "envelope_id": "env-1",
+                "type": "slash_commands",
+                "accepts_response_payload": true,
+                "payload": {}
+            })
+            .to_string(),
+        );
+
+        let ack = socket.next_ack().await;
+        assert_eq!(ack["envelope_id"], "env-1");
+        assert_eq!(ack["payload"]["text"], "handled");
+
+        client.shutdown();
+        running.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_frame_reconnects_with_same_handler() {
+        let connector = Arc::new(MockConnector::default());
+        let handler = Arc::new(CountingHandler::default());
+        let first = connector.push_socket();
+        let mut second = connector.push_socket();
+        let config = SocketModeConfig::new("xapp-test").with_reconnect_delay(Duration::from_secs(3600));
+        let client = client(config, connector.clone(), handler.clone());
+
+        let running = tokio::spawn({
+            let client = client.clone();
+            async move { client.run().await }
+        });
+
+        first.send(HELLO);
+        first.send(r#"{"type":"disconnect","reason":"refresh_requested"}"#);
+
+        second.send(HELLO);
+        second.send(&events_api_envelope("env-2"));
+        assert_eq!(second.next_ack().await["envelope_id"], "env-2");
+
+        client.shutdown();
+        running.await.unwrap().unwrap();
+        assert_eq!(connector.connects.load(Ordering::SeqCst), 2);
+        assert_eq!(handler.envelopes.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_reconnects_with_backoff_and_replays_unsent_acks() {
+        let connector = Arc::new(MockConnector::default());
+        let handler = Arc::new(CountingHandler::default());
+        let first = connector.push_socket();
+        connector.push_failure();
+        let mut third = connector.push_socket();
+        let config = SocketModeConfig::new("xapp-test").with_reconnect_delay(Duration::from_secs(1));
+        let client = client(config, connector.clone(), handler);
+
+        let started = tokio::time::Instant::now();
+        let running = tokio::spawn({
+            let client = client.clone();
+            async move { client.run().await }
+        });
+
+        // The socket dies before the ack can be written
+        let MockSocket { server, acks } = first;
+        drop(acks);
+        server.send(SocketFrame::Text(events_api_envelope("env-3"))).unwrap();
+
+        // Close, refused connect, then success: 1s + 2s of backoff
+        assert_eq!(third.next_ack().await["envelope_id"], "env-3");
+        assert!(started.elapsed() >= Duration::from_secs(3));
+        assert_eq!(connector.connects.load(Ordering::SeqCst), 3);
+
+        client.shutdown();
+        running.await.unwrap().unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_gives_up_after_max_attempts() {
+        let connector = Arc::new(MockConnector::default());
+        let handler = Arc::new(CountingHandler::default());
+        connector.push_failure();
+        let config = SocketModeConfig::new("xapp-test").with_max_reconnect_attempts(2);
+        let client = client(config, connector.clone(), handler);
+
+        let result = client.run().await;
+        assert!(matches!(
+            result,
+            Err(SlackError::SocketMode(SocketModeError::ReconnectFailed { attempts: 2 }))
+        ));
+        assert_eq!(connector.connects.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_backoff_delay_is_capped() {
+        let config = SocketModeConfig::new("xapp-test")
+            .with_reconnect_delay(Duration::from_secs(5))
+            .with_max_reconnect_delay(Duration::from_secs(30));
+        assert_eq!(config.backoff_delay(1), Duration::from_secs(5));
+        assert_eq!(config.backoff_delay(2), Duration::from_secs(10));
+        assert_eq!(config.backoff_delay(4), Duration::from_secs(30));
+        assert_eq!(config.backoff_delay(40), Duration::from_secs(30));
+    }
+}
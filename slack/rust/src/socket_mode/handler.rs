@@ -260,7 +260,7 @@ mod tests {
                 println!("Disconnected: {:?}", reason);
             });
 
-        handler.on_connect().await;
-        handler.on_disconnect(Some("test")).await;
+        SocketModeHandler::on_connect(&handler).await;
+        SocketModeHandler::on_disconnect(&handler, Some("test")).await;
     }
 }
@@ -17,8 +17,8 @@
 //!         println!("Connected!");
 //!     });
 //!
-//! let mut client = SocketModeClient::new(config, Arc::new(handler));
-//! client.connect().await?;
+//! let client = SocketModeClient::new(config, Arc::new(handler));
+//! client.run().await?;
 //! ```
 
 mod client;
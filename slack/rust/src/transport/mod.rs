@@ -14,6 +14,10 @@ use serde::{de::DeserializeOwned, Serialize};
 use std::time::Duration;
 use tracing::{debug, instrument, warn};
 
+mod rate_limited;
+
+pub use rate_limited::RateLimitedTransport;
+
 /// HTTP transport trait for making API requests
 #[async_trait]
 pub trait HttpTransport: Send + Sync {
@@ -234,6 +238,17 @@ impl RawRequest {
             timeout: None,
         }
     }
+
+    /// Create a new raw POST request
+    pub fn post(url: impl Into<String>, headers: HeaderMap, body: impl Into<Bytes>) -> Self {
+        Self {
+            method: Method::POST,
+            url: url.into(),
+            headers,
+            body: Some(body.into()),
+            timeout: None,
+        }
+    }
 }
 
 /// Default HTTP transport implementation using reqwest
@@ -0,0 +1,184 @@
+//! Tier-aware rate limiting for transports.
+
+use super::{FormRequest, HttpTransport, MultipartRequest, RawRequest, TransportRequest};
+use crate::errors::{RateLimitError, SlackError, SlackResult};
+use crate::resilience::{TierBudget, TieredRateLimiter};
+use async_trait::async_trait;
+use bytes::Bytes;
+use serde::{de::DeserializeOwned, Serialize};
+use std::sync::Arc;
+
+/// Transport that throttles requests by the Slack rate limit tier of their method
+///
+/// Requests wait for a token from their tier's bucket before being sent. A
+/// 429 response pauses the tier for the `Retry-After` duration, so later calls
+/// wait instead of being rejected again.
+pub struct RateLimitedTransport<T> {
+    inner: T,
+    limiter: Arc<TieredRateLimiter>,
+}
+
+impl<T: HttpTransport> RateLimitedTransport<T> {
+    /// Wrap a transport using Slack's published tier rates
+    pub fn new(inner: T) -> Self {
+        Self::with_limiter(inner, Arc::new(TieredRateLimiter::new()))
+    }
+
+    /// Wrap a transport with a shared limiter
+    pub fn with_limiter(inner: T, limiter: Arc<TieredRateLimiter>) -> Self {
+        Self { inner, limiter }
+    }
+
+    /// The limiter used by this transport
+    pub fn limiter(&self) -> &Arc<TieredRateLimiter> {
+        &self.limiter
+    }
+
+    /// Current budget of every tier
+    pub fn tier_budgets(&self) -> Vec<TierBudget> {
+        self.limiter.budgets()
+    }
+
+    /// The wrapped transport
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    fn observe<R>(&self, method: &str, result: SlackResult<R>) -> SlackResult<R> {
+        if let Err(SlackError::RateLimit(RateLimitError::RateLimited { retry_after, .. })) = &result {
+            self.limiter.pause(method, *retry_after);
+        }
+        result
+    }
+}
+
+/// Web API method name from a request URL, e.g. `chat.postMessage`
+fn api_method(url: &str) -> &str {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    path.trim_end_matches('/').rsplit('/').next().unwrap_or(path)
+}
+
+#[async_trait]
+impl<T: HttpTransport> HttpTransport for RateLimitedTransport<T> {
+    async fn send_json<Req, Res>(&self, request: TransportRequest<Req>) -> SlackResult<Res>
+    where
+        Req: Serialize + Send + Sync,
+        Res: DeserializeOwned,
+    {
+        let method = api_method(&request.url).to_string();
+        self.limiter.acquire(&method).await?;
+        let result = self.inner.send_json(request).await;
+        self.observe(&method, result)
+    }
+
+    async fn send_form<Res>(&self, request: FormRequest) -> SlackResult<Res>
+    where
+        Res: DeserializeOwned,
+    {
+        let method = api_method(&request.url).to_string();
+        self.limiter.acquire(&method).await?;
+        let result = self.inner.send_form(request).await;
+        self.observe(&method, result)
+    }
+
+    async fn send_multipart<Res>(&self, request: MultipartRequest) -> SlackResult<Res>
+    where
+        Res: DeserializeOwned,
+    {
+        let method = api_method(&request.url).to_string();
+        self.limiter.acquire(&method).await?;
+        let result = self.inner.send_multipart(request).await;
+        self.observe(&method, result)
+    }
+
+    async fn send_raw(&self, request: RawRequest) -> SlackResult<Bytes> {
+        let method = api_method(&request.url).to_string();
+        self.limiter.acquire(&method).await?;
+        let result = self.inner.send_raw(request).await;
+        self.observe(&method, result)
+    }
+}
+
+impl<T> std::fmt::Debug for RateLimitedTransport<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RateLimitedTransport")
+            .field("limiter", &self.limiter)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocks::{MockHttpTransport, MockResponse};
+    use crate::resilience::{RateLimitTier, RateLimiterConfig};
+    use http::HeaderMap;
+    use std::time::{Duration, Instant};
+
+    fn post_message() -> TransportRequest<serde_json::Value> {
+        TransportRequest::post(
+            "https://slack.com/api/chat.postMessage",
+            HeaderMap::new(),
+            serde_json::json!({"channel": "C123", "text": "hi"}),
+        )
+    }
+
+    #[test]
+    fn test_api_method_from_url() {
+        assert_eq!(api_method("https://slack.com/api/chat.postMessage"), "chat.postMessage");
+        assert_eq!(
+            api_method("https://slack.com/api/conversations.history?channel=C1"),
+            "conversations.history"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exceeding_tier_rate_delays_next_call() {
+        let mock = MockHttpTransport::new().with_default_response(MockResponse::ok(r#"{"ok":true}"#));
+        let limiter = TieredRateLimiter::new().with_tier_config(
+            RateLimitTier::Tier4,
+            RateLimiterConfig::new().max_tokens(2).refill_rate(10.0),
+        );
+        let transport = RateLimitedTransport::with_limiter(mock, Arc::new(limiter));
+
+        let start = Instant::now();
+        for _ in 0..2 {
+            let _: serde_json::Value = transport.send_json(post_message()).await.unwrap();
+        }
+        assert!(start.elapsed() < Duration::from_millis(50));
+
+        let _: serde_json::Value = transport.send_json(post_message()).await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(90));
+        assert_eq!(transport.inner().recorded_requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_response_pauses_subsequent_calls() {
+        let mock = MockHttpTransport::new()
+            .add_response(MockResponse::error(SlackError::RateLimit(
+                RateLimitError::RateLimited {
+                    retry_after: Duration::from_millis(200),
+                    tier: Some("tier4".to_string()),
+                },
+            )))
+            .with_default_response(MockResponse::ok(r#"{"ok":true}"#));
+        let transport = RateLimitedTransport::new(mock);
+
+        let result: SlackResult<serde_json::Value> = transport.send_json(post_message()).await;
+        assert!(matches!(
+            result,
+            Err(SlackError::RateLimit(RateLimitError::RateLimited { .. }))
+        ));
+
+        let budget = transport
+            .tier_budgets()
+            .into_iter()
+            .find(|budget| budget.tier == RateLimitTier::Tier4)
+            .unwrap();
+        assert!(budget.paused_for.is_some());
+
+        let start = Instant::now();
+        let _: serde_json::Value = transport.send_json(post_message()).await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(150));
+    }
+}
@@ -304,7 +304,7 @@ pub struct Block {
     #[serde(rename = "type")]
     pub block_type: String,
     /// Block ID
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub block_id: Option<String>,
     /// Block elements (structure varies by type)
     #[serde(flatten)]
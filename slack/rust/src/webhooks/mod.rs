@@ -89,7 +89,7 @@ impl SignatureVerifier {
     }
 
     /// Compute HMAC-SHA256 signature
-    fn compute_signature(&self, timestamp: &str, body: &[u8]) -> String {
+    pub(crate) fn compute_signature(&self, timestamp: &str, body: &[u8]) -> String {
         // Create base string: v0:timestamp:body
         let mut base_string = format!("v0:{}:", timestamp).into_bytes();
         base_string.extend_from_slice(body);
@@ -14,7 +14,10 @@ use hmac::{Hmac, Mac};
 use md5::Md5;
 use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
+use futures::future::BoxFuture;
 use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
 
 use crate::errors::{SmtpError, SmtpErrorKind, SmtpResult};
 
@@ -97,11 +100,15 @@ pub enum Credentials {
         username: String,
         /// Access token (protected).
         access_token: SecretString,
+        /// Callback used to fetch a new token when the server rejects this one.
+        refresher: Option<TokenRefresher>,
     },
     /// OAuth 2.0 Bearer Token.
     OAuthBearer {
         /// Access token (protected).
         access_token: SecretString,
+        /// Callback used to fetch a new token when the server rejects this one.
+        refresher: Option<TokenRefresher>,
     },
 }
 
@@ -119,6 +126,7 @@ impl Credentials {
         Self::XOAuth2 {
             username: username.into(),
             access_token: SecretString::new(access_token.into()),
+            refresher: None,
         }
     }
 
@@ -126,6 +134,52 @@ impl Credentials {
     pub fn oauth_bearer(access_token: impl Into<String>) -> Self {
         Self::OAuthBearer {
             access_token: SecretString::new(access_token.into()),
+            refresher: None,
+        }
+    }
+
+    /// Attaches a token refresher to OAuth credentials.
+    ///
+    /// Has no effect on plain credentials.
+    pub fn with_token_refresher(mut self, token_refresher: TokenRefresher) -> Self {
+        match &mut self {
+            Credentials::Plain { .. } => {}
+            Credentials::XOAuth2 { refresher, .. } | Credentials::OAuthBearer { refresher, .. } => {
+                *refresher = Some(token_refresher);
+            }
+        }
+        self
+    }
+
+    /// Returns true if these credentials carry a token refresher.
+    pub fn can_refresh(&self) -> bool {
+        match self {
+            Credentials::Plain { .. } => false,
+            Credentials::XOAuth2 { refresher, .. } | Credentials::OAuthBearer { refresher, .. } => {
+                refresher.is_some()
+            }
+        }
+    }
+
+    /// Fetches a new access token, returning credentials that use it.
+    ///
+    /// The refresher is carried over so the result can be refreshed again.
+    pub async fn refreshed(&self) -> SmtpResult<Credentials> {
+        match self {
+            Credentials::XOAuth2 { username, refresher: Some(refresher), .. } => {
+                Ok(Credentials::XOAuth2 {
+                    username: username.clone(),
+                    access_token: refresher.refresh().await?,
+                    refresher: Some(refresher.clone()),
+                })
+            }
+            Credentials::OAuthBearer { refresher: Some(refresher), .. } => {
+                Ok(Credentials::OAuthBearer {
+                    access_token: refresher.refresh().await?,
+                    refresher: Some(refresher.clone()),
+                })
+            }
+            _ => Err(SmtpError::authentication("Credentials have no token refresher")),
         }
     }
 
@@ -160,19 +214,50 @@ impl fmt::Debug for Credentials {
                 .field("username", username)
                 .field("password", &"[REDACTED]")
                 .finish(),
-            Credentials::XOAuth2 { username, .. } => f
+            Credentials::XOAuth2 { username, refresher, .. } => f
                 .debug_struct("XOAuth2")
                 .field("username", username)
                 .field("access_token", &"[REDACTED]")
+                .field("refresher", refresher)
                 .finish(),
-            Credentials::OAuthBearer { .. } => f
+            Credentials::OAuthBearer { refresher, .. } => f
                 .debug_struct("OAuthBearer")
                 .field("access_token", &"[REDACTED]")
+                .field("refresher", refresher)
                 .finish(),
         }
     }
 }
 
+/// Async callback that fetches a fresh OAuth access token.
+///
+/// The client calls it when the server rejects the current token with a 535,
+/// then retries authentication once with the new token.
+#[derive(Clone)]
+pub struct TokenRefresher(Arc<dyn Fn() -> BoxFuture<'static, SmtpResult<String>> + Send + Sync>);
+
+impl TokenRefresher {
+    /// Creates a refresher from an async closure returning the new access token.
+    pub fn new<F, Fut>(refresh: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = SmtpResult<String>> + Send + 'static,
+    {
+        Self(Arc::new(move || Box::pin(refresh())))
+    }
+
+    /// Fetches a new access token.
+    pub async fn refresh(&self) -> SmtpResult<SecretString> {
+        (self.0)().await.map(SecretString::new)
+    }
+}
+
+impl fmt::Debug for TokenRefresher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TokenRefresher")
+    }
+}
+
 /// Provider for credentials with optional token refresh.
 #[async_trait]
 pub trait CredentialProvider: Send + Sync + fmt::Debug {
@@ -426,8 +511,42 @@ mod tests {
         let token = SecretString::new("test_token".to_string());
         let response = Authenticator::xoauth2_initial_response("user@example.com", &token);
         let decoded = String::from_utf8(BASE64.decode(&response).unwrap()).unwrap();
-        assert!(decoded.contains("user=user@example.com"));
-        assert!(decoded.contains("auth=Bearer test_token"));
+        assert_eq!(decoded, "user=user@example.com\x01auth=Bearer test_token\x01\x01");
+    }
+
+    #[tokio::test]
+    async fn test_refreshed_credentials() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let refresher = {
+            let calls = calls.clone();
+            TokenRefresher::new(move || {
+                let n = calls.fetch_add(1, Ordering::SeqCst) + 1;
+                async move { Ok(format!("token-{}", n)) }
+            })
+        };
+
+        let creds = Credentials::xoauth2("user@example.com", "stale").with_token_refresher(refresher);
+        assert!(creds.can_refresh());
+
+        let refreshed = creds.refreshed().await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(refreshed.can_refresh());
+        match &refreshed {
+            Credentials::XOAuth2 { username, access_token, .. } => {
+                let response = Authenticator::xoauth2_initial_response(username, access_token);
+                let decoded = BASE64.decode(&response).unwrap();
+                assert_eq!(decoded, b"user=user@example.com\x01auth=Bearer token-1\x01\x01");
+            }
+            other => panic!("Expected XOAuth2, got {:?}", other),
+        }
+
+        assert!(!Credentials::xoauth2("user", "token").can_refresh());
+        assert!(Credentials::xoauth2("user", "token").refreshed().await.is_err());
+        assert!(!Credentials::plain("user", "pass")
+            .with_token_refresher(TokenRefresher::new(|| async { Ok(String::new()) }))
+            .can_refresh());
     }
 
     #[test]
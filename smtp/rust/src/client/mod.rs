@@ -6,7 +6,7 @@
 //! - TLS negotiation
 //! - Resilience (retry, circuit breaker, rate limiting)
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
 use crate::auth::{AuthMethod, Authenticator, CredentialProvider, Credentials, StaticCredentialProvider};
@@ -14,13 +14,17 @@ use crate::config::{SmtpConfig, TlsMode};
 use crate::errors::{SmtpError, SmtpErrorKind, SmtpResult};
 use crate::mime::MimeEncoder;
 use crate::observability::{SmtpMetrics, Timer};
-use crate::protocol::{EsmtpCapabilities, SmtpCommand, TransactionState, codes};
+use crate::protocol::{EsmtpCapabilities, SmtpCommand, SmtpResponse, TransactionState, codes};
 use crate::resilience::{CircuitBreaker, RateLimiter, ResilienceOrchestrator, RetryExecutor};
-use crate::transport::{SmtpTransport, TcpTransport, pool::{SmtpPool, create_pool}};
+use crate::transport::{SmtpTransport, TcpTransport, pool::{SmtpPool, create_pool, spawn_maintenance}};
 use crate::types::{
     Address, BatchSendResult, ConnectionInfo, Email, PoolStatus, RejectedRecipient, SendResult,
+    ValidatedMessage,
 };
 
+/// Largest chunk sent with a single BDAT command.
+const BDAT_CHUNK_SIZE: usize = 64 * 1024;
+
 /// High-level SMTP client.
 pub struct SmtpClient {
     /// Configuration.
@@ -29,12 +33,16 @@ pub struct SmtpClient {
     pool: Option<SmtpPool>,
     /// Credential provider.
     credential_provider: Option<Arc<dyn CredentialProvider>>,
+    /// Most recently refreshed OAuth credentials, used for new connections.
+    refreshed_credentials: Mutex<Option<Credentials>>,
     /// Resilience orchestrator.
     resilience: ResilienceOrchestrator,
     /// Metrics collector.
     metrics: Arc<SmtpMetrics>,
     /// MIME encoder.
     encoder: MimeEncoder,
+    /// Background pool maintenance task.
+    maintenance: Option<tokio::task::JoinHandle<()>>,
 }
 
 impl SmtpClient {
@@ -54,6 +62,11 @@ impl SmtpClient {
 
         // Create connection pool
         let pool = create_pool((*config).clone())?;
+        let maintenance = if config.pool.health_check_enabled {
+            Some(spawn_maintenance(pool.clone()))
+        } else {
+            None
+        };
 
         // Create resilience orchestrator
         let resilience = ResilienceOrchestrator::new(
@@ -69,9 +82,11 @@ impl SmtpClient {
             config,
             pool: Some(pool),
             credential_provider,
+            refreshed_credentials: Mutex::new(None),
             resilience,
             metrics: Arc::new(SmtpMetrics::new()),
             encoder,
+            maintenance,
         })
     }
 
@@ -86,17 +101,17 @@ impl SmtpClient {
         let message_id = email.message_id.clone()
             .unwrap_or_else(|| self.encoder.generate_message_id());
 
-        // Encode the email
+        // Encode the email; its size is what SIZE limits apply to (RFC 1870)
         let encoded = self.encoder.encode(&email)?;
-        let data = MimeEncoder::prepare_data_content(&encoded);
+        let size = encoded.len();
 
         // Execute with resilience
         let result = self.resilience.execute(|| {
             let email = email.clone();
-            let data = data.clone();
+            let encoded = encoded.clone();
             let message_id = message_id.clone();
             async move {
-                self.send_inner(&email, &data, &message_id).await
+                self.send_inner(&email, &encoded, size, &message_id).await
             }
         }).await;
 
@@ -111,6 +126,83 @@ impl SmtpClient {
         })
     }
 
+    /// Validates and encodes an email without sending it.
+    ///
+    /// Checks the sender, every recipient and the Reply-To address, requires
+    /// at least one recipient and a body, runs the full MIME encoding, and
+    /// enforces the configured maximum message size. No connection is made.
+    pub fn validate(&self, email: &Email) -> SmtpResult<ValidatedMessage> {
+        if email.from.email.is_empty() {
+            return Err(SmtpError::message_error(
+                SmtpErrorKind::InvalidFromAddress,
+                "From address is required",
+            ));
+        }
+        Address::new(email.from.email.as_str()).map_err(|e| {
+            SmtpError::message_error(
+                SmtpErrorKind::InvalidFromAddress,
+                format!("Invalid From address '{}': {}", email.from.email, e.message()),
+            )
+        })?;
+
+        let recipients: Vec<Address> = email.all_recipients().cloned().collect();
+        if recipients.is_empty() {
+            return Err(SmtpError::message_error(
+                SmtpErrorKind::InvalidRecipientAddress,
+                "At least one recipient is required",
+            ));
+        }
+        for recipient in &recipients {
+            Address::new(recipient.email.as_str()).map_err(|e| {
+                SmtpError::message_error(
+                    SmtpErrorKind::InvalidRecipientAddress,
+                    format!("Invalid recipient address '{}': {}", recipient.email, e.message()),
+                )
+            })?;
+        }
+
+        if let Some(reply_to) = &email.reply_to {
+            Address::new(reply_to.email.as_str()).map_err(|e| {
+                SmtpError::message_error(
+                    SmtpErrorKind::InvalidRecipientAddress,
+                    format!("Invalid Reply-To address '{}': {}", reply_to.email, e.message()),
+                )
+            })?;
+        }
+
+        if email.text.is_none() && email.html.is_none() {
+            return Err(SmtpError::message_error(
+                SmtpErrorKind::EncodingFailed,
+                "Email body is required (text or HTML)",
+            ));
+        }
+
+        // Pin the message ID so the encoded headers match what is returned
+        let message_id = email.message_id.clone()
+            .unwrap_or_else(|| self.encoder.generate_message_id());
+        let mut email = email.clone();
+        email.message_id = Some(message_id.clone());
+
+        let bytes = self.encoder.encode(&email)?;
+        let size = bytes.len();
+        if size > self.config.max_message_size {
+            return Err(SmtpError::message_error(
+                SmtpErrorKind::MessageTooLarge,
+                format!(
+                    "Message size {} exceeds the configured limit of {} bytes",
+                    size, self.config.max_message_size
+                ),
+            ));
+        }
+
+        Ok(ValidatedMessage {
+            message_id,
+            recipients,
+            bytes,
+            size,
+        })
+    }
+
     /// Sends multiple emails.
     pub async fn send_batch(&self, emails: Vec<Email>) -> BatchSendResult {
         let start = Instant::now();
@@ -210,6 +302,7 @@ impl SmtpClient {
         &self,
         email: &Email,
         data: &[u8],
+        size: usize,
         message_id: &str,
     ) -> SmtpResult<SendResult> {
         // Get connection from pool
@@ -224,25 +317,40 @@ impl SmtpClient {
         let transport: &mut TcpTransport = &mut *conn;
 
         // Perform SMTP transaction
-        self.perform_transaction(transport, email, data, message_id).await
+        self.perform_transaction(transport, email, data, size, message_id).await
     }
 
     /// Performs the SMTP transaction.
+    ///
+    /// `data` is the encoded message; it is dot-stuffed only when sent with DATA.
     async fn perform_transaction(
         &self,
         transport: &mut TcpTransport,
         email: &Email,
         data: &[u8],
+        size: usize,
         message_id: &str,
     ) -> SmtpResult<SendResult> {
         // Ensure we're in a good state
         self.ensure_ready(transport).await?;
 
+        // Refuse oversized messages before anything is transmitted
+        let capabilities = transport.capabilities();
+        if let Some(limit) = capabilities.and_then(|c| c.size_limit()) {
+            if size > limit {
+                return Err(SmtpError::message_too_large(size, limit));
+            }
+        }
+
+        // Large messages go out with BDAT when the server offers CHUNKING (RFC 3030)
+        let chunked = capabilities.map(|c| c.chunking).unwrap_or(false)
+            && size > self.config.chunking_threshold;
+
         // Start mail transaction
         let mail_from = SmtpCommand::MailFrom {
             address: email.from.to_smtp(),
-            size: Some(data.len()),
-            body_8bit: transport.capabilities()
+            size: capabilities.and_then(|c| c.size).map(|_| size),
+            body_8bit: capabilities
                 .map(|c| c.eight_bit_mime)
                 .unwrap_or(false),
             smtputf8: false,
@@ -266,38 +374,51 @@ impl SmtpClient {
             let response = transport.send_command(&rcpt_to).await?;
             if response.is_success() {
                 accepted.push(recipient.clone());
-            } else {
+            } else if is_recipient_rejection(response.code) {
                 rejected.push(RejectedRecipient {
                     address: recipient.clone(),
                     code: response.code,
                     message: response.full_message(),
                 });
+            } else {
+                // Not about this mailbox; the transaction cannot continue
+                Self::reset_transaction(transport).await?;
+                return Err(response.to_error());
             }
         }
 
         if accepted.is_empty() {
-            // Reset and fail
-            transport.send_command(&SmtpCommand::Rset).await?;
-            return Err(SmtpError::message_error(
+            Self::reset_transaction(transport).await?;
+            let details: Vec<String> = rejected
+                .iter()
+                .map(|r| format!("{} ({} {})", r.address.email, r.code, r.message))
+                .collect();
+            let mut err = SmtpError::message_error(
                 SmtpErrorKind::InvalidRecipientAddress,
-                "All recipients were rejected",
-            ));
+                format!("All recipients were rejected: {}", details.join("; ")),
+            );
+            if let Some(first) = rejected.first() {
+                err = err.with_smtp_code(first.code);
+            }
+            return Err(err);
         }
 
         transport.set_state(TransactionState::RecipientsAdded);
 
-        // Send DATA command
-        let response = transport.send_command(&SmtpCommand::Data).await?;
-        if response.code != codes::START_MAIL_INPUT {
-            return Err(response.to_error());
-        }
-        transport.set_state(TransactionState::SendingData);
-
-        // Send message content
-        transport.send_data(data).await?;
+        let response = if chunked {
+            Self::send_chunked(transport, data).await?
+        } else {
+            // Send DATA command
+            let response = transport.send_command(&SmtpCommand::Data).await?;
+            if response.code != codes::START_MAIL_INPUT {
+                return Err(response.to_error());
+            }
+            transport.set_state(TransactionState::SendingData);
 
-        // Read final response
-        let response = transport.read_response().await?;
+            // Send dot-stuffed message content and read the final response
+            transport.send_data(&MimeEncoder::prepare_data_content(data)).await?;
+            transport.read_response().await?
+        };
         transport.set_state(TransactionState::Complete);
 
         if !response.is_success() {
@@ -314,6 +435,41 @@ impl SmtpClient {
         })
     }
 
+    /// Sends the message as BDAT chunks, returning the response to the last one.
+    ///
+    /// Chunks are length-prefixed, so the message is sent as-is: no
+    /// dot-stuffing and no terminating `.` line.
+    async fn send_chunked(transport: &mut TcpTransport, data: &[u8]) -> SmtpResult<SmtpResponse> {
+        transport.set_state(TransactionState::SendingData);
+
+        for chunk in data.chunks(BDAT_CHUNK_SIZE) {
+            let response = Self::send_bdat(transport, chunk, false).await?;
+            if !response.is_success() {
+                return Err(response.to_error());
+            }
+        }
+
+        Self::send_bdat(transport, &[], true).await
+    }
+
+    /// Sends one BDAT command followed by exactly `chunk.len()` octets.
+    async fn send_bdat(transport: &mut TcpTransport, chunk: &[u8], last: bool) -> SmtpResult<SmtpResponse> {
+        let command = SmtpCommand::Bdat { size: chunk.len(), last };
+        transport.send_data(format!("{}\r\n", command.to_smtp_string()).as_bytes()).await?;
+        transport.send_data(chunk).await?;
+        transport.read_response().await
+    }
+
+    /// Abandons the current mail transaction, leaving the connection reusable.
+    async fn reset_transaction(transport: &mut TcpTransport) -> SmtpResult<()> {
+        let response = transport.send_command(&SmtpCommand::Rset).await?;
+        if !response.is_success() {
+            return Err(response.to_error());
+        }
+        transport.set_state(TransactionState::Complete);
+        Ok(())
+    }
+
     /// Ensures the transport is ready for a new transaction.
     async fn ensure_ready(&self, transport: &mut TcpTransport) -> SmtpResult<()> {
         let state = transport.state();
@@ -381,14 +537,34 @@ impl SmtpClient {
         // Authenticate if needed
         if let Some(provider) = &self.credential_provider {
             if transport.state() != TransactionState::Authenticated {
-                let credentials = provider.get_credentials().await?;
-                self.authenticate(transport, &credentials).await?;
+                let refreshed = self.refreshed_credentials.lock().unwrap().clone();
+                let credentials = match refreshed {
+                    Some(credentials) => credentials,
+                    None => provider.get_credentials().await?,
+                };
+                self.authenticate_with_refresh(transport, &credentials).await?;
             }
         }
 
         Ok(())
     }
 
+    /// Authenticates, refreshing the OAuth token and retrying once on a 535.
+    async fn authenticate_with_refresh(
+        &self,
+        transport: &mut TcpTransport,
+        credentials: &Credentials,
+    ) -> SmtpResult<()> {
+        match self.authenticate(transport, credentials).await {
+            Err(e) if e.smtp_code() == Some(codes::AUTH_FAILED) && credentials.can_refresh() => {
+                let refreshed = credentials.refreshed().await?;
+                *self.refreshed_credentials.lock().unwrap() = Some(refreshed.clone());
+                self.authenticate(transport, &refreshed).await
+            }
+            result => result,
+        }
+    }
+
     /// Performs authentication.
     async fn authenticate(
         &self,
@@ -523,14 +699,19 @@ impl SmtpClient {
     }
 
     async fn auth_xoauth2(&self, transport: &mut TcpTransport, credentials: &Credentials) -> SmtpResult<()> {
-        if let Credentials::XOAuth2 { username, access_token } = credentials {
+        if let Credentials::XOAuth2 { username, access_token, .. } = credentials {
             let initial_response = Authenticator::xoauth2_initial_response(username, access_token);
             let command = SmtpCommand::Auth {
                 mechanism: "XOAUTH2".to_string(),
                 initial_response: Some(initial_response),
             };
 
-            let response = transport.send_command(&command).await?;
+            let mut response = transport.send_command(&command).await?;
+            if response.code == codes::AUTH_CONTINUE {
+                // Rejected tokens get an error challenge; an empty reply ends the exchange
+                transport.send_data(b"\r\n").await?;
+                response = transport.read_response().await?;
+            }
             if response.code == codes::AUTH_SUCCESS {
                 Ok(())
             } else {
@@ -542,7 +723,7 @@ impl SmtpClient {
     }
 
     async fn auth_oauth_bearer(&self, transport: &mut TcpTransport, credentials: &Credentials) -> SmtpResult<()> {
-        if let Credentials::OAuthBearer { access_token } = credentials {
+        if let Credentials::OAuthBearer { access_token, .. } = credentials {
             let initial_response = Authenticator::oauth_bearer_initial_response(
                 access_token,
                 Some(&self.config.host),
@@ -553,7 +734,12 @@ impl SmtpClient {
                 initial_response: Some(initial_response),
             };
 
-            let response = transport.send_command(&command).await?;
+            let mut response = transport.send_command(&command).await?;
+            if response.code == codes::AUTH_CONTINUE {
+                // RFC 7628: acknowledge the error challenge with a single %x01
+                transport.send_data(b"AQ==\r\n").await?;
+                response = transport.read_response().await?;
+            }
             if response.code == codes::AUTH_SUCCESS {
                 Ok(())
             } else {
@@ -565,6 +751,30 @@ impl SmtpClient {
     }
 }
 
+/// Returns true if an RCPT TO reply rejects only that recipient.
+///
+/// Other failures (e.g. 421 or 503) mean the transaction cannot continue.
+fn is_recipient_rejection(code: u16) -> bool {
+    matches!(
+        code,
+        codes::MAILBOX_UNAVAILABLE_TEMP
+            | codes::LOCAL_ERROR
+            | codes::INSUFFICIENT_STORAGE
+            | codes::MAILBOX_UNAVAILABLE
+            | codes::USER_NOT_LOCAL
+            | codes::MESSAGE_TOO_BIG
+            | codes::INVALID_MAILBOX
+    )
+}
+
+impl Drop for SmtpClient {
+    fn drop(&mut self) {
+        if let Some(task) = self.maintenance.take() {
+            task.abort();
+        }
+    }
+}
+
 impl MimeEncoder {
     /// Generates a message ID.
     pub fn generate_message_id(&self) -> String {
@@ -619,7 +829,11 @@ impl SmtpClientBuilder {
             .ok_or_else(|| SmtpError::configuration("Host is required"))?
             .build()?;
 
-        SmtpClient::new(config).await
+        let mut client = SmtpClient::new(config).await?;
+        if let Some(provider) = self.credential_provider {
+            client.credential_provider = Some(provider);
+        }
+        Ok(client)
     }
 }
 
@@ -639,4 +853,337 @@ mod tests {
         // Can't test build() without actual server
         assert!(builder.config_builder.is_some());
     }
+
+    #[tokio::test]
+    async fn test_send_replaces_dead_pooled_connection() {
+        let server = crate::mocks::MockSmtpServer::closing_after(1).await;
+        let config = server
+            .config()
+            .no_retry()
+            .pool(crate::config::PoolConfig {
+                min_idle: 0,
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+        let client = SmtpClient::new(config).await.unwrap();
+
+        client.send(crate::mocks::test_email().unwrap()).await.unwrap();
+        client.send(crate::mocks::test_email().unwrap()).await.unwrap();
+
+        assert_eq!(server.messages().len(), 2);
+        assert_eq!(server.connections(), 2);
+    }
+
+    fn xoauth2_client_credentials(refreshes: Arc<std::sync::atomic::AtomicUsize>) -> Credentials {
+        use crate::auth::TokenRefresher;
+        use std::sync::atomic::Ordering;
+
+        Credentials::xoauth2("user@example.com", "stale").with_token_refresher(TokenRefresher::new(
+            move || {
+                refreshes.fetch_add(1, Ordering::SeqCst);
+                async { Ok("fresh".to_string()) }
+            },
+        ))
+    }
+
+    async fn oauth_client(server: &crate::mocks::MockSmtpServer, credentials: Credentials) -> SmtpClient {
+        let mut client = plain_client(server).await;
+        client.credential_provider = Some(Arc::new(StaticCredentialProvider::new(credentials)));
+        client
+    }
+
+    fn auth_commands(server: &crate::mocks::MockSmtpServer) -> Vec<String> {
+        use base64::Engine;
+
+        server
+            .commands()
+            .iter()
+            .filter_map(|c| c.strip_prefix("AUTH XOAUTH2 "))
+            .map(|r| {
+                let decoded = base64::engine::general_purpose::STANDARD.decode(r).unwrap();
+                String::from_utf8(decoded).unwrap()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_auth_failure_refreshes_token_and_retries_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let server = crate::mocks::MockSmtpServer::rejecting_auth(1).await;
+        let refreshes = Arc::new(AtomicUsize::new(0));
+        let client = oauth_client(&server, xoauth2_client_credentials(refreshes.clone())).await;
+
+        client.send(crate::mocks::test_email().unwrap()).await.unwrap();
+
+        assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            auth_commands(&server),
+            vec![
+                "user=user@example.com\x01auth=Bearer stale\x01\x01",
+                "user=user@example.com\x01auth=Bearer fresh\x01\x01",
+            ]
+        );
+        assert_eq!(server.messages().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_auth_failure_after_refresh_is_returned() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let server = crate::mocks::MockSmtpServer::rejecting_auth(usize::MAX).await;
+        let refreshes = Arc::new(AtomicUsize::new(0));
+        let client = oauth_client(&server, xoauth2_client_credentials(refreshes.clone())).await;
+
+        let err = client.send(crate::mocks::test_email().unwrap()).await.unwrap_err();
+
+        assert_eq!(err.smtp_code(), Some(codes::AUTH_FAILED));
+        assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+        assert_eq!(auth_commands(&server).len(), 2);
+        assert!(server.messages().is_empty());
+    }
+
+    fn email_to(recipients: &[&str]) -> Email {
+        Email::builder()
+            .from("sender@example.com")
+            .unwrap()
+            .to_many(recipients.iter().copied())
+            .unwrap()
+            .subject("Test Subject")
+            .text("Test body")
+            .build()
+            .unwrap()
+    }
+
+    async fn plain_client(server: &crate::mocks::MockSmtpServer) -> SmtpClient {
+        let config = server
+            .config()
+            .no_retry()
+            .pool(crate::config::PoolConfig {
+                min_idle: 0,
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+        SmtpClient::new(config).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_send_delivers_to_accepted_recipients() {
+        let server = crate::mocks::MockSmtpServer::rejecting_recipients(&["unknown@example.com"]).await;
+        let client = plain_client(&server).await;
+
+        let result = client
+            .send(email_to(&["a@example.com", "unknown@example.com", "b@example.com"]))
+            .await
+            .unwrap();
+
+        let accepted: Vec<&str> = result.accepted.iter().map(|a| a.email.as_str()).collect();
+        assert_eq!(accepted, vec!["a@example.com", "b@example.com"]);
+        assert_eq!(result.rejected.len(), 1);
+        assert_eq!(result.rejected[0].address.email, "unknown@example.com");
+        assert_eq!(result.rejected[0].code, 550);
+        assert!(result.rejected[0].message.contains("User unknown"));
+        assert!(!result.is_complete_success());
+        assert_eq!(server.messages().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_send_fails_when_all_recipients_rejected() {
+        let server = crate::mocks::MockSmtpServer::rejecting_recipients(&[
+            "unknown@example.com",
+            "gone@example.com",
+        ])
+        .await;
+        let client = plain_client(&server).await;
+
+        let err = client
+            .send(email_to(&["unknown@example.com", "gone@example.com"]))
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.kind(), SmtpErrorKind::InvalidRecipientAddress);
+        assert_eq!(err.smtp_code(), Some(550));
+        assert!(err.message().contains("gone@example.com"));
+        assert!(server.messages().is_empty());
+        assert!(server.commands().iter().any(|c| c == "RSET"));
+        assert!(!server.commands().iter().any(|c| c == "DATA"));
+
+        // The reset connection is still usable
+        client.send(email_to(&["a@example.com"])).await.unwrap();
+        assert_eq!(server.messages().len(), 1);
+        assert_eq!(server.connections(), 1);
+    }
+
+    fn mail_from_size(server: &crate::mocks::MockSmtpServer) -> Option<usize> {
+        server
+            .commands()
+            .iter()
+            .find(|c| c.starts_with("MAIL FROM:"))?
+            .split_whitespace()
+            .find_map(|p| p.strip_prefix("SIZE="))
+            .map(|n| n.parse().unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_send_declares_size_under_server_limit() {
+        let server = crate::mocks::MockSmtpServer::with_size_limit(64 * 1024).await;
+        let client = plain_client(&server).await;
+        let email = crate::mocks::test_email().unwrap();
+        let expected = client.encoder.encode(&email).unwrap().len();
+
+        client.send(email).await.unwrap();
+
+        assert_eq!(mail_from_size(&server), Some(expected));
+        assert_eq!(server.messages().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_validate_encodes_without_connecting() {
+        let server = crate::mocks::MockSmtpServer::start().await;
+        let client = plain_client(&server).await;
+        let email = email_to(&["a@example.com", "b@example.com"]);
+
+        let validated = client.validate(&email).unwrap();
+
+        let message = String::from_utf8(validated.bytes.clone()).unwrap();
+        assert_eq!(validated.size, validated.bytes.len());
+        assert!(message.contains("From: sender@example.com\r\n"));
+        assert!(message.contains("To: a@example.com, b@example.com\r\n"));
+        assert!(message.contains(&format!("Message-ID: <{}>", validated.message_id)));
+        assert!(message.contains("MIME-Version: 1.0\r\n"));
+        assert_eq!(validated.recipients.len(), 2);
+        assert_eq!(server.connections(), 0);
+        assert!(server.commands().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_validate_rejects_missing_from() {
+        let server = crate::mocks::MockSmtpServer::start().await;
+        let client = plain_client(&server).await;
+        let mut email = email_to(&["a@example.com"]);
+        email.from = Address { name: None, email: String::new() };
+
+        let err = client.validate(&email).unwrap_err();
+
+        assert_eq!(err.kind(), SmtpErrorKind::InvalidFromAddress);
+        assert_eq!(server.connections(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_validate_rejects_malformed_recipient() {
+        let server = crate::mocks::MockSmtpServer::start().await;
+        let client = plain_client(&server).await;
+        let mut email = email_to(&["a@example.com"]);
+        email.cc.push(Address { name: None, email: "not-an-address".to_string() });
+
+        let err = client.validate(&email).unwrap_err();
+
+        assert_eq!(err.kind(), SmtpErrorKind::InvalidRecipientAddress);
+        assert!(err.message().contains("not-an-address"));
+
+        email.cc.clear();
+        email.to.clear();
+        let err = client.validate(&email).unwrap_err();
+        assert_eq!(err.kind(), SmtpErrorKind::InvalidRecipientAddress);
+        assert_eq!(server.connections(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_send_rejects_message_over_server_limit() {
+        let server = crate::mocks::MockSmtpServer::with_size_limit(256).await;
+        let client = plain_client(&server).await;
+        let email = email_to(&["a@example.com"]);
+        let size = client.encoder.encode(&email).unwrap().len();
+        assert!(size > 256);
+
+        let err = client.send(email).await.unwrap_err();
+
+        assert_eq!(err.kind(), SmtpErrorKind::MessageTooLarge);
+        assert!(err.message().contains(&size.to_string()));
+        assert!(err.message().contains("256"));
+        assert!(!server.commands().iter().any(|c| c.starts_with("MAIL FROM:") || c == "DATA"));
+        assert!(server.messages().is_empty());
+    }
+
+    fn large_email() -> Email {
+        let text = "Line of a large message body.\r\n.leading dot line\r\n".repeat(3_000);
+        Email::builder()
+            .from("sender@example.com")
+            .unwrap()
+            .to("recipient@example.com")
+            .unwrap()
+            .subject("Large message")
+            .text(text)
+            .build()
+            .unwrap()
+    }
+
+    async fn chunking_client(server: &crate::mocks::MockSmtpServer) -> SmtpClient {
+        let config = server
+            .config()
+            .no_retry()
+            .chunking_threshold(1024)
+            .pool(crate::config::PoolConfig {
+                min_idle: 0,
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+        SmtpClient::new(config).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_send_large_message_with_bdat_when_chunking_offered() {
+        let server = crate::mocks::MockSmtpServer::with_chunking().await;
+        let client = chunking_client(&server).await;
+        let email = large_email();
+        let size = client.encoder.encode(&email).unwrap().len();
+        assert!(size > 2 * BDAT_CHUNK_SIZE);
+
+        client.send(email).await.unwrap();
+
+        let bdat: Vec<String> = server
+            .commands()
+            .into_iter()
+            .filter(|c| c.starts_with("BDAT"))
+            .collect();
+        let mut expected: Vec<String> = (0..size)
+            .step_by(BDAT_CHUNK_SIZE)
+            .map(|start| format!("BDAT {}", (size - start).min(BDAT_CHUNK_SIZE)))
+            .collect();
+        expected.push("BDAT 0 LAST".to_string());
+        assert_eq!(bdat, expected);
+        assert!(!server.commands().iter().any(|c| c == "DATA"));
+
+        // Length-prefixed chunks carry the message unmodified
+        let messages = server.messages();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].len(), size);
+        assert!(messages[0].contains("\r\n.leading dot line\r\n"));
+        assert!(!messages[0].contains("\r\n..leading"));
+    }
+
+    #[tokio::test]
+    async fn test_send_falls_back_to_data_without_chunking() {
+        let server = crate::mocks::MockSmtpServer::start().await;
+        let client = chunking_client(&server).await;
+
+        client.send(large_email()).await.unwrap();
+
+        assert!(server.commands().iter().any(|c| c == "DATA"));
+        assert!(!server.commands().iter().any(|c| c.starts_with("BDAT")));
+        assert!(server.messages()[0].contains("\r\n..leading dot line\r\n"));
+
+        // Messages under the threshold use DATA even when CHUNKING is offered
+        let server = crate::mocks::MockSmtpServer::with_chunking().await;
+        let client = chunking_client(&server).await;
+
+        client.send(email_to(&["a@example.com"])).await.unwrap();
+
+        assert!(server.commands().iter().any(|c| c == "DATA"));
+        assert!(!server.commands().iter().any(|c| c.starts_with("BDAT")));
+        assert_eq!(server.messages().len(), 1);
+    }
 }
@@ -4,6 +4,7 @@
 //! - Server connection settings
 //! - TLS/SSL configuration
 //! - Authentication credentials
+//! - SOCKS5 proxying
 //! - Connection pooling
 //! - Retry and circuit breaker policies
 //! - Rate limiting
@@ -28,6 +29,9 @@ pub const DEFAULT_COMMAND_TIMEOUT: Duration = Duration::from_secs(60);
 /// Default maximum message size (10 MB).
 pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 10 * 1024 * 1024;
 
+/// Default message size above which BDAT is used when CHUNKING is offered (1 MB).
+pub const DEFAULT_CHUNKING_THRESHOLD: usize = 1024 * 1024;
+
 /// TLS mode for SMTP connections.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -182,6 +186,85 @@ impl TlsConfigBuilder {
     }
 }
 
+/// SOCKS5 proxy configuration.
+///
+/// The SMTP host name is passed to the proxy unresolved, so DNS lookups
+/// happen on the proxy side.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProxyConfig {
+    /// Proxy hostname.
+    pub host: String,
+    /// Proxy port.
+    #[serde(default = "default_proxy_port")]
+    pub port: u16,
+    /// Proxy username.
+    pub username: Option<String>,
+    /// Proxy password (serialization skipped for security).
+    #[serde(skip)]
+    pub password: Option<SecretString>,
+}
+
+fn default_proxy_port() -> u16 { 1080 }
+
+impl ProxyConfig {
+    /// Creates a proxy configuration without authentication.
+    pub fn new(host: impl Into<String>, port: u16) -> Self {
+        Self {
+            host: host.into(),
+            port,
+            username: None,
+            password: None,
+        }
+    }
+
+    /// Sets username/password authentication for the proxy.
+    pub fn credentials(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self.password = Some(SecretString::new(password.into()));
+        self
+    }
+
+    /// Returns the proxy address.
+    pub fn address(&self) -> String {
+        format!("{}:{}", self.host, self.port)
+    }
+
+    /// Validates the proxy configuration.
+    pub fn validate(&self) -> SmtpResult<()> {
+        if self.host.is_empty() {
+            return Err(SmtpError::configuration("Proxy host is required"));
+        }
+
+        if self.port == 0 {
+            return Err(SmtpError::configuration("Proxy port must be non-zero"));
+        }
+
+        // RFC 1929 length-prefixes both fields with a single byte
+        match (&self.username, &self.password) {
+            (Some(username), Some(password)) => {
+                if username.is_empty() || username.len() > 255 {
+                    return Err(SmtpError::configuration(
+                        "Proxy username must be 1 to 255 bytes",
+                    ));
+                }
+                if password.expose_secret().is_empty() || password.expose_secret().len() > 255 {
+                    return Err(SmtpError::configuration(
+                        "Proxy password must be 1 to 255 bytes",
+                    ));
+                }
+            }
+            (None, None) => {}
+            _ => {
+                return Err(SmtpError::configuration(
+                    "Proxy username and password must be set together",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
 /// Connection pool configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PoolConfig {
@@ -363,6 +446,9 @@ pub struct SmtpConfig {
     /// TLS configuration.
     #[serde(default)]
     pub tls: TlsConfig,
+    /// SOCKS5 proxy for outbound connections.
+    #[serde(default)]
+    pub proxy: Option<ProxyConfig>,
     /// Authentication username.
     pub username: Option<String>,
     /// Authentication password (serialization skipped for security).
@@ -379,6 +465,9 @@ pub struct SmtpConfig {
     /// Maximum message size.
     #[serde(default = "default_max_message_size")]
     pub max_message_size: usize,
+    /// Messages larger than this are sent with BDAT when the server offers CHUNKING.
+    #[serde(default = "default_chunking_threshold")]
+    pub chunking_threshold: usize,
     /// Connection pool configuration.
     #[serde(default)]
     pub pool: PoolConfig,
@@ -399,6 +488,7 @@ fn default_port() -> u16 { DEFAULT_PORT }
 fn default_connect_timeout() -> Duration { DEFAULT_CONNECT_TIMEOUT }
 fn default_command_timeout() -> Duration { DEFAULT_COMMAND_TIMEOUT }
 fn default_max_message_size() -> usize { DEFAULT_MAX_MESSAGE_SIZE }
+fn default_chunking_threshold() -> usize { DEFAULT_CHUNKING_THRESHOLD }
 
 impl SmtpConfig {
     /// Creates a new configuration builder.
@@ -419,6 +509,10 @@ impl SmtpConfig {
         // Validate TLS config
         self.tls.validate()?;
 
+        if let Some(proxy) = &self.proxy {
+            proxy.validate()?;
+        }
+
         // Validate pool config
         if self.pool.max_connections == 0 {
             return Err(SmtpError::configuration("max_connections must be positive"));
@@ -455,12 +549,14 @@ pub struct SmtpConfigBuilder {
     host: Option<String>,
     port: u16,
     tls: TlsConfig,
+    proxy: Option<ProxyConfig>,
     username: Option<String>,
     password: Option<SecretString>,
     auth_method: Option<AuthMethod>,
     connect_timeout: Duration,
     command_timeout: Duration,
     max_message_size: usize,
+    chunking_threshold: usize,
     pool: PoolConfig,
     retry: RetryConfig,
     circuit_breaker: CircuitBreakerConfig,
@@ -524,6 +620,12 @@ impl SmtpConfigBuilder {
         self
     }
 
+    /// Routes connections through a SOCKS5 proxy.
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
     /// Sets connect timeout.
     pub fn connect_timeout(mut self, timeout: Duration) -> Self {
         self.connect_timeout = timeout;
@@ -542,6 +644,12 @@ impl SmtpConfigBuilder {
         self
     }
 
+    /// Sets the message size above which BDAT is used when the server offers CHUNKING.
+    pub fn chunking_threshold(mut self, size: usize) -> Self {
+        self.chunking_threshold = size;
+        self
+    }
+
     /// Sets pool configuration.
     pub fn pool(mut self, config: PoolConfig) -> Self {
         self.pool = config;
@@ -590,6 +698,7 @@ impl SmtpConfigBuilder {
             host: self.host.ok_or_else(|| SmtpError::configuration("Host is required"))?,
             port: if self.port == 0 { DEFAULT_PORT } else { self.port },
             tls: self.tls,
+            proxy: self.proxy,
             username: self.username,
             password: self.password,
             auth_method: self.auth_method,
@@ -608,6 +717,11 @@ impl SmtpConfigBuilder {
             } else {
                 self.max_message_size
             },
+            chunking_threshold: if self.chunking_threshold == 0 {
+                DEFAULT_CHUNKING_THRESHOLD
+            } else {
+                self.chunking_threshold
+            },
             pool: self.pool,
             retry: self.retry,
             circuit_breaker: self.circuit_breaker,
@@ -690,6 +804,30 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_proxy_config_validation() {
+        let config = SmtpConfig::builder()
+            .host("smtp.example.com")
+            .proxy(ProxyConfig::new("proxy.internal", 1080).credentials("user", "pass"))
+            .build()
+            .unwrap();
+        assert_eq!(config.proxy.unwrap().address(), "proxy.internal:1080");
+
+        let mut proxy = ProxyConfig::new("proxy.internal", 1080);
+        proxy.username = Some("user".to_string());
+        let result = SmtpConfig::builder()
+            .host("smtp.example.com")
+            .proxy(proxy)
+            .build();
+        assert!(result.is_err());
+
+        let result = SmtpConfig::builder()
+            .host("smtp.example.com")
+            .proxy(ProxyConfig::new("proxy.internal", 1080).credentials("user", "p".repeat(256)))
+            .build();
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_tls_config() {
         let tls = TlsConfig::builder()
@@ -402,6 +402,14 @@ impl SmtpError {
         Self::new(kind, message)
     }
 
+    /// Creates an error for a message larger than the server's SIZE limit.
+    pub fn message_too_large(size: usize, limit: usize) -> Self {
+        Self::new(
+            SmtpErrorKind::MessageTooLarge,
+            format!("Message size {} bytes exceeds server limit of {} bytes", size, limit),
+        )
+    }
+
     /// Creates a configuration error.
     pub fn configuration(message: impl Into<String>) -> Self {
         Self::new(SmtpErrorKind::ConfigurationInvalid, message)
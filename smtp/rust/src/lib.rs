@@ -75,16 +75,16 @@ pub mod mocks;
 // Re-exports for convenience
 pub use client::{SmtpClient, SmtpClientBuilder};
 pub use config::{
-    SmtpConfig, SmtpConfigBuilder, TlsConfig, TlsMode, TlsVersion,
+    SmtpConfig, SmtpConfigBuilder, TlsConfig, TlsMode, TlsVersion, ProxyConfig,
     PoolConfig, RetryConfig, CircuitBreakerConfig, RateLimitConfig, OnLimitBehavior,
 };
 pub use errors::{SmtpError, SmtpErrorKind, SmtpResult};
 pub use types::{
     Email, EmailBuilder, Address, Attachment, InlineImage,
-    SendResult, BatchSendResult, RejectedRecipient,
+    SendResult, BatchSendResult, RejectedRecipient, ValidatedMessage,
     ConnectionInfo, PoolStatus,
 };
-pub use auth::{AuthMethod, Credentials, CredentialProvider};
+pub use auth::{AuthMethod, Credentials, CredentialProvider, TokenRefresher};
 pub use protocol::{SmtpCommand, SmtpResponse, EsmtpCapabilities};
 pub use transport::SmtpTransport;
 pub use mime::{MimeEncoder, ContentType, TransferEncoding};
@@ -4,7 +4,10 @@
 
 use async_trait::async_trait;
 use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
 
 use crate::config::TlsConfig;
 use crate::errors::{SmtpError, SmtpResult};
@@ -233,6 +236,447 @@ impl crate::auth::CredentialProvider for MockCredentialProvider {
     }
 }
 
+/// Scripted SMTP server on a local TCP socket.
+///
+/// Answers every command with a success code, accepts message data, and
+/// can drop each connection after a number of messages to simulate a server
+/// that closes idle connections.
+#[derive(Debug)]
+pub struct MockSmtpServer {
+    /// Listening port.
+    port: u16,
+    /// Connections accepted.
+    connections: Arc<AtomicUsize>,
+    /// Commands received, in order.
+    commands: Arc<Mutex<Vec<String>>>,
+    /// Message bodies received.
+    messages: Arc<Mutex<Vec<String>>>,
+    /// First TLS records received after STARTTLS.
+    tls_hellos: Arc<Mutex<Vec<Vec<u8>>>>,
+    /// Accept loop.
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl MockSmtpServer {
+    /// Starts a server that keeps connections open.
+    pub async fn start() -> Self {
+        Self::start_with(MockServerBehavior::default()).await
+    }
+
+    /// Starts a server that closes each connection after `messages` messages.
+    pub async fn closing_after(messages: usize) -> Self {
+        Self::start_with(MockServerBehavior {
+            close_after: Some(messages),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Starts a server that rejects the first `attempts` AUTH commands with 535.
+    pub async fn rejecting_auth(attempts: usize) -> Self {
+        Self::start_with(MockServerBehavior {
+            auth_rejections: Arc::new(AtomicUsize::new(attempts)),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Starts a server that advertises `SIZE limit` in its EHLO reply.
+    pub async fn with_size_limit(limit: usize) -> Self {
+        Self::start_with(MockServerBehavior {
+            size_limit: limit,
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Starts a server that answers RCPT TO for `addresses` with 550.
+    pub async fn rejecting_recipients(addresses: &[&str]) -> Self {
+        Self::start_with(MockServerBehavior {
+            rejected_recipients: addresses.iter().map(|a| a.to_string()).collect(),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Starts a server that advertises CHUNKING and accepts BDAT.
+    pub async fn with_chunking() -> Self {
+        Self::start_with(MockServerBehavior {
+            chunking: true,
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Starts a server that advertises STARTTLS.
+    ///
+    /// After the 220 reply it records the client's first TLS record and
+    /// closes the connection, since it cannot complete a handshake.
+    pub async fn offering_starttls() -> Self {
+        Self::start_with(MockServerBehavior {
+            starttls: true,
+            ..Default::default()
+        })
+        .await
+    }
+
+    async fn start_with(behavior: MockServerBehavior) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind mock server");
+        let port = listener.local_addr().expect("local addr").port();
+        let connections = Arc::new(AtomicUsize::new(0));
+        let commands = Arc::new(Mutex::new(Vec::new()));
+        let messages = Arc::new(Mutex::new(Vec::new()));
+        let tls_hellos = Arc::new(Mutex::new(Vec::new()));
+
+        let task = {
+            let connections = connections.clone();
+            let commands = commands.clone();
+            let messages = messages.clone();
+            let tls_hellos = tls_hellos.clone();
+            tokio::spawn(async move {
+                while let Ok((socket, _)) = listener.accept().await {
+                    connections.fetch_add(1, Ordering::SeqCst);
+                    tokio::spawn(Self::serve(
+                        socket,
+                        behavior.clone(),
+                        commands.clone(),
+                        messages.clone(),
+                        tls_hellos.clone(),
+                    ));
+                }
+            })
+        };
+
+        Self { port, connections, commands, messages, tls_hellos, task }
+    }
+
+    async fn serve(
+        socket: TcpStream,
+        behavior: MockServerBehavior,
+        commands: Arc<Mutex<Vec<String>>>,
+        messages: Arc<Mutex<Vec<String>>>,
+        tls_hellos: Arc<Mutex<Vec<Vec<u8>>>>,
+    ) -> std::io::Result<()> {
+        let mut stream = BufReader::new(socket);
+        let mut delivered = 0;
+        let mut chunks: Vec<u8> = Vec::new();
+        stream.get_mut().write_all(b"220 mock.example.com ESMTP ready\r\n").await?;
+
+        loop {
+            let mut line = String::new();
+            if stream.read_line(&mut line).await? == 0 {
+                return Ok(());
+            }
+            let command = line.trim_end().to_string();
+            commands.lock().unwrap().push(command.clone());
+            let verb = command.split_whitespace().next().unwrap_or("").to_ascii_uppercase();
+
+            let reply: &[u8] = match verb.as_str() {
+                "EHLO" => {
+                    let reply = format!(
+                        "250-mock.example.com\r\n250-8BITMIME\r\n{}{}250-AUTH XOAUTH2 OAUTHBEARER\r\n250 SIZE {}\r\n",
+                        if behavior.starttls { "250-STARTTLS\r\n" } else { "" },
+                        if behavior.chunking { "250-CHUNKING\r\n" } else { "" },
+                        behavior.size_limit
+                    );
+                    stream.get_mut().write_all(reply.as_bytes()).await?;
+                    continue;
+                }
+                "STARTTLS" if behavior.starttls => {
+                    stream.get_mut().write_all(b"220 2.0.0 Ready to start TLS\r\n").await?;
+                    // A TLS record is a 5-byte header followed by its length
+                    let mut header = [0u8; 5];
+                    stream.read_exact(&mut header).await?;
+                    let len = u16::from_be_bytes([header[3], header[4]]) as usize;
+                    let mut record = header.to_vec();
+                    record.resize(5 + len, 0);
+                    stream.read_exact(&mut record[5..]).await?;
+                    tls_hellos.lock().unwrap().push(record);
+                    return Ok(());
+                }
+                "AUTH" => {
+                    let reject = behavior
+                        .auth_rejections
+                        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                        .is_ok();
+                    if !reject {
+                        stream.get_mut().write_all(b"235 2.7.0 Accepted\r\n").await?;
+                        continue;
+                    }
+                    // Mirror Gmail: an error challenge, then 535 once the client answers it
+                    stream.get_mut().write_all(b"334 eyJzdGF0dXMiOiI0MDEifQ==\r\n").await?;
+                    let mut answer = String::new();
+                    if stream.read_line(&mut answer).await? == 0 {
+                        return Ok(());
+                    }
+                    b"535 5.7.8 Username and Password not accepted\r\n"
+                }
+                "RCPT" => {
+                    let rejected = behavior
+                        .rejected_recipients
+                        .iter()
+                        .any(|address| command.contains(&format!("<{}>", address)));
+                    if rejected {
+                        b"550 5.1.1 User unknown\r\n"
+                    } else {
+                        b"250 OK\r\n"
+                    }
+                }
+                "DATA" => {
+                    stream.get_mut().write_all(b"354 End data with <CR><LF>.<CR><LF>\r\n").await?;
+                    let mut body = String::new();
+                    loop {
+                        let mut data_line = String::new();
+                        if stream.read_line(&mut data_line).await? == 0 {
+                            return Ok(());
+                        }
+                        if data_line == ".\r\n" {
+                            break;
+                        }
+                        body.push_str(&data_line);
+                    }
+                    messages.lock().unwrap().push(body);
+                    delivered += 1;
+                    stream.get_mut().write_all(b"250 2.0.0 OK queued\r\n").await?;
+                    if behavior.close_after == Some(delivered) {
+                        return Ok(());
+                    }
+                    continue;
+                }
+                "BDAT" if behavior.chunking => {
+                    let mut args = command.split_whitespace().skip(1);
+                    let size: usize = args.next().and_then(|s| s.parse().ok()).unwrap_or(0);
+                    let last = args.next().is_some_and(|a| a.eq_ignore_ascii_case("LAST"));
+
+                    let start = chunks.len();
+                    chunks.resize(start + size, 0);
+                    stream.read_exact(&mut chunks[start..]).await?;
+                    if !last {
+                        let reply = format!("250 2.0.0 {} octets received\r\n", size);
+                        stream.get_mut().write_all(reply.as_bytes()).await?;
+                        continue;
+                    }
+
+                    messages
+                        .lock()
+                        .unwrap()
+                        .push(String::from_utf8_lossy(&std::mem::take(&mut chunks)).into_owned());
+                    delivered += 1;
+                    stream.get_mut().write_all(b"250 2.0.0 OK queued\r\n").await?;
+                    if behavior.close_after == Some(delivered) {
+                        return Ok(());
+                    }
+                    continue;
+                }
+                "QUIT" => {
+                    stream.get_mut().write_all(b"221 Bye\r\n").await?;
+                    return Ok(());
+                }
+                _ => b"250 OK\r\n",
+            };
+            stream.get_mut().write_all(reply).await?;
+        }
+    }
+
+    /// Returns the listening port.
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Returns a configuration builder pointing at this server without TLS.
+    pub fn config(&self) -> crate::config::SmtpConfigBuilder {
+        crate::config::SmtpConfig::builder()
+            .host("127.0.0.1")
+            .port(self.port)
+            .no_tls()
+    }
+
+    /// Returns the number of connections accepted.
+    pub fn connections(&self) -> usize {
+        self.connections.load(Ordering::SeqCst)
+    }
+
+    /// Returns the commands received.
+    pub fn commands(&self) -> Vec<String> {
+        self.commands.lock().unwrap().clone()
+    }
+
+    /// Returns the message bodies received.
+    pub fn messages(&self) -> Vec<String> {
+        self.messages.lock().unwrap().clone()
+    }
+
+    /// Returns the first TLS record of each attempted handshake.
+    pub fn tls_hellos(&self) -> Vec<Vec<u8>> {
+        self.tls_hellos.lock().unwrap().clone()
+    }
+}
+
+/// How a [`MockSmtpServer`] deviates from accepting everything.
+#[derive(Debug, Clone)]
+struct MockServerBehavior {
+    /// Close each connection after this many messages.
+    close_after: Option<usize>,
+    /// AUTH attempts still to be rejected, shared across connections.
+    auth_rejections: Arc<AtomicUsize>,
+    /// Recipients answered with 550.
+    rejected_recipients: Vec<String>,
+    /// Advertised SIZE limit.
+    size_limit: usize,
+    /// Advertise and accept STARTTLS.
+    starttls: bool,
+    /// Advertise CHUNKING and accept BDAT.
+    chunking: bool,
+}
+
+impl Default for MockServerBehavior {
+    fn default() -> Self {
+        Self {
+            close_after: None,
+            auth_rejections: Arc::new(AtomicUsize::new(0)),
+            rejected_recipients: Vec::new(),
+            size_limit: 10_485_760,
+            starttls: false,
+            chunking: false,
+        }
+    }
+}
+
+impl Drop for MockSmtpServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// SOCKS5 proxy on a local TCP socket.
+///
+/// Requires username/password auth when credentials are given, records
+/// each CONNECT target, and tunnels every connection to a fixed upstream
+/// port regardless of the requested target.
+#[derive(Debug)]
+pub struct MockSocks5Proxy {
+    /// Listening port.
+    port: u16,
+    /// CONNECT targets received, as `host:port`.
+    targets: Arc<Mutex<Vec<String>>>,
+    /// Accept loop.
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl MockSocks5Proxy {
+    /// Starts a proxy that tunnels to `127.0.0.1:upstream_port`.
+    pub async fn start(upstream_port: u16, credentials: Option<(&str, &str)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind mock proxy");
+        let port = listener.local_addr().expect("local addr").port();
+        let targets = Arc::new(Mutex::new(Vec::new()));
+        let credentials = credentials.map(|(u, p)| (u.to_string(), p.to_string()));
+
+        let task = {
+            let targets = targets.clone();
+            tokio::spawn(async move {
+                while let Ok((socket, _)) = listener.accept().await {
+                    tokio::spawn(Self::serve(
+                        socket,
+                        upstream_port,
+                        credentials.clone(),
+                        targets.clone(),
+                    ));
+                }
+            })
+        };
+
+        Self { port, targets, task }
+    }
+
+    async fn serve(
+        mut socket: TcpStream,
+        upstream_port: u16,
+        credentials: Option<(String, String)>,
+        targets: Arc<Mutex<Vec<String>>>,
+    ) -> std::io::Result<()> {
+        let mut greeting = [0u8; 2];
+        socket.read_exact(&mut greeting).await?;
+        let mut methods = vec![0u8; greeting[1] as usize];
+        socket.read_exact(&mut methods).await?;
+
+        let wanted = if credentials.is_some() { 0x02 } else { 0x00 };
+        if !methods.contains(&wanted) {
+            return socket.write_all(&[0x05, 0xFF]).await;
+        }
+        socket.write_all(&[0x05, wanted]).await?;
+
+        if let Some((username, password)) = credentials {
+            let mut header = [0u8; 2];
+            socket.read_exact(&mut header).await?;
+            let mut user = vec![0u8; header[1] as usize];
+            socket.read_exact(&mut user).await?;
+            let mut len = [0u8; 1];
+            socket.read_exact(&mut len).await?;
+            let mut pass = vec![0u8; len[0] as usize];
+            socket.read_exact(&mut pass).await?;
+
+            let accepted = user == username.as_bytes() && pass == password.as_bytes();
+            socket.write_all(&[0x01, if accepted { 0x00 } else { 0x01 }]).await?;
+            if !accepted {
+                return Ok(());
+            }
+        }
+
+        let mut request = [0u8; 4];
+        socket.read_exact(&mut request).await?;
+        let host = match request[3] {
+            0x01 => {
+                let mut ip = [0u8; 4];
+                socket.read_exact(&mut ip).await?;
+                std::net::Ipv4Addr::from(ip).to_string()
+            }
+            0x03 => {
+                let mut len = [0u8; 1];
+                socket.read_exact(&mut len).await?;
+                let mut name = vec![0u8; len[0] as usize];
+                socket.read_exact(&mut name).await?;
+                String::from_utf8_lossy(&name).into_owned()
+            }
+            _ => {
+                let mut ip = [0u8; 16];
+                socket.read_exact(&mut ip).await?;
+                std::net::Ipv6Addr::from(ip).to_string()
+            }
+        };
+        let mut port = [0u8; 2];
+        socket.read_exact(&mut port).await?;
+        targets
+            .lock()
+            .unwrap()
+            .push(format!("{}:{}", host, u16::from_be_bytes(port)));
+
+        let mut upstream = match TcpStream::connect(("127.0.0.1", upstream_port)).await {
+            Ok(upstream) => upstream,
+            Err(_) => return socket.write_all(&[0x05, 0x05, 0x00, 0x01, 0, 0, 0, 0, 0, 0]).await,
+        };
+        socket.write_all(&[0x05, 0x00, 0x00, 0x01, 127, 0, 0, 1, 0, 0]).await?;
+
+        tokio::io::copy_bidirectional(&mut socket, &mut upstream).await?;
+        Ok(())
+    }
+
+    /// Returns the listening port.
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Returns the CONNECT targets received, as `host:port`.
+    pub fn targets(&self) -> Vec<String> {
+        self.targets.lock().unwrap().clone()
+    }
+}
+
+impl Drop for MockSocks5Proxy {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
 /// Creates a test email.
 pub fn test_email() -> SmtpResult<Email> {
     Email::builder()
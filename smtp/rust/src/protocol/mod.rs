@@ -44,6 +44,13 @@ pub enum SmtpCommand {
     },
     /// DATA command.
     Data,
+    /// BDAT command (RFC 3030), announcing a chunk of `size` octets.
+    Bdat {
+        /// Chunk size in octets.
+        size: usize,
+        /// Whether this is the final chunk.
+        last: bool,
+    },
     /// Reset transaction.
     Rset,
     /// No operation (keepalive).
@@ -93,6 +100,13 @@ impl SmtpCommand {
             }
             SmtpCommand::RcptTo { address } => format!("RCPT TO:{}", address),
             SmtpCommand::Data => "DATA".to_string(),
+            SmtpCommand::Bdat { size, last } => {
+                if *last {
+                    format!("BDAT {} LAST", size)
+                } else {
+                    format!("BDAT {}", size)
+                }
+            }
             SmtpCommand::Rset => "RSET".to_string(),
             SmtpCommand::Noop => "NOOP".to_string(),
             SmtpCommand::Quit => "QUIT".to_string(),
@@ -253,7 +267,7 @@ impl fmt::Display for SmtpResponse {
 /// ESMTP server capabilities.
 #[derive(Debug, Clone, Default)]
 pub struct EsmtpCapabilities {
-    /// Maximum message size.
+    /// Maximum message size; `Some(0)` if SIZE is advertised without a limit.
     pub size: Option<usize>,
     /// Supported authentication mechanisms.
     pub auth_mechanisms: HashSet<AuthMethod>,
@@ -290,7 +304,7 @@ impl EsmtpCapabilities {
 
             match capability {
                 "SIZE" => {
-                    caps.size = params.parse().ok();
+                    caps.size = Some(params.parse().unwrap_or(0));
                 }
                 "AUTH" => {
                     for mech in params.split_whitespace() {
@@ -327,6 +341,11 @@ impl EsmtpCapabilities {
         caps
     }
 
+    /// Returns the advertised message size limit, if the server declared one.
+    pub fn size_limit(&self) -> Option<usize> {
+        self.size.filter(|&limit| limit > 0)
+    }
+
     /// Returns true if authentication is available.
     pub fn has_auth(&self) -> bool {
         !self.auth_mechanisms.is_empty()
@@ -477,6 +496,14 @@ mod tests {
             .to_smtp_string(),
             "MAIL FROM:<test@example.com> SIZE=1024 BODY=8BITMIME"
         );
+        assert_eq!(
+            SmtpCommand::Bdat { size: 4096, last: false }.to_smtp_string(),
+            "BDAT 4096"
+        );
+        assert_eq!(
+            SmtpCommand::Bdat { size: 0, last: true }.to_smtp_string(),
+            "BDAT 0 LAST"
+        );
     }
 
     #[test]
@@ -523,6 +550,7 @@ mod tests {
                 "STARTTLS".to_string(),
                 "8BITMIME".to_string(),
                 "PIPELINING".to_string(),
+                "CHUNKING".to_string(),
             ],
             is_multiline: true,
         };
@@ -535,6 +563,25 @@ mod tests {
         assert!(caps.starttls);
         assert!(caps.eight_bit_mime);
         assert!(caps.pipelining);
+        assert!(caps.chunking);
+    }
+
+    #[test]
+    fn test_capabilities_size_without_limit() {
+        let response = SmtpResponse {
+            code: 250,
+            enhanced_code: None,
+            message: vec!["smtp.example.com".to_string(), "SIZE".to_string()],
+            is_multiline: true,
+        };
+
+        let caps = EsmtpCapabilities::from_ehlo_response(&response);
+        assert_eq!(caps.size, Some(0));
+        assert_eq!(caps.size_limit(), None);
+
+        let caps = EsmtpCapabilities::default();
+        assert_eq!(caps.size, None);
+        assert_eq!(caps.size_limit(), None);
     }
 
     #[test]
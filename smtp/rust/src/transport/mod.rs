@@ -1,7 +1,7 @@
 //! Transport layer for SMTP connections.
 //!
 //! Provides abstractions for TCP connections with optional TLS,
-//! connection pooling, and health checks.
+//! SOCKS5 proxying, connection pooling, and health checks.
 
 use async_trait::async_trait;
 use std::fmt;
@@ -76,6 +76,8 @@ enum TransportStream {
     Tls(BufReader<tokio_rustls::client::TlsStream<TcpStream>>),
     #[cfg(feature = "native-tls")]
     NativeTls(BufReader<tokio_native_tls::TlsStream<TcpStream>>),
+    /// Placeholder while the TCP stream is handed to the TLS handshake.
+    Detached,
 }
 
 impl fmt::Debug for TcpTransport {
@@ -89,15 +91,23 @@ impl fmt::Debug for TcpTransport {
 }
 
 impl TcpTransport {
-    /// Connects to an SMTP server.
+    /// Connects to an SMTP server, through the configured proxy if any.
     pub async fn connect(config: &SmtpConfig) -> SmtpResult<Self> {
         let address = config.address();
 
         // Connect with timeout
-        let stream = timeout(config.connect_timeout, TcpStream::connect(&address))
+        let stream = match &config.proxy {
+            Some(proxy) => timeout(
+                config.connect_timeout,
+                socks5::connect(proxy, &config.host, config.port),
+            )
             .await
-            .map_err(|_| SmtpError::timeout(SmtpErrorKind::ConnectTimeout, "Connect timed out"))?
-            .map_err(|e| Self::map_io_error(e, &address))?;
+            .map_err(|_| SmtpError::timeout(SmtpErrorKind::ConnectTimeout, "Proxy connect timed out"))??,
+            None => timeout(config.connect_timeout, TcpStream::connect(&address))
+                .await
+                .map_err(|_| SmtpError::timeout(SmtpErrorKind::ConnectTimeout, "Connect timed out"))?
+                .map_err(|e| Self::map_io_error(e, &address))?,
+        };
 
         // Set TCP options
         stream.set_nodelay(true).ok();
@@ -119,7 +129,8 @@ impl TcpTransport {
 
         transport.state = TransactionState::Connected;
 
-        // Handle implicit TLS
+        // Handle implicit TLS. The handshake is always against the SMTP host,
+        // so a proxy only ever sees encrypted traffic after this point.
         if matches!(config.tls.mode, TlsMode::Implicit) {
             transport.upgrade_tls(&config.tls, &config.host).await?;
         }
@@ -143,6 +154,22 @@ impl TcpTransport {
         }
     }
 
+    /// Takes the plain TCP stream out for a TLS handshake.
+    fn take_plain_stream(&mut self) -> SmtpResult<TcpStream> {
+        match std::mem::replace(&mut self.stream, TransportStream::Detached) {
+            TransportStream::Plain(reader) => Ok(reader.into_inner()),
+            other => {
+                self.stream = other;
+                Err(SmtpError::tls("Already using TLS"))
+            }
+        }
+    }
+
+    /// Error for I/O on a connection whose TLS upgrade failed.
+    fn detached_error() -> SmtpError {
+        SmtpError::new(SmtpErrorKind::ConnectionReset, "Connection lost during TLS upgrade")
+    }
+
     /// Reads lines until we have a complete response.
     async fn read_response_inner<R: AsyncBufReadExt + Unpin>(
         reader: &mut R,
@@ -219,6 +246,7 @@ impl SmtpTransport for TcpTransport {
             TransportStream::NativeTls(ref mut stream) => {
                 Self::write_all(stream.get_mut(), cmd_str.as_bytes(), self.command_timeout).await?;
             }
+            TransportStream::Detached => return Err(Self::detached_error()),
         }
 
         self.read_response().await
@@ -237,6 +265,7 @@ impl SmtpTransport for TcpTransport {
             TransportStream::NativeTls(ref mut stream) => {
                 Self::write_all(stream.get_mut(), data, self.command_timeout).await?;
             }
+            TransportStream::Detached => return Err(Self::detached_error()),
         }
         Ok(())
     }
@@ -254,6 +283,7 @@ impl SmtpTransport for TcpTransport {
             TransportStream::NativeTls(ref mut stream) => {
                 Self::read_response_inner(stream, self.command_timeout).await?
             }
+            TransportStream::Detached => return Err(Self::detached_error()),
         };
 
         #[cfg(feature = "tracing")]
@@ -284,16 +314,9 @@ impl SmtpTransport for TcpTransport {
             let server_name = ServerName::try_from(host.to_string())
                 .map_err(|_| SmtpError::tls(format!("Invalid server name: {}", host)))?;
 
-            // Extract TCP stream
-            let tcp_stream = match std::mem::replace(
-                &mut self.stream,
-                TransportStream::Plain(BufReader::new(unsafe {
-                    std::mem::zeroed()
-                })),
-            ) {
-                TransportStream::Plain(reader) => reader.into_inner(),
-                _ => return Err(SmtpError::tls("Already using TLS")),
-            };
+            // Extract TCP stream; through a proxy this is the tunnel to the
+            // SMTP host, and the handshake still verifies the SMTP host's name
+            let tcp_stream = self.take_plain_stream()?;
 
             // Perform TLS handshake
             let tls_stream = timeout(
@@ -327,16 +350,9 @@ impl SmtpTransport for TcpTransport {
 
             let connector = tokio_native_tls::TlsConnector::from(connector);
 
-            // Extract TCP stream
-            let tcp_stream = match std::mem::replace(
-                &mut self.stream,
-                TransportStream::Plain(BufReader::new(unsafe {
-                    std::mem::zeroed()
-                })),
-            ) {
-                TransportStream::Plain(reader) => reader.into_inner(),
-                _ => return Err(SmtpError::tls("Already using TLS")),
-            };
+            // Extract TCP stream; through a proxy this is the tunnel to the
+            // SMTP host, and the handshake still verifies the SMTP host's name
+            let tcp_stream = self.take_plain_stream()?;
 
             let tls_stream = timeout(Duration::from_secs(30), connector.connect(host, tcp_stream))
                 .await
@@ -397,17 +413,190 @@ impl SmtpTransport for TcpTransport {
     }
 }
 
+/// Minimal SOCKS5 client (RFC 1928) with username/password auth (RFC 1929).
+mod socks5 {
+    use super::*;
+    use crate::config::ProxyConfig;
+    use secrecy::ExposeSecret;
+    use std::net::IpAddr;
+    use tokio::io::AsyncReadExt;
+
+    const VERSION: u8 = 0x05;
+    const METHOD_NONE: u8 = 0x00;
+    const METHOD_USERNAME_PASSWORD: u8 = 0x02;
+    const METHOD_UNACCEPTABLE: u8 = 0xFF;
+    const AUTH_VERSION: u8 = 0x01;
+    const CMD_CONNECT: u8 = 0x01;
+    const ATYP_IPV4: u8 = 0x01;
+    const ATYP_DOMAIN: u8 = 0x03;
+    const ATYP_IPV6: u8 = 0x04;
+
+    /// Dials the proxy and has it CONNECT to `host:port`.
+    ///
+    /// Domain names are sent unresolved so the proxy does the DNS lookup.
+    pub(super) async fn connect(proxy: &ProxyConfig, host: &str, port: u16) -> SmtpResult<TcpStream> {
+        let address = proxy.address();
+        let mut stream = TcpStream::connect(&address)
+            .await
+            .map_err(|e| TcpTransport::map_io_error(e, &address))?;
+
+        negotiate_auth(&mut stream, proxy).await?;
+        request_connect(&mut stream, host, port).await?;
+
+        Ok(stream)
+    }
+
+    async fn negotiate_auth<S>(stream: &mut S, proxy: &ProxyConfig) -> SmtpResult<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        let credentials = proxy.username.as_deref().zip(proxy.password.as_ref());
+        let method = if credentials.is_some() { METHOD_USERNAME_PASSWORD } else { METHOD_NONE };
+        stream.write_all(&[VERSION, 1, method]).await.map_err(io_error)?;
+
+        let mut reply = [0u8; 2];
+        stream.read_exact(&mut reply).await.map_err(io_error)?;
+        check_version(reply[0])?;
+
+        match (reply[1], credentials) {
+            (METHOD_NONE, None) => Ok(()),
+            (METHOD_USERNAME_PASSWORD, Some((username, password))) => {
+                let password = password.expose_secret();
+                let mut request = Vec::with_capacity(3 + username.len() + password.len());
+                request.push(AUTH_VERSION);
+                request.push(username.len() as u8);
+                request.extend_from_slice(username.as_bytes());
+                request.push(password.len() as u8);
+                request.extend_from_slice(password.as_bytes());
+                stream.write_all(&request).await.map_err(io_error)?;
+
+                let mut status = [0u8; 2];
+                stream.read_exact(&mut status).await.map_err(io_error)?;
+                if status[1] != 0x00 {
+                    return Err(SmtpError::new(
+                        SmtpErrorKind::CredentialsInvalid,
+                        "SOCKS5 proxy rejected the username/password",
+                    ));
+                }
+                Ok(())
+            }
+            (METHOD_UNACCEPTABLE, _) => Err(SmtpError::new(
+                SmtpErrorKind::AuthMethodNotSupported,
+                "SOCKS5 proxy accepted none of the offered authentication methods",
+            )),
+            (other, _) => Err(SmtpError::protocol(format!(
+                "SOCKS5 proxy selected an unrequested authentication method {:#04x}",
+                other
+            ))),
+        }
+    }
+
+    async fn request_connect<S>(stream: &mut S, host: &str, port: u16) -> SmtpResult<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        let mut request = vec![VERSION, CMD_CONNECT, 0x00];
+        match host.parse::<IpAddr>() {
+            Ok(IpAddr::V4(ip)) => {
+                request.push(ATYP_IPV4);
+                request.extend_from_slice(&ip.octets());
+            }
+            Ok(IpAddr::V6(ip)) => {
+                request.push(ATYP_IPV6);
+                request.extend_from_slice(&ip.octets());
+            }
+            Err(_) => {
+                let len = u8::try_from(host.len()).map_err(|_| {
+                    SmtpError::configuration("SMTP host name is too long for a SOCKS5 request")
+                })?;
+                request.push(ATYP_DOMAIN);
+                request.push(len);
+                request.extend_from_slice(host.as_bytes());
+            }
+        }
+        request.extend_from_slice(&port.to_be_bytes());
+        stream.write_all(&request).await.map_err(io_error)?;
+
+        let mut head = [0u8; 4];
+        stream.read_exact(&mut head).await.map_err(io_error)?;
+        check_version(head[0])?;
+        if head[1] != 0x00 {
+            return Err(reply_error(head[1], host, port));
+        }
+
+        // Skip the bound address and port
+        let addr_len = match head[3] {
+            ATYP_IPV4 => 4,
+            ATYP_IPV6 => 16,
+            ATYP_DOMAIN => {
+                let mut len = [0u8; 1];
+                stream.read_exact(&mut len).await.map_err(io_error)?;
+                len[0] as usize
+            }
+            other => {
+                return Err(SmtpError::protocol(format!(
+                    "SOCKS5 proxy replied with unknown address type {:#04x}",
+                    other
+                )))
+            }
+        };
+        let mut bound = vec![0u8; addr_len + 2];
+        stream.read_exact(&mut bound).await.map_err(io_error)?;
+
+        Ok(())
+    }
+
+    fn check_version(version: u8) -> SmtpResult<()> {
+        if version != VERSION {
+            return Err(SmtpError::protocol(format!(
+                "Proxy replied with SOCKS version {}, expected 5",
+                version
+            )));
+        }
+        Ok(())
+    }
+
+    fn reply_error(code: u8, host: &str, port: u16) -> SmtpError {
+        let (kind, reason) = match code {
+            0x02 => (SmtpErrorKind::ConnectionRefused, "connection not allowed by ruleset"),
+            0x03 => (SmtpErrorKind::NetworkUnreachable, "network unreachable"),
+            0x04 => (SmtpErrorKind::NetworkUnreachable, "host unreachable"),
+            0x05 => (SmtpErrorKind::ConnectionRefused, "connection refused"),
+            0x06 => (SmtpErrorKind::ConnectionTimeout, "TTL expired"),
+            _ => (SmtpErrorKind::ConnectionRefused, "general failure"),
+        };
+        SmtpError::new(
+            kind,
+            format!("SOCKS5 proxy could not connect to {}:{}: {}", host, port, reason),
+        )
+    }
+
+    fn io_error(error: io::Error) -> SmtpError {
+        SmtpError::connection(format!("SOCKS5 proxy error: {}", error))
+    }
+}
+
 /// Connection pool manager.
 pub mod pool {
     use super::*;
-    use deadpool::managed::{Manager, Metrics, Object, Pool, PoolConfig, RecycleResult};
+    use crate::config::PoolConfig;
+    use deadpool::managed::{
+        Manager, Metrics, Object, Pool, PoolConfig as DeadpoolConfig, RecycleError, RecycleResult,
+        Timeouts,
+    };
     use std::sync::atomic::{AtomicUsize, Ordering};
 
     /// Manager for SMTP connections.
+    ///
+    /// Pooled connections are checked before being handed out: connections
+    /// past `idle_timeout` or `max_lifetime`, closed connections, and (when
+    /// health checks are enabled) connections that fail a `NOOP` are
+    /// discarded and a fresh connection is opened in their place.
     #[derive(Debug)]
     pub struct SmtpConnectionManager {
         config: Arc<SmtpConfig>,
         created: AtomicUsize,
+        discarded: AtomicUsize,
     }
 
     impl SmtpConnectionManager {
@@ -416,8 +605,46 @@ pub mod pool {
             Self {
                 config: Arc::new(config),
                 created: AtomicUsize::new(0),
+                discarded: AtomicUsize::new(0),
             }
         }
+
+        /// Returns the number of connections opened.
+        pub fn created(&self) -> usize {
+            self.created.load(Ordering::SeqCst)
+        }
+
+        /// Returns the number of pooled connections discarded as stale.
+        pub fn discarded(&self) -> usize {
+            self.discarded.load(Ordering::SeqCst)
+        }
+
+        fn pool_config(&self) -> &PoolConfig {
+            &self.config.pool
+        }
+
+        /// Checks age and idle time without touching the connection.
+        fn check_expiry(&self, conn: &TcpTransport, metrics: &Metrics) -> Result<(), &'static str> {
+            let pool = self.pool_config();
+            if conn.state() == TransactionState::Closed {
+                Err("connection closed")
+            } else if metrics.age() > pool.max_lifetime {
+                Err("max lifetime exceeded")
+            } else if metrics.last_used() > pool.idle_timeout {
+                Err("idle timeout exceeded")
+            } else {
+                Ok(())
+            }
+        }
+
+        fn discard(&self, reason: String) -> RecycleError<SmtpError> {
+            self.discarded.fetch_add(1, Ordering::SeqCst);
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(reason = %reason, "Discarding pooled SMTP connection");
+
+            RecycleError::Message(reason)
+        }
     }
 
     #[async_trait]
@@ -430,11 +657,15 @@ pub mod pool {
             TcpTransport::connect(&self.config).await
         }
 
-        async fn recycle(&self, conn: &mut Self::Type, _: &Metrics) -> RecycleResult<Self::Error> {
-            // Health check
-            conn.health_check().await.map_err(|e| {
-                deadpool::managed::RecycleError::Backend(e)
-            })?;
+        async fn recycle(&self, conn: &mut Self::Type, metrics: &Metrics) -> RecycleResult<Self::Error> {
+            self.check_expiry(conn, metrics)
+                .map_err(|reason| self.discard(reason.to_string()))?;
+
+            if self.pool_config().health_check_enabled {
+                conn.health_check()
+                    .await
+                    .map_err(|e| self.discard(format!("health check failed: {}", e)))?;
+            }
             Ok(())
         }
     }
@@ -442,9 +673,12 @@ pub mod pool {
     /// Type alias for connection pool.
     pub type SmtpPool = Pool<SmtpConnectionManager>;
 
+    /// Type alias for a connection checked out of the pool.
+    pub type PooledConnection = Object<SmtpConnectionManager>;
+
     /// Creates a connection pool.
     pub fn create_pool(config: SmtpConfig) -> SmtpResult<SmtpPool> {
-        let pool_config = PoolConfig {
+        let pool_config = DeadpoolConfig {
             max_size: config.pool.max_connections,
             ..Default::default()
         };
@@ -457,11 +691,58 @@ pub mod pool {
 
         Ok(pool)
     }
+
+    /// Runs one maintenance pass over the pool.
+    ///
+    /// Drops idle connections past their idle timeout or lifetime, then
+    /// checks out up to `min_idle` connections without waiting, which
+    /// health-checks the idle ones and opens new ones to make up the
+    /// difference. Connections in use are left alone.
+    pub async fn maintain(pool: &SmtpPool) {
+        let manager = pool.manager();
+        pool.retain(|conn, metrics| manager.check_expiry(conn, &metrics).is_ok());
+
+        let min_idle = manager.pool_config().min_idle;
+        let no_wait = Timeouts {
+            wait: Some(Duration::ZERO),
+            ..pool.timeouts()
+        };
+
+        let mut warm = Vec::with_capacity(min_idle);
+        for _ in 0..min_idle {
+            match pool.timeout_get(&no_wait).await {
+                Ok(conn) => warm.push(conn),
+                Err(_e) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(error = %_e, "Pool maintenance could not warm connection");
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Spawns a task that calls [`maintain`] every `health_check_interval`.
+    ///
+    /// The task stops when the pool is closed.
+    pub fn spawn_maintenance(pool: SmtpPool) -> tokio::task::JoinHandle<()> {
+        let interval = pool.manager().pool_config().health_check_interval;
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            while !pool.is_closed() {
+                ticker.tick().await;
+                maintain(&pool).await;
+            }
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use super::pool::{create_pool, maintain};
+    use crate::config::{PoolConfig, ProxyConfig};
+    use crate::mocks::{MockSmtpServer, MockSocks5Proxy};
 
     #[test]
     fn test_transport_debug() {
@@ -469,4 +750,119 @@ mod tests {
         let state = TransactionState::Connected;
         assert!(format!("{:?}", state).contains("Connected"));
     }
+
+    #[tokio::test]
+    async fn test_pool_replaces_connection_closed_by_server() {
+        let server = MockSmtpServer::closing_after(1).await;
+        let pool = create_pool(server.config().build().unwrap()).unwrap();
+
+        {
+            let mut conn = pool.get().await.unwrap();
+            conn.send_command(&SmtpCommand::Data).await.unwrap();
+            conn.send_data(b"Subject: hi\r\n\r\nbody\r\n.\r\n").await.unwrap();
+            assert!(conn.read_response().await.unwrap().is_success());
+        }
+
+        // The server has hung up; the NOOP check discards the pooled connection.
+        let mut conn = pool.get().await.unwrap();
+        assert!(conn.health_check().await.is_ok());
+        assert_eq!(pool.manager().created(), 2);
+        assert_eq!(pool.manager().discarded(), 1);
+        assert_eq!(server.connections(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_pool_discards_idle_connections() {
+        let server = MockSmtpServer::start().await;
+        let config = server
+            .config()
+            .pool(PoolConfig {
+                idle_timeout: Duration::ZERO,
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+        let pool = create_pool(config).unwrap();
+
+        drop(pool.get().await.unwrap());
+        drop(pool.get().await.unwrap());
+
+        assert_eq!(pool.manager().created(), 2);
+        assert_eq!(pool.manager().discarded(), 1);
+        assert!(!server.commands().iter().any(|c| c == "NOOP"));
+    }
+
+    #[tokio::test]
+    async fn test_maintain_keeps_min_idle_warm() {
+        let server = MockSmtpServer::start().await;
+        let config = server
+            .config()
+            .pool(PoolConfig {
+                min_idle: 2,
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+        let pool = create_pool(config).unwrap();
+
+        maintain(&pool).await;
+        let status = pool.status();
+        assert_eq!(status.size, 2);
+        assert_eq!(status.available, 2);
+
+        // A second pass health-checks the warm connections instead of opening more.
+        maintain(&pool).await;
+        assert_eq!(pool.manager().created(), 2);
+        assert_eq!(server.commands().iter().filter(|c| *c == "NOOP").count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_connect_through_socks5_proxy_then_starttls() {
+        let server = MockSmtpServer::offering_starttls().await;
+        let proxy = MockSocks5Proxy::start(server.port(), Some(("relay", "s3cret"))).await;
+        let config = SmtpConfig::builder()
+            .host("smtp.example.com")
+            .port(587)
+            .tls_mode(TlsMode::StartTlsRequired)
+            .proxy(ProxyConfig::new("127.0.0.1", proxy.port()).credentials("relay", "s3cret"))
+            .build()
+            .unwrap();
+
+        // The host name only resolves on the proxy side
+        let mut transport = TcpTransport::connect(&config).await.unwrap();
+        assert_eq!(proxy.targets(), vec!["smtp.example.com:587"]);
+
+        let ehlo = transport
+            .send_command(&SmtpCommand::Ehlo("localhost".to_string()))
+            .await
+            .unwrap();
+        assert!(EsmtpCapabilities::from_ehlo_response(&ehlo).starttls);
+        let response = transport.send_command(&SmtpCommand::StartTls).await.unwrap();
+        assert_eq!(response.code, 220);
+
+        // The mock cannot finish the handshake, but the ClientHello it saw
+        // must name the SMTP host rather than the proxy
+        assert!(transport.upgrade_tls(&config.tls, &config.host).await.is_err());
+        let hellos = server.tls_hellos();
+        assert_eq!(hellos.len(), 1);
+        assert_eq!(hellos[0][0], 0x16);
+        assert!(hellos[0]
+            .windows(b"smtp.example.com".len())
+            .any(|w| w == b"smtp.example.com"));
+    }
+
+    #[tokio::test]
+    async fn test_socks5_proxy_rejects_bad_credentials() {
+        let server = MockSmtpServer::start().await;
+        let proxy = MockSocks5Proxy::start(server.port(), Some(("relay", "s3cret"))).await;
+        let config = server
+            .config()
+            .proxy(ProxyConfig::new("127.0.0.1", proxy.port()).credentials("relay", "wrong"))
+            .build()
+            .unwrap();
+
+        let err = TcpTransport::connect(&config).await.unwrap_err();
+        assert_eq!(err.kind(), SmtpErrorKind::CredentialsInvalid);
+        assert_eq!(server.connections(), 0);
+    }
 }
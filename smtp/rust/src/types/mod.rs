@@ -534,6 +534,19 @@ impl BatchSendResult {
     }
 }
 
+/// A message that passed validation and encoding without being sent.
+#[derive(Debug, Clone)]
+pub struct ValidatedMessage {
+    /// Message ID used in the encoded headers.
+    pub message_id: String,
+    /// Envelope recipients (to + cc + bcc).
+    pub recipients: Vec<Address>,
+    /// Encoded RFC 5322 message.
+    pub bytes: Vec<u8>,
+    /// Encoded size in bytes, as declared with the SIZE extension.
+    pub size: usize,
+}
+
 /// A recipient that was rejected by the server.
 #[derive(Debug, Clone)]
 pub struct RejectedRecipient {